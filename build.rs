@@ -11,6 +11,8 @@ pub fn main() -> io::Result<()> {
 
     let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR environment variable not set");
     let output_file = Path::new(&out_dir).join("stubs_map.rs");
+    let framework_output_file = Path::new(&out_dir).join("framework_stubs_map.rs");
+    let corpus_output_file = Path::new(&out_dir).join("self_test_corpus.rs");
 
     // Ensure the stubs directory exists
     if !stubs_dir.exists() {
@@ -40,6 +42,79 @@ pub fn main() -> io::Result<()> {
     writeln!(file, "{}", map_content.join(",\n"))?;
     writeln!(file, "];")?;
 
+    write_framework_stubs(Path::new("framework-stubs"), &framework_output_file)?;
+    write_self_test_corpus(Path::new("corpus"), &corpus_output_file)?;
+
+    Ok(())
+}
+
+/// Writes the list of snippets the `self-test` command formats and lints, from the files of the given directory.
+fn write_self_test_corpus(corpus_dir: &Path, output_file: &Path) -> io::Result<()> {
+    let mut corpus = Vec::new();
+    if corpus_dir.exists() {
+        collect_files(corpus_dir, corpus_dir, &mut corpus)?;
+        corpus.sort();
+    }
+
+    let map_content = corpus
+        .into_iter()
+        .map(|(simplified_path, include_path)| {
+            let simplified_path = simplified_path.replacen("stubs", "corpus", 1);
+
+            format!(r##"    (r#"@{simplified_path}"#, include_str!("{include_path}"))"##)
+        })
+        .collect::<Vec<_>>();
+
+    let mut file = File::create(output_file)?;
+
+    writeln!(file, "// This file is generated by the build script")?;
+    writeln!(file, "// Do not modify this file manually")?;
+    writeln!(file)?;
+    writeln!(file, "pub static SELF_TEST_CORPUS: [(&str, &str); {}] = [", map_content.len())?;
+    writeln!(file, "{}", map_content.join(",\n"))?;
+    writeln!(file, "];")?;
+
+    Ok(())
+}
+
+/// Writes the map of framework stubs, where each subdirectory of the given directory contains
+/// the stubs of the framework with the same name.
+fn write_framework_stubs(framework_stubs_dir: &Path, output_file: &Path) -> io::Result<()> {
+    let mut map_content = Vec::new();
+    if framework_stubs_dir.exists() {
+        let mut frameworks = fs::read_dir(framework_stubs_dir)?.collect::<Result<Vec<_>, _>>()?;
+        frameworks.sort_by_key(|entry| entry.path());
+
+        for entry in frameworks {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            let framework = entry.file_name().to_string_lossy().to_lowercase();
+            let mut stubs_map = Vec::new();
+            collect_files(&path, &path, &mut stubs_map)?;
+            stubs_map.sort();
+
+            for (simplified_path, include_path) in stubs_map {
+                let simplified_path = simplified_path.replacen("stubs", &format!("framework-stubs/{framework}"), 1);
+
+                map_content.push(format!(
+                    r##"    (r#"{framework}"#, r#"@{simplified_path}"#, include_str!("{include_path}"))"##
+                ));
+            }
+        }
+    }
+
+    let mut file = File::create(output_file)?;
+
+    writeln!(file, "// This file is generated by the build script")?;
+    writeln!(file, "// Do not modify this file manually")?;
+    writeln!(file)?;
+    writeln!(file, "pub static FRAMEWORK_STUBS: [(&str, &str, &str); {}] = [", map_content.len())?;
+    writeln!(file, "{}", map_content.join(",\n"))?;
+    writeln!(file, "];")?;
+
     Ok(())
 }
 
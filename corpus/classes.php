@@ -0,0 +1,107 @@
+<?php
+
+declare(strict_types=1);
+
+namespace App\Domain\Billing;
+
+use App\Domain\Billing\Contracts\InvoiceRepository;
+use App\Domain\Billing\Events\InvoicePaid;
+use DateTimeImmutable;
+use InvalidArgumentException;
+use Psr\EventDispatcher\EventDispatcherInterface;
+
+/**
+ * @template T of object
+ */
+abstract class AbstractEntity implements \JsonSerializable
+{
+    protected static int $instances = 0;
+
+    public function __construct(
+        protected readonly string $id,
+        private ?DateTimeImmutable $createdAt = null,
+    ) {
+        static::$instances++;
+        $this->createdAt ??= new DateTimeImmutable();
+    }
+
+    abstract public function toArray(): array;
+
+    public function jsonSerialize(): mixed
+    {
+        return ['id' => $this->id, 'created_at' => $this->createdAt?->format(DATE_ATOM)] + $this->toArray();
+    }
+}
+
+final class Invoice extends AbstractEntity
+{
+    public const STATUS_DRAFT = 'draft';
+    public const STATUS_PAID = 'paid';
+
+    /** @var list<array{description: string, amount: int}> */
+    private array $lines = [];
+
+    private string $status = self::STATUS_DRAFT;
+
+    public function addLine(string $description, int $amount): static
+    {
+        if ($amount <= 0) {
+            throw new InvalidArgumentException(sprintf('The amount of "%s" must be positive, %d given.', $description, $amount));
+        }
+
+        $this->lines[] = compact('description', 'amount');
+
+        return $this;
+    }
+
+    public function total(): int
+    {
+        return array_sum(array_column($this->lines, 'amount'));
+    }
+
+    public function markAsPaid(EventDispatcherInterface $dispatcher, InvoiceRepository $repository): void
+    {
+        $this->status = self::STATUS_PAID;
+        $repository->save($this);
+        $dispatcher->dispatch(new InvoicePaid($this->id, $this->total(), new DateTimeImmutable('now')));
+    }
+
+    public function toArray(): array
+    {
+        return ['status' => $this->status, 'lines' => $this->lines, 'total' => $this->total()];
+    }
+}
+
+interface Discount
+{
+    public function apply(int $amount): int;
+}
+
+trait Loggable
+{
+    private array $log = [];
+
+    protected function log(string $message, mixed ...$context): void
+    {
+        $this->log[] = [$message, $context];
+    }
+}
+
+enum Currency: string implements \Stringable
+{
+    case Euro = 'EUR';
+    case Dollar = 'USD';
+
+    public function symbol(): string
+    {
+        return match ($this) {
+            self::Euro => '€',
+            self::Dollar => '$',
+        };
+    }
+
+    public function __toString(): string
+    {
+        return $this->value;
+    }
+}
@@ -0,0 +1,54 @@
+<?php
+
+/**
+ * File level docblock.
+ */
+
+namespace App; // trailing comment after namespace
+
+use Foo\Bar; # hash comment
+
+/* block comment before a class */
+final class Commented // after class name
+{
+    // leading comment in class body
+
+    /**
+     * @param int $a the first value
+     */
+    public function sum(int $a /* inline */, int $b = 2 /* default */): int
+    {
+        return $a + $b; // trailing comment
+        // dangling comment after return
+    }
+
+    public function empty(): void
+    {
+        // only a comment
+    }
+
+    public /* visibility */ static /* static */ function modifiers(): array
+    {
+        return [
+            // leading array comment
+            'a' => 1, // first
+            'b' => 2,
+            /* before last */ 'c' => 3,
+        ];
+    }
+}
+
+$value = foo(/* no arguments */);
+$other = bar(
+    1, // one
+    2,
+);
+
+if ($value) { // after brace
+    // inside if
+} else /* before else body */ {
+}
+
+#[Foo] // after attribute
+#[Bar, /* between */ Baz]
+function attributed(): void {}
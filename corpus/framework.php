@@ -0,0 +1,62 @@
+<?php
+
+declare(strict_types=1);
+
+namespace App\Http\Controllers;
+
+use App\Http\Requests\StorePostRequest;
+use App\Models\Post;
+use Illuminate\Http\JsonResponse;
+use Illuminate\Support\Facades\DB;
+use Symfony\Component\HttpFoundation\Response;
+use Symfony\Component\Routing\Attribute\Route;
+
+#[Route('/posts', name: 'posts_')]
+final class PostController extends Controller
+{
+    public function __construct(
+        private readonly PostService $posts,
+        #[\SensitiveParameter] private readonly string $apiKey = '',
+    ) {
+        $this->middleware('auth')->except(['index', 'show']);
+    }
+
+    #[Route('/', name: 'index', methods: ['GET'])]
+    public function index(): JsonResponse
+    {
+        $posts = Post::query()
+            ->with(['author', 'comments' => fn($query) => $query->latest()->limit(5)])
+            ->where('published', true)
+            ->whereBetween('created_at', [now()->subMonth(), now()])
+            ->orderByDesc('created_at')
+            ->paginate(perPage: 20);
+
+        return response()->json($posts, Response::HTTP_OK);
+    }
+
+    #[Route('/', name: 'store', methods: ['POST'])]
+    public function store(StorePostRequest $request): JsonResponse
+    {
+        $post = DB::transaction(function () use ($request): Post {
+            $post = $this->posts->create($request->validated() + ['author_id' => $request->user()->id]);
+            $post->tags()->sync($request->input('tags', []));
+
+            return $post;
+        });
+
+        return new JsonResponse(['data' => $post->fresh(['tags'])], Response::HTTP_CREATED, ['Location' => route('posts.show', $post)]);
+    }
+
+    public function destroy(Post $post): Response
+    {
+        $this->authorize('delete', $post);
+
+        if (!$post->trashed() && $post->comments()->exists()) {
+            abort(Response::HTTP_CONFLICT, 'The post has comments.');
+        }
+
+        $post->delete();
+
+        return response()->noContent();
+    }
+}
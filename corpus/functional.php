@@ -0,0 +1,107 @@
+<?php
+
+namespace App\Support;
+
+use Closure;
+use Generator;
+
+function pipe(callable ...$stages): Closure
+{
+    return static fn(mixed $input): mixed => array_reduce($stages, static fn($carry, $stage) => $stage($carry), $input);
+}
+
+function lazy_map(iterable $items, callable $callback): Generator
+{
+    foreach ($items as $key => $value) {
+        yield $key => $callback($value, $key);
+    }
+}
+
+function group_by(array $items, string|callable $key): array
+{
+    $groups = [];
+    foreach ($items as $item) {
+        $group = is_callable($key) ? $key($item) : ($item[$key] ?? $item->{$key} ?? null);
+        $groups[$group][] = $item;
+    }
+
+    return $groups;
+}
+
+$users = [
+    ['name' => 'Ada', 'role' => 'admin', 'age' => 36],
+    ['name' => 'Grace', 'role' => 'editor', 'age' => 45],
+    ['name' => 'Linus', 'role' => 'viewer', 'age' => 28],
+];
+
+$names = array_map(fn(array $user): string => ucfirst($user['name']), array_filter($users, fn($user) => $user['age'] > 30));
+
+$describe = function (array $user) use (&$names): string {
+    [$name, $role] = [$user['name'], $user['role']];
+    ['age' => $age] = $user;
+
+    return match (true) {
+        $age < 18 => "{$name} is a minor",
+        $role === 'admin', $role === 'editor' => "{$name} can edit ({$role})",
+        default => sprintf('%s is %d years old', $name, $age),
+    };
+};
+
+$total = 0;
+for ($i = 0, $count = count($users); $i < $count; $i++) {
+    $total += $users[$i]['age'] ** 2 % 7 <=> 3;
+}
+
+while (($line = array_shift($names)) !== null) {
+    if ($line === '') {
+        continue;
+    } elseif (str_starts_with($line, 'A')) {
+        echo $line, PHP_EOL;
+    } else {
+        break;
+    }
+}
+
+do {
+    $total--;
+} while ($total > 0 && !($total & 1));
+
+switch ($total) {
+    case 0:
+    case 1:
+        echo 'small';
+        break;
+    default:
+        echo 'large';
+}
+
+try {
+    $result = pipe('trim', 'strtolower', ucfirst(...))('  HELLO  ');
+} catch (\TypeError | \ValueError $e) {
+    $result = null;
+} finally {
+    unset($describe);
+}
+
+$message = <<<TXT
+    Hello {$users[0]['name']},
+    your total is {$total}.
+    TXT;
+
+$raw = <<<'SQL'
+    SELECT * FROM users WHERE role = 'admin'
+    SQL;
+
+$callback = $result?->handler ?? static::class;
+$instance = new class($total) extends \ArrayObject implements \Countable {
+    public function __construct(private int $total) {}
+};
+
+list('a' => $a, 'b' => list($b, $c)) = ['a' => 1, 'b' => [2, 3]];
+$value = (int) $a + (float) $b . (string) $c;
+$ref = &$users[0];
+@$undefined[] = clone $instance;
+print $value;
+goto end;
+end:
+exit(0);
@@ -0,0 +1,46 @@
+<?php
+// Procedural code in the style of older applications, mixing HTML and PHP.
+require_once __DIR__ . '/config.php';
+include 'functions.inc.php';
+
+global $db;
+
+if (!defined('APP_ROOT')) define('APP_ROOT', dirname(__FILE__));
+
+function get_user($id) {
+    global $db;
+    static $cache = array();
+    if (isset($cache[$id])) return $cache[$id];
+    $result = mysqli_query($db, "SELECT * FROM users WHERE id = " . intval($id));
+    return $cache[$id] = mysqli_fetch_assoc($result);
+}
+
+$user = get_user($_GET['id']);
+$title = isset($user['name']) ? htmlspecialchars($user['name']) : 'Guest';
+?>
+<!DOCTYPE html>
+<html>
+<head><title><?= $title ?></title></head>
+<body>
+<?php if ($user): ?>
+    <p>Welcome back, <?php echo $title; ?>.</p>
+<?php else: ?>
+    <p>No user found.</p>
+<?php endif; ?>
+</body>
+</html>
+<?php
+foreach ($user as $key => $value):
+    echo $key, ': ', $value;
+endforeach;
+while (false):
+endwhile;
+for ($i = 0; $i < 3; $i++):
+    echo $i;
+endfor;
+switch ($title):
+    case 'Guest':
+        echo 'Welcome!';
+        break;
+endswitch;
+declare(ticks=1);
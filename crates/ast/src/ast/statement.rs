@@ -82,6 +82,8 @@ pub enum Statement {
     HaltCompiler(HaltCompiler),
     Unset(Unset),
     Noop(Span),
+    /// A malformed region of the source, skipped by the tolerant parsing mode.
+    Error(Span),
 }
 
 impl HasSpan for ExpressionStatement {
@@ -125,6 +127,7 @@ impl HasSpan for Statement {
             Statement::Unset(statement) => statement.span(),
             Statement::HaltCompiler(statement) => statement.span(),
             Statement::Noop(span) => *span,
+            Statement::Error(span) => *span,
         }
     }
 }
@@ -17,6 +17,7 @@ pub use crate::trivia::TriviaKind;
 pub mod ast;
 pub mod node;
 pub mod sequence;
+pub mod stack;
 pub mod trivia;
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
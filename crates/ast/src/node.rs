@@ -1886,7 +1886,7 @@ impl<'a> Node<'a> {
                 Statement::Static(node) => vec![Node::Static(node)],
                 Statement::HaltCompiler(node) => vec![Node::HaltCompiler(node)],
                 Statement::Unset(node) => vec![Node::Unset(node)],
-                Statement::Noop(_) => vec![],
+                Statement::Noop(_) | Statement::Error(_) => vec![],
             },
             Node::ExpressionStatement(node) => {
                 vec![Node::Expression(&node.expression), Node::Terminator(&node.terminator)]
@@ -0,0 +1,18 @@
+/// The minimum amount of stack space, in bytes, that must remain before recursing into a nested node.
+///
+/// This has to cover the stack used between two consecutive checks, which is much larger in unoptimized
+/// builds, where the frames of the large functions handling every kind of node are not shrunk.
+const RED_ZONE: usize = 1024 * 1024;
+
+/// The size, in bytes, of every additional stack segment allocated when the remaining stack runs low.
+const STACK_SEGMENT_SIZE: usize = 8 * 1024 * 1024;
+
+/// Runs the given closure, first moving to a newly allocated stack segment if the remaining stack space
+/// of the current thread is running low.
+///
+/// Functions recursing into nested nodes, e.g. expressions and statements, should call this before
+/// recursing, so that deeply nested code can be handled regardless of the stack size of the thread.
+#[inline]
+pub fn ensure_sufficient_stack<R>(callback: impl FnOnce() -> R) -> R {
+    stacker::maybe_grow(RED_ZONE, STACK_SEGMENT_SIZE, callback)
+}
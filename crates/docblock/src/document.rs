@@ -4,6 +4,8 @@ use serde::Serialize;
 use mago_interner::StringIdentifier;
 use mago_span::Span;
 
+use crate::tag::TemplateVariance;
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
 pub struct Document {
     pub elements: Vec<Element>,
@@ -109,6 +111,9 @@ pub enum TagKind {
     Extends,
     Implements,
     Use,
+    TemplateExtends,
+    TemplateImplements,
+    TemplateUse,
     NotDeprecated,
     PhpstanImpure,
     PhpstanPure,
@@ -178,6 +183,12 @@ pub enum TagKind {
     PhpstanVar,
     PhpstanReadOnly,
     PhpstanImmutable,
+    PhpstanExtends,
+    PhpstanImplements,
+    PhpstanUse,
+    PhpstanType,
+    PhpstanImportType,
+    PhpstanIgnore,
     Other,
 }
 
@@ -193,7 +204,10 @@ impl TagKind {
     /// If the tag does not have a vendor, `None` is returned.
     pub fn get_vendor(&self) -> Option<TagVendor> {
         match self {
-            Self::PsalmConsistentConstructor
+            Self::PsalmTemplate
+            | Self::PsalmApi
+            | Self::PsalmInheritors
+            | Self::PsalmConsistentConstructor
             | Self::PsalmConsistentTemplates
             | Self::PsalmParamOut
             | Self::PsalmVar
@@ -254,7 +268,15 @@ impl TagKind {
             | Self::PhpstanReturn
             | Self::PhpstanVar
             | Self::PhpstanReadOnly
-            | Self::PhpstanImmutable => Some(TagVendor::Phpstan),
+            | Self::PhpstanImmutable
+            | Self::PhpstanExtends
+            | Self::PhpstanImplements
+            | Self::PhpstanUse
+            | Self::PhpstanType
+            | Self::PhpstanImportType
+            | Self::PhpstanIgnore
+            | Self::PhpstanPure
+            | Self::PhpstanImpure => Some(TagVendor::Phpstan),
             _ => None,
         }
     }
@@ -267,6 +289,8 @@ impl TagKind {
     ///  `None` is returned.
     pub fn get_non_vendored_variant(&self) -> Option<TagKind> {
         match self {
+            Self::PsalmTemplate => Some(Self::Template),
+            Self::PsalmApi => Some(Self::Api),
             Self::PsalmParamOut => Some(Self::ParamOut),
             Self::PsalmVar => Some(Self::Var),
             Self::PsalmParam => Some(Self::Param),
@@ -291,10 +315,91 @@ impl TagKind {
             Self::PhpstanVar => Some(Self::Var),
             Self::PhpstanReadOnly => Some(Self::ReadOnly),
             Self::PhpstanImmutable => Some(Self::Immutable),
+            Self::PhpstanPure => Some(Self::Pure),
+            Self::PhpstanExtends => Some(Self::Extends),
+            Self::PhpstanImplements => Some(Self::Implements),
+            Self::PhpstanUse => Some(Self::Use),
             _ => None,
         }
     }
 
+    /// Returns the variance of the template declared by the tag, if the tag declares a template.
+    ///
+    /// This includes the `@template` tag, its covariant and contravariant forms, and their vendored variants.
+    pub fn get_template_variance(&self) -> Option<TemplateVariance> {
+        match self {
+            Self::Template | Self::PsalmTemplate | Self::PhpstanTemplate => Some(TemplateVariance::Invariant),
+            Self::TemplateCovariant | Self::PhpstanTemplateCovariant => Some(TemplateVariance::Covariant),
+            Self::TemplateContravariant | Self::PhpstanTemplateContravariant => Some(TemplateVariance::Contravariant),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the tag specifies the template arguments of the parent class, e.g. `@extends Foo<int>`.
+    pub fn is_extends(&self) -> bool {
+        matches!(self, Self::Extends | Self::TemplateExtends | Self::PhpstanExtends)
+    }
+
+    /// Returns `true` if the tag specifies the template arguments of an interface, e.g. `@implements Foo<int>`.
+    pub fn is_implements(&self) -> bool {
+        matches!(self, Self::Implements | Self::TemplateImplements | Self::PhpstanImplements)
+    }
+
+    /// Returns `true` if the tag specifies the template arguments of a trait, e.g. `@use Foo<int>`.
+    pub fn is_use(&self) -> bool {
+        matches!(self, Self::Use | Self::TemplateUse | Self::PhpstanUse)
+    }
+
+    /// Returns `true` if the tag specifies the type of a parameter, e.g. `@param T $value`.
+    pub fn is_param(&self) -> bool {
+        matches!(self, Self::Param | Self::PsalmParam | Self::PhpstanParam)
+    }
+
+    /// Returns `true` if the tag specifies the return type of a function or method, e.g. `@return T`.
+    pub fn is_return(&self) -> bool {
+        matches!(self, Self::Return | Self::PsalmReturn | Self::PhpstanReturn)
+    }
+
+    /// Returns `true` if the tag specifies the type of a variable or property, e.g. `@var T $value`.
+    pub fn is_var(&self) -> bool {
+        matches!(self, Self::Var | Self::PsalmVar | Self::PhpstanVar)
+    }
+
+    /// Returns `true` if the tag marks a function or method as pure, e.g. `@pure` or `@psalm-pure`.
+    pub fn is_pure(&self) -> bool {
+        matches!(self, Self::Pure | Self::PsalmPure | Self::PhpstanPure)
+    }
+
+    /// Returns `true` if the tag marks a class as immutable, e.g. `@immutable` or `@psalm-immutable`.
+    pub fn is_immutable(&self) -> bool {
+        matches!(self, Self::Immutable | Self::PsalmImmutable | Self::PhpstanImmutable)
+    }
+
+    /// Returns `true` if the tag declares a type alias, e.g. `@psalm-type` or `@phpstan-type`.
+    pub fn is_type_alias(&self) -> bool {
+        matches!(self, Self::PsalmType | Self::PhpstanType)
+    }
+
+    /// Returns `true` if the tag imports a type alias, e.g. `@psalm-import-type` or `@phpstan-import-type`.
+    pub fn is_type_alias_import(&self) -> bool {
+        matches!(self, Self::PsalmImportType | Self::PhpstanImportType)
+    }
+
+    /// Returns `true` if the tag asserts the type of a parameter unconditionally, e.g. `@psalm-assert`.
+    pub fn is_assert(&self) -> bool {
+        matches!(self, Self::PsalmAssert | Self::PhpstanAssert)
+    }
+
+    /// Returns `true` if the tag asserts the type of a parameter when the function returns `true`.
+    pub fn is_assert_if_true(&self) -> bool {
+        matches!(self, Self::PsalmAssertIfTrue | Self::PhpstanAssertIfTrue)
+    }
+
+    /// Returns `true` if the tag asserts the type of a parameter when the function returns `false`.
+    pub fn is_assert_if_false(&self) -> bool {
+        matches!(self, Self::PsalmAssertIfFalse | Self::PhpstanAssertIfFalse)
+    }
+
     pub fn is_repeatable(&self) -> bool {
         matches!(
             self,
@@ -316,6 +421,11 @@ impl TagKind {
                 | Self::Throws
                 | Self::Uses
                 | Self::Var
+                | Self::Template
+                | Self::TemplateCovariant
+                | Self::TemplateContravariant
+                | Self::Implements
+                | Self::Use
         )
     }
 }
@@ -393,6 +503,12 @@ where
             "extends" => TagKind::Extends,
             "implements" => TagKind::Implements,
             "use" => TagKind::Use,
+            "template-extends" => TagKind::TemplateExtends,
+            "templateextends" => TagKind::TemplateExtends,
+            "template-implements" => TagKind::TemplateImplements,
+            "templateimplements" => TagKind::TemplateImplements,
+            "template-use" => TagKind::TemplateUse,
+            "templateuse" => TagKind::TemplateUse,
             "not-deprecated" => TagKind::NotDeprecated,
             "notdeprecated" => TagKind::NotDeprecated,
             "phpstan-impure" => TagKind::PhpstanImpure,
@@ -495,6 +611,12 @@ where
             "phpstan-var" => TagKind::PhpstanVar,
             "phpstan-readonly" => TagKind::PhpstanReadOnly,
             "phpstan-immutable" => TagKind::PhpstanImmutable,
+            "phpstan-extends" => TagKind::PhpstanExtends,
+            "phpstan-implements" => TagKind::PhpstanImplements,
+            "phpstan-use" => TagKind::PhpstanUse,
+            "phpstan-type" => TagKind::PhpstanType,
+            "phpstan-import-type" => TagKind::PhpstanImportType,
+            "phpstan-ignore" => TagKind::PhpstanIgnore,
             _ => TagKind::Other,
         }
     }
@@ -1,6 +1,10 @@
+use mago_ast::AttributeList;
+use mago_ast::Program;
+use mago_ast::Sequence;
 use mago_ast::Trivia;
 use mago_ast::TriviaKind;
 use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
 use mago_span::Span;
 
 use crate::document::Document;
@@ -10,6 +14,7 @@ mod internal;
 
 pub mod document;
 pub mod error;
+pub mod tag;
 
 #[inline]
 pub fn parse_trivia(interner: &ThreadedInterner, trivia: &Trivia) -> Result<Document, ParseError> {
@@ -27,6 +32,50 @@ pub fn parse_phpdoc_with_span(interner: &ThreadedInterner, content: &str, span:
     internal::parser::parse_document(tokens.as_slice(), interner)
 }
 
+/// Retrieves the docblock comment that immediately precedes the given offset, if any.
+///
+/// Only whitespace may appear between the end of the docblock and the offset.
+#[inline]
+pub fn get_docblock_before(program: &Program, offset: usize) -> Option<&Trivia> {
+    let trivia = program.trivia.as_slice();
+    let mut index = trivia.partition_point(|trivia| trivia.span.end.offset <= offset);
+    let mut end = offset;
+
+    while index > 0 {
+        index -= 1;
+
+        let trivia = &trivia[index];
+        if trivia.span.end.offset != end {
+            return None;
+        }
+
+        match trivia.kind {
+            TriviaKind::WhiteSpace => end = trivia.span.start.offset,
+            TriviaKind::DocBlockComment => return Some(trivia),
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+/// Retrieves the docblock comment of a declaration, e.g. a class or a function, if any.
+///
+/// The docblock may be placed either before the attributes of the declaration, or between
+/// the attributes and the declaration itself.
+#[inline]
+pub fn get_declaration_docblock<'a>(
+    program: &'a Program,
+    attribute_lists: &Sequence<AttributeList>,
+    declaration: Span,
+) -> Option<&'a Trivia> {
+    get_docblock_before(program, declaration.start.offset).or_else(|| {
+        attribute_lists
+            .first()
+            .and_then(|attribute_list| get_docblock_before(program, attribute_list.span().start.offset))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,328 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::document::TagKind;
+
+/// The variance of a template, as declared by `@template`, `@template-covariant`, or `@template-contravariant`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum TemplateVariance {
+    Invariant,
+    Covariant,
+    Contravariant,
+}
+
+/// A template declared in a docblock, e.g. `@template T of Foo`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TemplateTag<'a> {
+    /// The name of the template, e.g. `T`.
+    pub name: &'a str,
+
+    /// The constraint of the template as written in the docblock, e.g. `Foo` in `T of Foo`.
+    pub constraint: Option<&'a str>,
+
+    /// The variance of the template.
+    pub variance: TemplateVariance,
+}
+
+/// A generic type reference in a docblock, e.g. `Collection<int, Foo>` in `@extends Collection<int, Foo>`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct GenericType<'a> {
+    /// The name of the generic type, as written in the docblock, e.g. `Collection`.
+    pub name: &'a str,
+
+    /// The template arguments, as written in the docblock, e.g. `int` and `Foo`.
+    pub arguments: Vec<&'a str>,
+}
+
+/// A type alias imported from another class, e.g. `@psalm-import-type Foo from Bar as Baz`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TypeAliasImport<'a> {
+    /// The name of the imported type alias, e.g. `Foo`.
+    pub name: &'a str,
+
+    /// The class declaring the type alias, as written in the docblock, e.g. `Bar`.
+    pub from: &'a str,
+
+    /// The local name of the type alias, if it is renamed, e.g. `Baz`.
+    pub alias: Option<&'a str>,
+}
+
+/// An assertion about the type of a parameter, e.g. `!null $value` in `@psalm-assert !null $value`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Assertion<'a> {
+    /// The asserted type as written in the docblock, e.g. `!null`.
+    pub r#type: &'a str,
+
+    /// The name of the asserted parameter, including the `$` prefix, e.g. `$value`.
+    pub parameter: &'a str,
+}
+
+/// Parses the description of a template tag, e.g. `T of Foo` in `@template T of Foo`.
+///
+/// Both the `of` (PHPStan, Psalm) and `as` (Psalm) constraint syntaxes are supported.
+///
+/// Returns `None` if the tag does not declare a template, or if the description is malformed.
+pub fn parse_template_tag(kind: TagKind, description: &str) -> Option<TemplateTag<'_>> {
+    let variance = kind.get_template_variance()?;
+    let description = description.trim_start();
+
+    let name_length = description.find(|c: char| !is_identifier_char(c)).unwrap_or(description.len());
+    let name = &description[..name_length];
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let rest = description[name_length..].trim_start();
+    let constraint = ["of ", "as "]
+        .into_iter()
+        .find_map(|keyword| rest.strip_prefix(keyword))
+        .and_then(|rest| split_type(rest).map(|(constraint, _)| constraint));
+
+    Some(TemplateTag { name, constraint, variance })
+}
+
+/// Parses the description of an `@extends`, `@implements`, or `@use` tag, e.g. `Collection<int, Foo>`.
+///
+/// Returns `None` if the description does not start with a named type.
+pub fn parse_generic_type(description: &str) -> Option<GenericType<'_>> {
+    let (r#type, _) = split_type(description)?;
+
+    let Some(opening) = r#type.find('<') else {
+        return is_name(r#type).then_some(GenericType { name: r#type, arguments: vec![] });
+    };
+
+    let name = r#type[..opening].trim_end();
+    let arguments = r#type[opening + 1..].strip_suffix('>')?;
+    if !is_name(name) {
+        return None;
+    }
+
+    Some(GenericType { name, arguments: split_arguments(arguments) })
+}
+
+/// Parses the description of a type alias tag, e.g. `Foo = array{foo: string}` in `@psalm-type`,
+/// and returns the name of the type alias.
+///
+/// Both the `Foo = Type` (Psalm, PHPStan) and `Foo Type` (PHPStan) syntaxes are supported.
+pub fn parse_type_alias_tag(description: &str) -> Option<&str> {
+    let description = description.trim_start();
+    let name_length = description.find(|c: char| !is_identifier_char(c)).unwrap_or(description.len());
+    let name = &description[..name_length];
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) { None } else { Some(name) }
+}
+
+/// Parses the description of a type alias import tag, e.g. `Foo from Bar as Baz` in `@psalm-import-type`.
+pub fn parse_type_alias_import_tag(description: &str) -> Option<TypeAliasImport<'_>> {
+    let mut words = description.split_whitespace();
+
+    let name = words.next().filter(|name| name.chars().all(is_identifier_char))?;
+    if words.next() != Some("from") {
+        return None;
+    }
+
+    let from = words.next().filter(|from| is_name(from))?;
+    let alias = match words.next() {
+        Some("as") => Some(words.next().filter(|alias| alias.chars().all(is_identifier_char))?),
+        _ => None,
+    };
+
+    Some(TypeAliasImport { name, from, alias })
+}
+
+/// Parses the description of an assertion tag, e.g. `int $value` in `@psalm-assert int $value`.
+///
+/// Returns `None` if the description does not contain both a type and a parameter name.
+pub fn parse_assertion_tag(description: &str) -> Option<Assertion<'_>> {
+    let (r#type, rest) = split_type(description)?;
+    let parameter = rest.split_whitespace().next().filter(|parameter| parameter.starts_with('$'))?;
+
+    Some(Assertion { r#type, parameter })
+}
+
+/// Splits the leading type from a tag description, e.g. `array<int, T>` and `$value` in `array<int, T> $value`.
+///
+/// Returns `None` if the description is empty.
+pub fn split_type(description: &str) -> Option<(&str, &str)> {
+    let description = description.trim_start();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut previous = None;
+    let mut chars = description.char_indices().peekable();
+
+    while let Some((index, char)) = chars.next() {
+        match (quote, char) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(char),
+            (None, '<' | '{' | '(' | '[') => depth += 1,
+            (None, '>' | '}' | ')' | ']') => depth = depth.saturating_sub(1),
+            (None, c) if c.is_whitespace() && depth == 0 => {
+                let continues = matches!(previous, Some('|' | '&' | ':' | ','))
+                    || description[index..].trim_start().starts_with(['|', '&', ':']);
+
+                if !continues {
+                    let (r#type, rest) = description.split_at(index);
+
+                    return Some((r#type, rest.trim_start()));
+                }
+            }
+            _ => {}
+        }
+
+        if !char.is_whitespace() {
+            previous = Some(char);
+        }
+
+        // The `-` of `->` should not close a generic type.
+        if char == '-' && chars.peek().is_some_and(|(_, next)| *next == '>') {
+            chars.next();
+        }
+    }
+
+    if description.is_empty() { None } else { Some((description, "")) }
+}
+
+/// Returns `true` if the given type, as written in a docblock, references the given name,
+/// e.g. `array<int, T>` references `T`, while `Tree` does not.
+pub fn type_references(r#type: &str, name: &str) -> bool {
+    r#type.match_indices(name).any(|(index, _)| {
+        let before = r#type[..index].chars().next_back();
+        let after = r#type[index + name.len()..].chars().next();
+
+        !before.is_some_and(|c| is_identifier_char(c) || c == '\\' || c == '$')
+            && !after.is_some_and(|c| is_identifier_char(c) || c == '\\')
+    })
+}
+
+#[inline]
+fn split_arguments(arguments: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, char) in arguments.char_indices() {
+        match char {
+            '<' | '{' | '(' | '[' => depth += 1,
+            '>' | '}' | ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                result.push(arguments[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = arguments[start..].trim();
+    if !last.is_empty() {
+        result.push(last);
+    }
+
+    result
+}
+
+#[inline]
+fn is_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| is_identifier_char(c) || c == '\\')
+}
+
+#[inline]
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || !c.is_ascii()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_template_tag() {
+        let tag = parse_template_tag(TagKind::Template, "T").unwrap();
+        assert_eq!(tag.name, "T");
+        assert_eq!(tag.constraint, None);
+        assert_eq!(tag.variance, TemplateVariance::Invariant);
+
+        let tag =
+            parse_template_tag(TagKind::PhpstanTemplateCovariant, "TValue of array<int, string> Description").unwrap();
+        assert_eq!(tag.name, "TValue");
+        assert_eq!(tag.constraint, Some("array<int, string>"));
+        assert_eq!(tag.variance, TemplateVariance::Covariant);
+
+        let tag = parse_template_tag(TagKind::PsalmTemplate, "T as Foo|Bar").unwrap();
+        assert_eq!(tag.name, "T");
+        assert_eq!(tag.constraint, Some("Foo|Bar"));
+
+        assert!(parse_template_tag(TagKind::Param, "T").is_none());
+        assert!(parse_template_tag(TagKind::Template, "").is_none());
+        assert!(parse_template_tag(TagKind::Template, "$value").is_none());
+    }
+
+    #[test]
+    fn test_parse_generic_type() {
+        let generic = parse_generic_type("Collection<int, array{foo: string, bar: int}> Description").unwrap();
+        assert_eq!(generic.name, "Collection");
+        assert_eq!(generic.arguments, vec!["int", "array{foo: string, bar: int}"]);
+
+        let generic = parse_generic_type("\\Foo\\Bar<Closure(int, string): void>").unwrap();
+        assert_eq!(generic.name, "\\Foo\\Bar");
+        assert_eq!(generic.arguments, vec!["Closure(int, string): void"]);
+
+        let generic = parse_generic_type("Foo").unwrap();
+        assert_eq!(generic.name, "Foo");
+        assert!(generic.arguments.is_empty());
+
+        assert!(parse_generic_type("").is_none());
+        assert!(parse_generic_type("array{foo: string}").is_none());
+    }
+
+    #[test]
+    fn test_parse_type_alias_tags() {
+        assert_eq!(parse_type_alias_tag("UserData = array{id: int, name: string}"), Some("UserData"));
+        assert_eq!(parse_type_alias_tag("UserData array{id: int}"), Some("UserData"));
+        assert_eq!(parse_type_alias_tag("= int"), None);
+
+        let import = parse_type_alias_import_tag("UserData from \\App\\User").unwrap();
+        assert_eq!(import.name, "UserData");
+        assert_eq!(import.from, "\\App\\User");
+        assert_eq!(import.alias, None);
+
+        let import = parse_type_alias_import_tag("UserData from User as Data").unwrap();
+        assert_eq!(import.alias, Some("Data"));
+
+        assert!(parse_type_alias_import_tag("UserData").is_none());
+        assert!(parse_type_alias_import_tag("UserData from User as").is_none());
+    }
+
+    #[test]
+    fn test_parse_assertion_tag() {
+        let assertion = parse_assertion_tag("!null $value").unwrap();
+        assert_eq!(assertion.r#type, "!null");
+        assert_eq!(assertion.parameter, "$value");
+
+        let assertion = parse_assertion_tag("list<int> $items The items").unwrap();
+        assert_eq!(assertion.r#type, "list<int>");
+        assert_eq!(assertion.parameter, "$items");
+
+        assert!(parse_assertion_tag("int").is_none());
+    }
+
+    #[test]
+    fn test_split_type() {
+        assert_eq!(split_type("array<int, T> $value"), Some(("array<int, T>", "$value")));
+        assert_eq!(split_type("int | string $value"), Some(("int | string", "$value")));
+        assert_eq!(split_type("callable(T): void $callback"), Some(("callable(T): void", "$callback")));
+        assert_eq!(split_type("'foo bar'|int $value"), Some(("'foo bar'|int", "$value")));
+        assert_eq!(split_type("T"), Some(("T", "")));
+        assert_eq!(split_type("  "), None);
+    }
+
+    #[test]
+    fn test_type_references() {
+        assert!(type_references("T", "T"));
+        assert!(type_references("array<int, T>", "T"));
+        assert!(type_references("callable(T): void", "T"));
+        assert!(!type_references("Tree", "T"));
+        assert!(!type_references("Foo\\T", "T"));
+        assert!(!type_references("$T", "T"));
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use ahash::HashMap;
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_ast::Node;
+use mago_ast::NodeKind;
+use mago_ast::Program;
+use mago_interner::ThreadedInterner;
+use mago_parser::tokenize_source;
+use mago_source::Source;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// The default minimum number of tokens a code fragment must contain to be considered a clone.
+pub const DEFAULT_MINIMUM_TOKENS: usize = 50;
+
+/// Settings controlling how duplicated code is detected.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct DuplicationSettings {
+    /// The minimum number of tokens (excluding whitespace and comments) a fragment must
+    /// contain to be reported.
+    pub minimum_tokens: usize,
+
+    /// Whether identifiers (names and variables) should be ignored when comparing fragments.
+    ///
+    /// When enabled, fragments that only differ in the names they use are reported as clones.
+    pub ignore_identifiers: bool,
+
+    /// Whether literals (strings and numbers) should be ignored when comparing fragments.
+    ///
+    /// When enabled, fragments that only differ in the literal values they use are reported as clones.
+    pub ignore_literals: bool,
+}
+
+/// A group of code fragments that are structurally identical.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct CloneGroup {
+    /// The number of tokens in each fragment of the group.
+    pub tokens: usize,
+
+    /// The spans of the duplicated fragments, sorted by their position.
+    pub fragments: Vec<Span>,
+}
+
+/// A code fragment that is a candidate for duplication.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+struct Fragment {
+    span: Span,
+    tokens: usize,
+}
+
+/// Detects duplicated code across multiple programs.
+///
+/// Every statement, block, and class-like member in a program is fingerprinted by hashing its
+/// normalized syntax tree. Fragments sharing the same fingerprint are reported as a [`CloneGroup`].
+///
+/// Programs can be added to separate detectors in parallel and combined using [`DuplicationDetector::merge`].
+#[derive(Debug, Clone, Default)]
+pub struct DuplicationDetector {
+    settings: DuplicationSettings,
+    candidates: HashMap<u64, Vec<Fragment>>,
+}
+
+impl DuplicationDetector {
+    /// Creates a new detector using the given settings.
+    pub fn new(settings: DuplicationSettings) -> Self {
+        Self { settings, candidates: HashMap::default() }
+    }
+
+    /// Fingerprints all fragments of the given program.
+    ///
+    /// The source is re-tokenized to count the tokens of each fragment.
+    pub fn add(&mut self, interner: &ThreadedInterner, source: &Source, program: &Program) {
+        let (tokens, _) = tokenize_source(interner, source);
+        let offsets = tokens
+            .iter()
+            .filter(|token| !token.kind.is_trivia())
+            .map(|token| token.span.start.offset)
+            .collect::<Vec<_>>();
+
+        let mut fingerprinter = Fingerprinter {
+            settings: &self.settings,
+            content: &source.content,
+            offsets: &offsets,
+            candidates: &mut self.candidates,
+        };
+
+        fingerprinter.fingerprint(Node::Program(program));
+    }
+
+    /// Merges the fragments collected by another detector into this one.
+    pub fn merge(&mut self, other: DuplicationDetector) {
+        for (fingerprint, fragments) in other.candidates {
+            self.candidates.entry(fingerprint).or_default().extend(fragments);
+        }
+    }
+
+    /// Consumes the detector, returning the detected clone groups sorted by position.
+    ///
+    /// Groups whose fragments are all contained within the fragments of a larger group are omitted,
+    /// so that only the largest duplicated fragments are reported.
+    pub fn finish(self) -> Vec<CloneGroup> {
+        let mut groups = self
+            .candidates
+            .into_values()
+            .filter(|fragments| fragments.len() > 1)
+            .map(|mut fragments| {
+                fragments.sort();
+                fragments.dedup_by_key(|fragment| fragment.span);
+
+                CloneGroup {
+                    tokens: fragments.iter().map(|fragment| fragment.tokens).max().unwrap_or_default(),
+                    fragments: fragments.into_iter().map(|fragment| fragment.span).collect(),
+                }
+            })
+            .filter(|group| group.fragments.len() > 1)
+            .collect::<Vec<_>>();
+
+        groups.sort_by(|a, b| b.tokens.cmp(&a.tokens).then_with(|| a.fragments.cmp(&b.fragments)));
+
+        let mut reported: Vec<Span> = vec![];
+        groups.retain(|group| {
+            let subsumed =
+                group.fragments.iter().all(|fragment| reported.iter().any(|outer| contains(outer, fragment)));
+            if !subsumed {
+                reported.extend(group.fragments.iter().copied());
+            }
+
+            !subsumed
+        });
+
+        groups.sort_by(|a, b| a.fragments.cmp(&b.fragments));
+        groups
+    }
+}
+
+impl Default for DuplicationSettings {
+    fn default() -> Self {
+        Self { minimum_tokens: DEFAULT_MINIMUM_TOKENS, ignore_identifiers: false, ignore_literals: false }
+    }
+}
+
+struct Fingerprinter<'a> {
+    settings: &'a DuplicationSettings,
+    content: &'a str,
+    offsets: &'a [usize],
+    candidates: &'a mut HashMap<u64, Vec<Fragment>>,
+}
+
+impl Fingerprinter<'_> {
+    /// Computes the fingerprint of the given node, recording it as a candidate if eligible.
+    ///
+    /// Inner nodes are hashed by their kind and the fingerprints of their children, while leaf nodes
+    /// are hashed by their kind and source text, unless they are identifiers or literals that should
+    /// be ignored.
+    fn fingerprint(&mut self, node: Node<'_>) -> u64 {
+        let kind = node.kind();
+        let mut hasher = DefaultHasher::new();
+        kind.hash(&mut hasher);
+
+        let children = node.children();
+        if children.is_empty() {
+            if !self.is_ignored(kind) {
+                let span = node.span();
+
+                self.content.get(span.start.offset..span.end.offset).hash(&mut hasher);
+            }
+        } else {
+            for child in children {
+                self.fingerprint(child).hash(&mut hasher);
+            }
+        }
+
+        let fingerprint = hasher.finish();
+        if matches!(node, Node::Statement(_) | Node::Block(_) | Node::ClassLikeMember(_)) {
+            let span = node.span();
+            let tokens = self.count_tokens(span);
+
+            if tokens >= self.settings.minimum_tokens {
+                self.candidates.entry(fingerprint).or_default().push(Fragment { span, tokens });
+            }
+        }
+
+        fingerprint
+    }
+
+    fn is_ignored(&self, kind: NodeKind) -> bool {
+        match kind {
+            NodeKind::LocalIdentifier
+            | NodeKind::QualifiedIdentifier
+            | NodeKind::FullyQualifiedIdentifier
+            | NodeKind::DirectVariable => self.settings.ignore_identifiers,
+            NodeKind::LiteralString
+            | NodeKind::LiteralInteger
+            | NodeKind::LiteralFloat
+            | NodeKind::LiteralStringPart => self.settings.ignore_literals,
+            _ => false,
+        }
+    }
+
+    fn count_tokens(&self, span: Span) -> usize {
+        let start = self.offsets.partition_point(|offset| *offset < span.start.offset);
+        let end = self.offsets.partition_point(|offset| *offset < span.end.offset);
+
+        end - start
+    }
+}
+
+#[inline]
+fn contains(outer: &Span, inner: &Span) -> bool {
+    outer.start.source == inner.start.source
+        && outer.start.offset <= inner.start.offset
+        && inner.end.offset <= outer.end.offset
+}
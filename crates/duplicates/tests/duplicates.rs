@@ -0,0 +1,114 @@
+use indoc::indoc;
+
+use mago_duplicates::CloneGroup;
+use mago_duplicates::DuplicationDetector;
+use mago_duplicates::DuplicationSettings;
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source;
+use mago_source::Source;
+
+fn detect(code: &'static str, settings: DuplicationSettings) -> (String, Vec<CloneGroup>) {
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "code.php", code);
+    let (program, error) = parse_source(&interner, &source);
+
+    assert!(error.is_none(), "unexpected parse error: {:?}", error);
+
+    let mut detector = DuplicationDetector::new(settings);
+    detector.add(&interner, &source, &program);
+
+    (code.to_string(), detector.finish())
+}
+
+fn settings(minimum_tokens: usize) -> DuplicationSettings {
+    DuplicationSettings { minimum_tokens, ..Default::default() }
+}
+
+const CODE: &str = indoc! {r#"
+    <?php
+
+    function first(array $items): int {
+        $total = 0;
+        foreach ($items as $item) {
+            $total += $item * 2;
+        }
+
+        return $total;
+    }
+
+    function second(array $values): int {
+        $sum = 0;
+        foreach ($values as $value) {
+            $sum += $value * 2;
+        }
+
+        return $sum;
+    }
+
+    function third(array $items): int {
+        $total = 0;
+        foreach ($items as $item) {
+            $total += $item * 2;
+        }
+
+        return $total;
+    }
+"#};
+
+#[test]
+fn test_exact_clones_are_reported_once() {
+    let (code, groups) = detect(CODE, settings(20));
+
+    assert_eq!(1, groups.len());
+
+    let group = &groups[0];
+    assert_eq!(2, group.fragments.len());
+    for fragment in &group.fragments {
+        let text = &code[fragment.start.offset..fragment.end.offset];
+
+        assert!(text.starts_with('{'), "expected a function body, got: {}", text);
+        assert!(text.contains("$total"));
+    }
+}
+
+#[test]
+fn test_renamed_clones_are_reported_when_ignoring_identifiers() {
+    let (code, groups) = detect(CODE, DuplicationSettings { ignore_identifiers: true, ..settings(20) });
+
+    assert_eq!(1, groups.len());
+    assert_eq!(3, groups[0].fragments.len());
+    assert!(code[groups[0].fragments[1].start.offset..].starts_with("function second"));
+}
+
+#[test]
+fn test_fragments_below_threshold_are_not_reported() {
+    let (_, groups) = detect(CODE, settings(200));
+
+    assert!(groups.is_empty());
+}
+
+#[test]
+fn test_literal_differences_are_reported_when_ignoring_literals() {
+    let code = indoc! {r#"
+        <?php
+
+        if ($debug) {
+            echo 'first message';
+            echo 'second message';
+            echo 'third message';
+        }
+
+        if ($debug) {
+            echo 'fourth message';
+            echo 'fifth message';
+            echo 'sixth message';
+        }
+    "#};
+
+    assert!(detect(code, settings(10)).1.is_empty());
+
+    let (_, groups) = detect(code, DuplicationSettings { ignore_literals: true, ..settings(10) });
+
+    assert_eq!(1, groups.len());
+    assert_eq!(2, groups[0].fragments.len());
+}
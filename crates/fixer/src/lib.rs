@@ -252,6 +252,43 @@ impl FixOperation {
             FixOperation::Delete { safety_classification, .. } => *safety_classification,
         }
     }
+
+    /// Returns the range of text affected by the operation, which is empty for an insertion.
+    pub fn get_range(&self) -> Range<usize> {
+        match self {
+            FixOperation::Insert { offset, .. } => *offset..*offset,
+            FixOperation::Replace { range, .. } => range.clone(),
+            FixOperation::Delete { range, .. } => range.clone(),
+        }
+    }
+
+    /// Determines whether this operation conflicts with another one, i.e. whether applying both
+    /// could produce a different result depending on the order in which they are applied.
+    ///
+    /// Identical operations never conflict, and neither do deletions, as deleting overlapping ranges
+    /// deletes their union regardless of the order. Otherwise, operations conflict when they affect
+    /// overlapping ranges, when text is inserted within, or at the start of, a range that is replaced
+    /// or deleted, or when different texts are inserted at the same offset.
+    pub fn conflicts_with(&self, other: &FixOperation) -> bool {
+        if self == other {
+            return false;
+        }
+
+        match (self, other) {
+            (FixOperation::Delete { .. }, FixOperation::Delete { .. }) => false,
+            (FixOperation::Insert { offset: a, .. }, FixOperation::Insert { offset: b, .. }) => a == b,
+            (FixOperation::Insert { offset, .. }, operation) | (operation, FixOperation::Insert { offset, .. }) => {
+                let range = operation.get_range();
+
+                range.start <= *offset && *offset < range.end
+            }
+            (a, b) => {
+                let (a, b) = (a.get_range(), b.get_range());
+
+                a.start < b.end && b.start < a.end
+            }
+        }
+    }
 }
 
 impl FixPlan {
@@ -353,6 +390,38 @@ impl FixPlan {
         }
     }
 
+    /// Merges another `FixPlan` into this one, unless any of its operations conflicts with the
+    /// operations of this plan, as determined by [`FixOperation::conflicts_with`].
+    ///
+    /// This is used to combine the fixes of several issues, e.g. reported by different rules, without
+    /// applying overlapping edits that could corrupt the content.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other `FixPlan` to merge.
+    ///
+    /// # Errors
+    ///
+    /// Returns the other plan, unchanged, if it conflicts with this one.
+    pub fn try_merge(&mut self, other: FixPlan) -> Result<(), FixPlan> {
+        let conflicts = other
+            .operations
+            .iter()
+            .any(|operation| self.operations.iter().any(|existing| existing.conflicts_with(operation)));
+
+        if conflicts {
+            return Err(other);
+        }
+
+        for operation in other.operations {
+            if !self.operations.contains(&operation) {
+                self.operation(operation);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns a reference to the operations in the plan.
     pub fn get_operations(&self) -> &Vec<FixOperation> {
         &self.operations
@@ -399,6 +468,36 @@ impl FixPlan {
         }
     }
 
+    /// Converts the line feeds of the inserted and replacing texts to the given line ending,
+    /// so that the fixes follow the line endings of the content they are applied to.
+    ///
+    /// Line feeds already preceded by a carriage return are left as they are.
+    pub fn with_line_ending(mut self, line_ending: &str) -> Self {
+        if line_ending == "\n" {
+            return self;
+        }
+
+        for operation in self.operations.iter_mut() {
+            if let FixOperation::Insert { text, .. } | FixOperation::Replace { text, .. } = operation {
+                let mut converted = String::with_capacity(text.len());
+                let mut previous = None;
+                for character in text.chars() {
+                    if character == '\n' && previous != Some('\r') {
+                        converted.push_str(line_ending);
+                    } else {
+                        converted.push(character);
+                    }
+
+                    previous = Some(character);
+                }
+
+                *text = converted;
+            }
+        }
+
+        self
+    }
+
     /// Determines whether the plan is empty.
     pub fn is_empty(&self) -> bool {
         self.operations.is_empty()
@@ -708,6 +807,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_with_line_ending() {
+        let content = "<?php\r\n\r\necho 1;\r\n";
+        let mut fix = FixPlan::new();
+        fix.insert(9, "declare(strict_types=1);\n\r\n", SafetyClassification::Safe);
+        fix.replace(14..15, "2;\n// done", SafetyClassification::Safe);
+        let result = fix.with_line_ending("\r\n").execute(content);
+        assert_eq!(result.get_fixed(), "<?php\r\n\r\ndeclare(strict_types=1);\r\n\r\necho 2;\r\n// done;\r\n");
+    }
+
     #[test]
     fn test_insert_within_bounds() {
         // Insert at a valid position within the content
@@ -928,4 +1037,51 @@ mod tests {
         let result = fix.execute(content);
         assert_eq!(result.get_fixed(), "<?php while(true): endwhile;");
     }
+
+    #[test]
+    fn test_try_merge_rejects_conflicting_plans() {
+        let content = "$a = ($b) + ($c);";
+
+        let mut plan = FixPlan::new();
+        plan.delete(5..6, SafetyClassification::Safe); // remove the `(` before $b
+        plan.delete(8..9, SafetyClassification::Safe); // remove the `)` after $b
+
+        // Deleting the same range, or an overlapping one, composes with the existing deletions.
+        let mut other = FixPlan::new();
+        other.delete(5..6, SafetyClassification::Safe);
+        assert!(plan.try_merge(other).is_ok());
+        assert_eq!(plan.len(), 2);
+
+        let mut other = FixPlan::new();
+        other.delete(4..6, SafetyClassification::Safe);
+        assert!(plan.clone().try_merge(other).is_ok());
+
+        // Replacing text overlapping a deleted range conflicts.
+        let mut other = FixPlan::new();
+        other.replace(5..8, "$d", SafetyClassification::Safe);
+        assert!(plan.try_merge(other).is_err());
+
+        // Inserting text at the start of a deleted range conflicts, but not at its end.
+        let mut other = FixPlan::new();
+        other.insert(5, "(int) ", SafetyClassification::Safe);
+        assert!(plan.try_merge(other).is_err());
+
+        let mut other = FixPlan::new();
+        other.replace(10..11, "*", SafetyClassification::Safe);
+        other.insert(9, " ", SafetyClassification::Safe);
+        assert!(plan.try_merge(other).is_ok());
+
+        // The same insertion is only applied once, but a different one at the same offset conflicts.
+        let mut other = FixPlan::new();
+        other.insert(9, " ", SafetyClassification::Safe);
+        assert!(plan.try_merge(other).is_ok());
+
+        let mut other = FixPlan::new();
+        other.insert(9, "  ", SafetyClassification::Safe);
+        let rejected = plan.try_merge(other.clone()).unwrap_err();
+        assert_eq!(rejected, other);
+
+        assert_eq!(plan.len(), 4);
+        assert_eq!(plan.execute(content).get_fixed(), "$a = $b  * ($c);");
+    }
 }
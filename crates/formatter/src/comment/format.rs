@@ -159,7 +159,7 @@ impl<'a> Formatter<'a> {
         Some(Document::Array(parts))
     }
 
-    fn print_trailing_comment(
+    pub(super) fn print_trailing_comment(
         &mut self,
         parts: &mut Vec<Document<'a>>,
         comment: Comment,
@@ -200,6 +200,17 @@ impl<'a> Formatter<'a> {
         comment.with_line_suffix(false)
     }
 
+    /// Skips the comments within the given range, which are printed as part of its source text.
+    pub(crate) fn skip_inner_comments(&mut self, range: Span) {
+        while let Some(comment) = self.comments.peek() {
+            if comment.span.start.offset >= range.start.offset && comment.span.end.offset <= range.end.offset {
+                self.comments.next();
+            } else {
+                break;
+            }
+        }
+    }
+
     #[must_use]
     pub(crate) fn print_inner_comment(&mut self, range: Span) -> Vec<Document<'a>> {
         let mut parts = vec![];
@@ -3,6 +3,7 @@ use bitflags::bitflags;
 use mago_ast::Trivia;
 
 pub mod format;
+pub mod ownership;
 
 bitflags! {
     #[derive(Debug, Clone, Copy)]
@@ -0,0 +1,118 @@
+use mago_ast::Trivia;
+use mago_span::Span;
+
+use crate::Formatter;
+use crate::comment::Comment;
+use crate::document::Document;
+
+/// The comments owned by a node, detached from the comment stream of the formatter.
+///
+/// Comments are otherwise located by their position while printing, in source order, which
+/// does not hold when nodes are printed in a different order than they appear in the source,
+/// e.g. when modifiers are reordered, or attributes are regrouped.
+///
+/// Printing a node along with its owned comments ensures its comments move with it.
+#[derive(Debug)]
+pub(crate) struct OwnedComments {
+    span: Span,
+    comments: Vec<Trivia>,
+}
+
+impl OwnedComments {
+    /// Determines whether any of the owned comments is a single-line comment preceding the node.
+    pub(crate) fn has_leading_line_comment(&self) -> bool {
+        self.comments
+            .iter()
+            .any(|comment| !comment.kind.is_block_comment() && comment.span.end.offset <= self.span.start.offset)
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// Detaches the comments surrounding the given sibling nodes from the comment stream,
+    /// and distributes them among the nodes, which must be given in source order.
+    ///
+    /// A comment is owned by:
+    ///
+    /// - the node that contains it,
+    /// - the preceding node, if it is on the same line as the end of that node, either directly after it
+    ///   or at the end of the line, and is either a block comment, or `trailing_line_comments` is set,
+    /// - the following node otherwise.
+    ///
+    /// `trailing_line_comments` should only be set when the nodes are printed on separate lines whenever
+    /// a line comment is present, as a trailing line comment would otherwise be moved past the following node.
+    ///
+    /// Comments following the last node on the same line, with nothing but whitespace, semicolons,
+    /// or commas in between, are owned by the last node.
+    pub(crate) fn detach_comments(&mut self, spans: &[Span], trailing_line_comments: bool) -> Vec<OwnedComments> {
+        let mut owned = spans.iter().map(|span| OwnedComments { span: *span, comments: vec![] }).collect::<Vec<_>>();
+        let Some(last) = spans.last() else {
+            return owned;
+        };
+
+        let mut last_end = last.end.offset;
+        while let Some(comment) = self.comments.peek() {
+            let span = comment.span;
+            let is_block = comment.kind.is_block_comment();
+
+            let owner = if span.end.offset <= last.end.offset {
+                let Some(index) = spans.iter().position(|node| span.end.offset <= node.end.offset) else {
+                    break;
+                };
+
+                let is_trailing = index > 0 && span.start.offset < spans[index].start.offset && {
+                    let before = &self.source_text[spans[index - 1].end.offset..span.start.offset];
+                    let after = &self.source_text[span.end.offset..spans[index].start.offset];
+
+                    !before.contains('\n')
+                        && (before.trim().is_empty() || after.trim_start_matches([' ', '\t']).starts_with(['\n', '\r']))
+                };
+
+                if is_trailing && (is_block || trailing_line_comments) { index - 1 } else { index }
+            } else if last_end <= span.start.offset
+                && self.source_text[last_end..span.start.offset].chars().all(|c| c == ' ' || c == ';' || c == ',')
+            {
+                last_end = span.end.offset;
+
+                spans.len() - 1
+            } else {
+                break;
+            };
+
+            if let Some(comment) = self.comments.next() {
+                owned[owner].comments.push(comment);
+            }
+        }
+
+        owned
+    }
+
+    /// Prints a node using the given owned comments in place of the comment stream.
+    ///
+    /// Owned comments that were not printed along with the node, e.g. comments following
+    /// a closing bracket that is not part of the node, are printed after it.
+    pub(crate) fn with_owned_comments(
+        &mut self,
+        owned: OwnedComments,
+        print: impl FnOnce(&mut Self) -> Document<'a>,
+    ) -> Document<'a> {
+        if owned.comments.is_empty() {
+            return print(self);
+        }
+
+        let comments = std::mem::replace(&mut self.comments, owned.comments.into_iter().peekable());
+        let document = print(self);
+        let remaining = std::mem::replace(&mut self.comments, comments).collect::<Vec<_>>();
+
+        if remaining.is_empty() {
+            return document;
+        }
+
+        let mut parts = vec![document];
+        let mut previous = None;
+        for comment in remaining {
+            previous = Some(self.print_trailing_comment(&mut parts, Comment::from_trivia(&comment), previous));
+        }
+
+        Document::Array(parts)
+    }
+}
@@ -13,6 +13,8 @@ pub enum Document<'a> {
     /// Increase the level of indentation.
     Indent(Vec<Document<'a>>),
     IndentIfBreak(IndentIfBreak<'a>),
+    /// Increase the indentation by a number of spaces, or to the current column, instead of a level of indentation.
+    Align(Align<'a>),
     /// Mark a group of items which the printer should try to fit on one line.
     /// This is the basic command to tell the printer when to break.
     /// Groups are usually nested, and the printer will try to fit everything on one line,
@@ -103,6 +105,27 @@ impl<'a> IndentIfBreak<'a> {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, PartialOrd, Ord)]
+pub struct Align<'a> {
+    pub alignment: Alignment,
+    pub contents: Vec<Document<'a>>,
+}
+
+/// The indentation added by an [`Align`] document, on top of the current indentation.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, PartialOrd, Ord)]
+pub enum Alignment {
+    /// Indent by the given number of spaces.
+    Spaces(usize),
+    /// Indent to the column at which the group with the given identifier started.
+    Column(GroupIdentifier),
+}
+
+impl<'a> Align<'a> {
+    pub fn new(alignment: Alignment, contents: Vec<Document<'a>>) -> Self {
+        Self { alignment, contents }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, PartialOrd, Ord)]
 pub struct Fill<'a> {
     pub parts: Vec<Document<'a>>,
@@ -185,9 +208,9 @@ impl<'a> Document<'a> {
 
         match self {
             Document::Array(docs) | Document::LineSuffix(docs) | Document::Indent(docs) => docs.iter().any(predicate),
-            Document::IndentIfBreak(IndentIfBreak { contents, .. }) | Document::Group(Group { contents, .. }) => {
-                contents.iter().any(predicate)
-            }
+            Document::IndentIfBreak(IndentIfBreak { contents, .. })
+            | Document::Align(Align { contents, .. })
+            | Document::Group(Group { contents, .. }) => contents.iter().any(predicate),
             Document::IfBreak(IfBreak { break_contents, flat_content, .. }) => {
                 predicate(break_contents) || predicate(flat_content)
             }
@@ -241,6 +264,14 @@ fn print_doc_to_debug(doc: &Document) -> String {
                 if options.is_empty() { String::new() } else { format!(", {{ {} }}", options.join(", ")) };
             format!("indentIfBreak({}{})", print_doc_to_debug(&Document::Array(contents.clone())), options_str)
         }
+        Document::Align(Align { alignment, contents }) => {
+            let alignment = match alignment {
+                Alignment::Spaces(width) => width.to_string(),
+                Alignment::Column(id) => format!("column of {:?}", id),
+            };
+
+            format!("align({}, {})", alignment, print_doc_to_debug(&Document::Array(contents.clone())))
+        }
         Document::Group(Group { contents, should_break, expanded_states, id }) => {
             let mut options = vec![];
             if *should_break {
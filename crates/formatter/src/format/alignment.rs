@@ -0,0 +1,208 @@
+use mago_ast::sequence::TokenSeparatedSequence;
+use mago_ast::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::Formatter;
+
+/// Aligns the `=` of consecutive simple assignment statements, and constant definitions, in the given statements,
+/// as per the `align_assignments` setting.
+pub(super) fn align_statements<'a>(f: &mut Formatter<'a>, statements: &'a Sequence<Statement>) {
+    if !f.settings.align_assignments {
+        return;
+    }
+
+    let candidates = statements
+        .iter()
+        .filter(|statement| !matches!(statement, Statement::Noop(_)))
+        .map(|statement| (statement.span(), get_statement_target(statement)))
+        .collect();
+
+    align(f, candidates);
+}
+
+/// Aligns the `=` of consecutive constant definitions in the given class-like members,
+/// as per the `align_assignments` setting.
+pub(super) fn align_class_like_members<'a>(f: &mut Formatter<'a>, members: &'a Sequence<ClassLikeMember>) {
+    if !f.settings.align_assignments {
+        return;
+    }
+
+    let candidates = members
+        .iter()
+        .map(|member| {
+            let target = match member {
+                ClassLikeMember::Constant(constant) => get_constant_target(&constant.attribute_lists, &constant.items),
+                _ => None,
+            };
+
+            (member.span(), target)
+        })
+        .collect();
+
+    align(f, candidates);
+}
+
+/// Aligns the `=>` of consecutive arms of a `match` expression, as per the `align_match_arrows` setting.
+pub(super) fn align_match_arms<'a>(f: &mut Formatter<'a>, arms: &'a TokenSeparatedSequence<MatchArm>) {
+    if !f.settings.align_match_arrows {
+        return;
+    }
+
+    let candidates = arms
+        .iter()
+        .map(|arm| {
+            let (span, target) = match arm {
+                MatchArm::Expression(arm) => {
+                    let target = get_match_arm_conditions_target(f, arm);
+
+                    (target.map_or(arm.span(), |target| target.join(arm.expression.span())), target)
+                }
+                MatchArm::Default(arm) => (arm.span(), Some(arm.default.span())),
+            };
+
+            // Arms that do not fit on a single line are not aligned, as they would push the others past the print width.
+            let text = &f.source_text[span.start.offset..span.end.offset];
+            if text.contains('\n') || text.chars().count() + f.settings.tab_width > f.settings.print_width {
+                return (span, None);
+            }
+
+            (span, target)
+        })
+        .collect();
+
+    align(f, candidates);
+}
+
+/// Returns the padding to add after the left-hand side of the given assignment, to align its operator.
+pub(super) fn take_alignment_padding(f: &mut Formatter<'_>, lhs: Span) -> usize {
+    f.alignment_paddings.remove(&lhs.start.offset).unwrap_or(0)
+}
+
+/// Returns the left-hand side of the assignment or constant definition in the given statement, if it may be aligned.
+fn get_statement_target(statement: &Statement) -> Option<Span> {
+    match statement {
+        Statement::Expression(ExpressionStatement { expression, terminator: Terminator::Semicolon(_) }) => {
+            match expression.as_ref() {
+                Expression::Assignment(Assignment { lhs, operator: AssignmentOperator::Assign(_), .. }) => {
+                    Some(lhs.span())
+                }
+                _ => None,
+            }
+        }
+        Statement::Constant(constant) => get_constant_target(&constant.attribute_lists, &constant.items),
+        _ => None,
+    }
+}
+
+/// Returns the span of the conditions of the given match arm, if they are printed on a single line.
+fn get_match_arm_conditions_target(f: &Formatter<'_>, arm: &MatchExpressionArm) -> Option<Span> {
+    if f.settings.break_match_arm_conditions && arm.conditions.len() > 1 {
+        return None;
+    }
+
+    // The conditions are printed separated by a comma and a single space.
+    let is_laid_out = arm
+        .conditions
+        .iter()
+        .zip(arm.conditions.iter().skip(1))
+        .all(|(previous, next)| &f.source_text[previous.span().end.offset..next.span().start.offset] == ", ");
+
+    if !is_laid_out {
+        return None;
+    }
+
+    Some(arm.conditions.first_span()?.join(arm.conditions.last_span()?))
+}
+
+/// Returns the name of the given constant definition, if it defines a single constant without attributes.
+fn get_constant_target<T: HasConstantName>(
+    attribute_lists: &Sequence<AttributeList>,
+    items: &TokenSeparatedSequence<T>,
+) -> Option<Span> {
+    if !attribute_lists.is_empty() || items.len() != 1 {
+        return None;
+    }
+
+    items.first().map(|item| item.name_span())
+}
+
+/// Aligns the runs of consecutive candidates, each given as the span of the statement or member,
+/// and the left-hand side of its operator, if it may be aligned.
+///
+/// A run is broken by a blank line, a comment, or a statement that may not be aligned.
+fn align(f: &mut Formatter<'_>, candidates: Vec<(Span, Option<Span>)>) {
+    let mut run = vec![];
+    let mut previous_end = None;
+    for (span, target) in candidates {
+        let is_consecutive = previous_end.is_some_and(|end| {
+            !f.is_next_line_empty_after_index(end) && !has_comment_between(f, end, span.start.offset)
+        });
+
+        if !is_consecutive {
+            flush(f, &mut run);
+        }
+
+        match target.and_then(|target| get_width(f, span, target)) {
+            Some(width) => {
+                run.push((target.map(|target| target.start.offset).unwrap_or_default(), width));
+                previous_end = Some(span.end.offset);
+            }
+            None => {
+                flush(f, &mut run);
+                previous_end = None;
+            }
+        }
+    }
+
+    flush(f, &mut run);
+}
+
+/// Records the padding of every candidate in the given run, if it contains more than one.
+fn flush(f: &mut Formatter<'_>, run: &mut Vec<(usize, usize)>) {
+    if run.len() > 1 {
+        let width = run.iter().map(|(_, width)| *width).max().unwrap_or_default();
+        for (offset, candidate_width) in run.iter() {
+            f.alignment_paddings.insert(*offset, width - candidate_width);
+        }
+    }
+
+    run.clear();
+}
+
+/// Returns the width of the given statement up to the end of its left-hand side, as it will be printed.
+///
+/// The width is only known if the source is already laid out on a single line, with single spaces
+/// and without comments, as the formatter would print it.
+fn get_width(f: &Formatter<'_>, span: Span, target: Span) -> Option<usize> {
+    let text = &f.source_text[span.start.offset..target.end.offset];
+    if text.contains(['\n', '\r', '\t'])
+        || text.contains("  ")
+        || has_comment_between(f, span.start.offset, target.end.offset)
+    {
+        return None;
+    }
+
+    Some(text.chars().count())
+}
+
+/// Determines whether a comment is located between the given offsets.
+fn has_comment_between(f: &Formatter<'_>, start: usize, end: usize) -> bool {
+    f.comments.clone().any(|comment| comment.span.start.offset >= start && comment.span.end.offset <= end)
+}
+
+trait HasConstantName: HasSpan {
+    fn name_span(&self) -> Span;
+}
+
+impl HasConstantName for ConstantItem {
+    fn name_span(&self) -> Span {
+        self.name.span
+    }
+}
+
+impl HasConstantName for ClassLikeConstantItem {
+    fn name_span(&self) -> Span {
+        self.name.span
+    }
+}
@@ -100,13 +100,19 @@ pub(super) fn print_array_like<'a>(f: &mut Formatter<'a>, array_like: ArrayLike<
         ]));
     }
 
+    let exceeds_threshold = !matches!(array_like, ArrayLike::List(_))
+        && !is_destructuring_target(f)
+        && f.settings.array_multiline_threshold.is_some_and(|threshold| array_like.len() > threshold);
+
     let mut parts = vec![left_delimiter];
 
-    if let Some(element) = inline_single_element(f, &array_like) {
-        parts.push(element);
-        parts.push(right_delimiter);
+    if !exceeds_threshold {
+        if let Some(element) = inline_single_element(f, &array_like) {
+            parts.push(element);
+            parts.push(right_delimiter);
 
-        return Document::Group(Group::new(parts));
+            return Document::Group(Group::new(parts));
+        }
     }
 
     parts.push(Document::Indent({
@@ -139,15 +145,39 @@ pub(super) fn print_array_like<'a>(f: &mut Formatter<'a>, array_like: ArrayLike<
     parts.push(right_delimiter);
 
     // preserve new lines between the opening delimiter ( e.g. `[` or `(` ) and the first element
-    let should_break = misc::has_new_line_in_range(
-        f.source_text,
-        array_like.span().start.offset,
-        array_like.elements()[0].span().start.offset,
-    );
+    let should_break = exceeds_threshold
+        || misc::has_new_line_in_range(
+            f.source_text,
+            array_like.span().start.offset,
+            array_like.elements()[0].span().start.offset,
+        );
 
     Document::Group(Group::new(parts).with_break(should_break))
 }
 
+/// Determines whether the array being printed is the target of a destructuring assignment,
+/// e.g. `[$a, $b] = $c;` or `foreach ($items as [$a, $b])`, including nested arrays.
+fn is_destructuring_target(f: &Formatter<'_>) -> bool {
+    let mut outermost_array = None;
+    for node in f.stack.iter().rev() {
+        match node {
+            Node::Array(array) => outermost_array = Some(array.span()),
+            Node::LegacyArray(array) => outermost_array = Some(array.span()),
+            Node::Expression(_)
+            | Node::ArrayElement(_)
+            | Node::KeyValueArrayElement(_)
+            | Node::ValueArrayElement(_) => {}
+            Node::Assignment(assignment) => {
+                return outermost_array.is_some_and(|span| assignment.lhs.span() == span);
+            }
+            Node::ForeachValueTarget(_) | Node::ForeachKeyValueTarget(_) => return true,
+            _ => return false,
+        }
+    }
+
+    false
+}
+
 fn inline_single_element<'a>(f: &mut Formatter<'a>, array_like: &ArrayLike<'a>) -> Option<Document<'a>> {
     if array_like.len() != 1 {
         return None;
@@ -8,6 +8,8 @@ use crate::document::Group;
 use crate::document::IndentIfBreak;
 use crate::document::Line;
 use crate::format::Format;
+use crate::format::alignment::take_alignment_padding;
+use crate::format::binaryish::is_conditional_chain;
 use crate::format::binaryish::should_inline_logical_or_coalesce_expression;
 
 /// Represents nodes in the Abstract Syntax Tree (AST) that involve assignment-like operations.
@@ -44,6 +46,19 @@ pub(super) enum AssignmentLikeNode<'a> {
     KeyValueArrayElement(&'a KeyValueArrayElement),
 }
 
+/// Returns the padding to add after the left-hand side of the given assignment-like node,
+/// to align its operator with the surrounding ones, as per the `align_assignments` setting.
+fn get_alignment_padding(f: &mut Formatter<'_>, assignment_node: &AssignmentLikeNode<'_>) -> usize {
+    let lhs = match assignment_node {
+        AssignmentLikeNode::AssignmentOperation(assignment) => assignment.lhs.span(),
+        AssignmentLikeNode::ClassLikeConstantItem(item) => item.name.span,
+        AssignmentLikeNode::ConstantItem(item) => item.name.span,
+        _ => return 0,
+    };
+
+    take_alignment_padding(f, lhs)
+}
+
 #[derive(Debug)]
 enum Layout {
     Chain,
@@ -62,56 +77,59 @@ pub(super) fn print_assignment<'a>(
     operator: Document<'a>,
     rhs_expression: &'a Expression,
 ) -> Document<'a> {
+    let lhs = match get_alignment_padding(f, &assignment_node) {
+        0 => lhs,
+        padding => Document::Array(vec![lhs, Document::String(f.as_str(" ".repeat(padding)))]),
+    };
+
+    let separated = match assignment_node {
+        AssignmentLikeNode::AssignmentOperation(Assignment { operator: AssignmentOperator::Concat(_), .. }) => {
+            f.settings.space_concatenation_assignment
+        }
+        _ => true,
+    };
+
+    let space = || Document::String(if separated { " " } else { "" });
+    let line = || Document::Line(if separated { Line::default() } else { Line::softline() });
+
     let layout = choose_layout(f, &lhs, &assignment_node, rhs_expression);
     let rhs = rhs_expression.format(f);
 
     match layout {
-        Layout::Chain => Document::Array(vec![
-            Document::Group(Group::new(vec![lhs])),
-            Document::space(),
-            operator,
-            Document::Line(Line::default()),
-            rhs,
-        ]),
+        Layout::Chain => Document::Array(vec![Document::Group(Group::new(vec![lhs])), space(), operator, line(), rhs]),
         Layout::ChainTailArrowChain => {
-            Document::Array(vec![Document::Group(Group::new(vec![lhs])), Document::space(), operator, rhs])
+            Document::Array(vec![Document::Group(Group::new(vec![lhs])), space(), operator, rhs])
         }
         Layout::ChainTail => Document::Group(Group::new(vec![
             lhs,
-            Document::space(),
+            space(),
             operator,
             Document::Indent(vec![Document::Line(Line::hardline()), rhs]),
         ])),
         Layout::BreakAfterOperator => Document::Group(Group::new(vec![
             Document::Group(Group::new(vec![lhs])),
-            Document::space(),
+            space(),
             operator,
-            Document::Group(Group::new(vec![Document::Indent(vec![Document::Line(Line::default()), rhs])])),
+            Document::Group(Group::new(vec![Document::Indent(vec![line(), rhs])])),
         ])),
         Layout::NeverBreakAfterOperator => Document::Group(Group::new(vec![
             Document::Group(Group::new(vec![lhs])),
-            Document::space(),
+            space(),
             operator,
-            Document::space(),
-            Document::Group(Group::new(vec![rhs])),
-        ])),
-        Layout::BreakLhs => Document::Group(Group::new(vec![
-            lhs,
-            Document::space(),
-            operator,
-            Document::space(),
+            space(),
             Document::Group(Group::new(vec![rhs])),
         ])),
+        Layout::BreakLhs => {
+            Document::Group(Group::new(vec![lhs, space(), operator, space(), Document::Group(Group::new(vec![rhs]))]))
+        }
         Layout::Fluid => {
             let assignment_id = f.next_id();
 
             Document::Group(Group::new(vec![
                 lhs,
-                Document::space(),
+                space(),
                 operator,
-                Document::Group(
-                    Group::new(vec![Document::Indent(vec![Document::Line(Line::default())])]).with_id(assignment_id),
-                ),
+                Document::Group(Group::new(vec![Document::Indent(vec![line()])]).with_id(assignment_id)),
                 Document::IndentIfBreak(IndentIfBreak::new(vec![rhs]).with_id(assignment_id)),
             ]))
         }
@@ -261,13 +279,16 @@ fn should_break_after_operator<'a>(f: &Formatter<'a>, rhs_expression: &'a Expres
         return should_break_after_operator(f, &parenthesized.expression, has_short_key);
     }
 
-    if rhs_expression.is_binary() && !should_inline_logical_or_coalesce_expression(rhs_expression) {
+    if rhs_expression.is_binary()
+        && !is_conditional_chain(f, rhs_expression)
+        && !should_inline_logical_or_coalesce_expression(rhs_expression)
+    {
         return true;
     }
 
     match rhs_expression {
         Expression::Binary(operation) => {
-            if let BinaryOperator::Elvis(_) = operation.operator {
+            if let BinaryOperator::Elvis(_) | BinaryOperator::NullCoalesce(_) = operation.operator {
                 let mut condition = operation.lhs.as_ref();
                 while let Expression::Parenthesized(parenthesized) = condition {
                     condition = &parenthesized.expression;
@@ -284,10 +305,8 @@ fn should_break_after_operator<'a>(f: &Formatter<'a>, rhs_expression: &'a Expres
 
             return condition.is_binary() && !should_inline_logical_or_coalesce_expression(condition);
         }
-        Expression::AnonymousClass(anonymous_class) => {
-            if !anonymous_class.attribute_lists.is_empty() {
-                return true;
-            }
+        Expression::AnonymousClass(anonymous_class) if !anonymous_class.attribute_lists.is_empty() => {
+            return true;
         }
         _ => {}
     }
@@ -24,6 +24,25 @@ pub(super) fn print_binaryish_expression<'a>(
 
     let is_inside_parenthesis =
         matches!(parent, Node::If(_) | Node::While(_) | Node::Switch(_) | Node::DoWhile(_) | Node::Match(_));
+
+    if let Some(operands) = get_conditional_chain_operands(f, left, operator, right) {
+        // Inside control structure parentheses, the chain is broken along with them, without indentation.
+        let is_condition = matches!(
+            grandparent,
+            Some(Node::If(_) | Node::While(_) | Node::Switch(_) | Node::DoWhile(_) | Node::Match(_))
+        );
+
+        let chain = print_conditional_chain(f, operator, operands, !is_condition);
+        if !is_condition && is_at_callee(f) {
+            return Document::Group(Group::new(vec![
+                Document::Indent(vec![Document::Line(Line::softline()), chain]),
+                Document::Line(Line::softline()),
+            ]));
+        }
+
+        return chain;
+    }
+
     let parts = print_binaryish_expressions(f, left, operator, right, is_inside_parenthesis, false);
 
     //   if (
@@ -174,6 +193,96 @@ pub(super) fn print_binaryish_expressions<'a>(
     parts
 }
 
+/// Determines whether the given expression is a chain of null-coalescing `??`, or elvis `?:` operations
+/// that is printed with the operators at the start of each line.
+pub(super) fn is_conditional_chain<'a>(f: &Formatter<'a>, expression: &'a Expression) -> bool {
+    match expression {
+        Expression::Binary(binary) => {
+            get_conditional_chain_operands(f, &binary.lhs, &binary.operator, &binary.rhs).is_some()
+        }
+        _ => false,
+    }
+}
+
+/// Prints a chain of null-coalescing `??`, or elvis `?:` operations, placing the operators
+/// at the start of each line when the chain breaks, as is done for ternaries:
+///
+/// ```php
+/// $value = $options['value']
+///     ?? $defaults['value']
+///     ?? $this->getFallbackValue();
+/// ```
+fn print_conditional_chain<'a>(
+    f: &mut Formatter<'a>,
+    operator: &'a BinaryOperator,
+    operands: Vec<&'a Expression>,
+    should_indent: bool,
+) -> Document<'a> {
+    let operator = operator.as_str(f.interner);
+
+    let mut operands = operands.into_iter();
+    let mut parts = vec![];
+    if let Some(first) = operands.next() {
+        parts.push(first.format(f));
+    }
+
+    let mut rest = vec![];
+    for operand in operands {
+        rest.push(Document::Line(Line::default()));
+        rest.push(Document::String(operator));
+        rest.push(Document::space());
+        rest.push(operand.format(f));
+    }
+
+    if !should_indent {
+        parts.extend(rest);
+
+        return Document::Array(parts);
+    }
+
+    parts.push(Document::Indent(rest));
+
+    Document::Group(Group::new(parts))
+}
+
+/// Returns the operands of the chain of null-coalescing `??`, or elvis `?:` operations formed
+/// by the given binary operation, if it may be printed as a chain.
+///
+/// Chains containing comments, or ending with an inlined array, are printed as regular binary operations.
+fn get_conditional_chain_operands<'a>(
+    f: &Formatter<'a>,
+    left: &'a Expression,
+    operator: &'a BinaryOperator,
+    right: &'a Expression,
+) -> Option<Vec<&'a Expression>> {
+    fn collect<'a>(expression: &'a Expression, operator: &BinaryOperator, operands: &mut Vec<&'a Expression>) {
+        match expression {
+            Expression::Binary(binary) if binary.operator.is_same_as(operator) => {
+                collect(&binary.lhs, operator, operands);
+                collect(&binary.rhs, operator, operands);
+            }
+            _ => operands.push(expression),
+        }
+    }
+
+    if !matches!(operator, BinaryOperator::NullCoalesce(_) | BinaryOperator::Elvis(_))
+        || should_inline_logical_or_coalesce_rhs(right, operator)
+        || f.has_comment(left.span().join(right.span()), CommentFlags::Dangling)
+    {
+        return None;
+    }
+
+    let mut operands = vec![];
+    collect(left, operator, &mut operands);
+    collect(right, operator, &mut operands);
+
+    if operands.last().is_some_and(|last| should_inline_logical_or_coalesce_rhs(last, operator)) {
+        return None;
+    }
+
+    Some(operands)
+}
+
 pub(super) fn should_inline_logical_or_coalesce_expression(expression: &Expression) -> bool {
     if let Expression::Parenthesized(parenthesized) = expression {
         return should_inline_logical_or_coalesce_expression(&parenthesized.expression);
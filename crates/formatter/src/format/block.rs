@@ -3,6 +3,7 @@ use mago_span::HasSpan;
 use mago_span::Span;
 
 use crate::Formatter;
+use crate::comment::CommentFlags;
 use crate::document::Document;
 use crate::document::Group;
 use crate::document::Line;
@@ -57,11 +58,12 @@ pub(super) fn print_block<'a>(
     let mut contents = vec![];
     contents.push(Document::String("{"));
     let has_body = stmts.iter().any(|stmt| !matches!(stmt, Statement::Noop(_)));
+    let inline = matches!(f.parent_node(), Node::Closure(closure) if should_inline_closure_body(f, &closure.body));
     let should_break = if has_body {
         let mut statements = statement::print_statement_sequence(f, stmts);
-        statements.insert(0, Document::Line(Line::hardline()));
+        statements.insert(0, Document::Line(if inline { Line::default() } else { Line::hardline() }));
         contents.push(Document::Indent(statements));
-        true
+        !inline
     } else {
         let parent = f.parent_node();
         // in case the block is empty, we still want to add a new line
@@ -96,7 +98,7 @@ pub(super) fn print_block<'a>(
     if let Some(comments) = f.print_dangling_comments(left_brace.join(*right_brace), true) {
         contents.push(comments);
     } else {
-        contents.push(Document::Line(Line::softline()));
+        contents.push(Document::Line(if inline { Line::default() } else { Line::softline() }));
     }
 
     contents.push(Document::String("}"));
@@ -104,6 +106,23 @@ pub(super) fn print_block<'a>(
     Document::Group(Group::new(contents).with_break(should_break))
 }
 
+/// Determines whether the body of a closure may be kept on one line, as per the `inline_simple_closures` setting.
+///
+/// Only bodies consisting of a single `return` or expression statement, without comments, are kept on one line.
+pub(super) fn should_inline_closure_body(f: &Formatter<'_>, body: &Block) -> bool {
+    if !f.settings.inline_simple_closures {
+        return false;
+    }
+
+    let mut statements = body.statements.iter().filter(|statement| !matches!(statement, Statement::Noop(_)));
+    if !matches!((statements.next(), statements.next()), (Some(Statement::Return(_) | Statement::Expression(_)), None))
+    {
+        return false;
+    }
+
+    !f.has_comment(body.span(), CommentFlags::Dangling)
+}
+
 pub(super) fn print_block_body<'a>(f: &mut Formatter<'a>, stmts: &'a Sequence<Statement>) -> Option<Document<'a>> {
     let has_body = stmts.iter().any(|stmt| !matches!(stmt, Statement::Noop(_)));
 
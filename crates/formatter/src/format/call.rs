@@ -1,9 +1,13 @@
 use mago_ast::*;
 
 use crate::Formatter;
+use crate::document::Align;
+use crate::document::Alignment;
 use crate::document::Document;
 use crate::document::Group;
 use crate::format::Format;
+use crate::settings::ChainIndent;
+use crate::settings::ChainIndentStyle;
 
 use super::Line;
 use super::call_arguments::print_call_arguments;
@@ -53,6 +57,18 @@ pub(super) fn print_method_call_chain<'a>(method_chain: &MethodChain<'a>, f: &mu
         vec![base_document]
     };
 
+    // With `chain_indent = "align"`, the calls are aligned under the column the chain starts at, after the base.
+    let alignment = match f.settings.chain_indent {
+        ChainIndent::Style(ChainIndentStyle::Indent) => None,
+        ChainIndent::Spaces(width) => Some(Alignment::Spaces(width)),
+        ChainIndent::Style(ChainIndentStyle::Align) => {
+            let id = f.next_id();
+            parts.push(Document::Group(Group::new(vec![]).with_id(id)));
+
+            Some(Alignment::Column(id))
+        }
+    };
+
     let mut calls_iter = method_chain.calls.iter();
 
     // Handle the first method call
@@ -83,7 +99,10 @@ pub(super) fn print_method_call_chain<'a>(method_chain: &MethodChain<'a>, f: &mu
 
         contents.push(Document::Group(Group::new(vec![print_call_arguments(f, chain_link)])));
 
-        parts.push(Document::Indent(contents));
+        parts.push(match alignment {
+            Some(alignment) => Document::Align(Align::new(alignment, contents)),
+            None => Document::Indent(contents),
+        });
     }
 
     parts.push(Document::BreakParent);
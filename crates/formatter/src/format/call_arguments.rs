@@ -5,6 +5,7 @@ use crate::Format;
 use crate::Formatter;
 use crate::comment::CommentFlags;
 use crate::document::*;
+use crate::format::block::should_inline_closure_body;
 use crate::format::call_node::CallLikeNode;
 use crate::format::misc::should_hug_expression;
 use crate::utils::will_break;
@@ -119,6 +120,37 @@ pub(super) fn print_argument_list<'a>(f: &mut Formatter<'a>, argument_list: &'a
                 )),
             ]);
         }
+
+        // An array, or a closure kept on one line, is only expanded if the arguments do not fit on one line otherwise.
+        let should_hug_first_argument = match argument_list.arguments.first().map(|a| a.value()) {
+            Some(Expression::Closure(closure)) => should_inline_closure_body(f, &closure.body),
+            Some(Expression::Array(_) | Expression::LegacyArray(_)) => true,
+            _ => false,
+        };
+
+        if should_hug_first_argument {
+            let last_doc = get_printed_arguments(f, 1).pop().unwrap();
+
+            return Document::Group(Group::conditional(
+                vec![
+                    Document::String("("),
+                    first_doc.clone(),
+                    Document::String(", "),
+                    last_doc.clone(),
+                    Document::String(")"),
+                ],
+                vec![
+                    Document::Array(vec![
+                        Document::String("("),
+                        Document::Group(Group::new(vec![first_doc]).with_break(true)),
+                        Document::String(", "),
+                        last_doc,
+                        Document::String(")"),
+                    ]),
+                    all_arguments_broken_out(f),
+                ],
+            ));
+        }
     }
 
     if should_expand_last_arg(f, argument_list) {
@@ -226,6 +258,8 @@ fn should_expand_first_arg<'a>(f: &Formatter<'a>, argument_list: &'a ArgumentLis
     match first_argument.value() {
         Expression::Closure(c) if c.use_clause.is_none() => {}
         Expression::Match(_) => {}
+        Expression::Array(Array { elements, .. }) | Expression::LegacyArray(LegacyArray { elements, .. })
+            if !elements.is_empty() => {}
         _ => return false,
     };
 
@@ -384,17 +418,13 @@ fn is_simple_call_argument<'a>(node: &'a Expression, depth: usize) -> bool {
         Expression::ArrayAccess(array_access) => {
             is_simple_call_argument(&array_access.array, depth) && is_simple_call_argument(&array_access.index, depth)
         }
-        Expression::Instantiation(instantiation) => {
-            if is_simple_call_argument(&instantiation.class, depth) {
-                match &instantiation.arguments {
-                    Some(argument_list) => {
-                        argument_list.arguments.len() <= depth
-                            && argument_list.arguments.iter().map(|a| a.value()).all(is_child_simple)
-                    }
-                    None => true,
+        Expression::Instantiation(instantiation) if is_simple_call_argument(&instantiation.class, depth) => {
+            match &instantiation.arguments {
+                Some(argument_list) => {
+                    argument_list.arguments.len() <= depth
+                        && argument_list.arguments.iter().map(|a| a.value()).all(is_child_simple)
                 }
-            } else {
-                false
+                None => true,
             }
         }
         _ => false,
@@ -407,6 +437,10 @@ fn could_expand_argument_value(argument_value: &Expression, arrow_chain_recursio
         Expression::LegacyArray(expr) => !expr.elements.is_empty(),
         Expression::List(expr) => !expr.elements.is_empty(),
         Expression::Closure(_) => true,
+        // An anonymous class is only expanded if its head is unlikely to break, to avoid breaking its arguments instead.
+        Expression::AnonymousClass(class) => class.arguments.as_ref().is_none_or(|argument_list| {
+            argument_list.arguments.iter().all(|argument| is_simple_call_argument(argument.value(), 0))
+        }),
         Expression::Binary(operation) => could_expand_argument_value(&operation.lhs, arrow_chain_recursion),
         Expression::ArrowFunction(arrow_function) => match arrow_function.expression.as_ref() {
             Expression::Array(_) | Expression::List(_) | Expression::LegacyArray(_) => {
@@ -5,6 +5,7 @@ use crate::Formatter;
 use crate::document::Document;
 use crate::settings::BraceStyle;
 
+use super::alignment::align_class_like_members;
 use super::block::print_block_of_nodes;
 
 pub fn print_class_like_body<'a>(
@@ -18,5 +19,7 @@ pub fn print_class_like_body<'a>(
         BraceStyle::NextLine => false,
     };
 
+    align_class_like_members(f, class_like_members);
+
     print_block_of_nodes(f, left_brace, class_like_members, right_brace, inline_empty)
 }
@@ -1,7 +1,9 @@
 use mago_ast::*;
 use mago_span::HasSpan;
+use mago_span::Span;
 
 use crate::Formatter;
+use crate::comment::CommentFlags;
 use crate::document::Document;
 use crate::document::Group;
 use crate::document::Line;
@@ -58,6 +60,22 @@ impl<'a> Format<'a> for IfStatementBody {
 impl<'a> Format<'a> for IfStatementBodyElseClause {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, IfStatementBodyElseClause, {
+            if let Statement::If(r#if @ If { body: IfBody::Statement(body), .. }) = self.statement.as_ref() {
+                if f.settings.else_if_style == ElseIfStyle::ElseIf
+                    && !f.has_comment(Span::between(self.r#else.span(), r#if.r#if.span()), CommentFlags::Dangling)
+                {
+                    return Document::Group(Group::new(vec![
+                        Document::String(match f.settings.keyword_case {
+                            CasingStyle::Lowercase => "elseif",
+                            CasingStyle::Uppercase => "ELSEIF",
+                        }),
+                        Document::space(),
+                        misc::print_condition(f, &r#if.condition),
+                        body.format(f),
+                    ]));
+                }
+            }
+
             Document::Group(Group::new(vec![self.r#else.format(f), misc::print_clause(f, &self.statement, false)]))
         })
     }
@@ -66,8 +84,16 @@ impl<'a> Format<'a> for IfStatementBodyElseClause {
 impl<'a> Format<'a> for IfStatementBodyElseIfClause {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, IfStatementBodyElseIfClause, {
+            let elseif = match f.settings.else_if_style {
+                ElseIfStyle::ElseSpaceIf => Document::String(match f.settings.keyword_case {
+                    CasingStyle::Lowercase => "else if",
+                    CasingStyle::Uppercase => "ELSE IF",
+                }),
+                _ => self.elseif.format(f),
+            };
+
             Document::Group(Group::new(vec![
-                self.elseif.format(f),
+                elseif,
                 Document::space(),
                 misc::print_condition(f, &self.condition),
                 misc::print_clause(f, &self.statement, false),
@@ -1,3 +1,4 @@
+use mago_ast::stack::ensure_sufficient_stack;
 use mago_ast::*;
 use mago_span::HasSpan;
 
@@ -8,6 +9,8 @@ use crate::format::Group;
 use crate::format::IfBreak;
 use crate::format::IndentIfBreak;
 use crate::format::Separator;
+use crate::format::alignment::align_match_arms;
+use crate::format::alignment::take_alignment_padding;
 use crate::format::array::ArrayLike;
 use crate::format::array::print_array_like;
 use crate::format::assignment::AssignmentLikeNode;
@@ -31,58 +34,60 @@ use crate::format::Format;
 
 impl<'a> Format<'a> for Expression {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
-        if let Expression::Parenthesized(parenthesized) = self {
-            return parenthesized.expression.format(f);
-        }
+        ensure_sufficient_stack(|| {
+            if let Expression::Parenthesized(parenthesized) = self {
+                return parenthesized.expression.format(f);
+            }
 
-        wrap!(f, self, Expression, {
-            match self {
-                Expression::Binary(op) => op.format(f),
-                Expression::UnaryPrefix(op) => op.format(f),
-                Expression::UnaryPostfix(op) => op.format(f),
-                Expression::Literal(literal) => literal.format(f),
-                Expression::CompositeString(c) => c.format(f),
-                Expression::Assignment(op) => op.format(f),
-                Expression::Conditional(op) => op.format(f),
-                Expression::Array(array) => array.format(f),
-                Expression::LegacyArray(legacy_array) => legacy_array.format(f),
-                Expression::List(list) => list.format(f),
-                Expression::ArrayAccess(a) => a.format(f),
-                Expression::ArrayAppend(a) => a.format(f),
-                Expression::AnonymousClass(c) => c.format(f),
-                Expression::Closure(c) => c.format(f),
-                Expression::ArrowFunction(a) => a.format(f),
-                Expression::Variable(v) => v.format(f),
-                Expression::Identifier(i) => i.format(f),
-                Expression::Match(m) => m.format(f),
-                Expression::Yield(y) => y.format(f),
-                Expression::Construct(construct) => construct.format(f),
-                Expression::Throw(t) => t.format(f),
-                Expression::Clone(c) => c.format(f),
-                Expression::Call(c) => {
-                    if let Some(method_chain) = collect_method_call_chain(self) {
-                        let chain_length = method_chain.calls.len();
-                        if chain_length >= f.settings.method_chain_break_threshold {
-                            // Chain is longer than threshold; format with line breaks
-                            print_method_call_chain(&method_chain, f)
+            wrap!(f, self, Expression, {
+                match self {
+                    Expression::Binary(op) => op.format(f),
+                    Expression::UnaryPrefix(op) => op.format(f),
+                    Expression::UnaryPostfix(op) => op.format(f),
+                    Expression::Literal(literal) => literal.format(f),
+                    Expression::CompositeString(c) => c.format(f),
+                    Expression::Assignment(op) => op.format(f),
+                    Expression::Conditional(op) => op.format(f),
+                    Expression::Array(array) => array.format(f),
+                    Expression::LegacyArray(legacy_array) => legacy_array.format(f),
+                    Expression::List(list) => list.format(f),
+                    Expression::ArrayAccess(a) => a.format(f),
+                    Expression::ArrayAppend(a) => a.format(f),
+                    Expression::AnonymousClass(c) => c.format(f),
+                    Expression::Closure(c) => c.format(f),
+                    Expression::ArrowFunction(a) => a.format(f),
+                    Expression::Variable(v) => v.format(f),
+                    Expression::Identifier(i) => i.format(f),
+                    Expression::Match(m) => m.format(f),
+                    Expression::Yield(y) => y.format(f),
+                    Expression::Construct(construct) => construct.format(f),
+                    Expression::Throw(t) => t.format(f),
+                    Expression::Clone(c) => c.format(f),
+                    Expression::Call(c) => {
+                        if let Some(method_chain) = collect_method_call_chain(self) {
+                            let chain_length = method_chain.calls.len();
+                            if chain_length >= f.settings.method_chain_break_threshold {
+                                // Chain is longer than threshold; format with line breaks
+                                print_method_call_chain(&method_chain, f)
+                            } else {
+                                // Regular formatting
+                                c.format(f)
+                            }
                         } else {
-                            // Regular formatting
                             c.format(f)
                         }
-                    } else {
-                        c.format(f)
                     }
+                    Expression::Access(a) => a.format(f),
+                    Expression::ConstantAccess(a) => a.format(f),
+                    Expression::ClosureCreation(c) => c.format(f),
+                    Expression::Parent(k) => k.format(f),
+                    Expression::Static(k) => k.format(f),
+                    Expression::Self_(k) => k.format(f),
+                    Expression::Instantiation(i) => i.format(f),
+                    Expression::MagicConstant(c) => c.format(f),
+                    _ => unreachable!(),
                 }
-                Expression::Access(a) => a.format(f),
-                Expression::ConstantAccess(a) => a.format(f),
-                Expression::ClosureCreation(c) => c.format(f),
-                Expression::Parent(k) => k.format(f),
-                Expression::Static(k) => k.format(f),
-                Expression::Self_(k) => k.format(f),
-                Expression::Instantiation(i) => i.format(f),
-                Expression::MagicConstant(c) => c.format(f),
-                _ => unreachable!(),
-            }
+            })
         })
     }
 }
@@ -96,7 +101,7 @@ impl<'a> Format<'a> for Binary {
 impl<'a> Format<'a> for UnaryPrefix {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, UnaryPrefix, {
-            if self.operator.is_cast() {
+            if self.operator.is_cast() && f.settings.space_after_cast {
                 Document::Group(Group::new(vec![self.operator.format(f), Document::space(), self.operand.format(f)]))
             } else {
                 Document::Group(Group::new(vec![self.operator.format(f), self.operand.format(f)]))
@@ -108,7 +113,19 @@ impl<'a> Format<'a> for UnaryPrefix {
 impl<'a> Format<'a> for UnaryPrefixOperator {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, UnaryPrefixOperator, {
-            let value = self.as_str(f.interner);
+            let value = match self {
+                _ if !f.settings.canonical_casts => self.as_str(f.interner),
+                UnaryPrefixOperator::ArrayCast(..) => "(array)",
+                UnaryPrefixOperator::BoolCast(..) | UnaryPrefixOperator::BooleanCast(..) => "(bool)",
+                UnaryPrefixOperator::DoubleCast(..)
+                | UnaryPrefixOperator::RealCast(..)
+                | UnaryPrefixOperator::FloatCast(..) => "(float)",
+                UnaryPrefixOperator::IntCast(..) | UnaryPrefixOperator::IntegerCast(..) => "(int)",
+                UnaryPrefixOperator::ObjectCast(..) => "(object)",
+                UnaryPrefixOperator::UnsetCast(..) => "(unset)",
+                UnaryPrefixOperator::StringCast(..) | UnaryPrefixOperator::BinaryCast(..) => "(string)",
+                _ => self.as_str(f.interner),
+            };
 
             Document::String(match f.settings.keyword_case {
                 CasingStyle::Lowercase => f.as_str(value.to_lowercase()),
@@ -139,9 +156,14 @@ impl<'a> Format<'a> for Literal {
                 Literal::String(literal) => literal.format(f),
                 Literal::Integer(literal) => literal.format(f),
                 Literal::Float(literal) => literal.format(f),
-                Literal::True(keyword) => keyword.format(f),
-                Literal::False(keyword) => keyword.format(f),
-                Literal::Null(keyword) => keyword.format(f),
+                Literal::True(keyword) | Literal::False(keyword) | Literal::Null(keyword) => {
+                    let value = f.lookup(&keyword.value);
+
+                    Document::String(match f.settings.constant_case.unwrap_or(f.settings.keyword_case) {
+                        CasingStyle::Lowercase => f.as_str(value.to_ascii_lowercase()),
+                        CasingStyle::Uppercase => f.as_str(value.to_ascii_uppercase()),
+                    })
+                }
             }
         })
     }
@@ -762,10 +784,15 @@ impl<'a> Format<'a> for MatchArm {
 impl<'a> Format<'a> for MatchDefaultArm {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, MatchDefaultArm, {
+            let padding = take_alignment_padding(f, self.default.span());
+
             Document::Group(Group::new(vec![
                 self.default.format(f),
                 Document::IndentIfBreak(IndentIfBreak::new(vec![
-                    Document::IfBreak(IfBreak::new(Document::Line(Line::default()), Document::space())),
+                    Document::IfBreak(IfBreak::new(
+                        Document::Line(Line::default()),
+                        Document::String(f.as_str(" ".repeat(padding + 1))),
+                    )),
                     Document::String("=> "),
                 ])),
                 self.expression.format(f),
@@ -777,6 +804,11 @@ impl<'a> Format<'a> for MatchDefaultArm {
 impl<'a> Format<'a> for MatchExpressionArm {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, MatchExpressionArm, {
+            let padding = match self.conditions.first_span() {
+                Some(span) => take_alignment_padding(f, span),
+                None => 0,
+            };
+
             let len = self.conditions.len();
             let mut contents = vec![];
             for (i, condition) in self.conditions.iter().enumerate() {
@@ -790,12 +822,17 @@ impl<'a> Format<'a> for MatchExpressionArm {
             }
 
             contents.push(Document::IndentIfBreak(IndentIfBreak::new(vec![
-                Document::IfBreak(IfBreak::new(Document::Line(Line::default()), Document::space())),
+                Document::IfBreak(IfBreak::new(
+                    Document::Line(Line::default()),
+                    Document::String(f.as_str(" ".repeat(padding + 1))),
+                )),
                 Document::String("=> "),
             ])));
 
+            let should_break = f.settings.break_match_arm_conditions && len > 1;
+
             Document::Array(vec![
-                Document::Group(Group::new(contents)),
+                Document::Group(Group::new(contents).with_break(should_break)),
                 Document::Group(Group::new(vec![self.expression.format(f)])),
             ])
         })
@@ -818,11 +855,16 @@ impl<'a> Format<'a> for Match {
 
             contents.push(Document::String("{"));
 
+            let should_break = self.arms.len() > f.settings.match_multiline_threshold;
+            if should_break {
+                align_match_arms(f, &self.arms);
+            }
+
             if !self.arms.is_empty() {
                 let mut inner_contents =
                     Document::join(self.arms.iter().map(|arm| arm.format(f)).collect::<Vec<_>>(), Separator::CommaLine);
 
-                if f.settings.trailing_comma {
+                if f.settings.match_trailing_comma.unwrap_or(f.settings.trailing_comma) {
                     inner_contents.push(Document::IfBreak(IfBreak::then(Document::String(","))));
                 }
 
@@ -837,7 +879,7 @@ impl<'a> Format<'a> for Match {
 
             contents.push(Document::String("}"));
 
-            Document::Group(Group::new(contents).with_break(true))
+            Document::Group(Group::new(contents).with_break(should_break))
         })
     }
 }
@@ -1151,30 +1193,43 @@ impl<'a> Format<'a> for AnonymousClass {
                 signature.push(arguments.format(f));
             }
 
+            let mut heritage = vec![];
             if let Some(extends) = &self.extends {
-                signature.push(Document::space());
-                signature.push(extends.format(f));
+                heritage.push(Document::space());
+                heritage.push(extends.format(f));
             }
 
             if let Some(implements) = &self.implements {
-                signature.push(Document::space());
-                signature.push(implements.format(f));
+                heritage.push(Document::space());
+                heritage.push(implements.format(f));
             }
 
-            let signature_id = f.next_id();
-            let signature = Document::Group(Group::new(signature).with_id(signature_id));
+            let heritage_id = if heritage.is_empty() {
+                None
+            } else {
+                let heritage_id = f.next_id();
+                signature.push(Document::Group(Group::new(heritage).with_id(heritage_id)));
+
+                Some(heritage_id)
+            };
+
+            let signature = Document::Group(Group::new(signature));
 
             let body = Document::Group(Group::new(vec![
-                // we follow the same brace style as closures, not classes
-                match f.settings.closure_brace_style {
-                    BraceStyle::SameLine => Document::space(),
-                    BraceStyle::NextLine => Document::IfBreak(
+                // we follow the same brace style as closures, not classes, unless the list of interfaces
+                // is broken, in which case the brace is placed on its own line, to separate it from the body.
+                match (f.settings.closure_brace_style, heritage_id) {
+                    (BraceStyle::SameLine, None) => Document::space(),
+                    (BraceStyle::SameLine, Some(heritage_id)) => Document::IfBreak(
                         IfBreak::new(
-                            Document::space(),
                             Document::Array(vec![Document::Line(Line::hardline()), Document::BreakParent]),
+                            Document::space(),
                         )
-                        .with_id(signature_id),
+                        .with_id(heritage_id),
                     ),
+                    (BraceStyle::NextLine, _) => {
+                        Document::Array(vec![Document::Line(Line::hardline()), Document::BreakParent])
+                    }
                 },
                 print_class_like_body(f, &self.left_brace, &self.members, &self.right_brace),
             ]));
@@ -1,3 +1,5 @@
+use std::mem::Discriminant;
+
 use mago_ast::*;
 use mago_span::HasSpan;
 use mago_span::Span;
@@ -6,10 +8,12 @@ use crate::Formatter;
 use crate::comment::CommentFlags;
 use crate::document::Document;
 use crate::document::Group;
+use crate::document::IfBreak;
 use crate::document::Line;
 use crate::document::Separator;
 use crate::format::Format;
 use crate::format::statement::print_statement_sequence;
+use crate::settings::AttributeGrouping;
 use crate::settings::BraceStyle;
 
 pub(super) fn has_new_line_in_range(text: &str, start: usize, end: usize) -> bool {
@@ -127,48 +131,40 @@ pub(super) fn print_colon_delimited_body<'a>(
 }
 
 pub(super) fn print_modifiers<'a>(f: &mut Formatter<'a>, modifiers: &'a Sequence<Modifier>) -> Vec<Document<'a>> {
-    let mut printed_modifiers = vec![];
-
-    if let Some(modifier) = modifiers.get_final() {
-        printed_modifiers.push(modifier.format(f));
-    }
-
-    if let Some(modifier) = modifiers.get_abstract() {
-        printed_modifiers.push(modifier.format(f));
-    }
+    let mut ordered_modifiers = vec![modifiers.get_final(), modifiers.get_abstract()];
 
     if f.settings.static_before_visibility {
-        if let Some(modifier) = modifiers.get_static() {
-            printed_modifiers.push(modifier.format(f));
-        }
-
-        if let Some(modifier) = modifiers.get_readonly() {
-            printed_modifiers.push(modifier.format(f));
-        }
-
-        if let Some(modifier) = modifiers.get_first_read_visibility() {
-            printed_modifiers.push(modifier.format(f));
-        }
-
-        if let Some(modifier) = modifiers.get_first_write_visibility() {
-            printed_modifiers.push(modifier.format(f));
-        }
+        ordered_modifiers.extend([
+            modifiers.get_static(),
+            modifiers.get_readonly(),
+            modifiers.get_first_read_visibility(),
+            modifiers.get_first_write_visibility(),
+        ]);
     } else {
-        if let Some(modifier) = modifiers.get_first_read_visibility() {
-            printed_modifiers.push(modifier.format(f));
-        }
+        ordered_modifiers.extend([
+            modifiers.get_first_read_visibility(),
+            modifiers.get_first_write_visibility(),
+            modifiers.get_static(),
+            modifiers.get_readonly(),
+        ]);
+    }
 
-        if let Some(modifier) = modifiers.get_first_write_visibility() {
-            printed_modifiers.push(modifier.format(f));
-        }
+    let ordered_modifiers = ordered_modifiers.into_iter().flatten().collect::<Vec<_>>();
 
-        if let Some(modifier) = modifiers.get_static() {
-            printed_modifiers.push(modifier.format(f));
-        }
+    // the modifiers may be reordered, so their comments are detached to be printed along with them.
+    let mut source_order = ordered_modifiers.clone();
+    source_order.sort_by_key(|modifier| modifier.span().start.offset);
 
-        if let Some(modifier) = modifiers.get_readonly() {
-            printed_modifiers.push(modifier.format(f));
-        }
+    let spans = source_order.iter().map(|modifier| modifier.span()).collect::<Vec<_>>();
+    let mut owned_comments = f.detach_comments(&spans, false).into_iter().map(Some).collect::<Vec<_>>();
+
+    let mut printed_modifiers = vec![];
+    for modifier in ordered_modifiers {
+        let index = source_order.iter().position(|m| std::ptr::eq(*m, modifier)).unwrap_or_default();
+        printed_modifiers.push(match owned_comments[index].take() {
+            Some(owned) => f.with_owned_comments(owned, |f| modifier.format(f)),
+            None => modifier.format(f),
+        });
     }
 
     Document::join(printed_modifiers, Separator::Space)
@@ -179,18 +175,36 @@ pub(super) fn print_attribute_list_sequence<'a>(
     attribute_lists: &'a Sequence<AttributeList>,
     can_inline: bool,
 ) -> Option<Document<'a>> {
-    if attribute_lists.is_empty() {
-        return None;
-    }
+    let last_list = attribute_lists.last()?;
 
     let mut lists = vec![];
-    let mut has_new_line = false;
-    for attribute_list in attribute_lists.iter() {
-        lists.push(attribute_list.format(f));
+    match f.settings.attribute_grouping {
+        AttributeGrouping::Preserve => {
+            for attribute_list in attribute_lists.iter() {
+                lists.push(attribute_list.format(f));
+            }
+        }
+        AttributeGrouping::Separate => {
+            for (attribute, has_leading_line_comment) in print_regrouped_attributes(f, attribute_lists) {
+                lists.push(print_attribute_list(f, vec![attribute], has_leading_line_comment, None));
+            }
+        }
+        AttributeGrouping::Combine => {
+            let mut attributes = vec![];
+            let mut has_leading_line_comment = false;
+            for (attribute, attribute_has_leading_line_comment) in print_regrouped_attributes(f, attribute_lists) {
+                attributes.push(attribute);
+                has_leading_line_comment |= attribute_has_leading_line_comment;
+            }
 
-        has_new_line = f.is_next_line_empty(attribute_list.span());
+            let should_break = has_leading_line_comment || attributes.len() > 3;
+
+            lists.push(print_attribute_list(f, attributes, should_break, None));
+        }
     }
 
+    let has_new_line = f.is_next_line_empty(last_list.span());
+
     // if there is a single attribute list, we can inline it
     if can_inline && !has_new_line && lists.len() == 1 {
         return Some(Document::Group(Group::new(vec![lists.remove(0), Document::Line(Line::default())])));
@@ -205,11 +219,119 @@ pub(super) fn print_attribute_list_sequence<'a>(
     Some(Document::Group(Group::new(contents).with_break(true)))
 }
 
+/// Prints the attributes of the given attribute lists, to be regrouped into new attribute lists.
+///
+/// The brackets of the original attribute lists are dropped, so the comments around
+/// the attributes are detached to be printed along with them.
+///
+/// Each attribute is returned along with whether it is preceded by a single-line comment,
+/// in which case its attribute list must be broken.
+fn print_regrouped_attributes<'a>(
+    f: &mut Formatter<'a>,
+    attribute_lists: &'a Sequence<AttributeList>,
+) -> Vec<(Document<'a>, bool)> {
+    let attributes =
+        attribute_lists.iter().flat_map(|attribute_list| attribute_list.attributes.iter()).collect::<Vec<_>>();
+
+    let spans = attributes.iter().map(|attribute| attribute.span()).collect::<Vec<_>>();
+    let owned_comments = f.detach_comments(&spans, true);
+
+    attributes
+        .into_iter()
+        .zip(owned_comments)
+        .map(|(attribute, owned)| {
+            let has_leading_line_comment = owned.has_leading_line_comment();
+
+            (f.with_owned_comments(owned, |f| attribute.format(f)), has_leading_line_comment)
+        })
+        .collect()
+}
+
+/// Prints the given attributes as a single attribute list, e.g. `#[Foo, Bar]`.
+///
+/// Like call arguments, a list of several attributes is broken into one attribute per line
+/// if it does not fit on one line, while a single attribute is hugged by the brackets.
+pub(super) fn print_attribute_list<'a>(
+    f: &Formatter<'a>,
+    attributes: Vec<Document<'a>>,
+    should_break: bool,
+    dangling_comments: Option<Document<'a>>,
+) -> Document<'a> {
+    if attributes.len() == 1 && !should_break && dangling_comments.is_none() {
+        let mut contents = vec![Document::String("#[")];
+        contents.extend(attributes.into_iter().map(|attribute| Document::Group(Group::new(vec![attribute]))));
+        contents.push(Document::String("]"));
+
+        return Document::Group(Group::new(contents));
+    }
+
+    let mut inner_content = Document::join(
+        attributes.into_iter().map(|attribute| Document::Group(Group::new(vec![attribute]))).collect(),
+        Separator::CommaLine,
+    );
+    inner_content.insert(0, Document::Line(Line::softline()));
+    if f.settings.trailing_comma {
+        inner_content.push(Document::IfBreak(IfBreak::then(Document::String(","))));
+    }
+
+    Document::Group(
+        Group::new(vec![
+            Document::String("#["),
+            Document::Indent(inner_content),
+            dangling_comments.unwrap_or(Document::Line(Line::softline())),
+            Document::String("]"),
+        ])
+        .with_break(should_break),
+    )
+}
+
+/// Prints a union or intersection type hint, e.g. `A|B|C`.
+///
+/// If the type hint does not fit on one line, every member after the first one is printed on its own line,
+/// preceded by the separator and a space, e.g. `| B`.
+pub(super) fn print_compound_hint<'a>(f: &mut Formatter<'a>, hint: &'a Hint, spacing: Document<'a>) -> Document<'a> {
+    let separator = if matches!(hint, Hint::Intersection(_)) { "&" } else { "|" };
+    let members = collect_compound_hint_members(hint, std::mem::discriminant(hint));
+
+    let mut members = members.into_iter();
+    let Some(first_member) = members.next() else {
+        return Document::empty();
+    };
+
+    let mut contents = vec![first_member.format(f)];
+    let mut rest = vec![];
+    for member in members {
+        rest.push(Document::Line(Line::softline()));
+        rest.push(Document::IfBreak(IfBreak::new(Document::empty(), spacing.clone())));
+        rest.push(Document::String(separator));
+        rest.push(Document::IfBreak(IfBreak::new(Document::space(), spacing.clone())));
+        rest.push(member.format(f));
+    }
+
+    contents.push(Document::Indent(rest));
+
+    Document::Group(Group::new(contents))
+}
+
+/// Collects the members of the given compound type hint, flattening the nested ones of the same kind.
+fn collect_compound_hint_members(hint: &Hint, kind: Discriminant<Hint>) -> Vec<&Hint> {
+    match hint {
+        Hint::Union(UnionHint { left, right, .. }) | Hint::Intersection(IntersectionHint { left, right, .. })
+            if std::mem::discriminant(hint) == kind =>
+        {
+            let mut members = collect_compound_hint_members(left, kind);
+            members.extend(collect_compound_hint_members(right, kind));
+
+            members
+        }
+        _ => vec![hint],
+    }
+}
+
 pub(super) fn print_clause<'a>(f: &mut Formatter<'a>, node: &'a Statement, force_space: bool) -> Document<'a> {
     let clause = node.format(f);
-    let clause = adjust_clause(f, node, clause, force_space);
 
-    clause
+    adjust_clause(f, node, clause, force_space)
 }
 
 pub(super) fn adjust_clause<'a>(
@@ -1,3 +1,4 @@
+use mago_ast::stack::ensure_sufficient_stack;
 use mago_ast::*;
 use mago_span::HasSpan;
 use mago_span::Span;
@@ -7,6 +8,7 @@ use crate::comment::CommentFlags;
 use crate::document::*;
 use crate::format::assignment::AssignmentLikeNode;
 use crate::format::assignment::print_assignment;
+use crate::format::binaryish::is_conditional_chain;
 use crate::format::block::print_block_of_nodes;
 use crate::format::call_node::CallLikeNode;
 use crate::format::call_node::print_call_like_node;
@@ -22,6 +24,7 @@ use crate::utils::get_left_side;
 use crate::utils::has_naked_left_side;
 use crate::wrap;
 
+pub mod alignment;
 pub mod array;
 pub mod assignment;
 pub mod binaryish;
@@ -89,41 +92,49 @@ impl<'a> Format<'a> for Program {
 
 impl<'a> Format<'a> for Statement {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
-        wrap!(f, self, Statement, {
-            match self {
-                Statement::OpeningTag(t) => t.format(f),
-                Statement::ClosingTag(t) => t.format(f),
-                Statement::Inline(i) => i.format(f),
-                Statement::Namespace(n) => n.format(f),
-                Statement::Use(u) => u.format(f),
-                Statement::Class(c) => c.format(f),
-                Statement::Interface(i) => i.format(f),
-                Statement::Trait(t) => t.format(f),
-                Statement::Enum(e) => e.format(f),
-                Statement::Block(b) => b.format(f),
-                Statement::Constant(c) => c.format(f),
-                Statement::Function(u) => u.format(f),
-                Statement::Declare(d) => d.format(f),
-                Statement::Goto(g) => g.format(f),
-                Statement::Label(l) => l.format(f),
-                Statement::Try(t) => t.format(f),
-                Statement::Foreach(o) => o.format(f),
-                Statement::For(o) => o.format(f),
-                Statement::While(w) => w.format(f),
-                Statement::DoWhile(d) => d.format(f),
-                Statement::Continue(c) => c.format(f),
-                Statement::Break(b) => b.format(f),
-                Statement::Switch(s) => s.format(f),
-                Statement::If(i) => i.format(f),
-                Statement::Return(r) => r.format(f),
-                Statement::Expression(e) => e.format(f),
-                Statement::Echo(e) => e.format(f),
-                Statement::Global(g) => g.format(f),
-                Statement::Static(s) => s.format(f),
-                Statement::HaltCompiler(h) => h.format(f),
-                Statement::Unset(u) => u.format(f),
-                Statement::Noop(_) => Document::String(";"),
-            }
+        ensure_sufficient_stack(|| {
+            wrap!(f, self, Statement, {
+                match self {
+                    Statement::OpeningTag(t) => t.format(f),
+                    Statement::ClosingTag(t) => t.format(f),
+                    Statement::Inline(i) => i.format(f),
+                    Statement::Namespace(n) => n.format(f),
+                    Statement::Use(u) => u.format(f),
+                    Statement::Class(c) => c.format(f),
+                    Statement::Interface(i) => i.format(f),
+                    Statement::Trait(t) => t.format(f),
+                    Statement::Enum(e) => e.format(f),
+                    Statement::Block(b) => b.format(f),
+                    Statement::Constant(c) => c.format(f),
+                    Statement::Function(u) => u.format(f),
+                    Statement::Declare(d) => d.format(f),
+                    Statement::Goto(g) => g.format(f),
+                    Statement::Label(l) => l.format(f),
+                    Statement::Try(t) => t.format(f),
+                    Statement::Foreach(o) => o.format(f),
+                    Statement::For(o) => o.format(f),
+                    Statement::While(w) => w.format(f),
+                    Statement::DoWhile(d) => d.format(f),
+                    Statement::Continue(c) => c.format(f),
+                    Statement::Break(b) => b.format(f),
+                    Statement::Switch(s) => s.format(f),
+                    Statement::If(i) => i.format(f),
+                    Statement::Return(r) => r.format(f),
+                    Statement::Expression(e) => e.format(f),
+                    Statement::Echo(e) => e.format(f),
+                    Statement::Global(g) => g.format(f),
+                    Statement::Static(s) => s.format(f),
+                    Statement::HaltCompiler(h) => h.format(f),
+                    Statement::Unset(u) => u.format(f),
+                    Statement::Noop(_) => Document::String(";"),
+                    Statement::Error(span) => {
+                        // Malformed code is printed as it is written.
+                        f.skip_inner_comments(*span);
+
+                        Document::String(&f.source_text[span.start.offset..span.end.offset])
+                    }
+                }
+            })
         })
     }
 }
@@ -703,7 +714,8 @@ impl<'a> Format<'a> for Property {
 impl<'a> Format<'a> for PlainProperty {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, PlainProperty, {
-            let attributes = misc::print_attribute_list_sequence(f, &self.attribute_lists, false);
+            let attributes =
+                misc::print_attribute_list_sequence(f, &self.attribute_lists, f.settings.inline_property_attributes);
             let property = {
                 let mut contents = print_modifiers(f, &self.modifiers);
                 if let Some(var) = &self.var {
@@ -759,7 +771,8 @@ impl<'a> Format<'a> for PlainProperty {
 impl<'a> Format<'a> for HookedProperty {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, HookedProperty, {
-            let attributes = misc::print_attribute_list_sequence(f, &self.attribute_lists, false);
+            let attributes =
+                misc::print_attribute_list_sequence(f, &self.attribute_lists, f.settings.inline_property_attributes);
 
             let property = {
                 let mut contents = print_modifiers(f, &self.modifiers);
@@ -1176,7 +1189,7 @@ impl<'a> Format<'a> for Return {
                         expression = &parenthesized.expression;
                     }
 
-                    if expression.is_binary()
+                    if (expression.is_binary() && !is_conditional_chain(f, expression))
                         || matches!(expression, Expression::Conditional(conditional) if (
                             conditional.then.is_none() || (
                                 matches!(conditional.then.as_ref().map(|e| e.as_ref()), Some(Expression::Conditional(_))) &&
@@ -1285,7 +1298,7 @@ impl<'a> Format<'a> for Attribute {
 impl<'a> Format<'a> for Hint {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, Hint, {
-            let k = |v: &str| match f.settings.keyword_case {
+            let k = |v: &str| match f.settings.type_keyword_case.unwrap_or(f.settings.keyword_case) {
                 CasingStyle::Lowercase => Document::String(f.as_str(v.to_ascii_lowercase())),
                 CasingStyle::Uppercase => Document::String(f.as_str(v.to_ascii_uppercase())),
             };
@@ -1386,28 +1399,16 @@ impl<'a> Format<'a> for Hint {
                         }
                     }
 
-                    Document::Group(Group::new(vec![
-                        union_hint.left.format(f),
-                        spacing.clone(),
-                        Document::String("|"),
-                        spacing,
-                        union_hint.right.format(f),
-                    ]))
+                    misc::print_compound_hint(f, self, spacing)
                 }
-                Hint::Intersection(intersection_hint) => {
+                Hint::Intersection(_) => {
                     let spacing = if f.settings.type_spacing > 0 {
                         Document::String(f.as_str(" ".repeat(f.settings.type_spacing)))
                     } else {
                         Document::empty()
                     };
 
-                    Document::Group(Group::new(vec![
-                        intersection_hint.left.format(f),
-                        spacing.clone(),
-                        Document::String("&"),
-                        spacing,
-                        intersection_hint.right.format(f),
-                    ]))
+                    misc::print_compound_hint(f, self, spacing)
                 }
                 Hint::Null(_) => k("null"),
                 Hint::True(_) => k("true"),
@@ -1490,39 +1491,18 @@ impl<'a> Format<'a> for AttributeList {
                 || has_comments_after_last()
                 || is_empty_with_comments();
 
-            let mut contents = vec![Document::String("#[")];
             let mut attributes = vec![];
             for attribute in self.attributes.iter() {
-                attributes.push(Document::Group(Group::new(vec![attribute.format(f)])));
+                attributes.push(attribute.format(f));
             }
 
-            if should_break {
-                let mut inner_conent = Document::join(attributes, Separator::CommaLine);
-                inner_conent.insert(0, Document::Line(Line::softline()));
-                if f.settings.trailing_comma {
-                    inner_conent.push(Document::IfBreak(IfBreak::then(Document::String(","))));
-                }
-
-                contents.push(Document::Indent(inner_conent));
-                if let Some(comments) = f.print_dangling_comments(self.hash_left_bracket.join(self.right_bracket), true)
-                {
-                    contents.push(comments);
-                } else {
-                    contents.push(Document::Line(Line::softline()));
-                }
+            let dangling_comments = if should_break {
+                f.print_dangling_comments(self.hash_left_bracket.join(self.right_bracket), true)
             } else {
-                for (i, attribute) in attributes.into_iter().enumerate() {
-                    if i != 0 {
-                        contents.push(Document::String(", "));
-                    }
-
-                    contents.push(attribute);
-                }
-            }
-
-            contents.push(Document::String("]"));
+                None
+            };
 
-            Document::Group(Group::new(contents))
+            misc::print_attribute_list(f, attributes, should_break, dangling_comments)
         })
     }
 }
@@ -1627,7 +1607,8 @@ impl<'a> Format<'a> for FunctionLikeParameterDefaultValue {
 impl<'a> Format<'a> for FunctionLikeParameter {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, FunctionLikeParameter, {
-            let attributes = print_attribute_list_sequence(f, &self.attribute_lists, true);
+            let attributes =
+                print_attribute_list_sequence(f, &self.attribute_lists, f.settings.inline_parameter_attributes);
             let parameter = {
                 let mut contents = print_modifiers(f, &self.modifiers);
                 if let Some(hint) = &self.hint {
@@ -35,6 +35,12 @@ pub(super) fn should_hug_the_only_parameter<'a>(
         return false;
     }
 
+    // Don't hug the parameter if its type hint is a union or intersection type, so that
+    // it is moved to its own line before its members are broken into multiple lines.
+    if matches!(parameter.hint, Some(Hint::Union(_) | Hint::Intersection(_))) {
+        return false;
+    }
+
     true
 }
 
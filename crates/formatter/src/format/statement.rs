@@ -5,8 +5,11 @@ use crate::Formatter;
 use crate::document::Document;
 use crate::document::Line;
 use crate::format::Format;
+use crate::format::alignment::align_statements;
 
 pub fn print_statement_sequence<'a>(f: &mut Formatter<'a>, stmts: &'a Sequence<Statement>) -> Vec<Document<'a>> {
+    align_statements(f, stmts);
+
     let mut parts = vec![];
 
     let last_non_noop_index = stmts.iter().rposition(|stmt| !matches!(stmt, Statement::Noop(_)));
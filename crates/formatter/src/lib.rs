@@ -1,9 +1,16 @@
 use std::iter::Peekable;
+use std::ops::Range;
 use std::vec::IntoIter;
 
+use ahash::HashMap;
+use diffy::DiffOptions;
+use diffy::Line;
+
 use mago_ast::Node;
 use mago_ast::Program;
 use mago_ast::Trivia;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
 use mago_interner::StringIdentifier;
 use mago_interner::ThreadedInterner;
 use mago_source::Source;
@@ -38,6 +45,67 @@ pub fn format<'a>(
     formatter.format(program)
 }
 
+/// Formats the lines of a program touched by the given byte range, leaving the rest of the source untouched.
+///
+/// The whole program is formatted, and the result is compared line by line with the original source.
+/// Only the changed lines overlapping the lines of the range are kept, so a change may extend past the
+/// range when the formatter rewrites several lines at once, e.g. when joining a broken expression.
+///
+/// # Returns
+///
+/// A plan replacing the changed lines of the source with their formatted counterpart.
+pub fn format_range<'a>(
+    interner: &'a ThreadedInterner,
+    source: &'a Source,
+    program: &'a Program,
+    settings: FormatSettings,
+    range: Range<usize>,
+) -> FixPlan {
+    let content = &source.content;
+    let formatted = format(interner, source, program, settings);
+
+    let first_line = source.line_number(range.start);
+    let last_line = source.line_number(range.end.max(range.start));
+    let get_line_offset = |line: usize| source.get_line_start_offset(line).unwrap_or(content.len());
+
+    let mut plan = FixPlan::new();
+    for hunk in DiffOptions::new().set_context_len(0).create_patch(content, &formatted).hunks() {
+        let old_range = hunk.old_range();
+
+        // Line numbers are 1-based, except for insertions, which refer to the line preceding the inserted lines.
+        let start = if old_range.is_empty() { old_range.start() } else { old_range.start() - 1 };
+        let end = start + old_range.len();
+
+        if end < first_line || start > last_line + 1 || (!old_range.is_empty() && start > last_line) {
+            continue;
+        }
+
+        let mut deleted = 0;
+        let mut inserted = vec![];
+        for line in hunk.lines() {
+            match line {
+                Line::Insert(text) => inserted.push(*text),
+                Line::Delete(_) | Line::Context(_) => deleted += 1,
+            }
+        }
+
+        // Adjacent changed lines are grouped into a single hunk; when each line is replaced by exactly one
+        // line, only the ones within the range are replaced, leaving the surrounding lines untouched.
+        if deleted == inserted.len() {
+            for (index, text) in inserted.into_iter().enumerate() {
+                let line = start + index;
+                if line >= first_line && line <= last_line {
+                    plan.replace(get_line_offset(line)..get_line_offset(line + 1), text, SafetyClassification::Safe);
+                }
+            }
+        } else {
+            plan.replace(get_line_offset(start)..get_line_offset(end), inserted.concat(), SafetyClassification::Safe);
+        }
+    }
+
+    plan
+}
+
 struct ArgumentState {
     expand_first_argument: bool,
     expand_last_argument: bool,
@@ -53,6 +121,7 @@ pub struct Formatter<'a> {
     scripting_mode: bool,
     id_builder: GroupIdentifierBuilder,
     argument_state: ArgumentState,
+    alignment_paddings: HashMap<usize, usize>,
 }
 
 impl<'a> Formatter<'a> {
@@ -60,13 +129,14 @@ impl<'a> Formatter<'a> {
         Self {
             interner,
             source,
-            source_text: interner.lookup(&source.content),
+            source_text: &source.content,
             settings,
             stack: vec![],
             comments: vec![].into_iter().peekable(),
             scripting_mode: false,
             id_builder: GroupIdentifierBuilder::new(),
             argument_state: ArgumentState { expand_first_argument: false, expand_last_argument: false },
+            alignment_paddings: HashMap::default(),
         }
     }
 
@@ -228,11 +298,21 @@ impl<'a> Formatter<'a> {
             self.source_text[start_index..].bytes().next()
         }?;
 
-        if matches!(c, b'\n') {
-            return Some(if backwards { start_index - 1 } else { start_index + 1 });
+        if !matches!(c, b'\n' | b'\r') {
+            return Some(start_index);
         }
 
-        Some(start_index)
+        // A `\r\n` sequence is skipped as a single line break.
+        let bytes = self.source_text.as_bytes();
+        if backwards {
+            let is_crlf = c == b'\n' && start_index > 0 && bytes[start_index - 1] == b'\r';
+
+            start_index.checked_sub(if is_crlf { 2 } else { 1 })
+        } else {
+            let is_crlf = c == b'\r' && bytes.get(start_index + 1) == Some(&b'\n');
+
+            Some(start_index + if is_crlf { 2 } else { 1 })
+        }
     }
 
     fn has_newline(&self, start_index: usize, backwards: bool) -> bool {
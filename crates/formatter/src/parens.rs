@@ -41,6 +41,17 @@ impl<'a> Formatter<'a> {
             return false;
         };
 
+        if !self.settings.parenthesize_nested_ternaries {
+            if let (Node::Conditional(conditional), Node::Conditional(parent)) = (node, parent_node) {
+                // PHP allows a ternary nested in the middle operand to be left without parentheses.
+                if conditional.then.is_some()
+                    && parent.then.as_ref().is_some_and(|then| then.span().contains(&conditional.span().start))
+                {
+                    return false;
+                }
+            }
+        }
+
         self.is_unary_or_binary_or_ternary(parent_node)
     }
 
@@ -37,14 +37,22 @@ impl Mode {
 pub struct Indent {
     pub root: bool,
     pub length: usize,
+    /// The number of spaces added after the levels of indentation.
+    pub align: usize,
 }
 
 impl Indent {
     pub fn root() -> Self {
-        Self { root: true, length: 0 }
+        Self { root: true, length: 0, align: 0 }
     }
 
-    pub fn new(length: usize) -> Self {
-        Self { root: false, length }
+    /// Returns this indentation, increased by a level.
+    pub fn indented(self) -> Self {
+        Self { root: false, length: self.length + 1, align: self.align }
+    }
+
+    /// Returns this indentation, increased by the given number of spaces.
+    pub fn aligned(self, width: usize) -> Self {
+        Self { root: false, length: self.length, align: self.align + width }
     }
 }
@@ -1,11 +1,17 @@
 use std::collections::VecDeque;
+use std::iter::Chain;
+use std::iter::Rev;
+use std::slice::IterMut;
 
 use ahash::HashMap;
 
 use mago_source::Source;
 
+use crate::document::Align;
+use crate::document::Alignment;
 use crate::document::Document;
 use crate::document::Fill;
+use crate::document::Group;
 use crate::document::IfBreak;
 use crate::document::IndentIfBreak;
 use crate::document::Line;
@@ -24,6 +30,7 @@ pub struct Printer<'a> {
     commands: Vec<Command<'a>>,
     line_suffix: Vec<Command<'a>>,
     group_mode_map: HashMap<GroupIdentifier, Mode>,
+    group_column_map: HashMap<GroupIdentifier, usize>,
     new_line: &'static str,
 }
 
@@ -41,7 +48,8 @@ impl<'a> Printer<'a> {
             commands: cmds,
             line_suffix: vec![],
             group_mode_map: HashMap::default(),
-            new_line: settings.end_of_line.as_str(),
+            group_column_map: HashMap::default(),
+            new_line: settings.end_of_line.resolve(&source.content).as_str(),
         }
     }
 
@@ -63,6 +71,7 @@ impl<'a> Printer<'a> {
                 Document::Indent(docs) => self.handle_indent(indent, mode, docs),
                 Document::Group(_) => self.handle_group(indent, mode, document),
                 Document::IndentIfBreak(docs) => self.handle_indent_if_break(indent, mode, docs),
+                Document::Align(align) => self.handle_align(indent, mode, align),
                 Document::Line(line) => self.handle_line(line, indent, mode, document),
                 Document::LineSuffix(docs) => self.handle_line_suffix(indent, mode, docs),
                 Document::IfBreak(if_break) => self.handle_if_break(if_break, indent, mode),
@@ -90,10 +99,14 @@ impl<'a> Printer<'a> {
     }
 
     fn handle_indent(&mut self, indent: Indent, mode: Mode, docs: Vec<Document<'a>>) {
-        self.commands.extend(docs.into_iter().rev().map(|doc| Command::new(Indent::new(indent.length + 1), mode, doc)));
+        self.commands.extend(docs.into_iter().rev().map(|doc| Command::new(indent.indented(), mode, doc)));
     }
 
     fn handle_group(&mut self, indent: Indent, mode: Mode, doc: Document<'a>) {
+        if let Document::Group(Group { id: Some(id), .. }) = &doc {
+            self.group_column_map.insert(*id, self.position);
+        }
+
         match mode {
             Mode::Flat => {
                 let Document::Group(group) = doc else {
@@ -153,6 +166,22 @@ impl<'a> Printer<'a> {
         }
     }
 
+    fn handle_align(&mut self, indent: Indent, mode: Mode, align: Align<'a>) {
+        let Align { alignment, contents } = align;
+        let width = match alignment {
+            Alignment::Spaces(width) => width,
+            // The line of the group starts with the current indentation, so the rest of the line is aligned.
+            Alignment::Column(id) => {
+                let column = self.group_column_map.get(&id).copied().unwrap_or(self.position);
+
+                column.saturating_sub(self.indentation_width(indent))
+            }
+        };
+
+        let indent = indent.aligned(width);
+        self.commands.extend(contents.into_iter().rev().map(|doc| Command::new(indent, mode, doc)));
+    }
+
     fn handle_indent_if_break(&mut self, indent: Indent, mode: Mode, doc: IndentIfBreak<'a>) {
         let IndentIfBreak { contents, group_id } = doc;
         let group_mode = group_id.map_or(Some(mode), |id| self.group_mode_map.get(&id).copied());
@@ -162,9 +191,7 @@ impl<'a> Printer<'a> {
                 self.commands.extend(contents.into_iter().rev().map(|doc| Command::new(indent, mode, doc)));
             }
             Some(Mode::Break) => {
-                self.commands.extend(
-                    contents.into_iter().rev().map(|doc| Command::new(Indent::new(indent.length + 1), mode, doc)),
-                );
+                self.commands.extend(contents.into_iter().rev().map(|doc| Command::new(indent.indented(), mode, doc)));
             }
             None => {}
         }
@@ -198,7 +225,7 @@ impl<'a> Printer<'a> {
 
         self.trim();
         self.out.extend(self.new_line.as_bytes());
-        self.position = self.indent(indent.length);
+        self.position = self.indent(indent);
     }
 
     fn handle_line_suffix(&mut self, indent: Indent, mode: Mode, docs: Vec<Document<'a>>) {
@@ -307,15 +334,24 @@ impl<'a> Printer<'a> {
         };
     }
 
-    fn indent(&mut self, size: usize) -> usize {
+    fn indent(&mut self, indent: Indent) -> usize {
         if self.settings.use_tabs {
-            self.out.extend("\t".repeat(size).as_bytes());
-            size
+            self.out.extend("\t".repeat(indent.length).as_bytes());
         } else {
-            let count = self.settings.tab_width * size;
-            self.out.extend(" ".repeat(count).as_bytes());
-            count
+            self.out.extend(" ".repeat(self.settings.tab_width * indent.length).as_bytes());
         }
+
+        // Alignment is always made of spaces, so that it is preserved regardless of the width of tabs.
+        self.out.extend(" ".repeat(indent.align).as_bytes());
+
+        self.indentation_width(indent)
+    }
+
+    /// Returns the width of the given indentation, counting each tab as a single character, as the position does.
+    fn indentation_width(&self, indent: Indent) -> usize {
+        let levels = if self.settings.use_tabs { indent.length } else { self.settings.tab_width * indent.length };
+
+        levels + indent.align
     }
 
     fn trim(&mut self) {
@@ -352,6 +388,7 @@ impl<'a> Printer<'a> {
                     remaining_width -= string.len() as isize;
                 }
                 Document::IndentIfBreak(IndentIfBreak { contents: docs, .. })
+                | Document::Align(Align { contents: docs, .. })
                 | Document::Indent(docs)
                 | Document::Array(docs) => {
                     // Prepend docs to the queue
@@ -361,8 +398,8 @@ impl<'a> Printer<'a> {
                 }
                 Document::Group(group) => {
                     let mode = if group.should_break { Mode::Break } else { mode };
-                    if group.expanded_states.is_some() && mode.is_break() {
-                        queue.push_front((mode, group.expanded_states.as_ref().unwrap().last().unwrap()));
+                    if let Some(expanded_states) = group.expanded_states.as_ref().filter(|_| mode.is_break()) {
+                        queue.push_front((mode, expanded_states.last().unwrap()));
                     } else {
                         for d in group.contents.iter().rev() {
                             queue.push_front((mode, d));
@@ -411,29 +448,77 @@ impl<'a> Printer<'a> {
         true
     }
 
+    /// Marks the groups containing a hard break as broken, returning whether the given document contains one.
+    ///
+    /// The document is traversed using an explicit stack, instead of recursing, so that deeply nested documents
+    /// cannot overflow the stack. Like the `any` it replaces, the traversal of a document's children stops at the
+    /// first child, in reverse order, that contains a break.
     pub fn propagate_breaks(doc: &mut Document<'_>) -> bool {
-        let check_array = |arr: &mut Vec<Document<'_>>| arr.iter_mut().rev().any(|doc| Self::propagate_breaks(doc));
-
-        match doc {
-            Document::BreakParent => true,
-            Document::Group(group) => {
-                let mut should_break = false;
-                if let Some(expanded_states) = &mut group.expanded_states {
-                    should_break = expanded_states.iter_mut().rev().any(Self::propagate_breaks);
-                }
-                if !should_break {
-                    should_break = check_array(&mut group.contents);
+        struct Frame<'a, 'd> {
+            children: Chain<Rev<IterMut<'a, Document<'d>>>, Rev<IterMut<'a, Document<'d>>>>,
+            // The `should_break` flag of the group whose children are traversed, and whether it has expanded states.
+            group: Option<(&'a mut bool, bool)>,
+        }
+
+        let mut stack: Vec<Frame<'_, '_>> = vec![];
+        let mut next = Some(doc);
+        // The result of the last document whose traversal is complete, if any.
+        let mut result = None;
+
+        loop {
+            if let Some(doc) = next.take() {
+                match doc {
+                    Document::BreakParent => result = Some(true),
+                    Document::Group(Group { contents, should_break, expanded_states, .. }) => {
+                        let has_expanded_states = expanded_states.is_some();
+                        let expanded_states: &mut [Document<'_>] = expanded_states.as_deref_mut().unwrap_or_default();
+
+                        stack.push(Frame {
+                            children: expanded_states.iter_mut().rev().chain(contents.iter_mut().rev()),
+                            group: Some((should_break, has_expanded_states)),
+                        });
+                    }
+                    Document::IfBreak(d) => {
+                        next = Some(&mut d.break_contents);
+
+                        continue;
+                    }
+                    Document::Array(arr)
+                    | Document::Indent(arr)
+                    | Document::IndentIfBreak(IndentIfBreak { contents: arr, .. })
+                    | Document::Align(Align { contents: arr, .. }) => {
+                        let empty: &mut [Document<'_>] = &mut [];
+
+                        stack.push(Frame { children: empty.iter_mut().rev().chain(arr.iter_mut().rev()), group: None });
+                    }
+                    _ => result = Some(false),
                 }
-                if group.expanded_states.is_none() && should_break {
-                    group.should_break = should_break;
+            }
+
+            let Some(frame) = stack.last_mut() else {
+                return result.unwrap_or(false);
+            };
+
+            if result != Some(true) {
+                if let Some(child) = frame.children.next() {
+                    next = Some(child);
+                    result = None;
+
+                    continue;
                 }
-                group.should_break
             }
-            Document::IfBreak(d) => Self::propagate_breaks(&mut d.break_contents),
-            Document::Array(arr)
-            | Document::Indent(arr)
-            | Document::IndentIfBreak(IndentIfBreak { contents: arr, .. }) => check_array(arr),
-            _ => false,
+
+            let should_break = result == Some(true);
+            result = Some(match stack.pop().and_then(|frame| frame.group) {
+                Some((group_should_break, has_expanded_states)) => {
+                    if !has_expanded_states && should_break {
+                        *group_should_break = true;
+                    }
+
+                    *group_should_break
+                }
+                None => should_break,
+            });
         }
     }
 }
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -26,7 +27,9 @@ pub struct FormatSettings {
 
     /// Specify which end-of-line characters to use.
     ///
-    /// Default: "lf"
+    /// When set to `auto`, the dominant line ending of each file is used, or `lf` if it has no line break.
+    ///
+    /// Default: "auto"
     #[serde(default)]
     pub end_of_line: EndOfLine,
 
@@ -98,6 +101,42 @@ pub struct FormatSettings {
     #[serde(default)]
     pub keyword_case: CasingStyle,
 
+    /// Specifies the case to use for the `true`, `false`, and `null` constants.
+    ///
+    /// When not set, the `keyword_case` setting is used.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // lowercase
+    /// $foo = [true, false, null];
+    ///
+    /// // uppercase
+    /// $foo = [TRUE, FALSE, NULL];
+    /// ```
+    ///
+    /// Default: none
+    #[serde(default)]
+    pub constant_case: Option<CasingStyle>,
+
+    /// Specifies the case to use for type keywords in type hints, e.g. `int`, `string`, or `array`.
+    ///
+    /// When not set, the `keyword_case` setting is used.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // lowercase
+    /// function foo(int $bar): array {}
+    ///
+    /// // uppercase
+    /// function foo(INT $bar): ARRAY {}
+    /// ```
+    ///
+    /// Default: none
+    #[serde(default)]
+    pub type_keyword_case: Option<CasingStyle>,
+
     /// Blank line after the opening PHP tag.
     ///
     /// When enabled, the formatter will add a blank line after the opening PHP tag.
@@ -428,9 +467,25 @@ pub struct FormatSettings {
     /// Note: The number of spaces added around the concatenation operator is controlled by the `binary_op_spacing` setting.
     ///
     /// Default: true
-    #[serde(default = "default_true")]
+    #[serde(default = "default_true", alias = "space_around_concatenation")]
     pub space_concatenation: bool,
 
+    /// Whether to add a space before and after the concatenation assignment operator (`.=`).
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// $foo .= 'world!';
+    ///
+    /// // or
+    ///
+    /// $foo.='world!';
+    /// ```
+    ///
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub space_concatenation_assignment: bool,
+
     /// How to format broken method/property chains.
     ///
     /// When breaking a method or property chain, this option determines whether the
@@ -453,6 +508,32 @@ pub struct FormatSettings {
     #[serde(default)]
     pub method_chain_breaking_style: MethodChainBreakingStyle,
 
+    /// How to indent the lines of a broken method chain.
+    ///
+    /// By default, the lines are indented by a level of indentation, as any other construct. The setting can be
+    /// given a number of spaces instead, to indent method chains independently of `tab_width`, or `"align"`,
+    /// to align the calls under the first `->` of the chain.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // chain_indent = "indent", with tab_width = 2
+    /// $foo->bar()
+    ///   ->baz();
+    ///
+    /// // chain_indent = 4, with tab_width = 2
+    /// $foo->bar()
+    ///     ->baz();
+    ///
+    /// // chain_indent = "align", with method_chain_breaking_style = "same_line"
+    /// $foo->bar()
+    ///     ->baz();
+    /// ```
+    ///
+    /// Default: "indent"
+    #[serde(default)]
+    pub chain_indent: ChainIndent,
+
     /// Whether to add a line before binary operators or after when breaking.
     ///
     /// Note: This setting will always be false if the rhs of the binary operator has a leading comment.
@@ -486,6 +567,296 @@ pub struct FormatSettings {
     /// Default: false
     #[serde(default = "default_false")]
     pub line_before_binary_operator: bool,
+
+    /// Whether to keep closures whose body is a single `return` or expression statement on one line, when they fit.
+    ///
+    /// This applies to closures used anywhere, including as call arguments, where the closure is only expanded if
+    /// the arguments do not fit on one line otherwise. Arrow functions are always kept on one line when they fit.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // inline_simple_closures = true
+    /// $doubled = array_map(function ($x) { return $x * 2; }, $items);
+    ///
+    /// // inline_simple_closures = false
+    /// $doubled = array_map(function ($x) {
+    ///     return $x * 2;
+    /// }, $items);
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub inline_simple_closures: bool,
+
+    /// Whether to vertically align the `=` of consecutive simple assignment statements and constant definitions.
+    ///
+    /// Alignment groups are broken by blank lines, comments, and any other statement. Only assignments using
+    /// `=` whose left-hand side fits on a single line are aligned, as are constant definitions defining a single
+    /// constant, both at the top level and in classes, interfaces, traits, and enums.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // align_assignments = true
+    /// $foo    = 1;
+    /// $barBaz = 2;
+    ///
+    /// $qux = 3;
+    ///
+    /// // align_assignments = false
+    /// $foo = 1;
+    /// $barBaz = 2;
+    ///
+    /// $qux = 3;
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub align_assignments: bool,
+
+    /// Whether to print casts in their canonical form, replacing the aliases `(integer)`, `(boolean)`, `(double)`,
+    /// `(real)`, and `(binary)` with `(int)`, `(bool)`, `(float)`, `(float)`, and `(string)`, and removing the
+    /// whitespace inside the parentheses.
+    ///
+    /// The case of casts is controlled by the `keyword_case` setting.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // canonical_casts = true
+    /// $foo = (int) $bar;
+    ///
+    /// // canonical_casts = false
+    /// $foo = ( integer ) $bar;
+    /// ```
+    ///
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub canonical_casts: bool,
+
+    /// Whether to add a space between a cast and its operand.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // space_after_cast = true
+    /// $foo = (int) $bar;
+    ///
+    /// // space_after_cast = false
+    /// $foo = (int)$bar;
+    /// ```
+    ///
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub space_after_cast: bool,
+
+    /// Whether to rewrite `else if` to `elseif`, or `elseif` to `else if`, in `if` statements.
+    ///
+    /// `else if` is only rewritten to `elseif` when there is no comment between the two keywords,
+    /// and `if` statements using the alternative syntax, e.g. `if ($foo): ... endif;`, are never rewritten,
+    /// as `else if` is not allowed there.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // elseif
+    /// if ($foo) {
+    ///     foo();
+    /// } elseif ($bar) {
+    ///     bar();
+    /// }
+    ///
+    /// // else_if
+    /// if ($foo) {
+    ///     foo();
+    /// } else if ($bar) {
+    ///     bar();
+    /// }
+    /// ```
+    ///
+    /// Default: Preserve
+    #[serde(default)]
+    pub else_if_style: ElseIfStyle,
+
+    /// The number of elements above which arrays are always broken into multiple lines, even if they fit on one line.
+    ///
+    /// When not set, arrays are only broken if they do not fit on one line, or if their first element
+    /// is on a new line in the source.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // array_multiline_threshold = 2
+    /// $foo = [1, 2];
+    /// $bar = [
+    ///     1,
+    ///     2,
+    ///     3,
+    /// ];
+    /// ```
+    ///
+    /// Default: none
+    #[serde(default)]
+    pub array_multiline_threshold: Option<usize>,
+
+    /// Whether to keep a single attribute list on the same line as the parameter it applies to, when it fits.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // inline_parameter_attributes = true
+    /// function foo(#[SensitiveParameter] string $password) {}
+    ///
+    /// // inline_parameter_attributes = false
+    /// function foo(
+    ///     #[SensitiveParameter]
+    ///     string $password,
+    /// ) {}
+    /// ```
+    ///
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub inline_parameter_attributes: bool,
+
+    /// Whether to keep a single attribute list on the same line as the property it applies to, when it fits.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // inline_property_attributes = true
+    /// #[ORM\Column] private int $id;
+    ///
+    /// // inline_property_attributes = false
+    /// #[ORM\Column]
+    /// private int $id;
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub inline_property_attributes: bool,
+
+    /// How to group attributes into attribute lists.
+    ///
+    /// Comments surrounding the attributes are moved along with them.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // preserve
+    /// #[Foo, Bar]
+    /// #[Baz]
+    ///
+    /// // separate
+    /// #[Foo]
+    /// #[Bar]
+    /// #[Baz]
+    ///
+    /// // combine
+    /// #[Foo, Bar, Baz]
+    /// ```
+    ///
+    /// Default: Preserve
+    #[serde(default)]
+    pub attribute_grouping: AttributeGrouping,
+
+    /// Whether to wrap ternaries nested in the middle operand of another ternary in parentheses.
+    ///
+    /// Ternaries nested in the condition or the `else` operand are always wrapped in parentheses,
+    /// as PHP does not allow them otherwise.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // parenthesize_nested_ternaries = true
+    /// $foo = $a ? ($b ? 1 : 2) : 3;
+    ///
+    /// // parenthesize_nested_ternaries = false
+    /// $foo = $a ? $b ? 1 : 2 : 3;
+    /// ```
+    ///
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub parenthesize_nested_ternaries: bool,
+
+    /// The number of arms above which a `match` expression is always broken into multiple lines.
+    ///
+    /// A `match` expression with at most this many arms is kept on a single line when it fits.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // match_multiline_threshold = 0
+    /// $foo = match ($bar) {
+    ///     1 => 'one',
+    ///     default => 'other',
+    /// };
+    ///
+    /// // match_multiline_threshold = 2
+    /// $foo = match ($bar) { 1 => 'one', default => 'other' };
+    /// ```
+    ///
+    /// Default: 0
+    #[serde(default)]
+    pub match_multiline_threshold: usize,
+
+    /// Whether to align the `=>` of consecutive `match` arms.
+    ///
+    /// Arms are only aligned if they fit on a single line.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // align_match_arrows = true
+    /// $foo = match ($bar) {
+    ///     1       => 'one',
+    ///     2, 3    => 'two or three',
+    ///     default => 'other',
+    /// };
+    ///
+    /// // align_match_arrows = false
+    /// $foo = match ($bar) {
+    ///     1 => 'one',
+    ///     2, 3 => 'two or three',
+    ///     default => 'other',
+    /// };
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub align_match_arrows: bool,
+
+    /// Whether to place each condition of a `match` arm with multiple conditions on its own line.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // break_match_arm_conditions = true
+    /// $foo = match ($bar) {
+    ///     1,
+    ///     2,
+    ///         => 'one or two',
+    ///     default => 'other',
+    /// };
+    ///
+    /// // break_match_arm_conditions = false
+    /// $foo = match ($bar) {
+    ///     1, 2 => 'one or two',
+    ///     default => 'other',
+    /// };
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub break_match_arm_conditions: bool,
+
+    /// Whether to add a trailing comma after the last arm of a multi-line `match` expression.
+    ///
+    /// If not set, `trailing_comma` is used.
+    ///
+    /// Default: None
+    #[serde(default)]
+    pub match_trailing_comma: Option<bool>,
 }
 
 impl Default for FormatSettings {
@@ -500,6 +871,8 @@ impl Default for FormatSettings {
             trailing_comma: true,
             space_around_declare_equals: false,
             keyword_case: CasingStyle::default(),
+            constant_case: None,
+            type_keyword_case: None,
             blank_line_after_open_tag: true,
             control_space_parens: false,
             closure_brace_style: BraceStyle::SameLine,
@@ -516,14 +889,30 @@ impl Default for FormatSettings {
             method_chain_break_threshold: default_method_chain_break_threshold(),
             break_promoted_properties_list: true,
             space_concatenation: true,
+            space_concatenation_assignment: true,
             method_chain_breaking_style: MethodChainBreakingStyle::NextLine,
+            chain_indent: ChainIndent::default(),
             line_before_binary_operator: false,
+            inline_simple_closures: false,
+            align_assignments: false,
+            canonical_casts: true,
+            space_after_cast: true,
+            else_if_style: ElseIfStyle::default(),
+            array_multiline_threshold: None,
+            inline_parameter_attributes: true,
+            inline_property_attributes: false,
+            attribute_grouping: AttributeGrouping::default(),
+            parenthesize_nested_ternaries: true,
+            match_multiline_threshold: 0,
+            align_match_arrows: false,
+            break_match_arm_conditions: false,
+            match_trailing_comma: None,
         }
     }
 }
 
 /// Specifies the style of line endings.
-#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, PartialOrd, Ord)]
 pub enum EndOfLine {
     #[default]
     #[serde(alias = "auto")]
@@ -537,7 +926,7 @@ pub enum EndOfLine {
 }
 
 /// Specifies the style of line endings.
-#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, PartialOrd, Ord)]
 pub enum CasingStyle {
     #[default]
     #[serde(alias = "lowercase", alias = "lower")]
@@ -547,7 +936,7 @@ pub enum CasingStyle {
 }
 
 /// Specifies the style of line endings.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, PartialOrd, Ord)]
 pub enum BraceStyle {
     #[serde(alias = "same_line")]
     SameLine,
@@ -555,7 +944,7 @@ pub enum BraceStyle {
     NextLine,
 }
 
-#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, PartialOrd, Ord)]
 pub enum MethodChainBreakingStyle {
     #[serde(alias = "same_line")]
     SameLine,
@@ -564,6 +953,31 @@ pub enum MethodChainBreakingStyle {
     NextLine,
 }
 
+/// Specifies the indentation of the lines of a broken method chain.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, PartialOrd, Ord)]
+#[serde(untagged)]
+pub enum ChainIndent {
+    /// Indent by the given number of spaces.
+    Spaces(usize),
+    /// Indent by a level of indentation, or align under the first `->`.
+    Style(ChainIndentStyle),
+}
+
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, PartialOrd, Ord)]
+pub enum ChainIndentStyle {
+    #[default]
+    #[serde(alias = "indent")]
+    Indent,
+    #[serde(alias = "align")]
+    Align,
+}
+
+impl Default for ChainIndent {
+    fn default() -> Self {
+        Self::Style(ChainIndentStyle::Indent)
+    }
+}
+
 impl BraceStyle {
     pub fn same_line() -> Self {
         Self::SameLine
@@ -587,6 +1001,36 @@ impl MethodChainBreakingStyle {
 }
 
 impl EndOfLine {
+    /// Detects the dominant line ending of the given content, defaulting to `Lf`.
+    pub fn detect(content: &str) -> Self {
+        let bytes = content.as_bytes();
+        let (mut lf, mut crlf, mut cr) = (0, 0, 0);
+        for (index, byte) in bytes.iter().enumerate() {
+            match byte {
+                b'\n' if index > 0 && bytes[index - 1] == b'\r' => crlf += 1,
+                b'\n' => lf += 1,
+                b'\r' if bytes.get(index + 1) != Some(&b'\n') => cr += 1,
+                _ => {}
+            }
+        }
+
+        if crlf > lf && crlf >= cr {
+            Self::Crlf
+        } else if cr > lf && cr > crlf {
+            Self::Cr
+        } else {
+            Self::Lf
+        }
+    }
+
+    /// Returns the line ending to use for the given content, resolving `Auto` to its dominant line ending.
+    pub fn resolve(self, content: &str) -> Self {
+        match self {
+            Self::Auto => Self::detect(content),
+            _ => self,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Crlf => "\r\n",
@@ -611,7 +1055,7 @@ impl FromStr for EndOfLine {
 }
 
 /// Specifies null type hint style.
-#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, PartialOrd, Ord)]
 pub enum NullTypeHint {
     #[default]
     #[serde(alias = "null_pipe", alias = "pipe", alias = "long", alias = "|")]
@@ -626,6 +1070,30 @@ impl NullTypeHint {
     }
 }
 
+/// Specifies how to group attributes into attribute lists.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, PartialOrd, Ord)]
+pub enum AttributeGrouping {
+    #[default]
+    #[serde(alias = "preserve")]
+    Preserve,
+    #[serde(alias = "separate")]
+    Separate,
+    #[serde(alias = "combine")]
+    Combine,
+}
+
+/// Specifies whether to use `elseif` or `else if`.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, JsonSchema, PartialOrd, Ord)]
+pub enum ElseIfStyle {
+    #[default]
+    #[serde(alias = "preserve")]
+    Preserve,
+    #[serde(alias = "elseif")]
+    ElseIf,
+    #[serde(alias = "else_if", alias = "else if")]
+    ElseSpaceIf,
+}
+
 fn default_print_width() -> usize {
     120
 }
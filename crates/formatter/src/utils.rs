@@ -1,6 +1,7 @@
 use mago_ast::*;
 
 use crate::Formatter;
+use crate::document::Align;
 use crate::document::Document;
 use crate::document::IndentIfBreak;
 
@@ -119,7 +120,8 @@ pub fn will_break(document: &mut Document<'_>) -> bool {
         Document::Array(arr)
         | Document::Indent(arr)
         | Document::LineSuffix(arr)
-        | Document::IndentIfBreak(IndentIfBreak { contents: arr, .. }) => check_array(arr),
+        | Document::IndentIfBreak(IndentIfBreak { contents: arr, .. })
+        | Document::Align(Align { contents: arr, .. }) => check_array(arr),
         Document::Fill(doc) => check_array(&mut doc.parts),
         Document::Line(doc) => doc.hard,
         Document::String(_) => false,
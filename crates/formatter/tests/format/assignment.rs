@@ -176,3 +176,89 @@ pub fn test_conditional_assignment() {
 
     test_format(code, expected_super_narrow, FormatSettings { print_width: 40, ..Default::default() });
 }
+
+#[test]
+pub fn test_align_assignments() {
+    let code = indoc! {r#"
+        <?php
+
+        const A = 1;
+        const LONGER_NAME = 2;
+
+        $foo = 1;
+        $barBaz = 2;
+        $x['key'] = 3;
+        $y += 4;
+        $short = 5;
+        $s = 6;
+
+        $qux = 3;
+        // comment
+        $quux = 4;
+        $a = 5;
+
+        final class Foo
+        {
+            public const A = 1;
+            const BBBBBB = 2;
+            private const string C = 'c';
+
+            const D = 4;
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        const A           = 1;
+        const LONGER_NAME = 2;
+
+        $foo      = 1;
+        $barBaz   = 2;
+        $x['key'] = 3;
+        $y += 4;
+        $short = 5;
+        $s     = 6;
+
+        $qux = 3;
+        // comment
+        $quux = 4;
+        $a    = 5;
+
+        final class Foo
+        {
+            public const A         = 1;
+            const BBBBBB           = 2;
+            private const string C = 'c';
+
+            const D = 4;
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { align_assignments: true, ..Default::default() });
+    test_format(expected, code, FormatSettings::default());
+}
+
+#[test]
+pub fn test_space_concatenation_assignment() {
+    let code = indoc! {r#"
+        <?php
+
+        $a .= 'x' . $b;
+        $c = $d . 'y';
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a.='x'.$b;
+        $c = $d.'y';
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings { space_concatenation: false, space_concatenation_assignment: false, ..Default::default() },
+    );
+    test_format(expected, code, FormatSettings::default());
+}
@@ -0,0 +1,149 @@
+use indoc::indoc;
+
+use mago_formatter::settings::AttributeGrouping;
+use mago_formatter::settings::FormatSettings;
+
+use crate::test_format;
+
+#[test]
+pub fn test_attribute_list_wrapping() {
+    let code = indoc! {r#"
+        <?php
+
+        #[ORM\Entity(repositoryClass: UserRepository::class, readOnly: false), ORM\Table(name: 'users', schema: 'public', options: [])]
+        final class User
+        {
+            #[Assert\Length(min: 3, max: 255, minMessage: 'The name must be at least {{ limit }} characters long', maxMessage: 'Too long')]
+            private string $name;
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        #[
+            ORM\Entity(repositoryClass: UserRepository::class, readOnly: false),
+            ORM\Table(name: 'users', schema: 'public', options: []),
+        ]
+        final class User
+        {
+            #[Assert\Length(
+                min: 3,
+                max: 255,
+                minMessage: 'The name must be at least {{ limit }} characters long',
+                maxMessage: 'Too long',
+            )]
+            private string $name;
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings::default());
+}
+
+#[test]
+pub fn test_attribute_placement() {
+    let code = indoc! {r#"
+        <?php
+
+        final class User
+        {
+            #[ORM\Id]
+            private int $id;
+
+            public function __construct(#[SensitiveParameter] string $password) {}
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        final class User
+        {
+            #[ORM\Id] private int $id;
+
+            public function __construct(
+                #[SensitiveParameter]
+                string $password,
+            ) {
+            }
+        }
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings { inline_parameter_attributes: false, inline_property_attributes: true, ..Default::default() },
+    );
+}
+
+#[test]
+pub fn test_attribute_grouping() {
+    let code = indoc! {r#"
+        <?php
+
+        #[Foo, Bar]
+        #[Baz]
+        final class A {}
+
+        #[Foo] // comment
+        #[Bar]
+        final class B {}
+
+        #[Foo, /* bar */ Bar]
+        #[Baz]
+        final class C {}
+    "#};
+
+    let separate = indoc! {r#"
+        <?php
+
+        #[Foo]
+        #[Bar]
+        #[Baz]
+        final class A
+        {
+        }
+
+        #[Foo] // comment
+        #[Bar]
+        final class B
+        {
+        }
+
+        #[Foo]
+        #[/* bar */ Bar]
+        #[Baz]
+        final class C
+        {
+        }
+    "#};
+
+    let combine = indoc! {r#"
+        <?php
+
+        #[Foo, Bar, Baz]
+        final class A
+        {
+        }
+
+        #[
+            Foo, // comment
+            Bar,
+        ]
+        final class B
+        {
+        }
+
+        #[Foo, /* bar */ Bar, Baz]
+        final class C
+        {
+        }
+    "#};
+
+    test_format(
+        code,
+        separate,
+        FormatSettings { attribute_grouping: AttributeGrouping::Separate, ..Default::default() },
+    );
+    test_format(code, combine, FormatSettings { attribute_grouping: AttributeGrouping::Combine, ..Default::default() });
+}
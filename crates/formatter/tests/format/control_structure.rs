@@ -1,5 +1,6 @@
 use indoc::indoc;
 
+use mago_formatter::settings::ElseIfStyle;
 use mago_formatter::settings::FormatSettings;
 
 use crate::test_format;
@@ -177,3 +178,51 @@ pub fn test_inline_for_no_indent() {
 
     test_format(code, expected, FormatSettings::default())
 }
+
+#[test]
+pub fn test_else_if_style() {
+    let else_if = indoc! {r#"
+        <?php
+
+        if ($a) {
+            a();
+        } else if ($b) {
+            b();
+        } else if ($c) {
+            c();
+        } else {
+            d();
+        }
+
+        if ($a) {
+            a();
+        } else /* keep */ if ($b) {
+            b();
+        }
+    "#};
+
+    let elseif = indoc! {r#"
+        <?php
+
+        if ($a) {
+            a();
+        } elseif ($b) {
+            b();
+        } elseif ($c) {
+            c();
+        } else {
+            d();
+        }
+
+        if ($a) {
+            a();
+        } else /* keep */ if ($b) {
+            b();
+        }
+    "#};
+
+    test_format(else_if, elseif, FormatSettings { else_if_style: ElseIfStyle::ElseIf, ..Default::default() });
+    test_format(elseif, else_if, FormatSettings { else_if_style: ElseIfStyle::ElseSpaceIf, ..Default::default() });
+    test_format(else_if, else_if, FormatSettings::default());
+    test_format(elseif, elseif, FormatSettings::default());
+}
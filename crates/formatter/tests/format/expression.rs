@@ -1,6 +1,10 @@
 use indoc::indoc;
 
+use mago_formatter::settings::CasingStyle;
+use mago_formatter::settings::ChainIndent;
+use mago_formatter::settings::ChainIndentStyle;
 use mago_formatter::settings::FormatSettings;
+use mago_formatter::settings::MethodChainBreakingStyle;
 
 use crate::test_format;
 
@@ -112,3 +116,492 @@ pub fn test_format_expressions() {
 
     test_format(code, expected, FormatSettings::default())
 }
+
+#[test]
+pub fn test_inline_simple_closures() {
+    let code = indoc! {r#"
+        <?php
+
+        $doubled = array_map(function ($x) { return $x * 2; }, $items);
+        $collection->each(function ($item) { $item->save(); });
+        $incremented = array_map(function ($x) { $x++; return $x; }, $items);
+        $doubled = array_map(function ($x) { return $x * 2; }, $itemsWithAVeryLongNameSoThatTheArgumentsDoNotFitOnASingleLineAnymore);
+    "#};
+
+    let expected_inline = indoc! {r#"
+        <?php
+
+        $doubled = array_map(function ($x) { return $x * 2; }, $items);
+        $collection->each(function ($item) { $item->save(); });
+        $incremented = array_map(function ($x) {
+            $x++;
+            return $x;
+        }, $items);
+        $doubled = array_map(function ($x) {
+            return $x * 2;
+        }, $itemsWithAVeryLongNameSoThatTheArgumentsDoNotFitOnASingleLineAnymore);
+    "#};
+
+    test_format(code, expected_inline, FormatSettings { inline_simple_closures: true, ..Default::default() });
+
+    let expected_expanded = indoc! {r#"
+        <?php
+
+        $doubled = array_map(function ($x) {
+            return $x * 2;
+        }, $items);
+        $collection->each(function ($item) {
+            $item->save();
+        });
+        $incremented = array_map(function ($x) {
+            $x++;
+            return $x;
+        }, $items);
+        $doubled = array_map(function ($x) {
+            return $x * 2;
+        }, $itemsWithAVeryLongNameSoThatTheArgumentsDoNotFitOnASingleLineAnymore);
+    "#};
+
+    test_format(code, expected_expanded, FormatSettings::default());
+}
+
+#[test]
+pub fn test_chain_indent() {
+    let code = indoc! {r#"
+        <?php
+
+        $user = $builder->select('*')->from('users')->where('id', $id)->first();
+    "#};
+
+    let expected_spaces = indoc! {r#"
+        <?php
+
+        $user = $builder->select('*')
+              ->from('users')
+              ->where('id', $id)
+              ->first();
+    "#};
+
+    test_format(
+        code,
+        expected_spaces,
+        FormatSettings {
+            chain_indent: ChainIndent::Spaces(6),
+            method_chain_breaking_style: MethodChainBreakingStyle::SameLine,
+            ..Default::default()
+        },
+    );
+
+    let expected_aligned = indoc! {r#"
+        <?php
+
+        $user = $builder->select('*')
+                        ->from('users')
+                        ->where('id', $id)
+                        ->first();
+    "#};
+
+    test_format(
+        code,
+        expected_aligned,
+        FormatSettings {
+            chain_indent: ChainIndent::Style(ChainIndentStyle::Align),
+            method_chain_breaking_style: MethodChainBreakingStyle::SameLine,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+pub fn test_casts() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = ( INTEGER ) $a;
+        $b = (boolean)$b;
+        $c = (double) $c;
+        $d = (real) $d;
+        $e = (binary) $e;
+        $f = (Array) $f;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = (int) $a;
+        $b = (bool) $b;
+        $c = (float) $c;
+        $d = (float) $d;
+        $e = (string) $e;
+        $f = (array) $f;
+    "#};
+
+    test_format(code, expected, FormatSettings::default());
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = ( integer )$a;
+        $b = (boolean)$b;
+        $c = (double)$c;
+        $d = (real)$d;
+        $e = (binary)$e;
+        $f = (array)$f;
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings { canonical_casts: false, space_after_cast: false, ..Default::default() },
+    );
+}
+
+#[test]
+pub fn test_keyword_case() {
+    let code = indoc! {r#"
+        <?php
+
+        IF (TRUE) {
+            $a = Array(1);
+        } ELSEIF (NULL) {
+            $b = FALSE;
+        }
+
+        function f(INT $a, String $b): Array|NULL
+        {
+            RETURN $a;
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        if (true) {
+            $a = array(1);
+        } elseif (null) {
+            $b = false;
+        }
+
+        function f(int $a, string $b): array|null
+        {
+            return $a;
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings::default());
+
+    let expected = indoc! {r#"
+        <?php
+
+        if (TRUE) {
+            $a = array(1);
+        } elseif (NULL) {
+            $b = FALSE;
+        }
+
+        function f(int $a, string $b): array|null
+        {
+            return $a;
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { constant_case: Some(CasingStyle::Uppercase), ..Default::default() });
+
+    let expected = indoc! {r#"
+        <?PHP
+
+        IF (TRUE) {
+            $a = ARRAY(1);
+        } ELSEIF (NULL) {
+            $b = FALSE;
+        }
+
+        FUNCTION f(int $a, string $b): array|null
+        {
+            RETURN $a;
+        }
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings {
+            keyword_case: CasingStyle::Uppercase,
+            type_keyword_case: Some(CasingStyle::Lowercase),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+pub fn test_array_argument_hugging() {
+    let code = indoc! {r#"
+        <?php
+
+        $this->assertSame(['alpha' => 1, 'beta' => 2, 'gamma' => 3, 'delta' => 4, 'epsilon' => 5, 'zeta' => 6, 'eta' => 7], $result);
+        $this->assertSame($result, ['alpha' => 1, 'beta' => 2, 'gamma' => 3, 'delta' => 4, 'epsilon' => 5, 'zeta' => 6, 'eta' => 7]);
+        $this->assertSame(['alpha' => 1, 'beta' => 2], $result);
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $this->assertSame([
+            'alpha' => 1,
+            'beta' => 2,
+            'gamma' => 3,
+            'delta' => 4,
+            'epsilon' => 5,
+            'zeta' => 6,
+            'eta' => 7,
+        ], $result);
+        $this->assertSame($result, [
+            'alpha' => 1,
+            'beta' => 2,
+            'gamma' => 3,
+            'delta' => 4,
+            'epsilon' => 5,
+            'zeta' => 6,
+            'eta' => 7,
+        ]);
+        $this->assertSame(['alpha' => 1, 'beta' => 2], $result);
+    "#};
+
+    test_format(code, expected, FormatSettings::default());
+}
+
+#[test]
+pub fn test_array_multiline_threshold() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = [1, 2];
+        $b = [1, 2, 3];
+        $c = array(1, 2, 3);
+        [$x, $y, $z] = $b;
+        foreach ($items as [$x, $y, $z]) {
+            foo(['a' => 1, 'b' => 2, 'c' => 3], $d);
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = [1, 2];
+        $b = [
+            1,
+            2,
+            3,
+        ];
+        $c = array(
+            1,
+            2,
+            3,
+        );
+        [$x, $y, $z] = $b;
+        foreach ($items as [$x, $y, $z]) {
+            foo([
+                'a' => 1,
+                'b' => 2,
+                'c' => 3,
+            ], $d);
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { array_multiline_threshold: Some(2), ..Default::default() });
+    test_format(code, code, FormatSettings::default());
+}
+
+#[test]
+pub fn test_conditional_chains() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = $x ? 1 : 2;
+        $b = $someVeryLongConditionVariableName ? $someVeryLongValueWhenTheConditionIsTrue : $someOtherLongValueWhenTheConditionIsFalse;
+        $c = $someVeryLongVariableName ?? $anotherVeryLongVariableName ?? $yetAnotherVeryLongVariableName ?? $defaultValue ?? null;
+        $d = $someVeryLongConditionVariableName ?: $someVeryLongValueWhenTheConditionIsFalsy ?: $someOtherLongValueWhenAllOfThemAreFalsy;
+        $e = $a ?? $b ?? $c;
+
+        if ($someVeryLongVariableName ?? $anotherVeryLongVariableName ?? $yetAnotherVeryLongVariableName ?? $default ?? $fallback) {
+        }
+
+        return $someVeryLongVariableName ?? $anotherVeryLongVariableName ?? $yetAnotherVeryLongVariableName ?? $defaultFallbackValue;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = $x ? 1 : 2;
+        $b = $someVeryLongConditionVariableName
+            ? $someVeryLongValueWhenTheConditionIsTrue
+            : $someOtherLongValueWhenTheConditionIsFalse;
+        $c = $someVeryLongVariableName
+            ?? $anotherVeryLongVariableName
+            ?? $yetAnotherVeryLongVariableName
+            ?? $defaultValue
+            ?? null;
+        $d = $someVeryLongConditionVariableName
+            ?: $someVeryLongValueWhenTheConditionIsFalsy
+            ?: $someOtherLongValueWhenAllOfThemAreFalsy;
+        $e = $a ?? $b ?? $c;
+
+        if (
+            $someVeryLongVariableName
+            ?? $anotherVeryLongVariableName
+            ?? $yetAnotherVeryLongVariableName
+            ?? $default
+            ?? $fallback
+        ) {
+        }
+
+        return $someVeryLongVariableName
+            ?? $anotherVeryLongVariableName
+            ?? $yetAnotherVeryLongVariableName
+            ?? $defaultFallbackValue;
+    "#};
+
+    test_format(code, expected, FormatSettings::default());
+}
+
+#[test]
+pub fn test_parenthesize_nested_ternaries() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = $x ? $y ? 1 : 2 : 3;
+        $b = $x ? 1 : ($y ? 2 : 3);
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = $x ? ($y ? 1 : 2) : 3;
+        $b = $x ? 1 : ($y ? 2 : 3);
+    "#};
+
+    test_format(code, expected, FormatSettings::default());
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = $x ? $y ? 1 : 2 : 3;
+        $b = $x ? 1 : ($y ? 2 : 3);
+    "#};
+
+    test_format(code, expected, FormatSettings { parenthesize_nested_ternaries: false, ..FormatSettings::default() });
+}
+
+#[test]
+pub fn test_match_arms() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = match ($value) { 1 => 'one', default => 'other' };
+        $b = match ($value) {
+            1 => 'one',
+            2, 3 => 'two or three',
+            default => 'other',
+        };
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = match ($value) {
+            1 => 'one',
+            default => 'other',
+        };
+        $b = match ($value) {
+            1 => 'one',
+            2, 3 => 'two or three',
+            default => 'other',
+        };
+    "#};
+
+    test_format(code, expected, FormatSettings::default());
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = match ($value) { 1 => 'one', default => 'other' };
+        $b = match ($value) {
+            1       => 'one',
+            2, 3    => 'two or three',
+            default => 'other'
+        };
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings {
+            match_multiline_threshold: 2,
+            align_match_arrows: true,
+            match_trailing_comma: Some(false),
+            ..FormatSettings::default()
+        },
+    );
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = match ($value) {
+            1 => 'one',
+            default => 'other',
+        };
+        $b = match ($value) {
+            1 => 'one',
+            2,
+            3,
+                => 'two or three',
+            default => 'other',
+        };
+    "#};
+
+    test_format(code, expected, FormatSettings { break_match_arm_conditions: true, ..FormatSettings::default() });
+}
+
+#[test]
+pub fn test_anonymous_class_arguments() {
+    let code = indoc! {r#"
+        <?php
+
+        register('name', new class implements Foo {
+            public function bar(): void {}
+        });
+
+        $foo = new Foo(new class($dependency, $anotherDependency) extends BaseHandler implements HandlerInterface, LoggerAwareInterface {
+            public function handle(): void {}
+        });
+
+        $x = new class {
+            public $a;
+        };
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        register('name', new class implements Foo {
+            public function bar(): void
+            {
+            }
+        });
+
+        $foo = new Foo(new class($dependency, $anotherDependency) extends BaseHandler implements
+            HandlerInterface,
+            LoggerAwareInterface
+        {
+            public function handle(): void
+            {
+            }
+        });
+
+        $x = new class {
+            public $a;
+        };
+    "#};
+
+    test_format(code, expected, FormatSettings::default());
+}
@@ -0,0 +1,51 @@
+use indoc::indoc;
+
+use mago_formatter::settings::FormatSettings;
+
+use crate::test_format;
+
+#[test]
+pub fn test_compound_hint_wrapping() {
+    let code = indoc! {r#"
+        <?php
+
+        function foo(FirstVeryLongInterfaceName|SecondVeryLongInterfaceName|ThirdVeryLongInterfaceName|FourthInterfaceName|FifthInterfaceName|null $value): void
+        {
+        }
+
+        function bar(): FirstVeryLongInterfaceName&SecondVeryLongInterfaceName&ThirdVeryLongInterfaceName&FourthInterfaceName&Countable
+        {
+        }
+
+        function baz(int|string|null $value): A&B
+        {
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        function foo(
+            FirstVeryLongInterfaceName
+                | SecondVeryLongInterfaceName
+                | ThirdVeryLongInterfaceName
+                | FourthInterfaceName
+                | FifthInterfaceName
+                | null $value,
+        ): void {
+        }
+
+        function bar(): FirstVeryLongInterfaceName
+            & SecondVeryLongInterfaceName
+            & ThirdVeryLongInterfaceName
+            & FourthInterfaceName
+            & Countable {
+        }
+
+        function baz(int|string|null $value): A&B
+        {
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings::default());
+}
@@ -1,13 +1,17 @@
 use indoc::indoc;
 
+use mago_formatter::settings::EndOfLine;
 use mago_formatter::settings::FormatSettings;
 
 use crate::test_format;
 
 pub mod assignment;
+pub mod attribute;
 pub mod binaryish;
 pub mod control_structure;
 pub mod expression;
+pub mod hint;
+pub mod nesting;
 pub mod string;
 
 #[test]
@@ -62,6 +66,22 @@ pub fn test_inline_html() {
     test_format(code, expected, FormatSettings::default())
 }
 
+#[test]
+pub fn test_line_endings_are_preserved() {
+    let code = "<?php\r\n// comment\r\nfunction  foo() {\r\n  /**\r\n   * doc\r\n   */\r\n  return 1;\r\n}\r\n\r\n\r\necho   foo();\r\n";
+    let expected = "<?php\r\n// comment\r\nfunction foo()\r\n{\r\n    /**\r\n     * doc\r\n     */\r\n    return 1;\r\n}\r\n\r\necho foo();\r\n";
+
+    test_format(code, expected, FormatSettings::default())
+}
+
+#[test]
+pub fn test_line_endings_are_forced() {
+    let code = "<?php\r\n\r\necho   1;\r\n";
+    let expected = "<?php\n\necho 1;\n";
+
+    test_format(code, expected, FormatSettings { end_of_line: EndOfLine::Lf, ..FormatSettings::default() })
+}
+
 #[test]
 pub fn test_php_85_constant_attributes() {
     let code = indoc! {r#"
@@ -0,0 +1,33 @@
+use mago_formatter::settings::FormatSettings;
+use mago_interner::ThreadedInterner;
+use mago_parser::MAXIMUM_NESTING_DEPTH;
+use mago_parser::parse_source;
+use mago_source::Source;
+
+/// Formats deeply nested code on the current thread, which uses the default stack size of test threads.
+#[test]
+pub fn test_format_deeply_nested_code() {
+    let depth = MAXIMUM_NESTING_DEPTH - 6;
+    let codes = [
+        format!("<?php\n\n$a = {}{};\n", "[".repeat(depth), "]".repeat(depth)),
+        format!("<?php\n\n$a = {}1{};\n", "(".repeat(depth), ")".repeat(depth)),
+        format!("<?php\n\n$a = {}1{};\n", "f(".repeat(depth), ")".repeat(depth)),
+        format!("<?php\n\n$a = {}2;\n", "$b ? 1 : ".repeat(depth)),
+        format!("<?php\n\n$a = {}$b;\n", "!".repeat(depth)),
+        format!("<?php\n\n$a = {}$b;\n", "$b . ".repeat(depth)),
+        format!("<?php\n\n$a = {}$b;\n", "$b && ".repeat(depth)),
+        format!("<?php\n\n$a = $b{};\n", "->c()".repeat(depth)),
+        format!("<?php\n\n$a = $b{};\n", "[0]".repeat(depth)),
+        format!("<?php\n\n{}{}\n", "if ($a) {\n".repeat(depth / 2), "}\n".repeat(depth / 2)),
+    ];
+
+    let interner = ThreadedInterner::new();
+    for code in codes {
+        let source = Source::standalone(&interner, "code.php", &code);
+        let (program, error) = parse_source(&interner, &source);
+        assert_eq!(error, None, "Error parsing code");
+
+        let formatted = mago_formatter::format(&interner, &source, &program, FormatSettings::default());
+        assert!(formatted.starts_with("<?php"), "Formatted code is missing the opening tag");
+    }
+}
@@ -6,6 +6,7 @@ use mago_source::Source;
 pub mod comment;
 pub mod format;
 pub mod parens;
+pub mod range;
 
 /// Test that the given code is formatted to the expected result.
 ///
@@ -0,0 +1,80 @@
+use indoc::indoc;
+
+use mago_formatter::format_range;
+use mago_formatter::settings::FormatSettings;
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source;
+use mago_source::Source;
+
+/// Formats the lines touched by the given range, and returns the resulting code.
+fn format_code_range(code: &str, range: std::ops::Range<usize>) -> String {
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "code.php", code);
+    let (program, error) = parse_source(&interner, &source);
+    assert_eq!(error, None, "Error parsing code");
+
+    format_range(&interner, &source, &program, FormatSettings::default(), range).execute(code).get_fixed()
+}
+
+#[test]
+pub fn test_format_range_only_changes_the_given_lines() {
+    let code = indoc! {r#"
+        <?php
+
+        $a   =   1;
+        $b   =   2;
+        $c   =   3;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a   =   1;
+        $b = 2;
+        $c   =   3;
+    "#};
+
+    let start = code.find("$b").unwrap();
+
+    pretty_assertions::assert_eq!(expected, format_code_range(code, start..start + 11));
+}
+
+#[test]
+pub fn test_format_range_spanning_multiple_lines() {
+    let code = indoc! {r#"
+        <?php
+
+        function foo() {
+        return   1;
+        }
+
+        $a   =   1;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        function foo()
+        {
+            return 1;
+        }
+
+        $a   =   1;
+    "#};
+
+    let start = code.find("function").unwrap();
+    let end = code.find("}").unwrap() + 1;
+
+    pretty_assertions::assert_eq!(expected, format_code_range(code, start..end));
+}
+
+#[test]
+pub fn test_format_range_of_formatted_code() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = 1;
+    "#};
+
+    pretty_assertions::assert_eq!(code, format_code_range(code, 0..code.len()));
+}
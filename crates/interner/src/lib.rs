@@ -188,6 +188,29 @@ impl Interner {
     }
 }
 
+/// A snapshot of the strings interned by a [`ThreadedInterner`] at some point in time.
+///
+/// A snapshot is taken with [`ThreadedInterner::snapshot`], and used to create a new interner
+/// without the strings interned since then, with [`ThreadedInterner::reset`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct InternerSnapshot {
+    length: usize,
+}
+
+impl InternerSnapshot {
+    /// Returns the number of strings interned when the snapshot was taken.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if no strings were interned when the snapshot was taken.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
 /// A thread-safe interner, allowing multiple threads to concurrently intern strings.
 #[derive(Debug, Clone)]
 pub struct ThreadedInterner {
@@ -302,6 +325,54 @@ impl ThreadedInterner {
         )
     }
 
+    /// Takes a snapshot of the strings interned so far.
+    ///
+    /// Strings are never removed from an interner, so long-lived processes, such as the language server,
+    /// can take a snapshot once the strings shared by every run are interned, e.g. after reflecting the
+    /// stubs, and [`reset`] the interner to it between runs.
+    ///
+    /// [`reset`]: ThreadedInterner::reset
+    #[inline]
+    pub fn snapshot(&self) -> InternerSnapshot {
+        InternerSnapshot { length: self.rodeo.len() }
+    }
+
+    /// Creates a new interner holding only the strings interned before the given snapshot was taken.
+    ///
+    /// The strings keep their identifiers, so identifiers obtained before the snapshot was taken remain
+    /// valid in the new interner, while identifiers obtained after it must not be used with it.
+    ///
+    /// The interner is shared between its clones, so the memory of the discarded strings is only freed
+    /// once every clone of this interner is dropped, along with anything else still holding on to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - A snapshot taken from this interner, or from the interner it was reset from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the snapshot holds more strings than this interner, which happens when it was taken from
+    /// another interner.
+    #[inline]
+    pub fn reset(&self, snapshot: &InternerSnapshot) -> ThreadedInterner {
+        assert!(
+            snapshot.length <= self.rodeo.len(),
+            "the snapshot holds {} strings, but the interner only holds {}; it was taken from another interner",
+            snapshot.length,
+            self.rodeo.len()
+        );
+
+        let rodeo = ThreadedRodeo::with_capacity(lasso::Capacity::for_strings(snapshot.length));
+        for index in 0..snapshot.length {
+            let identifier = StringIdentifier(index + 1);
+
+            // Interning the strings in the order of their identifiers yields the same identifiers.
+            rodeo.get_or_intern(self.lookup(&identifier));
+        }
+
+        Self { rodeo: Arc::new(rodeo) }
+    }
+
     /// Returns all interned strings and their identifiers as a hashmap.
     #[inline]
     pub fn all(&self) -> HashSet<(StringIdentifier, &str)> {
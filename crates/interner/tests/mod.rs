@@ -82,3 +82,38 @@ fn test_interner_multithreaded() {
         assert_eq!(first_set, set);
     }
 }
+
+#[test]
+fn test_interner_reset() {
+    let interner = ThreadedInterner::new();
+
+    let hello = interner.intern("hello");
+    let world = interner.intern("world");
+    let snapshot = interner.snapshot();
+    assert_eq!(snapshot.len(), 2);
+
+    interner.intern("edited");
+    interner.intern("again");
+    assert_eq!(interner.len(), 4);
+
+    let interner = interner.reset(&snapshot);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.lookup(&hello), "hello");
+    assert_eq!(interner.lookup(&world), "world");
+    assert_eq!(interner.intern("world"), world);
+
+    // A snapshot can be reused with the reset interner, for every subsequent run.
+    interner.intern("edited");
+    let interner = interner.reset(&snapshot);
+    assert_eq!(interner.len(), 2);
+    assert!(interner.all().iter().all(|(_, string)| *string != "edited"));
+}
+
+#[test]
+#[should_panic]
+fn test_interner_reset_with_foreign_snapshot() {
+    let interner = ThreadedInterner::new();
+    interner.intern("hello");
+
+    ThreadedInterner::new().reset(&interner.snapshot());
+}
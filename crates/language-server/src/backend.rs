@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use parking_lot::RwLock;
+use tower_lsp::Client;
+use tower_lsp::LanguageServer;
+use tower_lsp::jsonrpc::Result;
+use tower_lsp::lsp_types::*;
+
+use crate::Workspace;
+use crate::code_action::get_code_actions;
+use crate::completion::get_completions;
+use crate::definition::get_definition;
+use crate::definition::get_references;
+use crate::diagnostic::get_diagnostics;
+use crate::document::Document;
+use crate::document_symbol::get_document_symbols;
+use crate::formatting::get_on_type_edits;
+use crate::hover::get_hover;
+use crate::semantic_token::get_legend;
+use crate::semantic_token::get_semantic_tokens;
+use crate::workspace_symbol::get_workspace_symbols;
+
+/// The number of strings that can be interned for open documents since the interner was last reset,
+/// before it is reset again.
+const MAXIMUM_DOCUMENT_STRINGS: usize = 100_000;
+
+/// The language server backend, handling requests and notifications sent by the client.
+#[derive(Debug)]
+pub struct Backend {
+    client: Client,
+    workspace: RwLock<Workspace>,
+    documents: RwLock<HashMap<Url, Document>>,
+    /// The number of strings held by the interner after it was last reset, and the open documents analyzed again.
+    retained_strings: AtomicUsize,
+}
+
+impl Backend {
+    pub fn new(client: Client, workspace: Workspace) -> Self {
+        let retained_strings = AtomicUsize::new(workspace.interner.len());
+
+        Self { client, workspace: RwLock::new(workspace), documents: RwLock::new(HashMap::new()), retained_strings }
+    }
+
+    /// Analyzes the given content of a document, and publishes the resulting diagnostics.
+    async fn update(&self, uri: Url, version: i32, content: String) {
+        let diagnostics = {
+            let workspace = self.workspace.read();
+            let document = Document::new(&workspace, uri.clone(), content);
+            let diagnostics = get_diagnostics(&document);
+
+            self.documents.write().insert(uri.clone(), document);
+
+            diagnostics
+        };
+
+        self.reclaim_strings(false);
+        self.client.publish_diagnostics(uri, diagnostics, Some(version)).await;
+    }
+
+    /// Resets the interner of the workspace, dropping the strings interned for documents that are no
+    /// longer needed, once too many were interned since it was last reset, or whenever `force` is set.
+    ///
+    /// Documents are analyzed again every time they change, interning every new name, literal, and
+    /// partially typed word, which would otherwise grow the interner without bound.
+    fn reclaim_strings(&self, force: bool) {
+        let retained_strings = self.retained_strings.load(Ordering::Relaxed);
+        let interned_strings = self.workspace.read().interner.len().saturating_sub(retained_strings);
+        if interned_strings == 0 || (!force && interned_strings <= MAXIMUM_DOCUMENT_STRINGS) {
+            return;
+        }
+
+        let mut workspace = self.workspace.write();
+        let mut documents = self.documents.write();
+
+        workspace.reset_interner();
+        // The open documents hold identifiers of the previous interner, so they are analyzed again.
+        for document in documents.values_mut() {
+            *document = Document::new(&workspace, document.uri.clone(), std::mem::take(&mut document.content));
+        }
+
+        let strings = workspace.interner.len();
+        self.retained_strings.store(strings, Ordering::Relaxed);
+
+        tracing::debug!(
+            "Reset the interner, retaining {} of {} strings.",
+            strings,
+            retained_strings + interned_strings
+        );
+    }
+}
+
+#[tower_lsp::async_trait]
+impl LanguageServer for Backend {
+    async fn initialize(&self, _: InitializeParams) -> Result<InitializeResult> {
+        Ok(InitializeResult {
+            capabilities: ServerCapabilities {
+                text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+                    code_action_kinds: Some(vec![
+                        CodeActionKind::QUICKFIX,
+                        CodeActionKind::SOURCE_FIX_ALL,
+                        CodeActionKind::SOURCE_ORGANIZE_IMPORTS,
+                    ]),
+                    ..Default::default()
+                })),
+                definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+                    first_trigger_character: ";".to_string(),
+                    more_trigger_character: Some(vec!["}".to_string(), "\n".to_string()]),
+                }),
+                semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
+                    SemanticTokensOptions {
+                        legend: get_legend(),
+                        range: Some(true),
+                        full: Some(SemanticTokensFullOptions::Bool(true)),
+                        ..Default::default()
+                    },
+                )),
+                completion_provider: self.workspace.read().completion.then(|| CompletionOptions {
+                    trigger_characters: Some(
+                        ["$", ">", ":", "\\"].into_iter().map(|character| character.to_string()).collect(),
+                    ),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            server_info: Some(ServerInfo {
+                name: "mago".to_string(),
+                version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            }),
+        })
+    }
+
+    async fn initialized(&self, _: InitializedParams) {
+        tracing::info!("Language server initialized for workspace `{}`.", self.workspace.read().root.display());
+    }
+
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let document = params.text_document;
+
+        self.update(document.uri, document.version, document.text).await;
+    }
+
+    async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        // The server only supports full synchronization, so the last change contains the whole content.
+        let Some(change) = params.content_changes.into_iter().last() else {
+            return;
+        };
+
+        self.update(params.text_document.uri, params.text_document.version, change.text).await;
+    }
+
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = params.text_document.uri;
+
+        let documents = {
+            let mut documents = self.documents.write();
+            documents.remove(&uri);
+            documents.len()
+        };
+
+        // Once every document is closed, none of the strings interned for documents are needed anymore.
+        self.reclaim_strings(documents == 0);
+        self.client.publish_diagnostics(uri, vec![], None).await;
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let documents = self.documents.read();
+        let Some(document) = documents.get(&params.text_document.uri) else {
+            return Ok(None);
+        };
+
+        let actions = get_code_actions(document, params.range, params.context.only.as_deref());
+
+        Ok(if actions.is_empty() { None } else { Some(actions) })
+    }
+
+    async fn goto_definition(&self, params: GotoDefinitionParams) -> Result<Option<GotoDefinitionResponse>> {
+        let position = params.text_document_position_params;
+        let workspace = self.workspace.read();
+        let documents = self.documents.read();
+        let Some(document) = documents.get(&position.text_document.uri) else {
+            return Ok(None);
+        };
+
+        Ok(get_definition(&workspace, document, position.position).map(GotoDefinitionResponse::Scalar))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let position = params.text_document_position;
+        let workspace = self.workspace.read();
+        let documents = self.documents.read();
+        let Some(document) = documents.get(&position.text_document.uri) else {
+            return Ok(None);
+        };
+
+        let locations =
+            get_references(&workspace, &documents, document, position.position, params.context.include_declaration);
+
+        Ok(Some(locations))
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params;
+        let workspace = self.workspace.read();
+        let documents = self.documents.read();
+        let Some(document) = documents.get(&position.text_document.uri) else {
+            return Ok(None);
+        };
+
+        Ok(get_hover(&workspace, document, position.position))
+    }
+
+    async fn document_symbol(&self, params: DocumentSymbolParams) -> Result<Option<DocumentSymbolResponse>> {
+        let workspace = self.workspace.read();
+        let documents = self.documents.read();
+        let Some(document) = documents.get(&params.text_document.uri) else {
+            return Ok(None);
+        };
+
+        Ok(Some(DocumentSymbolResponse::Nested(get_document_symbols(&workspace.interner, document))))
+    }
+
+    async fn symbol(&self, params: WorkspaceSymbolParams) -> Result<Option<Vec<SymbolInformation>>> {
+        let workspace = self.workspace.read();
+        let documents = self.documents.read();
+
+        Ok(Some(get_workspace_symbols(&workspace, documents.values(), &params.query)))
+    }
+
+    async fn on_type_formatting(&self, params: DocumentOnTypeFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        let position = params.text_document_position;
+        let workspace = self.workspace.read();
+        let documents = self.documents.read();
+        let Some(document) = documents.get(&position.text_document.uri) else {
+            return Ok(None);
+        };
+
+        Ok(get_on_type_edits(&workspace, document, position.position, &params.ch))
+    }
+
+    async fn semantic_tokens_full(&self, params: SemanticTokensParams) -> Result<Option<SemanticTokensResult>> {
+        let workspace = self.workspace.read();
+        let documents = self.documents.read();
+        let Some(document) = documents.get(&params.text_document.uri) else {
+            return Ok(None);
+        };
+
+        let data = get_semantic_tokens(&workspace, document, None);
+
+        Ok(Some(SemanticTokensResult::Tokens(SemanticTokens { result_id: None, data })))
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> Result<Option<SemanticTokensRangeResult>> {
+        let workspace = self.workspace.read();
+        let documents = self.documents.read();
+        let Some(document) = documents.get(&params.text_document.uri) else {
+            return Ok(None);
+        };
+
+        let data = get_semantic_tokens(&workspace, document, Some(params.range));
+
+        Ok(Some(SemanticTokensRangeResult::Tokens(SemanticTokens { result_id: None, data })))
+    }
+
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let workspace = self.workspace.read();
+        if !workspace.completion {
+            return Ok(None);
+        }
+
+        let position = params.text_document_position;
+        let documents = self.documents.read();
+        let Some(document) = documents.get(&position.text_document.uri) else {
+            return Ok(None);
+        };
+
+        Ok(get_completions(&workspace, document, position.position))
+    }
+}
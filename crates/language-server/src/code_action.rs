@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+
+use tower_lsp::lsp_types::CodeAction;
+use tower_lsp::lsp_types::CodeActionKind;
+use tower_lsp::lsp_types::CodeActionOrCommand;
+use tower_lsp::lsp_types::Diagnostic;
+use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::WorkspaceEdit;
+
+use mago_ast::Sequence;
+use mago_ast::Statement;
+use mago_ast::Use;
+use mago_ast::UseItems;
+use mago_ast::UseType;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Issue;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::diagnostic::to_diagnostic;
+use crate::document::Document;
+
+/// Returns the code actions available in the given range of a document.
+///
+/// # Parameters
+///
+/// - `document`: The document to compute the code actions for.
+/// - `range`: The range the actions are requested for, usually the cursor or the selection.
+/// - `only`: The kinds of actions requested by the client, if any.
+pub fn get_code_actions(
+    document: &Document,
+    range: Range,
+    only: Option<&[CodeActionKind]>,
+) -> Vec<CodeActionOrCommand> {
+    let is_requested = |kind: &CodeActionKind| match only {
+        Some(kinds) => kinds.iter().any(|requested| {
+            kind.as_str() == requested.as_str() || kind.as_str().starts_with(&format!("{}.", requested.as_str()))
+        }),
+        None => true,
+    };
+
+    let mut actions = vec![];
+
+    if is_requested(&CodeActionKind::QUICKFIX) {
+        actions.extend(get_quick_fixes(document, range));
+    }
+
+    if is_requested(&CodeActionKind::SOURCE_FIX_ALL) {
+        let plan =
+            get_plan(document, document.issues.iter()).to_minimum_safety_classification(SafetyClassification::Safe);
+
+        if let Some(action) = create_action(document, "Fix all safe issues", CodeActionKind::SOURCE_FIX_ALL, &plan) {
+            actions.push(action);
+        }
+    }
+
+    if is_requested(&CodeActionKind::SOURCE_ORGANIZE_IMPORTS) {
+        let plan = organize_imports(document);
+
+        if let Some(action) =
+            create_action(document, "Organize imports", CodeActionKind::SOURCE_ORGANIZE_IMPORTS, &plan)
+        {
+            actions.push(action);
+        }
+    }
+
+    actions.into_iter().map(CodeActionOrCommand::CodeAction).collect()
+}
+
+/// Returns the quick fixes of the issues intersecting the given range.
+///
+/// Each fixable issue gets its own action, and every rule with more than one fixable issue in the
+/// document gets an additional action fixing all of them at once.
+fn get_quick_fixes(document: &Document, range: Range) -> Vec<CodeAction> {
+    let start = document.get_offset(range.start);
+    let end = document.get_offset(range.end);
+
+    let mut actions = vec![];
+    let mut rules: Vec<&str> = vec![];
+    let mut fixable_issues: HashMap<&str, Vec<&Issue>> = HashMap::new();
+
+    for issue in document.issues.iter() {
+        if get_plan(document, [issue]).is_empty() {
+            continue;
+        }
+
+        if let Some(code) = issue.code.as_deref() {
+            fixable_issues.entry(code).or_default().push(issue);
+        }
+    }
+
+    for issue in document.issues.iter() {
+        let Some(span) = document.get_issue_span(issue) else {
+            continue;
+        };
+
+        if span.start.offset > end || span.end.offset < start {
+            continue;
+        }
+
+        let plan = get_plan(document, [issue]);
+        if plan.is_empty() {
+            continue;
+        }
+
+        let title = match get_safety_label(&plan) {
+            Some(label) => format!("Fix: {} ({})", issue.message, label),
+            None => format!("Fix: {}", issue.message),
+        };
+
+        if let Some(mut action) = create_action(document, title, CodeActionKind::QUICKFIX, &plan) {
+            action.diagnostics = to_diagnostic(document, issue).map(|diagnostic| vec![diagnostic]);
+            action.is_preferred = Some(plan.get_minimum_safety_classification() == SafetyClassification::Safe);
+
+            actions.push(action);
+        }
+
+        let Some(code) = issue.code.as_deref() else {
+            continue;
+        };
+
+        let Some(issues) = fixable_issues.get(code) else {
+            continue;
+        };
+
+        if issues.len() < 2 || rules.contains(&code) {
+            continue;
+        }
+
+        rules.push(code);
+
+        let plan = get_plan(document, issues.iter().copied());
+        let title = match get_safety_label(&plan) {
+            Some(label) => format!("Fix all `{}` issues in this file ({})", code, label),
+            None => format!("Fix all `{}` issues in this file", code),
+        };
+
+        if let Some(mut action) = create_action(document, title, CodeActionKind::QUICKFIX, &plan) {
+            action.diagnostics =
+                Some(issues.iter().filter_map(|issue| to_diagnostic(document, issue)).collect::<Vec<Diagnostic>>());
+
+            actions.push(action);
+        }
+    }
+
+    actions
+}
+
+/// Merges the suggestions of the given issues that apply to the given document into a single plan.
+fn get_plan<'a>(document: &Document, issues: impl IntoIterator<Item = &'a Issue>) -> FixPlan {
+    let mut plan = FixPlan::new();
+    for issue in issues {
+        for (source, suggestion) in issue.suggestions.iter() {
+            if *source == document.source.identifier {
+                plan.merge(suggestion.clone());
+            }
+        }
+    }
+
+    plan
+}
+
+/// Returns a label describing the safety of the given plan, or `None` if the plan is safe.
+fn get_safety_label(plan: &FixPlan) -> Option<&'static str> {
+    match plan.get_minimum_safety_classification() {
+        SafetyClassification::Safe => None,
+        SafetyClassification::PotentiallyUnsafe => Some("potentially unsafe"),
+        SafetyClassification::Unsafe => Some("unsafe"),
+    }
+}
+
+/// Creates a code action applying the given plan to the given document.
+///
+/// Returns `None` if the plan does not change the document.
+fn create_action(
+    document: &Document,
+    title: impl Into<String>,
+    kind: CodeActionKind,
+    plan: &FixPlan,
+) -> Option<CodeAction> {
+    if plan.is_empty() {
+        return None;
+    }
+
+    let edits = document.get_text_edits(plan);
+    if edits.is_empty() {
+        return None;
+    }
+
+    Some(CodeAction {
+        title: title.into(),
+        kind: Some(kind),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(document.uri.clone(), edits)])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+/// Returns a plan sorting and deduplicating every run of consecutive `use` statements in the document.
+///
+/// Class imports come first, followed by function and constant imports, each group sorted
+/// alphabetically. Runs containing comments are left untouched, as there is no way to tell which
+/// statement a comment belongs to.
+fn organize_imports(document: &Document) -> FixPlan {
+    let mut runs = vec![];
+    collect_use_runs(&document.program.statements, &mut runs);
+
+    let mut plan = FixPlan::new();
+    for run in runs {
+        let (Some(first), Some(last)) = (run.first(), run.last()) else {
+            continue;
+        };
+
+        let span = Span::between(first.span(), last.span());
+        if document
+            .program
+            .trivia
+            .iter()
+            .any(|trivia| trivia.kind.is_comment() && span.has_offset(trivia.span.start.offset))
+        {
+            continue;
+        }
+
+        let mut statements = run
+            .iter()
+            .map(|statement| (get_use_group(statement), document.get_text(statement.span())))
+            .collect::<Vec<_>>();
+        statements.sort_by_cached_key(|(group, text)| (*group, text.to_ascii_lowercase()));
+        statements.dedup_by(|(_, a), (_, b)| a == b);
+
+        let line_start = document.content[..span.start.offset].rfind('\n').map(|offset| offset + 1).unwrap_or(0);
+        let indentation = &document.content[line_start..span.start.offset];
+        let separator = if indentation.trim().is_empty() { format!("\n{indentation}") } else { "\n".to_string() };
+
+        let organized = statements.into_iter().map(|(_, text)| text).collect::<Vec<_>>().join(&separator);
+        if organized != document.get_text(span) {
+            plan.replace(span.to_range(), organized, SafetyClassification::Safe);
+        }
+    }
+
+    plan
+}
+
+/// Collects the runs of consecutive `use` statements in the given statements, and in the namespaces they contain.
+fn collect_use_runs<'a>(statements: &'a Sequence<Statement>, runs: &mut Vec<Vec<&'a Use>>) {
+    let mut run = vec![];
+    for statement in statements.iter() {
+        if let Statement::Use(r#use) = statement {
+            run.push(r#use);
+
+            continue;
+        }
+
+        if !run.is_empty() {
+            runs.push(std::mem::take(&mut run));
+        }
+
+        if let Statement::Namespace(namespace) = statement {
+            collect_use_runs(namespace.statements(), runs);
+        }
+    }
+
+    if !run.is_empty() {
+        runs.push(run);
+    }
+}
+
+/// Returns the group a `use` statement is sorted into: classes, functions, then constants.
+fn get_use_group(r#use: &Use) -> u8 {
+    let r#type = match &r#use.items {
+        UseItems::Sequence(_) | UseItems::MixedList(_) => None,
+        UseItems::TypedSequence(sequence) => Some(&sequence.r#type),
+        UseItems::TypedList(list) => Some(&list.r#type),
+    };
+
+    match r#type {
+        None => 0,
+        Some(UseType::Function(_)) => 1,
+        Some(UseType::Const(_)) => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tower_lsp::lsp_types::Position;
+    use tower_lsp::lsp_types::TextEdit;
+
+    use crate::tests::workspace;
+
+    use super::*;
+
+    fn document(content: &str) -> Document {
+        crate::tests::document(&workspace(&[]), "index.php", content)
+    }
+
+    fn get_actions(document: &Document, range: Range, only: Option<&[CodeActionKind]>) -> Vec<CodeAction> {
+        get_code_actions(document, range, only)
+            .into_iter()
+            .filter_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) => Some(action),
+                CodeActionOrCommand::Command(_) => None,
+            })
+            .collect()
+    }
+
+    fn get_edits(action: &CodeAction) -> Vec<TextEdit> {
+        action
+            .edit
+            .as_ref()
+            .and_then(|edit| edit.changes.as_ref())
+            .and_then(|changes| changes.values().next())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_quick_fixes() {
+        let document = document("<?php\n\necho sizeof([]);\necho sizeof([1]);\n");
+        let cursor = Range::new(Position::new(2, 6), Position::new(2, 6));
+
+        let actions = get_actions(&document, cursor, Some(&[CodeActionKind::QUICKFIX]));
+        let titles = actions.iter().map(|action| action.title.as_str()).collect::<Vec<_>>();
+
+        assert!(titles.iter().any(|title| title.starts_with("Fix: ")));
+        assert!(titles.contains(&"Fix all `consistency/no-function-aliases` issues in this file"));
+
+        let fix_all = actions.iter().find(|action| action.title.starts_with("Fix all")).unwrap();
+
+        assert_eq!(get_edits(fix_all).len(), 2);
+    }
+
+    #[test]
+    fn test_quick_fixes_outside_of_range() {
+        let document = document("<?php\n\necho sizeof([]);\n");
+        let cursor = Range::new(Position::new(0, 0), Position::new(0, 0));
+
+        assert!(get_actions(&document, cursor, Some(&[CodeActionKind::QUICKFIX])).is_empty());
+    }
+
+    #[test]
+    fn test_organize_imports() {
+        let document = document(
+            "<?php\n\nnamespace App;\n\nuse function strlen;\nuse Foo\\Bar;\nuse const PHP_EOL;\nuse Foo\\Baz;\nuse Foo\\Bar;\n\necho strlen(PHP_EOL);\necho new Bar(), new Baz();\n",
+        );
+        let range = Range::new(Position::new(0, 0), Position::new(0, 0));
+
+        let actions = get_actions(&document, range, Some(&[CodeActionKind::SOURCE]));
+        let action =
+            actions.iter().find(|action| action.kind == Some(CodeActionKind::SOURCE_ORGANIZE_IMPORTS)).unwrap();
+
+        assert_eq!(
+            get_edits(action),
+            vec![TextEdit::new(
+                Range::new(Position::new(4, 0), Position::new(8, 12)),
+                "use Foo\\Bar;\nuse Foo\\Baz;\nuse function strlen;\nuse const PHP_EOL;".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_organize_imports_skips_runs_with_comments() {
+        let document = document("<?php\n\nuse Foo\\Baz;\n// Bar is needed for ...\nuse Foo\\Bar;\n");
+        let range = Range::new(Position::new(0, 0), Position::new(0, 0));
+
+        let actions = get_actions(&document, range, Some(&[CodeActionKind::SOURCE_ORGANIZE_IMPORTS]));
+
+        assert!(actions.is_empty());
+    }
+}
@@ -0,0 +1,666 @@
+use std::collections::HashSet;
+
+use tower_lsp::lsp_types::CompletionItem;
+use tower_lsp::lsp_types::CompletionItemKind;
+use tower_lsp::lsp_types::CompletionList;
+use tower_lsp::lsp_types::CompletionResponse;
+use tower_lsp::lsp_types::CompletionTextEdit;
+use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::TextEdit;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_project::module::Module;
+use mago_project::module::ModuleBuildOptions;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::class_like::member::ClassLikeMemberVisibilityReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_source::Source;
+use mago_span::HasSpan;
+
+use crate::Workspace;
+use crate::document::Document;
+use crate::inference::Inference;
+use crate::workspace_symbol::get_fuzzy_score;
+
+/// The maximum number of symbols suggested in a `use` statement.
+const MAXIMUM_IMPORT_COMPLETIONS: usize = 128;
+
+/// The code inserted at the cursor to turn an incomplete expression into one that parses.
+const REPAIRS: &[&str] = &["", "x", "x;"];
+
+/// What is being completed, as determined by the text preceding the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompletionContext {
+    /// A variable, e.g. `$us|`.
+    Variable,
+    /// An instance member, e.g. `$user->na|` or `$user?->na|`.
+    InstanceMember { separator_end: usize },
+    /// A static member, e.g. `User::fi|` or `self::$ins|`.
+    StaticMember { separator_end: usize },
+    /// The name imported by a `use` statement, e.g. `use App\Us|` or `use function App\gr|`.
+    Import(ImportKind),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportKind {
+    ClassLike,
+    Trait,
+    Function,
+    Constant,
+}
+
+/// Returns the completions at the given position of a document.
+///
+/// Completions are offered for variables in scope, for members after `->`, `?->`, and `::`, and for
+/// the names imported by `use` statements. The class of a member access receiver is inferred from native
+/// type hints, docblocks, and the assignments preceding the access.
+///
+/// As the code around the cursor is usually incomplete, the document is re-parsed with a placeholder
+/// inserted at the cursor, so that the receiver of the member being typed can be found.
+///
+/// # Parameters
+///
+/// - `workspace`: The workspace the document belongs to.
+/// - `document`: The document being edited.
+/// - `position`: The position of the cursor.
+pub fn get_completions(workspace: &Workspace, document: &Document, position: Position) -> Option<CompletionResponse> {
+    let offset = document.get_offset(position);
+    let text = &document.content[..offset];
+
+    let mut prefix_start = text
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_ascii_alphanumeric() || *c == '_' || *c == '\\' || !c.is_ascii())
+        .last()
+        .map_or(offset, |(index, _)| index);
+
+    if text[..prefix_start].ends_with('$') {
+        prefix_start -= 1;
+    }
+
+    let prefix = &text[prefix_start..];
+    let context = get_context(&text[..prefix_start], prefix)?;
+    let range = Range::new(document.get_position(prefix_start), position);
+
+    let (module, program) = parse_repaired(workspace, document, offset);
+    let inference = Inference::new(workspace, &module, &program);
+
+    let (is_incomplete, items) = match context {
+        CompletionContext::Variable => (false, get_variable_completions(&inference, prefix_start)),
+        CompletionContext::InstanceMember { separator_end } => {
+            (false, get_member_completions(&inference, separator_end, false)?)
+        }
+        CompletionContext::StaticMember { separator_end } => {
+            (false, get_member_completions(&inference, separator_end, true)?)
+        }
+        CompletionContext::Import(kind) => {
+            let kind = if kind == ImportKind::ClassLike && inference.get_enclosing_class(prefix_start).is_some() {
+                ImportKind::Trait
+            } else {
+                kind
+            };
+
+            get_import_completions(&inference, kind, prefix)
+        }
+    };
+
+    let items = items
+        .into_iter()
+        .map(|(label, kind, detail)| CompletionItem {
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(range, label.clone()))),
+            label,
+            kind: Some(kind),
+            detail,
+            ..Default::default()
+        })
+        .collect();
+
+    Some(CompletionResponse::List(CompletionList { is_incomplete, items }))
+}
+
+/// Determines what is being completed from the text preceding the prefix being typed.
+fn get_context(before: &str, prefix: &str) -> Option<CompletionContext> {
+    let trimmed = before.trim_end();
+    let is_member_prefix = !prefix.contains('\\');
+
+    if is_member_prefix && (trimmed.ends_with("->") || trimmed.ends_with("?->")) && !prefix.starts_with('$') {
+        return Some(CompletionContext::InstanceMember { separator_end: trimmed.len() });
+    }
+
+    if is_member_prefix && trimmed.ends_with("::") {
+        return Some(CompletionContext::StaticMember { separator_end: trimmed.len() });
+    }
+
+    if prefix.starts_with('$') {
+        return Some(CompletionContext::Variable);
+    }
+
+    let statement_start = trimmed.rfind([';', '{', '}']).map_or(0, |index| index + 1);
+    let statement =
+        trimmed[statement_start..].split_whitespace().filter(|word| !word.starts_with("<?")).collect::<Vec<_>>();
+    if before.len() == trimmed.len() && !statement.is_empty() {
+        // The keyword is still being typed, e.g. `use|`.
+        return None;
+    }
+
+    match statement.as_slice() {
+        [keyword] if keyword.eq_ignore_ascii_case("use") => Some(CompletionContext::Import(ImportKind::ClassLike)),
+        [keyword, kind] if keyword.eq_ignore_ascii_case("use") && kind.eq_ignore_ascii_case("function") => {
+            Some(CompletionContext::Import(ImportKind::Function))
+        }
+        [keyword, kind] if keyword.eq_ignore_ascii_case("use") && kind.eq_ignore_ascii_case("const") => {
+            Some(CompletionContext::Import(ImportKind::Constant))
+        }
+        _ => None,
+    }
+}
+
+/// Parses the document with a placeholder inserted at the given offset, trying each repair in turn until
+/// one parses, and falling back to the last one.
+fn parse_repaired(workspace: &Workspace, document: &Document, offset: usize) -> (Module, Program) {
+    let name = workspace.interner.lookup(&document.source.identifier.value());
+
+    let mut repaired = None;
+    for repair in REPAIRS {
+        let mut content = document.content.clone();
+        content.insert_str(offset, repair);
+
+        let source = Source::standalone(&workspace.interner, name, &content);
+        let (module, program) = Module::build_with_ast(
+            &workspace.interner,
+            workspace.php_version,
+            source,
+            ModuleBuildOptions::reflection(),
+        );
+
+        let parsed = module.parse_errors.is_empty();
+        repaired = Some((module, program));
+        if parsed {
+            break;
+        }
+    }
+
+    repaired.expect("there is at least one repair")
+}
+
+fn get_variable_completions(
+    inference: &Inference<'_>,
+    prefix_start: usize,
+) -> Vec<(String, CompletionItemKind, Option<String>)> {
+    let interner = inference.interner();
+    let root = Node::Program(inference.program());
+
+    // The scopes whose variables are visible: the innermost function-like, along with the enclosing
+    // ones for as long as they are inherited, as with arrow functions.
+    let mut scopes = vec![];
+    let mut scope = inference.get_function_like(prefix_start);
+    let mut has_this = false;
+    loop {
+        scopes.push(scope.map(|function_like| function_like.span()));
+
+        let Some(function_like) = scope else {
+            break;
+        };
+
+        if !function_like.inherits_scope() {
+            has_this = function_like.has_this() && inference.get_enclosing_class(prefix_start).is_some();
+
+            break;
+        }
+
+        scope = inference.get_parent_function_like(&function_like);
+    }
+
+    let static_properties = root
+        .filter_map(|node| match node {
+            Node::StaticPropertyAccess(access) => Some(access.property.span()),
+            _ => None,
+        })
+        .into_iter()
+        .collect::<HashSet<_>>();
+
+    let mut names = vec![];
+    if has_this {
+        names.push("$this".to_string());
+    }
+
+    for variable in root.filter_map(|node| match node {
+        Node::DirectVariable(variable) => Some(*variable),
+        _ => None,
+    }) {
+        let start = variable.span.start.offset;
+        if start == prefix_start || static_properties.contains(&variable.span) {
+            continue;
+        }
+
+        let function_like = inference.get_function_like(start);
+        if function_like.is_none() && inference.get_enclosing_class(start).is_some() {
+            // A property declaration.
+            continue;
+        }
+
+        if !scopes.contains(&function_like.map(|function_like| function_like.span())) {
+            continue;
+        }
+
+        let name = interner.lookup(&variable.name);
+        if !names.iter().any(|existing| existing == name) {
+            names.push(name.to_string());
+        }
+    }
+
+    names.into_iter().map(|name| (name, CompletionItemKind::VARIABLE, None)).collect()
+}
+
+/// Returns the members of the receiver whose member separator ends at the given offset.
+///
+/// Members that are not public are only suggested when accessed through `$this`, `self`, `static`, or
+/// `parent`, or from within the class-like itself.
+fn get_member_completions(
+    inference: &Inference<'_>,
+    separator_end: usize,
+    is_static: bool,
+) -> Option<Vec<(String, CompletionItemKind, Option<String>)>> {
+    let interner = inference.interner();
+
+    let receiver = Node::Program(inference.program()).filter_map(|node| {
+        let (receiver, separator) = match node {
+            Node::PropertyAccess(access) => (access.object.as_ref(), access.arrow),
+            Node::NullSafePropertyAccess(access) => (access.object.as_ref(), access.question_mark_arrow),
+            Node::MethodCall(call) => (call.object.as_ref(), call.arrow),
+            Node::NullSafeMethodCall(call) => (call.object.as_ref(), call.question_mark_arrow),
+            Node::StaticPropertyAccess(access) => (access.class.as_ref(), access.double_colon),
+            Node::ClassConstantAccess(access) => (access.class.as_ref(), access.double_colon),
+            Node::StaticMethodCall(call) => (call.class.as_ref(), call.double_colon),
+            _ => return None,
+        };
+
+        if separator.end.offset == separator_end { Some(receiver) } else { None }
+    });
+
+    let receiver = *receiver.first()?;
+    let enclosing = inference.get_enclosing_class(separator_end);
+
+    let (class, is_scoped) = if is_static {
+        let class = inference.get_class_expression(receiver).or_else(|| inference.infer(receiver))?;
+        let is_scoped = matches!(receiver, Expression::Self_(_) | Expression::Static(_) | Expression::Parent(_));
+
+        (class, is_scoped)
+    } else {
+        let class = inference.infer(receiver)?;
+        let is_this = matches!(
+            receiver,
+            Expression::Variable(Variable::Direct(variable)) if interner.lookup(&variable.name) == "$this"
+        );
+
+        (class, is_this)
+    };
+
+    let is_privileged = is_scoped || enclosing.is_some_and(|enclosing| is_same_name(inference, &enclosing, &class));
+    let is_visible = |visibility: &Option<ClassLikeMemberVisibilityReflection>| {
+        is_privileged || visibility.as_ref().is_none_or(|visibility| visibility.is_public())
+    };
+
+    let mut items = vec![];
+    let mut labels = HashSet::new();
+    let mut push = |label: String, kind: CompletionItemKind, detail: Option<String>| {
+        if labels.insert(label.to_ascii_lowercase()) {
+            items.push((label, kind, detail));
+        }
+    };
+
+    if is_static {
+        push("class".to_string(), CompletionItemKind::KEYWORD, None);
+    }
+
+    for reflection in get_hierarchy(inference, &class) {
+        let mut methods = reflection.methods.members.values().collect::<Vec<_>>();
+        methods.sort_by_key(|method| method.span.start.offset);
+        for method in methods {
+            if !is_visible(&method.visibility_reflection) || (is_static && !is_scoped && !method.is_static) {
+                continue;
+            }
+
+            if !is_static && method.is_static {
+                continue;
+            }
+
+            let FunctionLikeName::Method(_, name) = method.name else {
+                continue;
+            };
+
+            // Magic methods are only suggested when called explicitly, e.g. `parent::__construct()`.
+            let name = interner.lookup(&name.value);
+            if name.starts_with("__") && !is_scoped {
+                continue;
+            }
+
+            push(name.to_string(), CompletionItemKind::METHOD, Some(get_signature(inference, name, method)));
+        }
+
+        let mut properties = reflection.properties.members.values().collect::<Vec<_>>();
+        properties.sort_by_key(|property| property.item_span.start.offset);
+        for property in properties {
+            if !is_visible(&property.read_visibility_reflection) || property.is_static != is_static {
+                continue;
+            }
+
+            let name = interner.lookup(&property.name.member.value);
+            let label = if is_static { name } else { name.trim_start_matches('$') };
+            let detail = property.type_reflection.as_ref().map(|r#type| r#type.kind.get_key(interner));
+
+            push(label.to_string(), CompletionItemKind::PROPERTY, detail);
+        }
+
+        // Promoted properties are only reflected as constructor parameters, which do not record their
+        // visibility, so it is read from the declaration itself.
+        let constructor = reflection.methods.members.get(&interner.intern("__construct"));
+        for parameter in constructor.iter().flat_map(|constructor| constructor.parameters.iter()) {
+            if !parameter.is_promoted_property || is_static {
+                continue;
+            }
+
+            if !is_privileged && !is_public_parameter(inference, parameter) {
+                continue;
+            }
+
+            let name = interner.lookup(&parameter.name).trim_start_matches('$');
+            let detail = parameter.type_reflection.as_ref().map(|r#type| r#type.kind.get_key(interner));
+
+            push(name.to_string(), CompletionItemKind::PROPERTY, detail);
+        }
+
+        if !is_static {
+            continue;
+        }
+
+        let mut cases = reflection.cases.values().collect::<Vec<_>>();
+        cases.sort_by_key(|case| case.span.start.offset);
+        for case in cases {
+            push(interner.lookup(&case.name.member.value).to_string(), CompletionItemKind::ENUM_MEMBER, None);
+        }
+
+        let mut constants = reflection.constants.values().collect::<Vec<_>>();
+        constants.sort_by_key(|constant| constant.item_span.start.offset);
+        for constant in constants {
+            if !is_visible(&constant.visibility_reflection) {
+                continue;
+            }
+
+            let detail =
+                constant.type_reflection.as_ref().unwrap_or(&constant.inferred_type_reflection).kind.get_key(interner);
+
+            push(interner.lookup(&constant.name.member.value).to_string(), CompletionItemKind::CONSTANT, Some(detail));
+        }
+    }
+
+    Some(items)
+}
+
+/// Returns the given class-like, followed by the class-likes it inherits members from, closest first.
+fn get_hierarchy<'a>(inference: &Inference<'a>, class: &StringIdentifier) -> Vec<&'a ClassLikeReflection> {
+    let mut hierarchy: Vec<&ClassLikeReflection> = vec![];
+    let mut pending = vec![*class];
+    let mut index = 0;
+
+    loop {
+        for name in pending.drain(..) {
+            let Some(reflection) = inference.get_class_like(&name) else {
+                continue;
+            };
+
+            if !hierarchy.iter().any(|existing| existing.name == reflection.name) {
+                hierarchy.push(reflection);
+            }
+        }
+
+        let Some(reflection) = hierarchy.get(index) else {
+            break;
+        };
+
+        pending.extend(inference.get_ancestors(reflection));
+        index += 1;
+    }
+
+    hierarchy
+}
+
+/// Returns whether the given promoted property is readable from outside its class-like.
+fn is_public_parameter(inference: &Inference<'_>, parameter: &FunctionLikeParameterReflection) -> bool {
+    let Some(text) = inference.get_text(parameter.span) else {
+        return true;
+    };
+
+    let modifiers = text.split('$').next().unwrap_or_default();
+
+    !modifiers
+        .split_whitespace()
+        .any(|word| word.eq_ignore_ascii_case("private") || word.eq_ignore_ascii_case("protected"))
+}
+
+fn is_same_name(inference: &Inference<'_>, a: &StringIdentifier, b: &StringIdentifier) -> bool {
+    let interner = inference.interner();
+
+    interner.lookup(a).eq_ignore_ascii_case(interner.lookup(b))
+}
+
+/// Returns the signature of the given method, e.g. `greet(string $name): string`.
+fn get_signature(inference: &Inference<'_>, name: &str, method: &FunctionLikeReflection) -> String {
+    let interner = inference.interner();
+
+    let parameters = method
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let name = interner.lookup(&parameter.name);
+
+            match &parameter.type_reflection {
+                Some(r#type) => format!("{} {name}", r#type.kind.get_key(interner)),
+                None => name.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    match &method.return_type_reflection {
+        Some(r#return) => format!("{name}({parameters}): {}", r#return.type_reflection.kind.get_key(interner)),
+        None => format!("{name}({parameters})"),
+    }
+}
+
+/// Returns the symbols of the given kind matching the given prefix, best matches first.
+///
+/// The returned flag is set when the list was truncated, so that the client asks again as the prefix grows.
+fn get_import_completions(
+    inference: &Inference<'_>,
+    kind: ImportKind,
+    prefix: &str,
+) -> (bool, Vec<(String, CompletionItemKind, Option<String>)>) {
+    let interner = inference.interner();
+    let query = prefix.trim_start_matches('\\');
+
+    let mut candidates: Vec<(StringIdentifier, CompletionItemKind)> = vec![];
+    for codebase in inference.codebases() {
+        match kind {
+            ImportKind::ClassLike | ImportKind::Trait => {
+                candidates.extend(codebase.class_like_reflections.values().filter_map(|reflection| {
+                    match (reflection.name, kind) {
+                        (ClassLikeName::Trait(name), _) => Some((name.value, CompletionItemKind::CLASS)),
+                        (_, ImportKind::Trait) => None,
+                        (ClassLikeName::Class(name), _) => Some((name.value, CompletionItemKind::CLASS)),
+                        (ClassLikeName::Interface(name), _) => Some((name.value, CompletionItemKind::INTERFACE)),
+                        (ClassLikeName::Enum(name), _) => Some((name.value, CompletionItemKind::ENUM)),
+                        (ClassLikeName::AnonymousClass(_), _) => None,
+                    }
+                }));
+            }
+            ImportKind::Function => {
+                candidates.extend(codebase.function_like_reflections.values().filter_map(|reflection| {
+                    match reflection.name {
+                        FunctionLikeName::Function(name) => Some((name.value, CompletionItemKind::FUNCTION)),
+                        _ => None,
+                    }
+                }));
+            }
+            ImportKind::Constant => {
+                candidates.extend(
+                    codebase
+                        .constant_reflections
+                        .values()
+                        .map(|reflection| (reflection.name.value, CompletionItemKind::CONSTANT)),
+                );
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut matches = candidates
+        .into_iter()
+        .filter(|(name, _)| seen.insert(*name))
+        .filter_map(|(name, kind)| {
+            let name = interner.lookup(&name);
+
+            get_fuzzy_score(query, name).map(|score| (score, name.to_string(), kind))
+        })
+        .collect::<Vec<_>>();
+
+    // Better matches come first, and shorter names first among equally good matches.
+    matches.sort_by(|(a_score, a_name, _), (b_score, b_name, _)| {
+        b_score.cmp(a_score).then_with(|| a_name.len().cmp(&b_name.len())).then_with(|| a_name.cmp(b_name))
+    });
+
+    let is_incomplete = matches.len() > MAXIMUM_IMPORT_COMPLETIONS;
+    let items =
+        matches.into_iter().take(MAXIMUM_IMPORT_COMPLETIONS).map(|(_, name, kind)| (name, kind, None)).collect();
+
+    (is_incomplete, items)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::document;
+    use crate::tests::workspace;
+
+    use super::*;
+
+    fn complete(sources: &[(&str, &str)], content: &str) -> Vec<(String, Option<String>)> {
+        let workspace = workspace(sources);
+        let offset = content.find('|').expect("the content contains a cursor");
+        let content = content.replacen('|', "", 1);
+        let document = document(&workspace, "src/index.php", &content);
+
+        let Some(CompletionResponse::List(list)) =
+            get_completions(&workspace, &document, document.get_position(offset))
+        else {
+            return vec![];
+        };
+
+        list.items.into_iter().map(|item| (item.label, item.detail)).collect()
+    }
+
+    fn labels(sources: &[(&str, &str)], content: &str) -> Vec<String> {
+        complete(sources, content).into_iter().map(|(label, _)| label).collect()
+    }
+
+    const USER: &str = r#"<?php
+
+namespace App;
+
+class Model
+{
+    public function save(): static
+    {
+        return $this;
+    }
+}
+
+final class User extends Model
+{
+    public const string TABLE = 'users';
+
+    private static ?User $instance = null;
+
+    public function __construct(public string $name, protected int $age) {}
+
+    /**
+     * @return Address
+     */
+    public function getAddress()
+    {
+        return new Address();
+    }
+
+    public static function create(): self
+    {
+        return new self('');
+    }
+}
+
+final class Address
+{
+    public string $city = '';
+}
+"#;
+
+    #[test]
+    fn test_variables_in_scope() {
+        assert_eq!(
+            labels(&[], "<?php\n\n$outer = 1;\n\nfunction main(int $first) {\n    $second = 2;\n    $|\n}\n"),
+            vec!["$first", "$second"]
+        );
+
+        assert_eq!(labels(&[], "<?php\n\n$outer = 1;\n\n$fn = fn($inner) => $|;\n"), vec!["$outer", "$fn", "$inner"]);
+    }
+
+    #[test]
+    fn test_instance_members() {
+        assert_eq!(
+            complete(&[("src/User.php", USER)], "<?php\n\nuse App\\User;\n\n$user = User::create();\n$user->|\n"),
+            vec![
+                ("getAddress".to_string(), Some("getAddress()".to_string())),
+                ("name".to_string(), Some("string".to_string())),
+                ("save".to_string(), Some("save(): static".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_members_resolved_through_docblocks() {
+        let content = "<?php\n\nuse App\\User;\n\nfunction main(User $user) {\n    $user->getAddress()->c|\n}\n";
+
+        assert_eq!(labels(&[("src/User.php", USER)], content), vec!["city"]);
+
+        let content = "<?php\n\n/** @var \\App\\User $user */\n$user = get();\n$user->save()->getA|\n";
+
+        assert!(labels(&[("src/User.php", USER)], content).contains(&"getAddress".to_string()));
+    }
+
+    #[test]
+    fn test_static_members() {
+        assert_eq!(
+            labels(&[("src/User.php", USER)], "<?php\n\nuse App\\User;\n\nUser::|\n"),
+            vec!["class", "create", "TABLE"]
+        );
+    }
+
+    #[test]
+    fn test_private_members_are_suggested_within_the_class() {
+        let content = "<?php\n\nclass Counter\n{\n    private static int $count = 0;\n\n    private function increment(): void\n    {\n        self::|\n    }\n}\n";
+
+        assert_eq!(labels(&[], content), vec!["class", "increment", "$count"]);
+    }
+
+    #[test]
+    fn test_use_statement_symbols() {
+        let sources =
+            [("src/User.php", USER), ("src/functions.php", "<?php\n\nnamespace App;\n\nfunction greet() {}\n")];
+
+        assert_eq!(labels(&sources, "<?php\n\nuse App\\Us|\n"), vec!["App\\User"]);
+        assert_eq!(labels(&sources, "<?php\n\nuse function gr|\n"), vec!["App\\greet"]);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use tower_lsp::lsp_types::Location;
+use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::Url;
+
+use mago_project::module::Module;
+use mago_project::module::ModuleBuildOptions;
+use mago_reference::ReferenceFinder;
+use mago_reference::ReferenceKind;
+use mago_reference::query::Query;
+use mago_source::SourceCategory;
+
+use crate::Workspace;
+use crate::document::Document;
+use crate::document::get_position;
+use crate::symbol::get_declaration;
+use crate::symbol::get_location;
+use crate::symbol::get_reference_at;
+
+/// Returns the location of the declaration of the symbol at the given position.
+pub fn get_definition(workspace: &Workspace, document: &Document, position: Position) -> Option<Location> {
+    let reference = get_reference_at(workspace, document, document.get_offset(position))?;
+    let declaration = get_declaration(workspace, document, &reference.value)?;
+
+    get_location(workspace, document, declaration.get_name_span())
+}
+
+/// Returns the locations of all references to the symbol at the given position.
+///
+/// Open documents are searched using their current content, while the remaining user-defined
+/// sources of the workspace are parsed from disk.
+///
+/// # Parameters
+///
+/// - `workspace`: The workspace to search.
+/// - `documents`: The documents opened in the client.
+/// - `document`: The document containing the position.
+/// - `position`: The position of the symbol.
+/// - `include_declaration`: Whether to include the declaration of the symbol.
+pub fn get_references(
+    workspace: &Workspace,
+    documents: &HashMap<Url, Document>,
+    document: &Document,
+    position: Position,
+    include_declaration: bool,
+) -> Vec<Location> {
+    let Some(reference) = get_reference_at(workspace, document, document.get_offset(position)) else {
+        return vec![];
+    };
+
+    let finder = ReferenceFinder::new(&workspace.interner);
+    let query = Query::Exact(workspace.interner.lookup(&reference.value).to_string(), false);
+    let is_included = |kind: ReferenceKind| include_declaration || kind != ReferenceKind::Definition;
+
+    let mut locations = vec![];
+    let mut searched = HashSet::new();
+    for document in documents.values() {
+        searched.insert(document.source.identifier);
+
+        for reference in finder.find(&document.module, &document.program, query.clone()) {
+            if is_included(reference.kind) {
+                locations.push(Location::new(document.uri.clone(), document.get_range(reference.span)));
+            }
+        }
+    }
+
+    for source_id in workspace.manager.source_ids_for_category(SourceCategory::UserDefined) {
+        if searched.contains(&source_id) {
+            continue;
+        }
+
+        let Ok(source) = workspace.manager.load(&source_id) else {
+            continue;
+        };
+
+        let Some(uri) = workspace.get_source_uri(&source) else {
+            continue;
+        };
+
+        let (module, program) = Module::build_with_ast(
+            &workspace.interner,
+            workspace.php_version,
+            source.clone(),
+            ModuleBuildOptions::new(false, false),
+        );
+
+        let content = &source.content;
+        for reference in finder.find(&module, &program, query.clone()) {
+            if is_included(reference.kind) {
+                let start = get_position(&source, content, reference.span.start.offset);
+                let end = get_position(&source, content, reference.span.end.offset);
+
+                locations.push(Location::new(uri.clone(), Range::new(start, end)));
+            }
+        }
+    }
+
+    locations.sort_by(|a, b| (a.uri.as_str(), a.range.start).cmp(&(b.uri.as_str(), b.range.start)));
+    locations
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::document;
+    use crate::tests::workspace;
+
+    use super::*;
+
+    const GREETER: &str = "<?php\n\nnamespace App;\n\nfinal class Greeter\n{\n}\n";
+
+    #[test]
+    fn test_definition_in_workspace() {
+        let workspace = workspace(&[("src/Greeter.php", GREETER)]);
+        let document = document(&workspace, "src/index.php", "<?php\n\nuse App\\Greeter;\n\nnew Greeter();\n");
+
+        let location = get_definition(&workspace, &document, Position::new(4, 6)).unwrap();
+
+        assert_eq!(location.uri.as_str(), "file:///project/src/Greeter.php");
+        assert_eq!(location.range, Range::new(Position::new(4, 12), Position::new(4, 19)));
+    }
+
+    #[test]
+    fn test_definition_in_document() {
+        let workspace = workspace(&[]);
+        let document = document(&workspace, "src/index.php", "<?php\n\nfunction greet() {}\n\ngreet();\n");
+
+        let location = get_definition(&workspace, &document, Position::new(4, 1)).unwrap();
+
+        assert_eq!(location.uri.as_str(), "file:///project/src/index.php");
+        assert_eq!(location.range, Range::new(Position::new(2, 9), Position::new(2, 14)));
+    }
+
+    #[test]
+    fn test_references() {
+        let workspace = workspace(&[
+            ("src/Greeter.php", GREETER),
+            ("src/main.php", "<?php\n\nnamespace App;\n\n$greeter = new Greeter();\n"),
+        ]);
+
+        let document = document(&workspace, "src/index.php", "<?php\n\nuse App\\Greeter;\n\nnew Greeter();\n");
+        let documents = HashMap::from([(document.uri.clone(), document)]);
+        let document = documents.values().next().unwrap();
+
+        let references = get_references(&workspace, &documents, document, Position::new(4, 6), false)
+            .into_iter()
+            .map(|location| (location.uri.path().to_string(), location.range.start.line))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            references,
+            vec![
+                ("/project/src/index.php".to_string(), 2),
+                ("/project/src/index.php".to_string(), 4),
+                ("/project/src/main.php".to_string(), 4),
+            ]
+        );
+
+        let references = get_references(&workspace, &documents, document, Position::new(4, 6), true);
+
+        assert_eq!(references.len(), 4);
+    }
+}
@@ -0,0 +1,67 @@
+use tower_lsp::lsp_types::CodeDescription;
+use tower_lsp::lsp_types::Diagnostic;
+use tower_lsp::lsp_types::DiagnosticRelatedInformation;
+use tower_lsp::lsp_types::DiagnosticSeverity;
+use tower_lsp::lsp_types::Location;
+use tower_lsp::lsp_types::NumberOrString;
+use tower_lsp::lsp_types::Url;
+
+use mago_reporting::Issue;
+use mago_reporting::Level;
+
+use crate::document::Document;
+
+/// Converts the given issue to an LSP diagnostic.
+///
+/// Issues that are not located in the given document are skipped, as they are published with
+/// the document they are located in.
+pub fn to_diagnostic(document: &Document, issue: &Issue) -> Option<Diagnostic> {
+    let span = document.get_issue_span(issue)?;
+
+    let mut message = issue.message.clone();
+    for note in issue.notes.iter() {
+        message.push('\n');
+        message.push_str(note);
+    }
+
+    if let Some(help) = issue.help.as_ref() {
+        message.push_str("\nHelp: ");
+        message.push_str(help);
+    }
+
+    let related_information = issue
+        .annotations
+        .iter()
+        .filter(|annotation| !annotation.is_primary() && annotation.span.start.source == document.source.identifier)
+        .map(|annotation| DiagnosticRelatedInformation {
+            location: Location::new(document.uri.clone(), document.get_range(annotation.span)),
+            message: annotation.message.clone().unwrap_or_default(),
+        })
+        .collect::<Vec<_>>();
+
+    Some(Diagnostic {
+        range: document.get_range(span),
+        severity: Some(match issue.level {
+            Level::Error => DiagnosticSeverity::ERROR,
+            Level::Warning => DiagnosticSeverity::WARNING,
+            Level::Help => DiagnosticSeverity::HINT,
+            Level::Note => DiagnosticSeverity::INFORMATION,
+        }),
+        code: issue.code.clone().map(NumberOrString::String),
+        code_description: issue
+            .link
+            .as_ref()
+            .and_then(|link| Url::parse(link).ok())
+            .map(|href| CodeDescription { href }),
+        source: Some("mago".to_string()),
+        message,
+        related_information: if related_information.is_empty() { None } else { Some(related_information) },
+        tags: None,
+        data: None,
+    })
+}
+
+/// Returns the diagnostics of all the issues located in the given document.
+pub fn get_diagnostics(document: &Document) -> Vec<Diagnostic> {
+    document.issues.iter().filter_map(|issue| to_diagnostic(document, issue)).collect()
+}
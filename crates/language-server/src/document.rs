@@ -0,0 +1,227 @@
+use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::TextEdit;
+use tower_lsp::lsp_types::Url;
+
+use mago_ast::Program;
+use mago_fixer::Change;
+use mago_fixer::FixPlan;
+use mago_project::module::Module;
+use mago_project::module::ModuleBuildOptions;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_source::Source;
+use mago_span::Span;
+
+use crate::Workspace;
+
+/// A document opened in the client.
+#[derive(Debug)]
+pub struct Document {
+    pub uri: Url,
+    pub content: String,
+    pub source: Source,
+    pub program: Program,
+    pub module: Module,
+    pub issues: IssueCollection,
+}
+
+impl Document {
+    /// Parses and lints the given content of a document.
+    pub fn new(workspace: &Workspace, uri: Url, content: String) -> Self {
+        let name = uri
+            .to_file_path()
+            .ok()
+            .map(|path| path.strip_prefix(&workspace.root).map(|path| path.to_path_buf()).unwrap_or(path))
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_else(|| uri.to_string());
+
+        let source = Source::standalone(&workspace.interner, &name, &content);
+        let (mut module, program) = Module::build_with_ast(
+            &workspace.interner,
+            workspace.php_version,
+            source.clone(),
+            ModuleBuildOptions::default(),
+        );
+
+        let mut issues = workspace.linter.lint(&module);
+        issues.extend(std::mem::take(&mut module.issues));
+        issues.extend(module.parse_errors.iter().map(Into::<Issue>::into));
+
+        Self { uri, content, source, program, module, issues }
+    }
+
+    /// Returns the LSP position of the given byte offset.
+    ///
+    /// LSP positions count characters in UTF-16 code units.
+    pub fn get_position(&self, offset: usize) -> Position {
+        get_position(&self.source, &self.content, offset)
+    }
+
+    /// Returns the byte offset of the given LSP position.
+    ///
+    /// Positions past the end of a line are clamped to the end of that line.
+    pub fn get_offset(&self, position: Position) -> usize {
+        let line = position.line as usize;
+        let Some(line_start) = self.source.get_line_start_offset(line) else {
+            return self.content.len();
+        };
+
+        let line_end = self.source.get_line_start_offset(line + 1).unwrap_or(self.content.len());
+        let mut character = 0;
+        for (index, c) in self.content[line_start..line_end].char_indices() {
+            if character >= position.character as usize || c == '\n' || c == '\r' {
+                return line_start + index;
+            }
+
+            character += c.len_utf16();
+        }
+
+        line_end
+    }
+
+    /// Returns the LSP range of the given span.
+    pub fn get_range(&self, span: Span) -> Range {
+        Range::new(self.get_position(span.start.offset), self.get_position(span.end.offset))
+    }
+
+    /// Returns the primary span of the given issue, if it is located in this document.
+    pub fn get_issue_span(&self, issue: &Issue) -> Option<Span> {
+        issue
+            .annotations
+            .iter()
+            .find(|annotation| annotation.is_primary())
+            .map(|annotation| annotation.span)
+            .filter(|span| span.start.source == self.source.identifier)
+    }
+
+    /// Returns the text edits applying the given plan to this document.
+    ///
+    /// The plan is executed first, so that overlapping operations are resolved the same way `mago fix` does.
+    pub fn get_text_edits(&self, plan: &FixPlan) -> Vec<TextEdit> {
+        let mut edits: Vec<TextEdit> = vec![];
+        let mut offset = 0;
+        let mut previous_end = None;
+
+        for change in plan.execute(&self.content).iter() {
+            let (start, end, text) = match change {
+                Change::Unchanged(text) => {
+                    offset += text.len();
+
+                    continue;
+                }
+                Change::Deleted(text) => {
+                    offset += text.len();
+
+                    (offset - text.len(), offset, "")
+                }
+                Change::Inserted(text) => (offset, offset, text.as_str()),
+            };
+
+            // Merge adjacent changes, e.g. a deletion followed by an insertion, into a single edit.
+            match edits.last_mut() {
+                Some(edit) if previous_end == Some(start) => {
+                    edit.range.end = self.get_position(end);
+                    edit.new_text.push_str(text);
+                }
+                _ => {
+                    edits.push(TextEdit::new(
+                        Range::new(self.get_position(start), self.get_position(end)),
+                        text.to_string(),
+                    ));
+                }
+            }
+
+            previous_end = Some(end);
+        }
+
+        edits
+    }
+
+    /// Returns the content of the given span.
+    pub fn get_text(&self, span: Span) -> &str {
+        &self.content[span.start.offset..span.end.offset]
+    }
+}
+
+/// Returns the LSP position of the given byte offset in the given source.
+///
+/// LSP positions count characters in UTF-16 code units.
+pub fn get_position(source: &Source, content: &str, offset: usize) -> Position {
+    let mut offset = offset.min(content.len());
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let line = source.line_number(offset);
+    let line_start = source.get_line_start_offset(line).unwrap_or(0);
+    let character = content[line_start..offset].encode_utf16().count();
+
+    Position::new(line as u32, character as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_fixer::SafetyClassification;
+
+    use crate::tests::document;
+    use crate::tests::workspace;
+
+    use super::*;
+
+    #[test]
+    fn test_positions_count_utf16_code_units() {
+        let document = document(&workspace(&[]), "src/index.php", "<?php\n$a = '😀';\n$b = 1;\n");
+
+        assert_eq!(document.get_position(0), Position::new(0, 0));
+        assert_eq!(document.get_position(12), Position::new(1, 6));
+        assert_eq!(document.get_position(16), Position::new(1, 8));
+        assert_eq!(document.get_position(19), Position::new(2, 0));
+
+        assert_eq!(document.get_offset(Position::new(1, 8)), 16);
+        assert_eq!(document.get_offset(Position::new(2, 100)), 26);
+        assert_eq!(document.get_offset(Position::new(10, 0)), document.content.len());
+    }
+
+    #[test]
+    fn test_document_is_named_relative_to_the_workspace_root() {
+        let workspace = workspace(&[]);
+        let document = document(&workspace, "src/index.php", "<?php\n");
+
+        assert_eq!(workspace.interner.lookup(&document.source.identifier.value()), "src/index.php");
+    }
+
+    #[test]
+    fn test_documents_are_analyzed_again_after_resetting_the_interner() {
+        let mut workspace = workspace(&[("src/a.php", "<?php\n\nfunction a(): void {}\n")]);
+        let content = "<?php\n\nfunction b_function_unknown_to_the_workspace(): void {}\n";
+        let before = document(&workspace, "src/index.php", content);
+        assert!(workspace.interner.len() > workspace.snapshot.len());
+
+        workspace.reset_interner();
+        assert_eq!(workspace.interner.len(), workspace.snapshot.len());
+
+        let after = document(&workspace, "src/index.php", content);
+        assert_eq!(workspace.interner.lookup(&after.source.identifier.value()), "src/index.php");
+        assert_eq!(after.issues.len(), before.issues.len());
+    }
+
+    #[test]
+    fn test_text_edits() {
+        let document = document(&workspace(&[]), "src/index.php", "<?php\n\n$a = sizeof($b);\n");
+
+        let mut plan = FixPlan::new();
+        plan.replace(12..18, "count", SafetyClassification::Safe);
+        plan.insert(7, "// comment\n", SafetyClassification::Safe);
+
+        let edits = document.get_text_edits(&plan);
+
+        assert_eq!(
+            edits,
+            vec![
+                TextEdit::new(Range::new(Position::new(2, 0), Position::new(2, 0)), "// comment\n".to_string()),
+                TextEdit::new(Range::new(Position::new(2, 5), Position::new(2, 11)), "count".to_string()),
+            ]
+        );
+    }
+}
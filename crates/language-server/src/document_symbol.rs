@@ -0,0 +1,300 @@
+use tower_lsp::lsp_types::DocumentSymbol;
+use tower_lsp::lsp_types::SymbolKind;
+
+use mago_ast::ClassLikeMember;
+use mago_ast::EnumCaseItem;
+use mago_ast::LocalIdentifier;
+use mago_ast::Sequence;
+use mago_ast::Statement;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::document::Document;
+
+/// Returns the outline of the given document.
+///
+/// The outline contains the namespaces, class-likes, functions, and constants declared at the top level
+/// of the document, along with the members of each class-like.
+pub fn get_document_symbols(interner: &ThreadedInterner, document: &Document) -> Vec<DocumentSymbol> {
+    get_statement_symbols(interner, document, &document.program.statements)
+}
+
+fn get_statement_symbols(
+    interner: &ThreadedInterner,
+    document: &Document,
+    statements: &Sequence<Statement>,
+) -> Vec<DocumentSymbol> {
+    let mut symbols = vec![];
+
+    for statement in statements.iter() {
+        match statement {
+            Statement::Namespace(namespace) => {
+                let children = get_statement_symbols(interner, document, namespace.statements());
+
+                match &namespace.name {
+                    Some(name) => symbols.push(create_symbol(
+                        document,
+                        interner.lookup(&name.value()),
+                        SymbolKind::NAMESPACE,
+                        namespace.span(),
+                        name.span(),
+                        children,
+                    )),
+                    None => symbols.extend(children),
+                }
+            }
+            Statement::Class(class) => {
+                let children = get_member_symbols(interner, document, &class.members);
+
+                symbols.push(create_local_symbol(
+                    interner,
+                    document,
+                    &class.name,
+                    SymbolKind::CLASS,
+                    class.span(),
+                    children,
+                ));
+            }
+            Statement::Interface(interface) => {
+                let children = get_member_symbols(interner, document, &interface.members);
+
+                symbols.push(create_local_symbol(
+                    interner,
+                    document,
+                    &interface.name,
+                    SymbolKind::INTERFACE,
+                    interface.span(),
+                    children,
+                ));
+            }
+            Statement::Trait(r#trait) => {
+                let children = get_member_symbols(interner, document, &r#trait.members);
+
+                symbols.push(create_local_symbol(
+                    interner,
+                    document,
+                    &r#trait.name,
+                    SymbolKind::CLASS,
+                    r#trait.span(),
+                    children,
+                ));
+            }
+            Statement::Enum(r#enum) => {
+                let children = get_member_symbols(interner, document, &r#enum.members);
+
+                symbols.push(create_local_symbol(
+                    interner,
+                    document,
+                    &r#enum.name,
+                    SymbolKind::ENUM,
+                    r#enum.span(),
+                    children,
+                ));
+            }
+            Statement::Function(function) => {
+                symbols.push(create_local_symbol(
+                    interner,
+                    document,
+                    &function.name,
+                    SymbolKind::FUNCTION,
+                    function.span(),
+                    vec![],
+                ));
+            }
+            Statement::Constant(constant) => {
+                for item in constant.items.iter() {
+                    symbols.push(create_local_symbol(
+                        interner,
+                        document,
+                        &item.name,
+                        SymbolKind::CONSTANT,
+                        item.span(),
+                        vec![],
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    symbols
+}
+
+fn get_member_symbols(
+    interner: &ThreadedInterner,
+    document: &Document,
+    members: &Sequence<ClassLikeMember>,
+) -> Vec<DocumentSymbol> {
+    let mut symbols = vec![];
+
+    for member in members.iter() {
+        match member {
+            ClassLikeMember::Method(method) => {
+                let kind = if interner.lookup(&method.name.value).eq_ignore_ascii_case("__construct") {
+                    SymbolKind::CONSTRUCTOR
+                } else {
+                    SymbolKind::METHOD
+                };
+
+                symbols.push(create_local_symbol(interner, document, &method.name, kind, method.span(), vec![]));
+            }
+            ClassLikeMember::Property(property) => {
+                for variable in property.variables() {
+                    symbols.push(create_symbol(
+                        document,
+                        interner.lookup(&variable.name),
+                        SymbolKind::PROPERTY,
+                        property.span(),
+                        variable.span(),
+                        vec![],
+                    ));
+                }
+            }
+            ClassLikeMember::Constant(constant) => {
+                for item in constant.items.iter() {
+                    symbols.push(create_local_symbol(
+                        interner,
+                        document,
+                        &item.name,
+                        SymbolKind::CONSTANT,
+                        item.span(),
+                        vec![],
+                    ));
+                }
+            }
+            ClassLikeMember::EnumCase(case) => {
+                let name = match &case.item {
+                    EnumCaseItem::Unit(item) => &item.name,
+                    EnumCaseItem::Backed(item) => &item.name,
+                };
+
+                symbols.push(create_local_symbol(
+                    interner,
+                    document,
+                    name,
+                    SymbolKind::ENUM_MEMBER,
+                    case.span(),
+                    vec![],
+                ));
+            }
+            ClassLikeMember::TraitUse(_) => {}
+        }
+    }
+
+    symbols
+}
+
+fn create_local_symbol(
+    interner: &ThreadedInterner,
+    document: &Document,
+    name: &LocalIdentifier,
+    kind: SymbolKind,
+    span: Span,
+    children: Vec<DocumentSymbol>,
+) -> DocumentSymbol {
+    create_symbol(document, interner.lookup(&name.value), kind, span, name.span, children)
+}
+
+#[allow(deprecated)]
+fn create_symbol(
+    document: &Document,
+    name: &str,
+    kind: SymbolKind,
+    span: Span,
+    name_span: Span,
+    children: Vec<DocumentSymbol>,
+) -> DocumentSymbol {
+    DocumentSymbol {
+        name: name.to_string(),
+        detail: None,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: document.get_range(span),
+        selection_range: document.get_range(name_span),
+        children: if children.is_empty() { None } else { Some(children) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::document;
+    use crate::tests::workspace;
+
+    use super::*;
+
+    fn flatten(symbols: &[DocumentSymbol], depth: usize, output: &mut Vec<String>) {
+        for symbol in symbols {
+            output.push(format!("{}{:?} {}", "  ".repeat(depth), symbol.kind, symbol.name));
+
+            flatten(symbol.children.as_deref().unwrap_or_default(), depth + 1, output);
+        }
+    }
+
+    #[test]
+    fn test_document_symbols() {
+        let workspace = workspace(&[]);
+        let document = document(
+            &workspace,
+            "src/index.php",
+            r#"<?php
+
+namespace App;
+
+const VERSION = '1.0', NAME = 'app';
+
+interface Greeter
+{
+    public function greet(string $name): string;
+}
+
+enum Status: string
+{
+    case Active = 'active';
+    case Inactive = 'inactive';
+}
+
+final class Service implements Greeter
+{
+    private const string PREFIX = 'Hello';
+
+    public function __construct(private string $suffix, public int $a = 0, public int $b = 1) {}
+
+    public string $name, $title;
+
+    public function greet(string $name): string
+    {
+        return self::PREFIX . $name;
+    }
+}
+
+function main(): void {}
+"#,
+        );
+
+        let mut symbols = vec![];
+        flatten(&get_document_symbols(&workspace.interner, &document), 0, &mut symbols);
+
+        assert_eq!(
+            symbols,
+            vec![
+                "Namespace App",
+                "  Constant VERSION",
+                "  Constant NAME",
+                "  Interface Greeter",
+                "    Method greet",
+                "  Enum Status",
+                "    EnumMember Active",
+                "    EnumMember Inactive",
+                "  Class Service",
+                "    Constant PREFIX",
+                "    Constructor __construct",
+                "    Property $name",
+                "    Property $title",
+                "    Method greet",
+                "  Function main",
+            ]
+        );
+    }
+}
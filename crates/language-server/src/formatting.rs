@@ -0,0 +1,157 @@
+use tower_lsp::lsp_types::Position;
+use tower_lsp::lsp_types::TextEdit;
+
+use mago_ast::Node;
+use mago_ast::Statement;
+use mago_formatter::format_range;
+use mago_parser::error::ParseError;
+use mago_span::HasSpan;
+
+use crate::Workspace;
+use crate::document::Document;
+
+/// Returns the edits formatting the code just typed in a document.
+///
+/// When `;` or `}` is typed, the outermost statement or class-like member ending with it is formatted.
+/// When a newline is typed, the line preceding the new line is formatted. Code overlapping a malformed
+/// region of the document is left untouched, as it is likely still being typed, and so are documents
+/// that fail to be tokenized, as their program is truncated.
+///
+/// # Parameters
+///
+/// - `workspace`: The workspace the document belongs to.
+/// - `document`: The document being edited.
+/// - `position`: The position of the cursor, right after the typed character.
+/// - `character`: The typed character.
+pub fn get_on_type_edits(
+    workspace: &Workspace,
+    document: &Document,
+    position: Position,
+    character: &str,
+) -> Option<Vec<TextEdit>> {
+    if document.module.parse_errors.iter().any(|error| matches!(error, ParseError::SyntaxError(_))) {
+        return None;
+    }
+
+    let range = match character {
+        ";" | "}" => {
+            let offset = document.get_offset(position);
+
+            Node::Program(&document.program)
+                .filter_map(|node| {
+                    let span = match node {
+                        Node::Statement(statement) => statement.span(),
+                        Node::ClassLikeMember(member) => member.span(),
+                        _ => return None,
+                    };
+
+                    if span.end.offset == offset { Some(span.start.offset..span.end.offset) } else { None }
+                })
+                .into_iter()
+                .min_by_key(|range| range.start)?
+        }
+        "\n" => {
+            let line = position.line.checked_sub(1)? as usize;
+            let start = document.source.get_line_start_offset(line)?;
+
+            start..document.get_offset(Position::new(line as u32, u32::MAX))
+        }
+        _ => return None,
+    };
+
+    let overlaps_error = Node::Program(&document.program)
+        .filter_map(|node| match node {
+            Node::Statement(Statement::Error(span)) => Some(*span),
+            _ => None,
+        })
+        .into_iter()
+        .any(|span| span.start.offset <= range.end && range.start <= span.end.offset);
+
+    if overlaps_error {
+        return None;
+    }
+
+    let plan = format_range(&workspace.interner, &document.source, &document.program, workspace.format_settings, range);
+
+    let edits = document.get_text_edits(&plan);
+
+    if edits.is_empty() { None } else { Some(edits) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::document;
+    use crate::tests::workspace;
+
+    use super::*;
+
+    fn format_on_type(content: &str, position: Position, character: &str) -> String {
+        let workspace = workspace(&[]);
+        let document = document(&workspace, "src/index.php", content);
+
+        let Some(edits) = get_on_type_edits(&workspace, &document, position, character) else {
+            return content.to_string();
+        };
+
+        let mut result = content.to_string();
+        for edit in edits.iter().rev() {
+            let start = document.get_offset(edit.range.start);
+            let end = document.get_offset(edit.range.end);
+
+            result.replace_range(start..end, &edit.new_text);
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_format_statement_on_semicolon() {
+        assert_eq!(
+            format_on_type("<?php\n\n$a   =   1;\n$b   =   2;\n", Position::new(3, 11), ";"),
+            "<?php\n\n$a   =   1;\n$b = 2;\n"
+        );
+    }
+
+    #[test]
+    fn test_format_block_on_closing_brace() {
+        assert_eq!(
+            format_on_type("<?php\n\nif ($a)   {\necho    1;\n}\n\n$b   =   2;\n", Position::new(4, 1), "}"),
+            "<?php\n\nif ($a) {\n    echo 1;\n}\n\n$b   =   2;\n"
+        );
+    }
+
+    #[test]
+    fn test_format_previous_line_on_newline() {
+        assert_eq!(
+            format_on_type("<?php\n\n$a   =   1;\n$b   =   2;\n", Position::new(3, 0), "\n"),
+            "<?php\n\n$a = 1;\n$b   =   2;\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_document_is_left_untouched() {
+        let content = "<?php\n\n$a   =   ;\n";
+
+        assert_eq!(format_on_type(content, Position::new(2, 10), ";"), content);
+    }
+
+    #[test]
+    fn test_valid_statement_is_formatted_in_invalid_document() {
+        assert_eq!(
+            format_on_type(
+                "<?php
+
+$a   =   ;
+$b   =   2;
+",
+                Position::new(3, 11),
+                ";"
+            ),
+            "<?php
+
+$a   =   ;
+$b = 2;
+"
+        );
+    }
+}
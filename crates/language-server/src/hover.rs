@@ -0,0 +1,173 @@
+use tower_lsp::lsp_types::Hover;
+use tower_lsp::lsp_types::HoverContents;
+use tower_lsp::lsp_types::MarkupContent;
+use tower_lsp::lsp_types::MarkupKind;
+use tower_lsp::lsp_types::Position;
+
+use mago_ast::Program;
+use mago_docblock::document::Element;
+use mago_docblock::document::TextSegment;
+use mago_docblock::get_docblock_before;
+use mago_docblock::parse_trivia;
+use mago_interner::ThreadedInterner;
+
+use crate::Workspace;
+use crate::document::Document;
+use crate::symbol::Declaration;
+use crate::symbol::get_declaration;
+use crate::symbol::get_reference_at;
+
+/// Returns the signature and the docblock summary of the symbol at the given position.
+pub fn get_hover(workspace: &Workspace, document: &Document, position: Position) -> Option<Hover> {
+    let reference = get_reference_at(workspace, document, document.get_offset(position))?;
+    let declaration = get_declaration(workspace, document, &reference.value)?;
+
+    let source_id = declaration.get_span().start.source;
+    let value = if source_id == document.source.identifier {
+        describe(&workspace.interner, &declaration, &document.content, &document.program)
+    } else {
+        let source = workspace.manager.load(&source_id).ok()?;
+        let (program, _) = mago_parser::parse_source(&workspace.interner, &source);
+
+        describe(&workspace.interner, &declaration, &source.content, &program)
+    };
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value }),
+        range: Some(document.get_range(reference.span)),
+    })
+}
+
+/// Describes the given declaration in markdown, using the content and the program of the source it belongs to.
+fn describe(interner: &ThreadedInterner, declaration: &Declaration<'_>, content: &str, program: &Program) -> String {
+    let span = declaration.get_span();
+    let attributes_end = span.start.offset + skip_attributes(&content[span.start.offset..span.end.offset]);
+
+    let signature = match declaration {
+        Declaration::Constant(reflection) => {
+            let item = &content[reflection.item_span.start.offset..reflection.item_span.end.offset];
+
+            if item.starts_with("define") { item.to_string() } else { format!("const {item}") }
+        }
+        _ => {
+            let declaration = &content[attributes_end..span.end.offset];
+
+            declaration[..declaration.find(['{', ';']).unwrap_or(declaration.len())].to_string()
+        }
+    };
+
+    let mut description = format!("```php\n{}\n```", signature.split_whitespace().collect::<Vec<_>>().join(" "));
+
+    let summary = get_docblock_before(program, span.start.offset)
+        .or_else(|| get_docblock_before(program, attributes_end))
+        .and_then(|docblock| parse_trivia(interner, docblock).ok())
+        .and_then(|docblock| match docblock.elements.into_iter().next() {
+            Some(Element::Text(text)) => Some(text),
+            _ => None,
+        })
+        .map(|text| {
+            text.segments
+                .iter()
+                .map(|segment| match segment {
+                    TextSegment::Paragraph { content, .. } => interner.lookup(content).to_string(),
+                    TextSegment::InlineCode(code) => format!("`{}`", interner.lookup(&code.content)),
+                    TextSegment::InlineTag(tag) => {
+                        format!("`{} {}`", interner.lookup(&tag.name), interner.lookup(&tag.description))
+                    }
+                })
+                .collect::<String>()
+        });
+
+    if let Some(summary) = summary.filter(|summary| !summary.trim().is_empty()) {
+        description.push_str("\n\n---\n\n");
+        description.push_str(summary.trim());
+    }
+
+    description
+}
+
+/// Returns the offset of the first character following the attributes at the start of the given text.
+fn skip_attributes(text: &str) -> usize {
+    let mut offset = 0;
+    loop {
+        let rest = &text[offset..];
+        let trimmed = rest.trim_start();
+        offset += rest.len() - trimmed.len();
+
+        if !trimmed.starts_with("#[") {
+            return offset;
+        }
+
+        let mut depth = 0;
+        let end = trimmed.char_indices().find_map(|(index, character)| {
+            match character {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                _ => return None,
+            }
+
+            if depth == 0 { Some(index + 1) } else { None }
+        });
+
+        match end {
+            Some(end) => offset += end,
+            None => return offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::document;
+    use crate::tests::workspace;
+
+    use super::*;
+
+    fn get_hover_value(hover: Option<Hover>) -> String {
+        match hover.map(|hover| hover.contents) {
+            Some(HoverContents::Markup(content)) => content.value,
+            contents => panic!("unexpected hover contents: {contents:?}"),
+        }
+    }
+
+    #[test]
+    fn test_hover_class() {
+        let workspace = workspace(&[(
+            "src/Greeter.php",
+            "<?php\n\nnamespace App;\n\n/**\n * Greets people.\n *\n * @internal\n */\n#[Foo([1])]\nfinal class Greeter extends Base implements Stringable\n{\n}\n",
+        )]);
+        let document = document(&workspace, "src/index.php", "<?php\n\nnew App\\Greeter();\n");
+
+        assert_eq!(
+            get_hover_value(get_hover(&workspace, &document, Position::new(2, 6))),
+            "```php\nfinal class Greeter extends Base implements Stringable\n```\n\n---\n\nGreets people."
+        );
+    }
+
+    #[test]
+    fn test_hover_function_and_constant() {
+        let workspace = workspace(&[]);
+        let document = document(
+            &workspace,
+            "src/index.php",
+            "<?php\n\nconst ANSWER = 42;\n\nfunction greet(string $name): string { return $name; }\n\necho greet('World'), ANSWER;\n",
+        );
+
+        assert_eq!(
+            get_hover_value(get_hover(&workspace, &document, Position::new(6, 6))),
+            "```php\nfunction greet(string $name): string\n```"
+        );
+        assert_eq!(
+            get_hover_value(get_hover(&workspace, &document, Position::new(6, 23))),
+            "```php\nconst ANSWER = 42\n```"
+        );
+    }
+
+    #[test]
+    fn test_hover_unknown_symbol() {
+        let workspace = workspace(&[]);
+        let document = document(&workspace, "src/index.php", "<?php\n\nnew Unknown();\n");
+
+        assert!(get_hover(&workspace, &document, Position::new(2, 6)).is_none());
+    }
+}
@@ -0,0 +1,742 @@
+use mago_ast::*;
+use mago_docblock::document::Element;
+use mago_docblock::document::Tag;
+use mago_docblock::get_declaration_docblock;
+use mago_docblock::get_docblock_before;
+use mago_docblock::parse_trivia;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_project::module::Module;
+use mago_reflection::CodebaseReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::class_like::property::PropertyReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
+use mago_reflection::r#type::kind::ObjectTypeKind;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::Workspace;
+
+/// Names of the types that may appear in a docblock, and never refer to a class-like.
+const BUILTIN_TYPES: &[&str] = &[
+    "array",
+    "array-key",
+    "bool",
+    "boolean",
+    "callable",
+    "class-string",
+    "double",
+    "false",
+    "float",
+    "int",
+    "integer",
+    "iterable",
+    "list",
+    "mixed",
+    "never",
+    "non-empty-array",
+    "non-empty-list",
+    "non-empty-string",
+    "null",
+    "numeric",
+    "numeric-string",
+    "object",
+    "resource",
+    "scalar",
+    "string",
+    "true",
+    "void",
+];
+
+/// A function-like declared in a program, delimiting the scope of its variables.
+#[derive(Debug, Clone, Copy)]
+pub enum FunctionLike<'a> {
+    Function(&'a Function),
+    Method(&'a Method),
+    Closure(&'a Closure),
+    ArrowFunction(&'a ArrowFunction),
+}
+
+impl<'a> FunctionLike<'a> {
+    pub fn span(&self) -> Span {
+        match self {
+            FunctionLike::Function(function) => function.span(),
+            FunctionLike::Method(method) => method.span(),
+            FunctionLike::Closure(closure) => closure.span(),
+            FunctionLike::ArrowFunction(arrow_function) => arrow_function.span(),
+        }
+    }
+
+    pub fn parameters(&self) -> impl Iterator<Item = &'a FunctionLikeParameter> {
+        let list = match self {
+            FunctionLike::Function(function) => &function.parameter_list,
+            FunctionLike::Method(method) => &method.parameter_list,
+            FunctionLike::Closure(closure) => &closure.parameter_list,
+            FunctionLike::ArrowFunction(arrow_function) => &arrow_function.parameter_list,
+        };
+
+        list.parameters.iter()
+    }
+
+    /// Returns whether the variables of the enclosing scope are visible, as in arrow functions.
+    pub fn inherits_scope(&self) -> bool {
+        matches!(self, FunctionLike::ArrowFunction(_))
+    }
+
+    /// Returns whether `$this` is available, i.e. the function-like is a non-static method or closure.
+    pub fn has_this(&self) -> bool {
+        match self {
+            FunctionLike::Function(_) => false,
+            FunctionLike::Method(method) => !method.modifiers.contains_static(),
+            FunctionLike::Closure(closure) => closure.r#static.is_none(),
+            FunctionLike::ArrowFunction(arrow_function) => arrow_function.r#static.is_none(),
+        }
+    }
+
+    fn attribute_lists(&self) -> &'a Sequence<AttributeList> {
+        match self {
+            FunctionLike::Function(function) => &function.attribute_lists,
+            FunctionLike::Method(method) => &method.attribute_lists,
+            FunctionLike::Closure(closure) => &closure.attribute_lists,
+            FunctionLike::ArrowFunction(arrow_function) => &arrow_function.attribute_lists,
+        }
+    }
+}
+
+/// Infers the class-like of expressions in a program, using native type hints, docblocks, and the
+/// assignments preceding the expression.
+///
+/// The inference is deliberately shallow: it follows assignments, calls, and property accesses, but does
+/// not narrow types through conditions, nor resolve generics.
+#[derive(Debug)]
+pub struct Inference<'a> {
+    workspace: &'a Workspace,
+    module: &'a Module,
+    program: &'a Program,
+    function_likes: Vec<FunctionLike<'a>>,
+    assignments: Vec<&'a Assignment>,
+}
+
+impl<'a> Inference<'a> {
+    pub fn new(workspace: &'a Workspace, module: &'a Module, program: &'a Program) -> Self {
+        let root = Node::Program(program);
+
+        let function_likes = root.filter_map(|node| match node {
+            Node::Function(function) => Some(FunctionLike::Function(function)),
+            Node::Method(method) => Some(FunctionLike::Method(method)),
+            Node::Closure(closure) => Some(FunctionLike::Closure(closure)),
+            Node::ArrowFunction(arrow_function) => Some(FunctionLike::ArrowFunction(arrow_function)),
+            _ => None,
+        });
+
+        let assignments = root.filter_map(|node| match node {
+            Node::Assignment(assignment) if matches!(assignment.operator, AssignmentOperator::Assign(_)) => {
+                Some(*assignment)
+            }
+            _ => None,
+        });
+
+        Self { workspace, module, program, function_likes, assignments }
+    }
+
+    pub fn interner(&self) -> &'a ThreadedInterner {
+        &self.workspace.interner
+    }
+
+    pub fn program(&self) -> &'a Program {
+        self.program
+    }
+
+    /// Returns the codebases used to look up class-likes, starting with the one of the program.
+    pub fn codebases(&self) -> impl Iterator<Item = &'a CodebaseReflection> {
+        self.module.reflection.iter().chain(std::iter::once(&self.workspace.codebase))
+    }
+
+    pub fn get_class_like(&self, name: &StringIdentifier) -> Option<&'a ClassLikeReflection> {
+        self.codebases().find_map(|codebase| codebase.get_named_class_like(self.interner(), name))
+    }
+
+    /// Returns the innermost function-like containing the given offset, if any.
+    pub fn get_function_like(&self, offset: usize) -> Option<FunctionLike<'a>> {
+        self.function_likes
+            .iter()
+            .filter(|function_like| function_like.span().has_offset(offset))
+            .max_by_key(|function_like| function_like.span().start.offset)
+            .copied()
+    }
+
+    /// Returns the innermost function-like enclosing the given function-like, if any.
+    pub fn get_parent_function_like(&self, function_like: &FunctionLike<'a>) -> Option<FunctionLike<'a>> {
+        let span = function_like.span();
+
+        self.function_likes
+            .iter()
+            .filter(|parent| {
+                let parent_span = parent.span();
+
+                parent_span.start.offset < span.start.offset && span.end.offset <= parent_span.end.offset
+            })
+            .max_by_key(|parent| parent.span().start.offset)
+            .copied()
+    }
+
+    /// Returns whether the given offset belongs to the scope of the given function-like, or to the
+    /// top-level scope if no function-like is given.
+    pub fn is_in_scope(&self, offset: usize, scope: Option<&FunctionLike<'a>>) -> bool {
+        let innermost = self.get_function_like(offset);
+
+        innermost.map(|function_like| function_like.span()) == scope.map(|function_like| function_like.span())
+    }
+
+    /// Returns the fully qualified name of the class-like enclosing the given offset, if any.
+    pub fn get_enclosing_class(&self, offset: usize) -> Option<StringIdentifier> {
+        Node::Program(self.program)
+            .filter_map(|node| {
+                let (span, name) = match node {
+                    Node::Class(class) => (class.span(), &class.name),
+                    Node::Interface(interface) => (interface.span(), &interface.name),
+                    Node::Trait(r#trait) => (r#trait.span(), &r#trait.name),
+                    Node::Enum(r#enum) => (r#enum.span(), &r#enum.name),
+                    _ => return None,
+                };
+
+                if span.has_offset(offset) { Some((span.start.offset, self.resolve(name, name.value))) } else { None }
+            })
+            .into_iter()
+            .max_by_key(|(start, _)| *start)
+            .map(|(_, name)| name)
+    }
+
+    /// Returns the fully qualified name of the class referred to by a class expression, e.g. `Foo` or `self`.
+    pub fn get_class_expression(&self, expression: &Expression) -> Option<StringIdentifier> {
+        let offset = expression.span().start.offset;
+
+        match expression {
+            Expression::Identifier(identifier) => Some(self.resolve(identifier, identifier.value())),
+            Expression::Self_(_) | Expression::Static(_) => self.get_enclosing_class(offset),
+            Expression::Parent(_) => self.get_parent_class(&self.get_enclosing_class(offset)?),
+            _ => None,
+        }
+    }
+
+    /// Infers the fully qualified name of the class-like of the value of the given expression.
+    pub fn infer(&self, expression: &Expression) -> Option<StringIdentifier> {
+        match expression {
+            Expression::Parenthesized(parenthesized) => self.infer(&parenthesized.expression),
+            Expression::Assignment(assignment) => self.infer(&assignment.rhs),
+            Expression::Clone(clone) => self.infer(&clone.object),
+            Expression::Instantiation(instantiation) => self.get_class_expression(&instantiation.class),
+            Expression::Variable(Variable::Direct(variable)) => {
+                self.infer_variable(&variable.name, variable.span.start.offset)
+            }
+            Expression::Call(Call::Function(call)) => {
+                let Expression::Identifier(identifier) = call.function.as_ref() else {
+                    return None;
+                };
+
+                self.infer_function_call(identifier)
+            }
+            Expression::Call(Call::Method(call)) => self.infer_method_call(self.infer(&call.object)?, &call.method),
+            Expression::Call(Call::NullSafeMethod(call)) => {
+                self.infer_method_call(self.infer(&call.object)?, &call.method)
+            }
+            Expression::Call(Call::StaticMethod(call)) => {
+                self.infer_method_call(self.get_class_expression(&call.class)?, &call.method)
+            }
+            Expression::Access(Access::Property(access)) => {
+                self.infer_property_access(self.infer(&access.object)?, &access.property)
+            }
+            Expression::Access(Access::NullSafeProperty(access)) => {
+                self.infer_property_access(self.infer(&access.object)?, &access.property)
+            }
+            Expression::Access(Access::StaticProperty(access)) => {
+                let Variable::Direct(variable) = &access.property else {
+                    return None;
+                };
+
+                let class = self.get_class_expression(&access.class)?;
+                let (owner, property) = self.get_property(&class, &variable.name)?;
+
+                self.get_property_class(owner, property)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the fully qualified name of the parent class of the given class, if any.
+    pub fn get_parent_class(&self, class: &StringIdentifier) -> Option<StringIdentifier> {
+        self.get_class_like(class)?.inheritance.direct_extended_class.map(|name| name.value)
+    }
+
+    /// Returns the source code of the given span, which may belong to another source than the program.
+    pub fn get_text(&self, span: Span) -> Option<String> {
+        let content = if span.start.source == self.module.source.identifier {
+            self.module.source.content.clone()
+        } else {
+            self.workspace.manager.load(&span.start.source).ok()?.content
+        };
+
+        content.get(span.start.offset..span.end.offset).map(str::to_string)
+    }
+
+    /// Returns the class-likes the given class-like inherits members from: its parent class, the traits
+    /// it uses, and the interfaces it implements or extends.
+    pub fn get_ancestors(&self, reflection: &ClassLikeReflection) -> Vec<StringIdentifier> {
+        let inheritance = &reflection.inheritance;
+
+        inheritance
+            .direct_extended_class
+            .iter()
+            .chain(reflection.used_traits.iter())
+            .chain(inheritance.direct_implemented_interfaces.iter())
+            .chain(inheritance.direct_extended_interfaces.iter())
+            .map(|name| name.value)
+            .collect()
+    }
+
+    /// Finds a member of the given class-like, or of one of its ancestors.
+    ///
+    /// Returns the class-like declaring the member, along with the member itself.
+    pub fn find_member<T: 'a>(
+        &self,
+        class: &StringIdentifier,
+        get_member: impl Fn(&'a ClassLikeReflection) -> Option<&'a T> + Copy,
+    ) -> Option<(&'a ClassLikeReflection, &'a T)> {
+        let mut pending = vec![*class];
+        let mut visited = vec![];
+
+        while let Some(name) = pending.pop() {
+            let Some(reflection) = self.get_class_like(&name) else {
+                continue;
+            };
+
+            if visited.contains(&reflection.name) {
+                continue;
+            }
+
+            visited.push(reflection.name);
+            if let Some(member) = get_member(reflection) {
+                return Some((reflection, member));
+            }
+
+            pending.extend(self.get_ancestors(reflection).into_iter().rev());
+        }
+
+        None
+    }
+
+    fn get_property(
+        &self,
+        class: &StringIdentifier,
+        name: &StringIdentifier,
+    ) -> Option<(&'a ClassLikeReflection, &'a PropertyReflection)> {
+        self.find_member(class, |reflection| reflection.properties.members.get(name))
+    }
+
+    /// Finds a property promoted by the constructor of the given class-like, or of one of its ancestors.
+    ///
+    /// Promoted properties are only reflected as constructor parameters, so they are looked up separately.
+    pub fn get_promoted_property(
+        &self,
+        class: &StringIdentifier,
+        name: &StringIdentifier,
+    ) -> Option<(&'a ClassLikeReflection, &'a FunctionLikeParameterReflection)> {
+        let constructor = self.interner().intern("__construct");
+
+        self.find_member(class, |reflection| {
+            let constructor = reflection.methods.members.get(&constructor)?;
+
+            constructor.parameters.iter().find(|parameter| parameter.is_promoted_property && parameter.name == *name)
+        })
+    }
+
+    fn infer_variable(&self, name: &StringIdentifier, offset: usize) -> Option<StringIdentifier> {
+        let interner = self.interner();
+        if interner.lookup(name) == "$this" {
+            return self.get_enclosing_class(offset);
+        }
+
+        let mut offset = offset;
+        let mut scope = self.get_function_like(offset);
+        loop {
+            if let Some(class) = self.infer_variable_in_scope(name, offset, scope.as_ref()) {
+                return Some(class);
+            }
+
+            let function_like = scope?;
+
+            if let Some(parameter) = function_like.parameters().find(|parameter| parameter.variable.name == *name) {
+                return self.get_parameter_class(&function_like, parameter);
+            }
+
+            let is_captured = match function_like {
+                FunctionLike::Closure(closure) => closure
+                    .use_clause
+                    .as_ref()
+                    .is_some_and(|clause| clause.variables.iter().any(|variable| variable.variable.name == *name)),
+                _ => function_like.inherits_scope(),
+            };
+
+            if !is_captured {
+                return None;
+            }
+
+            offset = function_like.span().start.offset;
+            scope = self.get_parent_function_like(&function_like);
+        }
+    }
+
+    /// Infers the class of a variable from the last assignment, or `@var` tag, preceding the given offset
+    /// within the given scope.
+    fn infer_variable_in_scope(
+        &self,
+        name: &StringIdentifier,
+        offset: usize,
+        scope: Option<&FunctionLike<'a>>,
+    ) -> Option<StringIdentifier> {
+        let interner = self.interner();
+        let variable = interner.lookup(name);
+
+        let assignment = self
+            .assignments
+            .iter()
+            .filter(|assignment| {
+                matches!(assignment.lhs.as_ref(), Expression::Variable(Variable::Direct(lhs)) if lhs.name == *name)
+            })
+            .filter(|assignment| assignment.span().end.offset <= offset)
+            .filter(|assignment| self.is_in_scope(assignment.span().start.offset, scope))
+            .max_by_key(|assignment| assignment.span().start.offset);
+
+        let annotation = self
+            .program
+            .trivia
+            .iter()
+            .filter(|trivia| matches!(trivia.kind, TriviaKind::DocBlockComment) && trivia.span.end.offset <= offset)
+            .filter(|trivia| self.is_in_scope(trivia.span.start.offset, scope))
+            .filter_map(|trivia| {
+                let document = parse_trivia(interner, trivia).ok()?;
+                let r#type = get_tag_type(interner, &document.elements, |tag| tag.kind.is_var(), Some(variable))?;
+
+                Some((trivia.span.end.offset, r#type))
+            })
+            .max_by_key(|(end, _)| *end);
+
+        match (assignment, annotation) {
+            (Some(assignment), annotation)
+                if annotation.as_ref().is_none_or(|(end, _)| *end <= assignment.span().start.offset) =>
+            {
+                let start = assignment.span().start.offset;
+                let annotated = get_docblock_before(self.program, start)
+                    .and_then(|docblock| parse_trivia(interner, docblock).ok())
+                    .and_then(|document| {
+                        get_tag_type(interner, &document.elements, |tag| tag.kind.is_var(), Some(variable))
+                            .or_else(|| get_tag_type(interner, &document.elements, |tag| tag.kind.is_var(), None))
+                    });
+
+                match annotated {
+                    Some(r#type) => self.resolve_type(self.program, start, &r#type, None),
+                    None => self.infer(&assignment.rhs),
+                }
+            }
+            (_, Some((end, r#type))) => self.resolve_type(self.program, end, &r#type, None),
+            _ => None,
+        }
+    }
+
+    fn get_parameter_class(
+        &self,
+        function_like: &FunctionLike<'a>,
+        parameter: &FunctionLikeParameter,
+    ) -> Option<StringIdentifier> {
+        let interner = self.interner();
+        let span = function_like.span();
+        let variable = interner.lookup(&parameter.variable.name);
+
+        let documented = get_declaration_docblock(self.program, function_like.attribute_lists(), span)
+            .and_then(|docblock| parse_trivia(interner, docblock).ok())
+            .and_then(|document| get_tag_type(interner, &document.elements, |tag| tag.kind.is_param(), Some(variable)))
+            .and_then(|r#type| self.resolve_type(self.program, span.start.offset, &r#type, None));
+
+        documented.or_else(|| parameter.hint.as_ref().and_then(|hint| self.get_hint_class(hint)))
+    }
+
+    fn get_hint_class(&self, hint: &Hint) -> Option<StringIdentifier> {
+        match hint {
+            Hint::Identifier(identifier) => Some(self.resolve(identifier, identifier.value())),
+            Hint::Nullable(nullable) => self.get_hint_class(&nullable.hint),
+            Hint::Parenthesized(parenthesized) => self.get_hint_class(&parenthesized.hint),
+            Hint::Union(union) => self.get_hint_class(&union.left).or_else(|| self.get_hint_class(&union.right)),
+            Hint::Self_(keyword) | Hint::Static(keyword) => self.get_enclosing_class(keyword.span.start.offset),
+            Hint::Parent(keyword) => self.get_parent_class(&self.get_enclosing_class(keyword.span.start.offset)?),
+            _ => None,
+        }
+    }
+
+    fn infer_function_call(&self, identifier: &Identifier) -> Option<StringIdentifier> {
+        let interner = self.interner();
+        let name = self.resolve(identifier, identifier.value());
+        let fallback = self.module.names.get_fallback(identifier).copied();
+
+        let function = self.codebases().find_map(|codebase| {
+            codebase
+                .get_function(interner, &name)
+                .or_else(|| fallback.and_then(|fallback| codebase.get_function(interner, &fallback)))
+        })?;
+
+        self.get_return_class(function, None)
+    }
+
+    fn infer_method_call(
+        &self,
+        class: StringIdentifier,
+        selector: &ClassLikeMemberSelector,
+    ) -> Option<StringIdentifier> {
+        let ClassLikeMemberSelector::Identifier(method) = selector else {
+            return None;
+        };
+
+        let method = self.interner().lowered(&method.value);
+        let (_, reflection) = self.find_member(&class, |reflection| reflection.methods.members.get(&method))?;
+
+        self.get_return_class(reflection, Some(class))
+    }
+
+    fn infer_property_access(
+        &self,
+        class: StringIdentifier,
+        selector: &ClassLikeMemberSelector,
+    ) -> Option<StringIdentifier> {
+        let ClassLikeMemberSelector::Identifier(property) = selector else {
+            return None;
+        };
+
+        let interner = self.interner();
+        let name = interner.intern(format!("${}", interner.lookup(&property.value)));
+        if let Some((owner, property)) = self.get_property(&class, &name) {
+            return self.get_property_class(owner, property);
+        }
+
+        let (owner, parameter) = self.get_promoted_property(&class, &name)?;
+
+        get_type_class(&parameter.type_reflection.as_ref()?.kind, get_class_like_name(owner))
+    }
+
+    /// Returns the class returned by the given function-like, preferring its `@return` tag over its
+    /// native return type, as the former is usually more precise.
+    ///
+    /// `static` return types resolve to the given receiver, if any.
+    fn get_return_class(
+        &self,
+        function_like: &FunctionLikeReflection,
+        receiver: Option<StringIdentifier>,
+    ) -> Option<StringIdentifier> {
+        let documented = self.get_documented_class(function_like.span, receiver, |tag| tag.kind.is_return(), None);
+
+        documented.or_else(|| {
+            let kind = &function_like.return_type_reflection.as_ref()?.type_reflection.kind;
+
+            get_type_class(kind, receiver)
+        })
+    }
+
+    fn get_property_class(
+        &self,
+        owner: &ClassLikeReflection,
+        property: &PropertyReflection,
+    ) -> Option<StringIdentifier> {
+        let scope = get_class_like_name(owner);
+        let variable = self.interner().lookup(&property.name.member.value);
+
+        let documented = self
+            .get_documented_class(property.definition_span, scope, |tag| tag.kind.is_var(), Some(variable))
+            .or_else(|| self.get_documented_class(property.definition_span, scope, |tag| tag.kind.is_var(), None));
+
+        documented.or_else(|| get_type_class(&property.type_reflection.as_ref()?.kind, scope))
+    }
+
+    /// Returns the class declared by a tag of the docblock preceding the declaration at the given span.
+    ///
+    /// The declaration may belong to another source, in which case the source is parsed to find the
+    /// docblock, and to resolve the declared type against its imports.
+    fn get_documented_class(
+        &self,
+        span: Span,
+        scope: Option<StringIdentifier>,
+        filter: impl Fn(&Tag) -> bool,
+        variable: Option<&str>,
+    ) -> Option<StringIdentifier> {
+        let interner = self.interner();
+
+        let parsed;
+        let program = if span.start.source == self.module.source.identifier {
+            self.program
+        } else {
+            let source = self.workspace.manager.load(&span.start.source).ok()?;
+            parsed = mago_parser::parse_source(interner, &source).0;
+
+            &parsed
+        };
+
+        let docblock = get_docblock_before(program, span.start.offset)?;
+        let document = parse_trivia(interner, docblock).ok()?;
+        let r#type = get_tag_type(interner, &document.elements, filter, variable)?;
+
+        self.resolve_type(program, span.start.offset, &r#type, scope)
+    }
+
+    /// Resolves the class-like named in the given docblock type, e.g. `?Foo` or `Foo|null`.
+    ///
+    /// Names are resolved against the namespace and imports of the given program at the given offset,
+    /// while `self`, `static`, and `$this` resolve to the given scope or, failing that, to the class-like
+    /// enclosing the offset.
+    fn resolve_type(
+        &self,
+        program: &Program,
+        offset: usize,
+        r#type: &str,
+        scope: Option<StringIdentifier>,
+    ) -> Option<StringIdentifier> {
+        let interner = self.interner();
+
+        for part in r#type.trim_start_matches('?').split('|') {
+            let name = part.split('<').next().unwrap_or_default().trim();
+            if name.is_empty() || name.ends_with("[]") || BUILTIN_TYPES.contains(&name.to_ascii_lowercase().as_str()) {
+                continue;
+            }
+
+            if matches!(name.to_ascii_lowercase().as_str(), "self" | "static" | "$this") {
+                return scope.or_else(|| self.get_enclosing_class(offset));
+            }
+
+            return Some(interner.intern(resolve_class_name(interner, program, offset, name)));
+        }
+
+        None
+    }
+
+    /// Returns the resolved name of the given identifier, falling back to the identifier itself.
+    fn resolve(&self, identifier: &impl HasSpan, value: StringIdentifier) -> StringIdentifier {
+        let names = &self.module.names;
+        let position = identifier.span().start;
+
+        if names.contains(&position) { *names.get(&position) } else { value }
+    }
+}
+
+/// Returns the fully qualified name of the given class-like, unless it is anonymous.
+pub fn get_class_like_name(reflection: &ClassLikeReflection) -> Option<StringIdentifier> {
+    use mago_reflection::identifier::ClassLikeName;
+
+    match reflection.name {
+        ClassLikeName::Class(name)
+        | ClassLikeName::Interface(name)
+        | ClassLikeName::Enum(name)
+        | ClassLikeName::Trait(name) => Some(name.value),
+        ClassLikeName::AnonymousClass(_) => None,
+    }
+}
+
+/// Returns the class-like named by the given type, if any.
+fn get_type_class(kind: &TypeKind, scope: Option<StringIdentifier>) -> Option<StringIdentifier> {
+    match kind {
+        TypeKind::Object(ObjectTypeKind::NamedObject { name, .. }) => Some(*name),
+        TypeKind::Object(ObjectTypeKind::Static { scope: declaring } | ObjectTypeKind::Self_ { scope: declaring }) => {
+            Some(scope.unwrap_or(*declaring))
+        }
+        TypeKind::Union { kinds } => kinds.iter().find_map(|kind| get_type_class(kind, scope)),
+        _ => None,
+    }
+}
+
+/// Returns the type declared by the first tag accepted by the given filter.
+///
+/// When a variable is given, only tags describing that variable are considered, e.g. `@param Foo $foo`;
+/// otherwise, only tags that do not name a variable are, e.g. `@return Foo`.
+fn get_tag_type(
+    interner: &ThreadedInterner,
+    elements: &[Element],
+    filter: impl Fn(&Tag) -> bool,
+    variable: Option<&str>,
+) -> Option<String> {
+    elements.iter().find_map(|element| {
+        let Element::Tag(tag) = element else {
+            return None;
+        };
+
+        if !filter(tag) {
+            return None;
+        }
+
+        let mut words = interner.lookup(&tag.description).split_whitespace();
+        let r#type = words.next()?;
+        let described = words.next().filter(|word| word.starts_with('$'));
+
+        if described != variable {
+            return None;
+        }
+
+        Some(r#type.to_string())
+    })
+}
+
+/// Resolves a class name found in a docblock against the namespace and imports of the given program.
+fn resolve_class_name(interner: &ThreadedInterner, program: &Program, offset: usize, name: &str) -> String {
+    if let Some(name) = name.strip_prefix('\\') {
+        return name.to_string();
+    }
+
+    let mut namespace = None;
+    let mut statements = program.statements.iter().collect::<Vec<_>>();
+    for statement in program.statements.iter() {
+        if let Statement::Namespace(declaration) = statement {
+            if declaration.span().has_offset(offset) {
+                namespace = declaration.name.as_ref().map(|name| interner.lookup(&name.value()));
+                statements.extend(declaration.statements().iter());
+            }
+        }
+    }
+
+    let (first, rest) = match name.split_once('\\') {
+        Some((first, rest)) => (first, Some(rest)),
+        None => (name, None),
+    };
+
+    for statement in statements {
+        let Statement::Use(r#use) = statement else {
+            continue;
+        };
+
+        let items: Vec<(String, &UseItem)> = match &r#use.items {
+            UseItems::Sequence(sequence) => sequence.items.iter().map(|item| (String::new(), item)).collect(),
+            UseItems::MixedList(list) => list
+                .items
+                .iter()
+                .filter(|item| item.r#type.is_none())
+                .map(|item| (format!("{}\\", interner.lookup(&list.namespace.value())), &item.item))
+                .collect(),
+            _ => continue,
+        };
+
+        for (prefix, item) in items {
+            let imported = interner.lookup(&item.name.value());
+            let alias = match &item.alias {
+                Some(alias) => interner.lookup(&alias.identifier.value),
+                None => imported.rsplit('\\').next().unwrap_or(imported),
+            };
+
+            if alias.eq_ignore_ascii_case(first) {
+                return match rest {
+                    Some(rest) => format!("{prefix}{imported}\\{rest}"),
+                    None => format!("{prefix}{imported}"),
+                };
+            }
+        }
+    }
+
+    match namespace {
+        Some(namespace) => format!("{namespace}\\{name}"),
+        None => name.to_string(),
+    }
+}
@@ -0,0 +1,172 @@
+use std::path::PathBuf;
+
+use tower_lsp::LspService;
+use tower_lsp::Server;
+use tower_lsp::lsp_types::Location;
+use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::Url;
+
+use mago_formatter::settings::FormatSettings;
+use mago_interner::InternerSnapshot;
+use mago_interner::ThreadedInterner;
+use mago_linter::Linter;
+use mago_php_version::PHPVersion;
+use mago_reflection::CodebaseReflection;
+use mago_source::Source;
+use mago_source::SourceManager;
+use mago_span::Span;
+
+use crate::backend::Backend;
+use crate::document::get_position;
+
+mod backend;
+mod code_action;
+mod completion;
+mod definition;
+mod diagnostic;
+mod document;
+mod document_symbol;
+mod formatting;
+mod hover;
+mod inference;
+mod semantic_token;
+mod symbol;
+mod workspace_symbol;
+
+/// The workspace served by the language server.
+///
+/// The workspace is analyzed once, before the server is started. Open documents are re-linted against
+/// the resulting codebase every time they change.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    /// The interner used to build the codebase, and to parse open documents.
+    pub interner: ThreadedInterner,
+    /// A snapshot of the interner taken once the workspace is loaded, which it is reset to as open documents change.
+    pub snapshot: InternerSnapshot,
+    /// The PHP version used to parse open documents.
+    pub php_version: PHPVersion,
+    /// The linter, configured for the workspace.
+    pub linter: Linter,
+    /// The formatter settings, used to format open documents as they are edited.
+    pub format_settings: FormatSettings,
+    /// Whether completion is enabled, as it is opt-in.
+    pub completion: bool,
+    /// The sources of the workspace, including stubs and external sources.
+    pub manager: SourceManager,
+    /// The reflection of the workspace, used to resolve symbols to their declarations.
+    pub codebase: CodebaseReflection,
+    /// The root directory of the workspace, used to name open documents consistently with the CLI.
+    pub root: PathBuf,
+}
+
+impl Workspace {
+    /// Resets the interner to the strings interned while loading the workspace.
+    ///
+    /// This drops the strings interned for open documents since then, so every open document must
+    /// be analyzed again afterwards, as the identifiers it holds are no longer valid.
+    pub fn reset_interner(&mut self) {
+        self.interner = self.interner.reset(&self.snapshot);
+        self.linter = self.linter.clone().with_interner(self.interner.clone());
+        self.manager = self.manager.clone().with_interner(self.interner.clone());
+    }
+
+    /// Returns the URI of the given source, if it is located on disk.
+    ///
+    /// User-defined sources that were not loaded from disk are assumed to be named relative to the workspace root.
+    pub fn get_source_uri(&self, source: &Source) -> Option<Url> {
+        let path = match &source.path {
+            Some(path) => path.clone(),
+            None if source.identifier.category().is_user_defined() => {
+                self.root.join(self.interner.lookup(&source.identifier.value()))
+            }
+            None => return None,
+        };
+
+        Url::from_file_path(path).ok()
+    }
+
+    /// Returns the location of the given span, as stored in the workspace sources.
+    ///
+    /// Spans of open documents should be resolved using the document instead, as its content may
+    /// have changed since the workspace was loaded.
+    pub fn get_location(&self, span: Span) -> Option<Location> {
+        let source = self.manager.load(&span.start.source).ok()?;
+        let uri = self.get_source_uri(&source)?;
+        let content = &source.content;
+
+        Some(Location::new(
+            uri,
+            Range::new(
+                get_position(&source, content, span.start.offset),
+                get_position(&source, content, span.end.offset),
+            ),
+        ))
+    }
+}
+
+/// Starts the language server, communicating with the client over stdin and stdout.
+///
+/// This function returns once the client has shut down the server.
+///
+/// # Parameters
+///
+/// - `workspace`: The workspace to serve.
+pub async fn serve(workspace: Workspace) {
+    let (service, socket) = LspService::new(|client| Backend::new(client, workspace));
+
+    Server::new(tokio::io::stdin(), tokio::io::stdout(), socket).serve(service).await;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::path::PathBuf;
+
+    use tower_lsp::lsp_types::Url;
+
+    use mago_formatter::settings::FormatSettings;
+    use mago_interner::ThreadedInterner;
+    use mago_linter::Linter;
+    use mago_linter::settings::Settings;
+    use mago_php_version::PHPVersion;
+    use mago_project::ProjectBuilder;
+    use mago_project::module::Module;
+    use mago_project::module::ModuleBuildOptions;
+    use mago_source::SourceCategory;
+    use mago_source::SourceManager;
+
+    use crate::Workspace;
+    use crate::document::Document;
+
+    /// Creates a workspace rooted at `/project`, containing the given user-defined sources.
+    pub fn workspace(sources: &[(&str, &str)]) -> Workspace {
+        let interner = ThreadedInterner::new();
+        let manager = SourceManager::new(interner.clone());
+
+        let mut builder = ProjectBuilder::new(interner.clone());
+        for (name, content) in sources {
+            let source = manager.load(&manager.insert_content(name, content, SourceCategory::UserDefined)).unwrap();
+
+            builder.add_module(Module::build(&interner, PHPVersion::PHP84, source, ModuleBuildOptions::default()));
+        }
+
+        let codebase = builder.build(true).reflection;
+        let linter = Linter::with_all_plugins(Settings::new(PHPVersion::PHP84), interner.clone(), codebase.clone());
+
+        Workspace {
+            snapshot: interner.snapshot(),
+            interner,
+            php_version: PHPVersion::PHP84,
+            linter,
+            format_settings: FormatSettings::default(),
+            completion: true,
+            manager,
+            codebase,
+            root: PathBuf::from("/project"),
+        }
+    }
+
+    /// Opens the given document, named relative to the root of the given workspace.
+    pub fn document(workspace: &Workspace, name: &str, content: &str) -> Document {
+        Document::new(workspace, Url::parse(&format!("file:///project/{name}")).unwrap(), content.to_string())
+    }
+}
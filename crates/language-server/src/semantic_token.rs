@@ -0,0 +1,685 @@
+use std::collections::HashSet;
+
+use tower_lsp::lsp_types::Range;
+use tower_lsp::lsp_types::SemanticToken;
+use tower_lsp::lsp_types::SemanticTokenModifier;
+use tower_lsp::lsp_types::SemanticTokenType;
+use tower_lsp::lsp_types::SemanticTokensLegend;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reflection::CodebaseReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_span::HasPosition;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::Workspace;
+use crate::document::Document;
+
+/// The token types reported by the server, in the order of their index in the legend.
+///
+/// Constants are reported as read-only variables, as LSP has no dedicated token type for them.
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::METHOD,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::VARIABLE,
+];
+
+/// The token modifiers reported by the server, in the order of their bit in the legend.
+const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::STATIC,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::ABSTRACT,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+];
+
+// The bits of the modifiers above, as set in the modifiers bitset of a token.
+const DECLARATION: u32 = 1 << 0;
+const STATIC: u32 = 1 << 1;
+const READONLY: u32 = 1 << 2;
+const ABSTRACT: u32 = 1 << 3;
+const DEFAULT_LIBRARY: u32 = 1 << 4;
+
+/// Returns the legend of the semantic tokens reported by the server.
+pub fn get_legend() -> SemanticTokensLegend {
+    SemanticTokensLegend { token_types: TOKEN_TYPES.to_vec(), token_modifiers: TOKEN_MODIFIERS.to_vec() }
+}
+
+/// Returns the semantic tokens of the given document, optionally restricted to the given range.
+///
+/// Names are classified using the resolved names of the document, so that e.g. an identifier used as a
+/// type hint is reported as an interface or an enum depending on the declaration it resolves to.
+/// Symbols declared outside of the project, e.g. in the PHP stubs, are marked with the `defaultLibrary`
+/// modifier.
+pub fn get_semantic_tokens(workspace: &Workspace, document: &Document, range: Option<Range>) -> Vec<SemanticToken> {
+    let mut context = Context { workspace, document, tokens: vec![], scopes: vec![] };
+    SemanticTokenWalker.walk_program(&document.program, &mut context);
+
+    let (start, end) = match range {
+        Some(range) => (document.get_offset(range.start), document.get_offset(range.end)),
+        None => (0, document.content.len()),
+    };
+
+    // Tokens are pushed before the tokens of their children, so the first token found at a given
+    // offset is the most specific one, e.g. a parameter declaration rather than a plain variable.
+    let mut tokens = std::mem::take(&mut context.tokens);
+    tokens.sort_by_key(|token| token.span.start.offset);
+
+    let mut data = vec![];
+    let mut previous_end = 0;
+    let mut previous_position = None;
+    for token in tokens {
+        if token.span.start.offset < previous_end || token.span.start.offset < start || token.span.end.offset > end {
+            continue;
+        }
+
+        let position = document.get_position(token.span.start.offset);
+        let end_position = document.get_position(token.span.end.offset);
+        if end_position.line != position.line {
+            continue;
+        }
+
+        let (delta_line, delta_start) = match previous_position {
+            Some((line, character)) if line == position.line => (0, position.character - character),
+            Some((line, _)) => (position.line - line, position.character),
+            None => (position.line, position.character),
+        };
+
+        data.push(SemanticToken {
+            delta_line,
+            delta_start,
+            length: end_position.character - position.character,
+            token_type: token.token_type,
+            token_modifiers_bitset: token.modifiers,
+        });
+
+        previous_end = token.span.end.offset;
+        previous_position = Some((position.line, position.character));
+    }
+
+    data
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    span: Span,
+    token_type: u32,
+    modifiers: u32,
+}
+
+#[derive(Debug)]
+struct Scope {
+    parameters: HashSet<StringIdentifier>,
+    /// Whether the parameters of the enclosing scope are visible, as in arrow functions.
+    inherits: bool,
+}
+
+#[derive(Debug)]
+struct Context<'a> {
+    workspace: &'a Workspace,
+    document: &'a Document,
+    tokens: Vec<Token>,
+    scopes: Vec<Scope>,
+}
+
+impl Context<'_> {
+    fn push(&mut self, span: Span, token_type: SemanticTokenType, modifiers: u32) {
+        let Some(token_type) = TOKEN_TYPES.iter().position(|candidate| *candidate == token_type) else {
+            return;
+        };
+
+        self.tokens.push(Token { span, token_type: token_type as u32, modifiers });
+    }
+
+    fn enter_scope(&mut self, inherits: bool) {
+        self.scopes.push(Scope { parameters: HashSet::new(), inherits });
+    }
+
+    fn is_parameter(&self, name: &StringIdentifier) -> bool {
+        for scope in self.scopes.iter().rev() {
+            if scope.parameters.contains(name) {
+                return true;
+            }
+
+            if !scope.inherits {
+                break;
+            }
+        }
+
+        false
+    }
+
+    fn codebases(&self) -> impl Iterator<Item = &CodebaseReflection> {
+        self.document.module.reflection.iter().chain(std::iter::once(&self.workspace.codebase))
+    }
+
+    /// Returns the resolved name of the given identifier, falling back to the identifier itself.
+    fn resolve(&self, identifier: &impl HasPosition, value: StringIdentifier) -> StringIdentifier {
+        let names = &self.document.module.names;
+
+        if names.contains(&identifier.position()) { *names.get(identifier) } else { value }
+    }
+
+    /// Pushes a token for the given identifier, referring to a class-like.
+    fn push_class_like(&mut self, identifier: &Identifier) {
+        let name = self.resolve(identifier, identifier.value());
+        let interner = &self.workspace.interner;
+
+        let reflection = self.codebases().find_map(|codebase| codebase.get_named_class_like(interner, &name));
+        let token_type = match reflection.map(|reflection| reflection.name) {
+            Some(ClassLikeName::Interface(_)) => SemanticTokenType::INTERFACE,
+            Some(ClassLikeName::Enum(_)) => SemanticTokenType::ENUM,
+            _ => SemanticTokenType::CLASS,
+        };
+
+        let is_default_library = reflection.is_some_and(|reflection| is_default_library(reflection.span));
+
+        self.push(identifier.span(), token_type, if is_default_library { DEFAULT_LIBRARY } else { 0 });
+    }
+
+    /// Pushes a token for the given identifier, referring to a function.
+    fn push_function(&mut self, identifier: &Identifier) {
+        let names = &self.document.module.names;
+        let name = self.resolve(identifier, identifier.value());
+        let fallback = names.get_fallback(identifier).copied();
+        let interner = &self.workspace.interner;
+
+        let is_default_library = self
+            .codebases()
+            .find_map(|codebase| {
+                codebase
+                    .get_function(interner, &name)
+                    .or_else(|| fallback.and_then(|fallback| codebase.get_function(interner, &fallback)))
+            })
+            .is_some_and(|reflection| is_default_library(reflection.span));
+
+        self.push(identifier.span(), SemanticTokenType::FUNCTION, if is_default_library { DEFAULT_LIBRARY } else { 0 });
+    }
+
+    /// Pushes a token for the given identifier, referring to a constant.
+    fn push_constant(&mut self, identifier: &Identifier) {
+        let names = &self.document.module.names;
+        let name = self.resolve(identifier, identifier.value());
+        let fallback = names.get_fallback(identifier).copied();
+        let interner = &self.workspace.interner;
+
+        let is_default_library = self
+            .codebases()
+            .find_map(|codebase| {
+                codebase
+                    .get_constant(interner, &name)
+                    .or_else(|| fallback.and_then(|fallback| codebase.get_constant(interner, &fallback)))
+            })
+            .is_some_and(|reflection| is_default_library(reflection.name.span));
+
+        self.push(
+            identifier.span(),
+            SemanticTokenType::VARIABLE,
+            if is_default_library { READONLY | DEFAULT_LIBRARY } else { READONLY },
+        );
+    }
+
+    /// Pushes a token for the given expression, if it is an identifier referring to a class-like.
+    fn push_class_like_expression(&mut self, expression: &Expression) {
+        if let Expression::Identifier(identifier) = expression {
+            self.push_class_like(identifier);
+        }
+    }
+
+    /// Pushes a token for the given member selector, if it is an identifier.
+    fn push_member(&mut self, selector: &ClassLikeMemberSelector, token_type: SemanticTokenType, is_static: bool) {
+        if let ClassLikeMemberSelector::Identifier(identifier) = selector {
+            self.push(identifier.span, token_type, if is_static { STATIC } else { 0 });
+        }
+    }
+
+    /// Pushes a token for the given use item, referring to a symbol of the given type.
+    fn push_use_item(&mut self, item: &UseItem, name: StringIdentifier, r#type: Option<&UseType>) {
+        let identifiers =
+            std::iter::once(item.name.span()).chain(item.alias.as_ref().map(|alias| alias.identifier.span));
+
+        for span in identifiers {
+            match r#type {
+                Some(UseType::Function(_)) => self.push(span, SemanticTokenType::FUNCTION, 0),
+                Some(UseType::Const(_)) => self.push(span, SemanticTokenType::VARIABLE, READONLY),
+                None => {
+                    let interner = &self.workspace.interner;
+                    let token_type = match self
+                        .codebases()
+                        .find_map(|codebase| codebase.get_named_class_like(interner, &name))
+                        .map(|reflection| reflection.name)
+                    {
+                        Some(ClassLikeName::Interface(_)) => SemanticTokenType::INTERFACE,
+                        Some(ClassLikeName::Enum(_)) => SemanticTokenType::ENUM,
+                        _ => SemanticTokenType::CLASS,
+                    };
+
+                    self.push(span, token_type, 0);
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether the declaration at the given span is part of the PHP stubs, or an external library.
+fn is_default_library(span: Span) -> bool {
+    !span.start.source.category().is_user_defined()
+}
+
+#[derive(Debug)]
+struct SemanticTokenWalker;
+
+impl<'a> Walker<Context<'a>> for SemanticTokenWalker {
+    fn walk_in_namespace(&self, namespace: &Namespace, context: &mut Context<'a>) {
+        if let Some(name) = &namespace.name {
+            context.push(name.span(), SemanticTokenType::NAMESPACE, 0);
+        }
+    }
+
+    fn walk_in_use(&self, r#use: &Use, context: &mut Context<'a>) {
+        let interner = &context.workspace.interner;
+
+        match &r#use.items {
+            UseItems::Sequence(sequence) => {
+                for item in sequence.items.iter() {
+                    context.push_use_item(item, item.name.value(), None);
+                }
+            }
+            UseItems::TypedSequence(sequence) => {
+                for item in sequence.items.iter() {
+                    context.push_use_item(item, item.name.value(), Some(&sequence.r#type));
+                }
+            }
+            UseItems::TypedList(list) => {
+                let prefix = interner.lookup(&list.namespace.value());
+
+                for item in list.items.iter() {
+                    let name = interner.intern(format!("{}\\{}", prefix, interner.lookup(&item.name.value())));
+
+                    context.push_use_item(item, name, Some(&list.r#type));
+                }
+            }
+            UseItems::MixedList(list) => {
+                let prefix = interner.lookup(&list.namespace.value());
+
+                for item in list.items.iter() {
+                    let name = interner.intern(format!("{}\\{}", prefix, interner.lookup(&item.item.name.value())));
+
+                    context.push_use_item(&item.item, name, item.r#type.as_ref());
+                }
+            }
+        }
+    }
+
+    fn walk_in_class(&self, class: &Class, context: &mut Context<'a>) {
+        let modifiers = if class.modifiers.contains_abstract() { DECLARATION | ABSTRACT } else { DECLARATION };
+
+        context.push(class.name.span, SemanticTokenType::CLASS, modifiers);
+    }
+
+    fn walk_in_interface(&self, interface: &Interface, context: &mut Context<'a>) {
+        context.push(interface.name.span, SemanticTokenType::INTERFACE, DECLARATION);
+    }
+
+    fn walk_in_trait(&self, r#trait: &Trait, context: &mut Context<'a>) {
+        context.push(r#trait.name.span, SemanticTokenType::CLASS, DECLARATION);
+    }
+
+    fn walk_in_enum(&self, r#enum: &Enum, context: &mut Context<'a>) {
+        context.push(r#enum.name.span, SemanticTokenType::ENUM, DECLARATION);
+    }
+
+    fn walk_in_extends(&self, extends: &Extends, context: &mut Context<'a>) {
+        for identifier in extends.types.iter() {
+            context.push_class_like(identifier);
+        }
+    }
+
+    fn walk_in_implements(&self, implements: &Implements, context: &mut Context<'a>) {
+        for identifier in implements.types.iter() {
+            context.push_class_like(identifier);
+        }
+    }
+
+    fn walk_in_trait_use(&self, trait_use: &TraitUse, context: &mut Context<'a>) {
+        for identifier in trait_use.trait_names.iter() {
+            context.push_class_like(identifier);
+        }
+    }
+
+    fn walk_in_attribute(&self, attribute: &Attribute, context: &mut Context<'a>) {
+        context.push_class_like(&attribute.name);
+    }
+
+    fn walk_in_hint(&self, hint: &Hint, context: &mut Context<'a>) {
+        if let Hint::Identifier(identifier) = hint {
+            context.push_class_like(identifier);
+        }
+    }
+
+    fn walk_in_instantiation(&self, instantiation: &Instantiation, context: &mut Context<'a>) {
+        context.push_class_like_expression(&instantiation.class);
+    }
+
+    fn walk_in_binary(&self, binary: &Binary, context: &mut Context<'a>) {
+        if let BinaryOperator::Instanceof(_) = binary.operator {
+            context.push_class_like_expression(&binary.rhs);
+        }
+    }
+
+    fn walk_in_function(&self, function: &Function, context: &mut Context<'a>) {
+        context.push(function.name.span, SemanticTokenType::FUNCTION, DECLARATION);
+        context.enter_scope(false);
+    }
+
+    fn walk_out_function(&self, _function: &Function, context: &mut Context<'a>) {
+        context.scopes.pop();
+    }
+
+    fn walk_in_method(&self, method: &Method, context: &mut Context<'a>) {
+        let mut modifiers = DECLARATION;
+        if method.modifiers.contains_static() {
+            modifiers |= STATIC;
+        }
+
+        if method.modifiers.contains_abstract() {
+            modifiers |= ABSTRACT;
+        }
+
+        context.push(method.name.span, SemanticTokenType::METHOD, modifiers);
+        context.enter_scope(false);
+    }
+
+    fn walk_out_method(&self, _method: &Method, context: &mut Context<'a>) {
+        context.scopes.pop();
+    }
+
+    fn walk_in_property_hook(&self, property_hook: &PropertyHook, context: &mut Context<'a>) {
+        context.push(property_hook.name.span, SemanticTokenType::METHOD, DECLARATION);
+        context.enter_scope(false);
+    }
+
+    fn walk_out_property_hook(&self, _property_hook: &PropertyHook, context: &mut Context<'a>) {
+        context.scopes.pop();
+    }
+
+    fn walk_in_closure(&self, _closure: &Closure, context: &mut Context<'a>) {
+        context.enter_scope(false);
+    }
+
+    fn walk_out_closure(&self, _closure: &Closure, context: &mut Context<'a>) {
+        context.scopes.pop();
+    }
+
+    fn walk_in_arrow_function(&self, _arrow_function: &ArrowFunction, context: &mut Context<'a>) {
+        context.enter_scope(true);
+    }
+
+    fn walk_out_arrow_function(&self, _arrow_function: &ArrowFunction, context: &mut Context<'a>) {
+        context.scopes.pop();
+    }
+
+    fn walk_in_function_like_parameter(&self, parameter: &FunctionLikeParameter, context: &mut Context<'a>) {
+        let variable = &parameter.variable;
+
+        if parameter.modifiers.is_empty() {
+            context.push(variable.span, SemanticTokenType::PARAMETER, DECLARATION);
+        } else {
+            let modifiers = if parameter.modifiers.contains_readonly() { DECLARATION | READONLY } else { DECLARATION };
+
+            context.push(variable.span, SemanticTokenType::PROPERTY, modifiers);
+        }
+
+        if let Some(scope) = context.scopes.last_mut() {
+            scope.parameters.insert(variable.name);
+        }
+    }
+
+    fn walk_in_plain_property(&self, property: &PlainProperty, context: &mut Context<'a>) {
+        for item in property.items.iter() {
+            push_property_declaration(item.variable(), &property.modifiers, context);
+        }
+    }
+
+    fn walk_in_hooked_property(&self, property: &HookedProperty, context: &mut Context<'a>) {
+        push_property_declaration(property.item.variable(), &property.modifiers, context);
+    }
+
+    fn walk_in_class_like_constant_item(&self, item: &ClassLikeConstantItem, context: &mut Context<'a>) {
+        context.push(item.name.span, SemanticTokenType::VARIABLE, DECLARATION | READONLY);
+    }
+
+    fn walk_in_enum_case(&self, enum_case: &EnumCase, context: &mut Context<'a>) {
+        let name = match &enum_case.item {
+            EnumCaseItem::Unit(item) => &item.name,
+            EnumCaseItem::Backed(item) => &item.name,
+        };
+
+        context.push(name.span, SemanticTokenType::ENUM_MEMBER, DECLARATION);
+    }
+
+    fn walk_in_constant_item(&self, item: &ConstantItem, context: &mut Context<'a>) {
+        context.push(item.name.span, SemanticTokenType::VARIABLE, DECLARATION | READONLY);
+    }
+
+    fn walk_in_call(&self, call: &Call, context: &mut Context<'a>) {
+        match call {
+            Call::Function(call) => {
+                if let Expression::Identifier(identifier) = call.function.as_ref() {
+                    context.push_function(identifier);
+                }
+            }
+            Call::Method(call) => context.push_member(&call.method, SemanticTokenType::METHOD, false),
+            Call::NullSafeMethod(call) => context.push_member(&call.method, SemanticTokenType::METHOD, false),
+            Call::StaticMethod(call) => {
+                context.push_class_like_expression(&call.class);
+                context.push_member(&call.method, SemanticTokenType::METHOD, true);
+            }
+        }
+    }
+
+    fn walk_in_closure_creation(&self, closure_creation: &ClosureCreation, context: &mut Context<'a>) {
+        match closure_creation {
+            ClosureCreation::Function(creation) => {
+                if let Expression::Identifier(identifier) = creation.function.as_ref() {
+                    context.push_function(identifier);
+                }
+            }
+            ClosureCreation::Method(creation) => {
+                context.push_member(&creation.method, SemanticTokenType::METHOD, false)
+            }
+            ClosureCreation::StaticMethod(creation) => {
+                context.push_class_like_expression(&creation.class);
+                context.push_member(&creation.method, SemanticTokenType::METHOD, true);
+            }
+        }
+    }
+
+    fn walk_in_access(&self, access: &Access, context: &mut Context<'a>) {
+        match access {
+            Access::Property(access) => context.push_member(&access.property, SemanticTokenType::PROPERTY, false),
+            Access::NullSafeProperty(access) => {
+                context.push_member(&access.property, SemanticTokenType::PROPERTY, false);
+            }
+            Access::StaticProperty(access) => {
+                context.push_class_like_expression(&access.class);
+
+                if let Variable::Direct(variable) = &access.property {
+                    context.push(variable.span, SemanticTokenType::PROPERTY, STATIC);
+                }
+            }
+            Access::ClassConstant(access) => {
+                context.push_class_like_expression(&access.class);
+
+                let ClassLikeConstantSelector::Identifier(identifier) = &access.constant else {
+                    return;
+                };
+
+                let interner = &context.workspace.interner;
+                if interner.lookup(&identifier.value).eq_ignore_ascii_case("class") {
+                    return;
+                }
+
+                let is_enum_case = match access.class.as_ref() {
+                    Expression::Identifier(class) => {
+                        let name = context.resolve(class, class.value());
+
+                        context
+                            .codebases()
+                            .find_map(|codebase| codebase.get_named_class_like(interner, &name))
+                            .is_some_and(|reflection| reflection.cases.contains_key(&identifier.value))
+                    }
+                    _ => false,
+                };
+
+                if is_enum_case {
+                    context.push(identifier.span, SemanticTokenType::ENUM_MEMBER, 0);
+                } else {
+                    context.push(identifier.span, SemanticTokenType::VARIABLE, STATIC | READONLY);
+                }
+            }
+        }
+    }
+
+    fn walk_in_constant_access(&self, constant_access: &ConstantAccess, context: &mut Context<'a>) {
+        context.push_constant(&constant_access.name);
+    }
+
+    fn walk_in_named_argument(&self, named_argument: &NamedArgument, context: &mut Context<'a>) {
+        context.push(named_argument.name.span, SemanticTokenType::PARAMETER, 0);
+    }
+
+    fn walk_in_direct_variable(&self, variable: &DirectVariable, context: &mut Context<'a>) {
+        if context.is_parameter(&variable.name) {
+            context.push(variable.span, SemanticTokenType::PARAMETER, 0);
+        } else {
+            context.push(variable.span, SemanticTokenType::VARIABLE, 0);
+        }
+    }
+}
+
+fn push_property_declaration(variable: &DirectVariable, modifiers: &Sequence<Modifier>, context: &mut Context<'_>) {
+    let mut token_modifiers = DECLARATION;
+    if modifiers.contains_static() {
+        token_modifiers |= STATIC;
+    }
+
+    if modifiers.contains_readonly() {
+        token_modifiers |= READONLY;
+    }
+
+    context.push(variable.span, SemanticTokenType::PROPERTY, token_modifiers);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::document;
+    use crate::tests::workspace;
+
+    use super::*;
+
+    /// Decodes the tokens of the given content, as `(text, type, modifiers)` tuples.
+    fn get_tokens(workspace: &Workspace, content: &str) -> Vec<(String, String, Vec<String>)> {
+        let document = document(workspace, "src/index.php", content);
+        let lines = content.lines().collect::<Vec<_>>();
+
+        let mut line = 0;
+        let mut character = 0;
+        let mut tokens = vec![];
+        for token in get_semantic_tokens(workspace, &document, None) {
+            if token.delta_line > 0 {
+                line += token.delta_line as usize;
+                character = 0;
+            }
+
+            character += token.delta_start as usize;
+
+            let text = lines[line].chars().skip(character).take(token.length as usize).collect::<String>();
+            let modifiers = TOKEN_MODIFIERS
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| token.token_modifiers_bitset & (1 << index) != 0)
+                .map(|(_, modifier)| modifier.as_str().to_string())
+                .collect();
+
+            tokens.push((text, TOKEN_TYPES[token.token_type as usize].as_str().to_string(), modifiers));
+        }
+
+        tokens
+    }
+
+    fn token(text: &str, token_type: &str, modifiers: &[&str]) -> (String, String, Vec<String>) {
+        (text.to_string(), token_type.to_string(), modifiers.iter().map(|modifier| modifier.to_string()).collect())
+    }
+
+    #[test]
+    fn test_semantic_tokens() {
+        let workspace = workspace(&[(
+            "src/Status.php",
+            "<?php\n\nnamespace App;\n\ninterface HasLabel {}\n\nenum Status implements HasLabel\n{\n    case Active;\n}\n",
+        )]);
+
+        let tokens = get_tokens(
+            &workspace,
+            r#"<?php
+
+namespace App;
+
+final class Service
+{
+    public const string PREFIX = 'a';
+
+    public function __construct(private readonly HasLabel $label) {}
+
+    public static function create(Status $status = Status::Active): self
+    {
+        return new self(label: $status);
+    }
+}
+
+$service = Service::create(fn($status) => $status);
+echo strlen(PHP_EOL), $service->label, Service::PREFIX;
+"#,
+        );
+
+        assert_eq!(
+            tokens,
+            vec![
+                token("App", "namespace", &[]),
+                token("Service", "class", &["declaration"]),
+                token("PREFIX", "variable", &["declaration", "readonly"]),
+                token("__construct", "method", &["declaration"]),
+                token("HasLabel", "interface", &[]),
+                token("$label", "property", &["declaration", "readonly"]),
+                token("create", "method", &["declaration", "static"]),
+                token("Status", "enum", &[]),
+                token("$status", "parameter", &["declaration"]),
+                token("Status", "enum", &[]),
+                token("Active", "enumMember", &[]),
+                token("label", "parameter", &[]),
+                token("$status", "parameter", &[]),
+                token("$service", "variable", &[]),
+                token("Service", "class", &[]),
+                token("create", "method", &["static"]),
+                token("$status", "parameter", &["declaration"]),
+                token("$status", "parameter", &[]),
+                token("strlen", "function", &[]),
+                token("PHP_EOL", "variable", &["readonly"]),
+                token("$service", "variable", &[]),
+                token("label", "property", &[]),
+                token("Service", "class", &[]),
+                token("PREFIX", "variable", &["static", "readonly"]),
+            ]
+        );
+    }
+}
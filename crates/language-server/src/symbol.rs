@@ -0,0 +1,109 @@
+use tower_lsp::lsp_types::Location;
+
+use mago_interner::StringIdentifier;
+use mago_reference::Reference;
+use mago_reference::ReferenceFinder;
+use mago_reference::query::Query;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::constant::ConstantReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_span::Span;
+
+use crate::Workspace;
+use crate::document::Document;
+
+/// The declaration of a symbol, found either in an open document or in the workspace codebase.
+#[derive(Debug, Clone, Copy)]
+pub enum Declaration<'a> {
+    ClassLike(&'a ClassLikeReflection),
+    Function(&'a FunctionLikeReflection),
+    Constant(&'a ConstantReflection),
+}
+
+impl Declaration<'_> {
+    /// Returns the span of the name of the declared symbol.
+    pub fn get_name_span(&self) -> Span {
+        match self {
+            Declaration::ClassLike(reflection) => match reflection.name {
+                ClassLikeName::Class(name)
+                | ClassLikeName::Interface(name)
+                | ClassLikeName::Enum(name)
+                | ClassLikeName::Trait(name) => name.span,
+                ClassLikeName::AnonymousClass(span) => span,
+            },
+            Declaration::Function(reflection) => match reflection.name {
+                FunctionLikeName::Function(name) => name.span,
+                _ => reflection.span,
+            },
+            Declaration::Constant(reflection) => reflection.name.span,
+        }
+    }
+
+    /// Returns the span of the whole declaration, including its attributes, if any.
+    pub fn get_span(&self) -> Span {
+        match self {
+            Declaration::ClassLike(reflection) => reflection.span,
+            Declaration::Function(reflection) => reflection.span,
+            Declaration::Constant(reflection) => reflection.definition_span,
+        }
+    }
+}
+
+/// Returns the reference to a symbol found at the given offset of a document, if any.
+///
+/// References include usages, imports, and declarations of class-likes, functions, and constants.
+pub fn get_reference_at(workspace: &Workspace, document: &Document, offset: usize) -> Option<Reference> {
+    ReferenceFinder::new(&workspace.interner)
+        .find(&document.module, &document.program, Query::Contains(String::new(), false))
+        .into_iter()
+        .find(|reference| reference.span.has_offset(offset))
+}
+
+/// Returns the declaration of the symbol with the given fully qualified name.
+///
+/// Declarations in the given document take precedence over the ones in the workspace codebase, as the
+/// codebase is not updated as documents change. Functions and constants fall back to the global namespace,
+/// as PHP does at runtime.
+pub fn get_declaration<'a>(
+    workspace: &'a Workspace,
+    document: &'a Document,
+    name: &StringIdentifier,
+) -> Option<Declaration<'a>> {
+    let interner = &workspace.interner;
+    let codebases = || document.module.reflection.iter().chain(std::iter::once(&workspace.codebase));
+
+    for codebase in codebases() {
+        if let Some(reflection) = codebase.get_named_class_like(interner, name) {
+            return Some(Declaration::ClassLike(reflection));
+        }
+
+        if let Some(reflection) = codebase.get_function(interner, name) {
+            return Some(Declaration::Function(reflection));
+        }
+
+        if let Some(reflection) = codebase.get_constant(interner, name) {
+            return Some(Declaration::Constant(reflection));
+        }
+    }
+
+    let (_, global_name) = interner.lookup(name).rsplit_once('\\')?;
+    let global_name = interner.intern(global_name);
+
+    codebases().find_map(|codebase| {
+        codebase
+            .get_function(interner, &global_name)
+            .map(Declaration::Function)
+            .or_else(|| codebase.get_constant(interner, &global_name).map(Declaration::Constant))
+    })
+}
+
+/// Returns the location of the given span, if the source it belongs to is located on disk.
+pub fn get_location(workspace: &Workspace, document: &Document, span: Span) -> Option<Location> {
+    if span.start.source == document.source.identifier {
+        return Some(Location::new(document.uri.clone(), document.get_range(span)));
+    }
+
+    workspace.get_location(span)
+}
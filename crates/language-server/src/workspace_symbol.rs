@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+
+use tower_lsp::lsp_types::Location;
+use tower_lsp::lsp_types::SymbolInformation;
+use tower_lsp::lsp_types::SymbolKind;
+
+use mago_reflection::CodebaseReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_source::SourceIdentifier;
+use mago_span::Span;
+
+use crate::Workspace;
+use crate::document::Document;
+
+/// The maximum number of symbols returned by a workspace symbol search.
+const MAXIMUM_WORKSPACE_SYMBOLS: usize = 128;
+
+/// Searches the class-likes, functions, and constants declared in the workspace.
+///
+/// Declarations are matched by their fully qualified name, using a fuzzy search: every character of the
+/// query must appear in the name, in order, ignoring case. Declarations in open documents are taken from
+/// the documents themselves, as their content may have changed since the workspace was loaded.
+///
+/// # Parameters
+///
+/// - `workspace`: The workspace to search.
+/// - `documents`: The documents opened in the client.
+/// - `query`: The query to match declarations against.
+pub fn get_workspace_symbols<'a>(
+    workspace: &Workspace,
+    documents: impl IntoIterator<Item = &'a Document>,
+    query: &str,
+) -> Vec<SymbolInformation> {
+    let mut candidates = vec![];
+    let mut searched = HashSet::new();
+
+    for document in documents {
+        searched.insert(document.source.identifier);
+
+        if let Some(codebase) = &document.module.reflection {
+            collect_candidates(
+                workspace,
+                codebase,
+                |_| true,
+                &mut candidates,
+                |span| Some(Location::new(document.uri.clone(), document.get_range(span))),
+            );
+        }
+    }
+
+    collect_candidates(
+        workspace,
+        &workspace.codebase,
+        |source| source.category().is_user_defined() && !searched.contains(source),
+        &mut candidates,
+        |span| workspace.get_location(span),
+    );
+
+    let mut matches = candidates
+        .into_iter()
+        .filter_map(|(name, symbol)| get_fuzzy_score(query, &name).map(|score| (score, name, symbol)))
+        .collect::<Vec<_>>();
+
+    // Better matches come first, and shorter names first among equally good matches.
+    matches.sort_by(|(a_score, a_name, _), (b_score, b_name, _)| {
+        b_score.cmp(a_score).then_with(|| a_name.len().cmp(&b_name.len())).then_with(|| a_name.cmp(b_name))
+    });
+    matches.into_iter().take(MAXIMUM_WORKSPACE_SYMBOLS).map(|(_, _, symbol)| symbol).collect()
+}
+
+/// Collects the declarations of the given codebase whose source is accepted by the given filter.
+///
+/// Each candidate is paired with its fully qualified name, used for matching.
+#[allow(deprecated)]
+fn collect_candidates(
+    workspace: &Workspace,
+    codebase: &CodebaseReflection,
+    filter: impl Fn(&SourceIdentifier) -> bool,
+    candidates: &mut Vec<(String, SymbolInformation)>,
+    get_location: impl Fn(Span) -> Option<Location>,
+) {
+    let class_likes = codebase.class_like_reflections.values().filter_map(|reflection| {
+        let (name, kind) = match reflection.name {
+            ClassLikeName::Class(name) | ClassLikeName::Trait(name) => (name, SymbolKind::CLASS),
+            ClassLikeName::Interface(name) => (name, SymbolKind::INTERFACE),
+            ClassLikeName::Enum(name) => (name, SymbolKind::ENUM),
+            ClassLikeName::AnonymousClass(_) => return None,
+        };
+
+        Some((name.value, kind, name.span))
+    });
+
+    let functions = codebase.function_like_reflections.values().filter_map(|reflection| match reflection.name {
+        FunctionLikeName::Function(name) => Some((name.value, SymbolKind::FUNCTION, name.span)),
+        _ => None,
+    });
+
+    let constants = codebase
+        .constant_reflections
+        .values()
+        .map(|reflection| (reflection.name.value, SymbolKind::CONSTANT, reflection.name.span));
+
+    for (name, kind, span) in class_likes.chain(functions).chain(constants) {
+        if !filter(&span.start.source) {
+            continue;
+        }
+
+        let Some(location) = get_location(span) else {
+            continue;
+        };
+
+        let name = workspace.interner.lookup(&name);
+        let (container_name, short_name) = match name.rsplit_once('\\') {
+            Some((namespace, short_name)) => (Some(namespace.to_string()), short_name),
+            None => (None, name),
+        };
+
+        candidates.push((
+            name.to_string(),
+            SymbolInformation {
+                name: short_name.to_string(),
+                kind,
+                tags: None,
+                deprecated: None,
+                location,
+                container_name,
+            },
+        ));
+    }
+}
+
+/// Returns the score of the given name for the given query, or `None` if the name does not match.
+///
+/// Every character of the query must appear in the name, in order, ignoring case. Consecutive characters,
+/// and characters starting a word, e.g. `G` in `App\Greeter` or `R` in `UserRepository`, score higher.
+/// The best scoring alignment of the query is used, so that `UR` matches the `R` of `Repository` rather
+/// than the one of `User`.
+pub(crate) fn get_fuzzy_score(query: &str, name: &str) -> Option<usize> {
+    let query = query.chars().filter(|character| !character.is_whitespace()).collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let get_base_score = |index: usize| {
+        let starts_word = match index.checked_sub(1).map(|previous| name[previous]) {
+            None => true,
+            Some(previous) => {
+                previous == '\\' || previous == '_' || (previous.is_lowercase() && name[index].is_uppercase())
+            }
+        };
+
+        if starts_word { 4 } else { 1 }
+    };
+
+    // `scores[index]` is the best score of matching the rest of the query, given that its current
+    // character is matched at `index` in the name.
+    let mut scores: Vec<Option<usize>> = vec![None; name.len()];
+    for (query_index, expected) in query.iter().enumerate().rev() {
+        let mut next_scores = vec![None; name.len()];
+
+        for (index, character) in name.iter().enumerate() {
+            if !character.eq_ignore_ascii_case(expected) {
+                continue;
+            }
+
+            let rest = if query_index == query.len() - 1 {
+                Some(0)
+            } else {
+                (index + 1..name.len())
+                    .filter_map(|next| scores[next].map(|score| if next == index + 1 { score + 2 } else { score }))
+                    .max()
+            };
+
+            next_scores[index] = rest.map(|rest| rest + get_base_score(index));
+        }
+
+        scores = next_scores;
+    }
+
+    scores.into_iter().flatten().max()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::document;
+    use crate::tests::workspace;
+
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(get_fuzzy_score("grt", "App\\Greeter").is_some());
+        assert!(get_fuzzy_score("trg", "App\\Greeter").is_none());
+        assert!(get_fuzzy_score("", "App\\Greeter").is_some());
+
+        assert!(get_fuzzy_score("Greeter", "App\\Greeter") > get_fuzzy_score("Greeter", "App\\GreetingReporter"));
+        assert!(get_fuzzy_score("UR", "App\\UserRepository") > get_fuzzy_score("UR", "App\\Uncurried"));
+    }
+
+    #[test]
+    fn test_workspace_symbols() {
+        let workspace = workspace(&[
+            ("src/Greeter.php", "<?php\n\nnamespace App;\n\nfinal class Greeter {}\n\nfunction greet() {}\n"),
+            ("src/Stale.php", "<?php\n\nnamespace App;\n\nfinal class Stale {}\n"),
+        ]);
+
+        let documents = [document(&workspace, "src/Stale.php", "<?php\n\nnamespace App;\n\nenum GreetingStatus {}\n")];
+
+        let symbols = get_workspace_symbols(&workspace, &documents, "greet")
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.kind, symbol.container_name, symbol.location.uri.path().to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            symbols,
+            vec![
+                (
+                    "greet".to_string(),
+                    SymbolKind::FUNCTION,
+                    Some("App".to_string()),
+                    "/project/src/Greeter.php".to_string()
+                ),
+                (
+                    "Greeter".to_string(),
+                    SymbolKind::CLASS,
+                    Some("App".to_string()),
+                    "/project/src/Greeter.php".to_string()
+                ),
+                (
+                    "GreetingStatus".to_string(),
+                    SymbolKind::ENUM,
+                    Some("App".to_string()),
+                    "/project/src/Stale.php".to_string()
+                ),
+            ]
+        );
+    }
+}
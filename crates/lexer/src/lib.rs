@@ -49,6 +49,7 @@ pub struct Lexer<'a, 'i> {
     input: Input<'a>,
     mode: LexerMode<'a>,
     interpolating: bool,
+    short_open_tag: bool,
 }
 
 impl<'a, 'i> Lexer<'a, 'i> {
@@ -63,7 +64,7 @@ impl<'a, 'i> Lexer<'a, 'i> {
     ///
     /// A new `Lexer` instance that reads from the provided byte slice.
     pub fn new(interner: &'i ThreadedInterner, input: Input<'a>) -> Lexer<'a, 'i> {
-        Lexer { interner, input, mode: LexerMode::Inline, interpolating: false }
+        Lexer { interner, input, mode: LexerMode::Inline, interpolating: false, short_open_tag: true }
     }
 
     /// Creates a new `Lexer` instance for parsing a script block.
@@ -77,7 +78,16 @@ impl<'a, 'i> Lexer<'a, 'i> {
     ///
     /// A new `Lexer` instance that reads from the provided byte slice.
     pub fn scripting(interner: &'i ThreadedInterner, input: Input<'a>) -> Lexer<'a, 'i> {
-        Lexer { interner, input, mode: LexerMode::Script, interpolating: false }
+        Lexer { interner, input, mode: LexerMode::Script, interpolating: false, short_open_tag: true }
+    }
+
+    /// Sets whether the short opening tag, `<?`, is recognized.
+    ///
+    /// When disabled, `<?` is treated as inline text unless it is part of a `<?php` or `<?=` tag,
+    /// mirroring PHP's `short_open_tag` ini setting. Short opening tags are recognized by default.
+    pub fn with_short_open_tag(mut self, short_open_tag: bool) -> Self {
+        self.short_open_tag = short_open_tag;
+        self
     }
 
     /// Check if the lexer has reached the end of the input.
@@ -156,7 +166,7 @@ impl<'a, 'i> Lexer<'a, 'i> {
         match self.mode {
             LexerMode::Inline => {
                 let start = self.input.position();
-                if self.input.is_at(b"<?", false) {
+                if self.is_at_opening_tag() {
                     let (kind, buffer) = if self.input.is_at(b"<?php", true) {
                         (TokenKind::OpenTag, self.input.consume(5))
                     } else if self.input.is_at(b"<?=", false) {
@@ -179,16 +189,26 @@ impl<'a, 'i> Lexer<'a, 'i> {
 
                     self.token(TokenKind::InlineShebang, buffer, start, end)
                 } else {
-                    let buffer = self.input.consume_until(b"<?", false);
+                    loop {
+                        self.input.consume_until(b"<?", false);
+                        if self.input.has_reached_eof() || self.is_at_opening_tag() {
+                            break;
+                        }
+
+                        // A `<?` that does not open a tag is part of the inline text.
+                        self.input.skip(2);
+                    }
+
                     let end = self.input.position();
+                    let buffer = &self.input.bytes[start.offset..end.offset];
 
                     self.token(TokenKind::InlineText, buffer, start, end)
                 }
             }
             LexerMode::Script => {
+                let start = self.input.position();
                 let whitespaces = self.input.consume_whitespaces();
                 if !whitespaces.is_empty() {
-                    let start = self.input.position();
                     let buffer = whitespaces;
                     let end = self.input.position();
 
@@ -924,10 +944,7 @@ impl<'a, 'i> Lexer<'a, 'i> {
 
                             self.token(TokenKind::CloseTag, buffer, start, end)
                         } else {
-                            return Some(Err(SyntaxError::UnexpectedToken(
-                                self.input.read(1)[0],
-                                self.input.position(),
-                            )));
+                            Some(Err(SyntaxError::UnexpectedToken(self.input.read(1)[0], self.input.position())))
                         }
                     }
                     _ => unreachable!(),
@@ -936,6 +953,12 @@ impl<'a, 'i> Lexer<'a, 'i> {
         }
     }
 
+    #[inline]
+    fn is_at_opening_tag(&self) -> bool {
+        self.input.is_at(b"<?", false)
+            && (self.short_open_tag || self.input.is_at(b"<?php", true) || self.input.is_at(b"<?=", false))
+    }
+
     #[inline]
     fn token(
         &mut self,
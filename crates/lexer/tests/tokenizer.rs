@@ -914,10 +914,67 @@ fn test_escape_in_string() -> Result<(), SyntaxError> {
     })
 }
 
+#[test]
+fn test_short_open_tag() -> Result<(), SyntaxError> {
+    let code = b"<?xml version=\"1.0\"?>\n<? echo 1;";
+    let expected = vec![
+        TokenKind::ShortOpenTag,
+        TokenKind::Identifier,
+        TokenKind::Whitespace,
+        TokenKind::Identifier,
+        TokenKind::Equal,
+        TokenKind::LiteralString,
+        TokenKind::CloseTag,
+        TokenKind::InlineText,
+        TokenKind::ShortOpenTag,
+        TokenKind::Whitespace,
+        TokenKind::Echo,
+        TokenKind::Whitespace,
+        TokenKind::LiteralInteger,
+        TokenKind::Semicolon,
+    ];
+
+    test_lexer(code, expected).map_err(|err| {
+        panic!("unexpected error: {}", err);
+    })
+}
+
+#[test]
+fn test_short_open_tag_disabled() -> Result<(), SyntaxError> {
+    let code = b"<?xml version=\"1.0\"?>\n<? echo 1; ?>\n<?= $a ?>\n<?PHP echo 2;";
+    let expected = vec![
+        TokenKind::InlineText,
+        TokenKind::EchoTag,
+        TokenKind::Whitespace,
+        TokenKind::Variable,
+        TokenKind::Whitespace,
+        TokenKind::CloseTag,
+        TokenKind::InlineText,
+        TokenKind::OpenTag,
+        TokenKind::Whitespace,
+        TokenKind::Echo,
+        TokenKind::Whitespace,
+        TokenKind::LiteralInteger,
+        TokenKind::Semicolon,
+    ];
+
+    test_lexer_with_short_open_tag(code, false, expected).map_err(|err| {
+        panic!("unexpected error: {}", err);
+    })
+}
+
 fn test_lexer(code: &[u8], expected_kinds: Vec<TokenKind>) -> Result<(), SyntaxError> {
+    test_lexer_with_short_open_tag(code, true, expected_kinds)
+}
+
+fn test_lexer_with_short_open_tag(
+    code: &[u8],
+    short_open_tag: bool,
+    expected_kinds: Vec<TokenKind>,
+) -> Result<(), SyntaxError> {
     let interner = ThreadedInterner::new();
     let input = Input::new(SourceIdentifier::dummy(), code);
-    let mut lexer = Lexer::new(&interner, input);
+    let mut lexer = Lexer::new(&interner, input).with_short_open_tag(short_open_tag);
 
     let mut tokens = Vec::new();
     let mut error = None;
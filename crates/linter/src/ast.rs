@@ -1,4 +1,5 @@
 use mago_ast::Node;
+use mago_ast::stack::ensure_sufficient_stack;
 
 /// A precomputed tree node for the AST.
 ///
@@ -27,7 +28,7 @@ impl<'a> From<Node<'a>> for AstNode<'a> {
         let node_children = node.children();
         let mut children = Vec::with_capacity(node_children.len());
         for child in node_children {
-            children.push(AstNode::from(child));
+            children.push(ensure_sufficient_stack(|| AstNode::from(child)));
         }
 
         Self { node, children }
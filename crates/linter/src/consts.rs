@@ -1894,3 +1894,188 @@ pub const EXTENSION_FUNCTIONS: [(&str, &[&str]); 48] = [
         ],
     ),
 ];
+
+/// List of common misspellings and their corrections, sorted by misspelling.
+///
+/// This list is not exhaustive, and only contains words that are almost always misspelled.
+pub const MISSPELLINGS: [(&str, &str); 179] = [
+    ("accomodate", "accommodate"),
+    ("accross", "across"),
+    ("acheive", "achieve"),
+    ("adress", "address"),
+    ("agressive", "aggressive"),
+    ("aleady", "already"),
+    ("algoritm", "algorithm"),
+    ("allready", "already"),
+    ("amoung", "among"),
+    ("anonymus", "anonymous"),
+    ("apparantly", "apparently"),
+    ("appearence", "appearance"),
+    ("arguement", "argument"),
+    ("assigment", "assignment"),
+    ("asssert", "assert"),
+    ("asyncronous", "asynchronous"),
+    ("attribue", "attribute"),
+    ("authenticaton", "authentication"),
+    ("availabe", "available"),
+    ("availible", "available"),
+    ("basicly", "basically"),
+    ("becasue", "because"),
+    ("becuase", "because"),
+    ("begining", "beginning"),
+    ("beleive", "believe"),
+    ("boundry", "boundary"),
+    ("buisness", "business"),
+    ("calender", "calendar"),
+    ("charactor", "character"),
+    ("childs", "children"),
+    ("collapsable", "collapsible"),
+    ("comming", "coming"),
+    ("commited", "committed"),
+    ("comparision", "comparison"),
+    ("compatability", "compatibility"),
+    ("compatable", "compatible"),
+    ("completly", "completely"),
+    ("concatinate", "concatenate"),
+    ("configuraton", "configuration"),
+    ("connnection", "connection"),
+    ("consistant", "consistent"),
+    ("containg", "containing"),
+    ("continous", "continuous"),
+    ("convertion", "conversion"),
+    ("correclty", "correctly"),
+    ("curent", "current"),
+    ("definately", "definitely"),
+    ("defualt", "default"),
+    ("delimeter", "delimiter"),
+    ("dependancy", "dependency"),
+    ("depricated", "deprecated"),
+    ("desciption", "description"),
+    ("descripton", "description"),
+    ("destory", "destroy"),
+    ("diffrent", "different"),
+    ("dissapear", "disappear"),
+    ("efficent", "efficient"),
+    ("elemnt", "element"),
+    ("enviroment", "environment"),
+    ("equivelant", "equivalent"),
+    ("excecute", "execute"),
+    ("exisiting", "existing"),
+    ("existance", "existence"),
+    ("existant", "existent"),
+    ("explicitely", "explicitly"),
+    ("extention", "extension"),
+    ("failiure", "failure"),
+    ("familar", "familiar"),
+    ("fucntion", "function"),
+    ("funtion", "function"),
+    ("garantee", "guarantee"),
+    ("gaurd", "guard"),
+    ("goverment", "government"),
+    ("grammer", "grammar"),
+    ("guarentee", "guarantee"),
+    ("happend", "happened"),
+    ("heirarchy", "hierarchy"),
+    ("hieght", "height"),
+    ("identifer", "identifier"),
+    ("immediatly", "immediately"),
+    ("implemention", "implementation"),
+    ("implmentation", "implementation"),
+    ("incomming", "incoming"),
+    ("indentifier", "identifier"),
+    ("independant", "independent"),
+    ("infomation", "information"),
+    ("inital", "initial"),
+    ("initalize", "initialize"),
+    ("intance", "instance"),
+    ("interupt", "interrupt"),
+    ("invaild", "invalid"),
+    ("irrelevent", "irrelevant"),
+    ("kown", "known"),
+    ("langauge", "language"),
+    ("lenght", "length"),
+    ("libary", "library"),
+    ("maintainance", "maintenance"),
+    ("managment", "management"),
+    ("mesage", "message"),
+    ("messsage", "message"),
+    ("millenium", "millennium"),
+    ("mispell", "misspell"),
+    ("neccessary", "necessary"),
+    ("necesary", "necessary"),
+    ("nessecary", "necessary"),
+    ("noticable", "noticeable"),
+    ("occured", "occurred"),
+    ("occurence", "occurrence"),
+    ("occurrance", "occurrence"),
+    ("ocurred", "occurred"),
+    ("offical", "official"),
+    ("optionnal", "optional"),
+    ("orignal", "original"),
+    ("overriden", "overridden"),
+    ("paramater", "parameter"),
+    ("paramter", "parameter"),
+    ("parrallel", "parallel"),
+    ("particualr", "particular"),
+    ("passowrd", "password"),
+    ("peformance", "performance"),
+    ("perfomance", "performance"),
+    ("permision", "permission"),
+    ("persistant", "persistent"),
+    ("posible", "possible"),
+    ("potentialy", "potentially"),
+    ("precendence", "precedence"),
+    ("prefered", "preferred"),
+    ("presense", "presence"),
+    ("previus", "previous"),
+    ("priviledge", "privilege"),
+    ("probaly", "probably"),
+    ("proccess", "process"),
+    ("processsing", "processing"),
+    ("propery", "property"),
+    ("publically", "publicly"),
+    ("recepient", "recipient"),
+    ("recieve", "receive"),
+    ("recieved", "received"),
+    ("recursivly", "recursively"),
+    ("redundent", "redundant"),
+    ("referance", "reference"),
+    ("refered", "referred"),
+    ("relevent", "relevant"),
+    ("remaing", "remaining"),
+    ("repositry", "repository"),
+    ("requried", "required"),
+    ("resouce", "resource"),
+    ("responce", "response"),
+    ("retreive", "retrieve"),
+    ("retrun", "return"),
+    ("reuslt", "result"),
+    ("seperate", "separate"),
+    ("seperator", "separator"),
+    ("succesful", "successful"),
+    ("successfull", "successful"),
+    ("sucess", "success"),
+    ("sufficent", "sufficient"),
+    ("suport", "support"),
+    ("supress", "suppress"),
+    ("suprise", "surprise"),
+    ("synchronus", "synchronous"),
+    ("targetted", "targeted"),
+    ("temporaly", "temporarily"),
+    ("thier", "their"),
+    ("threshhold", "threshold"),
+    ("tommorow", "tomorrow"),
+    ("transfered", "transferred"),
+    ("truely", "truly"),
+    ("unecessary", "unnecessary"),
+    ("unkown", "unknown"),
+    ("untill", "until"),
+    ("usefull", "useful"),
+    ("usuall", "usual"),
+    ("varaible", "variable"),
+    ("verfication", "verification"),
+    ("visiblity", "visibility"),
+    ("wether", "whether"),
+    ("wich", "which"),
+    ("writting", "writing"),
+];
@@ -1,18 +1,26 @@
 use toml::value::Value;
 
+use mago_ast::stack::ensure_sufficient_stack;
 use mago_ast::*;
+use mago_docblock::document::Document;
 use mago_fixer::FixPlan;
 use mago_interner::StringIdentifier;
 use mago_interner::ThreadedInterner;
 use mago_php_version::PHPVersion;
+use mago_project::include::IncludeGraph;
 use mago_project::module::Module;
 use mago_reflection::CodebaseReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
 use mago_reporting::Annotation;
 use mago_reporting::AnnotationKind;
 use mago_reporting::Issue;
 use mago_reporting::Level;
 use mago_span::HasPosition;
 use mago_span::HasSpan;
+use mago_span::Span;
+use mago_typing::evaluator::ConstantEvaluator;
+use mago_typing::evaluator::ConstantValue;
 
 use crate::ast::AstNode;
 use crate::directive::LintDirective;
@@ -29,21 +37,28 @@ pub struct LintContext<'a> {
     pub rule: &'a ConfiguredRule,
     pub interner: &'a ThreadedInterner,
     pub codebase: &'a CodebaseReflection,
+    pub include_graph: &'a IncludeGraph,
     pub module: &'a Module,
+    pub program: &'a Program,
     pub ignores: Vec<&'a IgnoreDirective<'a>>,
     pub scope: ScopeStack,
 
     unused_ignores: Vec<&'a IgnoreDirective<'a>>,
     issues: Vec<Issue>,
+    suppressed: Vec<Issue>,
+    suppressing: usize,
 }
 
 impl LintContext<'_> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new<'a>(
         php_version: PHPVersion,
         rule: &'a ConfiguredRule,
         interner: &'a ThreadedInterner,
         codebase: &'a CodebaseReflection,
+        include_graph: &'a IncludeGraph,
         module: &'a Module,
+        program: &'a Program,
         ignores: Vec<&'a IgnoreDirective<'a>>,
     ) -> LintContext<'a> {
         LintContext {
@@ -51,11 +66,15 @@ impl LintContext<'_> {
             rule,
             interner,
             codebase,
+            include_graph,
             module,
+            program,
             ignores,
             scope: ScopeStack::new(),
             unused_ignores: Vec::new(),
             issues: Vec::new(),
+            suppressed: Vec::new(),
+            suppressing: 0,
         }
     }
 
@@ -69,6 +88,19 @@ impl LintContext<'_> {
         self.rule.settings.get_option(option_name)
     }
 
+    /// Checks if the linted source matches one of the glob patterns of a rule-specific option, e.g. `["legacy/**"]`.
+    ///
+    /// The patterns are matched against the name of the source, i.e. its path relative to the workspace.
+    pub fn is_source_matched(&self, option_name: &'static str) -> bool {
+        let Some(patterns) = self.option(option_name).and_then(|option| option.as_array()) else {
+            return false;
+        };
+
+        let name = self.lookup(&self.module.source.identifier.0).replace('\\', "/");
+
+        patterns.iter().filter_map(|pattern| pattern.as_str()).any(|pattern| glob_match::glob_match(pattern, &name))
+    }
+
     /// Retrieves the string associated with a given identifier.
     ///
     /// # Panics
@@ -78,6 +110,16 @@ impl LintContext<'_> {
         self.interner.lookup(id)
     }
 
+    /// Retrieves the parsed docblock of a declaration, if any.
+    ///
+    /// The docblock may be placed either before the attributes of the declaration, or between
+    /// the attributes and the declaration itself. Docblocks that fail to parse are ignored.
+    pub fn get_docblock(&self, attribute_lists: &Sequence<AttributeList>, declaration: Span) -> Option<Document> {
+        let trivia = mago_docblock::get_declaration_docblock(self.program, attribute_lists, declaration)?;
+
+        mago_docblock::parse_trivia(self.interner, trivia).ok()
+    }
+
     /// Checks if a name at a given position is imported.
     pub fn is_name_imported(&self, position: &impl HasPosition) -> bool {
         self.module.names.is_imported(&position.position())
@@ -94,6 +136,14 @@ impl LintContext<'_> {
         self.lookup(name_id)
     }
 
+    /// Retrieves the global fallback name associated with a given position in the code, if any.
+    ///
+    /// Unqualified function and constant references inside a namespace fall back to the global
+    /// name at runtime if the namespaced one does not exist.
+    pub fn lookup_fallback_name(&self, position: &impl HasPosition) -> Option<&str> {
+        self.module.names.get_fallback(&position.position()).map(|name_id| self.lookup(name_id))
+    }
+
     /// Resolves the correct function name based on PHP's dynamic name resolution rules.
     ///
     /// This function determines the fully qualified name (FQN) of a function being called,
@@ -143,10 +193,12 @@ impl LintContext<'_> {
             return self.lookup(fqfn_id);
         }
 
-        // If FQFN doesn't exist, check if the global function name exists.
-        if !name.contains('\\') && self.codebase.function_exists(self.interner, &name_id) {
-            // If global function name exists, return it.
-            return name;
+        // If FQFN doesn't exist, check if the global fallback function name exists.
+        if let Some(fallback_id) = self.module.names.get_fallback(&identifier.position()) {
+            if self.codebase.function_exists(self.interner, fallback_id) {
+                // If global function name exists, return it.
+                return self.lookup(fallback_id);
+            }
         }
 
         // If neither exists, assume the FQFN and return it.
@@ -201,16 +253,118 @@ impl LintContext<'_> {
             return self.lookup(fqcn_id);
         }
 
-        // If FQCN doesn't exist, check if the global constant name exists.
-        if !name.contains('\\') && self.codebase.constant_exists(self.interner, &name_id) {
-            // If global constant name exists,
-            return name;
+        // If FQCN doesn't exist, check if the global fallback constant name exists.
+        if let Some(fallback_id) = self.module.names.get_fallback(&identifier.position()) {
+            if self.codebase.constant_exists(self.interner, fallback_id) {
+                // If global constant name exists, return it.
+                return self.lookup(fallback_id);
+            }
         }
 
         // If neither exists, assume the FQCN and return it.
         self.lookup(fqcn_id)
     }
 
+    /// Resolves the function, or method, called by the given call, if it is known.
+    ///
+    /// Only functions, and methods called on `$this`, `self`, or `static`, or on a named class, are resolved.
+    ///
+    /// # Arguments
+    ///
+    /// - `call`: The call to resolve the callee of.
+    ///
+    /// # Returns
+    ///
+    /// - The reflection of the called function, or method, or `None` if it cannot be determined.
+    pub fn resolve_callee(&self, call: &Call) -> Option<&FunctionLikeReflection> {
+        match call {
+            Call::Function(FunctionCall { function, .. }) => {
+                let Expression::Identifier(identifier) = function.as_ref() else {
+                    return None;
+                };
+
+                let name = self.module.names.get(identifier);
+                self.codebase.get_function(self.interner, name).or_else(|| {
+                    let fallback = self.module.names.get_fallback(identifier)?;
+
+                    self.codebase.get_function(self.interner, fallback)
+                })
+            }
+            Call::Method(MethodCall { object, method: ClassLikeMemberSelector::Identifier(method), .. }) => {
+                let is_this = matches!(
+                    object.as_ref(),
+                    Expression::Variable(Variable::Direct(variable)) if self.lookup(&variable.name) == "$this"
+                );
+
+                if !is_this {
+                    return None;
+                }
+
+                let class_like = self.scope.get_class_like_reflection(self)?;
+
+                self.codebase.get_method(self.interner, class_like, &method.value)
+            }
+            Call::StaticMethod(StaticMethodCall {
+                class, method: ClassLikeMemberSelector::Identifier(method), ..
+            }) => {
+                let class_like = match class.as_ref() {
+                    Expression::Self_(_) | Expression::Static(_) => self.scope.get_class_like_reflection(self)?,
+                    Expression::Identifier(identifier) => {
+                        self.codebase.get_named_class_like(self.interner, self.module.names.get(identifier))?
+                    }
+                    _ => return None,
+                };
+
+                self.codebase.get_method(self.interner, class_like, &method.value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the parameter of the given function, or method, that receives the given argument, if any.
+    ///
+    /// Unpacked arguments, e.g. `...$values`, are not matched to any parameter.
+    ///
+    /// # Arguments
+    ///
+    /// - `function_like`: The reflection of the called function, or method.
+    /// - `position`: The position of the argument in the argument list.
+    /// - `argument`: The argument to match.
+    pub fn get_argument_parameter<'f>(
+        &self,
+        function_like: &'f FunctionLikeReflection,
+        position: usize,
+        argument: &Argument,
+    ) -> Option<&'f FunctionLikeParameterReflection> {
+        match argument {
+            Argument::Positional(argument) if argument.ellipsis.is_none() => function_like
+                .parameters
+                .get(position)
+                .or_else(|| function_like.parameters.last().filter(|parameter| parameter.is_variadic)),
+            Argument::Named(argument) => {
+                let name = self.lookup(&argument.name.value);
+
+                function_like.parameters.iter().find(|parameter| &self.lookup(&parameter.name)[1..] == name)
+            }
+            _ => None,
+        }
+    }
+
+    /// Evaluates the given constant expression, e.g. `1 << 3`, `'foo' . 'bar'`, or `self::BAR`, if possible.
+    ///
+    /// # Arguments
+    ///
+    /// - `expression`: The expression to evaluate.
+    ///
+    /// # Returns
+    ///
+    /// - The value of the expression, or `None` if it is not a constant expression, or cannot be evaluated.
+    pub fn evaluate(&self, expression: &Expression) -> Option<ConstantValue> {
+        let class_like = self.scope.get_class_like_reflection(self);
+
+        ConstantEvaluator::new(self.interner, &self.module.names, Some(self.codebase), class_like).evaluate(expression)
+    }
+
     /// Converts a type hint into a human-readable string representation.
     ///
     /// This function takes a type hint (e.g., an identifier, nullable type, union type)
@@ -290,7 +444,7 @@ impl LintContext<'_> {
     ///
     /// # Returns
     ///
-    /// Returns `true` if at least one ignore directive was applied (i.e. the node should be skipped),
+    /// Returns `true` if at least one ignore directive was applied (i.e. the node should be suppressed),
     /// and `false` otherwise.
     #[inline]
     fn ignores(&mut self, node: impl HasSpan) -> bool {
@@ -328,14 +482,20 @@ impl LintContext<'_> {
     /// Immediately reports the provided issue without performing any ignore checks.
     ///
     /// This method augments the issue with the current rule's slug (used as its code) and appends it to
-    /// the issue collection.
+    /// the issue collection. If the current node is within a node suppressed by an ignore directive, the
+    /// issue is recorded as suppressed instead.
     ///
     /// # Parameters
     ///
     /// - `issue`: The issue to be reported.
     #[inline(always)]
     pub fn force_report(&mut self, issue: Issue) {
-        self.issues.push(issue.with_code(&self.rule.slug));
+        let issue = issue.with_code(&self.rule.slug);
+        if self.suppressing > 0 {
+            self.suppressed.push(issue);
+        } else {
+            self.issues.push(issue);
+        }
     }
 
     /// Reports an issue if it is not suppressed by an applicable ignore directive.
@@ -362,8 +522,16 @@ impl LintContext<'_> {
             }
         }
 
+        if self.suppressing > 0 {
+            self.force_report(issue);
+
+            return false;
+        }
+
         if let Some(span) = span {
             if self.ignores(span) {
+                self.suppressed.push(issue.with_code(&self.rule.slug));
+
                 return false;
             }
         }
@@ -407,8 +575,8 @@ impl LintContext<'_> {
     /// - **Prune:** Skip the children.
     /// - **Abort:** Abort the current branch.
     ///
-    /// After processing the node, `filter_unused()` is called to move any ignore directives that apply to the
-    /// node into the `unused_ignores` vector.
+    /// If an ignore directive applies to the node, the node and its children are still linted, but any issue
+    /// reported within them is recorded as suppressed rather than reported.
     ///
     /// # Parameters
     ///
@@ -419,8 +587,9 @@ impl LintContext<'_> {
     /// Returns `true` if the linting process should be aborted for the current branch; otherwise, `false`.
     #[inline]
     pub(crate) fn lint(&mut self, ast_node: &AstNode<'_>) -> bool {
-        if self.ignores(ast_node.node) {
-            return false;
+        let is_suppressed = self.suppressing == 0 && self.ignores(ast_node.node);
+        if is_suppressed {
+            self.suppressing += 1;
         }
 
         let should_pop_scope = if let Some(scope) = match ast_node.node {
@@ -456,7 +625,7 @@ impl LintContext<'_> {
                 LintDirective::Continue => {
                     // Recurse into each child node.
                     for child in &ast_node.children {
-                        if self.lint(child) {
+                        if ensure_sufficient_stack(|| self.lint(child)) {
                             break 'lint true;
                         }
                     }
@@ -472,6 +641,13 @@ impl LintContext<'_> {
             self.scope.pop();
         }
 
+        if is_suppressed {
+            self.suppressing -= 1;
+
+            // Aborting a suppressed branch must not abort linting of the nodes that follow it.
+            return false;
+        }
+
         result
     }
 
@@ -484,14 +660,17 @@ impl LintContext<'_> {
     ///
     /// # Returns
     ///
-    /// An iterator over all reported issues.
+    /// A tuple containing the reported issues, and the issues suppressed by ignore directives.
     #[inline]
-    pub fn finish(mut self) -> impl Iterator<Item = Issue> {
+    pub fn finish(mut self) -> (Vec<Issue>, Vec<Issue>) {
         // Move any remaining active ignores into unused_ignores.
         for ignore in self.ignores.drain(..) {
             self.unused_ignores.push(ignore);
         }
 
+        // Suppressions translated from other tools are not reported, as they may still be used by those tools.
+        self.unused_ignores.retain(|ignore| !ignore.external);
+
         // Report each unused ignore as an issue.
         let mut issues = std::mem::take(&mut self.issues);
         issues.extend(self.unused_ignores.drain(..).map(|ignore| {
@@ -505,6 +684,6 @@ impl LintContext<'_> {
                 .with_help("Consider removing or updating this directive.")
         }));
 
-        issues.into_iter()
+        (issues, self.suppressed)
     }
 }
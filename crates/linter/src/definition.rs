@@ -1,11 +1,39 @@
 use ahash::HashMap;
 use mago_php_version::PHPVersion;
+use serde::Deserialize;
 use serde::Serialize;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantNames;
 use toml::Value;
 
 use mago_casing;
 use mago_reporting::Level;
 
+/// The category of a linting rule, describing the kind of problems it detects.
+///
+/// Categories cut across plugins, allowing whole groups of rules to be enabled or disabled at once,
+/// e.g. to only run the security rules in a quick, focused pass.
+#[derive(
+    PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy, Debug, Serialize, Deserialize, Display, EnumString, VariantNames,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum RuleCategory {
+    /// Rules enforcing a consistent and readable coding style.
+    Style,
+    /// Rules detecting code that is likely wrong, or fragile.
+    Correctness,
+    /// Rules detecting code that may be exploited, or leak sensitive information.
+    Security,
+    /// Rules detecting code that is needlessly slow.
+    Performance,
+    /// Rules detecting code that is deprecated, or could be migrated to newer PHP versions.
+    Compatibility,
+    /// Rules specific to a framework or library, such as Laravel, Symfony or PHPUnit.
+    Framework,
+}
+
 /// Represents a single configuration option for a linting rule, including its name, type,
 /// a short description of how it affects the rule, and its default value.
 #[derive(PartialEq, Clone, Debug, Serialize)]
@@ -69,6 +97,16 @@ pub struct RuleDefinition {
 
     /// Whether this rule is deprecated and should not be used.
     pub deprecated: bool,
+
+    /// Whether this rule proposes fixes for the issues it reports, which can be applied with `mago fix`.
+    pub fixable: bool,
+
+    /// The category of this rule, if it differs from the category of its plugin.
+    pub category: Option<RuleCategory>,
+
+    /// The version of Mago this rule was introduced in, e.g. `"0.12.0"`, if it was introduced
+    /// after rules started being versioned.
+    pub introduced_in: Option<&'static str>,
 }
 
 /// Holds high-level information about a plugin. Plugins generally bundle multiple rules together,
@@ -84,6 +122,9 @@ pub struct PluginDefinition {
 
     /// Indicates whether this plugin should be considered enabled if not otherwise specified.
     pub enabled_by_default: bool,
+
+    /// The category of the rules of this plugin, unless a rule specifies its own.
+    pub category: RuleCategory,
 }
 
 impl RuleUsageExample {
@@ -151,8 +192,11 @@ impl RuleDefinition {
             options: Vec::new(),
             examples: Vec::new(),
             deprecated: false,
+            fixable: false,
             minimum_supported_php_version: None,
             maximum_supported_php_version: None,
+            category: None,
+            introduced_in: None,
         }
     }
 
@@ -174,8 +218,11 @@ impl RuleDefinition {
             options: Vec::new(),
             examples: Vec::new(),
             deprecated: false,
+            fixable: false,
             minimum_supported_php_version: None,
             maximum_supported_php_version: None,
+            category: None,
+            introduced_in: None,
         }
     }
 
@@ -249,6 +296,47 @@ impl RuleDefinition {
         self
     }
 
+    /// Sets the category of this rule, overriding the category of its plugin.
+    ///
+    /// # Parameters
+    ///
+    /// * `category` - The category of problems this rule detects.
+    ///
+    /// # Returns
+    ///
+    /// A modified `RuleDefinition` with the category set.
+    pub fn with_category(mut self, category: RuleCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Sets the version of Mago this rule was introduced in.
+    ///
+    /// Rules introduced after the version configured by the user are considered new, and are handled
+    /// according to the [`NewRulesPolicy`](crate::settings::NewRulesPolicy) until they are adopted.
+    ///
+    /// # Parameters
+    ///
+    /// * `version` - The version of Mago this rule was introduced in, e.g. `"0.12.0"`.
+    ///
+    /// # Returns
+    ///
+    /// A modified `RuleDefinition` with the version it was introduced in set.
+    pub fn with_introduced_in(mut self, version: &'static str) -> Self {
+        self.introduced_in = Some(version);
+        self
+    }
+
+    /// Marks this rule as fixable, meaning it proposes fixes for the issues it reports.
+    ///
+    /// # Returns
+    ///
+    /// A modified `RuleDefinition` with the `fixable` flag set to `true`.
+    pub fn fixable(mut self) -> Self {
+        self.fixable = true;
+        self
+    }
+
     /// Marks this rule as deprecated, meaning it should not be used.
     ///
     /// # Returns
@@ -20,15 +20,73 @@ pub struct IgnoreDirective<'a> {
     pub rule: &'a str,
     /// An optional description explaining why this ignore is present.
     pub description: &'a str,
+    /// Indicates whether the directive was translated from a Psalm or PHPStan suppression.
+    ///
+    /// Such directives are primarily meant for other tools, and are therefore never reported as unused.
+    pub external: bool,
 }
 
+/// Maps Psalm issue types and PHPStan error identifiers to the equivalent Mago rules.
+///
+/// An issue type may map to several rules, in which case it appears once for each of them.
+const EXTERNAL_SUPPRESSIONS: [(&str, &str); 45] = [
+    // Psalm
+    ("UndefinedFunction", "analysis/undefined-function-or-method"),
+    ("UndefinedMethod", "analysis/undefined-function-or-method"),
+    ("UndefinedConstant", "analysis/undefined-constant-or-enum-case"),
+    ("UndefinedClass", "analysis/instantiation"),
+    ("AbstractInstantiation", "analysis/instantiation"),
+    ("InterfaceInstantiation", "analysis/instantiation"),
+    ("MissingOverrideAttribute", "analysis/override-attribute"),
+    ("TooManyTemplateParams", "analysis/template-argument-count"),
+    ("MissingTemplateParam", "analysis/template-argument-count"),
+    ("InvalidDocblock", "comment/docblock-syntax"),
+    ("MissingParamType", "strictness/require-parameter-type"),
+    ("MissingReturnType", "strictness/require-return-type"),
+    ("MissingPropertyType", "strictness/require-property-type"),
+    ("MissingClassConstType", "strictness/require-constant-type"),
+    ("ForbiddenCode", "best-practices/no-debug-symbols"),
+    ("ForbiddenCode", "best-practices/disallowed-functions"),
+    ("UnusedParam", "best-practices/no-unused-parameter"),
+    ("PossiblyUnusedParam", "best-practices/no-unused-parameter"),
+    ("UnusedClosureParam", "best-practices/no-unused-parameter"),
+    ("TaintedInput", "security/tainted-data-to-sink"),
+    ("TaintedSql", "security/tainted-data-to-sink"),
+    ("TaintedShell", "security/tainted-data-to-sink"),
+    ("TaintedHtml", "security/tainted-data-to-sink"),
+    // PHPStan
+    ("function.notFound", "analysis/undefined-function-or-method"),
+    ("staticMethod.notFound", "analysis/undefined-function-or-method"),
+    ("constant.notFound", "analysis/undefined-constant-or-enum-case"),
+    ("classConstant.notFound", "analysis/undefined-constant-or-enum-case"),
+    ("class.notFound", "analysis/instantiation"),
+    ("new.abstract", "analysis/instantiation"),
+    ("new.interface", "analysis/instantiation"),
+    ("method.missingOverride", "analysis/override-attribute"),
+    ("generics.lessTypes", "analysis/template-argument-count"),
+    ("generics.moreTypes", "analysis/template-argument-count"),
+    ("generics.notGeneric", "analysis/template-argument-count"),
+    ("function.templateTypeNotInParameter", "analysis/unreferenced-template"),
+    ("method.templateTypeNotInParameter", "analysis/unreferenced-template"),
+    ("phpDoc.parseError", "comment/docblock-syntax"),
+    ("missingType.parameter", "strictness/require-parameter-type"),
+    ("missingType.return", "strictness/require-return-type"),
+    ("missingType.property", "strictness/require-property-type"),
+    ("equal.notAllowed", "strictness/require-identity-comparison"),
+    ("notEqual.notAllowed", "strictness/require-identity-comparison"),
+    ("function.strict", "strictness/require-strict-search"),
+    ("disallowed.function", "best-practices/disallowed-functions"),
+    ("disallowed.constant", "best-practices/disallowed-functions"),
+];
+
 /// Extracts and returns all ignore comments from the given module.
 ///
 /// This function looks at every trivia (non-code) element in the AST, filtering for comments.
 /// For each comment, it:
 ///
 /// 1. Parses its content into individual lines via `comment_lines()`.
-/// 2. Extracts ignore directives from lines that start with `@mago-ignore`.
+/// 2. Extracts ignore directives from lines that start with `@mago-ignore`, as well as Psalm and PHPStan
+///    suppressions that map to a Mago rule.
 ///    - If a directive’s content contains a `/`, it is split on the first whitespace into a
 ///      rule and an optional description.
 ///    - If no `/` is present, the entire content is used as the rule, with an empty description.
@@ -50,8 +108,8 @@ pub fn get_ignores<'s, 'a>(
     program: &'s Program,
     interner: &'a ThreadedInterner,
 ) -> Vec<IgnoreDirective<'a>> {
-    // Get the full source code from the interner.
-    let source_code: &'a str = interner.lookup(&module.source.content);
+    // Get the full source code of the module.
+    let source_code: &str = &module.source.content;
 
     program
         .trivia
@@ -59,8 +117,10 @@ pub fn get_ignores<'s, 'a>(
         .filter(|trivia| trivia.kind.is_comment())
         .filter_map(|trivia| {
             // Parse ignore directives from the comment's text.
-            let directives = parse_ignore_directives(comment_lines(trivia, interner));
-            if directives.is_empty() {
+            let lines = comment_lines(trivia, interner);
+            let external_directives = parse_external_ignore_directives(&lines);
+            let directives = parse_ignore_directives(lines);
+            if directives.is_empty() && external_directives.is_empty() {
                 return None;
             }
 
@@ -70,13 +130,17 @@ pub fn get_ignores<'s, 'a>(
             let prefix = &source_code[line_start..trivia.span.start.offset];
             let own_line = prefix.trim().is_empty();
 
-            Some(directives.into_iter().map(move |(rule, description)| IgnoreDirective {
+            let directives = directives.into_iter().map(|(rule, description)| (rule, description, false));
+            let external_directives = external_directives.into_iter().map(|rule| (rule, "", true));
+
+            Some(directives.chain(external_directives).map(move |(rule, description, external)| IgnoreDirective {
                 span: trivia.span,
                 start_line,
                 end_line,
                 own_line,
                 rule,
                 description,
+                external,
             }))
         })
         .flatten()
@@ -130,6 +194,54 @@ pub fn parse_ignore_directives(comment_lines: Vec<&str>) -> Vec<(&str, &str)> {
     ignores
 }
 
+/// Parses a set of comment lines for Psalm and PHPStan suppressions, and translates them to Mago rules.
+///
+/// The following suppressions are recognized:
+///
+/// - `@psalm-suppress IssueType`
+/// - `@phpstan-ignore error.identifier (optional reason)`
+///
+/// Multiple issue types or identifiers may be separated by commas. Issue types and identifiers that
+/// do not have an equivalent Mago rule are skipped.
+///
+/// # Parameters
+///
+/// - `comment_lines`: A slice of string slices, each representing a line from a comment.
+///
+/// # Returns
+///
+/// A vector of the names of the Mago rules to ignore.
+#[inline]
+pub fn parse_external_ignore_directives(comment_lines: &[&str]) -> Vec<&'static str> {
+    let mut rules = Vec::new();
+
+    for line in comment_lines {
+        let line = line.trim();
+        let Some(content) = ["@psalm-suppress", "@phpstan-ignore"].into_iter().find_map(|keyword| {
+            line.strip_prefix(keyword).filter(|content| content.is_empty() || content.starts_with(char::is_whitespace))
+        }) else {
+            continue;
+        };
+
+        // Strip the optional reason, e.g. `@phpstan-ignore function.notFound (defined at runtime)`.
+        let content = content.split('(').next().unwrap_or_default();
+
+        for issue in content.split(',') {
+            let Some(issue) = issue.split_whitespace().next() else {
+                continue;
+            };
+
+            for (external, rule) in EXTERNAL_SUPPRESSIONS {
+                if external == issue && !rules.contains(&rule) {
+                    rules.push(rule);
+                }
+            }
+        }
+    }
+
+    rules
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,4 +298,30 @@ mod tests {
         let result = parse_ignore_directives(comment.lines().collect());
         assert_eq!(result.len(), 0);
     }
+
+    #[test]
+    fn test_parse_external_ignores() {
+        let comment = r#"
+            @psalm-suppress UndefinedFunction
+            @phpstan-ignore missingType.return, function.strict (legacy code)
+            @psalm-suppress UnknownIssueType
+            @phpstan-ignore-next-line
+        "#;
+        let result = parse_external_ignore_directives(&comment.lines().collect::<Vec<_>>());
+        assert_eq!(
+            result,
+            vec![
+                "analysis/undefined-function-or-method",
+                "strictness/require-return-type",
+                "strictness/require-strict-search",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_external_ignore_mapping_to_multiple_rules() {
+        let comment = "@psalm-suppress ForbiddenCode";
+        let result = parse_external_ignore_directives(&comment.lines().collect::<Vec<_>>());
+        assert_eq!(result, vec!["best-practices/no-debug-symbols", "best-practices/disallowed-functions"]);
+    }
 }
@@ -3,15 +3,19 @@ use std::sync::RwLock;
 use std::sync::RwLockReadGuard;
 
 use mago_interner::ThreadedInterner;
+use mago_project::include::IncludeGraph;
 use mago_project::module::Module;
 use mago_reflection::CodebaseReflection;
 use mago_reporting::IssueCollection;
 use mago_reporting::Level;
 
+use crate::definition::RuleCategory;
 use crate::plugin::Plugin;
+use crate::profile::RuleProfiler;
 use crate::rule::ConfiguredRule;
 use crate::rule::Rule;
 use crate::runner::Runner;
+use crate::settings::NewRulesPolicy;
 use crate::settings::RuleSettings;
 use crate::settings::Settings;
 
@@ -20,6 +24,7 @@ pub mod context;
 pub mod definition;
 pub mod directive;
 pub mod plugin;
+pub mod profile;
 pub mod rule;
 pub mod scope;
 pub mod settings;
@@ -34,7 +39,9 @@ pub struct Linter {
     settings: Settings,
     interner: ThreadedInterner,
     codebase: Arc<CodebaseReflection>,
+    include_graph: Arc<IncludeGraph>,
     rules: Arc<RwLock<Vec<ConfiguredRule>>>,
+    profiler: Option<Arc<RuleProfiler>>,
 }
 
 impl Linter {
@@ -52,7 +59,56 @@ impl Linter {
     ///
     /// A new linter.
     pub fn new(settings: Settings, interner: ThreadedInterner, codebase: CodebaseReflection) -> Self {
-        Self { settings, interner, codebase: Arc::new(codebase), rules: Arc::new(RwLock::new(Vec::new())) }
+        Self {
+            settings,
+            interner,
+            codebase: Arc::new(codebase),
+            include_graph: Arc::new(IncludeGraph::default()),
+            rules: Arc::new(RwLock::new(Vec::new())),
+            profiler: None,
+        }
+    }
+
+    /// Attaches a profiler measuring the wall time and allocations of every rule run by this linter.
+    ///
+    /// # Parameters
+    ///
+    /// - `profiler`: The profiler to record the measurements of every rule into.
+    ///
+    /// # Returns
+    ///
+    /// The linter, with the profiler attached.
+    pub fn with_profiler(mut self, profiler: Arc<RuleProfiler>) -> Self {
+        self.profiler = Some(profiler);
+        self
+    }
+
+    /// Replaces the interner of this linter, e.g. after it was reset to a snapshot.
+    ///
+    /// # Parameters
+    ///
+    /// - `interner`: The interner to use for the linter, holding the strings of the codebase.
+    ///
+    /// # Returns
+    ///
+    /// The linter, using the given interner.
+    pub fn with_interner(mut self, interner: ThreadedInterner) -> Self {
+        self.interner = interner;
+        self
+    }
+
+    /// Attaches the include graph of the project, checked by the rules of the include plugin.
+    ///
+    /// # Parameters
+    ///
+    /// - `include_graph`: The graph of the literal inclusions between the files of the project.
+    ///
+    /// # Returns
+    ///
+    /// The linter, with the include graph attached.
+    pub fn with_include_graph(mut self, include_graph: IncludeGraph) -> Self {
+        self.include_graph = Arc::new(include_graph);
+        self
     }
 
     /// Creates a new linter with all plugins enabled.
@@ -105,7 +161,7 @@ impl Linter {
         }
 
         for rule in plugin.get_rules() {
-            self.add_rule(&plugin_slug, rule);
+            self.add_rule(&plugin_slug, plugin_definition.category, rule);
         }
 
         tracing::debug!("Plugin '{plugin_slug}' loaded successfully.");
@@ -118,8 +174,9 @@ impl Linter {
     /// # Parameters
     ///
     /// - `plugin_slug`: The slug of the plugin that the rule belongs to.
+    /// - `plugin_category`: The category of the plugin, used unless the rule specifies its own.
     /// - `rule`: The rule to add to the linter.
-    pub fn add_rule(&mut self, plugin_slug: impl Into<String>, rule: Box<dyn Rule>) {
+    pub fn add_rule(&mut self, plugin_slug: impl Into<String>, plugin_category: RuleCategory, mut rule: Box<dyn Rule>) {
         let rule_definition = rule.get_definition();
         let plugin_slug = plugin_slug.into();
         let slug = format!("{}/{}", plugin_slug, rule_definition.get_slug());
@@ -147,6 +204,21 @@ impl Linter {
             return;
         }
 
+        let category = rule_definition.category.unwrap_or(plugin_category);
+        if !self.settings.is_category_enabled(category) {
+            tracing::debug!("Rule `{slug}` skipped, as its category `{category}` is not enabled.");
+
+            return;
+        }
+
+        // Rules configured by the user have been reviewed, and are never considered new.
+        let is_new = settings.is_none() && self.settings.is_new_rule(rule_definition.introduced_in);
+        if is_new && self.settings.new_rules == NewRulesPolicy::Off {
+            tracing::debug!("Rule `{slug}` skipped, as it is new and has not been adopted yet.");
+
+            return;
+        }
+
         let settings = settings.cloned().unwrap_or_else(|| {
             tracing::debug!("No configuration found for rule `{slug}`, using default.");
 
@@ -171,16 +243,32 @@ impl Linter {
             },
         };
 
+        let level = if is_new && self.settings.new_rules == NewRulesPolicy::Warn && level > Level::Warning {
+            tracing::debug!("Rule `{slug}` is new and has not been adopted yet, reporting its issues as warnings.");
+
+            Level::Warning
+        } else {
+            level
+        };
+
         tracing::debug!("Rule `{slug}` is enabled with level `{level}`.");
 
+        rule.configure(&settings);
+
         self.rules.write().expect("Unable to add rule: poisoned lock").push(ConfiguredRule {
             slug,
             level,
+            category,
             settings,
             rule,
         });
     }
 
+    /// Returns the settings of the linter.
+    pub fn get_settings(&self) -> &Settings {
+        &self.settings
+    }
+
     /// Returns a read lock for the vector of [`ConfiguredRule`] instances maintained by the linter.
     ///
     /// This method provides direct, read-only access to all currently configured rules.
@@ -225,15 +313,38 @@ impl Linter {
     ///
     /// A collection of issues.
     pub fn lint(&self, module: &Module) -> IssueCollection {
+        let (issues, _) = self.lint_with_suppressed(module);
+
+        issues
+    }
+
+    /// Lints the given module, keeping track of the issues suppressed by ignore directives.
+    ///
+    /// # Parameters
+    ///
+    /// - `module`: The module to lint.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the collection of reported issues, and the collection of suppressed issues.
+    pub fn lint_with_suppressed(&self, module: &Module) -> (IssueCollection, IssueCollection) {
         let configured_rules = self.rules.read().expect("Unable to read rules: poisoned lock");
         if configured_rules.is_empty() {
             tracing::warn!("Linting aborted - no rules configured.");
 
-            return IssueCollection::new();
+            return (IssueCollection::new(), IssueCollection::new());
         }
 
         let program = module.parse(&self.interner);
-        let mut runner = Runner::new(self.settings.php_version, &self.interner, &self.codebase, module, &program);
+        let mut runner = Runner::new(
+            self.settings.php_version,
+            &self.interner,
+            &self.codebase,
+            &self.include_graph,
+            module,
+            &program,
+            self.profiler.as_deref(),
+        );
         for configured_rule in configured_rules.iter() {
             runner.run(configured_rule);
         }
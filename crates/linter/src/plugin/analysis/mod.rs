@@ -1,9 +1,23 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
+use crate::plugin::analysis::rules::date_format::DateFormatRule;
+use crate::plugin::analysis::rules::duplicate_array_key::DuplicateArrayKeyRule;
+use crate::plugin::analysis::rules::duplicate_branch_condition::DuplicateBranchConditionRule;
+use crate::plugin::analysis::rules::duplicate_enum_case_value::DuplicateEnumCaseValueRule;
+use crate::plugin::analysis::rules::format_string::FormatStringRule;
 use crate::plugin::analysis::rules::instantiation::InstantiationRule;
+use crate::plugin::analysis::rules::integer_overflow::IntegerOverflowRule;
+use crate::plugin::analysis::rules::invalid_shape_argument::InvalidShapeArgumentRule;
+use crate::plugin::analysis::rules::null_safety::NullSafetyRule;
 use crate::plugin::analysis::rules::override_attribute::OverrideAttributeRule;
 use crate::plugin::analysis::rules::parameter_name::ParameterNameRule;
+use crate::plugin::analysis::rules::property_initialization::PropertyInitializationRule;
+use crate::plugin::analysis::rules::return_type_consistency::ReturnTypeConsistencyRule;
+use crate::plugin::analysis::rules::template_argument_count::TemplateArgumentCountRule;
 use crate::plugin::analysis::rules::undefined_constant_or_case::UndefinedConstantOrCaseRule;
 use crate::plugin::analysis::rules::undefined_function_or_method::UndefinedFunctionOrMethodRule;
+use crate::plugin::analysis::rules::undefined_shape_key::UndefinedShapeKeyRule;
+use crate::plugin::analysis::rules::unreferenced_template::UnreferencedTemplateRule;
 
 use crate::plugin::Plugin;
 use crate::rule::Rule;
@@ -19,16 +33,30 @@ impl Plugin for AnalysisPlugin {
             name: "Analysis",
             description: "Provides rules that analyze the codebase for potential runtime issues.",
             enabled_by_default: true,
+            category: RuleCategory::Correctness,
         }
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
         vec![
+            Box::new(DateFormatRule),
+            Box::new(DuplicateArrayKeyRule),
+            Box::new(DuplicateBranchConditionRule),
+            Box::new(DuplicateEnumCaseValueRule),
+            Box::new(FormatStringRule),
             Box::new(InstantiationRule),
+            Box::new(IntegerOverflowRule),
+            Box::new(InvalidShapeArgumentRule),
+            Box::new(NullSafetyRule),
             Box::new(OverrideAttributeRule),
             Box::new(ParameterNameRule),
+            Box::new(PropertyInitializationRule),
+            Box::new(ReturnTypeConsistencyRule),
+            Box::new(TemplateArgumentCountRule),
             Box::new(UndefinedConstantOrCaseRule),
             Box::new(UndefinedFunctionOrMethodRule),
+            Box::new(UndefinedShapeKeyRule),
+            Box::new(UnreferencedTemplateRule),
         ]
     }
 }
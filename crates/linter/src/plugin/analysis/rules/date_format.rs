@@ -0,0 +1,226 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+use crate::utils::get_string_value;
+
+/// The functions formatting a date, along with the position of their format argument.
+const FORMAT_FUNCTIONS: [(&str, usize); 3] = [("date", 0), ("gmdate", 0), ("date_format", 1)];
+
+/// The functions parsing a date, along with the position of their format argument.
+const PARSE_FUNCTIONS: [(&str, usize); 2] = [("date_create_from_format", 0), ("date_create_immutable_from_format", 0)];
+
+/// The functions creating a date object, whose `format` method is checked.
+const CREATE_FUNCTIONS: [&str; 2] = ["date_create", "date_create_immutable"];
+
+/// The date classes, whose `format` and `createFromFormat` methods are checked.
+const DATE_CLASSES: [&str; 2] = ["DateTime", "DateTimeImmutable"];
+
+/// The characters recognized when formatting a date.
+const FORMAT_CHARACTERS: &str = "dDjlNSwzWFmMntLoXxYyaABgGhHisuveIOPpTZcrU";
+
+/// The characters recognized when parsing a date.
+const PARSE_CHARACTERS: &str = "dDjlSzFMmnYyaAgGhHisvueOPpTU";
+
+/// Common mistakes, borrowed from other date formatting syntaxes, along with their replacements.
+const MISTAKES: [(&str, &str); 10] = [
+    ("YYYY", "Y"),
+    ("yyyy", "Y"),
+    ("YY", "y"),
+    ("yy", "y"),
+    ("MM", "m"),
+    ("DD", "d"),
+    ("dd", "d"),
+    ("HH", "H"),
+    ("hh", "h"),
+    ("mm", "i"),
+];
+
+#[derive(Clone, Debug)]
+pub struct DateFormatRule;
+
+impl Rule for DateFormatRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Date Format", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Validates the literal format strings passed to `date()`, `gmdate()`, `DateTime::format()`,
+                `DateTime::createFromFormat()`, and their `DateTimeImmutable` and procedural counterparts.
+
+                Letters that are not format characters are output, or expected, as is, which is rarely
+                intended, and usually comes from other date formatting syntaxes, e.g. `YYYY-MM-DD`
+                instead of `Y-m-d`. Such letters must be escaped with a backslash, e.g. `\\T`.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A valid date format",
+                indoc! {r#"
+                    <?php
+
+                    $date = date('Y-m-d\TH:i:sP');
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A date format using another syntax",
+                indoc! {r#"
+                    <?php
+
+                    $date = date('YYYY-MM-DD');
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A parsing format with an unknown format character",
+                indoc! {r#"
+                    <?php
+
+                    $date = DateTimeImmutable::createFromFormat('Y-m-d H:i:s.f', $value);
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let (argument_list, position, is_parsing) = match node {
+            Node::FunctionCall(function_call) => {
+                let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+                    return LintDirective::default();
+                };
+
+                let function_name = context.resolve_function_name(function_identifier);
+                if let Some((_, position)) =
+                    FORMAT_FUNCTIONS.iter().find(|(name, _)| function_name.eq_ignore_ascii_case(name))
+                {
+                    (&function_call.argument_list, *position, false)
+                } else if let Some((_, position)) =
+                    PARSE_FUNCTIONS.iter().find(|(name, _)| function_name.eq_ignore_ascii_case(name))
+                {
+                    (&function_call.argument_list, *position, true)
+                } else {
+                    return LintDirective::default();
+                }
+            }
+            Node::MethodCall(method_call) => {
+                if !is_method_named(context, &method_call.method, "format")
+                    || !is_date_object(context, &method_call.object)
+                {
+                    return LintDirective::default();
+                }
+
+                (&method_call.argument_list, 0, false)
+            }
+            Node::StaticMethodCall(static_method_call) => {
+                let Expression::Identifier(class_identifier) = static_method_call.class.as_ref() else {
+                    return LintDirective::default();
+                };
+
+                if !is_method_named(context, &static_method_call.method, "createFromFormat")
+                    || !is_date_class(context, class_identifier)
+                {
+                    return LintDirective::default();
+                }
+
+                (&static_method_call.argument_list, 0, true)
+            }
+            _ => return LintDirective::default(),
+        };
+
+        let Some(Argument::Positional(PositionalArgument { ellipsis: None, value })) =
+            argument_list.arguments.get(position)
+        else {
+            return LintDirective::default();
+        };
+
+        let Expression::Literal(Literal::String(format)) = value else {
+            return LintDirective::default();
+        };
+
+        let Some(format_value) = get_string_value(format, context.interner) else {
+            return LintDirective::default();
+        };
+
+        if let Some((mistake, replacement)) = MISTAKES.iter().find(|(mistake, _)| format_value.contains(mistake)) {
+            context.report(
+                Issue::new(context.level(), format!("Date format `{}` is not a PHP date format.", mistake))
+                    .with_annotation(Annotation::primary(format.span()).with_message(format!(
+                        "`{}` repeats the `{}` format character.",
+                        mistake,
+                        &mistake[..1]
+                    )))
+                    .with_help(format!("Use `{}` instead, e.g. `Y-m-d` for `YYYY-MM-DD`.", replacement)),
+            );
+
+            return LintDirective::default();
+        }
+
+        let characters = if is_parsing { PARSE_CHARACTERS } else { FORMAT_CHARACTERS };
+        let mut unknown = vec![];
+        let mut format_characters = format_value.chars();
+        while let Some(character) = format_characters.next() {
+            if character == '\\' {
+                format_characters.next();
+            } else if character.is_ascii_alphabetic()
+                && !characters.contains(character)
+                && !unknown.contains(&character)
+            {
+                unknown.push(character);
+            }
+        }
+
+        if unknown.is_empty() {
+            return LintDirective::default();
+        }
+
+        let unknown = unknown.iter().map(|character| format!("`{}`", character)).collect::<Vec<_>>().join(", ");
+        let effect = if is_parsing { "expected as is" } else { "output as is" };
+
+        context.report(
+            Issue::new(context.level(), format!("Date format contains unknown format characters: {}.", unknown))
+                .with_annotation(
+                    Annotation::primary(format.span()).with_message(format!("These characters are {}.", effect)),
+                )
+                .with_help(
+                    "Escape the characters with a backslash if they are intended, or use a valid format character.",
+                ),
+        );
+
+        LintDirective::default()
+    }
+}
+
+fn is_method_named(context: &LintContext<'_>, method: &ClassLikeMemberSelector, name: &str) -> bool {
+    match method {
+        ClassLikeMemberSelector::Identifier(identifier) => context.lookup(&identifier.value).eq_ignore_ascii_case(name),
+        _ => false,
+    }
+}
+
+fn is_date_class(context: &LintContext<'_>, class_identifier: &Identifier) -> bool {
+    let class_name = context.lookup_name(class_identifier).trim_start_matches('\\');
+
+    DATE_CLASSES.iter().any(|date_class| class_name.eq_ignore_ascii_case(date_class))
+}
+
+/// Determines whether the expression provably evaluates to a date object, e.g. `new DateTime()`.
+fn is_date_object(context: &LintContext<'_>, expression: &Expression) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => is_date_object(context, &parenthesized.expression),
+        Expression::Instantiation(instantiation) => match instantiation.class.as_ref() {
+            Expression::Identifier(class_identifier) => is_date_class(context, class_identifier),
+            _ => false,
+        },
+        Expression::Call(Call::Function(function_call)) => match function_call.function.as_ref() {
+            Expression::Identifier(function_identifier) => {
+                let function_name = context.resolve_function_name(function_identifier);
+
+                CREATE_FUNCTIONS.iter().any(|name| function_name.eq_ignore_ascii_case(name))
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,134 @@
+use ahash::HashMap;
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_typing::evaluator::ArrayKey;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DuplicateArrayKeyRule;
+
+impl Rule for DuplicateArrayKeyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Duplicate Array Key", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects array literals defining the same key more than once, in which case only the last
+                value is kept.
+
+                Keys are compared after being evaluated, and cast as done by PHP, e.g. `1`, `'1'`, and `true`
+                are the same key. Keys using constants, e.g. `Foo::BAR`, are resolved when their value is known.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "An array with distinct keys",
+                indoc! {r#"
+                    <?php
+
+                    $options = [
+                        'host' => 'localhost',
+                        'port' => 8080,
+                    ];
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "An array defining the same key twice",
+                indoc! {r#"
+                    <?php
+
+                    $options = [
+                        'host' => 'localhost',
+                        'port' => 8080,
+                        'host' => '127.0.0.1',
+                    ];
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "An array defining the same key using an integer, and a numeric string",
+                indoc! {r#"
+                    <?php
+
+                    $codes = [
+                        1 => 'first',
+                        '1' => 'second',
+                    ];
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let elements = match node {
+            Node::Array(array) => &array.elements,
+            Node::LegacyArray(array) => &array.elements,
+            _ => return LintDirective::default(),
+        };
+
+        let mut issues = vec![];
+        let mut keys: HashMap<ArrayKey, Span> = HashMap::default();
+        // The key assigned to the next element without a key, if it is known.
+        let mut next_index = Some(0);
+        for element in elements.iter() {
+            let (key, span) = match element {
+                ArrayElement::KeyValue(element) => {
+                    let Some(key) = context.evaluate(&element.key).and_then(|key| key.to_array_key()) else {
+                        next_index = None;
+
+                        continue;
+                    };
+
+                    (key, element.key.span())
+                }
+                ArrayElement::Value(element) => {
+                    let Some(index) = next_index else {
+                        continue;
+                    };
+
+                    (ArrayKey::Integer(index), element.value.span())
+                }
+                ArrayElement::Missing(_) => {
+                    next_index = next_index.and_then(|index| index.checked_add(1));
+
+                    continue;
+                }
+                ArrayElement::Variadic(_) => {
+                    next_index = None;
+
+                    continue;
+                }
+            };
+
+            if let (ArrayKey::Integer(integer), Some(index)) = (&key, next_index) {
+                next_index = integer.checked_add(1).map(|next| next.max(index));
+            }
+
+            if let Some(previous) = keys.get(&key) {
+                issues.push(
+                    Issue::new(context.level(), format!("Key `{}` is defined more than once in this array.", key))
+                        .with_annotation(Annotation::primary(span).with_message("This key overrides a previous value."))
+                        .with_annotation(
+                            Annotation::secondary(*previous)
+                                .with_message(format!("Key `{}` is first defined here.", key)),
+                        )
+                        .with_note("When an array defines the same key more than once, only the last value is kept.")
+                        .with_help("Remove the duplicate key, or use a different key."),
+                );
+
+                continue;
+            }
+
+            keys.insert(key, span);
+        }
+
+        for issue in issues {
+            context.report(issue);
+        }
+
+        LintDirective::default()
+    }
+}
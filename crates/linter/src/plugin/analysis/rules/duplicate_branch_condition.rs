@@ -0,0 +1,162 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_typing::evaluator::ConstantValue;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DuplicateBranchConditionRule;
+
+impl Rule for DuplicateBranchConditionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Duplicate Branch Condition", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects `match` arms, and `switch` cases, whose condition matches the same value as a previous
+                one, making them unreachable.
+
+                Conditions are compared after being evaluated, e.g. `1 + 1`, and `2` are the same condition.
+                `match` conditions are compared using `===`, while `switch` cases are compared using `==`,
+                as done by PHP, e.g. `case 1:` and `case '1':` match the same value.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A match expression with distinct conditions",
+                indoc! {r#"
+                    <?php
+
+                    function get_label(int $status): string
+                    {
+                        return match ($status) {
+                            1 => 'active',
+                            2 => 'inactive',
+                            default => 'unknown',
+                        };
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A match expression with the same condition in two arms",
+                indoc! {r#"
+                    <?php
+
+                    function get_label(int $status): string
+                    {
+                        return match ($status) {
+                            1 => 'active',
+                            2 => 'inactive',
+                            1 => 'disabled',
+                            default => 'unknown',
+                        };
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A switch statement with loosely equal cases",
+                indoc! {r#"
+                    <?php
+
+                    function get_label(int $status): string
+                    {
+                        switch ($status) {
+                            case 1:
+                                return 'active';
+                            case '1':
+                                return 'disabled';
+                            default:
+                                return 'unknown';
+                        }
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let (conditions, is_strict, kind, construct) = match node {
+            Node::Match(r#match) => {
+                let conditions = r#match
+                    .arms
+                    .iter()
+                    .filter_map(|arm| match arm {
+                        MatchArm::Expression(arm) => Some(arm.conditions.iter()),
+                        MatchArm::Default(_) => None,
+                    })
+                    .flatten()
+                    .collect::<Vec<_>>();
+
+                (conditions, true, "arm", "`match` expression")
+            }
+            Node::Switch(switch) => {
+                let conditions = switch
+                    .body
+                    .cases()
+                    .iter()
+                    .filter_map(|case| match case {
+                        SwitchCase::Expression(case) => Some(case.expression.as_ref()),
+                        SwitchCase::Default(_) => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                (conditions, false, "case", "`switch` statement")
+            }
+            _ => return LintDirective::default(),
+        };
+
+        let mut issues = vec![];
+        let mut values: Vec<(ConstantValue, Span)> = vec![];
+        for condition in conditions {
+            let Some(value) = context.evaluate(condition) else {
+                continue;
+            };
+
+            let previous = values.iter().find(|(previous, _)| {
+                if is_strict { previous.is_identical(&value) } else { previous.is_loosely_equal(&value) }
+            });
+
+            if let Some((previous_value, previous_span)) = previous {
+                let note = if is_strict {
+                    format!("This {} is unreachable, as `{}` is already matched by a previous {}.", kind, value, kind)
+                } else {
+                    format!(
+                        "This {} is unreachable, as `{}` is loosely equal to `{}`, which is matched by a previous {}.",
+                        kind, value, previous_value, kind
+                    )
+                };
+
+                issues.push(
+                    Issue::new(
+                        context.level(),
+                        format!(
+                            "Condition `{}` is already matched by a previous {} of this {}.",
+                            value, kind, construct
+                        ),
+                    )
+                    .with_annotation(
+                        Annotation::primary(condition.span()).with_message("This condition is a duplicate."),
+                    )
+                    .with_annotation(
+                        Annotation::secondary(*previous_span).with_message("The same value is first matched here."),
+                    )
+                    .with_note(note)
+                    .with_help(format!("Remove the duplicate condition, or merge the {}s.", kind)),
+                );
+
+                continue;
+            }
+
+            values.push((value, condition.span()));
+        }
+
+        for issue in issues {
+            context.report(issue);
+        }
+
+        LintDirective::default()
+    }
+}
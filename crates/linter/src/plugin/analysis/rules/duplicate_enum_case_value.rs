@@ -0,0 +1,125 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_typing::evaluator::ConstantValue;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DuplicateEnumCaseValueRule;
+
+impl Rule for DuplicateEnumCaseValueRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Duplicate Enum Case Value", Level::Error)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects backed enum cases sharing the same value, which results in a fatal error.
+
+                Values are compared after being evaluated, e.g. `'a' . 'b'`, and `'ab'` are the same value.
+                Values using constants, e.g. `self::PREFIX . 'a'`, are resolved when their value is known.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A backed enum with distinct values",
+                indoc! {r#"
+                    <?php
+
+                    enum Suit: string
+                    {
+                        case Hearts = 'H';
+                        case Spades = 'S';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A backed enum with two cases sharing the same value",
+                indoc! {r#"
+                    <?php
+
+                    enum Suit: string
+                    {
+                        case Hearts = 'H';
+                        case Spades = 'S';
+                        case Clubs = 'H';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A backed enum with a case value resolving to the value of another case",
+                indoc! {r#"
+                    <?php
+
+                    enum Status: int
+                    {
+                        const OFFSET = 1;
+
+                        case Active = 2;
+                        case Inactive = self::OFFSET + 1;
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Enum(r#enum) = node else {
+            return LintDirective::default();
+        };
+
+        if r#enum.backing_type_hint.is_none() {
+            return LintDirective::default();
+        }
+
+        let mut issues = vec![];
+        let mut values: Vec<(ConstantValue, &LocalIdentifier, Span)> = vec![];
+        for member in r#enum.members.iter() {
+            let ClassLikeMember::EnumCase(EnumCase { item: EnumCaseItem::Backed(item), .. }) = member else {
+                continue;
+            };
+
+            let Some(value) = context.evaluate(&item.value) else {
+                continue;
+            };
+
+            if let Some((_, previous_name, previous_span)) = values.iter().find(|(previous, _, _)| *previous == value) {
+                let case_name = context.lookup(&item.name.value).to_string();
+                let previous_case_name = context.lookup(&previous_name.value).to_string();
+
+                issues.push(
+                    Issue::new(
+                        context.level(),
+                        format!(
+                            "Enum case `{}` has the same value, `{}`, as case `{}`.",
+                            case_name, value, previous_case_name
+                        ),
+                    )
+                    .with_annotation(Annotation::primary(item.value.span()).with_message("This value is a duplicate."))
+                    .with_annotation(
+                        Annotation::secondary(*previous_span).with_message(format!(
+                            "Case `{}` is first defined with this value here.",
+                            previous_case_name
+                        )),
+                    )
+                    .with_note(
+                        "Each case of a backed enum must have a unique value, otherwise a fatal error is thrown.",
+                    )
+                    .with_help(format!("Use a different value for case `{}`.", case_name)),
+                );
+
+                continue;
+            }
+
+            values.push((value, &item.name, item.value.span()));
+        }
+
+        for issue in issues {
+            context.report(issue);
+        }
+
+        LintDirective::default()
+    }
+}
@@ -0,0 +1,353 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+use crate::utils::get_string_value;
+
+/// The formatting functions, along with the position of their format argument, and whether their values are
+/// passed as variadic arguments, as opposed to an array.
+const FORMAT_FUNCTIONS: [(&str, usize, bool); 6] = [
+    ("sprintf", 0, true),
+    ("printf", 0, true),
+    ("fprintf", 1, true),
+    ("vsprintf", 0, false),
+    ("vprintf", 0, false),
+    ("vfprintf", 1, false),
+];
+
+/// The conversion specifiers expecting a number.
+const NUMERIC_SPECIFIERS: &str = "bcdeEfFgGhHouxX";
+
+#[derive(Clone, Debug)]
+pub struct FormatStringRule;
+
+/// A conversion specification of a format string, e.g. `%05.2f`.
+struct Conversion {
+    /// The zero-based position of the value it formats.
+    argument: usize,
+    specifier: char,
+}
+
+impl Rule for FormatStringRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Format String", Level::Error)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Validates the literal format strings passed to `sprintf`, `printf`, `fprintf`, and their
+                `v*` counterparts, reporting unknown conversion specifiers, and, when the values are
+                statically known, a number of values not matching the format string.
+
+                Literal values that would be silently converted, such as a non-numeric string formatted
+                with `%d`, or an array formatted with `%s`, are also reported.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A format string matching its values",
+                indoc! {r#"
+                    <?php
+
+                    $message = sprintf('%s has %d new messages (%.1f%%).', $name, $count, $ratio);
+                    $message = vsprintf('%2$s, %1$s', [$first, $last]);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A format string expecting more values than given",
+                indoc! {r#"
+                    <?php
+
+                    $message = sprintf('%s has %d new messages.', $name);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A format string with an unknown conversion specifier",
+                indoc! {r#"
+                    <?php
+
+                    printf('%i items', $count);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A non-numeric string formatted as a number",
+                indoc! {r#"
+                    <?php
+
+                    $message = sprintf('%d items', 'many');
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::FunctionCall(function_call) = node else { return LintDirective::default() };
+
+        let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+            return LintDirective::default();
+        };
+
+        let function_name = context.resolve_function_name(function_identifier).to_owned();
+        let Some((_, format_position, is_variadic)) =
+            FORMAT_FUNCTIONS.iter().find(|(name, _, _)| function_name.eq_ignore_ascii_case(name))
+        else {
+            return LintDirective::default();
+        };
+
+        // Named or unpacked arguments make the positions of the format string and its values uncertain.
+        let mut arguments = vec![];
+        for argument in function_call.argument_list.arguments.iter() {
+            let Argument::Positional(PositionalArgument { ellipsis: None, value }) = argument else {
+                return LintDirective::default();
+            };
+
+            arguments.push(value);
+        }
+
+        let Some(Expression::Literal(Literal::String(format))) = arguments.get(*format_position) else {
+            return LintDirective::default();
+        };
+
+        let Some(format_value) = get_string_value(format, context.interner) else {
+            return LintDirective::default();
+        };
+
+        let (conversions, required) = match parse_format(&format_value) {
+            Ok(result) => result,
+            Err(message) => {
+                context.report(
+                    Issue::new(context.level(), format!("Invalid format string passed to `{}`.", function_name))
+                        .with_annotation(Annotation::primary(format.span()).with_message(message))
+                        .with_annotation(
+                            Annotation::secondary(function_call.span())
+                                .with_message(format!("`{}` throws a `ValueError` here.", function_name)),
+                        )
+                        .with_help("Use a valid conversion specification, e.g. `%s` or `%d`, or escape `%` as `%%`."),
+                );
+
+                return LintDirective::default();
+            }
+        };
+
+        let values = if *is_variadic {
+            arguments[format_position + 1..].to_vec()
+        } else {
+            let elements = match arguments.get(format_position + 1) {
+                Some(Expression::Array(array)) => array.elements.iter(),
+                Some(Expression::LegacyArray(array)) => array.elements.iter(),
+                _ => return LintDirective::default(),
+            };
+
+            let mut values = vec![];
+            for element in elements {
+                match element {
+                    ArrayElement::KeyValue(element) => values.push(element.value.as_ref()),
+                    ArrayElement::Value(element) => values.push(element.value.as_ref()),
+                    _ => return LintDirective::default(),
+                }
+            }
+
+            values
+        };
+
+        if values.len() < required {
+            context.report(
+                Issue::new(
+                    context.level(),
+                    format!(
+                        "`{}` expects {} value(s) for its format string, but {} given.",
+                        function_name,
+                        required,
+                        values.len()
+                    ),
+                )
+                .with_annotation(
+                    Annotation::primary(function_call.span())
+                        .with_message(format!("`{}` throws an `ArgumentCountError` here.", function_name)),
+                )
+                .with_annotation(
+                    Annotation::secondary(format.span()).with_message("The format string is defined here."),
+                )
+                .with_help("Pass a value for each conversion specification of the format string."),
+            );
+        } else if values.len() > required {
+            context.report(
+                Issue::new(
+                    context.level(),
+                    format!(
+                        "`{}` expects {} value(s) for its format string, but {} given.",
+                        function_name,
+                        required,
+                        values.len()
+                    ),
+                )
+                .with_annotation(
+                    Annotation::primary(values[required].span().join(values[values.len() - 1].span()))
+                        .with_message("These values are never formatted."),
+                )
+                .with_annotation(
+                    Annotation::secondary(format.span()).with_message("The format string is defined here."),
+                )
+                .with_help("Remove the unused values, or add conversion specifications for them."),
+            );
+        }
+
+        for conversion in conversions.iter() {
+            let Some(value) = values.get(conversion.argument) else {
+                continue;
+            };
+
+            let message = match value {
+                Expression::Array(_) | Expression::LegacyArray(_) => "An array is converted to the string `Array`.",
+                Expression::Literal(Literal::String(string)) if NUMERIC_SPECIFIERS.contains(conversion.specifier) => {
+                    match get_string_value(string, context.interner) {
+                        Some(value) if !is_numeric(&value) => "A non-numeric string is converted to `0`.",
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+
+            context.report(
+                Issue::new(
+                    context.level(),
+                    format!("Value of an incompatible type formatted with `%{}`.", conversion.specifier),
+                )
+                .with_annotation(Annotation::primary(value.span()).with_message(message))
+                .with_annotation(
+                    Annotation::secondary(format.span()).with_message("The format string is defined here."),
+                )
+                .with_help("Pass a value of the type expected by the conversion specifier, or change the specifier."),
+            );
+        }
+
+        LintDirective::default()
+    }
+}
+
+/// Parses a format string, following the rules of PHP, returning its conversion specifications, and the number of
+/// values it requires, or a description of the first error found.
+fn parse_format(format: &str) -> Result<(Vec<Conversion>, usize), String> {
+    let characters = format.chars().collect::<Vec<_>>();
+    let mut conversions = vec![];
+    let mut required = 0;
+    let mut sequential = 0;
+    let mut position = 0;
+
+    // Parses an argument number, e.g. `2$`, returning its zero-based position.
+    let parse_argument_number = |position: &mut usize| -> Result<Option<usize>, String> {
+        let start = *position;
+        let mut end = start;
+        while characters.get(end).is_some_and(char::is_ascii_digit) {
+            end += 1;
+        }
+
+        if end == start || characters.get(end) != Some(&'$') {
+            return Ok(None);
+        }
+
+        let number = characters[start..end].iter().collect::<String>().parse::<usize>().unwrap_or(usize::MAX);
+        if number == 0 {
+            return Err("Argument numbers must be greater than zero.".to_string());
+        }
+
+        *position = end + 1;
+
+        Ok(Some(number - 1))
+    };
+
+    while position < characters.len() {
+        if characters[position] != '%' {
+            position += 1;
+
+            continue;
+        }
+
+        position += 1;
+        if characters.get(position) == Some(&'%') {
+            position += 1;
+
+            continue;
+        }
+
+        let argument = parse_argument_number(&mut position)?;
+
+        // Flags, including a custom padding character, e.g. `'*`.
+        loop {
+            match characters.get(position) {
+                Some('-' | '+' | ' ' | '0') => position += 1,
+                Some('\'') if position + 1 < characters.len() => position += 2,
+                _ => break,
+            }
+        }
+
+        // The width and the precision, either literal, or taken from the values with `*`.
+        for is_precision in [false, true] {
+            if is_precision {
+                if characters.get(position) != Some(&'.') {
+                    break;
+                }
+
+                position += 1;
+            }
+
+            if characters.get(position) == Some(&'*') {
+                position += 1;
+
+                let width_argument = match parse_argument_number(&mut position)? {
+                    Some(argument) => argument,
+                    None => {
+                        sequential += 1;
+
+                        sequential - 1
+                    }
+                };
+
+                required = required.max(width_argument + 1);
+            } else {
+                while characters.get(position).is_some_and(char::is_ascii_digit) {
+                    position += 1;
+                }
+            }
+        }
+
+        // The `l` length modifier is accepted, and ignored.
+        if characters.get(position) == Some(&'l') {
+            position += 1;
+        }
+
+        let Some(specifier) = characters.get(position).copied() else {
+            return Err("The format string ends with an incomplete conversion specification.".to_string());
+        };
+
+        if specifier != 's' && !NUMERIC_SPECIFIERS.contains(specifier) {
+            return Err(format!("`{}` is not a valid conversion specifier.", specifier));
+        }
+
+        let argument = match argument {
+            Some(argument) => argument,
+            None => {
+                sequential += 1;
+
+                sequential - 1
+            }
+        };
+
+        required = required.max(argument + 1);
+        conversions.push(Conversion { argument, specifier });
+        position += 1;
+    }
+
+    Ok((conversions, required))
+}
+
+/// Determines whether a string is numeric, as understood by PHP, allowing surrounding whitespace.
+fn is_numeric(value: &str) -> bool {
+    let value = value.trim_matches([' ', '\t', '\n', '\r', '\x0B', '\x0C']);
+
+    !value.is_empty()
+        && value.bytes().all(|byte| byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.' | b'e' | b'E'))
+        && value.parse::<f64>().is_ok()
+}
@@ -1,6 +1,7 @@
 use indoc::indoc;
 
 use mago_ast::*;
+use mago_reflection::class_like::ClassLikeReflection;
 use mago_reporting::*;
 use mago_span::*;
 
@@ -118,7 +119,7 @@ impl Rule for InstantiationRule {
                 .with_note("Interfaces are abstract types that cannot be instantiated directly.")
                 .with_note("Use a class that implements the interface instead.");
 
-            context.report(issue);
+            context.report(with_declaration(issue, reflection, "Interface", &class_fqcn));
 
             return LintDirective::default();
         }
@@ -132,7 +133,7 @@ impl Rule for InstantiationRule {
                 .with_note("Traits are abstract types that cannot be instantiated directly.")
                 .with_note("Use a class that implements the trait instead.");
 
-            context.report(issue);
+            context.report(with_declaration(issue, reflection, "Trait", &class_fqcn));
 
             return LintDirective::default();
         }
@@ -146,7 +147,7 @@ impl Rule for InstantiationRule {
                 .with_note("Enums are types that represent a fixed set of constants.")
                 .with_note("Use one of the enum cases instead.");
 
-            context.report(issue);
+            context.report(with_declaration(issue, reflection, "Enum", &class_fqcn));
 
             return LintDirective::default();
         }
@@ -160,9 +161,19 @@ impl Rule for InstantiationRule {
                 .with_note("Abstract classes are incomplete types that cannot be instantiated directly.")
                 .with_note("Use a concrete subclass instead.");
 
-            context.report(issue);
+            context.report(with_declaration(issue, reflection, "Abstract class", &class_fqcn));
         }
 
         LintDirective::default()
     }
 }
+
+/// Points to the declaration of the instantiated class-like, if it is declared in the user's code.
+fn with_declaration(issue: Issue, reflection: &ClassLikeReflection, kind: &str, name: &str) -> Issue {
+    let span = reflection.name.span();
+    if !span.start.source.category().is_user_defined() {
+        return issue;
+    }
+
+    issue.with_annotation(Annotation::secondary(span).with_message(format!("{} `{}` is declared here.", kind, name)))
+}
@@ -0,0 +1,136 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_typing::evaluator::ConstantValue;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct IntegerOverflowRule;
+
+impl Rule for IntegerOverflowRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Integer Overflow", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects integer literals exceeding `PHP_INT_MAX`, and constant integer operations overflowing,
+                e.g. `PHP_INT_MAX + 1`.
+
+                In both cases, PHP silently uses a float instead of an integer, losing precision.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using the maximum integer value",
+                indoc! {r#"
+                    <?php
+
+                    $maximum = 9223372036854775807;
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "An integer literal exceeding the maximum integer value",
+                indoc! {r#"
+                    <?php
+
+                    $identifier = 9223372036854775808;
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "An integer operation overflowing",
+                indoc! {r#"
+                    <?php
+
+                    $next = PHP_INT_MAX + 1;
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        match node {
+            Node::LiteralInteger(integer) => {
+                if integer.value.is_some_and(|value| i64::try_from(value).is_ok()) {
+                    return LintDirective::default();
+                }
+
+                let raw = context.lookup(&integer.raw).to_string();
+                let mut issue = Issue::new(
+                    context.level(),
+                    format!(
+                        "Integer literal `{}` exceeds the maximum integer value, and is interpreted as a float.",
+                        raw
+                    ),
+                )
+                .with_annotation(
+                    Annotation::primary(integer.span).with_message("This literal is interpreted as a float."),
+                )
+                .with_note(format!("The maximum integer value, `PHP_INT_MAX`, is `{}`.", i64::MAX));
+
+                if integer.value == Some(i64::MIN.unsigned_abs()) {
+                    issue = issue.with_help("Use `PHP_INT_MIN` to refer to the minimum integer value.");
+                } else {
+                    issue = issue.with_help("Use a float literal, or a string if the exact value is needed.");
+                }
+
+                context.report(issue);
+            }
+            Node::Binary(binary) => {
+                if !matches!(
+                    binary.operator,
+                    BinaryOperator::Addition(_)
+                        | BinaryOperator::Subtraction(_)
+                        | BinaryOperator::Multiplication(_)
+                        | BinaryOperator::Exponentiation(_)
+                ) {
+                    return LintDirective::default();
+                }
+
+                let (Some(ConstantValue::Integer(lhs)), Some(ConstantValue::Integer(rhs))) =
+                    (context.evaluate(&binary.lhs), context.evaluate(&binary.rhs))
+                else {
+                    return LintDirective::default();
+                };
+
+                let overflows = match binary.operator {
+                    BinaryOperator::Addition(_) => lhs.checked_add(rhs).is_none(),
+                    BinaryOperator::Subtraction(_) => lhs.checked_sub(rhs).is_none(),
+                    BinaryOperator::Multiplication(_) => lhs.checked_mul(rhs).is_none(),
+                    // Negative exponents result in a float, without overflowing.
+                    BinaryOperator::Exponentiation(_) => {
+                        rhs >= 0 && u32::try_from(rhs).ok().and_then(|rhs| lhs.checked_pow(rhs)).is_none()
+                    }
+                    _ => return LintDirective::default(),
+                };
+
+                if overflows {
+                    report_overflow(context, binary.span());
+                }
+            }
+            Node::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Negation(_), operand }) => {
+                if let Some(ConstantValue::Integer(i64::MIN)) = context.evaluate(operand) {
+                    report_overflow(context, node.span());
+                }
+            }
+            _ => {}
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn report_overflow(context: &mut LintContext<'_>, span: Span) {
+    let issue = Issue::new(context.level(), "This integer operation overflows, and results in a float.")
+        .with_annotation(Annotation::primary(span).with_message("This operation overflows."))
+        .with_note(format!(
+            "Integers are limited to the range from `PHP_INT_MIN` (`{}`) to `PHP_INT_MAX` (`{}`).",
+            i64::MIN,
+            i64::MAX
+        ))
+        .with_help("Use floats explicitly, or make sure the result fits in an integer.");
+
+    context.report(issue);
+}
@@ -0,0 +1,248 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_reflection::r#type::kind::ArrayShape;
+use mago_reflection::r#type::kind::ArrayShapePropertyKey;
+use mago_reflection::r#type::kind::ArrayTypeKind;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct InvalidShapeArgumentRule;
+
+impl Rule for InvalidShapeArgumentRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Invalid Shape Argument", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects array literals passed to parameters declared with an array shape, e.g. using
+                `@param array{id: int, name: string} $user`, that are missing required keys, or that
+                contain keys not defined in the shape, as well as array literals with explicit keys
+                passed to parameters declared as lists, e.g. using `@param list<string> $names`.
+
+                Only calls to functions, and to methods called on `$this`, `self`, `static`, or on a
+                named class, are checked. Shapes declared with `...` allow other keys.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Passing an array matching the declared shape",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @param array{id: int, name: string, email?: string} $user
+                     */
+                    function save_user(array $user): void
+                    {
+                    }
+
+                    save_user(['id' => 1, 'name' => 'John']);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Passing an array missing a required key",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @param array{id: int, name: string} $user
+                     */
+                    function save_user(array $user): void
+                    {
+                    }
+
+                    save_user(['id' => 1]);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Passing an array with a key not defined in the shape",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @param array{id: int, name: string} $user
+                     */
+                    function save_user(array $user): void
+                    {
+                    }
+
+                    save_user(['id' => 1, 'name' => 'John', 'age' => 42]);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Passing an array with string keys to a list parameter",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @param list<string> $names
+                     */
+                    function greet_all(array $names): void
+                    {
+                    }
+
+                    greet_all(['first' => 'John', 'second' => 'Jane']);
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Call(call) = node else { return LintDirective::default() };
+
+        let argument_list = match call {
+            Call::Function(call) => &call.argument_list,
+            Call::Method(call) => &call.argument_list,
+            Call::NullSafeMethod(call) => &call.argument_list,
+            Call::StaticMethod(call) => &call.argument_list,
+        };
+
+        let mut issues = vec![];
+        {
+            let Some(function_like) = context.resolve_callee(call) else {
+                return LintDirective::default();
+            };
+
+            for (position, argument) in argument_list.arguments.iter().enumerate() {
+                let Some(parameter) = context.get_argument_parameter(function_like, position, argument) else {
+                    continue;
+                };
+
+                let Some(type_reflection) = &parameter.docblock_type_reflection else {
+                    continue;
+                };
+
+                let value = argument.value();
+                if !matches!(value, Expression::Array(_) | Expression::LegacyArray(_)) {
+                    continue;
+                }
+
+                let argument_kind =
+                    mago_typing::infere_kind(context.interner, &context.module.source, &context.module.names, value);
+
+                let empty_shape = ArrayShape { properties: vec![], additional_properties: None };
+                let argument_shape = match &argument_kind {
+                    TypeKind::Array(ArrayTypeKind::Shape(shape)) => shape,
+                    TypeKind::Array(ArrayTypeKind::Array { known_size: Some(0), .. }) => &empty_shape,
+                    // The keys of the array are not known.
+                    _ => continue,
+                };
+
+                let argument_keys = argument_shape
+                    .get_keyed_properties(context.interner)
+                    .into_iter()
+                    .map(|(key, _)| key)
+                    .collect::<Vec<_>>();
+
+                let parameter_name = context.lookup(&parameter.name);
+                let function_name = function_like.name.get_key(context.interner);
+                let declaration = Annotation::secondary(type_reflection.span).with_message(format!(
+                    "`{}` is declared as `{}` here.",
+                    parameter_name,
+                    type_reflection.kind.get_key(context.interner)
+                ));
+
+                match &type_reflection.kind {
+                    TypeKind::Array(ArrayTypeKind::Shape(shape)) => {
+                        let parameter_properties = shape.get_keyed_properties(context.interner);
+
+                        let missing_keys = parameter_properties
+                            .iter()
+                            .filter(|(key, property)| !property.optional && !argument_keys.contains(key))
+                            .map(|(key, _)| format_key(context.interner, key))
+                            .collect::<Vec<_>>();
+
+                        let unknown_keys = if shape.is_sealed() {
+                            argument_keys
+                                .iter()
+                                .filter(|key| {
+                                    parameter_properties.iter().all(|(parameter_key, _)| parameter_key != *key)
+                                })
+                                .map(|key| format_key(context.interner, key))
+                                .collect::<Vec<_>>()
+                        } else {
+                            vec![]
+                        };
+
+                        if missing_keys.is_empty() && unknown_keys.is_empty() {
+                            continue;
+                        }
+
+                        let mut issue = Issue::new(
+                            context.level(),
+                            format!(
+                                "Array passed to parameter `{}` of `{}` does not match its declared shape.",
+                                parameter_name, function_name
+                            ),
+                        )
+                        .with_annotation(
+                            Annotation::primary(value.span()).with_message("This array does not match the shape."),
+                        )
+                        .with_annotation(declaration);
+
+                        if !missing_keys.is_empty() {
+                            issue = issue.with_note(format!("Missing required keys: {}.", missing_keys.join(", ")));
+                        }
+
+                        if !unknown_keys.is_empty() {
+                            issue =
+                                issue.with_note(format!("Keys not defined in the shape: {}.", unknown_keys.join(", ")));
+                        }
+
+                        issues.push(issue.with_help(format!(
+                            "Pass an array with the keys declared in the shape of `{}`.",
+                            parameter_name
+                        )));
+                    }
+                    TypeKind::Array(ArrayTypeKind::List { .. }) => {
+                        let is_list = argument_keys
+                            .iter()
+                            .enumerate()
+                            .all(|(index, key)| *key == ArrayShapePropertyKey::Integer(index as isize));
+
+                        if is_list {
+                            continue;
+                        }
+
+                        issues.push(
+                            Issue::new(
+                                context.level(),
+                                format!(
+                                    "Array passed to parameter `{}` of `{}` is not a list.",
+                                    parameter_name, function_name
+                                ),
+                            )
+                            .with_annotation(
+                                Annotation::primary(value.span())
+                                    .with_message("This array does not have sequential integer keys."),
+                            )
+                            .with_annotation(declaration)
+                            .with_note("Lists have sequential integer keys, starting from `0`.")
+                            .with_help("Remove the keys of the array, or pass its values using `array_values`."),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for issue in issues {
+            context.report(issue);
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn format_key(interner: &ThreadedInterner, key: &ArrayShapePropertyKey) -> String {
+    match key {
+        ArrayShapePropertyKey::String(string) => format!("`'{}'`", interner.lookup(string)),
+        ArrayShapePropertyKey::Integer(integer) => format!("`{}`", integer),
+    }
+}
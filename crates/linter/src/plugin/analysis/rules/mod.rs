@@ -1,5 +1,18 @@
+pub mod date_format;
+pub mod duplicate_array_key;
+pub mod duplicate_branch_condition;
+pub mod duplicate_enum_case_value;
+pub mod format_string;
 pub mod instantiation;
+pub mod integer_overflow;
+pub mod invalid_shape_argument;
+pub mod null_safety;
 pub mod override_attribute;
 pub mod parameter_name;
+pub mod property_initialization;
+pub mod return_type_consistency;
+pub mod template_argument_count;
 pub mod undefined_constant_or_case;
 pub mod undefined_function_or_method;
+pub mod undefined_shape_key;
+pub mod unreferenced_template;
@@ -0,0 +1,1041 @@
+use ahash::HashMap;
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_ast_utils::condition::is_truthy;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reflection::r#type::kind::ValueTypeKind;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+/// Type checking functions returning `true` only for non-null values.
+const TYPE_CHECK_FUNCTIONS: [&str; 15] = [
+    "is_array",
+    "is_bool",
+    "is_callable",
+    "is_countable",
+    "is_double",
+    "is_float",
+    "is_int",
+    "is_integer",
+    "is_iterable",
+    "is_long",
+    "is_numeric",
+    "is_object",
+    "is_resource",
+    "is_scalar",
+    "is_string",
+];
+
+#[derive(Clone, Debug)]
+pub struct NullSafetyRule;
+
+/// Where a variable may have become `null`.
+#[derive(Debug, Clone, Copy)]
+struct Origin {
+    span: Span,
+    is_parameter: bool,
+}
+
+/// The variables that may be `null` at a given point of a function body, along with where they may have become `null`.
+type State = HashMap<String, Origin>;
+
+impl Rule for NullSafetyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Null Safety", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects variables that may be `null` being dereferenced, i.e. used to access a property,
+                call a method, or read an offset, or passed to a function parameter that does not accept
+                `null`, without being checked first.
+
+                A variable may be `null` if it is a parameter declared as nullable, or if it is assigned
+                `null`, the result of a nullsafe operation, or the result of a function or method declared
+                to return a nullable type. Checks such as `!== null`, `instanceof`, `isset`, or `is_null`,
+                as well as `??`, and early returns, are taken into account.
+
+                The analysis is limited to a single function body; properties, and variables captured by
+                closures, are not tracked.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Checking a nullable parameter before using it",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        public function __construct(
+                            public readonly string $name,
+                        ) {}
+                    }
+
+                    function greet(?User $user): string
+                    {
+                        if ($user === null) {
+                            return 'Hello, guest!';
+                        }
+
+                        return 'Hello, ' . $user->name . '!';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "Using the nullsafe and null coalescing operators",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        public function __construct(
+                            public readonly string $name,
+                        ) {}
+                    }
+
+                    function greet(?User $user): string
+                    {
+                        return 'Hello, ' . ($user?->name ?? 'guest') . '!';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Accessing a property of a nullable parameter",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        public function __construct(
+                            public readonly string $name,
+                        ) {}
+                    }
+
+                    function greet(?User $user): string
+                    {
+                        return 'Hello, ' . $user->name . '!';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Calling a method on the result of a function returning a nullable type",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        public function getName(): string
+                        {
+                            return 'John';
+                        }
+                    }
+
+                    function find_user(int $id): ?User
+                    {
+                        return $id === 1 ? new User() : null;
+                    }
+
+                    function greet(int $id): string
+                    {
+                        $user = find_user($id);
+
+                        return 'Hello, ' . $user->getName() . '!';
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let issues = {
+            let mut analyzer = Analyzer { context, reporting: true, exits: vec![], issues: vec![] };
+
+            match node {
+                Node::Function(function) => {
+                    let state = analyzer.get_parameters_state(&function.parameter_list);
+                    analyzer.analyze_statements(function.body.statements.as_slice(), state);
+                }
+                Node::Method(method) => {
+                    let MethodBody::Concrete(body) = &method.body else {
+                        return LintDirective::default();
+                    };
+
+                    let state = analyzer.get_parameters_state(&method.parameter_list);
+                    analyzer.analyze_statements(body.statements.as_slice(), state);
+                }
+                Node::Closure(closure) => {
+                    let state = analyzer.get_parameters_state(&closure.parameter_list);
+                    analyzer.analyze_statements(closure.body.statements.as_slice(), state);
+                }
+                Node::ArrowFunction(arrow_function) => {
+                    let mut state = analyzer.get_parameters_state(&arrow_function.parameter_list);
+                    analyzer.walk(Node::Expression(&arrow_function.expression), &mut state);
+                }
+                _ => return LintDirective::default(),
+            }
+
+            analyzer.issues
+        };
+
+        for issue in issues {
+            context.report(issue);
+        }
+
+        LintDirective::default()
+    }
+}
+
+struct Analyzer<'c, 'i> {
+    context: &'c LintContext<'i>,
+    /// Whether issues are reported, which is not the case when computing the state at the start of a loop iteration.
+    reporting: bool,
+    /// The states at the `break`, and `continue`, statements of the enclosing loops, and `switch` statements.
+    exits: Vec<Vec<State>>,
+    issues: Vec<Issue>,
+}
+
+impl<'a, 'c, 'i> Analyzer<'c, 'i> {
+    fn get_parameters_state(&self, parameter_list: &FunctionLikeParameterList) -> State {
+        let mut state = State::default();
+        for parameter in parameter_list.parameters.iter() {
+            if parameter.ellipsis.is_some() {
+                continue;
+            }
+
+            let has_null_default = parameter
+                .default_value
+                .as_ref()
+                .is_some_and(|default_value| matches!(default_value.value, Expression::Literal(Literal::Null(_))));
+
+            if has_null_default || parameter.hint.as_ref().is_some_and(is_nullable_hint) {
+                state.insert(
+                    self.context.lookup(&parameter.variable.name).to_string(),
+                    Origin { span: parameter.span(), is_parameter: true },
+                );
+            }
+        }
+
+        state
+    }
+
+    /// Analyzes the given statements, returning the state when their execution completes normally,
+    /// or `None` if it never does.
+    fn analyze_statements(&mut self, statements: &'a [Statement], state: State) -> Option<State> {
+        let mut state = Some(state);
+        for statement in statements {
+            state = self.analyze_statement(statement, state?);
+        }
+
+        state
+    }
+
+    fn analyze_statement(&mut self, statement: &'a Statement, mut state: State) -> Option<State> {
+        match statement {
+            Statement::Block(block) => self.analyze_statements(block.statements.as_slice(), state),
+            Statement::Return(r#return) => {
+                if let Some(value) = &r#return.value {
+                    self.walk(Node::Expression(value), &mut state);
+                }
+
+                None
+            }
+            Statement::Break(_) | Statement::Continue(_) => {
+                if let Some(exits) = self.exits.last_mut() {
+                    exits.push(state);
+                }
+
+                None
+            }
+            Statement::Expression(expression_statement) => {
+                self.walk(Node::Expression(&expression_statement.expression), &mut state);
+
+                match expression_statement.expression.as_ref() {
+                    Expression::Throw(_) | Expression::Construct(Construct::Exit(_) | Construct::Die(_)) => None,
+                    _ => Some(state),
+                }
+            }
+            Statement::If(r#if) => self.analyze_if(r#if, state),
+            Statement::While(r#while) => {
+                let statements = match &r#while.body {
+                    WhileBody::Statement(statement) => std::slice::from_ref(statement.as_ref()),
+                    WhileBody::ColonDelimited(body) => body.statements.as_slice(),
+                };
+
+                self.analyze_loop(state, |analyzer, mut state| {
+                    analyzer.walk(Node::Expression(&r#while.condition), &mut state);
+
+                    let end = {
+                        let body_state = analyzer.narrow(&state, &r#while.condition, true);
+
+                        analyzer.analyze_statements(statements, body_state)
+                    };
+
+                    (analyzer.narrow_if_completes(&state, &r#while.condition), end)
+                })
+            }
+            Statement::DoWhile(do_while) => self.analyze_loop(state, |analyzer, state| {
+                let Some(mut end) = analyzer.analyze_statement(&do_while.statement, state) else {
+                    return (None, None);
+                };
+
+                analyzer.walk(Node::Expression(&do_while.condition), &mut end);
+
+                (
+                    analyzer.narrow_if_completes(&end, &do_while.condition),
+                    Some(analyzer.narrow(&end, &do_while.condition, true)),
+                )
+            }),
+            Statement::For(r#for) => {
+                for initialization in r#for.initializations.iter() {
+                    self.walk(Node::Expression(initialization), &mut state);
+                }
+
+                let statements = match &r#for.body {
+                    ForBody::Statement(statement) => std::slice::from_ref(statement.as_ref()),
+                    ForBody::ColonDelimited(body) => body.statements.as_slice(),
+                };
+
+                self.analyze_loop(state, |analyzer, mut state| {
+                    for condition in r#for.conditions.iter() {
+                        analyzer.walk(Node::Expression(condition), &mut state);
+                    }
+
+                    let (body_state, exit) = match r#for.conditions.last() {
+                        Some(condition) => {
+                            (analyzer.narrow(&state, condition, true), analyzer.narrow_if_completes(&state, condition))
+                        }
+                        None => (state, None),
+                    };
+
+                    let mut end = analyzer.analyze_statements(statements, body_state);
+                    if let Some(end) = end.as_mut() {
+                        for increment in r#for.increments.iter() {
+                            analyzer.walk(Node::Expression(increment), end);
+                        }
+                    }
+
+                    (exit, end)
+                })
+            }
+            Statement::Foreach(foreach) => {
+                self.walk(Node::Expression(&foreach.expression), &mut state);
+
+                let statements = match &foreach.body {
+                    ForeachBody::Statement(statement) => std::slice::from_ref(statement.as_ref()),
+                    ForeachBody::ColonDelimited(body) => body.statements.as_slice(),
+                };
+
+                self.analyze_loop(state, |analyzer, mut state| {
+                    let exit = state.clone();
+                    match &foreach.target {
+                        ForeachTarget::Value(target) => analyzer.assign_unknown(&target.value, &mut state),
+                        ForeachTarget::KeyValue(target) => {
+                            analyzer.assign_unknown(&target.key, &mut state);
+                            analyzer.assign_unknown(&target.value, &mut state);
+                        }
+                    }
+
+                    (Some(exit), analyzer.analyze_statements(statements, state))
+                })
+            }
+            Statement::Switch(switch) => {
+                self.walk(Node::Expression(&switch.expression), &mut state);
+
+                self.exits.push(vec![]);
+                let mut has_default = false;
+                let mut fallthrough: Option<State> = None;
+                for case in switch.body.cases() {
+                    has_default |= matches!(case, SwitchCase::Default(_));
+
+                    let entry = join(Some(state.clone()), fallthrough.take()).unwrap_or_default();
+                    fallthrough = self.analyze_statements(case.statements(), entry);
+                }
+
+                let mut exit =
+                    self.exits.pop().into_iter().flatten().fold(fallthrough, |exit, state| join(exit, Some(state)));
+                if !has_default {
+                    exit = join(exit, Some(state));
+                }
+
+                exit
+            }
+            Statement::Try(r#try) => {
+                let try_end = self.analyze_statements(r#try.block.statements.as_slice(), state.clone());
+
+                // An exception may be thrown at any point of the `try` block.
+                let catch_entry = join(Some(state.clone()), try_end.clone()).unwrap_or_default();
+
+                let mut end = try_end;
+                for catch_clause in r#try.catch_clauses.iter() {
+                    let mut catch_state = catch_entry.clone();
+                    if let Some(variable) = &catch_clause.variable {
+                        catch_state.remove(self.context.lookup(&variable.name));
+                    }
+
+                    end = join(end, self.analyze_statements(catch_clause.block.statements.as_slice(), catch_state));
+                }
+
+                match &r#try.finally_clause {
+                    Some(finally_clause) => {
+                        let finally_entry = join(Some(catch_entry), end.clone()).unwrap_or_default();
+                        let finally_end =
+                            self.analyze_statements(finally_clause.block.statements.as_slice(), finally_entry);
+
+                        end.and(finally_end)
+                    }
+                    None => end,
+                }
+            }
+            Statement::Global(global) => {
+                for variable in global.variables.iter() {
+                    if let Variable::Direct(variable) = variable {
+                        state.remove(self.context.lookup(&variable.name));
+                    }
+                }
+
+                Some(state)
+            }
+            Statement::Static(r#static) => {
+                for item in r#static.items.iter() {
+                    let variable = match item {
+                        StaticItem::Abstract(item) => &item.variable,
+                        StaticItem::Concrete(item) => {
+                            self.walk(Node::Expression(&item.value), &mut state);
+
+                            &item.variable
+                        }
+                    };
+
+                    state.remove(self.context.lookup(&variable.name));
+                }
+
+                Some(state)
+            }
+            Statement::Unset(unset) => {
+                for value in unset.values.iter() {
+                    if let Expression::Variable(Variable::Direct(variable)) = value {
+                        state.remove(self.context.lookup(&variable.name));
+                    }
+                }
+
+                Some(state)
+            }
+            Statement::Function(_)
+            | Statement::Class(_)
+            | Statement::Interface(_)
+            | Statement::Trait(_)
+            | Statement::Enum(_) => Some(state),
+            statement => {
+                self.walk(Node::Statement(statement), &mut state);
+
+                Some(state)
+            }
+        }
+    }
+
+    fn analyze_if(&mut self, r#if: &'a If, mut state: State) -> Option<State> {
+        self.walk(Node::Expression(&r#if.condition), &mut state);
+
+        let mut branches: Vec<(Option<&'a Expression>, &'a [Statement])> = vec![];
+        let else_statements =
+            match &r#if.body {
+                IfBody::Statement(body) => {
+                    branches.push((None, std::slice::from_ref(body.statement.as_ref())));
+                    branches.extend(body.else_if_clauses.iter().map(|clause| {
+                        (Some(clause.condition.as_ref()), std::slice::from_ref(clause.statement.as_ref()))
+                    }));
+
+                    body.else_clause.as_ref().map(|clause| std::slice::from_ref(clause.statement.as_ref()))
+                }
+                IfBody::ColonDelimited(body) => {
+                    branches.push((None, body.statements.as_slice()));
+                    branches.extend(
+                        body.else_if_clauses
+                            .iter()
+                            .map(|clause| (Some(clause.condition.as_ref()), clause.statements.as_slice())),
+                    );
+
+                    body.else_clause.as_ref().map(|clause| clause.statements.as_slice())
+                }
+            };
+
+        let mut condition = r#if.condition.as_ref();
+        let mut end = None;
+        for (else_if_condition, statements) in branches {
+            if let Some(else_if_condition) = else_if_condition {
+                state = self.narrow(&state, condition, false);
+                self.walk(Node::Expression(else_if_condition), &mut state);
+                condition = else_if_condition;
+            }
+
+            end = join(end, self.analyze_statements(statements, self.narrow(&state, condition, true)));
+        }
+
+        let else_state = self.narrow(&state, condition, false);
+        match else_statements {
+            Some(statements) => join(end, self.analyze_statements(statements, else_state)),
+            None => join(end, Some(else_state)),
+        }
+    }
+
+    /// Analyzes a loop using the given iteration function, which returns the state when the loop condition
+    /// is not met, if it may not be, and the state at the end of the iteration.
+    ///
+    /// The loop is analyzed twice; first without reporting issues, to compute the state at the start of any
+    /// iteration other than the first one.
+    fn analyze_loop<F>(&mut self, state: State, iterate: F) -> Option<State>
+    where
+        F: Fn(&mut Self, State) -> (Option<State>, Option<State>),
+    {
+        let reporting = self.reporting;
+        self.reporting = false;
+        self.exits.push(vec![]);
+        let (_, end) = iterate(self, state.clone());
+        let continues = self.exits.pop().unwrap_or_default();
+        self.reporting = reporting;
+
+        let entry = continues.into_iter().fold(join(Some(state.clone()), end), |entry, state| join(entry, Some(state)));
+
+        self.exits.push(vec![]);
+        let (exit, _) = iterate(self, entry.unwrap_or(state));
+        let exits = self.exits.pop().unwrap_or_default();
+
+        exits.into_iter().fold(exit, |exit, state| join(exit, Some(state)))
+    }
+
+    /// Walks the given node in evaluation order, reporting dereferences of variables that may be `null`,
+    /// and updating the state according to the assignments within it.
+    fn walk(&mut self, node: Node<'a>, state: &mut State) {
+        match node {
+            Node::Closure(_)
+            | Node::ArrowFunction(_)
+            | Node::AnonymousClass(_)
+            | Node::Function(_)
+            | Node::Class(_)
+            | Node::Interface(_)
+            | Node::Trait(_)
+            | Node::Enum(_) => return,
+            Node::Binary(binary) => match binary.operator {
+                BinaryOperator::And(_) | BinaryOperator::LowAnd(_) => {
+                    self.walk(Node::Expression(&binary.lhs), state);
+                    let mut rhs_state = self.narrow(state, &binary.lhs, true);
+                    self.walk(Node::Expression(&binary.rhs), &mut rhs_state);
+                    extend(state, rhs_state);
+
+                    return;
+                }
+                BinaryOperator::Or(_) | BinaryOperator::LowOr(_) => {
+                    self.walk(Node::Expression(&binary.lhs), state);
+                    let mut rhs_state = self.narrow(state, &binary.lhs, false);
+                    self.walk(Node::Expression(&binary.rhs), &mut rhs_state);
+                    extend(state, rhs_state);
+
+                    return;
+                }
+                BinaryOperator::NullCoalesce(_) => {
+                    self.walk_guarded(&binary.lhs, state);
+                    let mut rhs_state = state.clone();
+                    self.walk(Node::Expression(&binary.rhs), &mut rhs_state);
+                    extend(state, rhs_state);
+
+                    return;
+                }
+                _ => {}
+            },
+            Node::Conditional(conditional) => {
+                self.walk(Node::Expression(&conditional.condition), state);
+
+                let then_state = match &conditional.then {
+                    Some(then) => {
+                        let mut then_state = self.narrow(state, &conditional.condition, true);
+                        self.walk(Node::Expression(then), &mut then_state);
+
+                        then_state
+                    }
+                    None => state.clone(),
+                };
+
+                let mut else_state = self.narrow(state, &conditional.condition, false);
+                self.walk(Node::Expression(&conditional.r#else), &mut else_state);
+
+                *state = then_state;
+                extend(state, else_state);
+
+                return;
+            }
+            Node::Construct(Construct::Isset(isset)) => {
+                for value in isset.values.iter() {
+                    self.walk_guarded(value, state);
+                }
+
+                return;
+            }
+            Node::Construct(Construct::Empty(empty)) => {
+                self.walk_guarded(&empty.value, state);
+
+                return;
+            }
+            Node::Assignment(assignment) => {
+                self.walk_assignment(assignment, state);
+
+                return;
+            }
+            Node::PropertyAccess(access) => {
+                self.check_dereference(&access.object, access.span(), state, |variable| {
+                    format!("A property is accessed on `{}`, which may be `null`.", variable)
+                });
+            }
+            Node::MethodCall(call) => {
+                self.check_dereference(&call.object, call.span(), state, |variable| {
+                    format!("A method is called on `{}`, which may be `null`.", variable)
+                });
+            }
+            Node::ArrayAccess(access) => {
+                self.check_dereference(&access.array, access.span(), state, |variable| {
+                    format!("An offset of `{}` is read, while it may be `null`.", variable)
+                });
+            }
+            _ => {}
+        }
+
+        for child in node.children() {
+            self.walk(child, state);
+        }
+
+        if let Node::Call(call) = node {
+            self.check_call(call, state);
+        }
+    }
+
+    /// Walks the given expression, where dereferencing `null` is allowed, e.g. within `isset`, or on the left-hand
+    /// side of `??`.
+    fn walk_guarded(&mut self, expression: &'a Expression, state: &mut State) {
+        match expression {
+            Expression::Variable(Variable::Direct(_)) => {}
+            Expression::Parenthesized(parenthesized) => self.walk_guarded(&parenthesized.expression, state),
+            Expression::Access(Access::Property(PropertyAccess { object, property, .. }))
+            | Expression::Access(Access::NullSafeProperty(NullSafePropertyAccess { object, property, .. })) => {
+                self.walk_guarded(object, state);
+                self.walk(Node::ClassLikeMemberSelector(property), state);
+            }
+            Expression::ArrayAccess(access) => {
+                self.walk_guarded(&access.array, state);
+                self.walk(Node::Expression(&access.index), state);
+            }
+            expression => self.walk(Node::Expression(expression), state),
+        }
+    }
+
+    fn walk_assignment(&mut self, assignment: &'a Assignment, state: &mut State) {
+        self.walk(Node::Expression(&assignment.rhs), state);
+
+        match assignment.lhs.as_ref() {
+            Expression::Variable(Variable::Direct(variable)) => {
+                let name = self.context.lookup(&variable.name).to_string();
+                let origin = match assignment.operator {
+                    AssignmentOperator::Assign(_) => self.get_null_origin(&assignment.rhs, state),
+                    _ => None,
+                };
+
+                match origin {
+                    Some(origin) => {
+                        state.insert(name, origin);
+                    }
+                    None => {
+                        state.remove(&name);
+                    }
+                }
+            }
+            lhs => self.assign_unknown(lhs, state),
+        }
+    }
+
+    /// Updates the state for writing a value that is not known to be `null` to the given expression.
+    fn assign_unknown(&mut self, target: &'a Expression, state: &mut State) {
+        match target {
+            Expression::Variable(Variable::Direct(variable)) => {
+                state.remove(self.context.lookup(&variable.name));
+            }
+            // Writing to an offset of `null` converts it to an array.
+            Expression::ArrayAccess(access) => {
+                self.walk(Node::Expression(&access.index), state);
+                self.assign_unknown(&access.array, state);
+            }
+            Expression::ArrayAppend(append) => self.assign_unknown(&append.array, state),
+            Expression::Array(Array { elements, .. })
+            | Expression::LegacyArray(LegacyArray { elements, .. })
+            | Expression::List(List { elements, .. }) => {
+                for element in elements.iter() {
+                    match element {
+                        ArrayElement::KeyValue(element) => {
+                            self.walk(Node::Expression(&element.key), state);
+                            self.assign_unknown(&element.value, state);
+                        }
+                        ArrayElement::Value(element) => self.assign_unknown(&element.value, state),
+                        _ => {}
+                    }
+                }
+            }
+            target => self.walk(Node::Expression(target), state),
+        }
+    }
+
+    /// Checks the arguments of the given call against the parameters of the called function, or method,
+    /// and narrows the state using `assert` calls.
+    fn check_call(&mut self, call: &'a Call, state: &mut State) {
+        let argument_list = match call {
+            Call::Function(call) => {
+                if let Expression::Identifier(identifier) = call.function.as_ref() {
+                    if self.context.resolve_function_name(identifier).eq_ignore_ascii_case("assert") {
+                        if let Some(argument) = call.argument_list.arguments.first() {
+                            *state = self.narrow(state, argument.value(), true);
+                        }
+
+                        return;
+                    }
+                }
+
+                &call.argument_list
+            }
+            Call::Method(call) => &call.argument_list,
+            Call::NullSafeMethod(call) => &call.argument_list,
+            Call::StaticMethod(call) => &call.argument_list,
+        };
+
+        let Some(function_like) = self.context.resolve_callee(call) else {
+            return;
+        };
+
+        for (position, argument) in argument_list.arguments.iter().enumerate() {
+            let Some(parameter) = self.context.get_argument_parameter(function_like, position, argument) else {
+                continue;
+            };
+
+            let Expression::Variable(Variable::Direct(variable)) = argument.value() else {
+                continue;
+            };
+
+            let variable_name = self.context.lookup(&variable.name);
+            if parameter.is_passed_by_reference {
+                state.remove(variable_name);
+
+                continue;
+            }
+
+            if !rejects_null(parameter) {
+                continue;
+            }
+
+            let Some(origin) = state.remove(variable_name) else {
+                continue;
+            };
+
+            let parameter_name = self.context.lookup(&parameter.name);
+            let function_name = function_like.name.get_key(self.context.interner);
+            self.report(
+                format!(
+                    "`{}`, which may be `null`, is passed to parameter `{}` of `{}`, which does not accept `null`.",
+                    variable_name, parameter_name, function_name
+                ),
+                variable.span,
+                variable_name,
+                origin,
+            );
+        }
+    }
+
+    fn check_dereference<F>(&mut self, object: &Expression, span: Span, state: &mut State, message: F)
+    where
+        F: FnOnce(&str) -> String,
+    {
+        let Expression::Variable(Variable::Direct(variable)) = object else {
+            return;
+        };
+
+        let variable_name = self.context.lookup(&variable.name);
+
+        // A dereference failing stops the execution, so the variable is known not to be `null` afterwards.
+        let Some(origin) = state.remove(variable_name) else {
+            return;
+        };
+
+        self.report(message(variable_name), span, variable_name, origin);
+    }
+
+    fn report(&mut self, message: String, span: Span, variable_name: &str, origin: Origin) {
+        if !self.reporting {
+            return;
+        }
+
+        let origin_message = if origin.is_parameter {
+            format!("`{}` is declared as nullable here.", variable_name)
+        } else {
+            format!("`{}` may be assigned `null` here.", variable_name)
+        };
+
+        self.issues.push(
+            Issue::new(self.context.level(), message)
+                .with_annotation(
+                    Annotation::primary(span).with_message(format!("`{}` may be `null` here.", variable_name)),
+                )
+                .with_annotation(Annotation::secondary(origin.span).with_message(origin_message))
+                .with_help(format!(
+                    "Check that `{}` is not `null` before using it, e.g. using `!== null`, or use the nullsafe operator `?->`.",
+                    variable_name
+                )),
+        );
+    }
+
+    /// Returns where the value of the given expression may have become `null`, if it may be `null`.
+    fn get_null_origin(&self, expression: &Expression, state: &State) -> Option<Origin> {
+        match expression {
+            Expression::Literal(Literal::Null(_))
+            | Expression::Access(Access::NullSafeProperty(_))
+            | Expression::Call(Call::NullSafeMethod(_)) => {
+                Some(Origin { span: expression.span(), is_parameter: false })
+            }
+            Expression::Variable(Variable::Direct(variable)) => state.get(self.context.lookup(&variable.name)).copied(),
+            Expression::Parenthesized(parenthesized) => self.get_null_origin(&parenthesized.expression, state),
+            Expression::Assignment(assignment) if matches!(assignment.operator, AssignmentOperator::Assign(_)) => {
+                self.get_null_origin(&assignment.rhs, state)
+            }
+            Expression::Binary(Binary { operator: BinaryOperator::NullCoalesce(_), rhs, .. }) => {
+                self.get_null_origin(rhs, state)
+            }
+            Expression::Conditional(conditional) => conditional
+                .then
+                .as_ref()
+                .and_then(|then| self.get_null_origin(then, &self.narrow(state, &conditional.condition, true)))
+                .or_else(|| {
+                    self.get_null_origin(&conditional.r#else, &self.narrow(state, &conditional.condition, false))
+                }),
+            Expression::Call(call) => {
+                let return_type = self.context.resolve_callee(call)?.return_type_reflection.as_ref()?;
+                if return_type.type_reflection.inferred || !contains_null(&return_type.type_reflection.kind) {
+                    return None;
+                }
+
+                Some(Origin { span: expression.span(), is_parameter: false })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a copy of the given state, without the variables known not to be `null` when the given
+    /// condition evaluates to the given value.
+    fn narrow(&self, state: &State, condition: &Expression, value: bool) -> State {
+        let mut narrowed = state.clone();
+        for variable in self.get_non_null_variables(condition, value) {
+            narrowed.remove(self.context.lookup(&variable.name));
+        }
+
+        narrowed
+    }
+
+    /// Returns the state when the given loop condition is not met, or `None` if it is always met.
+    fn narrow_if_completes(&self, state: &State, condition: &Expression) -> Option<State> {
+        if is_truthy(condition) {
+            return None;
+        }
+
+        Some(self.narrow(state, condition, false))
+    }
+
+    /// Returns the variables known not to be `null` when the given condition evaluates to the given value.
+    fn get_non_null_variables<'e>(&self, condition: &'e Expression, value: bool) -> Vec<&'e DirectVariable> {
+        match condition {
+            Expression::Parenthesized(parenthesized) => self.get_non_null_variables(&parenthesized.expression, value),
+            Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Not(_), operand }) => {
+                self.get_non_null_variables(operand, !value)
+            }
+            Expression::Binary(binary) => match binary.operator {
+                BinaryOperator::And(_) | BinaryOperator::LowAnd(_) => {
+                    let lhs = self.get_non_null_variables(&binary.lhs, value);
+                    let rhs = self.get_non_null_variables(&binary.rhs, value);
+
+                    if value { union(lhs, rhs) } else { self.intersection(lhs, rhs) }
+                }
+                BinaryOperator::Or(_) | BinaryOperator::LowOr(_) => {
+                    let lhs = self.get_non_null_variables(&binary.lhs, value);
+                    let rhs = self.get_non_null_variables(&binary.rhs, value);
+
+                    if value { self.intersection(lhs, rhs) } else { union(lhs, rhs) }
+                }
+                BinaryOperator::Instanceof(_) if value => get_checked_variable(&binary.lhs).into_iter().collect(),
+                BinaryOperator::NotIdentical(_) | BinaryOperator::NotEqual(_) | BinaryOperator::AngledNotEqual(_)
+                    if value =>
+                {
+                    get_compared_to_null(&binary.lhs, &binary.rhs).and_then(get_checked_variable).into_iter().collect()
+                }
+                BinaryOperator::Identical(_) | BinaryOperator::Equal(_) if !value => {
+                    get_compared_to_null(&binary.lhs, &binary.rhs).and_then(get_checked_variable).into_iter().collect()
+                }
+                BinaryOperator::Identical(_) if value => get_compared_to_non_null_literal(&binary.lhs, &binary.rhs)
+                    .and_then(get_checked_variable)
+                    .into_iter()
+                    .collect(),
+                _ => vec![],
+            },
+            Expression::Construct(Construct::Isset(isset)) if value => {
+                isset.values.iter().filter_map(get_base_variable).collect()
+            }
+            Expression::Construct(Construct::Empty(empty)) if !value => {
+                get_base_variable(&empty.value).into_iter().collect()
+            }
+            Expression::Call(Call::Function(FunctionCall { function, argument_list })) => {
+                let (Expression::Identifier(identifier), Some(argument)) =
+                    (function.as_ref(), argument_list.arguments.first())
+                else {
+                    return vec![];
+                };
+
+                let function_name = self.context.resolve_function_name(identifier);
+                let is_non_null = if function_name.eq_ignore_ascii_case("is_null") {
+                    !value
+                } else if TYPE_CHECK_FUNCTIONS.iter().any(|name| name.eq_ignore_ascii_case(function_name)) {
+                    value
+                } else {
+                    false
+                };
+
+                if is_non_null { get_checked_variable(argument.value()).into_iter().collect() } else { vec![] }
+            }
+            expression if value => get_checked_variable(expression).into_iter().collect(),
+            _ => vec![],
+        }
+    }
+
+    fn intersection<'e>(
+        &self,
+        left: Vec<&'e DirectVariable>,
+        right: Vec<&'e DirectVariable>,
+    ) -> Vec<&'e DirectVariable> {
+        left.into_iter()
+            .filter(|left| {
+                right.iter().any(|right| self.context.lookup(&left.name) == self.context.lookup(&right.name))
+            })
+            .collect()
+    }
+}
+
+/// Returns the variable whose value is checked by the given expression, e.g. `$foo`, or `($foo = bar())`.
+fn get_checked_variable(expression: &Expression) -> Option<&DirectVariable> {
+    match expression {
+        Expression::Variable(Variable::Direct(variable)) => Some(variable),
+        Expression::Parenthesized(parenthesized) => get_checked_variable(&parenthesized.expression),
+        Expression::Assignment(Assignment { lhs, operator: AssignmentOperator::Assign(_), .. }) => {
+            get_checked_variable(lhs)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the variable at the base of the given property, or offset, access chain, e.g. `$foo` for `$foo->bar['baz']`.
+fn get_base_variable(expression: &Expression) -> Option<&DirectVariable> {
+    match expression {
+        Expression::Variable(Variable::Direct(variable)) => Some(variable),
+        Expression::Parenthesized(parenthesized) => get_base_variable(&parenthesized.expression),
+        Expression::Access(Access::Property(PropertyAccess { object, .. }))
+        | Expression::Access(Access::NullSafeProperty(NullSafePropertyAccess { object, .. })) => {
+            get_base_variable(object)
+        }
+        Expression::ArrayAccess(access) => get_base_variable(&access.array),
+        _ => None,
+    }
+}
+
+/// Returns the operand compared to `null`, if any.
+fn get_compared_to_null<'e>(lhs: &'e Expression, rhs: &'e Expression) -> Option<&'e Expression> {
+    match (lhs, rhs) {
+        (operand, Expression::Literal(Literal::Null(_))) | (Expression::Literal(Literal::Null(_)), operand) => {
+            Some(operand)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the operand compared to a literal other than `null`, if any.
+fn get_compared_to_non_null_literal<'e>(lhs: &'e Expression, rhs: &'e Expression) -> Option<&'e Expression> {
+    match (lhs, rhs) {
+        (_, Expression::Literal(Literal::Null(_))) | (Expression::Literal(Literal::Null(_)), _) => None,
+        (operand, Expression::Literal(_)) | (Expression::Literal(_), operand) => Some(operand),
+        _ => None,
+    }
+}
+
+fn union<'e>(mut left: Vec<&'e DirectVariable>, right: Vec<&'e DirectVariable>) -> Vec<&'e DirectVariable> {
+    left.extend(right);
+    left
+}
+
+/// Merges the states of two paths, keeping the variables that may be `null` on either path.
+fn join(left: Option<State>, right: Option<State>) -> Option<State> {
+    match (left, right) {
+        (Some(mut left), Some(right)) => {
+            extend(&mut left, right);
+
+            Some(left)
+        }
+        (left, right) => left.or(right),
+    }
+}
+
+fn extend(state: &mut State, other: State) {
+    for (name, origin) in other {
+        state.entry(name).or_insert(origin);
+    }
+}
+
+fn is_nullable_hint(hint: &Hint) -> bool {
+    match hint {
+        Hint::Nullable(_) | Hint::Null(_) => true,
+        Hint::Parenthesized(parenthesized) => is_nullable_hint(&parenthesized.hint),
+        Hint::Union(union) => is_nullable_hint(&union.left) || is_nullable_hint(&union.right),
+        _ => false,
+    }
+}
+
+/// Returns whether the given parameter is declared with a type that does not accept `null`.
+fn rejects_null(parameter: &FunctionLikeParameterReflection) -> bool {
+    let Some(type_reflection) = &parameter.type_reflection else {
+        return false;
+    };
+
+    let has_null_default = parameter
+        .default
+        .as_ref()
+        .is_some_and(|default| matches!(default.type_reflection.kind, TypeKind::Value(ValueTypeKind::Null)));
+
+    !type_reflection.inferred && !has_null_default && excludes_null(&type_reflection.kind)
+}
+
+fn contains_null(kind: &TypeKind) -> bool {
+    match kind {
+        TypeKind::Union { kinds } => kinds.iter().any(contains_null),
+        TypeKind::Value(ValueTypeKind::Null) => true,
+        TypeKind::GenericParameter { of, .. } => contains_null(of),
+        _ => false,
+    }
+}
+
+fn excludes_null(kind: &TypeKind) -> bool {
+    match kind {
+        TypeKind::Union { kinds } => kinds.iter().all(excludes_null),
+        TypeKind::Intersection { kinds } => kinds.iter().any(excludes_null),
+        TypeKind::Value(ValueTypeKind::Null) => false,
+        TypeKind::Value(_)
+        | TypeKind::Scalar(_)
+        | TypeKind::Object(_)
+        | TypeKind::Array(_)
+        | TypeKind::Callable(_)
+        | TypeKind::Iterable { .. }
+        | TypeKind::Resource
+        | TypeKind::ClosedResource => true,
+        TypeKind::GenericParameter { of, .. } => excludes_null(of),
+        _ => false,
+    }
+}
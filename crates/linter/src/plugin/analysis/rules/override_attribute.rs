@@ -18,6 +18,7 @@ pub struct OverrideAttributeRule;
 impl Rule for OverrideAttributeRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Override Attribute", Level::Error)
+            .fixable()
             .with_minimum_supported_php_version(PHPVersion::PHP83)
             .with_description(indoc! {"
                 Ensures proper usage of the #[Override] attribute in PHP code.
@@ -250,7 +251,7 @@ impl Rule for OverrideAttributeRule {
             let classname = reflection.name.get_key(context.interner);
             let parent_class = parent_class_name.get_key(context.interner);
 
-            let issue = Issue::new(
+            let mut issue = Issue::new(
                 context.level(),
                 format!("Missing `#[Override]` attribute on overriding method `{}::{}`.", classname, name),
             )
@@ -261,8 +262,20 @@ impl Rule for OverrideAttributeRule {
             .with_note("The `#[Override]` attribute clarifies intent and prevents accidental signature mismatches.")
             .with_help("Add `#[Override]` attribute to method declaration.");
 
+            if let Some(parent_method) = context
+                .codebase
+                .get_class_like(parent_class_name)
+                .and_then(|parent| parent.methods.members.get(&lowercase_name))
+                .filter(|parent_method| parent_method.name.span().start.source.category().is_user_defined())
+            {
+                issue = issue.with_annotation(
+                    Annotation::secondary(parent_method.name.span())
+                        .with_message(format!("`{}::{}` is declared here.", parent_class, name)),
+                );
+            }
+
             context.propose(issue, |plan| {
-                let code = context.interner.lookup(&context.module.source.content);
+                let code = &context.module.source.content;
 
                 let offset = method.span().start.offset;
                 let line_start_offset = context
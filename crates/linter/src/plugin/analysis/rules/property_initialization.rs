@@ -0,0 +1,473 @@
+use ahash::HashMap;
+use ahash::HashSet;
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+const CONSTRUCTOR: &str = "__construct";
+const INITIALIZER_METHODS: &str = "initializer_methods";
+const INITIALIZER_METHODS_DEFAULT: [&str; 1] = ["setUp"];
+
+#[derive(Clone, Debug)]
+pub struct PropertyInitializationRule;
+
+/// The properties definitely initialized when the execution of a sequence of statements completes.
+#[derive(Debug, Default)]
+struct Flow {
+    /// The properties initialized when the execution continues with the statement that follows,
+    /// or `None` if it never does.
+    normal: Option<HashSet<String>>,
+    /// The properties initialized on every `return` statement, or `None` if there is no reachable `return` statement.
+    returned: Option<HashSet<String>>,
+}
+
+impl Rule for PropertyInitializationRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Property Initialization", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects typed properties without a default value, that are not initialized by the
+                constructor on every path.
+
+                Unlike untyped properties, which default to `null`, typed properties are uninitialized until
+                assigned, and reading them throws an `Error`, e.g. `Typed property Foo::$bar must not be
+                accessed before initialization`.
+
+                Methods of the class called on `$this` from within the constructor are followed, as well as
+                the methods listed in the `initializer_methods` option, e.g. `setUp` for test cases.
+                Abstract classes without a constructor are not reported.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: INITIALIZER_METHODS,
+                r#type: "array<string>",
+                description: "Names of the methods, other than the constructor, considered as initializing the object.",
+                default: Value::Array(
+                    INITIALIZER_METHODS_DEFAULT.iter().map(|method| Value::String(method.to_string())).collect(),
+                ),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Initializing a property on every path",
+                indoc! {r#"
+                    <?php
+
+                    final class Connection
+                    {
+                        private string $dsn;
+
+                        public function __construct(?string $dsn = null)
+                        {
+                            if ($dsn === null) {
+                                $this->dsn = 'sqlite::memory:';
+                            } else {
+                                $this->dsn = $dsn;
+                            }
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Initializing a property on some paths only",
+                indoc! {r#"
+                    <?php
+
+                    final class Connection
+                    {
+                        private string $dsn;
+
+                        public function __construct(?string $dsn = null)
+                        {
+                            if ($dsn !== null) {
+                                $this->dsn = $dsn;
+                            }
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Not initializing a property without a constructor",
+                indoc! {r#"
+                    <?php
+
+                    final class Connection
+                    {
+                        private string $dsn;
+
+                        public function getDsn(): string
+                        {
+                            return $this->dsn;
+                        }
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Class(class) = node else { return LintDirective::default() };
+
+        let mut properties = vec![];
+        let mut methods = HashMap::default();
+        for member in class.members.iter() {
+            match member {
+                ClassLikeMember::Property(Property::Plain(property)) => {
+                    if property.hint.is_none() || property.modifiers.contains_static() {
+                        continue;
+                    }
+
+                    for item in property.items.iter() {
+                        if let PropertyItem::Abstract(item) = item {
+                            properties.push(&item.variable);
+                        }
+                    }
+                }
+                ClassLikeMember::Method(method) => {
+                    if let MethodBody::Concrete(body) = &method.body {
+                        methods.insert(context.lookup(&method.name.value).to_ascii_lowercase(), body);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if properties.is_empty() {
+            return LintDirective::default();
+        }
+
+        let constructor = class
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                ClassLikeMember::Method(method) => Some(method),
+                _ => None,
+            })
+            .find(|method| context.lookup(&method.name.value).eq_ignore_ascii_case(CONSTRUCTOR));
+
+        if constructor.is_none() && class.modifiers.contains_abstract() {
+            return LintDirective::default();
+        }
+
+        let mut initializers = vec![CONSTRUCTOR.to_string()];
+        match context.option(INITIALIZER_METHODS).and_then(|option| option.as_array()) {
+            Some(methods) => initializers.extend(methods.iter().filter_map(|method| method.as_str()).map(String::from)),
+            None => initializers.extend(INITIALIZER_METHODS_DEFAULT.iter().map(|method| method.to_string())),
+        }
+
+        let mut analyzer = Analyzer { context, methods, visiting: vec![] };
+        let mut initialized = HashSet::default();
+        for initializer in initializers {
+            match analyzer.analyze_method(&initializer.to_ascii_lowercase()) {
+                Some(properties) => initialized.extend(properties),
+                // The initializer never completes, so the object is never used.
+                None if initializer == CONSTRUCTOR => return LintDirective::default(),
+                None => {}
+            }
+        }
+
+        let uninitialized = properties
+            .into_iter()
+            .filter(|variable| !initialized.contains(&context.lookup(&variable.name)[1..]))
+            .collect::<Vec<_>>();
+
+        let class_name = context.lookup(&class.name.value).to_string();
+        for variable in uninitialized {
+            let property_name = context.lookup(&variable.name);
+
+            let issue = Issue::new(
+                context.level(),
+                format!("Typed property `{}::{}` may be accessed before initialization.", class_name, property_name),
+            )
+            .with_annotation(
+                Annotation::primary(variable.span())
+                    .with_message(format!("`{}` is declared here, without a default value.", property_name)),
+            );
+
+            let issue = match constructor {
+                Some(constructor) => {
+                    issue.with_annotation(Annotation::secondary(constructor.name.span()).with_message(format!(
+                        "The constructor does not initialize `{}` on every path.",
+                        property_name
+                    )))
+                }
+                None => issue.with_annotation(
+                    Annotation::secondary(class.name.span())
+                        .with_message(format!("`{}` does not declare a constructor.", class_name)),
+                ),
+            };
+
+            context.report(
+                issue
+                    .with_note("Reading an uninitialized typed property throws an `Error`.")
+                    .with_help("Initialize the property in the constructor on every path, or give it a default value."),
+            );
+        }
+
+        LintDirective::default()
+    }
+}
+
+struct Analyzer<'a, 'c, 'i> {
+    context: &'c LintContext<'i>,
+    methods: HashMap<String, &'a Block>,
+    visiting: Vec<String>,
+}
+
+impl<'a> Analyzer<'a, '_, '_> {
+    /// Returns the properties definitely initialized by the given method of the class, if any.
+    ///
+    /// An empty set is returned for unknown methods, and methods already being analyzed, while
+    /// `None` is returned if the method never completes, e.g. because it always throws.
+    fn analyze_method(&mut self, name: &str) -> Option<HashSet<String>> {
+        let Some(body) = self.methods.get(name).copied() else {
+            return Some(HashSet::default());
+        };
+
+        if self.visiting.iter().any(|visiting| visiting == name) {
+            return Some(HashSet::default());
+        }
+
+        self.visiting.push(name.to_string());
+        let flow = self.analyze_statements(body.statements.as_slice(), HashSet::default());
+        self.visiting.pop();
+
+        meet(flow.normal, flow.returned)
+    }
+
+    fn analyze_statements(&mut self, statements: &'a [Statement], initialized: HashSet<String>) -> Flow {
+        let mut flow = Flow { normal: Some(initialized), returned: None };
+        for statement in statements {
+            let Some(initialized) = flow.normal.take() else {
+                break;
+            };
+
+            let statement_flow = self.analyze_statement(statement, initialized);
+            flow.normal = statement_flow.normal;
+            flow.returned = meet(flow.returned, statement_flow.returned);
+        }
+
+        flow
+    }
+
+    fn analyze_statement(&mut self, statement: &'a Statement, mut initialized: HashSet<String>) -> Flow {
+        match statement {
+            Statement::Block(block) => self.analyze_statements(block.statements.as_slice(), initialized),
+            Statement::Return(r#return) => {
+                if let Some(value) = &r#return.value {
+                    self.collect(Node::Expression(value), &mut initialized);
+                }
+
+                Flow { normal: None, returned: Some(initialized) }
+            }
+            Statement::Break(_) | Statement::Continue(_) => Flow::default(),
+            Statement::Expression(expression_statement) => match expression_statement.expression.as_ref() {
+                Expression::Throw(_) | Expression::Construct(Construct::Exit(_) | Construct::Die(_)) => Flow::default(),
+                expression => {
+                    self.collect(Node::Expression(expression), &mut initialized);
+
+                    Flow { normal: Some(initialized), returned: None }
+                }
+            },
+            Statement::If(r#if) => {
+                self.collect(Node::Expression(&r#if.condition), &mut initialized);
+
+                let mut branches: Vec<&'a [Statement]> = vec![];
+                let has_else = match &r#if.body {
+                    IfBody::Statement(body) => {
+                        branches.push(std::slice::from_ref(body.statement.as_ref()));
+                        branches.extend(
+                            body.else_if_clauses.iter().map(|clause| std::slice::from_ref(clause.statement.as_ref())),
+                        );
+                        branches.extend(
+                            body.else_clause.iter().map(|clause| std::slice::from_ref(clause.statement.as_ref())),
+                        );
+
+                        body.else_clause.is_some()
+                    }
+                    IfBody::ColonDelimited(body) => {
+                        branches.push(body.statements.as_slice());
+                        branches.extend(body.else_if_clauses.iter().map(|clause| clause.statements.as_slice()));
+                        branches.extend(body.else_clause.iter().map(|clause| clause.statements.as_slice()));
+
+                        body.else_clause.is_some()
+                    }
+                };
+
+                let mut flow = Flow { normal: if has_else { None } else { Some(initialized.clone()) }, returned: None };
+                for statements in branches {
+                    let branch_flow = self.analyze_statements(statements, initialized.clone());
+                    flow.normal = meet(flow.normal, branch_flow.normal);
+                    flow.returned = meet(flow.returned, branch_flow.returned);
+                }
+
+                flow
+            }
+            Statement::Try(r#try) => {
+                let try_flow = self.analyze_statements(r#try.block.statements.as_slice(), initialized.clone());
+                let mut flow = Flow { normal: try_flow.normal, returned: try_flow.returned };
+
+                // An exception may be thrown before any statement of the `try` block is executed.
+                for catch_clause in r#try.catch_clauses.iter() {
+                    let catch_flow =
+                        self.analyze_statements(catch_clause.block.statements.as_slice(), initialized.clone());
+                    flow.normal = meet(flow.normal, catch_flow.normal);
+                    flow.returned = meet(flow.returned, catch_flow.returned);
+                }
+
+                if let Some(finally_clause) = &r#try.finally_clause {
+                    let finally_flow = self.analyze_statements(finally_clause.block.statements.as_slice(), initialized);
+                    flow.normal = match (flow.normal, finally_flow.normal) {
+                        (Some(mut normal), Some(finally_normal)) => {
+                            normal.extend(finally_normal);
+
+                            Some(normal)
+                        }
+                        _ => None,
+                    };
+
+                    flow.returned = meet(flow.returned, finally_flow.returned);
+                }
+
+                flow
+            }
+            Statement::While(r#while) => {
+                self.collect(Node::Expression(&r#while.condition), &mut initialized);
+
+                let statements = match &r#while.body {
+                    WhileBody::Statement(statement) => std::slice::from_ref(statement.as_ref()),
+                    WhileBody::ColonDelimited(body) => body.statements.as_slice(),
+                };
+
+                self.analyze_loop(statements, initialized)
+            }
+            Statement::DoWhile(do_while) => {
+                self.analyze_loop(std::slice::from_ref(do_while.statement.as_ref()), initialized)
+            }
+            Statement::For(r#for) => {
+                for initialization in r#for.initializations.iter() {
+                    self.collect(Node::Expression(initialization), &mut initialized);
+                }
+
+                let statements = match &r#for.body {
+                    ForBody::Statement(statement) => std::slice::from_ref(statement.as_ref()),
+                    ForBody::ColonDelimited(body) => body.statements.as_slice(),
+                };
+
+                self.analyze_loop(statements, initialized)
+            }
+            Statement::Foreach(foreach) => {
+                self.collect(Node::Expression(&foreach.expression), &mut initialized);
+
+                let statements = match &foreach.body {
+                    ForeachBody::Statement(statement) => std::slice::from_ref(statement.as_ref()),
+                    ForeachBody::ColonDelimited(body) => body.statements.as_slice(),
+                };
+
+                self.analyze_loop(statements, initialized)
+            }
+            Statement::Switch(switch) => {
+                self.collect(Node::Expression(&switch.expression), &mut initialized);
+
+                let mut returned = None;
+                for case in switch.body.cases() {
+                    returned = meet(returned, self.analyze_statements(case.statements(), initialized.clone()).returned);
+                }
+
+                Flow { normal: Some(initialized), returned }
+            }
+            statement => {
+                self.collect(Node::Statement(statement), &mut initialized);
+
+                Flow { normal: Some(initialized), returned: None }
+            }
+        }
+    }
+
+    /// Analyzes the body of a loop, which may not be executed, or may be left at any point using `break`.
+    fn analyze_loop(&mut self, statements: &'a [Statement], initialized: HashSet<String>) -> Flow {
+        let body_flow = self.analyze_statements(statements, initialized.clone());
+
+        Flow { normal: Some(initialized), returned: body_flow.returned }
+    }
+
+    /// Collects the properties of `$this` assigned within the given node, including the properties
+    /// initialized by the methods of the class called on `$this`.
+    ///
+    /// Nested functions, and classes, are not visited, as they may not be executed, and `$this`
+    /// may not refer to the same object within them.
+    fn collect(&mut self, node: Node<'a>, initialized: &mut HashSet<String>) {
+        match node {
+            Node::Closure(_)
+            | Node::ArrowFunction(_)
+            | Node::Function(_)
+            | Node::Class(_)
+            | Node::AnonymousClass(_)
+            | Node::Interface(_)
+            | Node::Trait(_)
+            | Node::Enum(_) => return,
+            Node::Assignment(assignment) => {
+                self.collect_assignment_target(&assignment.lhs, initialized);
+            }
+            Node::MethodCall(MethodCall { object, method: ClassLikeMemberSelector::Identifier(method), .. })
+                if self.is_this(object) =>
+            {
+                let name = self.context.lookup(&method.value).to_ascii_lowercase();
+                if let Some(properties) = self.analyze_method(&name) {
+                    initialized.extend(properties);
+                }
+            }
+            _ => {}
+        }
+
+        for child in node.children() {
+            self.collect(child, initialized);
+        }
+    }
+
+    fn collect_assignment_target(&self, expression: &Expression, initialized: &mut HashSet<String>) {
+        match expression {
+            Expression::Access(Access::Property(access)) if self.is_this(&access.object) => {
+                if let ClassLikeMemberSelector::Identifier(identifier) = &access.property {
+                    initialized.insert(self.context.lookup(&identifier.value).to_string());
+                }
+            }
+            Expression::Parenthesized(parenthesized) => {
+                self.collect_assignment_target(&parenthesized.expression, initialized);
+            }
+            Expression::Array(Array { elements, .. })
+            | Expression::LegacyArray(LegacyArray { elements, .. })
+            | Expression::List(List { elements, .. }) => {
+                for element in elements.iter() {
+                    match element {
+                        ArrayElement::KeyValue(element) => self.collect_assignment_target(&element.value, initialized),
+                        ArrayElement::Value(element) => self.collect_assignment_target(&element.value, initialized),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn is_this(&self, expression: &Expression) -> bool {
+        matches!(
+            expression,
+            Expression::Variable(Variable::Direct(variable)) if self.context.lookup(&variable.name) == "$this"
+        )
+    }
+}
+
+/// Merges the properties initialized on two paths, keeping only the ones initialized on both.
+fn meet(left: Option<HashSet<String>>, right: Option<HashSet<String>>) -> Option<HashSet<String>> {
+    match (left, right) {
+        (Some(left), Some(right)) => Some(left.intersection(&right).cloned().collect()),
+        (left, right) => left.or(right),
+    }
+}
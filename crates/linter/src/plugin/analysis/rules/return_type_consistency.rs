@@ -0,0 +1,410 @@
+use std::slice;
+
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_ast_utils::block_has_yield;
+use mago_ast_utils::condition::is_truthy;
+use mago_ast_utils::find_returns_in_block;
+use mago_docblock::document::Element;
+use mago_docblock::tag::split_type;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ReturnTypeConsistencyRule;
+
+/// The return type declared by a function-like, either natively, or using a `@return` docblock tag.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum DeclaredReturn {
+    Void(Span),
+    Never,
+    Value(Span),
+    Undeclared,
+}
+
+/// The ways the execution of a sequence of statements may complete.
+#[derive(Debug, Clone, Copy, Default)]
+struct Completion {
+    /// The execution may continue with the statement that follows.
+    normal: bool,
+    /// The execution may leave the enclosing loop, or `switch` statement, using `break`.
+    breaks: bool,
+    /// The execution may continue with the next iteration of the enclosing loop, using `continue`.
+    continues: bool,
+}
+
+impl Rule for ReturnTypeConsistencyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Return Type Consistency", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Checks the return statements of a function, method, or closure, against its declared return
+                type, either native, or declared using a `@return` docblock tag.
+
+                Functions declared to return a value must return one on every path, i.e. neither use
+                `return;`, nor reach the end of their body. Functions declared to return `void` must not
+                return a value. Functions without a declared return type must not mix returning a value
+                with returning none, as callers cannot rely on the result.
+
+                The paths are derived from the structure of the function body, so calls to functions that
+                never return, e.g. a helper always throwing an exception, are not taken into account.
+                Generators are not checked.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Returning a value on every path",
+                indoc! {r#"
+                    <?php
+
+                    function get_discount(int $total): int
+                    {
+                        if ($total > 100) {
+                            return 10;
+                        }
+
+                        return 0;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Reaching the end of a function declared to return a value",
+                indoc! {r#"
+                    <?php
+
+                    function get_discount(int $total): int
+                    {
+                        if ($total > 100) {
+                            return 10;
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Returning a value from a function declared to return `void`",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @return void
+                     */
+                    function log_message(string $message)
+                    {
+                        return error_log($message);
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Mixing `return;` with returning a value",
+                indoc! {r#"
+                    <?php
+
+                    function find_user(array $users, string $name)
+                    {
+                        foreach ($users as $user) {
+                            if ($user['name'] === $name) {
+                                return $user;
+                            }
+                        }
+
+                        return;
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let (kind, attribute_lists, declaration, return_type_hint, body) = match node {
+            Node::Function(function) => (
+                "function",
+                &function.attribute_lists,
+                function.function.span(),
+                function.return_type_hint.as_ref(),
+                &function.body,
+            ),
+            Node::Method(method) => {
+                let MethodBody::Concrete(body) = &method.body else {
+                    return LintDirective::default();
+                };
+
+                (
+                    "method",
+                    &method.attribute_lists,
+                    method.modifiers.first_span().unwrap_or(method.function.span()),
+                    method.return_type_hint.as_ref(),
+                    body,
+                )
+            }
+            Node::Closure(closure) => (
+                "closure",
+                &closure.attribute_lists,
+                closure.r#static.as_ref().map_or(closure.function.span(), |r#static| r#static.span()),
+                closure.return_type_hint.as_ref(),
+                &closure.body,
+            ),
+            _ => return LintDirective::default(),
+        };
+
+        if block_has_yield(body) {
+            return LintDirective::default();
+        }
+
+        let declared = match return_type_hint {
+            Some(return_type_hint) => match &return_type_hint.hint {
+                Hint::Void(_) => DeclaredReturn::Void(return_type_hint.hint.span()),
+                Hint::Never(_) => DeclaredReturn::Never,
+                hint => DeclaredReturn::Value(hint.span()),
+            },
+            None => get_docblock_return(context, attribute_lists, declaration),
+        };
+
+        let returns = find_returns_in_block(body);
+        let reaches_end = complete_statements(body.statements.as_slice()).normal;
+
+        match declared {
+            DeclaredReturn::Void(void) => {
+                for r#return in returns.iter().filter(|r#return| r#return.value.is_some()) {
+                    context.report(
+                        Issue::new(context.level(), format!("A value is returned from a `void` {}.", kind))
+                            .with_annotation(
+                                Annotation::primary(r#return.span()).with_message("A value is returned here."),
+                            )
+                            .with_annotation(
+                                Annotation::secondary(void)
+                                    .with_message(format!("The {} is declared to return `void`.", kind)),
+                            )
+                            .with_help("Remove the returned value, or change the declared return type."),
+                    );
+                }
+            }
+            DeclaredReturn::Value(r#type) => {
+                for r#return in returns.iter().filter(|r#return| r#return.value.is_none()) {
+                    context.report(
+                        Issue::new(
+                            context.level(),
+                            format!("No value is returned from a {} declared to return a value.", kind),
+                        )
+                        .with_annotation(
+                            Annotation::primary(r#return.span()).with_message("No value is returned here."),
+                        )
+                        .with_annotation(
+                            Annotation::secondary(r#type).with_message("The return type is declared here."),
+                        )
+                        .with_help("Return a value matching the declared return type."),
+                    );
+                }
+
+                if reaches_end {
+                    context.report(
+                        Issue::new(context.level(), format!("The {} does not return a value on every path.", kind))
+                            .with_annotation(
+                                Annotation::primary(body.right_brace)
+                                    .with_message("The end of the body is reached without returning a value."),
+                            )
+                            .with_annotation(
+                                Annotation::secondary(r#type).with_message("The return type is declared here."),
+                            )
+                            .with_help("Return a value matching the declared return type on every path."),
+                    );
+                }
+            }
+            DeclaredReturn::Undeclared => {
+                if !returns.iter().any(|r#return| r#return.value.is_some()) {
+                    return LintDirective::default();
+                }
+
+                let mut annotations = returns
+                    .iter()
+                    .filter(|r#return| r#return.value.is_none())
+                    .map(|r#return| Annotation::secondary(r#return.span()).with_message("No value is returned here."))
+                    .collect::<Vec<_>>();
+
+                if reaches_end {
+                    annotations.push(
+                        Annotation::secondary(body.right_brace)
+                            .with_message("The end of the body is reached without returning a value."),
+                    );
+                }
+
+                if annotations.is_empty() {
+                    return LintDirective::default();
+                }
+
+                context.report(
+                    Issue::new(context.level(), format!("The {} returns a value only on some paths.", kind))
+                        .with_annotation(
+                            Annotation::primary(declaration).with_message(format!("This {} returns a value.", kind)),
+                        )
+                        .with_annotations(annotations)
+                        .with_note(
+                            "Callers cannot rely on the result of a function returning a value only on some paths.",
+                        )
+                        .with_help("Return a value on every path, e.g. `null`, and declare the return type."),
+                );
+            }
+            DeclaredReturn::Never => {}
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn get_docblock_return(
+    context: &LintContext<'_>,
+    attribute_lists: &Sequence<AttributeList>,
+    declaration: Span,
+) -> DeclaredReturn {
+    let Some(document) = context.get_docblock(attribute_lists, declaration) else {
+        return DeclaredReturn::Undeclared;
+    };
+
+    let Some((tag, r#type)) = document.elements.iter().find_map(|element| match element {
+        Element::Tag(tag) if tag.kind.is_return() => {
+            split_type(context.lookup(&tag.description)).map(|(r#type, _)| (tag, r#type))
+        }
+        _ => None,
+    }) else {
+        return DeclaredReturn::Undeclared;
+    };
+
+    match r#type.to_ascii_lowercase().as_str() {
+        "void" => DeclaredReturn::Void(tag.span),
+        "never" | "never-return" | "never-returns" | "no-return" => DeclaredReturn::Never,
+        _ => DeclaredReturn::Value(tag.span),
+    }
+}
+
+fn complete_statements(statements: &[Statement]) -> Completion {
+    let mut completion = Completion { normal: true, ..Default::default() };
+    for statement in statements {
+        if !completion.normal {
+            break;
+        }
+
+        let statement_completion = complete_statement(statement);
+        completion.normal = statement_completion.normal;
+        completion.breaks |= statement_completion.breaks;
+        completion.continues |= statement_completion.continues;
+    }
+
+    completion
+}
+
+fn complete_statement(statement: &Statement) -> Completion {
+    match statement {
+        Statement::Block(block) => complete_statements(block.statements.as_slice()),
+        Statement::Return(_) => Completion::default(),
+        Statement::Break(_) => Completion { breaks: true, ..Default::default() },
+        Statement::Continue(_) => Completion { continues: true, ..Default::default() },
+        Statement::Expression(expression_statement) => match expression_statement.expression.as_ref() {
+            Expression::Throw(_) | Expression::Construct(Construct::Exit(_) | Construct::Die(_)) => {
+                Completion::default()
+            }
+            _ => Completion { normal: true, ..Default::default() },
+        },
+        Statement::If(r#if) => {
+            let (statements, else_if_statements, else_statements) = match &r#if.body {
+                IfBody::Statement(body) => (
+                    slice::from_ref(body.statement.as_ref()),
+                    body.else_if_clauses.iter().map(|clause| slice::from_ref(clause.statement.as_ref())).collect(),
+                    body.else_clause.as_ref().map(|clause| slice::from_ref(clause.statement.as_ref())),
+                ),
+                IfBody::ColonDelimited(body) => (
+                    body.statements.as_slice(),
+                    body.else_if_clauses.iter().map(|clause| clause.statements.as_slice()).collect::<Vec<_>>(),
+                    body.else_clause.as_ref().map(|clause| clause.statements.as_slice()),
+                ),
+            };
+
+            let mut completion = complete_statements(statements);
+            for statements in else_if_statements.into_iter().chain(else_statements) {
+                completion = join(completion, complete_statements(statements));
+            }
+
+            if else_statements.is_none() {
+                completion.normal = true;
+            }
+
+            completion
+        }
+        Statement::While(r#while) => {
+            let statements = match &r#while.body {
+                WhileBody::Statement(statement) => slice::from_ref(statement.as_ref()),
+                WhileBody::ColonDelimited(body) => body.statements.as_slice(),
+            };
+
+            complete_loop(statements, is_truthy(&r#while.condition), false)
+        }
+        Statement::DoWhile(do_while) => {
+            complete_loop(slice::from_ref(do_while.statement.as_ref()), is_truthy(&do_while.condition), true)
+        }
+        Statement::For(r#for) => {
+            let statements = match &r#for.body {
+                ForBody::Statement(statement) => slice::from_ref(statement.as_ref()),
+                ForBody::ColonDelimited(body) => body.statements.as_slice(),
+            };
+
+            complete_loop(statements, r#for.conditions.iter().all(is_truthy), false)
+        }
+        Statement::Switch(switch) => {
+            let mut has_default = false;
+            let mut completion = Completion::default();
+            for case in switch.body.cases() {
+                has_default |= matches!(case, SwitchCase::Default(_));
+
+                // The statements of a case are reached either by matching it, or by falling through the previous one.
+                let case_completion = complete_statements(case.statements());
+                completion = Completion {
+                    normal: case_completion.normal,
+                    breaks: completion.breaks || case_completion.breaks || case_completion.continues,
+                    continues: false,
+                };
+            }
+
+            Completion { normal: !has_default || completion.normal || completion.breaks, ..Default::default() }
+        }
+        Statement::Try(r#try) => {
+            let mut completion = complete_statements(r#try.block.statements.as_slice());
+            for catch_clause in r#try.catch_clauses.iter() {
+                completion = join(completion, complete_statements(catch_clause.block.statements.as_slice()));
+            }
+
+            if let Some(finally_clause) = &r#try.finally_clause {
+                let finally_completion = complete_statements(finally_clause.block.statements.as_slice());
+                if !finally_completion.normal {
+                    return finally_completion;
+                }
+            }
+
+            completion
+        }
+        _ => Completion { normal: true, ..Default::default() },
+    }
+}
+
+fn complete_loop(statements: &[Statement], is_infinite: bool, runs_at_least_once: bool) -> Completion {
+    let body = complete_statements(statements);
+
+    let normal = if is_infinite {
+        body.breaks
+    } else if runs_at_least_once {
+        body.normal || body.breaks || body.continues
+    } else {
+        true
+    };
+
+    Completion { normal, ..Default::default() }
+}
+
+fn join(left: Completion, right: Completion) -> Completion {
+    Completion {
+        normal: left.normal || right.normal,
+        breaks: left.breaks || right.breaks,
+        continues: left.continues || right.continues,
+    }
+}
@@ -0,0 +1,196 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_docblock::document::Element;
+use mago_docblock::document::Tag;
+use mago_docblock::tag::parse_generic_type;
+use mago_reporting::*;
+use mago_span::HasPosition;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct TemplateArgumentCountRule;
+
+impl Rule for TemplateArgumentCountRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Template Argument Count", Level::Error)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Ensures that `@extends`, `@implements`, and `@use` docblock tags specify exactly as many
+                template arguments as the parent class, interface, or trait declares using `@template` tags.
+
+                The vendored variants of these tags used by Psalm and PHPStan, such as `@template-extends`
+                and `@phpstan-implements`, are checked as well.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Extending a generic class with the correct number of template arguments",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @template TKey
+                     * @template TValue
+                     */
+                    abstract class Collection {}
+
+                    /**
+                     * @extends Collection<int, string>
+                     */
+                    final class StringList extends Collection {}
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Extending a generic class with too few template arguments",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @template TKey
+                     * @template TValue
+                     */
+                    abstract class Collection {}
+
+                    /**
+                     * @extends Collection<string>
+                     */
+                    final class StringList extends Collection {}
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Implementing a non-generic interface with template arguments",
+                indoc! {r#"
+                    <?php
+
+                    interface Comparable {}
+
+                    /**
+                     * @implements Comparable<Money>
+                     */
+                    final class Money implements Comparable {}
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let (attribute_lists, declaration, members, extends, implements) = match node {
+            Node::Class(class) => (
+                &class.attribute_lists,
+                class.modifiers.first_span().unwrap_or(class.class.span()),
+                &class.members,
+                class.extends.as_ref(),
+                class.implements.as_ref(),
+            ),
+            Node::Interface(interface) => (
+                &interface.attribute_lists,
+                interface.interface.span(),
+                &interface.members,
+                interface.extends.as_ref(),
+                None,
+            ),
+            Node::Trait(r#trait) => (&r#trait.attribute_lists, r#trait.r#trait.span(), &r#trait.members, None, None),
+            Node::Enum(r#enum) => {
+                (&r#enum.attribute_lists, r#enum.r#enum.span(), &r#enum.members, None, r#enum.implements.as_ref())
+            }
+            _ => return LintDirective::default(),
+        };
+
+        let extended = extends.map(|extends| extends.types.iter().collect::<Vec<_>>()).unwrap_or_default();
+        let implemented = implements.map(|implements| implements.types.iter().collect::<Vec<_>>()).unwrap_or_default();
+
+        if let Some(document) = context.get_docblock(attribute_lists, declaration) {
+            for element in document.elements {
+                let Element::Tag(tag) = element else {
+                    continue;
+                };
+
+                if tag.kind.is_extends() {
+                    check_tag(context, &tag, &extended);
+                } else if tag.kind.is_implements() {
+                    check_tag(context, &tag, &implemented);
+                }
+            }
+        }
+
+        for member in members.iter() {
+            let ClassLikeMember::TraitUse(trait_use) = member else {
+                continue;
+            };
+
+            let Some(document) = context.get_docblock(&Sequence::empty(), trait_use.r#use.span()) else {
+                continue;
+            };
+
+            let used = trait_use.trait_names.iter().collect::<Vec<_>>();
+            for element in document.elements {
+                let Element::Tag(tag) = element else {
+                    continue;
+                };
+
+                if tag.kind.is_use() {
+                    check_tag(context, &tag, &used);
+                }
+            }
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn check_tag(context: &mut LintContext<'_>, tag: &Tag, candidates: &[&Identifier]) {
+    let Some(generic) = parse_generic_type(context.lookup(&tag.description)) else {
+        return;
+    };
+
+    // The name in the docblock is not resolved, so it is matched against the end of the resolved names.
+    let name = generic.name.trim_start_matches('\\').to_ascii_lowercase();
+    let Some(identifier) = candidates.iter().find(|identifier| {
+        let resolved = context.lookup_name(*identifier).to_ascii_lowercase();
+
+        resolved == name || resolved.ends_with(&format!("\\{}", name))
+    }) else {
+        return;
+    };
+
+    let Some(parent) =
+        context.codebase.get_named_class_like(context.interner, context.module.names.get(&identifier.position()))
+    else {
+        return;
+    };
+
+    let expected = parent.templates.len();
+    let given = generic.arguments.len();
+    if expected == given {
+        return;
+    }
+
+    let parent_name = parent.name.get_key(context.interner);
+    let tag_name = context.lookup(&tag.name);
+
+    let message = if expected == 0 {
+        format!(
+            "`{}` does not declare any templates, but `@{}` specifies {} template argument(s).",
+            parent_name, tag_name, given
+        )
+    } else {
+        format!("`{}` expects {} template argument(s), but `@{}` specifies {}.", parent_name, expected, tag_name, given)
+    };
+
+    let issue = Issue::new(context.level(), message)
+        .with_annotation(Annotation::primary(tag.span).with_message("Template arguments are specified here."))
+        .with_annotation(
+            Annotation::secondary(identifier.span()).with_message(format!("`{}` is inherited here.", parent_name)),
+        )
+        .with_help(if expected == 0 {
+            format!("Remove the template arguments, or declare templates on `{}` using `@template` tags.", parent_name)
+        } else {
+            format!("Specify exactly {} template argument(s) for `{}`.", expected, parent_name)
+        });
+
+    context.report(issue);
+}
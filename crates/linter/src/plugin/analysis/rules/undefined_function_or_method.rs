@@ -199,7 +199,7 @@ impl Rule for UndefinedFunctionOrMethodRule {
                     context.codebase.get_method(context.interner, class_like, &method_identifier.value)
                 else {
                     // If there's a __callStatic method, calls might be handled dynamically
-                    if class_like.methods.appering_members.contains_key(&context.interner.intern("__callStatic")) {
+                    if class_like.methods.appering_members.contains_key(&context.interner.intern("__callstatic")) {
                         let allow_dynamic_calls = context
                             .option(ALLOW_DYNAMIC_STATIC_CALLS)
                             .and_then(|o| o.as_bool())
@@ -463,6 +463,6 @@ fn should_allow_abstract_call(class_like: &ClassLikeReflection, method_name: &st
     const UNIT_ENUM_METHODS: [&str; 1] = ["cases"];
 
     class_like.is_enum()
-        && (UNIT_ENUM_METHODS.iter().any(|&m| m == method_name)
-            || (class_like.backing_type.is_some() && BACKED_ENUM_METHODS.iter().any(|&m| m == method_name)))
+        && (UNIT_ENUM_METHODS.contains(&method_name)
+            || (class_like.backing_type.is_some() && BACKED_ENUM_METHODS.contains(&method_name)))
 }
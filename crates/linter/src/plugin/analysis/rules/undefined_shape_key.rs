@@ -0,0 +1,423 @@
+use ahash::HashMap;
+use ahash::HashSet;
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reflection::r#type::TypeReflection;
+use mago_reflection::r#type::kind::ArrayShapePropertyKey;
+use mago_reflection::r#type::kind::ArrayTypeKind;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+use crate::utils::get_string_value;
+
+#[derive(Clone, Debug)]
+pub struct UndefinedShapeKeyRule;
+
+impl Rule for UndefinedShapeKeyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Undefined Shape Key", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects reads of keys that are not defined in the array shape declared for a parameter,
+                e.g. using `@param array{id: int, name: string} $user`, as well as reads of string keys
+                of parameters declared as lists, e.g. using `@param list<string> $names`.
+
+                Shapes declared with `...` allow other keys, and are not checked. Parameters written to
+                in the function body, and keys checked using `isset`, `empty`, `??`, or `array_key_exists`,
+                are not reported.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Reading a key defined in the shape of a parameter",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @param array{id: int, name: string} $user
+                     */
+                    function get_user_name(array $user): string
+                    {
+                        return $user['name'];
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "Checking that an undeclared key exists before reading it",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @param array{id: int, name: string} $user
+                     */
+                    function get_user_email(array $user): ?string
+                    {
+                        return $user['email'] ?? null;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Reading a key not defined in the shape of a parameter",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @param array{id: int, name: string} $user
+                     */
+                    function get_user_email(array $user): string
+                    {
+                        return $user['email'];
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Reading a string key of a list",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @param list<string> $names
+                     */
+                    function get_first_name(array $names): string
+                    {
+                        return $names['first'];
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let body = match node {
+            Node::Function(function) => Node::Block(&function.body),
+            Node::Method(method) => match &method.body {
+                MethodBody::Concrete(body) => Node::Block(body),
+                MethodBody::Abstract(_) => return LintDirective::default(),
+            },
+            Node::Closure(closure) => Node::Block(&closure.body),
+            Node::ArrowFunction(arrow_function) => Node::Expression(&arrow_function.expression),
+            _ => return LintDirective::default(),
+        };
+
+        let issues = {
+            let Some(function_like) = context.scope.get_function_like_reflection(context) else {
+                return LintDirective::default();
+            };
+
+            let mut shapes = HashMap::default();
+            for parameter in function_like.parameters.iter() {
+                let Some(type_reflection) = &parameter.docblock_type_reflection else {
+                    continue;
+                };
+
+                if parameter.is_passed_by_reference || parameter.is_variadic {
+                    continue;
+                }
+
+                if let TypeKind::Array(ArrayTypeKind::Shape(_) | ArrayTypeKind::List { .. }) = &type_reflection.kind {
+                    shapes.insert(context.lookup(&parameter.name), type_reflection);
+                }
+            }
+
+            if shapes.is_empty() {
+                return LintDirective::default();
+            }
+
+            let mut collector = Collector {
+                context,
+                shapes: &shapes,
+                written: HashSet::default(),
+                checked: HashSet::default(),
+                reads: vec![],
+            };
+
+            collector.walk(body);
+            collector.get_issues()
+        };
+
+        for issue in issues {
+            context.report(issue);
+        }
+
+        LintDirective::default()
+    }
+}
+
+/// Collects the reads of literal keys of parameters declared with an array shape, or list type, within
+/// a function body, along with the parameters written to, and the keys checked for existence.
+struct Collector<'c, 'i> {
+    context: &'c LintContext<'i>,
+    shapes: &'c HashMap<&'c str, &'c TypeReflection>,
+    written: HashSet<&'c str>,
+    checked: HashSet<(&'c str, ArrayShapePropertyKey)>,
+    reads: Vec<(&'c str, ArrayShapePropertyKey, Span)>,
+}
+
+impl<'a, 'c, 'i> Collector<'c, 'i> {
+    fn walk(&mut self, node: Node<'a>) {
+        match node {
+            Node::Closure(closure) => {
+                // Variables captured by reference may be written to by the closure.
+                if let Some(use_clause) = &closure.use_clause {
+                    for variable in use_clause.variables.iter() {
+                        if variable.ampersand.is_some() {
+                            self.written.insert(self.context.lookup(&variable.variable.name));
+                        }
+                    }
+                }
+
+                return;
+            }
+            Node::ArrowFunction(_)
+            | Node::AnonymousClass(_)
+            | Node::Function(_)
+            | Node::Class(_)
+            | Node::Interface(_)
+            | Node::Trait(_)
+            | Node::Enum(_) => return,
+            Node::IssetConstruct(isset) => {
+                for value in isset.values.iter() {
+                    self.walk_guarded(value);
+                }
+
+                return;
+            }
+            Node::EmptyConstruct(empty) => {
+                self.walk_guarded(&empty.value);
+
+                return;
+            }
+            Node::Binary(Binary { lhs, operator: BinaryOperator::NullCoalesce(_), rhs }) => {
+                self.walk_guarded(lhs);
+                self.walk(Node::Expression(rhs));
+
+                return;
+            }
+            Node::Assignment(assignment) => self.write(&assignment.lhs),
+            Node::UnaryPrefix(UnaryPrefix {
+                operator:
+                    UnaryPrefixOperator::Reference(_)
+                    | UnaryPrefixOperator::PreIncrement(_)
+                    | UnaryPrefixOperator::PreDecrement(_),
+                operand,
+            }) => self.write(operand),
+            Node::UnaryPostfix(postfix) => self.write(&postfix.operand),
+            Node::Unset(unset) => {
+                for value in unset.values.iter() {
+                    self.write(value);
+                }
+            }
+            Node::ForeachValueTarget(target) => self.write(&target.value),
+            Node::ForeachKeyValueTarget(target) => {
+                self.write(&target.key);
+                self.write(&target.value);
+            }
+            Node::Global(global) => {
+                for variable in global.variables.iter() {
+                    if let Variable::Direct(variable) = variable {
+                        self.written.insert(self.context.lookup(&variable.name));
+                    }
+                }
+            }
+            Node::StaticItem(item) => {
+                self.written.insert(self.context.lookup(&item.variable().name));
+            }
+            Node::ArrayAccess(access) => {
+                if let Some((variable, key)) = self.get_shape_key(access) {
+                    self.reads.push((variable, key, access.index.span()));
+                }
+            }
+            Node::Call(call) => self.check_call(call),
+            _ => {}
+        }
+
+        for child in node.children() {
+            self.walk(child);
+        }
+    }
+
+    /// Walks the given expression, where reading an undefined key is allowed, e.g. within `isset`, or on the
+    /// left-hand side of `??`.
+    fn walk_guarded(&mut self, expression: &'a Expression) {
+        match expression {
+            Expression::Parenthesized(parenthesized) => self.walk_guarded(&parenthesized.expression),
+            Expression::ArrayAccess(access) => {
+                if let Some(checked) = self.get_shape_key(access) {
+                    self.checked.insert(checked);
+                }
+
+                self.walk_guarded(&access.array);
+                self.walk(Node::Expression(&access.index));
+            }
+            Expression::Access(Access::Property(PropertyAccess { object, property, .. }))
+            | Expression::Access(Access::NullSafeProperty(NullSafePropertyAccess { object, property, .. })) => {
+                self.walk_guarded(object);
+                self.walk(Node::ClassLikeMemberSelector(property));
+            }
+            expression => self.walk(Node::Expression(expression)),
+        }
+    }
+
+    /// Marks the variable written to by the given assignment target, if any, as written.
+    fn write(&mut self, target: &'a Expression) {
+        match target {
+            Expression::Variable(Variable::Direct(variable)) => {
+                self.written.insert(self.context.lookup(&variable.name));
+            }
+            Expression::Parenthesized(parenthesized) => self.write(&parenthesized.expression),
+            Expression::ArrayAccess(access) => self.write(&access.array),
+            Expression::ArrayAppend(append) => self.write(&append.array),
+            Expression::Array(Array { elements, .. })
+            | Expression::LegacyArray(LegacyArray { elements, .. })
+            | Expression::List(List { elements, .. }) => {
+                for element in elements.iter() {
+                    match element {
+                        ArrayElement::KeyValue(element) => self.write(&element.value),
+                        ArrayElement::Value(element) => self.write(&element.value),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Marks the variables passed by reference to the called function as written, and records the keys checked
+    /// using `array_key_exists`.
+    fn check_call(&mut self, call: &'a Call) {
+        if let Call::Function(FunctionCall { function, argument_list }) = call {
+            if let Expression::Identifier(identifier) = function.as_ref() {
+                let name = self.context.resolve_function_name(identifier);
+                if name.eq_ignore_ascii_case("array_key_exists") || name.eq_ignore_ascii_case("key_exists") {
+                    let mut arguments = argument_list.arguments.iter();
+                    if let (Some(key), Some(Expression::Variable(Variable::Direct(variable)))) = (
+                        arguments.next().and_then(|key| self.get_key(key.value())),
+                        arguments.next().map(|a| a.value()),
+                    ) {
+                        let variable = self.context.lookup(&variable.name);
+
+                        self.checked.insert((variable, key));
+                    }
+
+                    return;
+                }
+            }
+        }
+
+        let Some(function_like) = self.context.resolve_callee(call) else {
+            return;
+        };
+
+        let argument_list = match call {
+            Call::Function(call) => &call.argument_list,
+            Call::Method(call) => &call.argument_list,
+            Call::NullSafeMethod(call) => &call.argument_list,
+            Call::StaticMethod(call) => &call.argument_list,
+        };
+
+        for (position, argument) in argument_list.arguments.iter().enumerate() {
+            let parameter = self.context.get_argument_parameter(function_like, position, argument);
+            if parameter.is_some_and(|parameter| parameter.is_passed_by_reference) {
+                self.write(argument.value());
+            }
+        }
+    }
+
+    /// Returns the name of the variable, and the literal key, of the given access to an array shape, or list,
+    /// parameter, if any.
+    fn get_shape_key(&self, access: &ArrayAccess) -> Option<(&'c str, ArrayShapePropertyKey)> {
+        let Expression::Variable(Variable::Direct(variable)) = access.array.as_ref() else {
+            return None;
+        };
+
+        let variable = self.context.lookup(&variable.name);
+        if !self.shapes.contains_key(variable) {
+            return None;
+        }
+
+        Some((variable, self.get_key(&access.index)?))
+    }
+
+    /// Returns the array key of the given literal, following the PHP key casting rules for numeric strings.
+    fn get_key(&self, expression: &Expression) -> Option<ArrayShapePropertyKey> {
+        match expression {
+            Expression::Literal(Literal::Integer(integer)) => {
+                Some(ArrayShapePropertyKey::Integer(isize::try_from(integer.value?).ok()?))
+            }
+            Expression::Literal(Literal::String(string)) => {
+                let value = get_string_value(string, self.context.interner)?;
+
+                Some(match value.parse::<isize>() {
+                    Ok(integer) if integer.to_string() == value => ArrayShapePropertyKey::Integer(integer),
+                    _ => ArrayShapePropertyKey::String(self.context.interner.intern(value)),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn get_issues(self) -> Vec<Issue> {
+        let interner = self.context.interner;
+
+        let mut issues = vec![];
+        for (variable, key, span) in self.reads.iter() {
+            if self.written.contains(variable) || self.checked.contains(&(*variable, *key)) {
+                continue;
+            }
+
+            let type_reflection = self.shapes[variable];
+            let key_name = match key {
+                ArrayShapePropertyKey::String(string) => format!("'{}'", interner.lookup(string)),
+                ArrayShapePropertyKey::Integer(integer) => integer.to_string(),
+            };
+
+            let (message, help) = match &type_reflection.kind {
+                TypeKind::Array(ArrayTypeKind::Shape(shape)) => {
+                    if !shape.is_sealed()
+                        || shape.get_keyed_properties(interner).iter().any(|(property_key, _)| property_key == key)
+                    {
+                        continue;
+                    }
+
+                    (
+                        format!("Key `{}` is not defined in the shape of `{}`.", key_name, variable),
+                        format!(
+                            "Add the key to the shape declared for `{}`, or check that it exists using `isset`, or `array_key_exists`.",
+                            variable
+                        ),
+                    )
+                }
+                _ => {
+                    let ArrayShapePropertyKey::String(_) = key else {
+                        continue;
+                    };
+
+                    (
+                        format!("String key `{}` is read from `{}`, which is a list.", key_name, variable),
+                        format!(
+                            "Lists only have sequential integer keys; use an integer offset to read from `{}`.",
+                            variable
+                        ),
+                    )
+                }
+            };
+
+            issues.push(
+                Issue::new(self.context.level(), message)
+                    .with_annotation(Annotation::primary(*span).with_message("This key is not defined."))
+                    .with_annotation(Annotation::secondary(type_reflection.span).with_message(format!(
+                        "`{}` is declared as `{}` here.",
+                        variable,
+                        type_reflection.kind.get_key(interner)
+                    )))
+                    .with_help(help),
+            );
+        }
+
+        issues
+    }
+}
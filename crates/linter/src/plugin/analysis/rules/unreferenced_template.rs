@@ -0,0 +1,132 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_docblock::document::Element;
+use mago_docblock::tag::parse_template_tag;
+use mago_docblock::tag::split_type;
+use mago_docblock::tag::type_references;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnreferencedTemplateRule;
+
+impl Rule for UnreferencedTemplateRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unreferenced Template", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects templates declared on functions and methods that are not referenced by any `@param` tag.
+
+                The type of a function template is inferred from the arguments passed to the function.
+                A template that is not referenced by any parameter can never be inferred, and is therefore
+                substituted with its constraint, or `mixed`.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A template referenced by a parameter",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @template T
+                     *
+                     * @param list<T> $items
+                     *
+                     * @return T|null
+                     */
+                    function first(array $items): mixed
+                    {
+                        return $items[0] ?? null;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A template only referenced by the return type",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @template T
+                     *
+                     * @return T
+                     */
+                    function make(string $class): object
+                    {
+                        return new $class();
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let (kind, attribute_lists, declaration) = match node {
+            Node::Function(function) => ("function", &function.attribute_lists, function.function.span()),
+            Node::Method(method) => {
+                ("method", &method.attribute_lists, method.modifiers.first_span().unwrap_or(method.function.span()))
+            }
+            Node::Closure(closure) => (
+                "closure",
+                &closure.attribute_lists,
+                closure.r#static.as_ref().map_or(closure.function.span(), |r#static| r#static.span()),
+            ),
+            Node::ArrowFunction(arrow_function) => (
+                "arrow function",
+                &arrow_function.attribute_lists,
+                arrow_function.r#static.as_ref().map_or(arrow_function.r#fn.span(), |r#static| r#static.span()),
+            ),
+            _ => return LintDirective::default(),
+        };
+
+        let Some(document) = context.get_docblock(attribute_lists, declaration) else {
+            return LintDirective::default();
+        };
+
+        let interner = context.interner;
+        let tags = document
+            .elements
+            .iter()
+            .filter_map(|element| match element {
+                Element::Tag(tag) => Some(tag),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let parameter_types = tags
+            .iter()
+            .filter(|tag| tag.kind.is_param())
+            .filter_map(|tag| split_type(interner.lookup(&tag.description)).map(|(r#type, _)| r#type))
+            .collect::<Vec<_>>();
+
+        for tag in tags.iter() {
+            let Some(template) = parse_template_tag(tag.kind, interner.lookup(&tag.description)) else {
+                continue;
+            };
+
+            if parameter_types.iter().any(|r#type| type_references(r#type, template.name)) {
+                continue;
+            }
+
+            let issue = Issue::new(
+                context.level(),
+                format!("Template `{}` is not referenced by any parameter of this {}.", template.name, kind),
+            )
+            .with_annotation(Annotation::primary(tag.span).with_message("Template is declared here."))
+            .with_annotation(Annotation::secondary(declaration).with_message(format!("The {} is declared here.", kind)))
+            .with_note("A template that is not referenced by any parameter cannot be inferred from the arguments.")
+            .with_help(format!(
+                "Reference `{}` in the type of a `@param` tag, or replace it with a concrete type.",
+                template.name
+            ));
+
+            context.report(issue);
+        }
+
+        LintDirective::default()
+    }
+}
@@ -1,8 +1,11 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::best_practices::rules::combine_consecutive_issets::CombineConsecutiveIssetsRule;
 use crate::plugin::best_practices::rules::disallowed_functions::DisallowedFunctionsRule;
 use crate::plugin::best_practices::rules::dont_catch_error::DontCatchErrorRule;
+use crate::plugin::best_practices::rules::enum_like_class::EnumLikeClassRule;
 use crate::plugin::best_practices::rules::excessive_nesting::ExcessiveNesting;
+use crate::plugin::best_practices::rules::json_error_handling::JsonErrorHandlingRule;
 use crate::plugin::best_practices::rules::loop_does_not_iterate::LoopDoesNotIterateRule;
 use crate::plugin::best_practices::rules::no_boolean_flag_parameter::NoBooleanFlagParameterRule;
 use crate::plugin::best_practices::rules::no_boolean_literal_comparison::NoBooleanLiteralComparisonRule;
@@ -14,6 +17,14 @@ use crate::plugin::best_practices::rules::no_goto::NoGotoRule;
 use crate::plugin::best_practices::rules::no_hash_emoji::NoHashEmojiRule;
 use crate::plugin::best_practices::rules::no_multi_assignments::NoMultiAssignmentsRule;
 use crate::plugin::best_practices::rules::no_unused_parameter::NoUnusedParameterRule;
+use crate::plugin::best_practices::rules::prefer_final_class::PreferFinalClassRule;
+use crate::plugin::best_practices::rules::prefer_readonly_promoted_property::PreferReadonlyPromotedPropertyRule;
+use crate::plugin::best_practices::rules::readonly_property_write::ReadonlyPropertyWriteRule;
+use crate::plugin::best_practices::rules::require_exception_message::RequireExceptionMessageRule;
+use crate::plugin::best_practices::rules::require_previous_exception::RequirePreviousExceptionRule;
+use crate::plugin::best_practices::rules::require_throwable::RequireThrowableRule;
+use crate::plugin::best_practices::rules::unclosed_resource::UnclosedResourceRule;
+use crate::plugin::best_practices::rules::unreleased_lock::UnreleasedLockRule;
 use crate::plugin::best_practices::rules::use_while_instead_of_for::UseWhileInsteadOfForRule;
 
 use crate::plugin::Plugin;
@@ -30,6 +41,7 @@ impl Plugin for BestPracticesPlugin {
             name: "Best Practices",
             description: "Provides rules that enforce best practices and idiomatic PHP.",
             enabled_by_default: true,
+            category: RuleCategory::Correctness,
         }
     }
 
@@ -39,7 +51,9 @@ impl Plugin for BestPracticesPlugin {
             Box::new(DisallowedFunctionsRule),
             Box::new(DontCatchErrorRule),
             Box::new(NoUnusedParameterRule),
+            Box::new(EnumLikeClassRule),
             Box::new(ExcessiveNesting),
+            Box::new(JsonErrorHandlingRule),
             Box::new(LoopDoesNotIterateRule),
             Box::new(NoBooleanFlagParameterRule),
             Box::new(NoBooleanLiteralComparisonRule),
@@ -50,7 +64,15 @@ impl Plugin for BestPracticesPlugin {
             Box::new(NoEmptyCatchClauseRule),
             Box::new(NoMultiAssignmentsRule),
             Box::new(NoEmptyLoopRule),
+            Box::new(ReadonlyPropertyWriteRule),
+            Box::new(RequireExceptionMessageRule),
+            Box::new(RequirePreviousExceptionRule),
+            Box::new(RequireThrowableRule),
+            Box::new(UnclosedResourceRule),
+            Box::new(UnreleasedLockRule),
             Box::new(UseWhileInsteadOfForRule),
+            Box::new(PreferFinalClassRule),
+            Box::new(PreferReadonlyPromotedPropertyRule),
         ]
     }
 }
@@ -6,6 +6,7 @@ use mago_reporting::*;
 use mago_span::HasSpan;
 
 use crate::context::LintContext;
+use crate::definition::RuleCategory;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
@@ -17,6 +18,8 @@ pub struct CombineConsecutiveIssetsRule;
 impl Rule for CombineConsecutiveIssetsRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Combine Consecutive Issets", Level::Warning)
+            .fixable()
+            .with_category(RuleCategory::Performance)
             .with_description(indoc! {"
                 Suggests combining consecutive calls to `isset()` when they are joined by a logical AND.
                 For example, `isset($a) && isset($b)` can be turned into `isset($a, $b)`, which is more concise
@@ -1,9 +1,11 @@
 use indoc::indoc;
 use toml::Value;
+use toml::value::Table;
 
 use mago_ast::*;
 use mago_reporting::*;
 use mago_span::HasSpan;
+use mago_span::Span;
 
 use crate::consts::EXTENSION_FUNCTIONS;
 use crate::context::LintContext;
@@ -14,24 +16,56 @@ use crate::directive::LintDirective;
 use crate::rule::Rule;
 
 const FUNCTIONS: &str = "functions";
+const CLASSES: &str = "classes";
+const CONSTANTS: &str = "constants";
 const EXTENSIONS: &str = "extensions";
 
+const MESSAGE: &str = "message";
+const REPLACEMENT: &str = "replacement";
+
 #[derive(Clone, Debug)]
 pub struct DisallowedFunctionsRule;
 
+/// The kind of a symbol that can be disallowed.
+#[derive(Clone, Copy, Debug)]
+enum SymbolKind {
+    Function,
+    Class,
+    Constant,
+}
+
 impl Rule for DisallowedFunctionsRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Disallowed Functions", Level::Warning)
             .with_description(indoc! {"
-                Flags calls to functions that are disallowed via rule configuration. You can specify
-                which functions or extensions should be disallowed through the `functions` or `extensions`
-                options. This helps enforce organizational coding standards, security restrictions, or
-                usage of preferred alternatives.
+                Flags usages of functions, classes, and constants that are disallowed via rule configuration.
+                You can specify which symbols or extensions should be disallowed through the `functions`,
+                `classes`, `constants`, or `extensions` options. This helps enforce organizational coding
+                standards, security restrictions, or usage of preferred alternatives.
+
+                The `functions`, `classes`, and `constants` options accept either a list of names, or a table
+                mapping each name to a message explaining why it is disallowed, or to a table with an optional
+                `message` and an optional `replacement` to suggest instead.
+
+                Names are resolved using the imports and namespace of the file, so aliased usages are also
+                reported. Function and class names are case-insensitive, while constant names are case-sensitive.
             "})
             .with_option(RuleOptionDefinition {
                 name: FUNCTIONS,
-                r#type: "array<string>",
-                description: "A list of function names to disallow (case-insensitive).",
+                r#type: "array<string> | table<string, string | { message?: string, replacement?: string }>",
+                description: "The functions to disallow (case-insensitive), optionally mapped to a message or a table with a message and a replacement.",
+                default: Value::Array(vec![]),
+            })
+            .with_option(RuleOptionDefinition {
+                name: CLASSES,
+                r#type: "array<string> | table<string, string | { message?: string, replacement?: string }>",
+                description: "The classes, interfaces, traits, and enums to disallow (case-insensitive), optionally mapped to a message or a table with a message and a replacement.",
+                default: Value::Array(vec![]),
+            })
+            .with_option(RuleOptionDefinition {
+                name: CONSTANTS,
+                r#type: "array<string> | table<string, string | { message?: string, replacement?: string }>",
+                description: "The constants to disallow (case-sensitive), optionally mapped to a message or a table with a message and a replacement.",
                 default: Value::Array(vec![]),
             })
             .with_option(RuleOptionDefinition {
@@ -92,66 +126,185 @@ impl Rule for DisallowedFunctionsRule {
                     "},
                 ).with_option(EXTENSIONS, Value::Array(vec![Value::String("curl".to_owned())]))
             )
-    }
-
-    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
-        let Node::FunctionCall(function_call) = node else { return LintDirective::default() };
+            .with_example(
+                RuleUsageExample::invalid(
+                    "Calling a disallowed function with a message",
+                    indoc! {"
+                        <?php
 
-        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
-            return LintDirective::default();
-        };
+                        sleep(5);
+                    "},
+                )
+                .with_option(FUNCTIONS, Value::Table(Table::from_iter([(
+                    "sleep".to_owned(),
+                    Value::String("Use the Clock abstraction.".to_owned()),
+                )]))),
+            )
+            .with_example(
+                RuleUsageExample::invalid(
+                    "Using a disallowed class through an alias",
+                    indoc! {"
+                        <?php
 
-        let function_name = context.resolve_function_name(identifier);
+                        use DateTime as Date;
 
-        // Check if the function is disallowed
-        if let Some(disallowed_functions) = context.option(FUNCTIONS).and_then(|o| o.as_array()) {
-            if disallowed_functions.iter().any(|f| f.as_str().is_some_and(|f| f.eq_ignore_ascii_case(function_name))) {
-                let issue = Issue::new(context.level(), format!("Function `{}` is disallowed.", function_name))
-                    .with_annotation(
-                        Annotation::primary(function_call.span())
-                            .with_message(format!("Function `{}` is called here.`", function_name)),
-                    )
-                    .with_note(format!("The function `{}` is disallowed by your project configuration.", function_name))
-                    .with_help("Use an alternative function or modify the configuration to allow this function.");
+                        $now = new Date();
+                    "},
+                )
+                .with_option(CLASSES, Value::Table(Table::from_iter([(
+                    "DateTime".to_owned(),
+                    Value::Table(Table::from_iter([(
+                        REPLACEMENT.to_owned(),
+                        Value::String("DateTimeImmutable".to_owned()),
+                    )])),
+                )]))),
+            )
+            .with_example(
+                RuleUsageExample::invalid(
+                    "Using a disallowed constant",
+                    indoc! {"
+                        <?php
 
-                context.report(issue);
+                        echo PHP_EOL;
+                    "},
+                )
+                .with_option(CONSTANTS, Value::Array(vec![Value::String("PHP_EOL".to_owned())])),
+            )
+    }
 
-                return LintDirective::default();
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        match node {
+            Node::FunctionCall(function_call) => {
+                if let Expression::Identifier(identifier) = function_call.function.as_ref() {
+                    check_function(context, identifier, function_call.span());
+                }
             }
-        }
+            Node::ConstantAccess(constant_access) => {
+                let name = context.resolve_constant_name(&constant_access.name).to_owned();
 
-        // Check if the function is part of a disallowed extension
-        if let Some(disallowed_extensions) = context.option(EXTENSIONS).and_then(|o| o.as_array()) {
-            let Some(extension) = EXTENSION_FUNCTIONS.into_iter().find_map(|(extension, function_names)| {
-                if function_names.iter().any(|f| function_name.eq_ignore_ascii_case(f)) {
-                    Some(extension)
-                } else {
-                    None
+                check_symbol(context, SymbolKind::Constant, &name, constant_access.name.span());
+            }
+            Node::Instantiation(Instantiation { class, .. })
+            | Node::StaticMethodCall(StaticMethodCall { class, .. })
+            | Node::StaticPropertyAccess(StaticPropertyAccess { class, .. })
+            | Node::ClassConstantAccess(ClassConstantAccess { class, .. }) => {
+                if let Expression::Identifier(identifier) = class.as_ref() {
+                    check_class(context, identifier);
+                }
+            }
+            Node::Binary(Binary { operator: BinaryOperator::Instanceof(_), rhs, .. }) => {
+                if let Expression::Identifier(identifier) = rhs.as_ref() {
+                    check_class(context, identifier);
                 }
-            }) else {
-                // not an extension function
-                return LintDirective::default();
-            };
-
-            if disallowed_extensions.iter().any(|e| e.as_str().is_some_and(|e| e.eq(extension))) {
-                let issue = Issue::new(
-                    context.level(),
-                    format!("Function `{}` from the `{}` extension is disallowed.", function_name, extension),
-                )
-                .with_annotation(
-                    Annotation::primary(function_call.span())
-                        .with_message(format!("Function `{}` is called here.", function_name)),
-                )
-                .with_note(format!(
-                    "Functions from the `{}` extension are disallowed by your project configuration.",
-                    extension
-                ))
-                .with_help("Use an alternative function or modify the configuration to allow this extension.");
-
-                context.report(issue);
             }
+            Node::Hint(Hint::Identifier(identifier)) => {
+                check_class(context, identifier);
+            }
+            Node::Extends(Extends { types, .. }) | Node::Implements(Implements { types, .. }) => {
+                for identifier in types.iter() {
+                    check_class(context, identifier);
+                }
+            }
+            _ => {}
         }
 
         LintDirective::default()
     }
 }
+
+fn check_function(context: &mut LintContext<'_>, identifier: &Identifier, span: Span) {
+    let function_name = context.resolve_function_name(identifier).to_owned();
+
+    // Check if the function is disallowed
+    if check_symbol(context, SymbolKind::Function, &function_name, span) {
+        return;
+    }
+
+    // Check if the function is part of a disallowed extension
+    let Some(disallowed_extensions) = context.option(EXTENSIONS).and_then(|o| o.as_array()) else {
+        return;
+    };
+
+    let Some(extension) = EXTENSION_FUNCTIONS.into_iter().find_map(|(extension, function_names)| {
+        if function_names.iter().any(|f| function_name.eq_ignore_ascii_case(f)) { Some(extension) } else { None }
+    }) else {
+        // not an extension function
+        return;
+    };
+
+    if disallowed_extensions.iter().any(|e| e.as_str().is_some_and(|e| e.eq(extension))) {
+        let issue = Issue::new(
+            context.level(),
+            format!("Function `{}` from the `{}` extension is disallowed.", function_name, extension),
+        )
+        .with_annotation(
+            Annotation::primary(span).with_message(format!("Function `{}` is called here.", function_name)),
+        )
+        .with_note(format!(
+            "Functions from the `{}` extension are disallowed by your project configuration.",
+            extension
+        ))
+        .with_help("Use an alternative function or modify the configuration to allow this extension.");
+
+        context.report(issue);
+    }
+}
+
+fn check_class(context: &mut LintContext<'_>, identifier: &Identifier) {
+    let name = context.lookup_name(identifier).to_owned();
+
+    check_symbol(context, SymbolKind::Class, &name, identifier.span());
+}
+
+/// Reports the given symbol if it is disallowed, returning `true` if an issue was reported.
+fn check_symbol(context: &mut LintContext<'_>, kind: SymbolKind, name: &str, span: Span) -> bool {
+    let (option, label, verb) = match kind {
+        SymbolKind::Function => (FUNCTIONS, "Function", "called"),
+        SymbolKind::Class => (CLASSES, "Class", "used"),
+        SymbolKind::Constant => (CONSTANTS, "Constant", "used"),
+    };
+
+    let matches = |symbol: &str| {
+        let symbol = symbol.strip_prefix('\\').unwrap_or(symbol);
+
+        match kind {
+            SymbolKind::Constant => symbol == name,
+            _ => symbol.eq_ignore_ascii_case(name),
+        }
+    };
+
+    // Symbols can either be listed by name, or mapped to a message and a replacement.
+    let entry = match context.option(option) {
+        Some(Value::Array(symbols)) => symbols.iter().filter_map(|s| s.as_str()).any(matches).then_some((None, None)),
+        Some(Value::Table(symbols)) => {
+            symbols.iter().find(|(symbol, _)| matches(symbol)).map(|(_, entry)| match entry {
+                Value::String(message) => (Some(message.to_owned()), None),
+                Value::Table(table) => (
+                    table.get(MESSAGE).and_then(|v| v.as_str()).map(str::to_owned),
+                    table.get(REPLACEMENT).and_then(|v| v.as_str()).map(str::to_owned),
+                ),
+                _ => (None, None),
+            })
+        }
+        _ => None,
+    };
+
+    let Some((message, replacement)) = entry else {
+        return false;
+    };
+
+    let issue = Issue::new(context.level(), format!("{} `{}` is disallowed.", label, name))
+        .with_annotation(Annotation::primary(span).with_message(format!("{} `{}` is {} here.", label, name, verb)))
+        .with_note(match message {
+            Some(message) => message,
+            None => format!("The {} `{}` is disallowed by your project configuration.", label.to_lowercase(), name),
+        })
+        .with_help(match replacement {
+            Some(replacement) => format!("Use `{}` instead.", replacement),
+            None => format!("Use an alternative, or modify the configuration to allow this {}.", label.to_lowercase()),
+        });
+
+    context.report(issue);
+
+    true
+}
@@ -0,0 +1,128 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+const CONSTRUCTOR: &str = "__construct";
+
+/// The minimum number of constants for a class to be considered as emulating an enum.
+const MINIMUM_CONSTANTS: usize = 2;
+
+#[derive(Clone, Copy, Debug)]
+pub struct EnumLikeClassRule;
+
+impl Rule for EnumLikeClassRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Enum Like Class", Level::Help)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects classes emulating enums, i.e. classes declaring only constants, and static methods,
+                that can be extended, or instantiated.
+
+                Such classes are not meant to have instances, or subclasses. Declaring them `final`, with a
+                private constructor, makes this explicit. Starting with PHP 8.1, an enum can be used instead.
+
+                Classes extending another class, or implementing an interface, are not reported.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A final class with a private constructor",
+                indoc! {r#"
+                    <?php
+
+                    final class OrderStatus
+                    {
+                        public const PENDING = 'pending';
+                        public const SHIPPED = 'shipped';
+
+                        private function __construct() {}
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A class emulating an enum that can be instantiated",
+                indoc! {r#"
+                    <?php
+
+                    class OrderStatus
+                    {
+                        public const PENDING = 'pending';
+                        public const SHIPPED = 'shipped';
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Class(class) = node else { return LintDirective::default() };
+
+        if class.extends.is_some() || class.implements.is_some() {
+            return LintDirective::default();
+        }
+
+        let mut constants = 0;
+        let mut constructor = None;
+        for member in class.members.iter() {
+            match member {
+                ClassLikeMember::Constant(constant) => constants += constant.items.len(),
+                ClassLikeMember::Method(method)
+                    if context.lookup(&method.name.value).eq_ignore_ascii_case(CONSTRUCTOR) =>
+                {
+                    constructor = Some(method);
+                }
+                ClassLikeMember::Method(method) if method.modifiers.contains_static() => {}
+                _ => return LintDirective::default(),
+            }
+        }
+
+        if constants < MINIMUM_CONSTANTS {
+            return LintDirective::default();
+        }
+
+        let is_final = class.modifiers.contains_final();
+        let has_private_constructor = constructor.is_some_and(|constructor| constructor.modifiers.contains_private());
+        if is_final && has_private_constructor {
+            return LintDirective::default();
+        }
+
+        let class_name = context.lookup(&class.name.value);
+        let mut issue = Issue::new(
+            context.level(),
+            format!("Class `{}` emulates an enum, but can be extended or instantiated.", class_name),
+        )
+        .with_annotation(
+            Annotation::primary(class.name.span())
+                .with_message(format!("`{}` only declares constants and static methods.", class_name)),
+        );
+
+        if !is_final {
+            issue = issue.with_annotation(
+                Annotation::secondary(class.class.span()).with_message("This class is not final, and can be extended."),
+            );
+        }
+
+        if let (false, Some(constructor)) = (has_private_constructor, constructor) {
+            issue = issue.with_annotation(
+                Annotation::secondary(constructor.name.span())
+                    .with_message("This constructor is not private, and allows instantiating the class."),
+            );
+        }
+
+        context.report(
+            issue
+                .with_note("Classes emulating enums are not meant to have instances, or subclasses.")
+                .with_help(format!(
+                    "Declare `{}` as `final`, with a private constructor, or use an enum instead, on PHP 8.1 and later.",
+                    class_name
+                )),
+        );
+
+        LintDirective::default()
+    }
+}
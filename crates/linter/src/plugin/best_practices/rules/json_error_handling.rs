@@ -0,0 +1,206 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+/// The JSON functions, along with the names of their parameters, in order.
+const JSON_FUNCTIONS: [(&str, &[&str]); 2] =
+    [("json_decode", &["json", "associative", "depth", "flags"]), ("json_encode", &["value", "flags", "depth"])];
+
+/// The default values of the parameters of `json_decode` preceding its `$flags` parameter.
+const JSON_DECODE_DEFAULTS: [&str; 2] = ["null", "512"];
+
+/// The flags handling errors, either by throwing, or by producing a partial output.
+const ERROR_HANDLING_FLAGS: [&str; 2] = ["JSON_THROW_ON_ERROR", "JSON_PARTIAL_OUTPUT_ON_ERROR"];
+
+const JSON_THROW_ON_ERROR: &str = "JSON_THROW_ON_ERROR";
+const JSON_LAST_ERROR: &str = "json_last_error";
+
+#[derive(Clone, Debug)]
+pub struct JsonErrorHandlingRule;
+
+impl Rule for JsonErrorHandlingRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("JSON Error Handling", Level::Warning)
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects calls to `json_decode` and `json_encode` whose errors are not handled, neither by
+                the `JSON_THROW_ON_ERROR` flag, nor by a later call to `json_last_error()` or
+                `json_last_error_msg()` in the same file.
+
+                Without error handling, these functions silently return `null` or `false` on failure.
+                When targeting PHP 7.3 or later, the `JSON_THROW_ON_ERROR` flag is added by the fix, so
+                that failures throw a `JsonException` instead.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using the `JSON_THROW_ON_ERROR` flag",
+                indoc! {r#"
+                    <?php
+
+                    $data = json_decode($json, true, 512, JSON_THROW_ON_ERROR);
+                    $json = json_encode($data, JSON_PRETTY_PRINT | JSON_THROW_ON_ERROR);
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "Checking `json_last_error()`",
+                indoc! {r#"
+                    <?php
+
+                    $data = json_decode($json, true);
+                    if (json_last_error() !== JSON_ERROR_NONE) {
+                        throw new RuntimeException(json_last_error_msg());
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Decoding JSON without error handling",
+                indoc! {r#"
+                    <?php
+
+                    $data = json_decode($json, true);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Encoding JSON without error handling",
+                indoc! {r#"
+                    <?php
+
+                    $json = json_encode($data, JSON_PRETTY_PRINT);
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::FunctionCall(function_call) = node else { return LintDirective::default() };
+
+        let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+            return LintDirective::default();
+        };
+
+        let function_name = context.resolve_function_name(function_identifier).to_owned();
+        let Some((_, parameters)) = JSON_FUNCTIONS.iter().find(|(name, _)| function_name.eq_ignore_ascii_case(name))
+        else {
+            return LintDirective::default();
+        };
+
+        let arguments = &function_call.argument_list.arguments;
+        if arguments.iter().any(|argument| match argument {
+            Argument::Positional(argument) => argument.ellipsis.is_some(),
+            Argument::Named(argument) => argument.ellipsis.is_some(),
+        }) {
+            // Arguments are unpacked, so the `$flags` parameter cannot be determined.
+            return LintDirective::default();
+        }
+
+        let mut flags = None;
+        for (position, argument) in arguments.iter().enumerate() {
+            let name = match argument {
+                Argument::Positional(_) => parameters.get(position).copied(),
+                Argument::Named(argument) => Some(context.lookup(&argument.name.value)),
+            };
+
+            if name == Some("flags") {
+                flags = Some(argument.value());
+            }
+        }
+
+        let flag_names = match flags {
+            Some(flags) => match get_flag_names(context, flags) {
+                Some(flag_names) => flag_names,
+                // The flags are not statically known.
+                None => return LintDirective::default(),
+            },
+            None => vec![],
+        };
+
+        if flag_names.iter().any(|name| ERROR_HANDLING_FLAGS.iter().any(|flag| name.eq_ignore_ascii_case(flag))) {
+            return LintDirective::default();
+        }
+
+        // Errors may be checked later on, using `json_last_error()` or `json_last_error_msg()`.
+        let following_code = &context.module.source.content[function_call.span().end.offset..];
+        if following_code.to_ascii_lowercase().contains(JSON_LAST_ERROR) {
+            return LintDirective::default();
+        }
+
+        let issue = Issue::new(context.level(), format!("Errors of `{}` are not handled.", function_name))
+            .with_annotation(
+                Annotation::primary(function_call.span())
+                    .with_message(format!("`{}` silently returns `null` or `false` on failure.", function_name)),
+            )
+            .with_help(format!(
+                "Pass the `JSON_THROW_ON_ERROR` flag to `{}`, or check `json_last_error()` after calling it.",
+                function_name
+            ));
+
+        if context.php_version < PHPVersion::PHP73 {
+            context.report(issue);
+
+            return LintDirective::default();
+        }
+
+        let insertion = match flags {
+            Some(flags) => (flags.span().end.offset, format!(" | {}", JSON_THROW_ON_ERROR)),
+            None => {
+                let Some(last_argument) = arguments.last() else {
+                    return LintDirective::default();
+                };
+
+                let offset = last_argument.span().end.offset;
+                let flags_position = parameters.iter().position(|name| *name == "flags").unwrap_or_default();
+                let has_named_arguments = arguments.iter().any(|argument| matches!(argument, Argument::Named(_)));
+                if !has_named_arguments && arguments.len() == flags_position {
+                    (offset, format!(", {}", JSON_THROW_ON_ERROR))
+                } else if has_named_arguments || context.php_version >= PHPVersion::PHP80 {
+                    (offset, format!(", flags: {}", JSON_THROW_ON_ERROR))
+                } else {
+                    // The omitted parameters of `json_decode` preceding `$flags` are passed their default values.
+                    let mut insertion = String::new();
+                    for default in JSON_DECODE_DEFAULTS.iter().skip(arguments.len() - 1) {
+                        insertion.push_str(", ");
+                        insertion.push_str(default);
+                    }
+
+                    (offset, format!("{}, {}", insertion, JSON_THROW_ON_ERROR))
+                }
+            }
+        };
+
+        context.propose(issue, |plan| {
+            plan.insert(insertion.0, insertion.1, SafetyClassification::PotentiallyUnsafe);
+        });
+
+        LintDirective::default()
+    }
+}
+
+/// Returns the names of the constants a flags expression is composed of, e.g. `JSON_PRETTY_PRINT | JSON_HEX_TAG`,
+/// or `None` if the expression is not statically known.
+fn get_flag_names(context: &LintContext<'_>, flags: &Expression) -> Option<Vec<String>> {
+    match flags {
+        Expression::Parenthesized(parenthesized) => get_flag_names(context, &parenthesized.expression),
+        Expression::Literal(Literal::Integer(_)) => Some(vec![]),
+        Expression::ConstantAccess(access) => {
+            let name = context.resolve_constant_name(&access.name);
+
+            Some(vec![name.rsplit('\\').next().unwrap_or(name).to_owned()])
+        }
+        Expression::Binary(Binary { lhs, operator: BinaryOperator::BitwiseOr(_), rhs }) => {
+            let mut names = get_flag_names(context, lhs)?;
+            names.extend(get_flag_names(context, rhs)?);
+
+            Some(names)
+        }
+        _ => None,
+    }
+}
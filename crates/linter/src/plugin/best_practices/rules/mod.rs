@@ -1,7 +1,9 @@
 pub mod combine_consecutive_issets;
 pub mod disallowed_functions;
 pub mod dont_catch_error;
+pub mod enum_like_class;
 pub mod excessive_nesting;
+pub mod json_error_handling;
 pub mod loop_does_not_iterate;
 pub mod no_boolean_flag_parameter;
 pub mod no_boolean_literal_comparison;
@@ -13,5 +15,13 @@ pub mod no_goto;
 pub mod no_hash_emoji;
 pub mod no_multi_assignments;
 pub mod no_unused_parameter;
+pub mod prefer_final_class;
+pub mod prefer_readonly_promoted_property;
+pub mod readonly_property_write;
+pub mod require_exception_message;
+pub mod require_previous_exception;
+pub mod require_throwable;
+pub mod unclosed_resource;
+pub mod unreleased_lock;
 pub mod use_while_instead_of_for;
 pub mod utils;
@@ -1,49 +1,210 @@
 use indoc::indoc;
+use toml::Value;
 
 use mago_ast::*;
+use mago_fixer::SafetyClassification;
 use mago_reporting::*;
 use mago_span::HasSpan;
+use mago_span::Span;
 
 use crate::context::LintContext;
+use crate::definition::RuleCategory;
 use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
 use crate::rule::Rule;
 
+const FUNCTIONS: &str = "functions";
+const ALLOWED_FUNCTIONS: &str = "allowed_functions";
+
 #[derive(Clone, Debug)]
 pub struct NoDebugSymbolsRule;
 
 impl Rule for NoDebugSymbolsRule {
     fn get_definition(&self) -> RuleDefinition {
-        RuleDefinition::enabled("No Debug Symbols", Level::Note).with_description(indoc! {"
-            Flags calls to debug functions like `var_dump`, `print_r`, `debug_backtrace`, etc.
-            in production code. Debug functions are useful for debugging, but they can expose
-            sensitive information or degrade performance in production environments.
-        "})
+        RuleDefinition::enabled("No Debug Symbols", Level::Note)
+            .fixable()
+            .with_category(RuleCategory::Security)
+            .with_description(indoc! {"
+                Flags calls to debug functions like `var_dump`, `print_r`, `debug_backtrace`, etc.
+                in production code. Debug functions are useful for debugging, but they can expose
+                sensitive information or degrade performance in production environments.
+
+                Calls to `print_r` and `var_export` that return their output instead of printing it
+                are not reported. Additional functions, such as `error_log`, can be flagged using the
+                `functions` option, and built-in debug functions can be allowed using the
+                `allowed_functions` option.
+
+                Debug calls used as standalone statements can be removed automatically.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: FUNCTIONS,
+                r#type: "array<string>",
+                description: "A list of additional function names to flag as debug functions (case-insensitive).",
+                default: Value::Array(vec![]),
+            })
+            .with_option(RuleOptionDefinition {
+                name: ALLOWED_FUNCTIONS,
+                r#type: "array<string>",
+                description: "A list of debug function names that should not be reported (case-insensitive).",
+                default: Value::Array(vec![]),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Using `print_r` to return a string",
+                indoc! {r#"
+                    <?php
+
+                    $output = print_r($value, true);
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "Calling an allowed debug function",
+                    indoc! {r#"
+                        <?php
+
+                        dump($value);
+                    "#},
+                )
+                .with_option(ALLOWED_FUNCTIONS, Value::Array(vec![Value::String("dump".to_owned())])),
+            )
+            .with_example(RuleUsageExample::invalid(
+                "Calling `var_dump`",
+                indoc! {r#"
+                    <?php
+
+                    var_dump($value);
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::invalid(
+                    "Calling a function configured as a debug function",
+                    indoc! {r#"
+                        <?php
+
+                        error_log('Reached here!');
+                    "#},
+                )
+                .with_option(FUNCTIONS, Value::Array(vec![Value::String("error_log".to_owned())])),
+            )
     }
 
     fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
-        let Node::FunctionCall(function_call) = node else { return LintDirective::default() };
+        match node {
+            Node::ExpressionStatement(statement) => {
+                let Expression::Call(Call::Function(function_call)) = statement.expression.as_ref() else {
+                    return LintDirective::default();
+                };
 
-        let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
-            return LintDirective::default();
-        };
+                let Some(issue) = check_function_call(function_call, context) else {
+                    return LintDirective::default();
+                };
 
-        let function_name = context.resolve_function_name(function_identifier);
+                // The call is a standalone statement, so it can be removed entirely, unless it is the unbraced
+                // body of a control structure, e.g. `if ($x) var_dump($y);`, where it is replaced by an empty
+                // statement so that the next statement does not become the body.
+                let span = statement.span();
+                let is_unbraced_body = is_unbraced_body(Node::Program(context.program), span);
+                context.propose(issue, |plan| {
+                    if is_unbraced_body {
+                        plan.replace(span.to_range(), ";", SafetyClassification::PotentiallyUnsafe)
+                    } else {
+                        plan.delete(span.to_range(), SafetyClassification::PotentiallyUnsafe)
+                    }
+                });
 
-        if DEBUG_FUNCTIONS.contains(&function_name) {
-            let issue = Issue::new(context.level(), format!("Usage of debug function `{}` detected.", function_name))
-                .with_annotation(
-                    Annotation::primary(function_call.span())
-                        .with_message(format!("Function `{}` is called here.", function_name)),
-                )
-                .with_note("Avoid using debug functions like `var_dump`, `print_r`, etc. in production code.")
-                .with_help("Remove the debug function call.");
+                LintDirective::Prune
+            }
+            Node::FunctionCall(function_call) => {
+                if let Some(issue) = check_function_call(function_call, context) {
+                    context.report(issue);
+                }
+
+                LintDirective::default()
+            }
+            _ => LintDirective::default(),
+        }
+    }
+}
+
+/// Returns `true` if the statement with the given span is the unbraced body of a control structure,
+/// rather than a statement of a statement list, such as a block.
+fn is_unbraced_body(node: Node<'_>, span: Span) -> bool {
+    for child in node.children() {
+        let child_span = child.span();
+        if child_span.start.offset > span.start.offset || child_span.end.offset < span.end.offset {
+            continue;
+        }
 
-            context.report(issue);
+        if matches!(child, Node::Statement(_)) && child_span == span {
+            return matches!(
+                node,
+                Node::IfStatementBody(_)
+                    | Node::IfStatementBodyElseIfClause(_)
+                    | Node::IfStatementBodyElseClause(_)
+                    | Node::WhileBody(_)
+                    | Node::ForBody(_)
+                    | Node::ForeachBody(_)
+                    | Node::DeclareBody(_)
+                    | Node::DoWhile(_)
+            );
         }
 
-        LintDirective::default()
+        return is_unbraced_body(child, span);
     }
+
+    false
+}
+
+/// Returns an issue if the given function call is a call to a debug function.
+fn check_function_call(function_call: &FunctionCall, context: &LintContext<'_>) -> Option<Issue> {
+    let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+        return None;
+    };
+
+    let function_name = context.resolve_function_name(function_identifier);
+    let is_listed = |option: &'static str| {
+        context.option(option).and_then(|o| o.as_array()).is_some_and(|functions| {
+            functions.iter().any(|f| f.as_str().is_some_and(|f| f.eq_ignore_ascii_case(function_name)))
+        })
+    };
+
+    if is_listed(ALLOWED_FUNCTIONS) {
+        return None;
+    }
+
+    let is_debug_function = DEBUG_FUNCTIONS.iter().any(|f| f.eq_ignore_ascii_case(function_name));
+    if !is_debug_function && !is_listed(FUNCTIONS) {
+        return None;
+    }
+
+    if is_debug_function && returns_output(function_name, function_call) {
+        return None;
+    }
+
+    Some(
+        Issue::new(context.level(), format!("Usage of debug function `{}` detected.", function_name))
+            .with_annotation(
+                Annotation::primary(function_call.span())
+                    .with_message(format!("Function `{}` is called here.", function_name)),
+            )
+            .with_note("Avoid using debug functions like `var_dump`, `print_r`, etc. in production code.")
+            .with_help("Remove the debug function call."),
+    )
+}
+
+/// Returns `true` if the given call to `print_r` or `var_export` returns its output instead of printing it.
+fn returns_output(function_name: &str, function_call: &FunctionCall) -> bool {
+    if !function_name.eq_ignore_ascii_case("print_r") && !function_name.eq_ignore_ascii_case("var_export") {
+        return false;
+    }
+
+    let Some(argument) = function_call.argument_list.arguments.get(1) else {
+        return false;
+    };
+
+    !matches!(argument.value(), Expression::Literal(Literal::False(_) | Literal::Null(_)))
 }
 
 const DEBUG_FUNCTIONS: [&str; 50] = [
@@ -5,6 +5,7 @@ use mago_reporting::*;
 use mago_span::HasSpan;
 
 use crate::context::LintContext;
+use crate::definition::RuleCategory;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
@@ -16,6 +17,7 @@ pub struct NoElseClauseRule;
 impl Rule for NoElseClauseRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("No Else Clause", Level::Help)
+            .with_category(RuleCategory::Style)
             .with_description(indoc! {"
                 Flags if statements that include an else branch, including else-if chains.
 
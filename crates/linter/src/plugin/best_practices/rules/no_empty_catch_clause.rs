@@ -3,6 +3,7 @@ use indoc::indoc;
 use mago_ast::*;
 use mago_reporting::*;
 use mago_span::HasSpan;
+use mago_span::Span;
 
 use crate::context::LintContext;
 use crate::definition::RuleDefinition;
@@ -21,6 +22,9 @@ impl Rule for NoEmptyCatchClauseRule {
 
                 An empty catch clause suppresses exceptions without handling or logging them,
                 potentially hiding errors that should be addressed.
+
+                Catch clauses containing a comment, e.g. explaining why the exception is ignored,
+                are not considered empty.
             "})
             .with_example(RuleUsageExample::valid(
                 "Catch clause with error handling",
@@ -35,6 +39,17 @@ impl Rule for NoEmptyCatchClauseRule {
                     }
                 "#},
             ))
+            .with_example(RuleUsageExample::valid(
+                "Catch clause with a comment explaining why the exception is ignored",
+                indoc! {r#"
+                    <?php
+                    try {
+                        unlink($file);
+                    } catch(Exception $e) {
+                        // the file may have already been removed.
+                    }
+                "#},
+            ))
             .with_example(RuleUsageExample::invalid(
                 "Empty clause block suppresses error",
                 indoc! {r#"
@@ -51,7 +66,9 @@ impl Rule for NoEmptyCatchClauseRule {
         let Node::Try(r#try) = node else { return LintDirective::default() };
 
         for catch_clause in r#try.catch_clauses.iter() {
-            if are_statements_empty(catch_clause.block.statements.as_slice()) {
+            if are_statements_empty(catch_clause.block.statements.as_slice())
+                && !has_comment(context.program, catch_clause.block.span())
+            {
                 let issue = Issue::new(context.level(), "Empty catch clause suppresses errors.")
                     .with_annotation(
                         Annotation::primary(catch_clause.span()).with_message("This catch clause is empty."),
@@ -67,6 +84,15 @@ impl Rule for NoEmptyCatchClauseRule {
     }
 }
 
+#[inline]
+fn has_comment(program: &Program, span: Span) -> bool {
+    program.trivia.iter().any(|trivia| {
+        trivia.kind.is_comment()
+            && trivia.span.start.offset >= span.start.offset
+            && trivia.span.end.offset <= span.end.offset
+    })
+}
+
 #[inline]
 fn is_statement_empty(statement: &Statement) -> bool {
     match statement {
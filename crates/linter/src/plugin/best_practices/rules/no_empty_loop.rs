@@ -15,7 +15,7 @@ pub struct NoEmptyLoopRule;
 
 impl Rule for NoEmptyLoopRule {
     fn get_definition(&self) -> RuleDefinition {
-        RuleDefinition::enabled("No Empty Loop", Level::Note).with_description(indoc! {"
+        RuleDefinition::enabled("No Empty Loop", Level::Note).fixable().with_description(indoc! {"
             Detects loops (for, foreach, while, do-while) that have an empty body. An empty loop body
             does not perform any actions and is likely a mistake or a sign of redundant code.
         "})
@@ -5,6 +5,7 @@ use mago_reporting::*;
 use mago_span::HasSpan;
 
 use crate::context::LintContext;
+use crate::definition::RuleCategory;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
@@ -15,7 +16,7 @@ pub struct NoHashEmojiRule;
 
 impl Rule for NoHashEmojiRule {
     fn get_definition(&self) -> RuleDefinition {
-        RuleDefinition::enabled("No Hash Emoji", Level::Warning)
+        RuleDefinition::enabled("No Hash Emoji", Level::Warning).fixable().with_category(RuleCategory::Style)
             .with_description(indoc! {"
                 Discourages usage of the `#️⃣` emoji in place of the ASCII `#`.
 
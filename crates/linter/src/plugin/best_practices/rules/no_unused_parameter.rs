@@ -21,7 +21,7 @@ pub struct NoUnusedParameterRule;
 
 impl Rule for NoUnusedParameterRule {
     fn get_definition(&self) -> RuleDefinition {
-        RuleDefinition::enabled("No Unused Parameter", Level::Note).with_description(indoc! {"
+        RuleDefinition::enabled("No Unused Parameter", Level::Note).fixable().with_description(indoc! {"
             Detects parameters that are declared but never used within a function, method, or closure.
             Unused parameters are a sign of dead code and can be safely removed to improve code clarity.
         "})
@@ -0,0 +1,87 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Copy, Debug)]
+pub struct PreferFinalClassRule;
+
+impl Rule for PreferFinalClassRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("Prefer Final Class")
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Suggests declaring classes as `final` when they are not extended anywhere in the project.
+
+                Final classes make the intended usage explicit, and allow changing implementation details without
+                worrying about breaking subclasses. Abstract classes, and classes with attributes (which are often
+                extended by frameworks at runtime, e.g. to create proxies) are ignored.
+
+                This rule is disabled by default, as classes of libraries may be extended outside of the project.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A final class, and a class that is extended",
+                indoc! {r#"
+                    <?php
+
+                    class Animal {}
+
+                    final class Dog extends Animal {}
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A class that is never extended",
+                indoc! {r#"
+                    <?php
+
+                    class Dog {}
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Class(class) = node else { return LintDirective::default() };
+
+        if class.modifiers.contains_final() || class.modifiers.contains_abstract() || !class.attribute_lists.is_empty()
+        {
+            return LintDirective::default();
+        }
+
+        let class_name = context.module.names.get(&class.name);
+        let lowercase_class_name = context.interner.lowered(class_name);
+        if context
+            .codebase
+            .direct_classlike_descendants
+            .get(&lowercase_class_name)
+            .is_some_and(|descendants| !descendants.is_empty())
+        {
+            return LintDirective::default();
+        }
+
+        let name = context.lookup(&class.name.value);
+        let issue =
+            Issue::new(context.level(), format!("Class `{}` is never extended and can be declared final.", name))
+                .with_annotation(
+                    Annotation::primary(class.name.span()).with_message(format!("Class `{}` is declared here.", name)),
+                )
+                .with_note("Classes that are not designed to be extended should be declared `final`.")
+                .with_help("Add the `final` modifier to the class declaration.");
+
+        let position = class.modifiers.first_span().unwrap_or(class.class.span).start;
+
+        context.propose(issue, |plan| {
+            plan.insert(position.offset, "final ", SafetyClassification::PotentiallyUnsafe);
+        });
+
+        LintDirective::default()
+    }
+}
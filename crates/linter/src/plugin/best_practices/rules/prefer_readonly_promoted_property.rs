@@ -0,0 +1,205 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_interner::StringIdentifier;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Copy, Debug)]
+pub struct PreferReadonlyPromotedPropertyRule;
+
+impl Rule for PreferReadonlyPromotedPropertyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Prefer Readonly Promoted Property", Level::Help)
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Suggests declaring private promoted constructor properties as `readonly` when they are never
+                reassigned within the class.
+
+                Only private, typed properties are considered, as protected and public properties may be modified
+                outside of the class. A property is considered reassigned if it is assigned, incremented, decremented,
+                unset, taken by reference, or passed as an argument (which may be by reference) anywhere in the class.
+            "})
+            .with_minimum_supported_php_version(PHPVersion::PHP81)
+            .with_example(RuleUsageExample::valid(
+                "Readonly and reassigned promoted properties",
+                indoc! {r#"
+                    <?php
+
+                    final class Counter {
+                        public function __construct(
+                            private readonly string $name,
+                            private int $count = 0,
+                        ) {}
+
+                        public function increment(): void {
+                            $this->count++;
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A promoted property that is never reassigned",
+                indoc! {r#"
+                    <?php
+
+                    final class User {
+                        public function __construct(
+                            private string $name,
+                        ) {}
+
+                        public function getName(): string {
+                            return $this->name;
+                        }
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Class(class) = node else { return LintDirective::default() };
+
+        if class.modifiers.contains_readonly() {
+            return LintDirective::default();
+        }
+
+        let Some(constructor) = class.members.iter().find_map(|member| match member {
+            ClassLikeMember::Method(method)
+                if context.lookup(&method.name.value).eq_ignore_ascii_case("__construct") =>
+            {
+                Some(method)
+            }
+            _ => None,
+        }) else {
+            return LintDirective::default();
+        };
+
+        let mut written = vec![];
+        collect_written_properties(Node::Class(class), &mut written);
+
+        for parameter in constructor.parameter_list.parameters.iter() {
+            if !parameter.modifiers.contains_private()
+                || parameter.modifiers.contains_readonly()
+                || parameter.hint.is_none()
+                || parameter.hooks.is_some()
+            {
+                continue;
+            }
+
+            // Strip the leading `$` to compare against property access names.
+            let variable_name = context.lookup(&parameter.variable.name);
+            let property_name = &variable_name[1..];
+            if written.iter().any(|written| context.lookup(written) == property_name) {
+                continue;
+            }
+
+            let issue = Issue::new(
+                context.level(),
+                format!("Promoted property `{}` is never reassigned and can be declared readonly.", variable_name),
+            )
+            .with_annotation(
+                Annotation::primary(parameter.variable.span())
+                    .with_message(format!("Property `{}` is promoted here.", variable_name)),
+            )
+            .with_note("Readonly properties guarantee that the value is not modified after initialization.")
+            .with_help("Add the `readonly` modifier to the promoted property.");
+
+            let Some(visibility) = parameter.modifiers.get_first_visibility() else {
+                continue;
+            };
+
+            let position = visibility.span().end;
+
+            context.propose(issue, |plan| {
+                plan.insert(position.offset, " readonly", SafetyClassification::PotentiallyUnsafe);
+            });
+        }
+
+        LintDirective::default()
+    }
+}
+
+/// Collects the names of all properties that are potentially written to within the given node.
+fn collect_written_properties(node: Node<'_>, written: &mut Vec<StringIdentifier>) {
+    match node {
+        Node::Assignment(assignment) => {
+            collect_target_properties(&assignment.lhs, written);
+        }
+        Node::UnaryPrefix(unary) => {
+            if matches!(
+                unary.operator,
+                UnaryPrefixOperator::Reference(_)
+                    | UnaryPrefixOperator::PreIncrement(_)
+                    | UnaryPrefixOperator::PreDecrement(_)
+            ) {
+                collect_target_properties(&unary.operand, written);
+            }
+        }
+        Node::UnaryPostfix(unary) => {
+            collect_target_properties(&unary.operand, written);
+        }
+        Node::Unset(unset) => {
+            for value in unset.values.iter() {
+                collect_target_properties(value, written);
+            }
+        }
+        Node::PositionalArgument(argument) => {
+            collect_target_properties(&argument.value, written);
+        }
+        Node::NamedArgument(argument) => {
+            collect_target_properties(&argument.value, written);
+        }
+        _ => {}
+    }
+
+    for child in node.children() {
+        collect_written_properties(child, written);
+    }
+}
+
+/// Collects the names of the properties modified when writing to the given expression.
+fn collect_target_properties(expression: &Expression, written: &mut Vec<StringIdentifier>) {
+    match expression {
+        Expression::Access(Access::Property(access)) => {
+            if let ClassLikeMemberSelector::Identifier(identifier) = &access.property {
+                written.push(identifier.value);
+            }
+        }
+        Expression::ArrayAccess(access) => collect_target_properties(&access.array, written),
+        Expression::ArrayAppend(append) => collect_target_properties(&append.array, written),
+        Expression::Parenthesized(parenthesized) => collect_target_properties(&parenthesized.expression, written),
+        Expression::Array(array) => {
+            for element in array.elements.iter() {
+                collect_element_properties(element, written);
+            }
+        }
+        Expression::LegacyArray(array) => {
+            for element in array.elements.iter() {
+                collect_element_properties(element, written);
+            }
+        }
+        Expression::List(list) => {
+            for element in list.elements.iter() {
+                collect_element_properties(element, written);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_element_properties(element: &ArrayElement, written: &mut Vec<StringIdentifier>) {
+    match element {
+        ArrayElement::KeyValue(element) => collect_target_properties(&element.value, written),
+        ArrayElement::Value(element) => collect_target_properties(&element.value, written),
+        _ => {}
+    }
+}
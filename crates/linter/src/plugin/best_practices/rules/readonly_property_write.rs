@@ -0,0 +1,231 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+const CONSTRUCTOR: &str = "__construct";
+const CLONE: &str = "__clone";
+
+#[derive(Clone, Copy, Debug)]
+pub struct ReadonlyPropertyWriteRule;
+
+impl Rule for ReadonlyPropertyWriteRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Readonly Property Write", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects writes to readonly properties of a class outside of its constructor, e.g. assigning,
+                incrementing, unsetting, or taking a reference to `$this->property` within another method.
+
+                A readonly property can only be initialized once, so writing to it outside of the constructor
+                either fails at runtime, if the property is already initialized, or makes its initialization
+                depend on the order in which methods are called. Starting with PHP 8.3, readonly properties
+                may also be reinitialized within `__clone`.
+
+                Only properties declared within the class, and accessed on `$this`, are considered.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Initializing a readonly property within the constructor",
+                indoc! {r#"
+                    <?php
+
+                    final class Invoice
+                    {
+                        private readonly int $total;
+
+                        public function __construct(int $total)
+                        {
+                            $this->total = $total;
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Writing to a readonly property within a method",
+                indoc! {r#"
+                    <?php
+
+                    final class Invoice
+                    {
+                        public function __construct(
+                            private readonly int $total,
+                        ) {}
+
+                        public function addLine(int $amount): void
+                        {
+                            $this->total += $amount;
+                        }
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let (modifiers, members) = match node {
+            Node::Class(class) => (&class.modifiers, &class.members),
+            Node::AnonymousClass(class) => (&class.modifiers, &class.members),
+            _ => return LintDirective::default(),
+        };
+
+        let is_readonly_class = modifiers.contains_readonly();
+
+        let mut readonly_properties = vec![];
+        for member in members.iter() {
+            match member {
+                ClassLikeMember::Property(property) => {
+                    let modifiers = property.modifiers();
+                    if modifiers.contains_static() || !(is_readonly_class || modifiers.contains_readonly()) {
+                        continue;
+                    }
+
+                    for variable in property.variables() {
+                        readonly_properties.push(&context.lookup(&variable.name)[1..]);
+                    }
+                }
+                ClassLikeMember::Method(method) if is_method_named(context, method, CONSTRUCTOR) => {
+                    for parameter in method.parameter_list.parameters.iter() {
+                        if parameter.is_promoted_property()
+                            && (is_readonly_class || parameter.modifiers.contains_readonly())
+                        {
+                            readonly_properties.push(&context.lookup(&parameter.variable.name)[1..]);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if readonly_properties.is_empty() {
+            return LintDirective::default();
+        }
+
+        let mut writes = vec![];
+        for member in members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            if is_method_named(context, method, CONSTRUCTOR)
+                || (context.php_version >= PHPVersion::PHP83 && is_method_named(context, method, CLONE))
+            {
+                continue;
+            }
+
+            if let MethodBody::Concrete(block) = &method.body {
+                collect_this_property_writes(Node::Block(block), context, &mut writes);
+            }
+        }
+
+        let writes = writes
+            .into_iter()
+            .map(|(property, span)| (context.lookup(&property.value), span))
+            .filter(|(property_name, _)| readonly_properties.contains(property_name))
+            .map(|(property_name, span)| (property_name.to_string(), span))
+            .collect::<Vec<_>>();
+
+        for (property_name, span) in writes {
+            context.report(
+                Issue::new(
+                    context.level(),
+                    format!("Readonly property `${}` is written to outside of the constructor.", property_name),
+                )
+                .with_annotation(
+                    Annotation::primary(span).with_message(format!("`${}` is written to here.", property_name)),
+                )
+                .with_note("A readonly property can only be initialized once, from within the scope of its class.")
+                .with_help("Initialize the property within the constructor, or return a modified copy of the object."),
+            );
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn is_method_named(context: &LintContext<'_>, method: &Method, name: &str) -> bool {
+    context.lookup(&method.name.value).eq_ignore_ascii_case(name)
+}
+
+/// Collects the properties of `$this` that are written to within the given node, along with the span of the write.
+///
+/// Nested functions, and classes, are not visited, as `$this` does not refer to the same object within them.
+fn collect_this_property_writes<'a>(
+    node: Node<'a>,
+    context: &LintContext<'_>,
+    writes: &mut Vec<(&'a LocalIdentifier, Span)>,
+) {
+    match node {
+        Node::Function(_) | Node::Class(_) | Node::AnonymousClass(_) => return,
+        Node::Assignment(assignment) => {
+            collect_target_properties(&assignment.lhs, assignment.span(), context, writes);
+        }
+        Node::UnaryPrefix(unary)
+            if matches!(
+                unary.operator,
+                UnaryPrefixOperator::Reference(_)
+                    | UnaryPrefixOperator::PreIncrement(_)
+                    | UnaryPrefixOperator::PreDecrement(_)
+            ) =>
+        {
+            collect_target_properties(&unary.operand, unary.span(), context, writes);
+        }
+        Node::UnaryPostfix(unary) => {
+            collect_target_properties(&unary.operand, unary.span(), context, writes);
+        }
+        Node::Unset(unset) => {
+            for value in unset.values.iter() {
+                collect_target_properties(value, value.span(), context, writes);
+            }
+        }
+        _ => {}
+    }
+
+    for child in node.children() {
+        collect_this_property_writes(child, context, writes);
+    }
+}
+
+/// Collects the properties of `$this` modified when writing to the given expression.
+fn collect_target_properties<'a>(
+    expression: &'a Expression,
+    span: Span,
+    context: &LintContext<'_>,
+    writes: &mut Vec<(&'a LocalIdentifier, Span)>,
+) {
+    match expression {
+        Expression::Access(Access::Property(access)) => {
+            let is_this = matches!(
+                access.object.as_ref(),
+                Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == "$this"
+            );
+
+            if let (true, ClassLikeMemberSelector::Identifier(identifier)) = (is_this, &access.property) {
+                writes.push((identifier, span));
+            }
+        }
+        Expression::ArrayAccess(access) => collect_target_properties(&access.array, span, context, writes),
+        Expression::ArrayAppend(append) => collect_target_properties(&append.array, span, context, writes),
+        Expression::Parenthesized(parenthesized) => {
+            collect_target_properties(&parenthesized.expression, span, context, writes)
+        }
+        Expression::Array(Array { elements, .. })
+        | Expression::LegacyArray(LegacyArray { elements, .. })
+        | Expression::List(List { elements, .. }) => {
+            for element in elements.iter() {
+                match element {
+                    ArrayElement::KeyValue(element) => collect_target_properties(&element.value, span, context, writes),
+                    ArrayElement::Value(element) => collect_target_properties(&element.value, span, context, writes),
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+}
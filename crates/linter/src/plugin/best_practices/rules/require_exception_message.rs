@@ -0,0 +1,125 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+use crate::utils::get_string_value;
+
+const MESSAGE_PARAMETER: &str = "message";
+
+/// The built-in exception and error classes, whose message defaults to an empty string.
+const BUILTIN_EXCEPTIONS: [&str; 24] = [
+    "Exception",
+    "ErrorException",
+    "Error",
+    "ArithmeticError",
+    "ArgumentCountError",
+    "AssertionError",
+    "DivisionByZeroError",
+    "TypeError",
+    "ValueError",
+    "UnhandledMatchError",
+    "JsonException",
+    "BadFunctionCallException",
+    "BadMethodCallException",
+    "DomainException",
+    "InvalidArgumentException",
+    "LengthException",
+    "LogicException",
+    "OutOfBoundsException",
+    "OutOfRangeException",
+    "OverflowException",
+    "RangeException",
+    "RuntimeException",
+    "UnderflowException",
+    "UnexpectedValueException",
+];
+
+#[derive(Clone, Debug)]
+pub struct RequireExceptionMessageRule;
+
+impl Rule for RequireExceptionMessageRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Require Exception Message", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects built-in exceptions, such as `Exception`, or `RuntimeException`, thrown without a
+                message, or with an empty one.
+
+                An exception without a message does not explain what went wrong, making the resulting
+                errors and logs harder to understand.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Throwing an exception with a message",
+                indoc! {r#"
+                    <?php
+
+                    throw new RuntimeException('Unable to connect to the database.');
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Throwing an exception without a message",
+                indoc! {r#"
+                    <?php
+
+                    throw new \Exception();
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Throw(throw) = node else {
+            return LintDirective::default();
+        };
+
+        let Expression::Instantiation(instantiation) = throw.exception.as_ref() else {
+            return LintDirective::default();
+        };
+
+        let Expression::Identifier(class_identifier) = instantiation.class.as_ref() else {
+            return LintDirective::default();
+        };
+
+        let class_fqcn = context.lookup(context.module.names.get(class_identifier)).trim_start_matches('\\');
+        let Some(class_name) = BUILTIN_EXCEPTIONS.iter().find(|exception| class_fqcn.eq_ignore_ascii_case(exception))
+        else {
+            return LintDirective::default();
+        };
+
+        let message = instantiation.arguments.as_ref().and_then(|argument_list| {
+            argument_list.arguments.iter().enumerate().find_map(|(position, argument)| match argument {
+                Argument::Positional(argument) if position == 0 => Some(&argument.value),
+                Argument::Named(argument)
+                    if context.lookup(&argument.name.value).eq_ignore_ascii_case(MESSAGE_PARAMETER) =>
+                {
+                    Some(&argument.value)
+                }
+                _ => None,
+            })
+        });
+
+        let annotation = match message {
+            None => Annotation::primary(instantiation.span()).with_message("This exception has no message."),
+            Some(Expression::Literal(Literal::String(string)))
+                if get_string_value(string, context.interner).is_some_and(|value| value.trim().is_empty()) =>
+            {
+                Annotation::primary(string.span()).with_message("This message is empty.")
+            }
+            _ => return LintDirective::default(),
+        };
+
+        context.report(
+            Issue::new(context.level(), format!("`{}` is thrown without a message.", class_name))
+                .with_annotation(annotation)
+                .with_help("Pass a message describing what went wrong to the exception."),
+        );
+
+        LintDirective::default()
+    }
+}
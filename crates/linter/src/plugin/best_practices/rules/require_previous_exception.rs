@@ -0,0 +1,110 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::best_practices::rules::utils::collect_from_block;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct RequirePreviousExceptionRule;
+
+impl Rule for RequirePreviousExceptionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Require Previous Exception", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects new exceptions thrown from a `catch` clause without passing the caught exception
+                as an argument, e.g. as the `$previous` exception.
+
+                Not chaining the caught exception loses its message, and its stack trace, making the
+                original cause of the error harder to find.
+
+                Exceptions thrown from `catch` clauses that do not capture the caught exception in a
+                variable are not reported.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Chaining the caught exception",
+                indoc! {r#"
+                    <?php
+
+                    try {
+                        $connection->connect();
+                    } catch (ConnectionException $e) {
+                        throw new RuntimeException('Unable to connect to the database.', 0, $e);
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Throwing a new exception without the caught exception",
+                indoc! {r#"
+                    <?php
+
+                    try {
+                        $connection->connect();
+                    } catch (ConnectionException $e) {
+                        throw new RuntimeException('Unable to connect to the database: ' . $e->getMessage());
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Try(r#try) = node else {
+            return LintDirective::default();
+        };
+
+        for catch_clause in r#try.catch_clauses.iter() {
+            let Some(variable) = &catch_clause.variable else {
+                continue;
+            };
+
+            let throws = collect_from_block(&catch_clause.block, |expression| {
+                let Expression::Throw(throw) = expression else {
+                    return None;
+                };
+
+                let Expression::Instantiation(instantiation) = throw.exception.as_ref() else {
+                    return None;
+                };
+
+                let is_chained = instantiation.arguments.as_ref().is_some_and(|argument_list| {
+                    argument_list.arguments.iter().any(|argument| {
+                        matches!(
+                            argument.value(),
+                            Expression::Variable(Variable::Direct(argument)) if argument.name == variable.name
+                        )
+                    })
+                });
+
+                if is_chained { None } else { Some(instantiation.span()) }
+            });
+
+            let variable_name = context.lookup(&variable.name).to_string();
+            for span in throws {
+                context.report(
+                    Issue::new(context.level(), "New exception is thrown without the caught exception.")
+                        .with_annotation(
+                            Annotation::primary(span)
+                                .with_message(format!("`{}` is not passed to this exception.", variable_name)),
+                        )
+                        .with_annotation(
+                            Annotation::secondary(variable.span())
+                                .with_message(format!("The exception is caught as `{}` here.", variable_name)),
+                        )
+                        .with_help(format!(
+                            "Pass `{}` as the previous exception, e.g. `new RuntimeException($message, 0, {})`.",
+                            variable_name, variable_name
+                        )),
+                );
+            }
+        }
+
+        LintDirective::default()
+    }
+}
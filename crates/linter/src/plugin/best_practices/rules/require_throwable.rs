@@ -0,0 +1,162 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+const THROWABLE: &str = "Throwable";
+
+/// The maximum depth of the inheritance chain to follow, guarding against cyclic declarations.
+const MAXIMUM_DEPTH: usize = 64;
+
+#[derive(Clone, Debug)]
+pub struct RequireThrowableRule;
+
+impl Rule for RequireThrowableRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Require Throwable", Level::Error)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects `throw` expressions whose value is not an object implementing `Throwable`, i.e. a
+                scalar, an array, or an instance of a class that extends neither `Exception` nor `Error`.
+
+                Throwing such a value results in an `Error` being thrown at runtime instead.
+
+                Classes whose ancestors are not all known are not reported.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Throwing an exception",
+                indoc! {r#"
+                    <?php
+
+                    final class PaymentDeclinedException extends RuntimeException {}
+
+                    throw new PaymentDeclinedException('The payment was declined.');
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Throwing an instance of a class that does not implement `Throwable`",
+                indoc! {r#"
+                    <?php
+
+                    final class PaymentDeclined {}
+
+                    throw new PaymentDeclined();
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Throwing a string",
+                indoc! {r#"
+                    <?php
+
+                    throw 'The payment was declined.';
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Throw(throw) = node else {
+            return LintDirective::default();
+        };
+
+        let exception = unwrap_parenthesized(&throw.exception);
+        match exception {
+            Expression::Literal(_)
+            | Expression::CompositeString(_)
+            | Expression::Array(_)
+            | Expression::LegacyArray(_) => {
+                context.report(
+                    Issue::new(context.level(), "Only objects implementing `Throwable` can be thrown.")
+                        .with_annotation(
+                            Annotation::primary(exception.span()).with_message("This value is not an object."),
+                        )
+                        .with_annotation(Annotation::secondary(throw.span()).with_message("The value is thrown here."))
+                        .with_help("Throw an instance of a class extending `Exception`, or `Error`, instead."),
+                );
+            }
+            Expression::Instantiation(instantiation) => {
+                let Expression::Identifier(class_identifier) = instantiation.class.as_ref() else {
+                    return LintDirective::default();
+                };
+
+                let class_name_identifier = context.module.names.get(class_identifier);
+                let Some(reflection) = context.codebase.get_named_class_like(context.interner, class_name_identifier)
+                else {
+                    return LintDirective::default();
+                };
+
+                if !reflection.is_class() || is_throwable(context, class_name_identifier, 0) != Some(false) {
+                    return LintDirective::default();
+                }
+
+                let class_name = context.lookup(&class_identifier.value()).to_string();
+                let class_fqcn = context.lookup(class_name_identifier).to_string();
+
+                context.report(
+                    Issue::new(
+                        context.level(),
+                        format!("Class `{}` cannot be thrown, as it does not implement `Throwable`.", class_name),
+                    )
+                    .with_annotation(
+                        Annotation::primary(instantiation.span())
+                            .with_message(format!("`{}` does not implement `Throwable`.", class_fqcn)),
+                    )
+                    .with_annotation(
+                        Annotation::secondary(reflection.span)
+                            .with_message(format!("`{}` is declared here.", class_fqcn)),
+                    )
+                    .with_help(format!("Make `{}` extend `Exception`, or `Error`.", class_fqcn)),
+                );
+            }
+            _ => {}
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn unwrap_parenthesized(expression: &Expression) -> &Expression {
+    match expression {
+        Expression::Parenthesized(parenthesized) => unwrap_parenthesized(&parenthesized.expression),
+        _ => expression,
+    }
+}
+
+/// Determine if the class-like implements, or is, `Throwable`.
+///
+/// Returns `None` if this cannot be determined, because one of its ancestors is not known.
+fn is_throwable(context: &LintContext<'_>, name: &StringIdentifier, depth: usize) -> Option<bool> {
+    if context.lookup(name).trim_start_matches('\\').eq_ignore_ascii_case(THROWABLE) {
+        return Some(true);
+    }
+
+    if depth > MAXIMUM_DEPTH {
+        return None;
+    }
+
+    let reflection = context.codebase.get_named_class_like(context.interner, name)?;
+    let inheritance = &reflection.inheritance;
+
+    let mut is_known = true;
+    for parent in inheritance
+        .direct_extended_class
+        .iter()
+        .chain(inheritance.direct_implemented_interfaces.iter())
+        .chain(inheritance.direct_extended_interfaces.iter())
+    {
+        match is_throwable(context, &parent.value, depth + 1) {
+            Some(true) => return Some(true),
+            Some(false) => {}
+            None => is_known = false,
+        }
+    }
+
+    if is_known { Some(false) } else { None }
+}
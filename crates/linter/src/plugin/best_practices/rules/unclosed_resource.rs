@@ -0,0 +1,197 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::best_practices::rules::utils::Resource;
+use crate::plugin::best_practices::rules::utils::collect_from_block;
+use crate::plugin::best_practices::rules::utils::find_unreleased_exits;
+use crate::plugin::best_practices::rules::utils::get_function_like_body;
+use crate::plugin::best_practices::rules::utils::is_variable_transferred;
+use crate::rule::Rule;
+
+/// The functions opening a resource, along with the function closing it.
+const OPENERS: [(&str, &str); 8] = [
+    ("fopen", "fclose"),
+    ("tmpfile", "fclose"),
+    ("fsockopen", "fclose"),
+    ("popen", "pclose"),
+    ("proc_open", "proc_close"),
+    ("opendir", "closedir"),
+    ("curl_init", "curl_close"),
+    ("curl_multi_init", "curl_multi_close"),
+];
+
+/// The functions returning objects instead of resources since PHP 8.0, which are freed once unreferenced.
+const OBJECT_OPENERS: [&str; 2] = ["curl_init", "curl_multi_init"];
+
+#[derive(Clone, Debug)]
+pub struct UnclosedResourceRule;
+
+impl Rule for UnclosedResourceRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unclosed Resource", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects resources opened within a function, e.g. using `fopen`, `fsockopen`, or `curl_init`,
+                that are not closed on all paths leading out of the function, including early returns and
+                thrown exceptions.
+
+                Resources that are returned, stored in a property or an array, or passed to a method or a
+                constructor are considered to be closed elsewhere, and are not reported.
+
+                The paths are derived from the structure of the function body, so closing the resource
+                in a `finally` clause satisfies this rule, as does not closing it when opening it failed,
+                e.g. `if ($handle === false) { return; }`.
+
+                `curl_init` and `curl_multi_init` handles are only checked when targeting PHP versions
+                prior to 8.0, as they are objects freed once unreferenced since then.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Closing the resource in a `finally` clause",
+                indoc! {r#"
+                    <?php
+
+                    function read_config(string $file): string
+                    {
+                        $handle = fopen($file, 'r');
+                        if ($handle === false) {
+                            throw new RuntimeException('Unable to open the configuration file.');
+                        }
+
+                        try {
+                            return stream_get_contents($handle);
+                        } finally {
+                            fclose($handle);
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Returning early without closing the resource",
+                indoc! {r#"
+                    <?php
+
+                    function read_header(string $file): ?string
+                    {
+                        $handle = fopen($file, 'r');
+                        $header = fgets($handle);
+                        if ($header === false) {
+                            return null;
+                        }
+
+                        fclose($handle);
+
+                        return $header;
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Some((body, foreign_variables)) = get_function_like_body(node) else {
+            return LintDirective::default();
+        };
+
+        let php_version = context.php_version;
+        let openings = collect_from_block(body, |expression| {
+            let Expression::Assignment(assignment) = expression else {
+                return None;
+            };
+
+            let (AssignmentOperator::Assign(_), Expression::Variable(Variable::Direct(variable))) =
+                (&assignment.operator, assignment.lhs.as_ref())
+            else {
+                return None;
+            };
+
+            let opener = get_opener(assignment.rhs.as_ref(), context)?;
+            if php_version >= PHPVersion::PHP80 && OBJECT_OPENERS.contains(&opener.0) {
+                return None;
+            }
+
+            Some((assignment.span(), variable.name, opener))
+        });
+
+        for (acquisition, variable, (opener, closer)) in openings {
+            if foreign_variables.contains(&variable) || is_variable_transferred(body, variable) {
+                continue;
+            }
+
+            let resource = Resource { variable, acquisition, is_held_by_variable: true, is_release };
+            let exits = find_unreleased_exits(body, &resource, context);
+            if exits.is_empty() {
+                continue;
+            }
+
+            let variable_name = context.lookup(&variable).to_string();
+            let mut issue =
+                Issue::new(context.level(), format!("Resource opened with `{}` is not closed on all paths.", opener))
+                    .with_annotation(
+                        Annotation::primary(acquisition).with_message(format!("`{}` is opened here.", variable_name)),
+                    );
+
+            for (span, kind) in exits {
+                issue = issue.with_annotation(Annotation::secondary(span).with_message(format!(
+                    "`{}` may still be open when {}.",
+                    variable_name,
+                    kind.get_description()
+                )));
+            }
+
+            context.report(
+                issue
+                    .with_note("Resources left open are only freed at the end of the request, which may exhaust file descriptors, or connections, in long-running processes.")
+                    .with_help(format!(
+                        "Close the resource with `{}({})` on every path, e.g. in a `finally` clause.",
+                        closer, variable_name
+                    )),
+            );
+        }
+
+        LintDirective::default()
+    }
+}
+
+/// Returns the names of the functions opening and closing the resource the expression evaluates to, if any.
+fn get_opener(expression: &Expression, context: &LintContext<'_>) -> Option<(&'static str, &'static str)> {
+    match expression {
+        Expression::Parenthesized(parenthesized) => get_opener(&parenthesized.expression, context),
+        Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::ErrorControl(_), operand }) => {
+            get_opener(operand, context)
+        }
+        Expression::Call(Call::Function(function_call)) => {
+            let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+                return None;
+            };
+
+            let function_name = context.resolve_function_name(function_identifier);
+
+            OPENERS.iter().find(|(opener, _)| function_name.eq_ignore_ascii_case(opener)).copied()
+        }
+        _ => None,
+    }
+}
+
+fn is_release(function_call: &FunctionCall, variable: StringIdentifier, context: &LintContext<'_>) -> bool {
+    let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+        return false;
+    };
+
+    let function_name = context.resolve_function_name(function_identifier);
+    if !OPENERS.iter().any(|(_, closer)| function_name.eq_ignore_ascii_case(closer)) {
+        return false;
+    }
+
+    matches!(
+        function_call.argument_list.arguments.first().map(|argument| argument.value()),
+        Some(Expression::Variable(Variable::Direct(argument))) if argument.name == variable
+    )
+}
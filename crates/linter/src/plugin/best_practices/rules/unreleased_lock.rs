@@ -0,0 +1,214 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::best_practices::rules::utils::Resource;
+use crate::plugin::best_practices::rules::utils::collect_from_block;
+use crate::plugin::best_practices::rules::utils::find_unreleased_exits;
+use crate::plugin::best_practices::rules::utils::get_function_like_body;
+use crate::plugin::best_practices::rules::utils::is_variable_transferred;
+use crate::rule::Rule;
+
+const FLOCK: &str = "flock";
+const FCLOSE: &str = "fclose";
+
+/// The values of the `LOCK_SH`, `LOCK_EX`, and `LOCK_UN` constants, whose bits select the operation of `flock`.
+const LOCK_OPERATIONS: [(&str, u64); 3] = [("LOCK_SH", 1), ("LOCK_EX", 2), ("LOCK_UN", 3)];
+const LOCK_OPERATION_MASK: u64 = 3;
+const LOCK_UN: u64 = 3;
+
+#[derive(Clone, Debug)]
+pub struct UnreleasedLockRule;
+
+impl Rule for UnreleasedLockRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unreleased Lock", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects locks acquired within a function using `flock` with `LOCK_SH` or `LOCK_EX`, that
+                are not released on all paths leading out of the function, using `flock` with `LOCK_UN`,
+                or by closing the locked handle with `fclose`.
+
+                Locks on handles received as parameters, or transferred out of the function, e.g. by
+                returning the handle, are considered to be released elsewhere, and are not reported.
+
+                The paths are derived from the structure of the function body, so releasing the lock
+                in a `finally` clause satisfies this rule, as does not releasing it when acquiring it
+                failed, e.g. `if (!flock($handle, LOCK_EX)) { return; }`.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Releasing the lock in a `finally` clause",
+                indoc! {r#"
+                    <?php
+
+                    function increment_counter(string $file): void
+                    {
+                        $handle = fopen($file, 'c+');
+                        if (!flock($handle, LOCK_EX)) {
+                            fclose($handle);
+
+                            return;
+                        }
+
+                        try {
+                            $count = (int) stream_get_contents($handle);
+                            ftruncate($handle, 0);
+                            rewind($handle);
+                            fwrite($handle, (string) ($count + 1));
+                        } finally {
+                            flock($handle, LOCK_UN);
+                            fclose($handle);
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Throwing without releasing the lock",
+                indoc! {r#"
+                    <?php
+
+                    function write_report(string $file, string $report): void
+                    {
+                        $handle = fopen($file, 'w');
+                        flock($handle, LOCK_EX);
+                        if (fwrite($handle, $report) === false) {
+                            throw new RuntimeException('Unable to write the report.');
+                        }
+
+                        flock($handle, LOCK_UN);
+                        fclose($handle);
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Some((body, foreign_variables)) = get_function_like_body(node) else {
+            return LintDirective::default();
+        };
+
+        let locks = collect_from_block(body, |expression| {
+            let Expression::Call(Call::Function(function_call)) = expression else {
+                return None;
+            };
+
+            let variable = get_flock_operation(function_call, context)
+                .filter(|(_, operation)| *operation != LOCK_UN)
+                .map(|(variable, _)| variable)?;
+
+            Some((function_call.span(), variable))
+        });
+
+        for (acquisition, variable) in locks {
+            if foreign_variables.contains(&variable) || is_variable_transferred(body, variable) {
+                continue;
+            }
+
+            let resource = Resource { variable, acquisition, is_held_by_variable: false, is_release };
+            let exits = find_unreleased_exits(body, &resource, context);
+            if exits.is_empty() {
+                continue;
+            }
+
+            let variable_name = context.lookup(&variable).to_string();
+            let mut issue = Issue::new(context.level(), "Lock acquired with `flock` is not released on all paths.")
+                .with_annotation(
+                    Annotation::primary(acquisition)
+                        .with_message(format!("A lock on `{}` is acquired here.", variable_name)),
+                );
+
+            for (span, kind) in exits {
+                issue = issue.with_annotation(
+                    Annotation::secondary(span)
+                        .with_message(format!("The lock may still be held when {}.", kind.get_description())),
+                );
+            }
+
+            context.report(
+                issue
+                    .with_note("Locks left held block other processes until the handle is closed, or the request ends.")
+                    .with_help(format!(
+                        "Release the lock with `flock({}, LOCK_UN)` on every path, e.g. in a `finally` clause.",
+                        variable_name
+                    )),
+            );
+        }
+
+        LintDirective::default()
+    }
+}
+
+/// Returns the locked handle variable and the operation of a `flock` call, if statically known.
+fn get_flock_operation(function_call: &FunctionCall, context: &LintContext<'_>) -> Option<(StringIdentifier, u64)> {
+    let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+        return None;
+    };
+
+    if !context.resolve_function_name(function_identifier).eq_ignore_ascii_case(FLOCK) {
+        return None;
+    }
+
+    let [Argument::Positional(handle), Argument::Positional(operation), ..] =
+        function_call.argument_list.arguments.as_slice()
+    else {
+        return None;
+    };
+
+    let Expression::Variable(Variable::Direct(variable)) = &handle.value else {
+        return None;
+    };
+
+    let operation = get_operation_value(&operation.value, context)? & LOCK_OPERATION_MASK;
+    if operation == 0 {
+        return None;
+    }
+
+    Some((variable.name, operation))
+}
+
+/// Returns the value of an operation expression, e.g. `LOCK_EX | LOCK_NB`, ignoring the bits of unknown constants.
+fn get_operation_value(expression: &Expression, context: &LintContext<'_>) -> Option<u64> {
+    match expression {
+        Expression::Parenthesized(parenthesized) => get_operation_value(&parenthesized.expression, context),
+        Expression::Literal(Literal::Integer(LiteralInteger { value: Some(value), .. })) => Some(*value),
+        Expression::ConstantAccess(access) => {
+            let name = context.resolve_constant_name(&access.name);
+            let name = name.rsplit('\\').next().unwrap_or(name);
+
+            Some(
+                LOCK_OPERATIONS
+                    .iter()
+                    .find(|(constant, _)| name.eq_ignore_ascii_case(constant))
+                    .map(|(_, value)| *value)
+                    .unwrap_or_default(),
+            )
+        }
+        Expression::Binary(Binary { lhs, operator: BinaryOperator::BitwiseOr(_), rhs }) => {
+            Some(get_operation_value(lhs, context)? | get_operation_value(rhs, context)?)
+        }
+        _ => None,
+    }
+}
+
+fn is_release(function_call: &FunctionCall, variable: StringIdentifier, context: &LintContext<'_>) -> bool {
+    if let Some((locked, operation)) = get_flock_operation(function_call, context) {
+        return locked == variable && operation == LOCK_UN;
+    }
+
+    let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+        return false;
+    };
+
+    context.resolve_function_name(function_identifier).eq_ignore_ascii_case(FCLOSE)
+        && matches!(
+            function_call.argument_list.arguments.first().map(|argument| argument.value()),
+            Some(Expression::Variable(Variable::Direct(argument))) if argument.name == variable
+        )
+}
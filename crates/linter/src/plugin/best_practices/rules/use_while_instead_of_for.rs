@@ -6,6 +6,7 @@ use mago_reporting::*;
 use mago_span::HasSpan;
 
 use crate::context::LintContext;
+use crate::definition::RuleCategory;
 use crate::definition::RuleDefinition;
 use crate::directive::LintDirective;
 use crate::rule::Rule;
@@ -15,7 +16,10 @@ pub struct UseWhileInsteadOfForRule;
 
 impl Rule for UseWhileInsteadOfForRule {
     fn get_definition(&self) -> RuleDefinition {
-        RuleDefinition::enabled("Use While Instead Of For", Level::Note).with_description(indoc! {"
+        RuleDefinition::enabled("Use While Instead Of For", Level::Note)
+            .fixable()
+            .with_category(RuleCategory::Style)
+            .with_description(indoc! {"
             Suggests using a `while` loop instead of a `for` loop when the `for` loop does not have any
             initializations or increments. This can make the code more readable and concise.
         "})
@@ -1,9 +1,48 @@
 use mago_ast::*;
 use mago_interner::StringIdentifier;
+use mago_span::Span;
 use mago_walker::Walker;
 
 use crate::context::LintContext;
 use crate::plugin::best_practices::rules::utils::internal::FunctionCallWalker;
+use crate::plugin::best_practices::rules::utils::internal::ResourceFlow;
+use crate::plugin::best_practices::rules::utils::internal::ResourceState;
+use crate::plugin::best_practices::rules::utils::internal::ScopedExpressionWalker;
+use crate::plugin::best_practices::rules::utils::internal::TransferWalker;
+
+/// The kind of an exit point of a function body.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ExitKind {
+    Return,
+    Throw,
+    /// The end of the body, reached without returning or throwing.
+    End,
+}
+
+impl ExitKind {
+    /// Describe the exit point, completing a sentence such as "The resource may still be held when ...".
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            ExitKind::Return => "returning here",
+            ExitKind::Throw => "throwing here",
+            ExitKind::End => "the function ends here",
+        }
+    }
+}
+
+/// A resource acquired within a function body, e.g. a file handle, or a lock.
+#[derive(Debug, Clone, Copy)]
+pub struct Resource {
+    /// The variable holding the resource, or the handle the resource is acquired on, in the case of a lock.
+    pub variable: StringIdentifier,
+    /// The span of the expression acquiring the resource, e.g. `$handle = fopen($file, 'r')`.
+    pub acquisition: Span,
+    /// Whether the variable holds the resource itself, in which case checking its value, e.g. `if (!$handle)`,
+    /// tells whether the acquisition succeeded.
+    pub is_held_by_variable: bool,
+    /// Determines whether the given function call releases the resource held by, or acquired on, the variable.
+    pub is_release: fn(&FunctionCall, StringIdentifier, &LintContext<'_>) -> bool,
+}
 
 /// Determine if a variable is a super global variable.
 pub fn is_super_global_variable(name: &str) -> bool {
@@ -159,11 +198,92 @@ pub fn get_foreign_variable_names(block: &Block, context: &LintContext<'_>) -> V
     foreign
 }
 
+/// Given a function-like node, get its body, along with the names of the variables it receives from outside,
+/// i.e. its parameters, and the variables captured by a closure.
+///
+/// Returns `None` if the node is not a function-like node with a body.
+pub fn get_function_like_body<'a>(node: Node<'a>) -> Option<(&'a Block, Vec<StringIdentifier>)> {
+    let (body, parameter_list, use_clause) = match node {
+        Node::Function(function) => (&function.body, &function.parameter_list, None),
+        Node::Method(method) => match &method.body {
+            MethodBody::Concrete(block) => (block, &method.parameter_list, None),
+            MethodBody::Abstract(_) => return None,
+        },
+        Node::Closure(closure) => (&closure.body, &closure.parameter_list, closure.use_clause.as_ref()),
+        _ => return None,
+    };
+
+    let mut variables = parameter_list.parameters.iter().map(|parameter| parameter.variable.name).collect::<Vec<_>>();
+    if let Some(use_clause) = use_clause {
+        variables.extend(use_clause.variables.iter().map(|variable| variable.variable.name));
+    }
+
+    Some((body, variables))
+}
+
+/// Collect the values returned by `f` for the expressions of the block.
+///
+/// The expressions of nested functions, closures, and classes are not visited, as they do not run
+/// as part of the block.
+pub fn collect_from_block<T>(block: &Block, f: impl Fn(&Expression) -> Option<T> + Sync + Send) -> Vec<T> {
+    let mut values = Vec::default();
+
+    ScopedExpressionWalker(f).walk_block(block, &mut values);
+
+    values
+}
+
+/// Determine if the value of the variable is transferred out of the block.
+///
+/// The value is considered transferred when it is returned, yielded, assigned to another variable, a property,
+/// or an array, passed to a method or a constructor, captured by a closure, or declared as global or static.
+///
+/// Passing the variable to a function is not considered a transfer.
+pub fn is_variable_transferred(block: &Block, variable: StringIdentifier) -> bool {
+    let mut transferred = false;
+
+    TransferWalker(variable).walk_block(block, &mut transferred);
+
+    transferred
+}
+
+/// Find the exit points of a function body where the given resource may still be held, i.e. acquired, but not
+/// released on at least one of the paths leading there.
+///
+/// The paths are approximated from the structure of the body: the branches of conditions and `switch`
+/// statements are joined, loops may run any number of times, `catch` clauses may be entered from any
+/// statement of their `try` block, and a `finally` clause releasing the resource covers its `try` statement.
+///
+/// Conditions checking the acquisition, such as `if (!$handle)`, or `if ($handle === false)`, are understood,
+/// so that the branches where the acquisition failed do not require a release.
+pub fn find_unreleased_exits(body: &Block, resource: &Resource, context: &LintContext<'_>) -> Vec<(Span, ExitKind)> {
+    let mut flow = ResourceFlow {
+        resource,
+        context,
+        exits: Vec::default(),
+        breaks: Vec::default(),
+        throws: Vec::default(),
+        releasing_finally_clauses: 0,
+    };
+
+    let state = flow.walk_statements(body.statements.as_slice(), ResourceState::Unacquired);
+    flow.exit(body.right_brace, ExitKind::End, state);
+
+    flow.exits
+}
+
 mod internal {
+    use super::ExitKind;
+    use super::Resource;
     use super::is_predefined_variable;
 
+    use std::slice;
+
     use mago_ast::*;
+    use mago_ast_utils::condition::is_truthy;
     use mago_interner::StringIdentifier;
+    use mago_span::HasSpan;
+    use mago_span::Span;
     use mago_walker::Walker;
 
     use crate::context::LintContext;
@@ -181,6 +301,368 @@ mod internal {
     #[derive(Debug)]
     pub(super) struct FunctionCallWalker<'a>(pub &'a str);
 
+    #[derive(Debug)]
+    pub(super) struct ScopedExpressionWalker<F>(pub F);
+
+    type ReleaseFilter<'a> = &'a (dyn Fn(&Expression) -> Option<()> + Sync + Send);
+
+    #[derive(Debug)]
+    pub(super) struct TransferWalker(pub StringIdentifier);
+
+    /// The state of a resource along a path of a function body.
+    ///
+    /// The states are ordered so that joining paths keeps the greatest state, i.e. a resource held on any of
+    /// the joined paths may still be held after them.
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
+    pub(super) enum ResourceState {
+        /// The path does not continue, e.g. after a `return` statement.
+        Unreachable,
+        /// The resource was not acquired yet, or its acquisition failed.
+        Unacquired,
+        Released,
+        Acquired,
+    }
+
+    pub(super) struct ResourceFlow<'a> {
+        pub resource: &'a Resource,
+        pub context: &'a LintContext<'a>,
+        pub exits: Vec<(Span, ExitKind)>,
+        /// The joined states at `break` and `continue` statements, for each enclosing loop or `switch` statement.
+        pub breaks: Vec<ResourceState>,
+        /// The joined states at `throw` expressions, for each enclosing `try` statement with `catch` clauses.
+        pub throws: Vec<ResourceState>,
+        /// The number of enclosing `try` statements whose `finally` clause releases the resource.
+        pub releasing_finally_clauses: usize,
+    }
+
+    impl ResourceFlow<'_> {
+        pub fn walk_statements(&mut self, statements: &[Statement], mut state: ResourceState) -> ResourceState {
+            for statement in statements {
+                if state == ResourceState::Unreachable {
+                    break;
+                }
+
+                state = self.walk_statement(statement, state);
+            }
+
+            state
+        }
+
+        pub fn exit(&mut self, span: Span, kind: ExitKind, state: ResourceState) {
+            if state == ResourceState::Acquired && self.releasing_finally_clauses == 0 {
+                self.exits.push((span, kind));
+            }
+        }
+
+        fn walk_statement(&mut self, statement: &Statement, state: ResourceState) -> ResourceState {
+            match statement {
+                Statement::Block(block) => self.walk_statements(block.statements.as_slice(), state),
+                Statement::If(r#if) => self.walk_if(r#if, state),
+                Statement::While(r#while) => {
+                    let state = self.walk_expression(&r#while.condition, state);
+                    let statements = match &r#while.body {
+                        WhileBody::Statement(statement) => slice::from_ref(statement.as_ref()),
+                        WhileBody::ColonDelimited(body) => body.statements.as_slice(),
+                    };
+
+                    self.walk_loop(statements, state, is_truthy(&r#while.condition), false)
+                }
+                Statement::DoWhile(do_while) => self.walk_loop(
+                    slice::from_ref(do_while.statement.as_ref()),
+                    state,
+                    is_truthy(&do_while.condition),
+                    true,
+                ),
+                Statement::For(r#for) => {
+                    let mut state = state;
+                    for expression in r#for.initializations.iter().chain(r#for.conditions.iter()) {
+                        state = self.walk_expression(expression, state);
+                    }
+
+                    let statements = match &r#for.body {
+                        ForBody::Statement(statement) => slice::from_ref(statement.as_ref()),
+                        ForBody::ColonDelimited(body) => body.statements.as_slice(),
+                    };
+
+                    self.walk_loop(statements, state, r#for.conditions.iter().all(is_truthy), false)
+                }
+                Statement::Foreach(foreach) => {
+                    let state = self.walk_expression(&foreach.expression, state);
+                    let statements = match &foreach.body {
+                        ForeachBody::Statement(statement) => slice::from_ref(statement.as_ref()),
+                        ForeachBody::ColonDelimited(body) => body.statements.as_slice(),
+                    };
+
+                    self.walk_loop(statements, state, false, false)
+                }
+                Statement::Switch(switch) => self.walk_switch(switch, state),
+                Statement::Try(r#try) => self.walk_try(r#try, state),
+                Statement::Return(r#return) => {
+                    let state = match &r#return.value {
+                        Some(value) => self.walk_expression(value, state),
+                        None => state,
+                    };
+
+                    self.exit(r#return.span(), ExitKind::Return, state);
+
+                    ResourceState::Unreachable
+                }
+                Statement::Continue(_) | Statement::Break(_) => {
+                    if let Some(breaks) = self.breaks.last_mut() {
+                        *breaks = (*breaks).max(state);
+                    }
+
+                    ResourceState::Unreachable
+                }
+                Statement::Expression(expression_statement) => match expression_statement.expression.as_ref() {
+                    Expression::Throw(throw) => {
+                        let state = self.walk_expression(&throw.exception, state);
+                        match self.throws.last_mut() {
+                            Some(throws) => *throws = (*throws).max(state),
+                            None => self.exit(throw.span(), ExitKind::Throw, state),
+                        }
+
+                        ResourceState::Unreachable
+                    }
+                    // The script ends, releasing all of its resources.
+                    Expression::Construct(Construct::Exit(_) | Construct::Die(_)) => ResourceState::Unreachable,
+                    expression => self.walk_expression(expression, state),
+                },
+                Statement::Function(_)
+                | Statement::Class(_)
+                | Statement::Interface(_)
+                | Statement::Trait(_)
+                | Statement::Enum(_) => state,
+                statement => {
+                    let releases = self.releases(|walker, calls| walker.walk_statement(statement, calls));
+
+                    self.transition(statement.span(), releases, state)
+                }
+            }
+        }
+
+        fn walk_expression(&mut self, expression: &Expression, state: ResourceState) -> ResourceState {
+            let releases = self.releases(|walker, calls| walker.walk_expression(expression, calls));
+
+            self.transition(expression.span(), releases, state)
+        }
+
+        fn walk_if(&mut self, r#if: &If, state: ResourceState) -> ResourceState {
+            let (statements, else_if_clauses, else_statements) = match &r#if.body {
+                IfBody::Statement(body) => (
+                    slice::from_ref(body.statement.as_ref()),
+                    body.else_if_clauses
+                        .iter()
+                        .map(|clause| (clause.condition.as_ref(), slice::from_ref(clause.statement.as_ref())))
+                        .collect::<Vec<_>>(),
+                    body.else_clause.as_ref().map(|clause| slice::from_ref(clause.statement.as_ref())),
+                ),
+                IfBody::ColonDelimited(body) => (
+                    body.statements.as_slice(),
+                    body.else_if_clauses
+                        .iter()
+                        .map(|clause| (clause.condition.as_ref(), clause.statements.as_slice()))
+                        .collect::<Vec<_>>(),
+                    body.else_clause.as_ref().map(|clause| clause.statements.as_slice()),
+                ),
+            };
+
+            let mut state = self.walk_expression(&r#if.condition, state);
+            let mut joined = self.walk_statements(statements, self.refine(&r#if.condition, true, state));
+            state = self.refine(&r#if.condition, false, state);
+
+            for (condition, statements) in else_if_clauses {
+                state = self.walk_expression(condition, state);
+                joined = joined.max(self.walk_statements(statements, self.refine(condition, true, state)));
+                state = self.refine(condition, false, state);
+            }
+
+            match else_statements {
+                Some(statements) => joined.max(self.walk_statements(statements, state)),
+                None => joined.max(state),
+            }
+        }
+
+        fn walk_loop(
+            &mut self,
+            statements: &[Statement],
+            state: ResourceState,
+            is_infinite: bool,
+            runs_at_least_once: bool,
+        ) -> ResourceState {
+            self.breaks.push(ResourceState::Unreachable);
+            let body = self.walk_statements(statements, state);
+            let breaks = self.breaks.pop().unwrap_or(ResourceState::Unreachable);
+
+            if is_infinite {
+                breaks
+            } else if runs_at_least_once {
+                breaks.max(body)
+            } else {
+                breaks.max(body).max(state)
+            }
+        }
+
+        fn walk_switch(&mut self, switch: &Switch, state: ResourceState) -> ResourceState {
+            let state = self.walk_expression(&switch.expression, state);
+
+            self.breaks.push(ResourceState::Unreachable);
+            let mut has_default = false;
+            let mut fallthrough = ResourceState::Unreachable;
+            for case in switch.body.cases() {
+                has_default |= matches!(case, SwitchCase::Default(_));
+                fallthrough = self.walk_statements(case.statements(), state.max(fallthrough));
+            }
+
+            let breaks = self.breaks.pop().unwrap_or(ResourceState::Unreachable);
+            if has_default { breaks.max(fallthrough) } else { breaks.max(fallthrough).max(state) }
+        }
+
+        fn walk_try(&mut self, r#try: &Try, state: ResourceState) -> ResourceState {
+            let releasing_finally = r#try.finally_clause.as_ref().is_some_and(|finally_clause| {
+                self.releases(|walker, calls| walker.walk_block(&finally_clause.block, calls))
+            });
+
+            if releasing_finally {
+                self.releasing_finally_clauses += 1;
+            }
+
+            let has_catch_clauses = !r#try.catch_clauses.is_empty();
+            if has_catch_clauses {
+                self.throws.push(ResourceState::Unreachable);
+            }
+
+            let mut joined = self.walk_statements(r#try.block.statements.as_slice(), state);
+            if has_catch_clauses {
+                // Any statement of the `try` block may throw, including those following the acquisition.
+                let mut entry = self.throws.pop().unwrap_or(ResourceState::Unreachable).max(state).max(joined);
+                if self.is_acquired_in(r#try.block.span()) {
+                    entry = ResourceState::Acquired;
+                }
+
+                for catch_clause in r#try.catch_clauses.iter() {
+                    joined = joined.max(self.walk_statements(catch_clause.block.statements.as_slice(), entry));
+                }
+            }
+
+            if releasing_finally {
+                self.releasing_finally_clauses -= 1;
+            }
+
+            match &r#try.finally_clause {
+                Some(finally_clause) if joined != ResourceState::Unreachable => {
+                    self.walk_statements(finally_clause.block.statements.as_slice(), joined)
+                }
+                _ => joined,
+            }
+        }
+
+        fn transition(&self, span: Span, releases: bool, state: ResourceState) -> ResourceState {
+            if self.is_acquired_in(span) {
+                ResourceState::Acquired
+            } else if releases && state == ResourceState::Acquired {
+                ResourceState::Released
+            } else {
+                state
+            }
+        }
+
+        /// Refine the state of the resource on the branch taken when the condition is, or is not, true.
+        fn refine(&self, condition: &Expression, is_true: bool, state: ResourceState) -> ResourceState {
+            if state != ResourceState::Acquired {
+                return state;
+            }
+
+            match self.is_failure_check(condition) {
+                Some(means_failure) if means_failure == is_true => ResourceState::Unacquired,
+                _ => state,
+            }
+        }
+
+        /// Determine whether the condition being true means that the acquisition failed (`Some(true)`),
+        /// or that it succeeded (`Some(false)`), or `None` if the condition does not check the acquisition.
+        fn is_failure_check(&self, condition: &Expression) -> Option<bool> {
+            if self.is_acquisition_result(condition) {
+                return Some(false);
+            }
+
+            match condition {
+                Expression::Parenthesized(parenthesized) => self.is_failure_check(&parenthesized.expression),
+                Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Not(_), operand }) => {
+                    self.is_failure_check(operand).map(|means_failure| !means_failure)
+                }
+                Expression::Call(Call::Function(function_call)) => {
+                    let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+                        return None;
+                    };
+
+                    let [Argument::Positional(argument)] = function_call.argument_list.arguments.as_slice() else {
+                        return None;
+                    };
+
+                    (self.context.resolve_function_name(function_identifier).eq_ignore_ascii_case("is_resource")
+                        && self.is_acquisition_result(&argument.value))
+                    .then_some(false)
+                }
+                Expression::Binary(binary) => {
+                    let other = if self.is_acquisition_result(&binary.lhs) {
+                        &binary.rhs
+                    } else if self.is_acquisition_result(&binary.rhs) {
+                        &binary.lhs
+                    } else {
+                        return None;
+                    };
+
+                    if !matches!(other.as_ref(), Expression::Literal(Literal::False(_) | Literal::Null(_))) {
+                        return None;
+                    }
+
+                    match binary.operator {
+                        BinaryOperator::Identical(_) | BinaryOperator::Equal(_) => Some(true),
+                        BinaryOperator::NotIdentical(_)
+                        | BinaryOperator::NotEqual(_)
+                        | BinaryOperator::AngledNotEqual(_) => Some(false),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+
+        /// Determine whether the expression evaluates to the result of the acquisition.
+        fn is_acquisition_result(&self, expression: &Expression) -> bool {
+            match expression {
+                Expression::Parenthesized(parenthesized) => self.is_acquisition_result(&parenthesized.expression),
+                Expression::Variable(Variable::Direct(variable)) => {
+                    self.resource.is_held_by_variable && variable.name == self.resource.variable
+                }
+                _ => expression.span() == self.resource.acquisition,
+            }
+        }
+
+        fn is_acquired_in(&self, span: Span) -> bool {
+            span.start.offset <= self.resource.acquisition.start.offset
+                && self.resource.acquisition.end.offset <= span.end.offset
+        }
+
+        fn releases(&self, walk: impl FnOnce(&ScopedExpressionWalker<ReleaseFilter<'_>>, &mut Vec<()>)) -> bool {
+            let (resource, context) = (self.resource, self.context);
+            let filter = move |expression: &Expression| match expression {
+                Expression::Call(Call::Function(function_call))
+                    if (resource.is_release)(function_call, resource.variable, context) =>
+                {
+                    Some(())
+                }
+                _ => None,
+            };
+
+            let mut releases = Vec::default();
+            walk(&ScopedExpressionWalker(&filter), &mut releases);
+
+            !releases.is_empty()
+        }
+    }
+
     impl<'a> Walker<(Vec<VariableReference>, &'a LintContext<'a>)> for VariableWalker {
         fn walk_in_foreach_value_target<'ast>(
             &self,
@@ -394,6 +876,127 @@ mod internal {
         fn walk_anonymous_class<'ast>(&self, _: &'ast AnonymousClass, _: &mut (bool, &'a LintContext<'a>)) {}
     }
 
+    impl<T, F> Walker<Vec<T>> for ScopedExpressionWalker<F>
+    where
+        F: Fn(&Expression) -> Option<T> + Sync + Send,
+    {
+        fn walk_in_expression(&self, expression: &Expression, context: &mut Vec<T>) {
+            if let Some(value) = (self.0)(expression) {
+                context.push(value);
+            }
+        }
+
+        #[inline(always)]
+        fn walk_closure(&self, _: &Closure, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_arrow_function(&self, _: &ArrowFunction, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_namespace(&self, _: &Namespace, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_class(&self, _: &Class, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_interface(&self, _: &Interface, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_trait(&self, _: &Trait, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_enum(&self, _: &Enum, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_function(&self, _: &Function, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_anonymous_class(&self, _: &AnonymousClass, _: &mut Vec<T>) {}
+    }
+
+    impl Walker<bool> for TransferWalker {
+        fn walk_in_return(&self, r#return: &Return, context: &mut bool) {
+            if let Some(value) = &r#return.value {
+                *context |= is_variable(value, self.0);
+            }
+        }
+
+        fn walk_in_yield_value(&self, yield_value: &YieldValue, context: &mut bool) {
+            if let Some(value) = &yield_value.value {
+                *context |= is_variable(value, self.0);
+            }
+        }
+
+        fn walk_in_yield_pair(&self, yield_pair: &YieldPair, context: &mut bool) {
+            *context |= is_variable(&yield_pair.value, self.0);
+        }
+
+        fn walk_in_assignment(&self, assignment: &Assignment, context: &mut bool) {
+            *context |= is_variable(&assignment.rhs, self.0);
+        }
+
+        fn walk_in_key_value_array_element(&self, element: &KeyValueArrayElement, context: &mut bool) {
+            *context |= is_variable(&element.value, self.0);
+        }
+
+        fn walk_in_value_array_element(&self, element: &ValueArrayElement, context: &mut bool) {
+            *context |= is_variable(&element.value, self.0);
+        }
+
+        fn walk_in_method_call(&self, method_call: &MethodCall, context: &mut bool) {
+            *context |= has_argument(&method_call.argument_list, self.0);
+        }
+
+        fn walk_in_null_safe_method_call(&self, method_call: &NullSafeMethodCall, context: &mut bool) {
+            *context |= has_argument(&method_call.argument_list, self.0);
+        }
+
+        fn walk_in_static_method_call(&self, method_call: &StaticMethodCall, context: &mut bool) {
+            *context |= has_argument(&method_call.argument_list, self.0);
+        }
+
+        fn walk_in_instantiation(&self, instantiation: &Instantiation, context: &mut bool) {
+            if let Some(argument_list) = &instantiation.arguments {
+                *context |= has_argument(argument_list, self.0);
+            }
+        }
+
+        fn walk_in_closure_use_clause_variable(
+            &self,
+            closure_use_clause_variable: &ClosureUseClauseVariable,
+            context: &mut bool,
+        ) {
+            *context |= closure_use_clause_variable.variable.name == self.0;
+        }
+
+        fn walk_in_global(&self, global: &Global, context: &mut bool) {
+            *context |= global.variables.iter().any(|variable| match variable {
+                Variable::Direct(variable) => variable.name == self.0,
+                _ => false,
+            });
+        }
+
+        fn walk_in_static_concrete_item(&self, item: &StaticConcreteItem, context: &mut bool) {
+            *context |= item.variable.name == self.0;
+        }
+
+        fn walk_in_static_abstract_item(&self, item: &StaticAbstractItem, context: &mut bool) {
+            *context |= item.variable.name == self.0;
+        }
+    }
+
+    fn is_variable(expression: &Expression, name: StringIdentifier) -> bool {
+        match expression {
+            Expression::Parenthesized(parenthesized) => is_variable(&parenthesized.expression, name),
+            Expression::Variable(Variable::Direct(variable)) => variable.name == name,
+            _ => false,
+        }
+    }
+
+    fn has_argument(argument_list: &ArgumentList, name: StringIdentifier) -> bool {
+        argument_list.arguments.iter().any(|argument| is_variable(argument.value(), name))
+    }
+
     fn scan_expression_for_assignment(
         expression: &Expression,
         context: &LintContext<'_>,
@@ -1,10 +1,13 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::comment::rules::docblock_syntax::DocblockSyntaxRule;
+use crate::plugin::comment::rules::file_header::FileHeaderRule;
 use crate::plugin::comment::rules::no_empty_comments::NoEmptyCommentsRule;
 use crate::plugin::comment::rules::no_shell_style::NoShellStyleRule;
 use crate::plugin::comment::rules::no_trailing_whitespace::NoTrailingWhitespaceRule;
 use crate::plugin::comment::rules::no_untagged_fixme::NoUntaggedFixmeRule;
 use crate::plugin::comment::rules::no_untagged_todo::NoUntaggedTodoRule;
+use crate::plugin::comment::rules::task_comment_policy::TaskCommentPolicyRule;
 
 use crate::plugin::Plugin;
 use crate::rule::Rule;
@@ -20,6 +23,7 @@ impl Plugin for CommentPlugin {
             name: "Comment",
             description: "Provides rules that enforce best practices for comments.",
             enabled_by_default: true,
+            category: RuleCategory::Style,
         }
     }
 
@@ -31,6 +35,8 @@ impl Plugin for CommentPlugin {
             Box::new(NoShellStyleRule),
             Box::new(NoTrailingWhitespaceRule),
             Box::new(DocblockSyntaxRule),
+            Box::new(TaskCommentPolicyRule),
+            Box::new(FileHeaderRule),
         ]
     }
 }
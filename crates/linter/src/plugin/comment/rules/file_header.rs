@@ -0,0 +1,265 @@
+use std::sync::LazyLock;
+
+use indoc::indoc;
+use regex::Regex;
+use toml::Value;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+use crate::utils::comment_lines;
+use crate::utils::get_today;
+
+const TEMPLATE: &str = "template";
+const COMPANY: &str = "company";
+const LOCATION: &str = "location";
+const LOCATION_AFTER_OPEN_TAG: &str = "after_open_tag";
+const LOCATION_AFTER_DECLARE_STRICT: &str = "after_declare_strict";
+
+const YEAR_PLACEHOLDER: &str = "{year}";
+const COMPANY_PLACEHOLDER: &str = "{company}";
+
+static YEAR_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b\d{4}(?:\s*-\s*\d{4})?\b").unwrap());
+
+#[derive(Clone, Debug)]
+pub struct FileHeaderRule;
+
+impl Rule for FileHeaderRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("File Header")
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Enforces a header comment, such as a copyright or license notice, at the top of each file,
+                following a configurable template.
+
+                The template may contain the `{year}` placeholder, matching any year or range of years,
+                e.g. `2019-2025`, and the `{company}` placeholder, replaced with the `company` option.
+
+                Missing headers are inserted, and outdated ones are replaced, keeping their year. Headers
+                are placed after the opening tag, or after the `declare(strict_types=1);` statement, and
+                always before the namespace.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: TEMPLATE,
+                r#type: "string",
+                description: "The text of the header, without the comment delimiters. The rule does nothing if it is empty.",
+                default: Value::String(String::new()),
+            })
+            .with_option(RuleOptionDefinition {
+                name: COMPANY,
+                r#type: "string",
+                description: "The value of the `{company}` placeholder of the template.",
+                default: Value::String(String::new()),
+            })
+            .with_option(RuleOptionDefinition {
+                name: LOCATION,
+                r#type: "string",
+                description: "Where missing headers are inserted, either `after_declare_strict`, or `after_open_tag`.",
+                default: Value::String(LOCATION_AFTER_DECLARE_STRICT.to_string()),
+            })
+            .with_example(
+                RuleUsageExample::valid(
+                    "A file with the configured header",
+                    indoc! {r#"
+                        <?php
+
+                        declare(strict_types=1);
+
+                        /*
+                         * This file is part of the Acme project.
+                         *
+                         * (c) 2019-2025 Acme Inc.
+                         */
+
+                        namespace Acme;
+                    "#},
+                )
+                .with_option(TEMPLATE, Value::String(example_template()))
+                .with_option(COMPANY, Value::String("Acme Inc.".to_string())),
+            )
+            .with_example(
+                RuleUsageExample::valid(
+                    "A file with the configured header, written as a docblock",
+                    indoc! {r#"
+                        <?php
+
+                        /**
+                         * This file is part of the Acme project.
+                         *
+                         * (c) 2025 Acme Inc.
+                         */
+
+                        namespace Acme;
+                    "#},
+                )
+                .with_option(TEMPLATE, Value::String(example_template()))
+                .with_option(COMPANY, Value::String("Acme Inc.".to_string())),
+            )
+            .with_example(
+                RuleUsageExample::invalid(
+                    "A file without a header",
+                    indoc! {r#"
+                        <?php
+
+                        declare(strict_types=1);
+
+                        namespace Acme;
+                    "#},
+                )
+                .with_option(TEMPLATE, Value::String(example_template()))
+                .with_option(COMPANY, Value::String("Acme Inc.".to_string())),
+            )
+            .with_example(
+                RuleUsageExample::invalid(
+                    "A file with an outdated header",
+                    indoc! {r#"
+                        <?php
+
+                        /*
+                         * This file is part of the Acme project.
+                         *
+                         * (c) 2019 Acme Corporation
+                         */
+
+                        namespace Acme;
+                    "#},
+                )
+                .with_option(TEMPLATE, Value::String(example_template()))
+                .with_option(COMPANY, Value::String("Acme Inc.".to_string())),
+            )
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Program(program) = node else { return LintDirective::Abort };
+
+        let template = context.option(TEMPLATE).and_then(|template| template.as_str()).map(normalize);
+        let Some(template) = template.filter(|template| !template.is_empty()) else {
+            return LintDirective::Abort;
+        };
+
+        let company = context.option(COMPANY).and_then(|company| company.as_str()).unwrap_or_default().to_string();
+        let after_declare_strict = match context.option(LOCATION).and_then(|location| location.as_str()) {
+            None | Some(LOCATION_AFTER_DECLARE_STRICT) => true,
+            Some(LOCATION_AFTER_OPEN_TAG) => false,
+            Some(location) => {
+                tracing::warn!("Ignoring invalid file header location `{}`, using `after_declare_strict`.", location);
+
+                true
+            }
+        };
+
+        // Files not starting with an opening tag, e.g. templates, are not checked.
+        let mut statements = program.statements.iter();
+        let Some(Statement::OpeningTag(opening_tag)) = statements.next() else {
+            return LintDirective::Abort;
+        };
+
+        let mut header_offset = opening_tag.span().end.offset;
+        let mut header_end = usize::MAX;
+        for statement in statements {
+            match statement {
+                Statement::Declare(declare) if after_declare_strict => {
+                    header_offset = declare.span().end.offset;
+                }
+                Statement::Declare(_) => {}
+                _ => {
+                    header_end = statement.span().start.offset;
+
+                    break;
+                }
+            }
+        }
+
+        let pattern = regex::escape(&template)
+            .replace(&regex::escape(YEAR_PLACEHOLDER), r"\d{4}(?:\s*-\s*\d{4})?")
+            .replace(&regex::escape(COMPANY_PLACEHOLDER), &regex::escape(&company));
+        let Ok(header_regex) = Regex::new(&format!("^{}$", pattern)) else {
+            return LintDirective::Abort;
+        };
+
+        let comments = program.trivia.iter().filter(|trivia| {
+            matches!(trivia.kind, TriviaKind::MultiLineComment | TriviaKind::DocBlockComment)
+                && trivia.span.start.offset >= opening_tag.span().end.offset
+                && trivia.span.end.offset <= header_end
+        });
+
+        let mut existing = None;
+        for comment in comments {
+            let content = normalize(&comment_lines(comment, context.interner).join("\n"));
+            if header_regex.is_match(&content) {
+                return LintDirective::Abort;
+            }
+
+            existing.get_or_insert((comment.span, content, comment.kind == TriviaKind::DocBlockComment));
+        }
+
+        let year = existing
+            .as_ref()
+            .and_then(|(_, content, _)| YEAR_REGEX.find(content))
+            .map(|year| year.as_str().to_string())
+            .unwrap_or_else(|| get_today().0.to_string());
+
+        let is_docblock = existing.as_ref().is_some_and(|(_, _, is_docblock)| *is_docblock);
+        let header = render(&template, &year, &company, is_docblock);
+
+        match existing {
+            Some((span, _, _)) => {
+                let issue = Issue::new(context.level(), "The file header does not match the configured template.")
+                    .with_annotation(Annotation::primary(span).with_message("This header is outdated."))
+                    .with_help("Update the file header to match the configured template.");
+
+                context.propose(issue, |plan| plan.replace(span.to_range(), header, SafetyClassification::Safe));
+            }
+            None => {
+                let issue = Issue::new(context.level(), "The file is missing the configured header.")
+                    .with_annotation(
+                        Annotation::primary(opening_tag.span()).with_message("The header should follow this tag."),
+                    )
+                    .with_help("Add the configured header at the top of the file.");
+
+                context.propose(issue, |plan| {
+                    plan.insert(header_offset, format!("\n\n{}", header), SafetyClassification::Safe)
+                });
+            }
+        }
+
+        LintDirective::Abort
+    }
+}
+
+/// Normalizes the text of a header, trimming its lines, and removing its leading and trailing empty lines.
+fn normalize(text: &str) -> String {
+    text.lines().map(str::trim).collect::<Vec<_>>().join("\n").trim_matches('\n').to_string()
+}
+
+/// Renders the header comment from the template, replacing its placeholders.
+///
+/// Headers written as a docblock, i.e. `/** ... */`, are kept as such.
+fn render(template: &str, year: &str, company: &str, is_docblock: bool) -> String {
+    let lines = template
+        .replace(YEAR_PLACEHOLDER, year)
+        .replace(COMPANY_PLACEHOLDER, company)
+        .lines()
+        .map(|line| if line.is_empty() { " *".to_string() } else { format!(" * {}", line) })
+        .collect::<Vec<_>>();
+
+    format!("{}\n{}\n */", if is_docblock { "/**" } else { "/*" }, lines.join("\n"))
+}
+
+fn example_template() -> String {
+    indoc! {"
+        This file is part of the Acme project.
+
+        (c) {year} {company}
+    "}
+    .to_string()
+}
@@ -1,6 +1,8 @@
 pub mod docblock_syntax;
+pub mod file_header;
 pub mod no_empty_comments;
 pub mod no_shell_style;
 pub mod no_trailing_whitespace;
 pub mod no_untagged_fixme;
 pub mod no_untagged_todo;
+pub mod task_comment_policy;
@@ -21,6 +21,7 @@ const PRESERVE_SINGLE_LINE_DEFAULT: bool = false;
 impl Rule for NoEmptyCommentsRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("No Empty Comments", Level::Note)
+            .fixable()
             .with_description(indoc! {"
             Detects empty comments in the codebase. Empty comments are not useful and should be removed
             to keep the codebase clean and maintainable.
@@ -15,7 +15,7 @@ pub struct NoShellStyleRule;
 
 impl Rule for NoShellStyleRule {
     fn get_definition(&self) -> RuleDefinition {
-        RuleDefinition::enabled("No Shell Style", Level::Warning).with_description(indoc! {"
+        RuleDefinition::enabled("No Shell Style", Level::Warning).fixable().with_description(indoc! {"
             Detects shell-style comments ('#') in PHP code. Double slash comments ('//') are preferred
             in PHP, as they are more consistent with the language's syntax and are easier to read.
         "})
@@ -0,0 +1,231 @@
+use indoc::indoc;
+use regex::Regex;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+use crate::utils::comment_lines;
+use crate::utils::get_today;
+
+const TAGS: &str = "tags";
+const TAGS_DEFAULT: [&str; 2] = ["TODO", "FIXME"];
+const REFERENCE_PATTERN: &str = "reference_pattern";
+const REFERENCE_PATTERN_DEFAULT: &str = r"[#@]?[^\s,()]+";
+
+#[derive(Clone, Debug)]
+pub struct TaskCommentPolicyRule;
+
+impl Rule for TaskCommentPolicyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("Task Comment Policy")
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Enforces that task comments, such as `TODO` and `FIXME`, reference an issue or a user matching
+                a configurable pattern, e.g. `TODO(PROJ-123)`, `TODO(#123)`, or `TODO(@azjezz)`, so that every
+                pending task is tracked.
+
+                By default, the same references accepted by the `no-untagged-todo` and `no-untagged-fixme`
+                rules are accepted. The `reference_pattern` option can be used to require a stricter format,
+                such as an issue key.
+
+                A task comment may also set an expiry date after the reference, e.g.
+                `TODO(PROJ-123, 2025-06-30)`. Once the date has passed, the comment is reported as an
+                error, regardless of the configured level.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: TAGS,
+                r#type: "array<string>",
+                description: "The tags of the task comments to check (case-insensitive).",
+                default: Value::Array(TAGS_DEFAULT.iter().map(|tag| Value::String(tag.to_string())).collect()),
+            })
+            .with_option(RuleOptionDefinition {
+                name: REFERENCE_PATTERN,
+                r#type: "string",
+                description: "The regular expression the issue reference must entirely match.",
+                default: Value::String(REFERENCE_PATTERN_DEFAULT.to_string()),
+            })
+            .with_example(RuleUsageExample::valid(
+                "A task comment referencing an issue",
+                indoc! {r#"
+                    <?php
+
+                    // TODO(PROJ-123) Remove once the legacy API is gone.
+                    function legacy(): void {}
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "A task comment referencing an issue, with an expiry date in the future",
+                indoc! {r#"
+                    <?php
+
+                    // FIXME(PROJ-123, 2999-12-31) Handle the timeout.
+                    function fetch(): void {}
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "A task comment referencing a user",
+                indoc! {r#"
+                    <?php
+
+                    // TODO(@azjezz) Remove once the legacy API is gone.
+                    function legacy(): void {}
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "A task comment referencing an issue matching a custom pattern",
+                    indoc! {r#"
+                        <?php
+
+                        // TODO(#123) Remove once the legacy API is gone.
+                        function legacy(): void {}
+                    "#},
+                )
+                .with_option(REFERENCE_PATTERN, Value::String(r"#\d+".to_string())),
+            )
+            .with_example(RuleUsageExample::invalid(
+                "A task comment without an issue reference",
+                indoc! {r#"
+                    <?php
+
+                    // TODO Remove once the legacy API is gone.
+                    function legacy(): void {}
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::invalid(
+                    "A task comment referencing a user, when an issue key is required",
+                    indoc! {r#"
+                        <?php
+
+                        // TODO(@azjezz) Remove once the legacy API is gone.
+                        function legacy(): void {}
+                    "#},
+                )
+                .with_option(REFERENCE_PATTERN, Value::String(r"[A-Z][A-Z0-9_]*-\d+".to_string())),
+            )
+            .with_example(RuleUsageExample::invalid(
+                "A task comment whose expiry date has passed",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * FIXME(PROJ-123, 2020-01-31) Handle the timeout.
+                     */
+                    function fetch(): void {}
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Program(program) = node else { return LintDirective::Abort };
+
+        let tags = match context.option(TAGS).and_then(|tags| tags.as_array()) {
+            Some(tags) => tags.iter().filter_map(|tag| tag.as_str()).map(str::to_ascii_lowercase).collect::<Vec<_>>(),
+            None => TAGS_DEFAULT.iter().map(|tag| tag.to_ascii_lowercase()).collect::<Vec<_>>(),
+        };
+
+        let pattern = context.option(REFERENCE_PATTERN).and_then(|pattern| pattern.as_str());
+        let pattern = pattern.unwrap_or(REFERENCE_PATTERN_DEFAULT).to_string();
+        let reference_regex = match Regex::new(&format!("^(?:{})$", pattern)) {
+            Ok(regex) => regex,
+            Err(error) => {
+                tracing::warn!("Ignoring invalid issue reference pattern `{}`: {}", pattern, error);
+
+                return LintDirective::Abort;
+            }
+        };
+
+        let today = get_today();
+
+        for trivia in program.trivia.iter() {
+            if !trivia.kind.is_comment() {
+                continue;
+            }
+
+            for line in comment_lines(trivia, context.interner) {
+                let line = line.trim_start();
+                let lowercase = line.to_ascii_lowercase();
+                let Some(tag) = tags.iter().find(|tag| {
+                    lowercase.starts_with(tag.as_str())
+                        && !lowercase[tag.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+                }) else {
+                    continue;
+                };
+
+                let name = &line[..tag.len()];
+                let arguments = line[tag.len()..]
+                    .strip_prefix('(')
+                    .and_then(|rest| rest.split_once(')'))
+                    .map(|(arguments, _)| arguments.split(',').map(str::trim).collect::<Vec<_>>())
+                    .unwrap_or_default();
+
+                let reference = arguments.first().copied().unwrap_or_default();
+                if !reference_regex.is_match(reference) {
+                    context.report(
+                        Issue::new(context.level(), format!("`{}` comment should reference an issue.", name))
+                            .with_annotation(Annotation::primary(trivia.span))
+                            .with_note(format!("The issue reference must match the pattern `{}`.", pattern))
+                            .with_help(format!(
+                                "Add an issue reference to the comment, e.g. `{}(PROJ-123)`, optionally followed by an expiry date, e.g. `{}(PROJ-123, 2025-06-30)`.",
+                                name, name
+                            )),
+                    );
+
+                    continue;
+                }
+
+                let Some(expiry) = arguments.get(1).copied().filter(|expiry| !expiry.is_empty()) else {
+                    continue;
+                };
+
+                match parse_date(expiry) {
+                    Some(date) if date < today => {
+                        context.report(
+                            Issue::new(Level::Error, format!("`{}` comment expired on {}.", name, expiry))
+                                .with_annotation(Annotation::primary(trivia.span))
+                                .with_help(format!(
+                                    "Address the task tracked by `{}`, or postpone its expiry date.",
+                                    reference
+                                )),
+                        );
+                    }
+                    Some(_) => {}
+                    None => {
+                        context.report(
+                            Issue::new(
+                                context.level(),
+                                format!("`{}` comment has an invalid expiry date `{}`.", name, expiry),
+                            )
+                            .with_annotation(Annotation::primary(trivia.span))
+                            .with_help("Write the expiry date in the `YYYY-MM-DD` format, e.g. `2025-06-30`."),
+                        );
+                    }
+                }
+            }
+        }
+
+        LintDirective::Abort
+    }
+}
+
+/// Parses a date in the `YYYY-MM-DD` format into its year, month, and day.
+fn parse_date(date: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next().filter(|year| year.len() == 4)?.parse().ok()?;
+    let month = parts.next().filter(|month| month.len() == 2)?.parse().ok()?;
+    let day = parts.next().filter(|day| day.len() == 2)?.parse().ok()?;
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    Some((year, month, day))
+}
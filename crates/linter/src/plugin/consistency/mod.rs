@@ -1,10 +1,16 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::consistency::rules::array_syntax::ArraySyntaxRule;
+use crate::plugin::consistency::rules::explicit_visibility::ExplicitVisibilityRule;
 use crate::plugin::consistency::rules::lowercase_hint::LowercaseHintRule;
 use crate::plugin::consistency::rules::lowercase_keyword::LowercaseKeywordRule;
 use crate::plugin::consistency::rules::no_function_aliases::NoFunctionAliasesRule;
+use crate::plugin::consistency::rules::no_short_opening_tag::NoShortOpeningTagRule;
 use crate::plugin::consistency::rules::no_tag_pair_terminator::NoTagPairTerminatorRule;
 use crate::plugin::consistency::rules::require_block_statement_body::RequireBlockStatementBodyRule;
+use crate::plugin::consistency::rules::string_style::StringStyleRule;
+use crate::plugin::consistency::rules::union_type_order::UnionTypeOrderRule;
+use crate::plugin::consistency::rules::yoda_conditions::YodaConditionsRule;
 
 use crate::plugin::Plugin;
 use crate::rule::Rule;
@@ -20,6 +26,7 @@ impl Plugin for ConsistencyPlugin {
             name: "Consistency",
             description: "Provides rules that enforce consistent coding standards.",
             enabled_by_default: true,
+            category: RuleCategory::Style,
         }
     }
 
@@ -29,8 +36,13 @@ impl Plugin for ConsistencyPlugin {
             Box::new(LowercaseHintRule),
             Box::new(LowercaseKeywordRule),
             Box::new(NoFunctionAliasesRule),
+            Box::new(NoShortOpeningTagRule),
             Box::new(NoTagPairTerminatorRule),
             Box::new(RequireBlockStatementBodyRule),
+            Box::new(ExplicitVisibilityRule),
+            Box::new(YodaConditionsRule),
+            Box::new(UnionTypeOrderRule),
+            Box::new(StringStyleRule),
         ]
     }
 }
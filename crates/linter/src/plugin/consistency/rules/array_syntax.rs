@@ -2,9 +2,12 @@ use indoc::indoc;
 use toml::Value;
 
 use mago_ast::*;
+use mago_fixer::FixPlan;
 use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
 use mago_reporting::*;
 use mago_span::HasSpan;
+use mago_span::Span;
 
 use crate::context::LintContext;
 use crate::definition::RuleDefinition;
@@ -24,10 +27,14 @@ const SYNTAX_DEFAULT: &str = SYNTAX_SHORT;
 impl Rule for ArraySyntaxRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Array Syntax", Level::Note)
+            .fixable()
             .with_description(indoc! {"
             Suggests using the short array syntax `[..]` instead of the long array syntax `array(..)`,
             or vice versa, depending on the configuration. The short array syntax is more concise and
             is the preferred way to define arrays in PHP.
+
+            With the short syntax, destructuring with `list(..)` is also reported in favor of `[..]`,
+            available since PHP 7.1.
         "})
             .with_option(RuleOptionDefinition {
                 name: SYNTAX,
@@ -69,6 +76,17 @@ impl Rule for ArraySyntaxRule {
                     "#},
                 )
             })
+            .with_example({
+                RuleUsageExample::invalid(
+                    "Using `list(..)` for destructuring when `syntax=short` is the default",
+                    indoc! {r#"
+                        <?php
+
+                        // By default, 'short' is enforced, so list(...) triggers a warning:
+                        list($a, $b) = [1, 2];
+                    "#},
+                )
+            })
             .with_example(
                 RuleUsageExample::invalid(
                     "Using short array syntax when `syntax=long` is configured",
@@ -99,10 +117,27 @@ impl Rule for ArraySyntaxRule {
                     .with_help("Use the short array syntax `[..]` instead");
 
                 context.propose(issue, |plan| {
-                    plan.replace(arr.array.span.join(arr.left_parenthesis).to_range(), "[", SafetyClassification::Safe);
+                    replace_keyword(plan, context.program, arr.array.span, arr.left_parenthesis);
                     plan.replace(arr.right_parenthesis.to_range(), "]", SafetyClassification::Safe);
                 });
             }
+            Node::List(list) => {
+                let preferred_syntax = context.option(SYNTAX).and_then(|o| o.as_str()).unwrap_or(SYNTAX_DEFAULT);
+                if !preferred_syntax.eq_ignore_ascii_case(SYNTAX_SHORT) || context.php_version < PHPVersion::PHP71 {
+                    return LintDirective::default();
+                }
+
+                let issue = Issue::new(context.level(), "Short list syntax `[..]` is preferred over `list(..)`.")
+                    .with_annotation(
+                        Annotation::primary(list.span()).with_message("This destructuring uses the `list(..)` syntax."),
+                    )
+                    .with_help("Use the short list syntax `[..]` instead");
+
+                context.propose(issue, |plan| {
+                    replace_keyword(plan, context.program, list.list.span, list.left_parenthesis);
+                    plan.replace(list.right_parenthesis.to_range(), "]", SafetyClassification::Safe);
+                });
+            }
             Node::Array(arr) => {
                 let preferred_syntax = context.option(SYNTAX).and_then(|o| o.as_str()).unwrap_or(SYNTAX_DEFAULT);
                 if !preferred_syntax.eq_ignore_ascii_case(SYNTAX_LONG) {
@@ -126,3 +161,20 @@ impl Rule for ArraySyntaxRule {
         LintDirective::default()
     }
 }
+
+/// Replaces the `array` or `list` keyword and its opening parenthesis with an opening bracket, keeping any
+/// comment between them.
+fn replace_keyword(plan: &mut FixPlan, program: &Program, keyword: Span, left_parenthesis: Span) {
+    let has_comment = program.trivia.iter().any(|trivia| {
+        trivia.kind.is_comment()
+            && trivia.span.start.offset >= keyword.end.offset
+            && trivia.span.end.offset <= left_parenthesis.start.offset
+    });
+
+    if has_comment {
+        plan.delete(keyword.to_range(), SafetyClassification::Safe);
+        plan.replace(left_parenthesis.to_range(), "[", SafetyClassification::Safe);
+    } else {
+        plan.replace(keyword.join(left_parenthesis).to_range(), "[", SafetyClassification::Safe);
+    }
+}
@@ -0,0 +1,146 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExplicitVisibilityRule;
+
+impl Rule for ExplicitVisibilityRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Explicit Visibility", Level::Warning)
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Enforces that methods, properties, and class constants declare their visibility explicitly.
+
+                Members without a visibility modifier are implicitly public, which can be easy to miss when
+                reading the code. Properties declared using the `var` keyword are also reported, as `var` is
+                an alias for `public`.
+
+                Class constants are only checked when targeting PHP 7.1 or later, as constant visibility
+                modifiers are not supported in earlier versions.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Members with explicit visibility",
+                indoc! {r#"
+                    <?php
+
+                    class Foo {
+                        public const BAR = 1;
+
+                        private int $baz = 0;
+
+                        public function qux(): void {}
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A method without explicit visibility",
+                indoc! {r#"
+                    <?php
+
+                    class Foo {
+                        function bar(): void {}
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A property declared using `var`",
+                indoc! {r#"
+                    <?php
+
+                    class Foo {
+                        var $bar;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A class constant without explicit visibility",
+                indoc! {r#"
+                    <?php
+
+                    class Foo {
+                        const BAR = 1;
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        match node {
+            Node::Method(method) => {
+                if method.modifiers.contains_visibility() {
+                    return LintDirective::default();
+                }
+
+                let name = context.lookup(&method.name.value).to_owned();
+                let position = method.modifiers.first_span().unwrap_or(method.function.span).start;
+
+                report_missing_visibility(context, "Method", &name, method.name.span(), position);
+            }
+            Node::Property(property) => {
+                let modifiers = property.modifiers();
+                if modifiers.contains_visibility() {
+                    return LintDirective::Prune;
+                }
+
+                let name = context.lookup(&property.first_variable().name).to_owned();
+
+                if let Some(var) = property.var() {
+                    let issue = Issue::new(context.level(), format!("Property `{}` is declared using `var`.", name))
+                        .with_annotation(Annotation::primary(var.span).with_message("The `var` keyword is used here."))
+                        .with_note("The `var` keyword is a legacy alias for `public`.")
+                        .with_help("Replace `var` with an explicit visibility modifier.");
+
+                    context.propose(issue, |plan| {
+                        plan.replace(var.span.to_range(), "public", SafetyClassification::Safe);
+                    });
+                } else if let Some(position) = modifiers.first_span().map(|span| span.start) {
+                    report_missing_visibility(context, "Property", &name, property.first_variable().span(), position);
+                }
+
+                return LintDirective::Prune;
+            }
+            Node::ClassLikeConstant(constant) => {
+                if context.php_version < PHPVersion::PHP71 || constant.modifiers.contains_visibility() {
+                    return LintDirective::Prune;
+                }
+
+                let Some(item) = constant.items.first() else {
+                    return LintDirective::Prune;
+                };
+
+                let name = context.lookup(&item.name.value).to_owned();
+                let position = constant.modifiers.first_span().unwrap_or(constant.r#const.span).start;
+
+                report_missing_visibility(context, "Constant", &name, item.name.span(), position);
+
+                return LintDirective::Prune;
+            }
+            _ => {}
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn report_missing_visibility(context: &mut LintContext<'_>, kind: &str, name: &str, span: Span, position: Position) {
+    let issue = Issue::new(context.level(), format!("{} `{}` does not declare its visibility.", kind, name))
+        .with_annotation(Annotation::primary(span).with_message(format!("{} `{}` is declared here.", kind, name)))
+        .with_note("Members without a visibility modifier are implicitly public.")
+        .with_help("Add an explicit visibility modifier, e.g. `public`, `protected`, or `private`.");
+
+    context.propose(issue, |plan| {
+        plan.insert(position.offset, "public ", SafetyClassification::Safe);
+    });
+}
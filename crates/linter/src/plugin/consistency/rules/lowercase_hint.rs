@@ -17,6 +17,7 @@ pub struct LowercaseHintRule;
 impl Rule for LowercaseHintRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Lowercase Hint", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Enforces that PHP type hints (like `void`, `bool`, `int`, `float`, etc.) be written
                 in lowercase. Using uppercase or mixed case is discouraged for consistency
@@ -17,6 +17,7 @@ pub struct LowercaseKeywordRule;
 impl Rule for LowercaseKeywordRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Lowercase Keyword", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                    Enforces that PHP keywords (like `if`, `else`, `return`, `function`, etc.) be written
                    in lowercase. Using uppercase or mixed case is discouraged for consistency
@@ -1,6 +1,11 @@
 pub mod array_syntax;
+pub mod explicit_visibility;
 pub mod lowercase_hint;
 pub mod lowercase_keyword;
 pub mod no_function_aliases;
+pub mod no_short_opening_tag;
 pub mod no_tag_pair_terminator;
 pub mod require_block_statement_body;
+pub mod string_style;
+pub mod union_type_order;
+pub mod yoda_conditions;
@@ -17,6 +17,7 @@ pub struct NoFunctionAliasesRule;
 impl Rule for NoFunctionAliasesRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("No Function Aliases", Level::Note)
+            .fixable()
             .with_description(indoc! {"
                 Detects usage of function aliases (e.g., `diskfreespace` instead of `disk_free_space`)
                 and suggests calling the canonical (original) function name instead.
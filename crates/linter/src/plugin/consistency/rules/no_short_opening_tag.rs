@@ -0,0 +1,86 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NoShortOpeningTagRule;
+
+impl Rule for NoShortOpeningTagRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Short Opening Tag", Level::Warning)
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Disallows the use of the short opening tag `<?`, which is only recognized by PHP when the
+                `short_open_tag` directive is enabled, and recommends the full opening tag `<?php` instead.
+
+                Short opening tags directly followed by an identifier, such as `<?xml`, are not reported, as they are
+                most likely part of the inline content, e.g. an XML declaration, rather than opening a PHP block; such
+                files can be parsed by disabling the `short_open_tag` configuration option.
+
+                Codebases relying on short opening tags can accept them by disabling this rule.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using the full opening tag",
+                indoc! {r#"
+                    <?php
+
+                    echo "Hello World";
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "Using the echo tag",
+                indoc! {r#"
+                    <p><?= $message ?></p>
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "Using an XML declaration",
+                indoc! {r#"
+                    <?xml version="1.0" encoding="UTF-8"?>
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using the short opening tag",
+                indoc! {r#"
+                    <?
+
+                    echo "Hello World";
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::ShortOpeningTag(tag) = node else { return LintDirective::default() };
+
+        let next_character = context.module.source.content[tag.span().end.offset..].chars().next();
+        if next_character.is_some_and(|character| character.is_alphanumeric() || character == '_') {
+            // e.g. `<?xml version="1.0"?>`, which is not meant to be PHP code.
+            return LintDirective::default();
+        }
+
+        let issue = Issue::new(context.level(), "Short opening tag `<?` should not be used.")
+            .with_annotation(Annotation::primary(tag.span()).with_message("This is a short opening tag."))
+            .with_note("Short opening tags are only recognized when the `short_open_tag` directive is enabled.")
+            .with_help("Replace the short opening tag `<?` with `<?php`.");
+
+        // The full opening tag must be followed by whitespace, unlike the short one, e.g. `<?$a = 1;`.
+        let replacement = if next_character.is_none_or(char::is_whitespace) { "<?php" } else { "<?php " };
+
+        // Files relying on `short_open_tag` being disabled are changed by the fix, so it is not safe.
+        context.propose(issue, |plan| {
+            plan.replace(tag.span().to_range(), replacement, SafetyClassification::PotentiallyUnsafe);
+        });
+
+        LintDirective::default()
+    }
+}
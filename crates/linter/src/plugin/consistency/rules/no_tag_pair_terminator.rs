@@ -17,6 +17,7 @@ pub struct NoTagPairTerminatorRule;
 impl Rule for NoTagPairTerminatorRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("No Tag Pair Terminator", Level::Note)
+            .fixable()
             .with_description(indoc! {"
                    Discourages the use of `?><?php` as a statement terminator. Recommends using a semicolon
                    (`;`) instead for clarity and consistency.
@@ -17,6 +17,7 @@ pub struct RequireBlockStatementBodyRule;
 impl Rule for RequireBlockStatementBodyRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Require Block Statement Body", Level::Note)
+            .fixable()
             .with_description(indoc! {"
                 Enforces that loop bodies (`for`, `while`, `foreach`) are enclosed in braces `{}`.
                 Using single statements without braces can lead to confusion or errors if new
@@ -0,0 +1,279 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct StringStyleRule;
+
+const STYLE: &str = "style";
+const STYLE_INTERPOLATION: &str = "interpolation";
+const STYLE_CONCATENATION: &str = "concatenation";
+const STYLE_DEFAULT: &str = STYLE_INTERPOLATION;
+const THRESHOLD: &str = "threshold";
+const THRESHOLD_DEFAULT: i64 = 3;
+
+impl Rule for StringStyleRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("String Style")
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Enforces a consistent style for building strings from values, either interpolation or
+                concatenation, depending on the configuration.
+
+                With the `interpolation` style, chains of at least `threshold` concatenated operands,
+                mixing strings and values, should use interpolation or `sprintf` instead. With the
+                `concatenation` style, interpolated strings should use concatenation instead.
+
+                Only strings built from string literals and plain variables are fixed automatically. Fixes
+                to concatenation are potentially unsafe, as the concatenated values may bind to operators of
+                higher precedence surrounding the string, e.g. `+`.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: STYLE,
+                r#type: "string",
+                description: "The style to enforce. Can be either `interpolation` or `concatenation`.",
+                default: Value::String(STYLE_DEFAULT.to_string()),
+            })
+            .with_option(RuleOptionDefinition {
+                name: THRESHOLD,
+                r#type: "integer",
+                description: "The minimum number of operands of a concatenation chain reported with the `interpolation` style.",
+                default: Value::Integer(THRESHOLD_DEFAULT),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Using interpolation",
+                indoc! {r#"
+                    <?php
+
+                    $message = "Hello, {$name}! You have {$count} new messages.";
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "Using a short concatenation",
+                indoc! {r#"
+                    <?php
+
+                    $greeting = 'Hello, ' . $name;
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "Using concatenation",
+                    indoc! {r#"
+                        <?php
+
+                        $message = 'Hello, ' . $name . '! You have ' . $count . ' new messages.';
+                    "#},
+                )
+                .with_option(STYLE, Value::String(STYLE_CONCATENATION.to_string())),
+            )
+            .with_example(RuleUsageExample::invalid(
+                "Using a long concatenation chain",
+                indoc! {r#"
+                    <?php
+
+                    $message = 'Hello, ' . $name . '! You have ' . $count . ' new messages.';
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::invalid(
+                    "Using interpolation",
+                    indoc! {r#"
+                        <?php
+
+                        $message = "Hello, {$name}!";
+                    "#},
+                )
+                .with_option(STYLE, Value::String(STYLE_CONCATENATION.to_string())),
+            )
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let style = context.option(STYLE).and_then(|o| o.as_str()).unwrap_or(STYLE_DEFAULT);
+
+        match node {
+            Node::Binary(binary) if binary.operator.is_concatenation() => {
+                if !style.eq_ignore_ascii_case(STYLE_INTERPOLATION) {
+                    return LintDirective::default();
+                }
+
+                let mut operands = vec![];
+                collect_operands(&binary.lhs, &mut operands);
+                collect_operands(&binary.rhs, &mut operands);
+
+                let threshold = context.option(THRESHOLD).and_then(|o| o.as_integer()).unwrap_or(THRESHOLD_DEFAULT);
+                if (operands.len() as i64) < threshold {
+                    return LintDirective::default();
+                }
+
+                let is_string = |operand: &&Expression| matches!(operand, Expression::Literal(Literal::String(_)));
+                if !operands.iter().any(is_string) || operands.iter().all(is_string) {
+                    return LintDirective::default();
+                }
+
+                let issue = Issue::new(context.level(), "String concatenation chain should use interpolation.")
+                    .with_annotation(
+                        Annotation::primary(binary.span())
+                            .with_message(format!("This string is concatenated from {} operands.", operands.len())),
+                    )
+                    .with_help("Use an interpolated string, e.g. `\"Hello, {$name}!\"`, or `sprintf` instead.");
+
+                match to_interpolated_string(context, &operands) {
+                    Some(replacement) => {
+                        context.propose(issue, |plan| {
+                            plan.replace(binary.span().to_range(), replacement, SafetyClassification::Safe)
+                        });
+                    }
+                    None => {
+                        context.report(issue);
+                    }
+                }
+
+                // The chain is reported as a whole, the shorter chains it contains are not.
+                LintDirective::Prune
+            }
+            Node::CompositeString(CompositeString::Interpolated(string)) => {
+                if !style.eq_ignore_ascii_case(STYLE_CONCATENATION) {
+                    return LintDirective::default();
+                }
+
+                if string.parts.iter().all(|part| matches!(part, StringPart::Literal(_))) {
+                    return LintDirective::default();
+                }
+
+                let issue = Issue::new(context.level(), "Interpolated string should use concatenation.")
+                    .with_annotation(
+                        Annotation::primary(string.span()).with_message("This string interpolates values."),
+                    )
+                    .with_help("Concatenate the values instead, e.g. `'Hello, ' . $name . '!'`.");
+
+                match to_concatenation(context, string) {
+                    Some(replacement) => {
+                        context.propose(issue, |plan| {
+                            plan.replace(string.span().to_range(), replacement, SafetyClassification::PotentiallyUnsafe)
+                        });
+                    }
+                    None => {
+                        context.report(issue);
+                    }
+                }
+
+                LintDirective::default()
+            }
+            _ => LintDirective::default(),
+        }
+    }
+}
+
+/// Collects the operands of a chain of concatenations, from left to right.
+fn collect_operands<'a>(expression: &'a Expression, operands: &mut Vec<&'a Expression>) {
+    match expression {
+        Expression::Binary(binary) if binary.operator.is_concatenation() => {
+            collect_operands(&binary.lhs, operands);
+            collect_operands(&binary.rhs, operands);
+        }
+        _ => operands.push(expression),
+    }
+}
+
+/// Converts a chain of string literals and plain variables to an interpolated string, returning `None`
+/// if the chain contains other operands, or literals that cannot be safely converted.
+fn to_interpolated_string(context: &LintContext<'_>, operands: &[&Expression]) -> Option<String> {
+    let mut content = String::new();
+    for operand in operands {
+        match operand {
+            Expression::Literal(Literal::String(string)) => {
+                let raw = context.interner.lookup(&string.value);
+                let inner = raw.get(1..raw.len().checked_sub(1)?)?;
+
+                match string.kind {
+                    LiteralStringKind::DoubleQuoted => {
+                        // An unescaped trailing `$` would turn the following variable into `${...}`, and a trailing
+                        // `\u` into a unicode escape sequence.
+                        if inner.ends_with('$') || inner.ends_with("\\u") {
+                            return None;
+                        }
+
+                        content.push_str(inner);
+                    }
+                    LiteralStringKind::SingleQuoted => {
+                        let mut characters = inner.chars().peekable();
+                        while let Some(character) = characters.next() {
+                            let character = match (character, characters.peek()) {
+                                ('\\', Some('\\' | '\'')) => characters.next()?,
+                                (character, _) => character,
+                            };
+
+                            match character {
+                                '\\' => content.push_str("\\\\"),
+                                '"' => content.push_str("\\\""),
+                                '$' => content.push_str("\\$"),
+                                character => content.push(character),
+                            }
+                        }
+                    }
+                }
+            }
+            Expression::Variable(Variable::Direct(variable)) => {
+                content.push('{');
+                content.push_str(context.interner.lookup(&variable.name));
+                content.push('}');
+            }
+            _ => return None,
+        }
+    }
+
+    Some(format!("\"{}\"", content))
+}
+
+/// Converts an interpolated string of literal parts and plain variables to a concatenation chain, returning `None`
+/// if the string interpolates other expressions, or has no literal part to keep the result a string.
+fn to_concatenation(context: &LintContext<'_>, string: &InterpolatedString) -> Option<String> {
+    let mut operands = vec![];
+    let mut has_literal = false;
+    for part in string.parts.iter() {
+        let expression = match part {
+            StringPart::Literal(literal) => {
+                let raw = context.interner.lookup(&literal.value);
+                if raw.is_empty() {
+                    continue;
+                }
+
+                has_literal = true;
+                if raw.contains(['\\', '\'']) {
+                    operands.push(format!("\"{}\"", raw));
+                } else {
+                    operands.push(format!("'{}'", raw));
+                }
+
+                continue;
+            }
+            StringPart::Expression(expression) => expression.as_ref(),
+            StringPart::BracedExpression(braced) => braced.expression.as_ref(),
+        };
+
+        let Expression::Variable(Variable::Direct(variable)) = expression else {
+            return None;
+        };
+
+        operands.push(context.interner.lookup(&variable.name).to_string());
+    }
+
+    if !has_literal {
+        return None;
+    }
+
+    Some(operands.join(" . "))
+}
@@ -0,0 +1,168 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnionTypeOrderRule;
+
+const NULL_POSITION: &str = "null-position";
+const NULL_POSITION_FIRST: &str = "first";
+const NULL_POSITION_LAST: &str = "last";
+const NULL_POSITION_DEFAULT: &str = NULL_POSITION_LAST;
+
+const SORT: &str = "sort";
+const SORT_DEFAULT: bool = false;
+
+impl Rule for UnionTypeOrderRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("Union Type Order")
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Enforces a canonical order for the members of union type hints, placing `null` either
+                first or last, and optionally sorting the remaining members alphabetically.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: NULL_POSITION,
+                r#type: "string",
+                description: "The position of `null` in union types. Can be either `first` or `last`.",
+                default: Value::String(NULL_POSITION_DEFAULT.to_string()),
+            })
+            .with_option(RuleOptionDefinition {
+                name: SORT,
+                r#type: "boolean",
+                description: "Whether to sort the members of union types, other than `null`, alphabetically.",
+                default: Value::Boolean(SORT_DEFAULT),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Placing `null` last in union types",
+                indoc! {r#"
+                    <?php
+
+                    function foo(string|int|null $value): Bar|null
+                    {
+                        return null;
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "Placing `null` first, and sorting union types when configured",
+                    indoc! {r#"
+                        <?php
+
+                        function foo(null|int|string $value): null|Bar
+                        {
+                            return null;
+                        }
+                    "#},
+                )
+                .with_option(NULL_POSITION, Value::String(NULL_POSITION_FIRST.to_string()))
+                .with_option(SORT, Value::Boolean(true)),
+            )
+            .with_example(RuleUsageExample::invalid(
+                "Placing `null` before other members when `null-position=last` is the default",
+                indoc! {r#"
+                    <?php
+
+                    function foo(null|string|int $value): void
+                    {
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::invalid(
+                    "Unsorted union types when `sort=true` is configured",
+                    indoc! {r#"
+                        <?php
+
+                        function foo(string|int $value): void
+                        {
+                        }
+                    "#},
+                )
+                .with_option(SORT, Value::Boolean(true)),
+            )
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Hint(hint @ Hint::Union(_)) = node else { return LintDirective::default() };
+
+        let null_position = context.option(NULL_POSITION).and_then(|o| o.as_str()).unwrap_or(NULL_POSITION_DEFAULT);
+        let null_first = null_position.eq_ignore_ascii_case(NULL_POSITION_FIRST);
+        let sort = context.option(SORT).and_then(|o| o.as_bool()).unwrap_or(SORT_DEFAULT);
+
+        let mut members = vec![];
+        collect_union_members(hint, &mut members);
+
+        let code = &context.module.source.content;
+        let texts = members.iter().map(|member| &code[member.span().to_range()]).collect::<Vec<_>>();
+
+        let mut ordered = texts.iter().enumerate().collect::<Vec<_>>();
+        if sort {
+            ordered.sort_by_key(|(_, text)| text.trim_start_matches('\\').to_ascii_lowercase());
+        }
+
+        ordered.sort_by_key(|(index, _)| match members[*index] {
+            Hint::Null(_) => !null_first,
+            _ => null_first,
+        });
+
+        if ordered.iter().enumerate().all(|(position, (index, _))| position == *index) {
+            // The nested unions are part of this one, and have already been checked.
+            return LintDirective::Prune;
+        }
+
+        let message = if sort {
+            "Union type members should be sorted alphabetically, with `null` placed "
+        } else {
+            "Union type members should be ordered with `null` placed "
+        };
+
+        let issue = Issue::new(context.level(), format!("{}{}.", message, if null_first { "first" } else { "last" }))
+            .with_annotation(
+                Annotation::primary(hint.span()).with_message("This union type is not in canonical order."),
+            )
+            .with_help("Reorder the members of this union type.");
+
+        // Only unions without comments between their members are fixed, to avoid losing them.
+        let has_comments = members.windows(2).any(|pair| {
+            let between = &code[pair[0].span().end.offset..pair[1].span().start.offset];
+
+            between.chars().any(|c| !c.is_whitespace() && c != '|')
+        });
+
+        if has_comments {
+            context.report(issue);
+        } else {
+            let replacement = ordered.iter().map(|(_, text)| **text).collect::<Vec<_>>().join("|");
+
+            context.propose(issue, |plan| {
+                plan.replace(hint.span().to_range(), replacement, SafetyClassification::Safe);
+            });
+        }
+
+        LintDirective::Prune
+    }
+}
+
+fn collect_union_members<'a>(hint: &'a Hint, members: &mut Vec<&'a Hint>) {
+    match hint {
+        Hint::Union(union) => {
+            collect_union_members(&union.left, members);
+            collect_union_members(&union.right, members);
+        }
+        _ => members.push(hint),
+    }
+}
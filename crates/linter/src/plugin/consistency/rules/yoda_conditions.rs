@@ -0,0 +1,179 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct YodaConditionsRule;
+
+const STYLE: &str = "style";
+const STYLE_YODA: &str = "yoda";
+const STYLE_NATURAL: &str = "natural";
+const STYLE_DEFAULT: &str = STYLE_NATURAL;
+
+impl Rule for YodaConditionsRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("Yoda Conditions")
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Enforces a consistent operand order in equality comparisons (`==`, `===`, `!=`, `!==`, `<>`).
+
+                When `style` is `natural`, the constant operand (e.g. a literal or a constant) must be on the
+                right side of the comparison, as in `$x === null`. When `style` is `yoda`, it must be on the
+                left side, as in `null === $x`.
+
+                Comparisons where both or neither of the operands are constant are ignored.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: STYLE,
+                r#type: "string",
+                description: "The comparison style to enforce. Can be either `natural` or `yoda`.",
+                default: Value::String(STYLE_DEFAULT.to_string()),
+            })
+            .with_example(RuleUsageExample::valid(
+                "A natural order comparison",
+                indoc! {r#"
+                    <?php
+
+                    if ($value === null) {
+                        echo 'No value.';
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "A Yoda condition when `style` is `yoda`",
+                    indoc! {r#"
+                        <?php
+
+                        if (null === $value) {
+                            echo 'No value.';
+                        }
+                    "#},
+                )
+                .with_option(STYLE, Value::String(STYLE_YODA.to_string())),
+            )
+            .with_example(RuleUsageExample::invalid(
+                "A Yoda condition",
+                indoc! {r#"
+                    <?php
+
+                    if (null === $value) {
+                        echo 'No value.';
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::invalid(
+                    "A natural order comparison when `style` is `yoda`",
+                    indoc! {r#"
+                        <?php
+
+                        if ($value === null) {
+                            echo 'No value.';
+                        }
+                    "#},
+                )
+                .with_option(STYLE, Value::String(STYLE_YODA.to_string())),
+            )
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Binary(binary) = node else { return LintDirective::default() };
+
+        if !matches!(
+            binary.operator,
+            BinaryOperator::Equal(_)
+                | BinaryOperator::NotEqual(_)
+                | BinaryOperator::Identical(_)
+                | BinaryOperator::NotIdentical(_)
+                | BinaryOperator::AngledNotEqual(_)
+        ) {
+            return LintDirective::default();
+        }
+
+        let lhs_is_constant = is_constant_operand(&binary.lhs);
+        let rhs_is_constant = is_constant_operand(&binary.rhs);
+        if lhs_is_constant == rhs_is_constant {
+            return LintDirective::default();
+        }
+
+        let style = context.option(STYLE).and_then(|o| o.as_str()).unwrap_or(STYLE_DEFAULT);
+        let issue = if style.eq_ignore_ascii_case(STYLE_YODA) {
+            if lhs_is_constant {
+                return LintDirective::default();
+            }
+
+            Issue::new(context.level(), "Comparison should use a Yoda condition.")
+                .with_annotation(
+                    Annotation::primary(binary.rhs.span()).with_message("This constant operand should be on the left."),
+                )
+                .with_help("Swap the operands so that the constant operand is on the left side of the comparison.")
+        } else {
+            if rhs_is_constant {
+                return LintDirective::default();
+            }
+
+            Issue::new(context.level(), "Comparison should not use a Yoda condition.")
+                .with_annotation(
+                    Annotation::primary(binary.lhs.span())
+                        .with_message("This constant operand should be on the right."),
+                )
+                .with_help("Swap the operands so that the constant operand is on the right side of the comparison.")
+        };
+
+        let issue = issue.with_annotation(
+            Annotation::secondary(binary.operator.span()).with_message("Equality comparison is performed here."),
+        );
+
+        // Only the operands are swapped, so comments surrounding the operator are preserved in place.
+        let code = &context.module.source.content;
+        let lhs_span = binary.lhs.span();
+        let rhs_span = binary.rhs.span();
+        let lhs_code = code[lhs_span.start.offset..lhs_span.end.offset].to_string();
+        let mut rhs_code = code[rhs_span.start.offset..rhs_span.end.offset].to_string();
+
+        // Low precedence expressions, e.g. `null === $x = foo()`, must be parenthesized once moved to the left.
+        if matches!(
+            binary.rhs.as_ref(),
+            Expression::Assignment(_) | Expression::Yield(_) | Expression::Construct(_) | Expression::Throw(_)
+        ) {
+            rhs_code = format!("({})", rhs_code);
+        }
+
+        context.propose(issue, |plan| {
+            plan.replace(lhs_span.to_range(), rhs_code, SafetyClassification::Safe);
+            plan.replace(rhs_span.to_range(), lhs_code, SafetyClassification::Safe);
+        });
+
+        LintDirective::default()
+    }
+}
+
+/// Returns `true` if the given operand is a constant value, such as a literal or a constant.
+fn is_constant_operand(expression: &Expression) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => is_constant_operand(&parenthesized.expression),
+        Expression::Literal(_) | Expression::ConstantAccess(_) | Expression::MagicConstant(_) => true,
+        Expression::Access(Access::ClassConstant(ClassConstantAccess {
+            class,
+            constant: ClassLikeConstantSelector::Identifier(_),
+            ..
+        })) => matches!(
+            class.as_ref(),
+            Expression::Identifier(_) | Expression::Static(_) | Expression::Self_(_) | Expression::Parent(_)
+        ),
+        _ => false,
+    }
+}
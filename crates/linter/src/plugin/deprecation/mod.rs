@@ -1,4 +1,5 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::deprecation::rules::php80::optional_parameter_before_required::OptionalParameterBeforeRequiredRule;
 use crate::plugin::deprecation::rules::php82::return_by_reference_from_void_function::ReturnByReferenceFromVoidFunctionRule;
 use crate::plugin::deprecation::rules::php84::implicitly_nullable_parameter::ImplicitlyNullableParameterRule;
@@ -18,6 +19,7 @@ impl Plugin for DeprecationPlugin {
             name: "Deprecation",
             description: "Provides rules that detect deprecated features in PHP code.",
             enabled_by_default: true,
+            category: RuleCategory::Compatibility,
         }
     }
 
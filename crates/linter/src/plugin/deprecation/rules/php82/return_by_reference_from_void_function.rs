@@ -17,7 +17,7 @@ pub struct ReturnByReferenceFromVoidFunctionRule;
 
 impl Rule for ReturnByReferenceFromVoidFunctionRule {
     fn get_definition(&self) -> RuleDefinition {
-        RuleDefinition::enabled("Return By Reference From Void Function", Level::Warning)
+        RuleDefinition::enabled("Return By Reference From Void Function", Level::Warning).fixable()
             .with_minimum_supported_php_version(PHPVersion::PHP82)
             .with_description(indoc! {"
                 Detects functions, methods, closures, arrow functions, and set property hooks that return by reference from a void function.
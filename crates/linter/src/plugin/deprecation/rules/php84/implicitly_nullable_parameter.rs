@@ -18,6 +18,7 @@ pub struct ImplicitlyNullableParameterRule;
 impl Rule for ImplicitlyNullableParameterRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Implicitly Nullable Parameter", Level::Warning)
+            .fixable()
             .with_minimum_supported_php_version(PHPVersion::PHP84)
             .with_description(indoc! {"
                 Detects parameters that are implicitly nullable and rely on a deprecated feature.
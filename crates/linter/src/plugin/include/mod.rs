@@ -0,0 +1,27 @@
+use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
+use crate::plugin::include::rules::circular_include::CircularIncludeRule;
+use crate::plugin::include::rules::missing_file::MissingFileRule;
+
+use crate::plugin::Plugin;
+use crate::rule::Rule;
+
+pub mod rules;
+
+#[derive(Debug)]
+pub struct IncludePlugin;
+
+impl Plugin for IncludePlugin {
+    fn get_definition(&self) -> PluginDefinition {
+        PluginDefinition {
+            name: "Include",
+            description: "Provides rules that check the files included with `include` and `require`.",
+            enabled_by_default: true,
+            category: RuleCategory::Correctness,
+        }
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(MissingFileRule), Box::new(CircularIncludeRule)]
+    }
+}
@@ -0,0 +1,60 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_project::include::normalize;
+use mago_reporting::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct CircularIncludeRule;
+
+impl Rule for CircularIncludeRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Circular Include", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects files that include each other, directly or through other files, using literal
+                `include` and `require` paths.
+
+                Each cycle is reported once, on the inclusion of the first file of the cycle, sorted by path.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Requiring a file that does not include the current file",
+                indoc! {r#"
+                    <?php
+
+                    require_once __DIR__ . '/functions.php';
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Program(_) = node else { return LintDirective::Abort };
+        let Some(file) = &context.module.source.path else { return LintDirective::Abort };
+
+        let Some(cycle) = context.include_graph.cycle_of(file) else { return LintDirective::Abort };
+        if cycle.first() != Some(&normalize(file)) {
+            return LintDirective::Abort;
+        }
+
+        let Some(edge) = context.include_graph.edges_of(file).iter().find(|edge| cycle.contains(&edge.target)) else {
+            return LintDirective::Abort;
+        };
+
+        let files = cycle.iter().map(|file| format!("`{}`", file.display())).collect::<Vec<_>>().join(", ");
+
+        context.report(
+            Issue::new(context.level(), "Circular file inclusion detected.")
+                .with_annotation(Annotation::primary(edge.span).with_message("This inclusion is part of a cycle."))
+                .with_note(format!("The following files include each other: {}.", files))
+                .with_help("Restructure the includes so that files do not depend on each other circularly."),
+        );
+
+        LintDirective::Abort
+    }
+}
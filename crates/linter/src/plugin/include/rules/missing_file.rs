@@ -0,0 +1,63 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct MissingFileRule;
+
+impl Rule for MissingFileRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Missing File", Level::Error)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects `include` and `require` constructs of files that do not exist.
+
+                Only literal paths are checked. Paths are resolved relative to the source root, or to the
+                directory of the including file when they start with `__DIR__` or `dirname(__FILE__)`.
+
+                As a missing file is not fatal for `include` and `include_once`, these are reported as
+                warnings at most.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Requiring a file relative to the current directory",
+                indoc! {r#"
+                    <?php
+
+                    require_once __DIR__ . '/bootstrap.php';
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Program(_) = node else { return LintDirective::Abort };
+        let Some(file) = &context.module.source.path else { return LintDirective::Abort };
+
+        let level = context.level();
+        let issues = context
+            .include_graph
+            .edges_of(file)
+            .iter()
+            .filter(|edge| !edge.exists)
+            .map(|edge| {
+                let level = if edge.kind.is_require() { level } else { level.min(Level::Warning) };
+
+                Issue::new(level, format!("Included file `{}` does not exist.", edge.target.display()))
+                    .with_annotation(Annotation::primary(edge.span).with_message("This file cannot be found."))
+                    .with_help("Ensure the path is correct, and relative to the source root or to `__DIR__`.")
+            })
+            .collect::<Vec<_>>();
+
+        for issue in issues {
+            context.report(issue);
+        }
+
+        LintDirective::Abort
+    }
+}
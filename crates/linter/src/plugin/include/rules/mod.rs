@@ -0,0 +1,2 @@
+pub mod circular_include;
+pub mod missing_file;
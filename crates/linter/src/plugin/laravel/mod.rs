@@ -1,4 +1,7 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
+use crate::plugin::laravel::rules::best_practices::no_env_outside_config::NoEnvOutsideConfigRule;
+use crate::plugin::laravel::rules::best_practices::no_facade_alias::NoFacadeAliasRule;
 use crate::plugin::laravel::rules::safety::no_request_all::NoRequestAllRule;
 
 use crate::plugin::Plugin;
@@ -15,10 +18,11 @@ impl Plugin for LaravelPlugin {
             name: "Laravel",
             description: "Provides rules that enforce best practices for Laravel applications.",
             enabled_by_default: false,
+            category: RuleCategory::Framework,
         }
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
-        vec![Box::new(NoRequestAllRule)]
+        vec![Box::new(NoRequestAllRule), Box::new(NoEnvOutsideConfigRule), Box::new(NoFacadeAliasRule)]
     }
 }
@@ -0,0 +1,73 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+const ENV_HELPER: &str = "env";
+const CONFIG_DIRECTORY: &str = "config";
+
+#[derive(Clone, Debug)]
+pub struct NoEnvOutsideConfigRule;
+
+impl Rule for NoEnvOutsideConfigRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Env Outside Config", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects calls to the `env()` helper outside of the `config` directory.
+
+                Once the configuration is cached using `php artisan config:cache`, the `.env` file is no
+                longer loaded, and all calls to `env()` outside of configuration files return `null`.
+                Environment values should be read in configuration files, and accessed using `config()`.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Reading a configuration value",
+                indoc! {r#"
+                    <?php
+
+                    $name = config('app.name');
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Reading an environment variable outside of the `config` directory",
+                indoc! {r#"
+                    <?php
+
+                    $name = env('APP_NAME');
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::FunctionCall(function_call) = node else { return LintDirective::default() };
+
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return LintDirective::default();
+        };
+
+        if !context.resolve_function_name(identifier).eq_ignore_ascii_case(ENV_HELPER) {
+            return LintDirective::default();
+        }
+
+        let source_name = context.lookup(&context.module.source.identifier.value());
+        if source_name.split(['/', '\\']).any(|component| component == CONFIG_DIRECTORY) {
+            return LintDirective::default();
+        }
+
+        let issue = Issue::new(context.level(), "The `env()` helper should only be used in configuration files.")
+            .with_annotation(Annotation::primary(function_call.span()).with_message("`env()` is called here."))
+            .with_note("When the configuration is cached, `env()` returns `null` outside of configuration files.")
+            .with_help("Read the environment variable in a configuration file, and use `config()` to access it.");
+
+        context.report(issue);
+
+        LintDirective::default()
+    }
+}
@@ -0,0 +1,125 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasPosition;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+const FACADE_NAMESPACE: &str = "Illuminate\\Support\\Facades";
+
+#[derive(Clone, Debug)]
+pub struct NoFacadeAliasRule;
+
+impl Rule for NoFacadeAliasRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Facade Alias", Level::Warning)
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects usages of global facade aliases, such as `\\Cache` or `\\DB`, instead of the facade classes
+                in the `Illuminate\\Support\\Facades` namespace.
+
+                Facade aliases are registered at runtime using `class_alias`, and are therefore invisible to static
+                analysis tools and IDEs. Importing the facade class makes the dependency explicit and resolvable.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using an imported facade",
+                indoc! {r#"
+                    <?php
+
+                    use Illuminate\Support\Facades\Cache;
+
+                    $value = Cache::get('key');
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using a global facade alias",
+                indoc! {r#"
+                    <?php
+
+                    $value = \Cache::get('key');
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let class = match node {
+            Node::StaticMethodCall(StaticMethodCall { class, .. })
+            | Node::StaticPropertyAccess(StaticPropertyAccess { class, .. })
+            | Node::ClassConstantAccess(ClassConstantAccess { class, .. }) => class,
+            _ => return LintDirective::default(),
+        };
+
+        let Expression::Identifier(identifier) = class.as_ref() else {
+            return LintDirective::default();
+        };
+
+        let name_id = context.module.names.get(&identifier.position());
+        let name = context.lookup(name_id);
+        let Some(facade) = FACADE_ALIASES.iter().find(|alias| alias.eq_ignore_ascii_case(name)) else {
+            return LintDirective::default();
+        };
+
+        // A class with the same name is declared in the global namespace, so this is not an alias.
+        if context.codebase.get_named_class_like(context.interner, name_id).is_some() {
+            return LintDirective::default();
+        }
+
+        let facade_class = format!("{}\\{}", FACADE_NAMESPACE, facade);
+        let issue =
+            Issue::new(context.level(), format!("Use the `{}` facade instead of the `{}` alias.", facade_class, name))
+                .with_annotation(
+                    Annotation::primary(identifier.span())
+                        .with_message(format!("Facade alias `{}` is used here.", name)),
+                )
+                .with_note("Facade aliases are registered at runtime, and cannot be resolved by static analysis.")
+                .with_help(format!("Import and use `{}` instead.", facade_class));
+
+        context.propose(issue, |plan| {
+            plan.replace(identifier.span().to_range(), format!("\\{}", facade_class), SafetyClassification::Safe)
+        });
+
+        LintDirective::default()
+    }
+}
+
+/// The facade aliases registered by default in Laravel applications.
+const FACADE_ALIASES: [&str; 30] = [
+    "App",
+    "Artisan",
+    "Auth",
+    "Blade",
+    "Bus",
+    "Cache",
+    "Config",
+    "Cookie",
+    "Crypt",
+    "DB",
+    "Event",
+    "File",
+    "Gate",
+    "Hash",
+    "Http",
+    "Lang",
+    "Log",
+    "Mail",
+    "Notification",
+    "Queue",
+    "Redirect",
+    "Request",
+    "Response",
+    "Route",
+    "Schema",
+    "Session",
+    "Storage",
+    "URL",
+    "Validator",
+    "View",
+];
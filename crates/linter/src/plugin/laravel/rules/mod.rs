@@ -1,3 +1,7 @@
+pub mod best_practices {
+    pub mod no_env_outside_config;
+    pub mod no_facade_alias;
+}
 pub mod safety {
     pub mod no_request_all;
 }
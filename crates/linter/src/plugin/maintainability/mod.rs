@@ -1,9 +1,11 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::maintainability::rules::cyclomatic_complexity::CyclomaticComplexityRule;
 use crate::plugin::maintainability::rules::excessive_parameter_list::ExcessiveParameterListRule;
 use crate::plugin::maintainability::rules::halstead::HalsteadRule;
 use crate::plugin::maintainability::rules::kan_defect::KanDefectRule;
 use crate::plugin::maintainability::rules::long_inheritance_chain::LongInheritanceChainRule;
+use crate::plugin::maintainability::rules::spelling::SpellingRule;
 use crate::plugin::maintainability::rules::too_many_enum_cases::TooManyEnumCasesRule;
 use crate::plugin::maintainability::rules::too_many_methods::TooManyMethodsRule;
 use crate::plugin::maintainability::rules::too_many_properties::TooManyPropertiesRule;
@@ -22,6 +24,7 @@ impl Plugin for MaintainabilityPlugin {
             name: "Maintainability",
             description: "Provides rules to ensure the maintainability of the codebase.",
             enabled_by_default: true,
+            category: RuleCategory::Style,
         }
     }
 
@@ -32,6 +35,7 @@ impl Plugin for MaintainabilityPlugin {
             Box::new(HalsteadRule),
             Box::new(KanDefectRule),
             Box::new(LongInheritanceChainRule),
+            Box::new(SpellingRule),
             Box::new(TooManyEnumCasesRule),
             Box::new(TooManyMethodsRule),
             Box::new(TooManyPropertiesRule),
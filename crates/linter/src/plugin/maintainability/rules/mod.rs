@@ -5,6 +5,7 @@ pub mod excessive_parameter_list;
 pub mod halstead;
 pub mod kan_defect;
 pub mod long_inheritance_chain;
+pub mod spelling;
 pub mod too_many_enum_cases;
 pub mod too_many_methods;
 pub mod too_many_properties;
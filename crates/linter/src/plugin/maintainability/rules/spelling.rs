@@ -0,0 +1,221 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::consts::MISSPELLINGS;
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+const WORDS: &str = "words";
+
+/// The minimum length of a word to be spell-checked.
+const MINIMUM_WORD_LENGTH: usize = 3;
+
+#[derive(Clone, Debug)]
+pub struct SpellingRule;
+
+impl Rule for SpellingRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("Spelling")
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects commonly misspelled words in comments, string literals, and the names of declared
+                symbols, such as classes, functions, methods, properties, constants, and parameters.
+
+                Identifiers are split into words on camelCase, PascalCase, and snake_case boundaries, and each
+                word is checked against a bundled list of common misspellings. Words that are intentionally
+                spelled differently in your project can be allowed using the `words` option.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: WORDS,
+                r#type: "array<string>",
+                description: "A list of words that should never be reported as misspelled (case-insensitive).",
+                default: Value::Array(vec![]),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Correctly spelled code",
+                indoc! {r#"
+                    <?php
+
+                    // Receive the message from the queue.
+                    function receiveMessage(string $address): string {
+                        return "Received a message from " . $address;
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "A misspelling allowed by the project word list",
+                    indoc! {r#"
+                        <?php
+
+                        function recieve(): void {}
+                    "#},
+                )
+                .with_option(WORDS, Value::Array(vec![Value::String("recieve".to_owned())])),
+            )
+            .with_example(RuleUsageExample::invalid(
+                "A misspelled comment",
+                indoc! {r#"
+                    <?php
+
+                    // Recieve the message from the queue.
+                    receive();
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A misspelled method name",
+                indoc! {r#"
+                    <?php
+
+                    final class Mailer {
+                        public function sendMesage(): void {}
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A misspelled string literal",
+                indoc! {r#"
+                    <?php
+
+                    throw new Exception('The operation was not succesful.');
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let interner = context.interner;
+
+        match node {
+            Node::Program(program) => {
+                for trivia in program.trivia.iter() {
+                    if trivia.kind.is_comment() {
+                        check_text(context, trivia.span, interner.lookup(&trivia.value), false);
+                    }
+                }
+            }
+            Node::LiteralString(literal_string) => {
+                check_text(context, literal_string.span, interner.lookup(&literal_string.value), true);
+            }
+            Node::Class(class) => check_identifier(context, &class.name),
+            Node::Interface(interface) => check_identifier(context, &interface.name),
+            Node::Trait(r#trait) => check_identifier(context, &r#trait.name),
+            Node::Enum(r#enum) => check_identifier(context, &r#enum.name),
+            Node::Function(function) => check_identifier(context, &function.name),
+            Node::Method(method) => check_identifier(context, &method.name),
+            Node::ConstantItem(item) => check_identifier(context, &item.name),
+            Node::ClassLikeConstantItem(item) => check_identifier(context, &item.name),
+            Node::EnumCaseItem(item) => check_identifier(context, item.name()),
+            Node::PropertyItem(item) => check_variable(context, item.variable()),
+            Node::FunctionLikeParameter(parameter) => check_variable(context, &parameter.variable),
+            _ => {}
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn check_identifier(context: &mut LintContext<'_>, identifier: &LocalIdentifier) {
+    let interner = context.interner;
+
+    check_text(context, identifier.span(), interner.lookup(&identifier.value), false);
+}
+
+fn check_variable(context: &mut LintContext<'_>, variable: &DirectVariable) {
+    let interner = context.interner;
+
+    check_text(context, variable.span(), interner.lookup(&variable.name), false);
+}
+
+/// Checks all words in the given text, which is expected to start at the beginning of the given span.
+///
+/// If `escapes` is `true`, the character following a backslash is not considered part of a word.
+fn check_text(context: &mut LintContext<'_>, span: Span, text: &str, escapes: bool) {
+    for (offset, word) in split_words(text, escapes) {
+        if word.len() < MINIMUM_WORD_LENGTH {
+            continue;
+        }
+
+        let lowercase = word.to_ascii_lowercase();
+        let Ok(index) = MISSPELLINGS.binary_search_by_key(&lowercase.as_str(), |(misspelling, _)| misspelling) else {
+            continue;
+        };
+
+        let is_allowed = context
+            .option(WORDS)
+            .and_then(|o| o.as_array())
+            .is_some_and(|words| words.iter().any(|w| w.as_str().is_some_and(|w| w.eq_ignore_ascii_case(word))));
+
+        if is_allowed {
+            continue;
+        }
+
+        let correction = MISSPELLINGS[index].1;
+        let issue = Issue::new(context.level(), format!("`{}` may be misspelled.", word))
+            .with_annotation(
+                Annotation::primary(span.subspan(offset, offset + word.len()))
+                    .with_message(format!("Did you mean `{}`?", correction)),
+            )
+            .with_help(format!(
+                "Replace `{}` with `{}`, or add it to the `{}` option of this rule if it is intentional.",
+                word, correction, WORDS
+            ));
+
+        context.report(issue);
+    }
+}
+
+/// Splits the given text into words, returning each word alongside its byte offset.
+///
+/// Words are sequences of ASCII letters, further split on camelCase and PascalCase boundaries,
+/// e.g. `parseHTTPResponse` yields `parse`, `HTTP`, and `Response`.
+fn split_words(text: &str, escapes: bool) -> Vec<(usize, &str)> {
+    let bytes = text.as_bytes();
+    let mut words = vec![];
+    let mut start = None;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        if !byte.is_ascii_alphabetic() {
+            if let Some(word_start) = start.take() {
+                words.push((word_start, &text[word_start..index]));
+            }
+
+            index += if escapes && byte == b'\\' { 2 } else { 1 };
+
+            continue;
+        }
+
+        match start {
+            None => start = Some(index),
+            Some(word_start) if byte.is_ascii_uppercase() => {
+                let previous = bytes[index - 1];
+                let next_is_lowercase = bytes.get(index + 1).is_some_and(|next| next.is_ascii_lowercase());
+
+                if previous.is_ascii_lowercase() || (previous.is_ascii_uppercase() && next_is_lowercase) {
+                    words.push((word_start, &text[word_start..index]));
+                    start = Some(index);
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    if let Some(word_start) = start {
+        words.push((word_start, &text[word_start..]));
+    }
+
+    words
+}
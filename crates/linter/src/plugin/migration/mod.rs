@@ -1,5 +1,8 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
+use crate::plugin::migration::rules::php80::null_safe_operator::NullSafeOperatorRule;
 use crate::plugin::migration::rules::php80::str_contains::StrContainsRule;
+use crate::plugin::migration::rules::php80::str_ends_with::StrEndsWithRule;
 use crate::plugin::migration::rules::php80::str_starts_with::StrStartsWithRule;
 use crate::plugin::migration::rules::php81::explicit_octal_notation::ExplicitOctalNotationRule;
 use crate::plugin::migration::rules::php82::readonly_class_promotion::ReadonlyClassPromotionRule;
@@ -18,6 +21,7 @@ impl Plugin for MigrationPlugin {
             name: "Migration",
             description: "Provides rules that help migrate code to newer PHP versions.",
             enabled_by_default: true,
+            category: RuleCategory::Compatibility,
         }
     }
 
@@ -26,6 +30,8 @@ impl Plugin for MigrationPlugin {
             // PHP 8.0
             Box::new(StrStartsWithRule),
             Box::new(StrContainsRule),
+            Box::new(StrEndsWithRule),
+            Box::new(NullSafeOperatorRule),
             // PHP 8.1
             Box::new(ExplicitOctalNotationRule),
             // PHP 8.2
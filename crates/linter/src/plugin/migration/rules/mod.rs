@@ -1,5 +1,9 @@
+mod utils;
+
 pub mod php80 {
+    pub mod null_safe_operator;
     pub mod str_contains;
+    pub mod str_ends_with;
     pub mod str_starts_with;
 }
 
@@ -0,0 +1,252 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NullSafeOperatorRule;
+
+/// A null check guarding an access chain, e.g. `$a !== null` or `isset($a->b)`.
+struct Guard<'a> {
+    code: &'a str,
+    is_variable: bool,
+    is_isset: bool,
+    is_matched: bool,
+}
+
+/// How an access chain reaches its next link from an object.
+enum Link {
+    Arrow(Span),
+    NullSafeArrow,
+    Index,
+}
+
+impl Rule for NullSafeOperatorRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("Null Safe Operator")
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_minimum_supported_php_version(PHPVersion::PHP80)
+            .with_description(indoc! {"
+                Detects conditional expressions guarding an access chain against `null`, such as
+                `$a !== null ? $a->b() : null`, or `isset($a->b) ? $a->b->c : null`, and suggests
+                replacing them with the null-safe operator `?->`, e.g. `$a?->b()`.
+
+                Guards checking plain variables with `!== null` are fixed safely. Guards checking properties,
+                or using `isset()`, are fixed as potentially unsafe, as the properties are no longer read
+                twice, and `isset()` also silences undefined variables and properties.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using the null-safe operator",
+                indoc! {r#"
+                    <?php
+
+                    $city = $user?->getAddress()?->city;
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Guarding a method call against `null`",
+                indoc! {r#"
+                    <?php
+
+                    $name = $user !== null ? $user->getName() : null;
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Guarding a property access chain with `isset()`",
+                indoc! {r#"
+                    <?php
+
+                    $city = isset($user->address) ? $user->address->city : null;
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Conditional(conditional) = node else { return LintDirective::default() };
+        let Some(then) = conditional.then.as_ref() else { return LintDirective::default() };
+
+        let code = &context.module.source.content;
+
+        // Either `guards ? chain : null`, or `!guards ? null : chain`.
+        let (chain, negated) = match (then.as_ref(), conditional.r#else.as_ref()) {
+            (chain, Expression::Literal(Literal::Null(_))) => (chain, false),
+            (Expression::Literal(Literal::Null(_)), chain) => (chain, true),
+            _ => return LintDirective::default(),
+        };
+
+        let mut guards = vec![];
+        if collect_guards(code, &conditional.condition, negated, &mut guards).is_none() {
+            return LintDirective::default();
+        }
+
+        let mut arrows = vec![];
+        let mut current = chain;
+        while let Some((object, link)) = get_link(current) {
+            let object_code = get_code(code, object.span());
+            let mut is_guarded = false;
+            for guard in guards.iter_mut().filter(|guard| guard.code == object_code) {
+                guard.is_matched = true;
+                is_guarded = true;
+            }
+
+            if is_guarded {
+                match link {
+                    Link::Arrow(arrow) => arrows.push(arrow),
+                    Link::NullSafeArrow => {}
+                    // There is no null-safe array access.
+                    Link::Index => return LintDirective::default(),
+                }
+            }
+
+            current = object;
+        }
+
+        if guards.iter().any(|guard| !guard.is_matched) {
+            return LintDirective::default();
+        }
+
+        let chain_code = get_code(code, chain.span());
+        let mut replacement = chain_code.to_string();
+        arrows.sort_by_key(|arrow| std::cmp::Reverse(arrow.start.offset));
+        for arrow in arrows.iter() {
+            replacement.insert(arrow.start.offset - chain.span().start.offset, '?');
+        }
+
+        let classification = if guards.iter().all(|guard| guard.is_variable && !guard.is_isset) {
+            SafetyClassification::Safe
+        } else {
+            SafetyClassification::PotentiallyUnsafe
+        };
+
+        let issue = Issue::new(
+            context.level(),
+            "Consider using the null-safe operator `?->` instead of guarding the access against `null`.",
+        )
+        .with_annotation(Annotation::primary(conditional.span()).with_message("This access is guarded against `null`."))
+        .with_help(format!("This expression can be simplified to `{}`.", replacement))
+        .with_note("The null-safe operator `?->` short-circuits the rest of the chain when its object is `null`.");
+
+        context.propose(issue, |plan| {
+            plan.replace(conditional.span().to_range(), replacement, classification);
+        });
+
+        LintDirective::default()
+    }
+}
+
+/// Collects the null checks of a condition, returning `None` if it checks anything else.
+///
+/// Unless negated, the checks must all hold for the chain to be evaluated, e.g. `$a !== null && isset($b)`;
+/// otherwise, any of them must fail for `null` to be returned, e.g. `$a === null || !isset($b)`.
+fn collect_guards<'a>(code: &'a str, condition: &Expression, negated: bool, guards: &mut Vec<Guard<'a>>) -> Option<()> {
+    match condition {
+        Expression::Parenthesized(parenthesized) => collect_guards(code, &parenthesized.expression, negated, guards),
+        Expression::Binary(binary) => {
+            let checked = match (&binary.operator, negated) {
+                (BinaryOperator::And(_) | BinaryOperator::LowAnd(_), false)
+                | (BinaryOperator::Or(_) | BinaryOperator::LowOr(_), true) => {
+                    collect_guards(code, &binary.lhs, negated, guards)?;
+
+                    return collect_guards(code, &binary.rhs, negated, guards);
+                }
+                (BinaryOperator::NotIdentical(_), false) | (BinaryOperator::Identical(_), true) => {
+                    match (binary.lhs.as_ref(), binary.rhs.as_ref()) {
+                        (checked, Expression::Literal(Literal::Null(_)))
+                        | (Expression::Literal(Literal::Null(_)), checked) => checked,
+                        _ => return None,
+                    }
+                }
+                _ => return None,
+            };
+
+            if !is_guardable(checked) {
+                return None;
+            }
+
+            guards.push(Guard {
+                code: get_code(code, checked.span()),
+                is_variable: matches!(checked, Expression::Variable(Variable::Direct(_))),
+                is_isset: false,
+                is_matched: false,
+            });
+
+            Some(())
+        }
+        Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Not(_), operand }) if negated => {
+            collect_guards(code, operand, false, guards)
+        }
+        Expression::Construct(Construct::Isset(isset)) if !negated => {
+            for value in isset.values.iter() {
+                if !is_guardable(value) {
+                    return None;
+                }
+
+                // `isset($a->b)` also checks that `$a` is not `null`.
+                let mut current = value;
+                loop {
+                    guards.push(Guard {
+                        code: get_code(code, current.span()),
+                        is_variable: matches!(current, Expression::Variable(Variable::Direct(_))),
+                        is_isset: true,
+                        is_matched: false,
+                    });
+
+                    let Some((object, _)) = get_link(current) else {
+                        break;
+                    };
+
+                    current = object;
+                }
+            }
+
+            Some(())
+        }
+        _ => None,
+    }
+}
+
+/// Determines whether the expression is a variable, or a chain of property accesses on a variable, which can be
+/// evaluated once instead of twice without side effects, barring magic methods.
+fn is_guardable(expression: &Expression) -> bool {
+    match expression {
+        Expression::Variable(Variable::Direct(_)) => true,
+        Expression::Access(Access::Property(PropertyAccess {
+            object,
+            property: ClassLikeMemberSelector::Identifier(_),
+            ..
+        }))
+        | Expression::Access(Access::NullSafeProperty(NullSafePropertyAccess {
+            object,
+            property: ClassLikeMemberSelector::Identifier(_),
+            ..
+        })) => is_guardable(object),
+        _ => false,
+    }
+}
+
+/// Returns the object of the last link of an access chain, along with how the link reaches it.
+fn get_link(expression: &Expression) -> Option<(&Expression, Link)> {
+    match expression {
+        Expression::Access(Access::Property(access)) => Some((&access.object, Link::Arrow(access.arrow))),
+        Expression::Access(Access::NullSafeProperty(access)) => Some((&access.object, Link::NullSafeArrow)),
+        Expression::Call(Call::Method(call)) => Some((&call.object, Link::Arrow(call.arrow))),
+        Expression::Call(Call::NullSafeMethod(call)) => Some((&call.object, Link::NullSafeArrow)),
+        Expression::ArrayAccess(access) => Some((&access.array, Link::Index)),
+        _ => None,
+    }
+}
+
+fn get_code(code: &str, span: Span) -> &str {
+    &code[span.start.offset..span.end.offset]
+}
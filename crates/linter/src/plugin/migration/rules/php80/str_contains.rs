@@ -21,10 +21,13 @@ pub struct StrContainsRule;
 impl Rule for StrContainsRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Str Contains", Level::Warning)
+            .fixable()
             .with_minimum_supported_php_version(PHPVersion::PHP80)
             .with_description(indoc! {"
                 Detects `strpos($a, $b) !== false` comparisons and suggests replacing them with `str_contains($a, $b)`
                 for improved readability and intent clarity.
+
+                Likewise, `strpos($a, $b) === false` comparisons are replaced with `!str_contains($a, $b)`.
             "})
             .with_example(RuleUsageExample::valid(
                 "Using `str_contains` instead of `strpos`",
@@ -52,18 +55,33 @@ impl Rule for StrContainsRule {
                     }
                 "#},
             ))
+            .with_example(RuleUsageExample::invalid(
+                "Using negated `strpos` comparison",
+                indoc! {r#"
+                    <?php
+
+                    $a = 'hello world';
+                    $b = 'world';
+
+                    if (strpos($a, $b) === false) {
+                        echo 'Not found';
+                    }
+                "#},
+            ))
     }
 
     fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
         let Node::Binary(binary) = node else { return LintDirective::default() };
 
-        // Detect `strpos($a, $b) !== false`
-        if !matches!(
-            binary.operator,
-            BinaryOperator::NotIdentical(_) | BinaryOperator::NotEqual(_) | BinaryOperator::AngledNotEqual(_)
-        ) {
-            return LintDirective::default();
-        }
+        // Detect `strpos($a, $b) !== false`, or `strpos($a, $b) === false`
+        let negated = match binary.operator {
+            BinaryOperator::NotIdentical(_) | BinaryOperator::NotEqual(_) | BinaryOperator::AngledNotEqual(_) => false,
+            // `strpos($a, $b) == false` also holds when `$b` is found at the start of `$a`, so it is not considered.
+            BinaryOperator::Identical(_) => true,
+            _ => {
+                return LintDirective::default();
+            }
+        };
 
         let (left, call) = match (binary.lhs.as_ref(), binary.rhs.as_ref()) {
             (
@@ -93,20 +111,28 @@ impl Rule for StrContainsRule {
             "Consider replacing `strpos` with `str_contains` for improved readability and intent clarity.",
         )
         .with_annotation(Annotation::primary(binary.span()).with_message("This comparison can be simplified."))
-        .with_help("`strpos($a, $b) !== false` can be simplified to `str_contains($a, $b)`.")
+        .with_help(if negated {
+            "`strpos($a, $b) === false` can be simplified to `!str_contains($a, $b)`."
+        } else {
+            "`strpos($a, $b) !== false` can be simplified to `str_contains($a, $b)`."
+        })
         .with_note("Using `str_contains` makes the code easier to understand and more expressive.");
 
         context.propose(issue, |plan| {
+            if negated {
+                plan.insert(binary.span().start_position().offset, "!", SafetyClassification::Safe);
+            }
+
             let function_span = function_identifier.span();
 
             // Replace `strpos` with `str_contains`
             plan.replace(function_span.to_range(), STR_CONTAINS.to_string(), SafetyClassification::Safe);
 
-            // Remove `!== false` part
+            // Remove `!== false`, or `=== false` part
             if left {
-                plan.delete(binary.operator.span().join(binary.rhs.span()).to_range(), SafetyClassification::Safe);
+                plan.delete(binary.lhs.span().end.offset..binary.rhs.span().end.offset, SafetyClassification::Safe);
             } else {
-                plan.delete(binary.lhs.span().join(binary.operator.span()).to_range(), SafetyClassification::Safe);
+                plan.delete(binary.lhs.span().start.offset..binary.rhs.span().start.offset, SafetyClassification::Safe);
             }
         });
 
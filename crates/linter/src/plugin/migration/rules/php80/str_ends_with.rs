@@ -0,0 +1,106 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::migration::rules::utils::get_substr_comparison;
+use crate::rule::Rule;
+
+const STR_ENDS_WITH: &str = "str_ends_with";
+
+#[derive(Clone, Debug)]
+pub struct StrEndsWithRule;
+
+impl Rule for StrEndsWithRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Str Ends With", Level::Warning)
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_minimum_supported_php_version(PHPVersion::PHP80)
+            .with_description(indoc! {"
+                Detects `substr($a, -6) === '.world'` comparisons and suggests replacing them with
+                `str_ends_with($a, '.world')` for improved readability and intent clarity.
+
+                Only comparisons with non-empty string literals, whose length matches the offset, are detected.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using `str_ends_with` instead of `substr`",
+                indoc! {r#"
+                    <?php
+
+                    $a = 'hello.world';
+
+                    if (str_ends_with($a, '.world')) {
+                        echo 'Found';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "Using `substr` with an offset not matching the length of the string",
+                indoc! {r#"
+                    <?php
+
+                    $a = 'hello.world';
+
+                    if (substr($a, -5) === '.world') {
+                        echo 'Never found';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using `substr` comparison",
+                indoc! {r#"
+                    <?php
+
+                    $a = 'hello.world';
+
+                    if (substr($a, -6) === '.world') {
+                        echo 'Found';
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Binary(binary) = node else { return LintDirective::default() };
+
+        let Some(comparison) = get_substr_comparison(context, binary) else {
+            return LintDirective::default();
+        };
+
+        // `substr($a, -0)` returns the whole string, so empty strings are not considered.
+        if comparison.literal_length == 0
+            || comparison.offset != -(comparison.literal_length as i64)
+            || comparison.length.is_some_and(|length| length < comparison.literal_length as i64)
+        {
+            return LintDirective::default();
+        }
+
+        let issue = Issue::new(
+            context.level(),
+            "Consider replacing `substr` with `str_ends_with` for improved readability and intent clarity.",
+        )
+        .with_annotation(Annotation::secondary(binary.span()).with_message("This expression can be simplified."))
+        .with_help("`substr($a, -6) === '.world'` can be simplified to `str_ends_with($a, '.world')`.")
+        .with_note("Using `str_ends_with` makes the code easier to understand and more expressive.");
+
+        let code = &context.module.source.content;
+        let haystack = &code[comparison.haystack.span().start.offset..comparison.haystack.span().end.offset];
+        let literal = &code[comparison.literal.span().start.offset..comparison.literal.span().end.offset];
+        let replacement =
+            format!("{}{}({}, {})", if comparison.identical { "" } else { "!" }, STR_ENDS_WITH, haystack, literal);
+
+        context.propose(issue, |plan| {
+            plan.replace(binary.span().to_range(), replacement, SafetyClassification::Safe);
+        });
+
+        LintDirective::Prune
+    }
+}
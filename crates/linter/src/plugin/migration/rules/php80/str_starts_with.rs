@@ -10,6 +10,7 @@ use crate::context::LintContext;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
+use crate::plugin::migration::rules::utils::get_substr_comparison;
 use crate::rule::Rule;
 
 const STR_STARTS_WITH: &str = "str_starts_with";
@@ -21,10 +22,14 @@ pub struct StrStartsWithRule;
 impl Rule for StrStartsWithRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Str Starts With", Level::Warning)
+            .fixable()
             .with_minimum_supported_php_version(PHPVersion::PHP80)
             .with_description(indoc! {"
                 Detects `strpos($a, $b) === 0` comparisons and suggests replacing them with `str_starts_with($a, $b)`
                 for improved readability and intent clarity.
+
+                Comparisons such as `substr($a, 0, 5) === 'hello'` are also detected, as long as the length is
+                the length of the compared string literal.
             "})
             .with_example(RuleUsageExample::valid(
                 "Using `str_starts_with` instead of `strpos`",
@@ -52,11 +57,54 @@ impl Rule for StrStartsWithRule {
                     }
                 "#},
             ))
+            .with_example(RuleUsageExample::invalid(
+                "Using `substr` comparison",
+                indoc! {r#"
+                    <?php
+
+                    $a = 'hello world';
+
+                    if (substr($a, 0, 5) === 'hello') {
+                        echo 'Found';
+                    }
+                "#},
+            ))
     }
 
     fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
         let Node::Binary(binary) = node else { return LintDirective::default() };
 
+        if let Some(comparison) = get_substr_comparison(context, binary) {
+            if comparison.offset != 0 || comparison.length != Some(comparison.literal_length as i64) {
+                return LintDirective::default();
+            }
+
+            let issue = Issue::new(
+                context.level(),
+                "Consider replacing `substr` with `str_starts_with` for improved readability and intent clarity.",
+            )
+            .with_annotation(Annotation::secondary(binary.span()).with_message("This expression can be simplified."))
+            .with_help("`substr($a, 0, 5) === 'hello'` can be simplified to `str_starts_with($a, 'hello')`.")
+            .with_note("Using `str_starts_with` makes the code easier to understand and more expressive.");
+
+            let code = &context.module.source.content;
+            let haystack = &code[comparison.haystack.span().start.offset..comparison.haystack.span().end.offset];
+            let literal = &code[comparison.literal.span().start.offset..comparison.literal.span().end.offset];
+            let replacement = format!(
+                "{}{}({}, {})",
+                if comparison.identical { "" } else { "!" },
+                STR_STARTS_WITH,
+                haystack,
+                literal
+            );
+
+            context.propose(issue, |plan| {
+                plan.replace(binary.span().to_range(), replacement, SafetyClassification::Safe);
+            });
+
+            return LintDirective::Prune;
+        }
+
         let equal = match binary.operator {
             BinaryOperator::Identical(_) | BinaryOperator::Equal(_) => true,
             BinaryOperator::AngledNotEqual(_) | BinaryOperator::NotEqual(_) | BinaryOperator::NotIdentical(_) => false,
@@ -108,10 +156,10 @@ impl Rule for StrStartsWithRule {
 
             if left {
                 // delete the `=== 0` part
-                plan.delete(binary.operator.span().join(binary.rhs.span()).to_range(), SafetyClassification::Safe);
+                plan.delete(binary.lhs.span().end.offset..binary.rhs.span().end.offset, SafetyClassification::Safe);
             } else {
                 // delete the `0 ===` part
-                plan.delete(binary.lhs.span().join(binary.operator.span()).to_range(), SafetyClassification::Safe);
+                plan.delete(binary.lhs.span().start.offset..binary.rhs.span().start.offset, SafetyClassification::Safe);
             }
         });
 
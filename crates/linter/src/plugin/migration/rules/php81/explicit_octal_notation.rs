@@ -18,6 +18,7 @@ pub struct ExplicitOctalNotationRule;
 impl Rule for ExplicitOctalNotationRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Explicit Octal Notation", Level::Warning)
+            .fixable()
             .with_minimum_supported_php_version(PHPVersion::PHP81)
             .with_description(indoc! {"
                 Detects implicit octal numeral notation and suggests replacing it with explicit octal numeral notation.
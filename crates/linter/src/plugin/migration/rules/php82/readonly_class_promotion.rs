@@ -17,12 +17,15 @@ use crate::rule::Rule;
 const FINAL_ONLY: &str = "final-only";
 const FINAL_ONLY_DEFAULT: bool = false;
 
+const ALLOW_DYNAMIC_PROPERTIES: &str = "AllowDynamicProperties";
+
 #[derive(Clone, Debug)]
 pub struct ReadonlyClassPromotionRule;
 
 impl Rule for ReadonlyClassPromotionRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Readonly Class Promotion", Level::Warning)
+            .fixable()
             .with_minimum_supported_php_version(PHPVersion::PHP82)
             .with_description(indoc! {"
                 Detects classes that contain only readonly properties and suggests promoting them to readonly classes.
@@ -56,6 +59,18 @@ impl Rule for ReadonlyClassPromotionRule {
                     }
                 "#},
             ))
+            .with_example(RuleUsageExample::valid(
+                "Class allows dynamic properties",
+                indoc! {r#"
+                    <?php
+
+                    #[AllowDynamicProperties]
+                    class Foo {
+                        public readonly int $a;
+                        public readonly int $b;
+                    }
+                "#},
+            ))
             .with_example(RuleUsageExample::invalid(
                 "Class contains only readonly properties, but is not marked as readonly",
                 indoc! {r#"
@@ -96,6 +111,17 @@ impl Rule for ReadonlyClassPromotionRule {
             return LintDirective::default();
         }
 
+        // Readonly classes cannot allow dynamic properties.
+        let allows_dynamic_properties = class
+            .attribute_lists
+            .iter()
+            .flat_map(|attribute_list| attribute_list.attributes.iter())
+            .any(|attribute| context.lookup_name(&attribute.name).eq_ignore_ascii_case(ALLOW_DYNAMIC_PROPERTIES));
+
+        if allows_dynamic_properties {
+            return LintDirective::default();
+        }
+
         if !reflection.is_final && context.option(FINAL_ONLY).and_then(|c| c.as_bool()).unwrap_or(FINAL_ONLY_DEFAULT) {
             return LintDirective::default();
         }
@@ -0,0 +1,84 @@
+use mago_ast::*;
+
+use crate::context::LintContext;
+use crate::utils::get_string_value;
+
+const SUBSTR: &str = "substr";
+
+/// A strict comparison between a `substr($haystack, $offset[, $length])` call and a string literal.
+pub struct SubstrComparison<'a> {
+    pub haystack: &'a Expression,
+    pub offset: i64,
+    pub length: Option<i64>,
+    pub literal: &'a Expression,
+    /// The length, in bytes, of the value of the string literal.
+    pub literal_length: usize,
+    /// Whether the comparison is `===`, as opposed to `!==`.
+    pub identical: bool,
+}
+
+/// Returns the `substr()` comparison the given binary expression consists of, if any.
+///
+/// Only calls with positional arguments and literal offsets and lengths, compared to string literals
+/// whose length can be statically determined, are considered.
+pub fn get_substr_comparison<'a>(context: &LintContext<'_>, binary: &'a Binary) -> Option<SubstrComparison<'a>> {
+    let identical = match binary.operator {
+        BinaryOperator::Identical(_) => true,
+        BinaryOperator::NotIdentical(_) => false,
+        _ => return None,
+    };
+
+    let (call, literal) = match (binary.lhs.as_ref(), binary.rhs.as_ref()) {
+        (Expression::Call(Call::Function(call)), literal @ Expression::Literal(Literal::String(string)))
+        | (literal @ Expression::Literal(Literal::String(string)), Expression::Call(Call::Function(call))) => {
+            (call, (literal, string))
+        }
+        _ => return None,
+    };
+
+    let Expression::Identifier(function_identifier) = call.function.as_ref() else {
+        return None;
+    };
+
+    if !context.resolve_function_name(function_identifier).eq_ignore_ascii_case(SUBSTR) {
+        return None;
+    }
+
+    let mut arguments = vec![];
+    for argument in call.argument_list.arguments.iter() {
+        let Argument::Positional(PositionalArgument { ellipsis: None, value }) = argument else {
+            return None;
+        };
+
+        arguments.push(value);
+    }
+
+    let (haystack, offset, length) = match arguments.as_slice() {
+        [haystack, offset] => (*haystack, get_integer_value(offset)?, None),
+        [haystack, offset, length] => (*haystack, get_integer_value(offset)?, Some(get_integer_value(length)?)),
+        _ => return None,
+    };
+
+    let (literal, string) = literal;
+
+    Some(SubstrComparison {
+        haystack,
+        offset,
+        length,
+        literal,
+        literal_length: get_string_value(string, context.interner)?.len(),
+        identical,
+    })
+}
+
+/// Returns the value of an integer literal, optionally negated.
+fn get_integer_value(expression: &Expression) -> Option<i64> {
+    match expression {
+        Expression::Parenthesized(parenthesized) => get_integer_value(&parenthesized.expression),
+        Expression::Literal(Literal::Integer(LiteralInteger { value: Some(value), .. })) => i64::try_from(*value).ok(),
+        Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Negation(_), operand }) => {
+            get_integer_value(operand).map(|value| -value)
+        }
+        _ => None,
+    }
+}
@@ -8,10 +8,12 @@ pub mod best_practices;
 pub mod comment;
 pub mod consistency;
 pub mod deprecation;
+pub mod include;
 pub mod laravel;
 pub mod maintainability;
 pub mod migration;
 pub mod naming;
+pub mod performance;
 pub mod phpunit;
 pub mod redundancy;
 pub mod safety;
@@ -27,10 +29,12 @@ macro_rules! foreach_plugin {
         $do($crate::plugin::comment::CommentPlugin);
         $do($crate::plugin::consistency::ConsistencyPlugin);
         $do($crate::plugin::deprecation::DeprecationPlugin);
+        $do($crate::plugin::include::IncludePlugin);
         $do($crate::plugin::laravel::LaravelPlugin);
         $do($crate::plugin::maintainability::MaintainabilityPlugin);
         $do($crate::plugin::migration::MigrationPlugin);
         $do($crate::plugin::naming::NamingPlugin);
+        $do($crate::plugin::performance::PerformancePlugin);
         $do($crate::plugin::phpunit::PHPUnitPlugin);
         $do($crate::plugin::redundancy::RedundancyPlugin);
         $do($crate::plugin::safety::SafetyPlugin);
@@ -1,9 +1,12 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::naming::rules::class::ClassRule;
 use crate::plugin::naming::rules::constant::ConstantRule;
 use crate::plugin::naming::rules::r#enum::EnumRule;
 use crate::plugin::naming::rules::function::FunctionRule;
 use crate::plugin::naming::rules::interface::InterfaceRule;
+use crate::plugin::naming::rules::method::MethodRule;
+use crate::plugin::naming::rules::test_method::TestMethodRule;
 use crate::plugin::naming::rules::r#trait::TraitRule;
 
 use crate::plugin::Plugin;
@@ -20,17 +23,20 @@ impl Plugin for NamingPlugin {
             name: "Naming",
             description: "Provides rules that enforce naming conventions.",
             enabled_by_default: true,
+            category: RuleCategory::Style,
         }
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
         vec![
-            Box::new(ClassRule),
-            Box::new(ConstantRule),
-            Box::new(EnumRule),
-            Box::new(FunctionRule),
-            Box::new(InterfaceRule),
-            Box::new(TraitRule),
+            Box::new(ClassRule::default()),
+            Box::new(ConstantRule::default()),
+            Box::new(EnumRule::default()),
+            Box::new(FunctionRule::default()),
+            Box::new(InterfaceRule::default()),
+            Box::new(MethodRule::default()),
+            Box::new(TestMethodRule),
+            Box::new(TraitRule::default()),
         ]
     }
 }
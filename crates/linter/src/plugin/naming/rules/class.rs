@@ -1,4 +1,5 @@
 use indoc::indoc;
+use regex::Regex;
 use toml::Value;
 
 use mago_ast::*;
@@ -10,13 +11,21 @@ use crate::definition::RuleDefinition;
 use crate::definition::RuleOptionDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
+use crate::plugin::naming::rules::utils::PATTERN;
+use crate::plugin::naming::rules::utils::compile_pattern;
+use crate::plugin::naming::rules::utils::pattern_mismatch_issue;
+use crate::plugin::naming::rules::utils::pattern_option;
 use crate::rule::Rule;
+use crate::settings::RuleSettings;
 
 const PSR: &str = "psr";
 const PSR_DEFAULT: bool = true;
 
-#[derive(Clone, Copy, Debug)]
-pub struct ClassRule;
+#[derive(Clone, Debug, Default)]
+pub struct ClassRule {
+    /// The compiled `pattern` option, if configured.
+    pattern: Option<Regex>,
+}
 
 impl Rule for ClassRule {
     fn get_definition(&self) -> RuleDefinition {
@@ -25,6 +34,7 @@ impl Rule for ClassRule {
                 Detects class declarations that do not follow class naming convention.
                 Class names should be in class case, also known as PascalCase.
             "})
+            .with_option(pattern_option())
             .with_option(RuleOptionDefinition {
                 name: PSR,
                 r#type: "boolean",
@@ -72,13 +82,28 @@ impl Rule for ClassRule {
                 )
                 .with_option(PSR, Value::Boolean(true)),
             )
+            .with_example(
+                RuleUsageExample::invalid(
+                    "A class name not matching the configured pattern",
+                    indoc! {r#"
+                    <?php
+
+                    final class UserManager {}
+                "#},
+                )
+                .with_option(PATTERN, Value::String("^[A-Z][a-zA-Z]*(Service|Repository)$".to_owned())),
+            )
     }
 
     fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
         let Node::Class(class) = node else { return LintDirective::default() };
         let mut issues = vec![];
         let name = context.lookup(&class.name.value);
-        if !mago_casing::is_class_case(name) {
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(name) {
+                issues.push(pattern_mismatch_issue(context.level(), "Class", name, class.name.span(), pattern));
+            }
+        } else if !mago_casing::is_class_case(name) {
             let issue = Issue::new(context.level(), format!("Class name `{}` should be in class case.", name))
                 .with_annotations([
                     Annotation::primary(class.name.span()).with_message(format!("Class `{}` is declared here.", name))
@@ -117,4 +142,8 @@ impl Rule for ClassRule {
 
         LintDirective::default()
     }
+
+    fn configure(&mut self, settings: &RuleSettings) {
+        self.pattern = compile_pattern(settings);
+    }
 }
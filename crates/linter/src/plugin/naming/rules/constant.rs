@@ -1,4 +1,5 @@
 use indoc::indoc;
+use regex::Regex;
 
 use mago_ast::*;
 use mago_reporting::*;
@@ -8,10 +9,17 @@ use crate::context::LintContext;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
+use crate::plugin::naming::rules::utils::compile_pattern;
+use crate::plugin::naming::rules::utils::pattern_mismatch_issue;
+use crate::plugin::naming::rules::utils::pattern_option;
 use crate::rule::Rule;
+use crate::settings::RuleSettings;
 
-#[derive(Clone, Copy, Debug)]
-pub struct ConstantRule;
+#[derive(Clone, Debug, Default)]
+pub struct ConstantRule {
+    /// The compiled `pattern` option, if configured.
+    pattern: Option<Regex>,
+}
 
 impl Rule for ConstantRule {
     fn get_definition(&self) -> RuleDefinition {
@@ -20,6 +28,7 @@ impl Rule for ConstantRule {
                 Detects constant declarations that do not follow constant naming convention.
                 Constant names should be in constant case, also known as UPPER_SNAKE_CASE.
             "})
+            .with_option(pattern_option())
             .with_example(RuleUsageExample::valid(
                 "A constant name in constant case",
                 indoc! {r#"
@@ -55,7 +64,17 @@ impl Rule for ConstantRule {
             Node::Constant(constant) => {
                 for item in constant.items.iter() {
                     let name = context.lookup(&item.name.value);
-                    if !mago_casing::is_constant_case(name) {
+                    if let Some(pattern) = &self.pattern {
+                        if !pattern.is_match(name) {
+                            context.report(pattern_mismatch_issue(
+                                context.level(),
+                                "Constant",
+                                name,
+                                item.name.span(),
+                                pattern,
+                            ));
+                        }
+                    } else if !mago_casing::is_constant_case(name) {
                         context.report(
                             Issue::new(
                                 context.level(),
@@ -83,7 +102,17 @@ impl Rule for ConstantRule {
                 for item in class_like_constant.items.iter() {
                     let name = context.lookup(&item.name.value);
 
-                    if !mago_casing::is_constant_case(name) {
+                    if let Some(pattern) = &self.pattern {
+                        if !pattern.is_match(name) {
+                            context.report(pattern_mismatch_issue(
+                                context.level(),
+                                "Constant",
+                                name,
+                                item.name.span(),
+                                pattern,
+                            ));
+                        }
+                    } else if !mago_casing::is_constant_case(name) {
                         context.report(
                             Issue::new(
                                 context.level(),
@@ -110,4 +139,8 @@ impl Rule for ConstantRule {
             _ => LintDirective::default(),
         }
     }
+
+    fn configure(&mut self, settings: &RuleSettings) {
+        self.pattern = compile_pattern(settings);
+    }
 }
@@ -1,4 +1,5 @@
 use indoc::indoc;
+use regex::Regex;
 
 use mago_ast::*;
 use mago_reporting::*;
@@ -8,10 +9,17 @@ use crate::context::LintContext;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
+use crate::plugin::naming::rules::utils::compile_pattern;
+use crate::plugin::naming::rules::utils::pattern_mismatch_issue;
+use crate::plugin::naming::rules::utils::pattern_option;
 use crate::rule::Rule;
+use crate::settings::RuleSettings;
 
-#[derive(Clone, Copy, Debug)]
-pub struct EnumRule;
+#[derive(Clone, Debug, Default)]
+pub struct EnumRule {
+    /// The compiled `pattern` option, if configured.
+    pattern: Option<Regex>,
+}
 
 impl Rule for EnumRule {
     fn get_definition(&self) -> RuleDefinition {
@@ -20,6 +28,7 @@ impl Rule for EnumRule {
                 Detects enum declarations that do not follow class naming convention.
                 Enum names should be in class case, also known as PascalCase.
             "})
+            .with_option(pattern_option())
             .with_example(RuleUsageExample::valid(
                 "An enum name in class case",
                 indoc! {r#"
@@ -46,7 +55,11 @@ impl Rule for EnumRule {
         let name = context.lookup(&r#enum.name.value);
         let fqcn = context.lookup_name(&r#enum.name);
 
-        if !mago_casing::is_class_case(name) {
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(name) {
+                context.report(pattern_mismatch_issue(context.level(), "Enum", name, r#enum.name.span(), pattern));
+            }
+        } else if !mago_casing::is_class_case(name) {
             context.report(
                 Issue::new(context.level(), format!("Enum name `{}` should be in class case.", name))
                     .with_annotation(
@@ -72,4 +85,8 @@ impl Rule for EnumRule {
             LintDirective::Prune
         }
     }
+
+    fn configure(&mut self, settings: &RuleSettings) {
+        self.pattern = compile_pattern(settings);
+    }
 }
@@ -1,4 +1,5 @@
 use indoc::indoc;
+use regex::Regex;
 use toml::Value;
 
 use mago_ast::*;
@@ -10,15 +11,22 @@ use crate::definition::RuleDefinition;
 use crate::definition::RuleOptionDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
+use crate::plugin::naming::rules::utils::compile_pattern;
+use crate::plugin::naming::rules::utils::pattern_mismatch_issue;
+use crate::plugin::naming::rules::utils::pattern_option;
 use crate::rule::Rule;
+use crate::settings::RuleSettings;
 
 const CAMEL: &str = "camel";
 const CAMEL_DEFAULT: bool = false;
 const EITHER: &str = "either";
 const EITHER_DEFAULT: bool = false;
 
-#[derive(Clone, Copy, Debug)]
-pub struct FunctionRule;
+#[derive(Clone, Debug, Default)]
+pub struct FunctionRule {
+    /// The compiled `pattern` option, if configured.
+    pattern: Option<Regex>,
+}
 
 impl Rule for FunctionRule {
     fn get_definition(&self) -> RuleDefinition {
@@ -27,6 +35,7 @@ impl Rule for FunctionRule {
                 Detects function declarations that do not follow camel or snake naming convention.
                 Function names should be in camel case or snake case, depending on the configuration.
             "})
+            .with_option(pattern_option())
             .with_option(RuleOptionDefinition {
                 name: CAMEL,
                 r#type: "boolean",
@@ -100,7 +109,17 @@ impl Rule for FunctionRule {
         let camel_case = context.option(CAMEL).and_then(|v| v.as_bool()).unwrap_or(CAMEL_DEFAULT);
         let either_case = context.option(EITHER).and_then(|v| v.as_bool()).unwrap_or(EITHER_DEFAULT);
 
-        if either_case {
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(name) {
+                context.report(pattern_mismatch_issue(
+                    context.level(),
+                    "Function",
+                    name,
+                    function.name.span(),
+                    pattern,
+                ));
+            }
+        } else if either_case {
             if !mago_casing::is_camel_case(name) && !mago_casing::is_snake_case(name) {
                 context.report(
                     Issue::new(
@@ -166,4 +185,8 @@ impl Rule for FunctionRule {
 
         LintDirective::default()
     }
+
+    fn configure(&mut self, settings: &RuleSettings) {
+        self.pattern = compile_pattern(settings);
+    }
 }
@@ -0,0 +1,106 @@
+use indoc::indoc;
+use regex::Regex;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::naming::rules::utils::PATTERN;
+use crate::plugin::naming::rules::utils::compile_pattern;
+use crate::plugin::naming::rules::utils::pattern_mismatch_issue;
+use crate::plugin::naming::rules::utils::pattern_option;
+use crate::rule::Rule;
+use crate::settings::RuleSettings;
+
+#[derive(Clone, Debug, Default)]
+pub struct MethodRule {
+    /// The compiled `pattern` option, if configured.
+    pattern: Option<Regex>,
+}
+
+impl Rule for MethodRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Method", Level::Help)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects method declarations that do not follow method naming convention.
+                Method names should be in camel case. Magic methods, such as `__construct`, are ignored.
+            "})
+            .with_option(pattern_option())
+            .with_example(RuleUsageExample::valid(
+                "A method name in camel case",
+                indoc! {r#"
+                    <?php
+
+                    class Foo {
+                        public function __construct() {}
+
+                        public function getBar(): void {}
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A method name not in camel case",
+                indoc! {r#"
+                    <?php
+
+                    class Foo {
+                        public function get_bar(): void {}
+
+                        public function GetBaz(): void {}
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "A method name in snake case, with a pattern allowing snake case",
+                    indoc! {r#"
+                        <?php
+
+                        class FooTest {
+                            public function test_it_works(): void {}
+                        }
+                    "#},
+                )
+                .with_option(PATTERN, toml::Value::String("^[a-z][a-zA-Z0-9_]*$".to_owned())),
+            )
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Method(method) = node else { return LintDirective::default() };
+
+        let name = context.lookup(&method.name.value);
+        if name.starts_with("__") {
+            return LintDirective::default();
+        }
+
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(name) {
+                context.report(pattern_mismatch_issue(context.level(), "Method", name, method.name.span(), pattern));
+            }
+        } else if !mago_casing::is_camel_case(name) {
+            context.report(
+                Issue::new(context.level(), format!("Method name `{}` should be in camel case.", name))
+                    .with_annotation(
+                        Annotation::primary(method.name.span())
+                            .with_message(format!("Method `{}` is declared here.", name)),
+                    )
+                    .with_note(format!("The method name `{}` does not follow camel naming convention.", name))
+                    .with_help(format!(
+                        "Consider renaming it to `{}` to adhere to the naming convention.",
+                        mago_casing::to_camel_case(name)
+                    )),
+            );
+        }
+
+        LintDirective::default()
+    }
+
+    fn configure(&mut self, settings: &RuleSettings) {
+        self.pattern = compile_pattern(settings);
+    }
+}
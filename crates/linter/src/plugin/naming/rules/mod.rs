@@ -1,6 +1,10 @@
+mod utils;
+
 pub mod class;
 pub mod constant;
 pub mod r#enum;
 pub mod function;
 pub mod interface;
+pub mod method;
+pub mod test_method;
 pub mod r#trait;
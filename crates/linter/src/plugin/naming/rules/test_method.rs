@@ -0,0 +1,124 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::phpunit::rules::utils::get_test_method_candidates;
+use crate::rule::Rule;
+
+const PREFIX: &str = "prefix";
+const PREFIX_DEFAULT: &str = "test";
+
+#[derive(Clone, Copy, Debug)]
+pub struct TestMethodRule;
+
+impl Rule for TestMethodRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("Test Method")
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects public methods in test classes (classes whose name ends with `Test`, or that extend
+                a `TestCase`) that are not prefixed with `test`. Such methods are not picked up by the test
+                runner, unless they are marked as tests using an attribute.
+
+                Static methods, lifecycle methods (e.g. `setUp`), magic methods, data providers, and methods
+                with attributes (e.g. `#[Test]` or `#[DataProvider]`) are ignored.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: PREFIX,
+                r#type: "string",
+                description: "The prefix that test method names must start with.",
+                default: Value::String(PREFIX_DEFAULT.to_owned()),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Test methods prefixed with `test`, or marked with an attribute",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\Attributes\Test;
+                    use PHPUnit\Framework\TestCase;
+
+                    final class UserTest extends TestCase {
+                        protected function setUp(): void {}
+
+                        public function testItWorks(): void {}
+
+                        #[Test]
+                        public function itAlsoWorks(): void {}
+
+                        public static function provideUsers(): iterable { return []; }
+
+                        private function createUser(): void {}
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A public test method not prefixed with `test`",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class UserTest extends TestCase {
+                        public function itWorks(): void {}
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "Test methods using a custom prefix",
+                    indoc! {r#"
+                        <?php
+
+                        use PHPUnit\Framework\TestCase;
+
+                        final class UserTest extends TestCase {
+                            public function itWorks(): void {}
+                        }
+                    "#},
+                )
+                .with_option(PREFIX, Value::String("it".to_owned())),
+            )
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Class(class) = node else { return LintDirective::default() };
+
+        let prefix = context.option(PREFIX).and_then(|o| o.as_str()).unwrap_or(PREFIX_DEFAULT).to_owned();
+
+        for method in get_test_method_candidates(class, context) {
+            if !method.attribute_lists.is_empty() {
+                continue;
+            }
+
+            let name = context.lookup(&method.name.value);
+            if name.starts_with(&prefix) {
+                continue;
+            }
+
+            let suggestion = format!("{}{}", prefix, mago_casing::to_class_case(name));
+
+            context.report(
+                Issue::new(context.level(), format!("Test method `{}` should be prefixed with `{}`.", name, prefix))
+                    .with_annotation(
+                        Annotation::primary(method.name.span())
+                            .with_message(format!("Method `{}` is declared here.", name)),
+                    )
+                    .with_note("Public methods of test classes that are not prefixed are not run as tests.")
+                    .with_help(format!(
+                        "Consider renaming it to `{}`, marking it with the `#[Test]` attribute, or reducing its visibility if it is not a test.",
+                        suggestion
+                    )),
+            );
+        }
+
+        LintDirective::default()
+    }
+}
@@ -1,4 +1,5 @@
 use indoc::indoc;
+use regex::Regex;
 use toml::Value;
 
 use mago_ast::*;
@@ -10,13 +11,20 @@ use crate::definition::RuleDefinition;
 use crate::definition::RuleOptionDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
+use crate::plugin::naming::rules::utils::compile_pattern;
+use crate::plugin::naming::rules::utils::pattern_mismatch_issue;
+use crate::plugin::naming::rules::utils::pattern_option;
 use crate::rule::Rule;
+use crate::settings::RuleSettings;
 
 const PSR: &str = "psr";
 const PSR_DEFAULT: bool = true;
 
-#[derive(Clone, Copy, Debug)]
-pub struct TraitRule;
+#[derive(Clone, Debug, Default)]
+pub struct TraitRule {
+    /// The compiled `pattern` option, if configured.
+    pattern: Option<Regex>,
+}
 
 impl Rule for TraitRule {
     fn get_definition(&self) -> RuleDefinition {
@@ -25,6 +33,7 @@ impl Rule for TraitRule {
                 Detects trait declarations that do not follow class naming convention.
                 Trait names should be in class case and suffixed with `Trait`, depending on the configuration.
             "})
+            .with_option(pattern_option())
             .with_option(RuleOptionDefinition {
                 name: PSR,
                 r#type: "boolean",
@@ -81,7 +90,11 @@ impl Rule for TraitRule {
         let name = context.lookup(&r#trait.name.value);
         let fqcn = context.lookup_name(&r#trait.name);
 
-        if !mago_casing::is_class_case(name) {
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(name) {
+                issues.push(pattern_mismatch_issue(context.level(), "Trait", name, r#trait.name.span(), pattern));
+            }
+        } else if !mago_casing::is_class_case(name) {
             issues.push(
                 Issue::new(context.level(), format!("Trait name `{}` should be in class case.", name))
                     .with_annotations([
@@ -118,4 +131,8 @@ impl Rule for TraitRule {
 
         LintDirective::default()
     }
+
+    fn configure(&mut self, settings: &RuleSettings) {
+        self.pattern = compile_pattern(settings);
+    }
 }
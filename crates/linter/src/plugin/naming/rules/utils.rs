@@ -0,0 +1,44 @@
+use regex::Regex;
+use toml::Value;
+
+use mago_reporting::*;
+use mago_span::Span;
+
+use crate::definition::RuleOptionDefinition;
+use crate::settings::RuleSettings;
+
+pub(super) const PATTERN: &str = "pattern";
+
+/// The definition of the `pattern` option shared by all naming rules.
+pub(super) fn pattern_option() -> RuleOptionDefinition {
+    RuleOptionDefinition {
+        name: PATTERN,
+        r#type: "string",
+        description: "A regular expression that names must match, overriding the default naming convention.",
+        default: Value::String(String::new()),
+    }
+}
+
+/// Compiles the regular expression configured using the `pattern` option, if any.
+///
+/// Invalid regular expressions are ignored, falling back to the default naming convention.
+pub(super) fn compile_pattern(settings: &RuleSettings) -> Option<Regex> {
+    let pattern = settings.get_option(PATTERN).and_then(|o| o.as_str()).filter(|pattern| !pattern.is_empty())?;
+
+    match Regex::new(pattern) {
+        Ok(regex) => Some(regex),
+        Err(error) => {
+            tracing::warn!("Ignoring invalid naming pattern `{}`: {}", pattern, error);
+
+            None
+        }
+    }
+}
+
+/// Creates an issue for a name that does not match the configured pattern.
+pub(super) fn pattern_mismatch_issue(level: Level, kind: &str, name: &str, span: Span, pattern: &Regex) -> Issue {
+    Issue::new(level, format!("{} name `{}` does not match the configured naming pattern.", kind, name))
+        .with_annotation(Annotation::primary(span).with_message(format!("{} `{}` is declared here.", kind, name)))
+        .with_note(format!("{} names must match the pattern `{}`.", kind, pattern.as_str()))
+        .with_help("Rename it to match the configured pattern, or adjust the `pattern` option of this rule.")
+}
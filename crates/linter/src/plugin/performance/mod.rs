@@ -0,0 +1,32 @@
+use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
+use crate::plugin::performance::rules::loop_invariant_method_call::LoopInvariantMethodCallRule;
+use crate::plugin::performance::rules::no_count_in_loop_condition::NoCountInLoopConditionRule;
+use crate::plugin::performance::rules::prefer_keyed_lookup::PreferKeyedLookupRule;
+
+use crate::plugin::Plugin;
+use crate::rule::Rule;
+
+pub mod rules;
+
+#[derive(Debug)]
+pub struct PerformancePlugin;
+
+impl Plugin for PerformancePlugin {
+    fn get_definition(&self) -> PluginDefinition {
+        PluginDefinition {
+            name: "Performance",
+            description: "Provides rules that detect code that is needlessly slow, such as repeated work in loops.",
+            enabled_by_default: true,
+            category: RuleCategory::Performance,
+        }
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![
+            Box::new(LoopInvariantMethodCallRule),
+            Box::new(NoCountInLoopConditionRule),
+            Box::new(PreferKeyedLookupRule),
+        ]
+    }
+}
@@ -0,0 +1,172 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_ast_utils::reference::MethodReference;
+use mago_ast_utils::reference::find_method_references_in_statement;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::performance::rules::utils::Loop;
+use crate::plugin::performance::rules::utils::contains_loop;
+use crate::plugin::performance::rules::utils::get_loop;
+use crate::plugin::performance::rules::utils::is_constant_value;
+use crate::plugin::performance::rules::utils::is_modified_in_loop;
+use crate::rule::Rule;
+
+const PREFIXES: &str = "prefixes";
+const PREFIXES_DEFAULT: [&str; 4] = ["get", "is", "has", "find"];
+
+#[derive(Clone, Debug)]
+pub struct LoopInvariantMethodCallRule;
+
+impl Rule for LoopInvariantMethodCallRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Loop Invariant Method Call", Level::Help)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects method calls repeated on every iteration of a tight loop, i.e. a loop containing no
+                other loop, with the same constant arguments, on an object that is not reassigned within
+                the loop.
+
+                Only methods whose name starts with one of the configured `prefixes` are considered, as
+                such methods usually return the same value when called with the same arguments. Calling
+                them once, before the loop, avoids the repeated calls.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: PREFIXES,
+                r#type: "array<string>",
+                description: "The prefixes of the names of the methods to consider, e.g. `get` for `getOption`.",
+                default: Value::Array(PREFIXES_DEFAULT.iter().map(|p| Value::String(p.to_string())).collect()),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Calling the method once, before the loop",
+                indoc! {r#"
+                    <?php
+
+                    $currency = $config->get('currency');
+                    foreach ($prices as $price) {
+                        echo $price . ' ' . $currency;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Calling the method with the same arguments on every iteration",
+                indoc! {r#"
+                    <?php
+
+                    foreach ($prices as $price) {
+                        echo $price . ' ' . $config->get('currency');
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Some(r#loop) = get_loop(node) else {
+            return LintDirective::default();
+        };
+
+        if contains_loop(r#loop.body) {
+            return LintDirective::default();
+        }
+
+        let prefixes = match context.option(PREFIXES).and_then(|o| o.as_array()) {
+            Some(prefixes) => prefixes.iter().filter_map(|p| p.as_str()).map(str::to_string).collect::<Vec<_>>(),
+            None => PREFIXES_DEFAULT.iter().map(|p| p.to_string()).collect(),
+        };
+
+        let mut reported = vec![];
+        for statement in r#loop.body {
+            let references = find_method_references_in_statement(statement, &|reference| {
+                is_invariant_call(context, &r#loop, &prefixes, reference)
+            });
+
+            for reference in references {
+                let span = reference.span();
+                let code = &context.module.source.content[span.start.offset..span.end.offset];
+                if reported.iter().any(|reported| reported == code) {
+                    continue;
+                }
+
+                reported.push(code.to_string());
+
+                let ClassLikeMemberSelector::Identifier(method) = reference.get_selector() else {
+                    continue;
+                };
+
+                let method_name = context.lookup(&method.value);
+
+                context.report(
+                    Issue::new(
+                        context.level(),
+                        format!("Method `{}` is called with the same arguments on every iteration.", method_name),
+                    )
+                    .with_annotation(
+                        Annotation::primary(span).with_message("This call does not depend on the iteration."),
+                    )
+                    .with_annotation(Annotation::secondary(node.span()).with_message("The loop is defined here."))
+                    .with_help(
+                        "If the result does not change between iterations, call the method once before the loop, and store the result in a variable.",
+                    ),
+                );
+            }
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn is_invariant_call(
+    context: &LintContext<'_>,
+    r#loop: &Loop<'_>,
+    prefixes: &[String],
+    reference: &MethodReference<'_>,
+) -> bool {
+    let argument_list = match reference {
+        MethodReference::MethodCall(call) => &call.argument_list,
+        MethodReference::StaticMethodCall(call) => &call.argument_list,
+        _ => return false,
+    };
+
+    if argument_list.arguments.is_empty()
+        || !argument_list.arguments.iter().all(|argument| match argument {
+            Argument::Positional(argument) => argument.ellipsis.is_none() && is_constant_value(&argument.value),
+            Argument::Named(argument) => argument.ellipsis.is_none() && is_constant_value(&argument.value),
+        })
+    {
+        return false;
+    }
+
+    let ClassLikeMemberSelector::Identifier(method) = reference.get_selector() else {
+        return false;
+    };
+
+    if !has_prefix(context.lookup(&method.value), prefixes) {
+        return false;
+    }
+
+    match reference.get_class_or_object() {
+        Expression::Identifier(_) | Expression::Self_(_) | Expression::Static(_) | Expression::Parent(_) => true,
+        Expression::Variable(Variable::Direct(variable)) => {
+            context.lookup(&variable.name) == "$this" || !is_modified_in_loop(r#loop, variable.name, false, context)
+        }
+        _ => false,
+    }
+}
+
+/// Determine if the method name starts with one of the prefixes, followed by the end of the name, an uppercase
+/// letter, or an underscore, e.g. `getOption` for `get`, but not `issue` for `is`.
+fn has_prefix(method_name: &str, prefixes: &[String]) -> bool {
+    prefixes.iter().any(|prefix| {
+        method_name.len() >= prefix.len()
+            && method_name.is_char_boundary(prefix.len())
+            && method_name[..prefix.len()].eq_ignore_ascii_case(prefix)
+            && method_name[prefix.len()..].chars().next().is_none_or(|c| c.is_ascii_uppercase() || c == '_')
+    })
+}
@@ -0,0 +1,4 @@
+pub mod loop_invariant_method_call;
+pub mod no_count_in_loop_condition;
+pub mod prefer_keyed_lookup;
+pub mod utils;
@@ -0,0 +1,130 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::performance::rules::utils::collect_from_expression;
+use crate::plugin::performance::rules::utils::get_loop;
+use crate::plugin::performance::rules::utils::is_modified_in_loop;
+use crate::rule::Rule;
+
+const COUNT_FUNCTIONS: [&str; 2] = ["count", "sizeof"];
+
+#[derive(Clone, Debug)]
+pub struct NoCountInLoopConditionRule;
+
+impl Rule for NoCountInLoopConditionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Count In Loop Condition", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects calls to `count()` or `sizeof()` in the condition of a loop, over an array that is not
+                modified within the loop.
+
+                The condition is evaluated on every iteration, calling the function each time, while the
+                number of elements never changes. Counting the elements once, before the loop, avoids the
+                repeated calls.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Counting the elements once, before the loop",
+                indoc! {r#"
+                    <?php
+
+                    for ($i = 0, $count = count($items); $i < $count; $i++) {
+                        echo $items[$i];
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "Counting the elements of an array modified within the loop",
+                indoc! {r#"
+                    <?php
+
+                    while (count($queue) > 0) {
+                        $item = array_shift($queue);
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Counting the elements on every iteration",
+                indoc! {r#"
+                    <?php
+
+                    for ($i = 0; $i < count($items); $i++) {
+                        echo $items[$i];
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let conditions = match node {
+            Node::For(r#for) => r#for.conditions.iter().collect::<Vec<_>>(),
+            Node::While(r#while) => vec![r#while.condition.as_ref()],
+            Node::DoWhile(do_while) => vec![do_while.condition.as_ref()],
+            _ => return LintDirective::default(),
+        };
+
+        let Some(r#loop) = get_loop(node) else {
+            return LintDirective::default();
+        };
+
+        for condition in conditions {
+            let calls = collect_from_expression(condition, |expression| {
+                let Expression::Call(Call::Function(function_call)) = expression else {
+                    return None;
+                };
+
+                let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+                    return None;
+                };
+
+                let function_name = context.resolve_function_name(function_identifier);
+                let function_name = COUNT_FUNCTIONS.iter().find(|name| function_name.eq_ignore_ascii_case(name))?;
+
+                let [Argument::Positional(PositionalArgument { ellipsis: None, value })] =
+                    function_call.argument_list.arguments.as_slice()
+                else {
+                    return None;
+                };
+
+                let Expression::Variable(Variable::Direct(variable)) = value else {
+                    return None;
+                };
+
+                Some((function_call.span(), *function_name, variable.name))
+            });
+
+            for (span, function_name, variable) in calls {
+                if is_modified_in_loop(&r#loop, variable, true, context) {
+                    continue;
+                }
+
+                let variable_name = context.lookup(&variable).to_string();
+
+                context.report(
+                    Issue::new(
+                        context.level(),
+                        format!("`{}` is called on every iteration of the loop.", function_name),
+                    )
+                    .with_annotation(
+                        Annotation::primary(span)
+                            .with_message(format!("`{}` is not modified within the loop.", variable_name)),
+                    )
+                    .with_annotation(Annotation::secondary(node.span()).with_message("The loop is defined here."))
+                    .with_help(format!(
+                        "Store the result of `{}({})` in a variable before the loop, and use it in the condition instead.",
+                        function_name, variable_name
+                    )),
+                );
+            }
+        }
+
+        LintDirective::default()
+    }
+}
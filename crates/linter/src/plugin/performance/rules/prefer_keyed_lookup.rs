@@ -0,0 +1,141 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::performance::rules::utils::collect_from_loop;
+use crate::plugin::performance::rules::utils::get_loop;
+use crate::rule::Rule;
+
+const IN_ARRAY: &str = "in_array";
+
+const THRESHOLD: &str = "threshold";
+const THRESHOLD_DEFAULT: i64 = 10;
+
+#[derive(Clone, Debug)]
+pub struct PreferKeyedLookupRule;
+
+impl Rule for PreferKeyedLookupRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Prefer Keyed Lookup", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects calls to `in_array()` within a loop, searching a literal array of at least `threshold`
+                string or integer values.
+
+                `in_array()` compares the searched value to each element of the array, on every iteration,
+                while looking up a key using `isset()` takes constant time. Flipping the literal array into
+                a map, whose keys are the searched values, and defining it once, before the loop, is cheaper.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: THRESHOLD,
+                r#type: "integer",
+                description: "The minimum number of values of the searched literal array.",
+                default: Value::Integer(THRESHOLD_DEFAULT),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Looking up a key of a map defined before the loop",
+                indoc! {r#"
+                    <?php
+
+                    $reserved = ['class' => true, 'function' => true, 'interface' => true, 'trait' => true];
+                    foreach ($words as $word) {
+                        if (isset($reserved[$word])) {
+                            echo $word;
+                        }
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::invalid(
+                    "Searching a literal array on every iteration",
+                    indoc! {r#"
+                        <?php
+
+                        foreach ($words as $word) {
+                            if (in_array($word, ['class', 'function', 'interface', 'trait'], true)) {
+                                echo $word;
+                            }
+                        }
+                    "#},
+                )
+                .with_option(THRESHOLD, Value::Integer(4)),
+            )
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Some(r#loop) = get_loop(node) else {
+            return LintDirective::default();
+        };
+
+        let threshold = context
+            .option(THRESHOLD)
+            .and_then(|o| o.as_integer())
+            .map(|threshold| threshold.max(1) as usize)
+            .unwrap_or(THRESHOLD_DEFAULT as usize);
+
+        let calls = collect_from_loop(&r#loop, |expression| {
+            let Expression::Call(Call::Function(function_call)) = expression else {
+                return None;
+            };
+
+            let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+                return None;
+            };
+
+            if !context.resolve_function_name(function_identifier).eq_ignore_ascii_case(IN_ARRAY) {
+                return None;
+            }
+
+            let Some(Argument::Positional(PositionalArgument { ellipsis: None, value })) =
+                function_call.argument_list.arguments.get(1)
+            else {
+                return None;
+            };
+
+            let elements = match value {
+                Expression::Array(array) => array.elements.as_slice(),
+                Expression::LegacyArray(array) => array.elements.as_slice(),
+                _ => return None,
+            };
+
+            let is_literal_list = elements.iter().all(|element| match element {
+                ArrayElement::Value(element) => {
+                    matches!(element.value.as_ref(), Expression::Literal(Literal::String(_) | Literal::Integer(_)))
+                }
+                _ => false,
+            });
+
+            if !is_literal_list || elements.len() < threshold {
+                return None;
+            }
+
+            Some((function_call.span(), value.span(), elements.len()))
+        });
+
+        for (call_span, array_span, length) in calls {
+            context.report(
+                Issue::new(context.level(), "`in_array` searches a large literal array on every iteration.")
+                    .with_annotation(
+                        Annotation::primary(call_span).with_message("This call compares the value to each element."),
+                    )
+                    .with_annotation(
+                        Annotation::secondary(array_span).with_message(format!("This array has {} values.", length)),
+                    )
+                    .with_annotation(Annotation::secondary(node.span()).with_message("The loop is defined here."))
+                    .with_help(
+                        "Define a map whose keys are the searched values before the loop, e.g. `['a' => true, 'b' => true]`, and look the value up using `isset($map[$value])`.",
+                    ),
+            );
+        }
+
+        LintDirective::default()
+    }
+}
@@ -0,0 +1,429 @@
+use std::slice;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::plugin::performance::rules::utils::internal::ExpressionWalker;
+use crate::plugin::performance::rules::utils::internal::LoopWalker;
+use crate::plugin::performance::rules::utils::internal::ModificationWalker;
+
+/// The functions known not to modify the arrays passed to them, nor to take them by reference.
+const READ_ONLY_FUNCTIONS: [&str; 24] = [
+    "count",
+    "sizeof",
+    "in_array",
+    "array_key_exists",
+    "key_exists",
+    "array_keys",
+    "array_values",
+    "array_search",
+    "array_slice",
+    "array_map",
+    "array_filter",
+    "array_merge",
+    "array_column",
+    "array_flip",
+    "array_unique",
+    "array_reverse",
+    "array_sum",
+    "implode",
+    "join",
+    "is_array",
+    "json_encode",
+    "max",
+    "min",
+    "iterator_to_array",
+];
+
+/// A loop, along with the expressions of its header, and the statements of its body.
+pub struct Loop<'a> {
+    /// The targets assigned on each iteration of a `foreach` loop, e.g. `$key => $value`.
+    pub targets: Vec<&'a Expression>,
+    /// The expressions evaluated on each iteration, e.g. the conditions and increments of a `for` loop.
+    pub header: Vec<&'a Expression>,
+    pub body: &'a [Statement],
+}
+
+/// Given a node, get the loop it represents, if any.
+pub fn get_loop(node: Node<'_>) -> Option<Loop<'_>> {
+    match node {
+        Node::For(r#for) => Some(Loop {
+            targets: vec![],
+            header: r#for.conditions.iter().chain(r#for.increments.iter()).collect(),
+            body: match &r#for.body {
+                ForBody::Statement(statement) => slice::from_ref(statement.as_ref()),
+                ForBody::ColonDelimited(body) => body.statements.as_slice(),
+            },
+        }),
+        Node::Foreach(foreach) => Some(Loop {
+            header: vec![],
+            targets: match &foreach.target {
+                ForeachTarget::Value(target) => vec![target.value.as_ref()],
+                ForeachTarget::KeyValue(target) => vec![target.key.as_ref(), target.value.as_ref()],
+            },
+            body: match &foreach.body {
+                ForeachBody::Statement(statement) => slice::from_ref(statement.as_ref()),
+                ForeachBody::ColonDelimited(body) => body.statements.as_slice(),
+            },
+        }),
+        Node::While(r#while) => Some(Loop {
+            targets: vec![],
+            header: vec![r#while.condition.as_ref()],
+            body: match &r#while.body {
+                WhileBody::Statement(statement) => slice::from_ref(statement.as_ref()),
+                WhileBody::ColonDelimited(body) => body.statements.as_slice(),
+            },
+        }),
+        Node::DoWhile(do_while) => Some(Loop {
+            targets: vec![],
+            header: vec![do_while.condition.as_ref()],
+            body: slice::from_ref(do_while.statement.as_ref()),
+        }),
+        _ => None,
+    }
+}
+
+/// Determine if the statements contain a loop, excluding the loops of nested functions, closures, and classes.
+pub fn contains_loop(statements: &[Statement]) -> bool {
+    let mut found = false;
+    for statement in statements {
+        LoopWalker.walk_statement(statement, &mut found);
+    }
+
+    found
+}
+
+/// Collect the values returned by `f` for the expressions evaluated on each iteration of the loop, i.e. those
+/// of its header and its body.
+///
+/// The expressions of nested loops, functions, closures, and classes are not visited.
+pub fn collect_from_loop<T>(r#loop: &Loop<'_>, f: impl Fn(&Expression) -> Option<T> + Sync + Send) -> Vec<T> {
+    let walker = ExpressionWalker(f);
+    let mut values = Vec::default();
+
+    for expression in r#loop.header.iter() {
+        walker.walk_expression(expression, &mut values);
+    }
+
+    for statement in r#loop.body {
+        walker.walk_statement(statement, &mut values);
+    }
+
+    values
+}
+
+/// Collect the values returned by `f` for the given expression, and its sub-expressions.
+///
+/// The expressions of nested functions, closures, and classes are not visited.
+pub fn collect_from_expression<T>(
+    expression: &Expression,
+    f: impl Fn(&Expression) -> Option<T> + Sync + Send,
+) -> Vec<T> {
+    let mut values = Vec::default();
+
+    ExpressionWalker(f).walk_expression(expression, &mut values);
+
+    values
+}
+
+/// Determine if the variable may be reassigned within the loop, including by its header, e.g. by being the
+/// target of a `foreach` loop.
+///
+/// If `include_calls` is true, passing the variable to a method, or to a function not known to be read-only,
+/// is also considered a modification, as the variable may be taken by reference.
+pub fn is_modified_in_loop(
+    r#loop: &Loop<'_>,
+    variable: StringIdentifier,
+    include_calls: bool,
+    context: &LintContext<'_>,
+) -> bool {
+    let walker = ModificationWalker { variable, include_calls };
+    let mut context = (false, context);
+
+    if r#loop.targets.iter().any(|target| assigns_variable(target, variable)) {
+        return true;
+    }
+
+    for expression in r#loop.header.iter() {
+        walker.walk_expression(expression, &mut context);
+    }
+
+    for statement in r#loop.body {
+        walker.walk_statement(statement, &mut context);
+    }
+
+    context.0
+}
+
+/// Determine if the expression is a constant value, i.e. a literal, or a constant, evaluating to the same
+/// value each time.
+pub fn is_constant_value(expression: &Expression) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => is_constant_value(&parenthesized.expression),
+        Expression::Literal(_) | Expression::ConstantAccess(_) | Expression::MagicConstant(_) => true,
+        Expression::UnaryPrefix(UnaryPrefix {
+            operator: UnaryPrefixOperator::Negation(_) | UnaryPrefixOperator::Plus(_),
+            operand,
+        }) => is_constant_value(operand),
+        Expression::Access(Access::ClassConstant(access)) => {
+            matches!(
+                access.class.as_ref(),
+                Expression::Identifier(_) | Expression::Self_(_) | Expression::Static(_) | Expression::Parent(_)
+            ) && matches!(access.constant, ClassLikeConstantSelector::Identifier(_))
+        }
+        _ => false,
+    }
+}
+
+/// Determine if the expression assigns the variable when used as the target of an assignment, or of a
+/// `foreach` loop, e.g. `$items[] = ...`, or `[$key, $value] = ...`.
+fn assigns_variable(expression: &Expression, variable: StringIdentifier) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => assigns_variable(&parenthesized.expression, variable),
+        Expression::Variable(Variable::Direct(direct_variable)) => direct_variable.name == variable,
+        Expression::ArrayAccess(access) => assigns_variable(&access.array, variable),
+        Expression::ArrayAppend(append) => assigns_variable(&append.array, variable),
+        Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Reference(_), operand }) => {
+            assigns_variable(operand, variable)
+        }
+        Expression::Array(Array { elements, .. })
+        | Expression::LegacyArray(LegacyArray { elements, .. })
+        | Expression::List(List { elements, .. }) => elements.iter().any(|element| match element {
+            ArrayElement::KeyValue(element) => assigns_variable(&element.value, variable),
+            ArrayElement::Value(element) => assigns_variable(&element.value, variable),
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+fn is_variable(expression: &Expression, variable: StringIdentifier) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => is_variable(&parenthesized.expression, variable),
+        Expression::Variable(Variable::Direct(direct_variable)) => direct_variable.name == variable,
+        _ => false,
+    }
+}
+
+mod internal {
+    use super::READ_ONLY_FUNCTIONS;
+    use super::assigns_variable;
+    use super::is_variable;
+
+    use mago_ast::*;
+    use mago_interner::StringIdentifier;
+    use mago_walker::Walker;
+
+    use crate::context::LintContext;
+
+    #[derive(Debug)]
+    pub(super) struct ExpressionWalker<F>(pub F);
+
+    #[derive(Debug)]
+    pub(super) struct LoopWalker;
+
+    #[derive(Debug)]
+    pub(super) struct ModificationWalker {
+        pub variable: StringIdentifier,
+        pub include_calls: bool,
+    }
+
+    impl ModificationWalker {
+        fn is_passed(&self, argument_list: &ArgumentList) -> bool {
+            self.include_calls
+                && argument_list.arguments.iter().any(|argument| is_variable(argument.value(), self.variable))
+        }
+    }
+
+    impl<T, F> Walker<Vec<T>> for ExpressionWalker<F>
+    where
+        F: Fn(&Expression) -> Option<T> + Sync + Send,
+    {
+        fn walk_in_expression(&self, expression: &Expression, context: &mut Vec<T>) {
+            if let Some(value) = (self.0)(expression) {
+                context.push(value);
+            }
+        }
+
+        #[inline(always)]
+        fn walk_for(&self, _: &For, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_foreach(&self, _: &Foreach, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_while(&self, _: &While, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_do_while(&self, _: &DoWhile, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_closure(&self, _: &Closure, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_arrow_function(&self, _: &ArrowFunction, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_function(&self, _: &Function, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_class(&self, _: &Class, _: &mut Vec<T>) {}
+
+        #[inline(always)]
+        fn walk_anonymous_class(&self, _: &AnonymousClass, _: &mut Vec<T>) {}
+    }
+
+    impl Walker<bool> for LoopWalker {
+        fn walk_in_for(&self, _: &For, context: &mut bool) {
+            *context = true;
+        }
+
+        fn walk_in_foreach(&self, _: &Foreach, context: &mut bool) {
+            *context = true;
+        }
+
+        fn walk_in_while(&self, _: &While, context: &mut bool) {
+            *context = true;
+        }
+
+        fn walk_in_do_while(&self, _: &DoWhile, context: &mut bool) {
+            *context = true;
+        }
+
+        #[inline(always)]
+        fn walk_closure(&self, _: &Closure, _: &mut bool) {}
+
+        #[inline(always)]
+        fn walk_arrow_function(&self, _: &ArrowFunction, _: &mut bool) {}
+
+        #[inline(always)]
+        fn walk_function(&self, _: &Function, _: &mut bool) {}
+
+        #[inline(always)]
+        fn walk_class(&self, _: &Class, _: &mut bool) {}
+
+        #[inline(always)]
+        fn walk_anonymous_class(&self, _: &AnonymousClass, _: &mut bool) {}
+    }
+
+    impl<'a> Walker<(bool, &'a LintContext<'a>)> for ModificationWalker {
+        fn walk_in_assignment<'ast>(&self, assignment: &'ast Assignment, context: &mut (bool, &'a LintContext<'a>)) {
+            context.0 |= assigns_variable(&assignment.lhs, self.variable);
+
+            // A reference to the variable allows modifying it through another variable, e.g. `$alias = &$items;`.
+            if let Expression::UnaryPrefix(UnaryPrefix { operator: UnaryPrefixOperator::Reference(_), operand }) =
+                assignment.rhs.as_ref()
+            {
+                context.0 |= is_variable(operand, self.variable);
+            }
+        }
+
+        fn walk_in_foreach<'ast>(&self, foreach: &'ast Foreach, context: &mut (bool, &'a LintContext<'a>)) {
+            context.0 |= match &foreach.target {
+                ForeachTarget::Value(target) => assigns_variable(&target.value, self.variable),
+                ForeachTarget::KeyValue(target) => {
+                    assigns_variable(&target.key, self.variable) || assigns_variable(&target.value, self.variable)
+                }
+            };
+        }
+
+        fn walk_in_unset<'ast>(&self, unset: &'ast Unset, context: &mut (bool, &'a LintContext<'a>)) {
+            context.0 |= unset.values.iter().any(|value| assigns_variable(value, self.variable));
+        }
+
+        fn walk_in_global<'ast>(&self, global: &'ast Global, context: &mut (bool, &'a LintContext<'a>)) {
+            context.0 |= global.variables.iter().any(|variable| match variable {
+                Variable::Direct(variable) => variable.name == self.variable,
+                _ => false,
+            });
+        }
+
+        fn walk_in_static_concrete_item<'ast>(
+            &self,
+            item: &'ast StaticConcreteItem,
+            context: &mut (bool, &'a LintContext<'a>),
+        ) {
+            context.0 |= item.variable.name == self.variable;
+        }
+
+        fn walk_in_static_abstract_item<'ast>(
+            &self,
+            item: &'ast StaticAbstractItem,
+            context: &mut (bool, &'a LintContext<'a>),
+        ) {
+            context.0 |= item.variable.name == self.variable;
+        }
+
+        fn walk_in_try_catch_clause<'ast>(
+            &self,
+            try_catch_clause: &'ast TryCatchClause,
+            context: &mut (bool, &'a LintContext<'a>),
+        ) {
+            if let Some(variable) = &try_catch_clause.variable {
+                context.0 |= variable.name == self.variable;
+            }
+        }
+
+        fn walk_in_closure_use_clause_variable<'ast>(
+            &self,
+            closure_use_clause_variable: &'ast ClosureUseClauseVariable,
+            context: &mut (bool, &'a LintContext<'a>),
+        ) {
+            context.0 |= closure_use_clause_variable.ampersand.is_some()
+                && closure_use_clause_variable.variable.name == self.variable;
+        }
+
+        fn walk_in_function_call<'ast>(
+            &self,
+            function_call: &'ast FunctionCall,
+            context: &mut (bool, &'a LintContext<'a>),
+        ) {
+            if !self.is_passed(&function_call.argument_list) {
+                return;
+            }
+
+            let is_read_only = match function_call.function.as_ref() {
+                Expression::Identifier(function_identifier) => {
+                    let function_name = context.1.resolve_function_name(function_identifier);
+
+                    READ_ONLY_FUNCTIONS.iter().any(|name| function_name.eq_ignore_ascii_case(name))
+                }
+                _ => false,
+            };
+
+            context.0 |= !is_read_only;
+        }
+
+        fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut (bool, &'a LintContext<'a>)) {
+            context.0 |= self.is_passed(&method_call.argument_list);
+        }
+
+        fn walk_in_null_safe_method_call<'ast>(
+            &self,
+            method_call: &'ast NullSafeMethodCall,
+            context: &mut (bool, &'a LintContext<'a>),
+        ) {
+            context.0 |= self.is_passed(&method_call.argument_list);
+        }
+
+        fn walk_in_static_method_call<'ast>(
+            &self,
+            method_call: &'ast StaticMethodCall,
+            context: &mut (bool, &'a LintContext<'a>),
+        ) {
+            context.0 |= self.is_passed(&method_call.argument_list);
+        }
+
+        fn walk_in_instantiation<'ast>(
+            &self,
+            instantiation: &'ast Instantiation,
+            context: &mut (bool, &'a LintContext<'a>),
+        ) {
+            if let Some(argument_list) = &instantiation.arguments {
+                context.0 |= self.is_passed(argument_list);
+            }
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::phpunit::rules::consistency::assertions_style::AssertionsStyleRule;
+use crate::plugin::phpunit::rules::consistency::test_method_marker::TestMethodMarkerRule;
+use crate::plugin::phpunit::rules::correctness::mock_misuse::MockMisuseRule;
+use crate::plugin::phpunit::rules::correctness::valid_data_provider::ValidDataProviderRule;
 use crate::plugin::phpunit::rules::redundancy::redundant_instanceof::RedundantInstanceOfRule;
+use crate::plugin::phpunit::rules::strictness::missing_assertions::MissingAssertionsRule;
 use crate::plugin::phpunit::rules::strictness::strict_assertions::StrictAssertionsRule;
 
 use crate::plugin::Plugin;
@@ -17,10 +22,19 @@ impl Plugin for PHPUnitPlugin {
             name: "PHPUnit",
             description: "Provides rules that enforce best practices for PHPUnit tests.",
             enabled_by_default: true,
+            category: RuleCategory::Framework,
         }
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
-        vec![Box::new(AssertionsStyleRule), Box::new(RedundantInstanceOfRule), Box::new(StrictAssertionsRule)]
+        vec![
+            Box::new(AssertionsStyleRule),
+            Box::new(RedundantInstanceOfRule),
+            Box::new(StrictAssertionsRule),
+            Box::new(MissingAssertionsRule),
+            Box::new(TestMethodMarkerRule),
+            Box::new(ValidDataProviderRule),
+            Box::new(MockMisuseRule),
+        ]
     }
 }
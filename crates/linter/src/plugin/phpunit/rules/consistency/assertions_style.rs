@@ -30,6 +30,7 @@ pub struct AssertionsStyleRule;
 impl Rule for AssertionsStyleRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Assertions Style", Level::Warning)
+            .fixable()
             .with_description(indoc! {"
                 Detects inconsistent assertions style in test methods.
                 Assertions should use the same style, either `static::`, `self::`, or `$this->`.
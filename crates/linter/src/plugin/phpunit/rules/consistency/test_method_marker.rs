@@ -0,0 +1,116 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::phpunit::rules::utils::get_test_method_candidates;
+use crate::plugin::phpunit::rules::utils::is_test_method;
+use crate::rule::Rule;
+
+/// A PHPUnit rule that detects public methods in test classes that are not recognized as tests.
+#[derive(Clone, Debug)]
+pub struct TestMethodMarkerRule;
+
+impl Rule for TestMethodMarkerRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Test Method Marker", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects public methods in test classes that are not recognized as tests by PHPUnit.
+
+                PHPUnit only runs methods whose name starts with `test`, or that are marked using the
+                `#[Test]` attribute or the `@test` annotation. A public method without any of these is
+                silently ignored, which usually means a test that never runs.
+
+                Lifecycle methods (e.g. `setUp`), data providers, and static methods are ignored.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Test methods recognized by PHPUnit",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\Attributes\Test;
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $this->assertTrue(true);
+                        }
+
+                        #[Test]
+                        public function somethingElse(): void
+                        {
+                            $this->assertTrue(true);
+                        }
+
+                        /**
+                         * @test
+                         */
+                        public function anotherThing(): void
+                        {
+                            $this->assertTrue(true);
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A public method that is never run by PHPUnit",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function itDoesSomething(): void
+                        {
+                            $this->assertTrue(true);
+                        }
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Class(class) = node else { return LintDirective::default() };
+
+        for method in get_test_method_candidates(class, context) {
+            if is_test_method(method, context) {
+                continue;
+            }
+
+            let name = context.lookup(&method.name.value);
+
+            let issue = Issue::new(context.level(), format!("Method `{}` is not recognized as a test by PHPUnit.", name))
+                .with_annotation(
+                    Annotation::primary(method.name.span())
+                        .with_message(format!("Public method `{}` is declared here.", name)),
+                )
+                .with_note("PHPUnit only runs methods prefixed with `test`, or marked using `#[Test]` or `@test`.")
+                .with_help(format!(
+                    "Rename the method to `test{}`, add the `#[Test]` attribute, or reduce its visibility if it is a helper.",
+                    capitalize(name)
+                ));
+
+            context.report(issue);
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn capitalize(name: &str) -> String {
+    let mut characters = name.chars();
+
+    match characters.next() {
+        Some(first) => first.to_uppercase().chain(characters).collect(),
+        None => String::new(),
+    }
+}
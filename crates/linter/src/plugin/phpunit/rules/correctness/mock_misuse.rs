@@ -0,0 +1,192 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+/// The stub methods passed to `will()`, along with the shorthand method replacing them.
+const STUB_SHORTHANDS: [(&str, &str); 6] = [
+    ("returnValue", "willReturn"),
+    ("returnSelf", "willReturnSelf"),
+    ("returnArgument", "willReturnArgument"),
+    ("returnCallback", "willReturnCallback"),
+    ("returnValueMap", "willReturnMap"),
+    ("throwException", "willThrowException"),
+];
+
+/// A PHPUnit rule that detects misused or redundant mock configuration.
+#[derive(Clone, Debug)]
+pub struct MockMisuseRule;
+
+impl Rule for MockMisuseRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Mock Misuse", Level::Warning)
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects misused or redundant mock configuration in PHPUnit tests:
+
+                - `expects($this->any())` is redundant, as it does not verify anything; configure the stub
+                  directly using `method()` instead.
+                - `will($this->returnValue(...))` and similar calls should use the shorthand methods, such as
+                  `willReturn(...)`, as the stub methods are deprecated since PHPUnit 10.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A stub configured using shorthand methods",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $mock = $this->createMock(Foo::class);
+                            $mock->method('bar')->willReturn(42);
+
+                            $this->assertSame(42, $mock->bar());
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A redundant `expects($this->any())` call",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $mock = $this->createMock(Foo::class);
+                            $mock->expects($this->any())->method('bar')->willReturn(42);
+
+                            $this->assertSame(42, $mock->bar());
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A stub configured using `will($this->returnValue(...))`",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $mock = $this->createMock(Foo::class);
+                            $mock->method('bar')->will($this->returnValue(42));
+
+                            $this->assertSame(42, $mock->bar());
+                        }
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::MethodCall(method_call) = node else { return LintDirective::default() };
+
+        let ClassLikeMemberSelector::Identifier(method) = &method_call.method else {
+            return LintDirective::default();
+        };
+
+        let method_name = context.lookup(&method.value);
+        let Some(argument) = method_call.argument_list.arguments.first() else {
+            return LintDirective::default();
+        };
+
+        if method_call.argument_list.arguments.len() != 1 {
+            return LintDirective::default();
+        }
+
+        let Some((inner_name, inner_call)) = get_this_method_call(argument.value(), context) else {
+            return LintDirective::default();
+        };
+
+        if method_name.eq_ignore_ascii_case("expects") && inner_name.eq_ignore_ascii_case("any") {
+            let issue = Issue::new(context.level(), "Redundant `expects($this->any())` call.")
+                .with_annotation(
+                    Annotation::primary(Span::between(method_call.arrow, method_call.argument_list.span()))
+                        .with_message("This expectation does not verify anything."),
+                )
+                .with_help(
+                    "Remove the `expects($this->any())` call, and configure the stub using `method()` directly.",
+                );
+
+            context.propose(issue, |plan| {
+                plan.delete(
+                    Span::between(method_call.arrow, method_call.argument_list.span()).to_range(),
+                    SafetyClassification::Safe,
+                );
+            });
+        } else if method_name.eq_ignore_ascii_case("will") {
+            let Some((_, shorthand)) = STUB_SHORTHANDS.iter().find(|(stub, _)| inner_name.eq_ignore_ascii_case(stub))
+            else {
+                return LintDirective::default();
+            };
+
+            let issue = Issue::new(
+                context.level(),
+                format!("Use `{}()` instead of `will($this->{}())`.", shorthand, inner_name),
+            )
+            .with_annotation(
+                Annotation::primary(Span::between(method.span(), method_call.argument_list.span()))
+                    .with_message(format!("The stub is configured using `{}()` here.", inner_name)),
+            )
+            .with_note(format!("The `{}()` stub method is deprecated since PHPUnit 10.", inner_name))
+            .with_help(format!("Use the `{}()` shorthand method instead.", shorthand));
+
+            let code = &context.module.source.content;
+            let inner_arguments = &inner_call.argument_list;
+            let arguments =
+                &code[inner_arguments.left_parenthesis.start.offset..inner_arguments.right_parenthesis.end.offset];
+            let replacement = format!("{}{}", shorthand, arguments);
+            let range = Span::between(method.span(), method_call.argument_list.span()).to_range();
+
+            context.propose(issue, |plan| {
+                plan.replace(range, replacement, SafetyClassification::Safe);
+            });
+        }
+
+        LintDirective::default()
+    }
+}
+
+/// If the given expression is a call to a method on `$this`, returns the method name and the call.
+fn get_this_method_call<'a>(
+    expression: &'a Expression,
+    context: &LintContext<'a>,
+) -> Option<(&'a str, &'a MethodCall)> {
+    let Expression::Call(Call::Method(method_call)) = expression else {
+        return None;
+    };
+
+    let Expression::Variable(Variable::Direct(variable)) = method_call.object.as_ref() else {
+        return None;
+    };
+
+    if context.lookup(&variable.name) != "$this" {
+        return None;
+    }
+
+    let ClassLikeMemberSelector::Identifier(method) = &method_call.method else {
+        return None;
+    };
+
+    Some((context.interner.lookup(&method.value), method_call))
+}
@@ -0,0 +1,187 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::phpunit::rules::utils::get_data_providers;
+use crate::plugin::phpunit::rules::utils::is_test_class;
+use crate::rule::Rule;
+
+/// A PHPUnit rule that ensures data providers exist, and are public and static.
+#[derive(Clone, Debug)]
+pub struct ValidDataProviderRule;
+
+impl Rule for ValidDataProviderRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Valid Data Provider", Level::Error)
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Ensures that data providers referenced using the `#[DataProvider]` attribute or the
+                `@dataProvider` annotation are declared in the test class, and are public and static.
+
+                Since PHPUnit 10, data providers must be public and static.
+
+                Data providers declared in a parent class or a trait cannot be verified, and are therefore
+                only reported if the test class does not extend another class or use any trait.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A static data provider",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\Attributes\DataProvider;
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        #[DataProvider('provideValues')]
+                        public function testSomething(int $value): void
+                        {
+                            $this->assertIsInt($value);
+                        }
+
+                        public static function provideValues(): iterable
+                        {
+                            yield [1];
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A non-static data provider",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\Attributes\DataProvider;
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        #[DataProvider('provideValues')]
+                        public function testSomething(int $value): void
+                        {
+                            $this->assertIsInt($value);
+                        }
+
+                        public function provideValues(): iterable
+                        {
+                            yield [1];
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A data provider that does not exist",
+                indoc! {r#"
+                    <?php
+
+                    final class SomeTest
+                    {
+                        /**
+                         * @dataProvider provideValues
+                         */
+                        public function testSomething(int $value): void
+                        {
+                            $this->assertIsInt($value);
+                        }
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Class(class) = node else { return LintDirective::default() };
+
+        if !is_test_class(class, context) {
+            return LintDirective::default();
+        }
+
+        // Data providers may be inherited from a parent class that is not a test case base class, or from a trait.
+        let may_inherit_methods = class.members.iter().any(|member| matches!(member, ClassLikeMember::TraitUse(_)))
+            || class.extends.as_ref().is_some_and(|extends| {
+                extends.types.iter().any(|parent| !context.lookup_name(parent).ends_with("TestCase"))
+            });
+
+        let methods = class
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                ClassLikeMember::Method(method) => Some(method),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Each data provider is only reported once, even if it is used by multiple tests.
+        let mut data_providers: Vec<(String, _)> = vec![];
+        for (name, span) in methods.iter().flat_map(|method| get_data_providers(method, context)) {
+            if !data_providers.iter().any(|(other, _)| other.eq_ignore_ascii_case(&name)) {
+                data_providers.push((name, span));
+            }
+        }
+
+        for (provider_name, span) in data_providers {
+            let Some(provider) =
+                methods.iter().find(|other| context.lookup(&other.name.value).eq_ignore_ascii_case(&provider_name))
+            else {
+                if may_inherit_methods {
+                    continue;
+                }
+
+                let issue = Issue::new(context.level(), format!("Data provider `{}` does not exist.", provider_name))
+                    .with_annotation(
+                        Annotation::primary(span)
+                            .with_message(format!("Data provider `{}` is referenced here.", provider_name)),
+                    )
+                    .with_help(format!(
+                        "Declare a public static method named `{}`, or fix the data provider name.",
+                        provider_name
+                    ));
+
+                context.report(issue);
+
+                continue;
+            };
+
+            let is_public = !provider.modifiers.contains_private() && !provider.modifiers.contains_protected();
+            if provider.modifiers.contains_static() && is_public {
+                continue;
+            }
+
+            let issue =
+                Issue::new(context.level(), format!("Data provider `{}` must be public and static.", provider_name))
+                    .with_annotation(
+                        Annotation::primary(provider.name.span())
+                            .with_message(format!("Data provider `{}` is declared here.", provider_name)),
+                    )
+                    .with_annotation(
+                        Annotation::secondary(span)
+                            .with_message(format!("Data provider `{}` is referenced here.", provider_name)),
+                    )
+                    .with_note("Since PHPUnit 10, data providers must be declared as `public static` methods.")
+                    .with_help(
+                        "Declare the data provider as `public static`, and remove any usage of `$this` from it.",
+                    );
+
+            if !is_public {
+                context.report(issue);
+
+                continue;
+            }
+
+            let position = provider.function.span.start;
+
+            context.propose(issue, |plan| {
+                plan.insert(position.offset, "static ", SafetyClassification::PotentiallyUnsafe);
+            });
+        }
+
+        LintDirective::default()
+    }
+}
@@ -1,7 +1,13 @@
-mod utils;
+pub(crate) mod utils;
 
 pub mod consistency {
     pub mod assertions_style;
+    pub mod test_method_marker;
+}
+
+pub mod correctness {
+    pub mod mock_misuse;
+    pub mod valid_data_provider;
 }
 
 pub mod redundancy {
@@ -9,5 +15,6 @@ pub mod redundancy {
 }
 
 pub mod strictness {
+    pub mod missing_assertions;
     pub mod strict_assertions;
 }
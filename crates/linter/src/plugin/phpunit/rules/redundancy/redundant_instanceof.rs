@@ -22,6 +22,7 @@ pub struct RedundantInstanceOfRule;
 impl Rule for RedundantInstanceOfRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant InstanceOf", Level::Warning)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant `instanceof` assertions in test methods.
                 An `instanceof` assertion is redundant if the subject is always an instance of the class being checked.
@@ -0,0 +1,129 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_ast_utils::reference::find_method_references_in_block;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::phpunit::rules::utils::get_method_docblock;
+use crate::plugin::phpunit::rules::utils::has_annotation;
+use crate::plugin::phpunit::rules::utils::has_attribute;
+use crate::plugin::phpunit::rules::utils::is_test_class;
+use crate::plugin::phpunit::rules::utils::is_test_method;
+use crate::rule::Rule;
+
+/// Methods that count as an assertion, in addition to those starting with `assert` or `expect`.
+const ASSERTION_LIKE_METHODS: [&str; 4] = ["fail", "markTestSkipped", "markTestIncomplete", "addToAssertionCount"];
+
+/// A PHPUnit rule that requires every test method to perform at least one assertion.
+#[derive(Clone, Debug)]
+pub struct MissingAssertionsRule;
+
+impl Rule for MissingAssertionsRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Missing Assertions", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects test methods that do not perform any assertion.
+
+                A test without assertions is reported as risky by PHPUnit, and usually indicates a test that
+                does not verify anything. Calls to methods starting with `assert` or `expect` (including mock
+                expectations), `fail`, and `markTestSkipped` are considered assertions.
+
+                Tests that intentionally do not perform assertions can be marked using the
+                `#[DoesNotPerformAssertions]` attribute or the `@doesNotPerformAssertions` annotation.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A test method with an assertion",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $this->assertSame(42, compute());
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A test method without assertions",
+                indoc! {r#"
+                    <?php
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            compute();
+                        }
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::Class(class) = node else { return LintDirective::default() };
+
+        if !is_test_class(class, context) {
+            return LintDirective::default();
+        }
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            let MethodBody::Concrete(block) = &method.body else {
+                continue;
+            };
+
+            if !is_test_method(method, context)
+                || has_attribute(&method.attribute_lists, context, "DoesNotPerformAssertions")
+                || get_method_docblock(method, context)
+                    .is_some_and(|docblock| has_annotation(docblock, "@doesNotPerformAssertions"))
+            {
+                continue;
+            }
+
+            let assertions = find_method_references_in_block(block, &|reference| {
+                let ClassLikeMemberSelector::Identifier(identifier) = reference.get_selector() else {
+                    return false;
+                };
+
+                let name = context.lookup(&identifier.value);
+
+                name.starts_with("assert")
+                    || name.starts_with("expect")
+                    || ASSERTION_LIKE_METHODS.iter().any(|method| name.eq_ignore_ascii_case(method))
+            });
+
+            if !assertions.is_empty() {
+                continue;
+            }
+
+            let name = context.lookup(&method.name.value);
+            let issue = Issue::new(context.level(), format!("Test method `{}` does not perform any assertion.", name))
+                .with_annotation(
+                    Annotation::primary(method.name.span()).with_message(format!("Test `{}` is declared here.", name)),
+                )
+                .with_note("Tests without assertions are reported as risky by PHPUnit.")
+                .with_help(
+                    "Add an assertion, or mark the test with `#[DoesNotPerformAssertions]` if this is intentional.",
+                );
+
+            context.report(issue);
+        }
+
+        LintDirective::default()
+    }
+}
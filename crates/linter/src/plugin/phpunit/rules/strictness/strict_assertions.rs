@@ -1,6 +1,7 @@
 use indoc::indoc;
 
 use mago_ast::*;
+use mago_ast_utils::reference::MethodReference;
 use mago_fixer::SafetyClassification;
 use mago_reporting::*;
 use mago_span::HasSpan;
@@ -22,10 +23,14 @@ pub struct StrictAssertionsRule;
 impl Rule for StrictAssertionsRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Strict Assertions", Level::Warning)
+            .fixable()
             .with_description(indoc! {"
                 Detects non-strict assertions in test methods.
                 Assertions should use strict comparison methods, such as `assertSame` or `assertNotSame`
                 instead of `assertEquals` or `assertNotEquals`.
+
+                Only assertions where the expected value is a scalar literal are reported, as comparing
+                objects using `assertSame` checks for identity instead of equality.
             "})
             .with_example(RuleUsageExample::valid(
                 "A strict assertion using the `assertSame` method",
@@ -79,7 +84,7 @@ impl Rule for StrictAssertionsRule {
             };
 
             let name = context.lookup(&identifier.value);
-            if NON_STRICT_ASSERTIONS.contains(&name) {
+            if NON_STRICT_ASSERTIONS.contains(&name) && has_scalar_expected_value(&reference) {
                 let strict_name = name.replacen("Equals", "Same", 1);
 
                 let issue = Issue::new(context.level(), "Use strict assertions in PHPUnit tests.")
@@ -105,3 +110,24 @@ impl Rule for StrictAssertionsRule {
         LintDirective::Prune
     }
 }
+
+/// Returns `true` if the expected value (i.e. the first argument) of the given assertion is a scalar literal.
+fn has_scalar_expected_value(reference: &MethodReference<'_>) -> bool {
+    let argument_list = match reference {
+        MethodReference::MethodCall(call) => &call.argument_list,
+        MethodReference::StaticMethodCall(call) => &call.argument_list,
+        _ => return false,
+    };
+
+    let Some(argument) = argument_list.arguments.first() else {
+        return false;
+    };
+
+    match argument.value() {
+        Expression::Literal(_) => true,
+        Expression::UnaryPrefix(UnaryPrefix { operator, operand }) => {
+            operator.is_arithmetic() && matches!(operand.as_ref(), Expression::Literal(_))
+        }
+        _ => false,
+    }
+}
@@ -1,5 +1,7 @@
 use mago_ast::*;
 use mago_ast_utils::reference::*;
+use mago_span::HasSpan;
+use mago_span::Span;
 
 use crate::context::LintContext;
 
@@ -289,3 +291,176 @@ pub fn find_assertion_references_in_method<'a>(
         method_names.iter().any(|&method_name| name.eq_ignore_ascii_case(method_name))
     })
 }
+
+/// Methods called by PHPUnit around each test, which are never tests themselves.
+pub const LIFECYCLE_METHODS: [&str; 6] =
+    ["setUp", "tearDown", "setUpBeforeClass", "tearDownAfterClass", "assertPreConditions", "assertPostConditions"];
+
+/// Returns `true` if the given class looks like a PHPUnit test case.
+///
+/// A class is considered a test case if its name ends with `Test`, or if it extends a class
+/// whose name ends with `TestCase`.
+pub fn is_test_class(class: &Class, context: &LintContext<'_>) -> bool {
+    if context.lookup(&class.name.value).ends_with("Test") {
+        return true;
+    }
+
+    class
+        .extends
+        .as_ref()
+        .is_some_and(|extends| extends.types.iter().any(|parent| context.lookup_name(parent).ends_with("TestCase")))
+}
+
+/// Returns the methods of the given class that PHPUnit would consider running as tests.
+///
+/// These are the public, non-static, non-abstract methods of a non-abstract test class, excluding
+/// magic methods, lifecycle methods, and data providers referenced by other methods of the class.
+pub fn get_test_method_candidates<'a>(class: &'a Class, context: &LintContext<'_>) -> Vec<&'a Method> {
+    if class.modifiers.contains_abstract() || !is_test_class(class, context) {
+        return vec![];
+    }
+
+    let methods = class
+        .members
+        .iter()
+        .filter_map(|member| match member {
+            ClassLikeMember::Method(method) => Some(method),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let data_providers =
+        methods.iter().flat_map(|method| get_data_providers(method, context)).map(|(name, _)| name).collect::<Vec<_>>();
+
+    methods
+        .into_iter()
+        .filter(|method| {
+            if method.modifiers.contains_static()
+                || method.modifiers.contains_abstract()
+                || method.modifiers.contains_private()
+                || method.modifiers.contains_protected()
+            {
+                return false;
+            }
+
+            let name = context.lookup(&method.name.value);
+
+            !name.starts_with("__")
+                && !LIFECYCLE_METHODS.iter().any(|lifecycle| lifecycle.eq_ignore_ascii_case(name))
+                && !data_providers.iter().any(|provider| provider.eq_ignore_ascii_case(name))
+        })
+        .collect()
+}
+
+/// Returns `true` if the given method is a test method.
+///
+/// A method is a test method if its name starts with `test`, if it has the `#[Test]` attribute,
+/// or if its docblock contains the `@test` annotation.
+pub fn is_test_method(method: &Method, context: &LintContext<'_>) -> bool {
+    let name = context.lookup(&method.name.value);
+    if name.starts_with("test") && name.chars().nth(4).is_none_or(|c| c == '_' || c.is_uppercase()) {
+        return true;
+    }
+
+    if has_attribute(&method.attribute_lists, context, "Test") {
+        return true;
+    }
+
+    get_method_docblock(method, context).is_some_and(|docblock| has_annotation(docblock, "@test"))
+}
+
+/// Returns `true` if any of the given attribute lists contains an attribute with the given short name.
+pub fn has_attribute(attribute_lists: &Sequence<AttributeList>, context: &LintContext<'_>, short_name: &str) -> bool {
+    !get_attributes(attribute_lists, context, short_name).is_empty()
+}
+
+/// Returns all attributes in the given attribute lists with the given short name.
+pub fn get_attributes<'a>(
+    attribute_lists: &'a Sequence<AttributeList>,
+    context: &LintContext<'_>,
+    short_name: &str,
+) -> Vec<&'a Attribute> {
+    attribute_lists
+        .iter()
+        .flat_map(|attribute_list| attribute_list.attributes.iter())
+        .filter(|attribute| {
+            let name = context.lookup_name(&attribute.name);
+
+            name.rsplit('\\').next().is_some_and(|name| name.eq_ignore_ascii_case(short_name))
+        })
+        .collect()
+}
+
+/// Returns the docblock of the given method, if any.
+///
+/// The docblock may be placed either before the attributes of the method, or between the attributes
+/// and the modifiers.
+pub fn get_method_docblock<'a>(method: &Method, context: &LintContext<'a>) -> Option<&'a str> {
+    let code = &context.module.source.content;
+
+    let declaration_start = method.modifiers.first_span().unwrap_or(method.function.span).start.offset;
+    let mut candidates = vec![declaration_start];
+    if let Some(attribute_list) = method.attribute_lists.first() {
+        candidates.push(attribute_list.span().start.offset);
+    }
+
+    candidates.into_iter().find_map(|offset| {
+        let preceding = code[..offset].trim_end();
+        if !preceding.ends_with("*/") {
+            return None;
+        }
+
+        let start = preceding.rfind("/**")?;
+
+        Some(&preceding[start..])
+    })
+}
+
+/// Returns `true` if the given docblock contains the given annotation, e.g. `@test`.
+pub fn has_annotation(docblock: &str, annotation: &str) -> bool {
+    get_annotation_values(docblock, annotation).next().is_some()
+}
+
+/// Returns the values of all occurrences of the given annotation in the given docblock.
+///
+/// For example, `@dataProvider provideValues` yields `provideValues`.
+pub fn get_annotation_values<'a>(docblock: &'a str, annotation: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    docblock.lines().filter_map(move |line| {
+        let line = line.trim_start().trim_start_matches(['/', '*']).trim();
+        let rest = line.strip_prefix(annotation)?;
+        if rest.starts_with(|c: char| c.is_alphanumeric()) {
+            return None;
+        }
+
+        Some(rest.split_whitespace().next().unwrap_or(""))
+    })
+}
+
+/// Returns the names of the data providers of the given method, along with the span where each is declared.
+///
+/// Data providers are declared using the `#[DataProvider('name')]` attribute, or the `@dataProvider name` annotation.
+pub fn get_data_providers(method: &Method, context: &LintContext<'_>) -> Vec<(String, Span)> {
+    let mut data_providers = vec![];
+
+    for attribute in get_attributes(&method.attribute_lists, context, "DataProvider") {
+        let Some(argument) = attribute.arguments.as_ref().and_then(|arguments| arguments.arguments.first()) else {
+            continue;
+        };
+
+        if let Expression::Literal(Literal::String(string)) = argument.value() {
+            let value = context.lookup(&string.value);
+
+            data_providers.push((value[1..value.len() - 1].to_string(), string.span));
+        }
+    }
+
+    if let Some(docblock) = get_method_docblock(method, context) {
+        for name in get_annotation_values(docblock, "@dataProvider") {
+            if !name.is_empty() {
+                data_providers.push((name.to_string(), method.name.span()));
+            }
+        }
+    }
+
+    data_providers
+}
@@ -1,4 +1,5 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::Plugin;
 use crate::plugin::redundancy::rules::redundant_block::RedundantBlockRule;
 use crate::plugin::redundancy::rules::redundant_closing_tag::RedudnantClosingTagRule;
@@ -26,6 +27,7 @@ impl Plugin for RedundancyPlugin {
             name: "Redundancy",
             description: "Provides rules that detect redundant code constructs.",
             enabled_by_default: true,
+            category: RuleCategory::Style,
         }
     }
 
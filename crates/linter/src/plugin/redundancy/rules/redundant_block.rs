@@ -17,6 +17,7 @@ pub struct RedundantBlockRule;
 impl Rule for RedundantBlockRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant Block", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant blocks around statements.
             "})
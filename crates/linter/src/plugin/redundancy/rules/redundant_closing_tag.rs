@@ -17,6 +17,7 @@ pub struct RedudnantClosingTagRule;
 impl Rule for RedudnantClosingTagRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant Closing Tag", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant closing tags ( `?>` ) at the end of a file.
             "})
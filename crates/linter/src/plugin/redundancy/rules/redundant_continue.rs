@@ -17,6 +17,7 @@ pub struct RedundantContinueRule;
 impl Rule for RedundantContinueRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant Continue", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant `continue` statements in loops.
             "})
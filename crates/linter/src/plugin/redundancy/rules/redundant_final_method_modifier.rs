@@ -17,6 +17,7 @@ pub struct RedundantFinalMethodModifierRule;
 impl Rule for RedundantFinalMethodModifierRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant Final Method Modifier", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant `final` modifiers on methods in final classes or enum methods.
             "})
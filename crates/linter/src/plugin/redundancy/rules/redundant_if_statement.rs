@@ -17,6 +17,7 @@ pub struct RedundantIfStatementRule;
 impl Rule for RedundantIfStatementRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant If Statement", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant `if` statements where the condition always evaluates to true or false.
             "})
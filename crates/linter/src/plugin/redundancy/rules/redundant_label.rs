@@ -17,6 +17,7 @@ pub struct RedundantLabelRule;
 impl Rule for RedundantLabelRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant Label", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant `goto` labels that are declared but not used.
             "})
@@ -18,6 +18,7 @@ pub struct RedundantMethodOverrideRule;
 impl Rule for RedundantMethodOverrideRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant Method Override", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects methods that override a parent method but only call the parent method with the same arguments.
             "})
@@ -16,6 +16,7 @@ pub struct RedundantNoopRule;
 impl Rule for RedundantNoopRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant Noop", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant `noop` statements.
             "})
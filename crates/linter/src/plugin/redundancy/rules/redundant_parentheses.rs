@@ -17,6 +17,7 @@ pub struct RedundantParenthesesRule;
 impl Rule for RedundantParenthesesRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant Parentheses", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant parentheses around expressions.
             "})
@@ -7,6 +7,7 @@ use mago_span::HasPosition;
 use mago_span::HasSpan;
 
 use crate::context::LintContext;
+use crate::definition::RuleCategory;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
@@ -18,6 +19,8 @@ pub struct RedundantStringConcatRule;
 impl Rule for RedundantStringConcatRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant String Concat", Level::Help)
+            .fixable()
+            .with_category(RuleCategory::Performance)
             .with_description(indoc! {"
                 Detects redundant string concatenation expressions.
             "})
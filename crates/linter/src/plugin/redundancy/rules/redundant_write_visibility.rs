@@ -19,6 +19,7 @@ pub struct RedundantWriteVisibilityRule;
 impl Rule for RedundantWriteVisibilityRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Redundant Write Visibility", Level::Help)
+            .fixable()
             .with_description(indoc! {"
                 Detects redundant write visibility modifiers on properties.
             "})
@@ -1,10 +1,13 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::safety::rules::no_error_control_operator::NoErrorControlOperatorRule;
 use crate::plugin::safety::rules::no_eval::NoEvalRule;
 use crate::plugin::safety::rules::no_ffi::NoFFIRule;
 use crate::plugin::safety::rules::no_global::NoGlobalRule;
+use crate::plugin::safety::rules::no_mutable_static_property::NoMutableStaticPropertyRule;
 use crate::plugin::safety::rules::no_request_variable::NoRequestVariableRule;
 use crate::plugin::safety::rules::no_shell_execute_string::NoShellExecuteStringRule;
+use crate::plugin::safety::rules::no_superglobal_access::NoSuperglobalAccessRule;
 use crate::plugin::safety::rules::no_unsafe_finally::NoUnsafeFinallyRule;
 
 use crate::plugin::Plugin;
@@ -21,6 +24,7 @@ impl Plugin for SafetyPlugin {
             name: "Safety",
             description: "Provides rules that enforce best practices for safe code.",
             enabled_by_default: true,
+            category: RuleCategory::Security,
         }
     }
 
@@ -33,6 +37,8 @@ impl Plugin for SafetyPlugin {
             Box::new(NoEvalRule),
             Box::new(NoErrorControlOperatorRule),
             Box::new(NoUnsafeFinallyRule),
+            Box::new(NoMutableStaticPropertyRule),
+            Box::new(NoSuperglobalAccessRule),
         ]
     }
 }
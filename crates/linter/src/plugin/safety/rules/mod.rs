@@ -2,6 +2,8 @@ pub mod no_error_control_operator;
 pub mod no_eval;
 pub mod no_ffi;
 pub mod no_global;
+pub mod no_mutable_static_property;
 pub mod no_request_variable;
 pub mod no_shell_execute_string;
+pub mod no_superglobal_access;
 pub mod no_unsafe_finally;
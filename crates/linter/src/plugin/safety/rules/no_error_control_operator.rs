@@ -1,4 +1,5 @@
 use indoc::indoc;
+use toml::Value;
 
 use mago_ast::*;
 use mago_fixer::SafetyClassification;
@@ -7,21 +8,47 @@ use mago_span::HasSpan;
 
 use crate::context::LintContext;
 use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
 use crate::rule::Rule;
 
+const ALLOWED_FUNCTIONS: &str = "allowed_functions";
+
 #[derive(Clone, Debug)]
 pub struct NoErrorControlOperatorRule;
 
 impl Rule for NoErrorControlOperatorRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("No Error Control Operator", Level::Error)
+            .fixable()
             .with_description(indoc! {"
                 Detects the use of the error control operator `@`.
 
                 The error control operator suppresses errors and makes debugging more difficult.
+
+                Suppressing errors of calls to specific functions, such as `unlink` or `mkdir` whose failure is
+                handled using their return value, can be allowed using the `allowed_functions` option.
             "})
+            .with_option(RuleOptionDefinition {
+                name: ALLOWED_FUNCTIONS,
+                r#type: "array<string>",
+                description: "A list of function names whose calls may be prefixed with `@` (case-insensitive).",
+                default: Value::Array(vec![]),
+            })
+            .with_example(
+                RuleUsageExample::valid(
+                    "Suppressing errors of an allowed function",
+                    indoc! {r#"
+                        <?php
+
+                        if (!@unlink($file)) {
+                            echo 'Could not remove the file.';
+                        }
+                    "#},
+                )
+                .with_option(ALLOWED_FUNCTIONS, Value::Array(vec![Value::String("unlink".to_owned())])),
+            )
             .with_example(RuleUsageExample::invalid(
                 "An unsafe use of the error control operator `@`",
                 indoc! {r#"
@@ -36,6 +63,10 @@ impl Rule for NoErrorControlOperatorRule {
         let Node::UnaryPrefix(unary_prefix) = node else { return LintDirective::default() };
 
         if let UnaryPrefixOperator::ErrorControl(_) = unary_prefix.operator {
+            if is_allowed_function_call(&unary_prefix.operand, context) {
+                return LintDirective::default();
+            }
+
             let issue = Issue::new(context.level(), "Unsafe use of error control operator `@`.")
                 .with_annotation(
                     Annotation::primary(unary_prefix.operator.span()).with_message("This operator suppresses errors."),
@@ -55,3 +86,20 @@ impl Rule for NoErrorControlOperatorRule {
         LintDirective::default()
     }
 }
+
+/// Returns `true` if the given expression is a call to a function allowed by the `allowed_functions` option.
+fn is_allowed_function_call(expression: &Expression, context: &LintContext<'_>) -> bool {
+    let Expression::Call(Call::Function(function_call)) = expression else {
+        return false;
+    };
+
+    let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+        return false;
+    };
+
+    let function_name = context.resolve_function_name(identifier);
+
+    context.option(ALLOWED_FUNCTIONS).and_then(|o| o.as_array()).is_some_and(|functions| {
+        functions.iter().any(|f| f.as_str().is_some_and(|f| f.eq_ignore_ascii_case(function_name)))
+    })
+}
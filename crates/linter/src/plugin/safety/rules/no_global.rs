@@ -1,4 +1,5 @@
 use indoc::indoc;
+use toml::Value;
 
 use mago_ast::*;
 use mago_reporting::*;
@@ -6,6 +7,7 @@ use mago_span::HasSpan;
 
 use crate::context::LintContext;
 use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
 use crate::rule::Rule;
@@ -20,7 +22,15 @@ impl Rule for NoGlobalRule {
                 Detects the use of the `global` keyword and the `$GLOBALS` variable.
 
                 The `global` keyword introduces global state into your function, making it harder to reason about and test.
+
+                Sources matching one of the `allowed_paths` glob patterns, e.g. `legacy/**`, are not reported.
             "})
+            .with_option(RuleOptionDefinition {
+                name: ALLOWED_PATHS,
+                r#type: "array<string>",
+                description: "Glob patterns of the paths, relative to the workspace, where global state is allowed.",
+                default: Value::Array(vec![]),
+            })
             .with_example(RuleUsageExample::invalid(
                 "Using the `global` keyword",
                 indoc! {r#"
@@ -51,6 +61,7 @@ impl Rule for NoGlobalRule {
 
     fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
         match node {
+            Node::Program(_) if context.is_source_matched(ALLOWED_PATHS) => LintDirective::Abort,
             Node::Global(global) => {
                 let mut issue = Issue::new(context.level(), "Unsafe use of `global` keyword.")
                     .with_annotation(Annotation::primary(global.global.span).with_message("This `global` keyword is used here."))
@@ -92,3 +103,4 @@ impl Rule for NoGlobalRule {
 }
 
 const GLOBALS_VARIABLE: &str = "$GLOBALS";
+const ALLOWED_PATHS: &str = "allowed_paths";
@@ -0,0 +1,90 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+const ALLOWED_PATHS: &str = "allowed_paths";
+
+#[derive(Clone, Debug)]
+pub struct NoMutableStaticPropertyRule;
+
+impl Rule for NoMutableStaticPropertyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("No Mutable Static Property")
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects declarations of static properties.
+
+                Static properties cannot be `readonly`, so they hold mutable state shared by the whole
+                process, i.e. global state, making the code harder to reason about and test. Constants,
+                or properties of an injected service, can be used instead.
+
+                Sources matching one of the `allowed_paths` glob patterns, e.g. `legacy/**`, are not reported.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: ALLOWED_PATHS,
+                r#type: "array<string>",
+                description: "Glob patterns of the paths, relative to the workspace, where static properties are allowed.",
+                default: Value::Array(vec![]),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Using a constant",
+                indoc! {r#"
+                    <?php
+
+                    final class Currency
+                    {
+                        public const array SUPPORTED = ['EUR', 'USD'];
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring a static property",
+                indoc! {r#"
+                    <?php
+
+                    final class Registry
+                    {
+                        private static array $services = [];
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let property = match node {
+            Node::Program(_) if context.is_source_matched(ALLOWED_PATHS) => return LintDirective::Abort,
+            Node::Property(property) => property,
+            _ => return LintDirective::default(),
+        };
+
+        let Some(r#static) = property.modifiers().get_static() else {
+            return LintDirective::default();
+        };
+
+        for variable in property.variables() {
+            let name = context.lookup(&variable.name);
+
+            context.report(
+                Issue::new(context.level(), format!("Static property `{}` holds mutable global state.", name))
+                    .with_annotation(
+                        Annotation::primary(variable.span()).with_message(format!("`{}` is declared here.", name)),
+                    )
+                    .with_annotation(Annotation::secondary(r#static.span()).with_message("The property is static."))
+                    .with_note("Static properties are shared by the whole process, and can be modified from anywhere.")
+                    .with_help("Use a constant, or a property of an injected service, instead."),
+            );
+        }
+
+        LintDirective::default()
+    }
+}
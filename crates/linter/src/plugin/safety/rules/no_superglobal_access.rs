@@ -0,0 +1,139 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+use crate::scope::ClassLikeScope;
+
+const SUPERGLOBAL_VARIABLES: [&str; 8] =
+    ["$_GET", "$_POST", "$_COOKIE", "$_REQUEST", "$_SERVER", "$_FILES", "$_ENV", "$_SESSION"];
+
+const ALLOWED_PATHS: &str = "allowed_paths";
+const ALLOWED_CLASSES: &str = "allowed_classes";
+
+#[derive(Clone, Debug)]
+pub struct NoSuperglobalAccessRule;
+
+impl Rule for NoSuperglobalAccessRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("No Superglobal Access")
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects direct access to superglobal variables, such as `$_GET`, `$_POST`, or `$_SERVER`,
+                outside of the layer allowed to read the request, e.g. controllers, or request classes.
+
+                Reading superglobals deep within the application couples it to the request it is
+                handling, making it harder to reason about and test. Reading them once, at the edge
+                of the application, and passing the values along avoids this.
+
+                The allowed layer is configured using the `allowed_paths` glob patterns, matched
+                against the path of the source, e.g. `src/Http/**`, and the `allowed_classes` glob
+                patterns, matched against the fully qualified name of the enclosing class, e.g.
+                `App\\Http\\**\\*Controller`, where `*` does not match namespace separators, but `**` does.
+
+                The `$GLOBALS` variable is reported by the `no-global` rule instead.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: ALLOWED_PATHS,
+                r#type: "array<string>",
+                description: "Glob patterns of the paths, relative to the workspace, where superglobals may be accessed.",
+                default: Value::Array(vec![]),
+            })
+            .with_option(RuleOptionDefinition {
+                name: ALLOWED_CLASSES,
+                r#type: "array<string>",
+                description: "Glob patterns of the fully qualified names of the classes where superglobals may be accessed.",
+                default: Value::Array(vec![]),
+            })
+            .with_example(
+                RuleUsageExample::valid(
+                    "Accessing a superglobal within an allowed class",
+                    indoc! {r#"
+                        <?php
+
+                        namespace App\Http;
+
+                        final class RequestFactory
+                        {
+                            public function create(): Request
+                            {
+                                return new Request($_GET, $_POST, $_SERVER);
+                            }
+                        }
+                    "#},
+                )
+                .with_option(ALLOWED_CLASSES, Value::Array(vec![Value::String("App\\Http\\*".to_string())])),
+            )
+            .with_example(RuleUsageExample::invalid(
+                "Accessing a superglobal deep within the application",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Billing;
+
+                    final class InvoiceRepository
+                    {
+                        public function findForCurrentUser(): array
+                        {
+                            return $this->findByUser((int) $_SESSION['user_id']);
+                        }
+                    }
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let direct_variable = match node {
+            Node::Program(_) if context.is_source_matched(ALLOWED_PATHS) => return LintDirective::Abort,
+            Node::DirectVariable(direct_variable) => direct_variable,
+            _ => return LintDirective::default(),
+        };
+
+        let name = context.lookup(&direct_variable.name);
+        if !SUPERGLOBAL_VARIABLES.contains(&name) || is_in_allowed_class(context) {
+            return LintDirective::default();
+        }
+
+        context.report(
+            Issue::new(context.level(), format!("Superglobal `{}` is accessed outside of the allowed layer.", name))
+                .with_annotation(
+                    Annotation::primary(direct_variable.span).with_message(format!("`{}` is accessed here.", name)),
+                )
+                .with_note("Accessing superglobals deep within the application couples it to the current request.")
+                .with_help("Read the value at the edge of the application, e.g. in a controller, and pass it along."),
+        );
+
+        LintDirective::default()
+    }
+}
+
+fn is_in_allowed_class(context: &LintContext<'_>) -> bool {
+    let Some(patterns) = context.option(ALLOWED_CLASSES).and_then(|option| option.as_array()) else {
+        return false;
+    };
+
+    let class_name = match context.scope.get_class_like_scope() {
+        Some(
+            ClassLikeScope::Class(name)
+            | ClassLikeScope::Trait(name)
+            | ClassLikeScope::Enum(name)
+            | ClassLikeScope::Interface(name),
+        ) => context.lookup(name),
+        _ => return false,
+    };
+
+    // Namespace separators are matched like path separators, as `\` escapes special characters in glob patterns.
+    let class_name = class_name.replace('\\', "/");
+
+    patterns
+        .iter()
+        .filter_map(|pattern| pattern.as_str())
+        .any(|pattern| glob_match::glob_match(&pattern.replace('\\', "/"), &class_name))
+}
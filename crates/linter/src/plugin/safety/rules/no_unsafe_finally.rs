@@ -7,6 +7,7 @@ use mago_reporting::*;
 use mago_span::HasSpan;
 
 use crate::context::LintContext;
+use crate::definition::RuleCategory;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
@@ -18,6 +19,7 @@ pub struct NoUnsafeFinallyRule;
 impl Rule for NoUnsafeFinallyRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("No Unsafe Finally", Level::Error)
+            .with_category(RuleCategory::Correctness)
             .with_description(indoc! {"
                 Detects control flow statements in `finally` blocks.
 
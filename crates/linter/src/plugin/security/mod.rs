@@ -1,4 +1,6 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
+use crate::plugin::security::rules::no_dynamic_sql_query::NoDynamicSqlQueryRule;
 use crate::plugin::security::rules::no_insecure_comparison::NoInsecureComparisonRule;
 use crate::plugin::security::rules::no_literal_password::NoLiteralPasswordRule;
 use crate::plugin::security::rules::tainted_data_to_skin::TaintedDataToSinkRule;
@@ -17,10 +19,16 @@ impl Plugin for SecurityPlugin {
             name: "Security",
             description: "Provides rules that enforce best practices for security.",
             enabled_by_default: true,
+            category: RuleCategory::Security,
         }
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
-        vec![Box::new(NoInsecureComparisonRule), Box::new(NoLiteralPasswordRule), Box::new(TaintedDataToSinkRule)]
+        vec![
+            Box::new(NoDynamicSqlQueryRule),
+            Box::new(NoInsecureComparisonRule),
+            Box::new(NoLiteralPasswordRule),
+            Box::new(TaintedDataToSinkRule),
+        ]
     }
 }
@@ -1,3 +1,4 @@
+pub mod no_dynamic_sql_query;
 pub mod no_insecure_comparison;
 pub mod no_literal_password;
 pub mod tainted_data_to_skin;
@@ -0,0 +1,289 @@
+use std::sync::LazyLock;
+
+use indoc::indoc;
+use regex::Regex;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::security::rules::utils::is_user_input;
+use crate::rule::Rule;
+use crate::utils::get_string_value;
+
+const FUNCTIONS: &str = "functions";
+const FUNCTIONS_DEFAULT: [&str; 10] = [
+    "mysqli_query",
+    "mysqli_real_query",
+    "mysqli_multi_query",
+    "mysqli_prepare",
+    "pg_query",
+    "pg_send_query",
+    "pg_prepare",
+    "odbc_exec",
+    "db2_exec",
+    "oci_parse",
+];
+const METHODS: &str = "methods";
+const METHODS_DEFAULT: [&str; 7] =
+    ["query", "exec", "prepare", "multi_query", "real_query", "executeQuery", "executeStatement"];
+
+/// Matches strings starting with an SQL statement, e.g. `SELECT ... FROM`, or `INSERT INTO`.
+static SQL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?is)^[\s(]*(select\b.*\bfrom|insert\s+into|update\b.*\bset|delete\s+from|replace\s+into|merge\s+into)\b",
+    )
+    .unwrap()
+});
+
+#[derive(Clone, Debug)]
+pub struct NoDynamicSqlQueryRule;
+
+impl Rule for NoDynamicSqlQueryRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("No Dynamic SQL Query")
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {r#"
+                Detects strings that look like SQL queries built from interpolated or concatenated values,
+                and suggests using parameterized queries instead, to prevent SQL injection.
+
+                Such strings are reported when passed to one of the configured query functions or methods,
+                or anywhere when they contain user input, e.g. `$_GET['id']`.
+
+                The detection is heuristic: only strings starting with an SQL statement, such as
+                `SELECT ... FROM` or `INSERT INTO`, are considered.
+            "#})
+            .with_option(RuleOptionDefinition {
+                name: FUNCTIONS,
+                r#type: "array<string>",
+                description: "The functions executing SQL queries (case-insensitive).",
+                default: Value::Array(FUNCTIONS_DEFAULT.iter().map(|f| Value::String(f.to_string())).collect()),
+            })
+            .with_option(RuleOptionDefinition {
+                name: METHODS,
+                r#type: "array<string>",
+                description: "The methods executing SQL queries, on any object (case-insensitive).",
+                default: Value::Array(METHODS_DEFAULT.iter().map(|m| Value::String(m.to_string())).collect()),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Using a parameterized query",
+                indoc! {r#"
+                    <?php
+
+                    $statement = $pdo->prepare('SELECT * FROM users WHERE id = :id');
+                    $statement->execute(['id' => $id]);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Interpolating a value into a query",
+                indoc! {r#"
+                    <?php
+
+                    $result = $pdo->query("SELECT * FROM users WHERE id = {$id}");
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Concatenating user input into a query",
+                indoc! {r#"
+                    <?php
+
+                    $sql = 'DELETE FROM users WHERE id = ' . $_GET['id'];
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let (name, argument_list) = match node {
+            Node::FunctionCall(function_call) => {
+                let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+                    return LintDirective::default();
+                };
+
+                let name = context.resolve_function_name(identifier).to_string();
+                if !is_configured(context, FUNCTIONS, &FUNCTIONS_DEFAULT, &name) {
+                    return LintDirective::default();
+                }
+
+                (name, &function_call.argument_list)
+            }
+            Node::MethodCall(MethodCall { method, argument_list, .. })
+            | Node::NullSafeMethodCall(NullSafeMethodCall { method, argument_list, .. }) => {
+                let ClassLikeMemberSelector::Identifier(identifier) = method else {
+                    return LintDirective::default();
+                };
+
+                let name = context.lookup(&identifier.value).to_string();
+                if !is_configured(context, METHODS, &METHODS_DEFAULT, &name) {
+                    return LintDirective::default();
+                }
+
+                (name, argument_list)
+            }
+            Node::Binary(binary) if binary.operator.is_concatenation() => {
+                let mut text = String::new();
+                let mut dynamic_values = vec![];
+                collect_parts(context, &binary.lhs, &mut text, &mut dynamic_values);
+                collect_parts(context, &binary.rhs, &mut text, &mut dynamic_values);
+
+                // The chain is checked as a whole, the shorter chains it contains are not.
+                return check_user_input(context, binary.span(), &text, dynamic_values);
+            }
+            Node::CompositeString(string) => {
+                let mut text = String::new();
+                let mut dynamic_values = vec![];
+                collect_string_parts(context, string, &mut text, &mut dynamic_values);
+
+                return check_user_input(context, string.span(), &text, dynamic_values);
+            }
+            _ => return LintDirective::default(),
+        };
+
+        let mut reported = false;
+        for argument in argument_list.arguments.iter() {
+            let value = argument.value();
+            let Some(dynamic_values) = get_dynamic_values(context, value) else {
+                continue;
+            };
+
+            let mut issue = Issue::new(context.level(), format!("Dynamic SQL query passed to `{}`.", name))
+                .with_annotation(
+                    Annotation::primary(value.span()).with_message("This query is built from dynamic values."),
+                );
+
+            for dynamic_value in dynamic_values {
+                let message = if is_user_input(context, dynamic_value) {
+                    "This value originates from user input."
+                } else {
+                    "This value is inserted into the query."
+                };
+
+                issue = issue.with_annotation(Annotation::secondary(dynamic_value.span()).with_message(message));
+            }
+
+            context.report(
+                issue
+                    .with_note("Building SQL queries from dynamic values allows SQL injection, unless they are properly escaped.")
+                    .with_help("Use a parameterized query, passing the values as parameters instead."),
+            );
+
+            reported = true;
+        }
+
+        if reported { LintDirective::Prune } else { LintDirective::default() }
+    }
+}
+
+/// Reports a string that looks like an SQL query, and contains user input.
+fn check_user_input(
+    context: &mut LintContext<'_>,
+    span: Span,
+    text: &str,
+    dynamic_values: Vec<&Expression>,
+) -> LintDirective {
+    if !SQL_REGEX.is_match(text) {
+        return LintDirective::default();
+    }
+
+    let user_inputs = dynamic_values.into_iter().filter(|value| is_user_input(context, value)).collect::<Vec<_>>();
+    if user_inputs.is_empty() {
+        return LintDirective::Prune;
+    }
+
+    let mut issue = Issue::new(context.level(), "User input is used to build an SQL query.")
+        .with_annotation(Annotation::primary(span).with_message("This string looks like an SQL query."));
+
+    for user_input in user_inputs {
+        issue = issue.with_annotation(
+            Annotation::secondary(user_input.span()).with_message("This value originates from user input."),
+        );
+    }
+
+    context.report(
+        issue
+            .with_note("Building SQL queries from user input allows SQL injection.")
+            .with_help("Use a parameterized query, passing the values as parameters instead."),
+    );
+
+    LintDirective::Prune
+}
+
+fn is_configured(context: &LintContext<'_>, option: &'static str, defaults: &[&str], name: &str) -> bool {
+    match context.option(option).and_then(|o| o.as_array()) {
+        Some(names) => names.iter().any(|n| n.as_str().is_some_and(|n| n.eq_ignore_ascii_case(name))),
+        None => defaults.iter().any(|n| n.eq_ignore_ascii_case(name)),
+    }
+}
+
+/// Returns the dynamic values of a string that looks like an SQL query, if any.
+///
+/// Returns `None` if the expression is not a string built from dynamic values, or does not look like an SQL query.
+fn get_dynamic_values<'a>(context: &LintContext<'_>, expression: &'a Expression) -> Option<Vec<&'a Expression>> {
+    let mut text = String::new();
+    let mut dynamic_values = vec![];
+    collect_parts(context, expression, &mut text, &mut dynamic_values);
+
+    if dynamic_values.is_empty() || !SQL_REGEX.is_match(&text) {
+        return None;
+    }
+
+    Some(dynamic_values)
+}
+
+/// Collects the literal text of a string, with placeholders for its dynamic values, which are collected separately.
+fn collect_parts<'a>(
+    context: &LintContext<'_>,
+    expression: &'a Expression,
+    text: &mut String,
+    dynamic_values: &mut Vec<&'a Expression>,
+) {
+    match expression {
+        Expression::Parenthesized(parenthesized) => {
+            collect_parts(context, &parenthesized.expression, text, dynamic_values);
+        }
+        Expression::Binary(binary) if binary.operator.is_concatenation() => {
+            collect_parts(context, &binary.lhs, text, dynamic_values);
+            collect_parts(context, &binary.rhs, text, dynamic_values);
+        }
+        Expression::Literal(Literal::String(string)) => {
+            text.push_str(&get_string_value(string, context.interner).unwrap_or_default());
+        }
+        Expression::Literal(_) => {
+            text.push('0');
+        }
+        Expression::CompositeString(string @ (CompositeString::Interpolated(_) | CompositeString::Document(_))) => {
+            collect_string_parts(context, string, text, dynamic_values);
+        }
+        _ => {
+            text.push('?');
+            dynamic_values.push(expression);
+        }
+    }
+}
+
+fn collect_string_parts<'a>(
+    context: &LintContext<'_>,
+    string: &'a CompositeString,
+    text: &mut String,
+    dynamic_values: &mut Vec<&'a Expression>,
+) {
+    for part in string.parts().iter() {
+        match part {
+            StringPart::Literal(literal) => text.push_str(context.lookup(&literal.value)),
+            StringPart::Expression(value) => {
+                text.push('?');
+                dynamic_values.push(value);
+            }
+            StringPart::BracedExpression(braced) => {
+                text.push('?');
+                dynamic_values.push(&braced.expression);
+            }
+        }
+    }
+}
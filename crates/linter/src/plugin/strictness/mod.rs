@@ -1,4 +1,5 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
 use crate::plugin::strictness::rules::missing_assert_description::MissingAssertDescriptionRule;
 use crate::plugin::strictness::rules::no_assignment_in_condition::NoAssignmentInConditionRule;
 use crate::plugin::strictness::rules::require_constant_type::RequireConstantTypeRule;
@@ -6,6 +7,7 @@ use crate::plugin::strictness::rules::require_identity_comparison::RequireIdenti
 use crate::plugin::strictness::rules::require_parameter_type::RequireParameterTypeRule;
 use crate::plugin::strictness::rules::require_property_type::RequirePropertyTypeRule;
 use crate::plugin::strictness::rules::require_return_type::RequireReturnTypeRule;
+use crate::plugin::strictness::rules::require_strict_search::RequireStrictSearchRule;
 use crate::plugin::strictness::rules::require_strict_types::RequireStrictTypesRule;
 
 use crate::plugin::Plugin;
@@ -22,6 +24,7 @@ impl Plugin for StrictnessPlugin {
             name: "Strictness",
             description: "Provides rules that enforce strictness in the codebase.",
             enabled_by_default: true,
+            category: RuleCategory::Correctness,
         }
     }
 
@@ -35,6 +38,7 @@ impl Plugin for StrictnessPlugin {
             Box::new(RequireReturnTypeRule),
             Box::new(RequireStrictTypesRule),
             Box::new(RequireIdentityComparisonRule),
+            Box::new(RequireStrictSearchRule),
         ]
     }
 }
@@ -1,3 +1,5 @@
+mod utils;
+
 pub mod missing_assert_description;
 pub mod no_assignment_in_condition;
 pub mod require_constant_type;
@@ -5,4 +7,5 @@ pub mod require_identity_comparison;
 pub mod require_parameter_type;
 pub mod require_property_type;
 pub mod require_return_type;
+pub mod require_strict_search;
 pub mod require_strict_types;
@@ -8,6 +8,7 @@ use crate::context::LintContext;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleUsageExample;
 use crate::directive::LintDirective;
+use crate::plugin::strictness::rules::utils::is_provably_same_type;
 use crate::rule::Rule;
 
 #[derive(Clone, Debug)]
@@ -16,8 +17,13 @@ pub struct RequireIdentityComparisonRule;
 impl Rule for RequireIdentityComparisonRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Require Identity Comparison", Level::Warning)
+            .fixable()
             .with_description(indoc! {"
                 Detects equality and inequality comparisons that should use identity comparison operators.
+
+                The proposed fix is only considered safe when both operands are provably of the same type,
+                such as two integer literals or a non-numeric string literal compared to another one, as the
+                result of the comparison is then guaranteed not to change.
             "})
             .with_example(RuleUsageExample::valid(
                 "An identity comparison operator",
@@ -48,6 +54,12 @@ impl Rule for RequireIdentityComparisonRule {
     fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
         let Node::Binary(binary) = node else { return LintDirective::default() };
 
+        let safety = if is_provably_same_type(context, &binary.lhs, &binary.rhs) {
+            SafetyClassification::Safe
+        } else {
+            SafetyClassification::Unsafe
+        };
+
         match &binary.operator {
             // `==` -> `===`
             BinaryOperator::Equal(span) => {
@@ -60,7 +72,7 @@ impl Rule for RequireIdentityComparisonRule {
                         )
                         .with_help("Use `===` to ensure both value and type are equal.");
 
-                context.propose(issue, |plan| plan.replace(span.to_range(), "===", SafetyClassification::Unsafe));
+                context.propose(issue, |plan| plan.replace(span.to_range(), "===", safety));
             }
             // `!=` -> `!==`
             BinaryOperator::NotEqual(span) => {
@@ -73,7 +85,7 @@ impl Rule for RequireIdentityComparisonRule {
                         )
                         .with_help("Use `!==` to ensure both value and type are different.");
 
-                context.propose(issue, |plan| plan.replace(span.to_range(), "!==", SafetyClassification::Unsafe));
+                context.propose(issue, |plan| plan.replace(span.to_range(), "!==", safety));
             }
             // `<>` -> `!==`
             BinaryOperator::AngledNotEqual(span) => {
@@ -88,7 +100,7 @@ impl Rule for RequireIdentityComparisonRule {
                 )
                 .with_help("Use `!==` to ensure both value and type are different.");
 
-                context.propose(issue, |plan| plan.replace(span.to_range(), "!==", SafetyClassification::Unsafe));
+                context.propose(issue, |plan| plan.replace(span.to_range(), "!==", safety));
             }
             _ => {}
         }
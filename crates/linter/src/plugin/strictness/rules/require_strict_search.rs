@@ -0,0 +1,178 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::*;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::plugin::strictness::rules::utils::get_provable_type;
+use crate::rule::Rule;
+
+/// The functions performing a loose search by default, along with the names of their parameters, in order.
+const SEARCH_FUNCTIONS: [(&str, [&str; 3]); 3] = [
+    ("in_array", ["needle", "haystack", "strict"]),
+    ("array_search", ["needle", "haystack", "strict"]),
+    ("array_keys", ["array", "filter_value", "strict"]),
+];
+
+#[derive(Clone, Debug)]
+pub struct RequireStrictSearchRule;
+
+impl Rule for RequireStrictSearchRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Require Strict Search", Level::Warning)
+            .fixable()
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects calls to `in_array`, `array_search`, and `array_keys` (when searching for a value)
+                that do not enable strict comparison using the `$strict` parameter.
+
+                Without strict comparison, the needle is compared to the haystack values using `==`, which
+                performs type coercion and can lead to unexpected results.
+
+                The proposed fix is only considered safe when the needle and all values of a literal haystack
+                are provably of the same type.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A strict search",
+                indoc! {r#"
+                    <?php
+
+                    if (in_array($value, ['a', 'b'], true)) {
+                        echo 'Found!';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A loose search",
+                indoc! {r#"
+                    <?php
+
+                    if (in_array($value, ['a', 'b'])) {
+                        echo 'Found!';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A search that explicitly disables strict comparison",
+                indoc! {r#"
+                    <?php
+
+                    $key = array_search($value, $values, false);
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::FunctionCall(function_call) = node else { return LintDirective::default() };
+
+        let Expression::Identifier(function_identifier) = function_call.function.as_ref() else {
+            return LintDirective::default();
+        };
+
+        let function_name = context.resolve_function_name(function_identifier).to_owned();
+        let Some((_, parameters)) = SEARCH_FUNCTIONS.iter().find(|(name, _)| function_name.eq_ignore_ascii_case(name))
+        else {
+            return LintDirective::default();
+        };
+
+        let arguments = &function_call.argument_list.arguments;
+        if arguments.iter().any(|argument| match argument {
+            Argument::Positional(argument) => argument.ellipsis.is_some(),
+            Argument::Named(argument) => argument.ellipsis.is_some(),
+        }) {
+            // Arguments are unpacked, so the `$strict` parameter cannot be determined.
+            return LintDirective::default();
+        }
+
+        let mut needle = None;
+        let mut haystack = None;
+        let mut strict = None;
+        for (position, argument) in arguments.iter().enumerate() {
+            let name = match argument {
+                Argument::Positional(_) => match parameters.get(position) {
+                    Some(name) => *name,
+                    None => continue,
+                },
+                Argument::Named(argument) => context.lookup(&argument.name.value),
+            };
+
+            match name {
+                "needle" | "filter_value" => needle = Some(argument.value()),
+                "haystack" | "array" => haystack = Some(argument.value()),
+                "strict" => strict = Some(argument.value()),
+                _ => {}
+            }
+        }
+
+        // `array_keys` only performs a search if a value to search for is given.
+        let Some(needle) = needle else {
+            return LintDirective::default();
+        };
+
+        let safety = match haystack {
+            Some(haystack) if is_provably_strict_safe(context, needle, haystack) => SafetyClassification::Safe,
+            _ => SafetyClassification::Unsafe,
+        };
+
+        match strict {
+            Some(Expression::Literal(Literal::False(keyword))) => {
+                let issue = create_issue(context, &function_name, function_call.span()).with_annotation(
+                    Annotation::secondary(keyword.span).with_message("Strict comparison is disabled here."),
+                );
+
+                context.propose(issue, |plan| plan.replace(keyword.span.to_range(), "true", safety));
+            }
+            Some(_) => {}
+            None => {
+                let Some(last_argument) = arguments.last() else {
+                    return LintDirective::default();
+                };
+
+                let has_named_arguments = arguments.iter().any(|argument| matches!(argument, Argument::Named(_)));
+                let insertion = if has_named_arguments { ", strict: true".to_owned() } else { ", true".to_owned() };
+                let offset = last_argument.span().end.offset;
+
+                let issue = create_issue(context, &function_name, function_call.span());
+
+                context.propose(issue, |plan| plan.insert(offset, insertion, safety));
+            }
+        }
+
+        LintDirective::default()
+    }
+}
+
+fn create_issue(context: &LintContext<'_>, function_name: &str, span: Span) -> Issue {
+    Issue::new(context.level(), format!("Call to `{}` does not use strict comparison.", function_name))
+        .with_annotation(Annotation::primary(span).with_message(format!("`{}` is called here.", function_name)))
+        .with_note("Without strict comparison, values are compared using `==`, which performs type coercion.")
+        .with_help("Pass `true` as the `$strict` argument to compare values using `===`.")
+}
+
+/// Returns `true` if the needle and all values of the haystack are provably of the same type.
+fn is_provably_strict_safe(context: &LintContext<'_>, needle: &Expression, haystack: &Expression) -> bool {
+    let Some(needle_type) = get_provable_type(context, needle) else {
+        return false;
+    };
+
+    let elements = match haystack {
+        Expression::Array(array) => array.elements.iter(),
+        Expression::LegacyArray(array) => array.elements.iter(),
+        _ => return false,
+    };
+
+    elements.into_iter().all(|element| {
+        let value = match element {
+            ArrayElement::KeyValue(element) => &element.value,
+            ArrayElement::Value(element) => &element.value,
+            _ => return false,
+        };
+
+        get_provable_type(context, value) == Some(needle_type)
+    })
+}
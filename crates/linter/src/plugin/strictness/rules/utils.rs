@@ -0,0 +1,58 @@
+use mago_ast::*;
+
+use crate::context::LintContext;
+
+/// A type that an expression is statically known to evaluate to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProvableType {
+    Integer,
+    Float,
+    Boolean,
+    Null,
+    /// A string that is not numeric, and therefore never subject to numeric comparison.
+    NonNumericString,
+}
+
+/// Returns the type the given expression is statically known to evaluate to, if any.
+///
+/// Only literals and scalar casts are considered. String literals are only considered when they
+/// are provably non-numeric, as numeric strings are compared numerically by `==`.
+pub fn get_provable_type(context: &LintContext<'_>, expression: &Expression) -> Option<ProvableType> {
+    match expression {
+        Expression::Parenthesized(parenthesized) => get_provable_type(context, &parenthesized.expression),
+        Expression::Literal(Literal::Integer(_)) => Some(ProvableType::Integer),
+        Expression::Literal(Literal::Float(_)) => Some(ProvableType::Float),
+        Expression::Literal(Literal::True(_) | Literal::False(_)) => Some(ProvableType::Boolean),
+        Expression::Literal(Literal::Null(_)) => Some(ProvableType::Null),
+        Expression::Literal(Literal::String(string)) => {
+            let value = context.lookup(&string.value);
+
+            // Escape sequences could produce digits, so only plain strings without digits are considered.
+            if value.bytes().any(|byte| byte.is_ascii_digit() || byte == b'\\' || byte == b'$') {
+                None
+            } else {
+                Some(ProvableType::NonNumericString)
+            }
+        }
+        Expression::UnaryPrefix(UnaryPrefix { operator, .. }) => match operator {
+            UnaryPrefixOperator::IntCast(_, _) | UnaryPrefixOperator::IntegerCast(_, _) => Some(ProvableType::Integer),
+            UnaryPrefixOperator::FloatCast(_, _)
+            | UnaryPrefixOperator::DoubleCast(_, _)
+            | UnaryPrefixOperator::RealCast(_, _) => Some(ProvableType::Float),
+            UnaryPrefixOperator::BoolCast(_, _)
+            | UnaryPrefixOperator::BooleanCast(_, _)
+            | UnaryPrefixOperator::Not(_) => Some(ProvableType::Boolean),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns `true` if both expressions are statically known to evaluate to the same type, in which
+/// case loose and strict comparisons between them are equivalent.
+pub fn is_provably_same_type(context: &LintContext<'_>, left: &Expression, right: &Expression) -> bool {
+    match (get_provable_type(context, left), get_provable_type(context, right)) {
+        (Some(left), Some(right)) => left == right,
+        _ => false,
+    }
+}
@@ -1,4 +1,6 @@
 use crate::definition::PluginDefinition;
+use crate::definition::RuleCategory;
+use crate::plugin::symfony::rules::best_practices::no_service_string_id::NoServiceStringIdRule;
 use crate::plugin::symfony::rules::quality::interface_should_be_used::InterfaceShouldBeUsed;
 
 use crate::plugin::Plugin;
@@ -15,10 +17,11 @@ impl Plugin for SymfonyPlugin {
             name: "Symfony",
             description: "Provides rules that enforce best practices for Symfony applications.",
             enabled_by_default: false,
+            category: RuleCategory::Framework,
         }
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
-        vec![Box::new(InterfaceShouldBeUsed)]
+        vec![Box::new(InterfaceShouldBeUsed), Box::new(NoServiceStringIdRule)]
     }
 }
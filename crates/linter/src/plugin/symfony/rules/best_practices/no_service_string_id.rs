@@ -0,0 +1,103 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::directive::LintDirective;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NoServiceStringIdRule;
+
+impl Rule for NoServiceStringIdRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Service String Id", Level::Warning)
+            .with_introduced_in("0.12.0")
+            .with_description(indoc! {"
+                Detects services fetched from the container using a string identifier, such as `app.mailer`,
+                instead of a class name.
+
+                String identifiers cannot be resolved by static analysis tools and IDEs, and fetching services
+                from the container hides the dependencies of a class. Prefer injecting the service, or
+                fetching it using its class name.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Fetching a service using its class name",
+                indoc! {r#"
+                    <?php
+
+                    use App\Mailer;
+
+                    $mailer = $container->get(Mailer::class);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Fetching a service using a string identifier",
+                indoc! {r#"
+                    <?php
+
+                    $mailer = $this->container->get('app.mailer');
+                "#},
+            ))
+    }
+
+    fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
+        let Node::MethodCall(method_call) = node else { return LintDirective::default() };
+
+        let ClassLikeMemberSelector::Identifier(method) = &method_call.method else {
+            return LintDirective::default();
+        };
+
+        if !context.lookup(&method.value).eq_ignore_ascii_case("get") || !is_container(context, &method_call.object) {
+            return LintDirective::default();
+        }
+
+        let Some(Argument::Positional(argument)) = method_call.argument_list.arguments.first() else {
+            return LintDirective::default();
+        };
+
+        let Expression::Literal(Literal::String(string)) = &argument.value else {
+            return LintDirective::default();
+        };
+
+        let value = context.lookup(&string.value);
+        let id = &value[1..value.len() - 1];
+        if id.contains('\\') {
+            return LintDirective::default();
+        }
+
+        let issue = Issue::new(context.level(), format!("Service `{}` is fetched using a string identifier.", id))
+            .with_annotation(Annotation::primary(string.span()).with_message("String service identifier used here."))
+            .with_annotation(
+                Annotation::secondary(method_call.span()).with_message("Service fetched from the container."),
+            )
+            .with_note(
+                "String identifiers cannot be resolved by static analysis, and hide the dependencies of a class.",
+            )
+            .with_help("Inject the service instead, or fetch it using its class name.");
+
+        context.report(issue);
+
+        LintDirective::default()
+    }
+}
+
+/// Determines whether the given expression refers to the service container,
+/// i.e. `$container`, `$this->container`, or `$this->getContainer()`.
+fn is_container(context: &LintContext<'_>, expression: &Expression) -> bool {
+    match expression {
+        Expression::Variable(Variable::Direct(variable)) => context.lookup(&variable.name) == "$container",
+        Expression::Access(Access::Property(PropertyAccess {
+            property: ClassLikeMemberSelector::Identifier(property),
+            ..
+        })) => context.lookup(&property.value) == "container",
+        Expression::Call(Call::Method(MethodCall { method: ClassLikeMemberSelector::Identifier(method), .. })) => {
+            context.lookup(&method.value).eq_ignore_ascii_case("getContainer")
+        }
+        _ => false,
+    }
+}
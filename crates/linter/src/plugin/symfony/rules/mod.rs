@@ -1,3 +1,6 @@
+pub mod best_practices {
+    pub mod no_service_string_id;
+}
 pub mod quality {
     pub mod interface_should_be_used;
 }
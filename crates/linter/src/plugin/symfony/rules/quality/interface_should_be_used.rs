@@ -17,6 +17,7 @@ pub struct InterfaceShouldBeUsed;
 impl Rule for InterfaceShouldBeUsed {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Interface Should Be Used", Level::Note)
+            .fixable()
             .with_description(indoc! {"
                 Detects when an implementation class is used instead of the interface.
             "})
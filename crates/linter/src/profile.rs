@@ -0,0 +1,117 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use ahash::HashMap;
+
+/// The measurements of a single rule, accumulated across every linted module.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleProfile {
+    /// The fully qualified slug of the rule, e.g. `"best-practices/excessive-nesting"`.
+    pub slug: String,
+
+    /// The total wall time spent running the rule.
+    pub duration: Duration,
+
+    /// The total number of bytes allocated while running the rule.
+    pub allocated: u64,
+
+    /// The number of modules the rule was run on.
+    pub runs: usize,
+
+    /// The number of issues the rule reported, including the suppressed ones.
+    pub issues: usize,
+}
+
+/// Measures the wall time and allocations of every rule run by a linter.
+///
+/// The profiler is shared by every clone of the linter it is attached to, using [`Linter::with_profiler`],
+/// so that the measurements of modules linted in parallel are accumulated.
+///
+/// Allocations can't be measured by the linter itself, as they are tracked by the global allocator of the
+/// program, so the profiler is given a function returning the number of bytes allocated so far by the
+/// current thread. Rules run synchronously, so the difference before and after a rule is its allocation.
+///
+/// [`Linter::with_profiler`]: crate::Linter::with_profiler
+#[derive(Debug)]
+pub struct RuleProfiler {
+    allocated: fn() -> u64,
+    profiles: Mutex<HashMap<String, RuleProfile>>,
+}
+
+impl RuleProfiler {
+    /// Creates a new profiler.
+    ///
+    /// # Parameters
+    ///
+    /// - `allocated`: A function returning the number of bytes allocated so far by the current thread,
+    ///   or `0` if allocations are not tracked.
+    pub fn new(allocated: fn() -> u64) -> Self {
+        Self { allocated, profiles: Mutex::new(HashMap::default()) }
+    }
+
+    /// Returns the number of bytes allocated so far by the current thread.
+    pub(crate) fn allocated(&self) -> u64 {
+        (self.allocated)()
+    }
+
+    /// Records a single run of the given rule.
+    pub(crate) fn record(&self, slug: &str, duration: Duration, allocated: u64, issues: usize) {
+        let mut profiles = self.profiles.lock().expect("Unable to record rule profile: poisoned lock");
+
+        let profile = match profiles.get_mut(slug) {
+            Some(profile) => profile,
+            None => profiles
+                .entry(slug.to_string())
+                .or_insert_with(|| RuleProfile { slug: slug.to_string(), ..RuleProfile::default() }),
+        };
+
+        profile.duration += duration;
+        profile.allocated += allocated;
+        profile.runs += 1;
+        profile.issues += issues;
+    }
+
+    /// Returns the measurements of every rule run so far, the most expensive rules first.
+    ///
+    /// Rules are ordered by wall time, then by allocated bytes, and finally by slug.
+    pub fn profiles(&self) -> Vec<RuleProfile> {
+        let profiles = self.profiles.lock().expect("Unable to read rule profiles: poisoned lock");
+
+        let mut profiles = profiles.values().cloned().collect::<Vec<_>>();
+        profiles.sort_by(|a, b| {
+            b.duration.cmp(&a.duration).then_with(|| b.allocated.cmp(&a.allocated)).then_with(|| a.slug.cmp(&b.slug))
+        });
+
+        profiles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles_are_accumulated_and_sorted() {
+        let profiler = RuleProfiler::new(|| 0);
+
+        profiler.record("strictness/no-echo", Duration::from_millis(2), 100, 1);
+        profiler.record("safety/no-eval", Duration::from_millis(3), 10, 0);
+        profiler.record("strictness/no-echo", Duration::from_millis(2), 50, 2);
+        profiler.record("naming/class", Duration::from_millis(3), 20, 0);
+
+        let profiles = profiler.profiles();
+        let slugs = profiles.iter().map(|profile| profile.slug.as_str()).collect::<Vec<_>>();
+        assert_eq!(slugs, ["strictness/no-echo", "naming/class", "safety/no-eval"]);
+
+        assert_eq!(
+            profiles[0],
+            RuleProfile {
+                slug: "strictness/no-echo".to_string(),
+                duration: Duration::from_millis(4),
+                allocated: 150,
+                runs: 2,
+                issues: 3,
+            }
+        );
+    }
+}
@@ -4,6 +4,7 @@ use mago_ast::Node;
 use mago_reporting::Level;
 
 use crate::context::LintContext;
+use crate::definition::RuleCategory;
 use crate::definition::RuleDefinition;
 use crate::directive::LintDirective;
 use crate::settings::RuleSettings;
@@ -29,6 +30,9 @@ pub struct ConfiguredRule {
     /// as a `ConfiguredRule` in the linter (thus it doesn’t run at all).
     pub level: Level,
 
+    /// The **effective category** of this rule, either its own, or the category of its plugin.
+    pub category: RuleCategory,
+
     /// The complete **configuration settings** for this rule, as specified or overridden
     /// by the user (e.g., in a TOML file). These settings might include:
     pub settings: RuleSettings,
@@ -83,6 +87,17 @@ pub trait Rule: Send + Sync + Debug {
     /// A [`LintDirective`] that determines how the linter should proceed after processing this node.
     #[allow(unused_variables)]
     fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective;
+
+    /// Prepares this rule using the effective settings, once, before it lints any source.
+    ///
+    /// Rules can override this to process their options ahead of time, e.g. to compile
+    /// a pattern once instead of for every node they inspect. Does nothing by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The effective settings of this rule.
+    #[allow(unused_variables)]
+    fn configure(&mut self, settings: &RuleSettings) {}
 }
 
 impl Rule for Box<dyn Rule> {
@@ -93,4 +108,8 @@ impl Rule for Box<dyn Rule> {
     fn lint_node(&self, node: Node<'_>, context: &mut LintContext<'_>) -> LintDirective {
         self.as_ref().lint_node(node, context)
     }
+
+    fn configure(&mut self, settings: &RuleSettings) {
+        self.as_mut().configure(settings)
+    }
 }
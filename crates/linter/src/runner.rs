@@ -1,7 +1,10 @@
+use std::time::Instant;
+
 use mago_ast::Node;
 use mago_ast::Program;
 use mago_interner::ThreadedInterner;
 use mago_php_version::PHPVersion;
+use mago_project::include::IncludeGraph;
 use mago_project::module::Module;
 use mago_reflection::CodebaseReflection;
 use mago_reporting::IssueCollection;
@@ -10,6 +13,7 @@ use crate::ast::AstNode;
 use crate::context::LintContext;
 use crate::ignore::IgnoreDirective;
 use crate::ignore::get_ignores;
+use crate::profile::RuleProfiler;
 use crate::rule::ConfiguredRule;
 
 /// The `Runner` is responsible for executing a lint rule on the AST of a PHP program.
@@ -22,15 +26,19 @@ use crate::rule::ConfiguredRule;
 ///
 /// 1. Create a new runner via [`Runner::new`].
 /// 2. For each configured lint rule, call [`Runner::run`].
-/// 3. After processing all rules, call [`Runner::finish`] to retrieve the reported issues.
+/// 3. After processing all rules, call [`Runner::finish`] to retrieve the reported and suppressed issues.
 pub struct Runner<'a> {
     php_version: PHPVersion,
     interner: &'a ThreadedInterner,
     codebase: &'a CodebaseReflection,
+    include_graph: &'a IncludeGraph,
     module: &'a Module,
+    program: &'a Program,
     issues: IssueCollection,
+    suppressed: IssueCollection,
     ast: AstNode<'a>,
     ignores: Vec<IgnoreDirective<'a>>,
+    profiler: Option<&'a RuleProfiler>,
 }
 
 impl<'a> Runner<'a> {
@@ -44,7 +52,10 @@ impl<'a> Runner<'a> {
     /// - `php_version`: The PHP version used during linting.
     /// - `interner`: A reference to the threaded interner for resolving interned strings.
     /// - `codebase`: A reference to the codebase reflection, providing additional context.
+    /// - `include_graph`: A reference to the include graph of the project.
     /// - `module`: The module of the program to be linted.
+    /// - `program`: The AST of the program to be linted.
+    /// - `profiler`: An optional profiler to record the wall time and allocations of every rule into.
     ///
     /// # Returns
     ///
@@ -53,17 +64,23 @@ impl<'a> Runner<'a> {
         php_version: PHPVersion,
         interner: &'a ThreadedInterner,
         codebase: &'a CodebaseReflection,
+        include_graph: &'a IncludeGraph,
         module: &'a Module,
         program: &'a Program,
+        profiler: Option<&'a RuleProfiler>,
     ) -> Self {
         Self {
             php_version,
             interner,
             codebase,
+            include_graph,
             module,
+            program,
             ast: AstNode::from(Node::Program(program)),
             ignores: get_ignores(module, program, interner),
             issues: IssueCollection::default(),
+            suppressed: IssueCollection::default(),
+            profiler,
         }
     }
 
@@ -73,12 +90,16 @@ impl<'a> Runner<'a> {
     ///
     /// - `configured_rule`: The lint rule configuration to execute.
     pub fn run(&mut self, configured_rule: &ConfiguredRule) {
+        let allocated = self.profiler.map(|profiler| profiler.allocated());
+        let start = Instant::now();
         let mut context = LintContext::new(
             self.php_version,
             configured_rule,
             self.interner,
             self.codebase,
+            self.include_graph,
             self.module,
+            self.program,
             // Filter the ignores to only those that are relevant to this rule.
             self.ignores
                 .iter()
@@ -88,15 +109,34 @@ impl<'a> Runner<'a> {
 
         context.lint(&self.ast);
 
-        self.issues.extend(context.finish());
+        let (issues, suppressed) = context.finish();
+        let duration = start.elapsed();
+
+        if let (Some(profiler), Some(allocated)) = (self.profiler, allocated) {
+            let allocated = profiler.allocated().saturating_sub(allocated);
+
+            profiler.record(&configured_rule.slug, duration, allocated, issues.len() + suppressed.len());
+        }
+
+        tracing::trace!(
+            file = self.interner.lookup(&self.module.source.identifier.0),
+            rule = configured_rule.slug,
+            duration_us = duration.as_micros() as u64,
+            issues = issues.len(),
+            "Executed rule.",
+        );
+
+        self.issues.extend(issues);
+        self.suppressed.extend(suppressed);
     }
 
-    /// Finalizes the linting process and returns the collection of reported issues.
+    /// Finalizes the linting process and returns the collections of reported and suppressed issues.
     ///
     /// # Returns
     ///
-    /// An [`IssueCollection`] containing all issues reported during linting.
-    pub fn finish(self) -> IssueCollection {
-        self.issues
+    /// A tuple of [`IssueCollection`]s, containing all issues reported during linting, and all issues
+    /// suppressed by ignore directives.
+    pub fn finish(self) -> (IssueCollection, IssueCollection) {
+        (self.issues, self.suppressed)
     }
 }
@@ -1,11 +1,16 @@
 use ahash::HashMap;
 use serde::Deserialize;
 use serde::Serialize;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantNames;
 use toml::value::Value;
 
 use mago_php_version::PHPVersion;
 use mago_reporting::Level;
 
+use crate::definition::RuleCategory;
+
 /// `Settings` is a struct that holds all the configuration options for the linter.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Settings {
@@ -22,6 +27,23 @@ pub struct Settings {
     /// even if not listed here.
     pub plugins: Vec<String>,
 
+    /// A list of rule categories to restrict the linter to, e.g. only [`RuleCategory::Security`].
+    ///
+    /// If empty, the rules of all categories are enabled.
+    pub categories: Vec<RuleCategory>,
+
+    /// A list of rule categories whose rules are all disabled, e.g. [`RuleCategory::Style`].
+    pub excluded_categories: Vec<RuleCategory>,
+
+    /// The version of Mago whose rules have been reviewed and adopted, e.g. `"0.11.0"`.
+    ///
+    /// Rules introduced in later versions, and not configured in [`rules`], are considered new, and are
+    /// handled according to [`new_rules`]. If `None`, no rule is considered new.
+    pub rules_version: Option<String>,
+
+    /// How rules that are new, according to [`rules_version`], are handled.
+    pub new_rules: NewRulesPolicy,
+
     /// A map of `rule_slug -> RuleSettings`, where `rule_slug` might look like `"analysis/instantiation"`.
     ///
     /// This allows fine-grained control (e.g. enabling or disabling a particular rule, or overriding
@@ -29,6 +51,21 @@ pub struct Settings {
     pub rules: HashMap<String, RuleSettings>,
 }
 
+/// Specifies how rules introduced after the adopted [`Settings::rules_version`] are handled,
+/// so that upgrading Mago does not suddenly report issues of rules that were never reviewed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString, VariantNames)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum NewRulesPolicy {
+    /// New rules are enabled, but report their issues as warnings at most.
+    #[default]
+    Warn,
+    /// New rules are disabled until they are adopted.
+    Off,
+    /// New rules are enabled with their default level, as any other rule.
+    On,
+}
+
 /// Specifies how a single rule is configured in user settings, such as whether it’s enabled,
 /// which severity level applies, and any custom options specific to that rule.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
@@ -48,7 +85,16 @@ pub struct RuleSettings {
 
 impl Settings {
     pub fn new(php_version: PHPVersion) -> Self {
-        Self { php_version, default_plugins: true, plugins: Vec::new(), rules: HashMap::default() }
+        Self {
+            php_version,
+            default_plugins: true,
+            plugins: Vec::new(),
+            categories: Vec::new(),
+            excluded_categories: Vec::new(),
+            rules_version: None,
+            new_rules: NewRulesPolicy::default(),
+            rules: HashMap::default(),
+        }
     }
 
     pub fn get_rule_settings(&self, rule_name: &str) -> Option<&RuleSettings> {
@@ -65,6 +111,43 @@ impl Settings {
         self
     }
 
+    pub fn with_categories(mut self, categories: Vec<RuleCategory>) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    pub fn with_excluded_categories(mut self, excluded_categories: Vec<RuleCategory>) -> Self {
+        self.excluded_categories = excluded_categories;
+        self
+    }
+
+    /// Determines whether the rules of the given category are enabled, given the selected and excluded categories.
+    pub fn is_category_enabled(&self, category: RuleCategory) -> bool {
+        (self.categories.is_empty() || self.categories.contains(&category))
+            && !self.excluded_categories.contains(&category)
+    }
+
+    pub fn with_rules_version(mut self, rules_version: Option<String>) -> Self {
+        self.rules_version = rules_version;
+        self
+    }
+
+    pub fn with_new_rules(mut self, new_rules: NewRulesPolicy) -> Self {
+        self.new_rules = new_rules;
+        self
+    }
+
+    /// Determines whether a rule introduced in the given version is new, i.e. was introduced after
+    /// the adopted [`rules_version`](Self::rules_version).
+    ///
+    /// Rules without a version predate rule versioning, and are never new.
+    pub fn is_new_rule(&self, introduced_in: Option<&str>) -> bool {
+        match (&self.rules_version, introduced_in) {
+            (Some(rules_version), Some(introduced_in)) => parse_version(introduced_in) > parse_version(rules_version),
+            _ => false,
+        }
+    }
+
     pub fn with_rules(mut self, rules: HashMap<String, RuleSettings>) -> Self {
         self.rules = rules;
         self
@@ -103,3 +186,13 @@ impl RuleSettings {
         self.options.get(option_name)
     }
 }
+
+/// Parses a version, e.g. `"0.12.0"` or `"v1.2"`, into its numeric components, for comparison.
+fn parse_version(version: &str) -> Vec<u64> {
+    version
+        .trim()
+        .trim_start_matches('v')
+        .split(['.', '-', '+'])
+        .map_while(|component| component.parse::<u64>().ok())
+        .collect()
+}
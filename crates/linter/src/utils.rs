@@ -1,6 +1,11 @@
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
 use mago_ast::*;
 use mago_interner::ThreadedInterner;
 
+pub use mago_typing::evaluator::get_string_value;
+
 /// A utility function to get the content of a comment trivia.
 ///
 /// This function will return the content of a comment trivia, without the comment markers.
@@ -42,3 +47,21 @@ fn remove_star_prefix(content: &str) -> Vec<&str> {
 fn remove_stared_line_prefix(line: &str) -> &str {
     if let Some(stripped) = line.trim_start().strip_prefix('*') { stripped.trim_start() } else { line }
 }
+
+/// Returns the current date, in UTC, as its year, month, and day.
+pub fn get_today() -> (u32, u32, u32) {
+    let days = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs() / 86_400).unwrap_or(0);
+
+    // Converts the number of days since the epoch to a civil date, see https://howardhinnant.github.io/date_algorithms.html.
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    (year as u32, month as u32, day as u32)
+}
@@ -1,13 +1,19 @@
+use mago_fixer::FixPlan;
 use mago_interner::ThreadedInterner;
 use mago_linter::Linter;
+use mago_linter::definition::RuleCategory;
 use mago_linter::definition::RuleUsageExample;
 use mago_linter::rule::Rule;
+use mago_linter::settings::NewRulesPolicy;
 use mago_linter::settings::RuleSettings;
 use mago_linter::settings::Settings;
 use mago_php_version::PHPVersion;
 use mago_project::Project;
 use mago_project::module::Module;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Level;
 use mago_source::Source;
+use toml::Value;
 
 pub mod plugins;
 
@@ -32,6 +38,11 @@ pub fn test_rule_usage_example(rule: Box<dyn Rule>, usage_example: &RuleUsageExa
     let interner = ThreadedInterner::new();
 
     let mut rule_settings = RuleSettings::enabled();
+    if definition.level.is_none() {
+        // Rules that are disabled by default have no default level, so we pick one to be able to run them.
+        rule_settings.level = Some(Level::Warning);
+    }
+
     for (option, value) in usage_example.options.iter() {
         rule_settings.options.insert(option.to_string(), value.clone());
     }
@@ -58,7 +69,7 @@ pub fn test_rule_usage_example(rule: Box<dyn Rule>, usage_example: &RuleUsageExa
 
     let mut linter = Linter::new(settings, interner.clone(), reflection);
 
-    linter.add_rule("test", rule);
+    linter.add_rule("test", RuleCategory::Correctness, rule);
 
     let mut issues = Vec::new();
     for module in modules {
@@ -74,6 +85,13 @@ pub fn test_rule_usage_example(rule: Box<dyn Rule>, usage_example: &RuleUsageExa
             issues
         );
     } else {
+        assert!(
+            definition.fixable || issues.iter().all(|issue| issue.suggestions.is_empty()),
+            "Rule `{}` example `{}` proposes fixes, but the rule is not marked as fixable.",
+            definition.get_slug(),
+            usage_example.description
+        );
+
         assert!(
             !issues.is_empty(),
             "Rule `{}` example `{}` should have issues, but got none.",
@@ -82,3 +100,90 @@ pub fn test_rule_usage_example(rule: Box<dyn Rule>, usage_example: &RuleUsageExa
         );
     }
 }
+
+/// Lints the given code with the given rule and options, and returns the code once every proposed fix is applied.
+pub fn fix_with_rule(rule: Box<dyn Rule>, options: &[(&str, Value)], code: &str) -> String {
+    let interner = ThreadedInterner::new();
+    let slug = format!("test/{}", rule.get_definition().get_slug());
+    let mut rule_settings = RuleSettings::enabled();
+    rule_settings.level = Some(Level::Warning);
+    for (option, value) in options {
+        rule_settings.options.insert(option.to_string(), value.clone());
+    }
+
+    let settings = Settings::new(PHPVersion::PHP84).with_rule(slug, rule_settings);
+    let source = Source::standalone(&interner, "code.php", code);
+
+    let Project { modules, reflection } = {
+        let mut builder = Project::builder(interner.clone());
+        builder.add_module(Module::build(&interner, PHPVersion::PHP84, source, Default::default()));
+
+        builder.build(true)
+    };
+
+    let mut linter = Linter::new(settings, interner.clone(), reflection);
+    linter.add_rule("test", RuleCategory::Correctness, rule);
+
+    let mut plan = FixPlan::new();
+    for module in modules {
+        for issue in linter.lint(&module) {
+            for (_, suggestion) in issue.suggestions {
+                plan.merge(suggestion);
+            }
+        }
+    }
+
+    plan.execute(code).get_fixed()
+}
+
+#[test]
+fn test_rule_categories() {
+    let interner = ThreadedInterner::new();
+    let settings = Settings::new(PHPVersion::PHP84).with_categories(vec![RuleCategory::Security]);
+    let linter = Linter::with_all_plugins(settings, interner.clone(), CodebaseReflection::new());
+    let rules = linter.get_configured_rules();
+
+    assert!(!rules.is_empty());
+    assert!(rules.iter().all(|rule| rule.category == RuleCategory::Security));
+    assert!(rules.iter().any(|rule| rule.slug == "security/no-literal-password"));
+    assert!(rules.iter().any(|rule| rule.slug == "best-practices/no-debug-symbols"));
+
+    let settings = Settings::new(PHPVersion::PHP84).with_excluded_categories(vec![RuleCategory::Style]);
+    let linter = Linter::with_all_plugins(settings, interner, CodebaseReflection::new());
+    let rules = linter.get_configured_rules();
+
+    assert!(rules.iter().all(|rule| rule.category != RuleCategory::Style));
+    assert!(!rules.iter().any(|rule| rule.slug == "best-practices/no-else-clause"));
+    assert!(rules.iter().any(|rule| rule.slug == "best-practices/no-goto"));
+}
+
+#[test]
+fn test_new_rules_policy() {
+    let interner = ThreadedInterner::new();
+    let slug = "analysis/template-argument-count";
+
+    let settings = Settings::new(PHPVersion::PHP84).with_rules_version(Some("0.11.0".to_string()));
+    let linter = Linter::with_all_plugins(settings.clone(), interner.clone(), CodebaseReflection::new());
+    assert_eq!(linter.get_rule_level(slug), Some(Level::Warning));
+
+    let linter = Linter::with_all_plugins(
+        settings.clone().with_new_rules(NewRulesPolicy::Off),
+        interner.clone(),
+        CodebaseReflection::new(),
+    );
+    assert_eq!(linter.get_rule_level(slug), None);
+    assert_eq!(linter.get_rule_level("analysis/instantiation"), Some(Level::Error));
+
+    let linter = Linter::with_all_plugins(
+        settings.with_new_rules(NewRulesPolicy::Off).with_rule(slug, RuleSettings::enabled()),
+        interner.clone(),
+        CodebaseReflection::new(),
+    );
+    assert_eq!(linter.get_rule_level(slug), Some(Level::Error));
+
+    let settings = Settings::new(PHPVersion::PHP84)
+        .with_rules_version(Some("0.12.0".to_string()))
+        .with_new_rules(NewRulesPolicy::Off);
+    let linter = Linter::with_all_plugins(settings, interner, CodebaseReflection::new());
+    assert_eq!(linter.get_rule_level(slug), Some(Level::Error));
+}
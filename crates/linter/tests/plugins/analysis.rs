@@ -1,11 +1,37 @@
+use mago_linter::plugin::analysis::rules::date_format::DateFormatRule;
+use mago_linter::plugin::analysis::rules::duplicate_array_key::DuplicateArrayKeyRule;
+use mago_linter::plugin::analysis::rules::duplicate_branch_condition::DuplicateBranchConditionRule;
+use mago_linter::plugin::analysis::rules::duplicate_enum_case_value::DuplicateEnumCaseValueRule;
+use mago_linter::plugin::analysis::rules::format_string::FormatStringRule;
 use mago_linter::plugin::analysis::rules::instantiation::InstantiationRule;
+use mago_linter::plugin::analysis::rules::integer_overflow::IntegerOverflowRule;
+use mago_linter::plugin::analysis::rules::invalid_shape_argument::InvalidShapeArgumentRule;
+use mago_linter::plugin::analysis::rules::null_safety::NullSafetyRule;
 use mago_linter::plugin::analysis::rules::override_attribute::OverrideAttributeRule;
+use mago_linter::plugin::analysis::rules::property_initialization::PropertyInitializationRule;
+use mago_linter::plugin::analysis::rules::return_type_consistency::ReturnTypeConsistencyRule;
+use mago_linter::plugin::analysis::rules::template_argument_count::TemplateArgumentCountRule;
 use mago_linter::plugin::analysis::rules::undefined_constant_or_case::UndefinedConstantOrCaseRule;
 use mago_linter::plugin::analysis::rules::undefined_function_or_method::UndefinedFunctionOrMethodRule;
+use mago_linter::plugin::analysis::rules::undefined_shape_key::UndefinedShapeKeyRule;
+use mago_linter::plugin::analysis::rules::unreferenced_template::UnreferencedTemplateRule;
 
 use crate::rule_test;
 
+rule_test!(test_date_format, DateFormatRule);
+rule_test!(test_duplicate_array_key, DuplicateArrayKeyRule);
+rule_test!(test_duplicate_branch_condition, DuplicateBranchConditionRule);
+rule_test!(test_duplicate_enum_case_value, DuplicateEnumCaseValueRule);
+rule_test!(test_format_string, FormatStringRule);
 rule_test!(test_instantiation, InstantiationRule);
+rule_test!(test_integer_overflow, IntegerOverflowRule);
+rule_test!(test_invalid_shape_argument, InvalidShapeArgumentRule);
+rule_test!(test_null_safety, NullSafetyRule);
 rule_test!(test_override_attribute, OverrideAttributeRule);
+rule_test!(test_property_initialization, PropertyInitializationRule);
+rule_test!(test_return_type_consistency, ReturnTypeConsistencyRule);
 rule_test!(test_undefined_constant_or_case, UndefinedConstantOrCaseRule);
 rule_test!(test_undefined_function_or_method, UndefinedFunctionOrMethodRule);
+rule_test!(test_undefined_shape_key, UndefinedShapeKeyRule);
+rule_test!(test_template_argument_count, TemplateArgumentCountRule);
+rule_test!(test_unreferenced_template, UnreferencedTemplateRule);
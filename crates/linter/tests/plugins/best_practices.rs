@@ -1,6 +1,8 @@
 use mago_linter::plugin::best_practices::rules::combine_consecutive_issets::CombineConsecutiveIssetsRule;
 use mago_linter::plugin::best_practices::rules::disallowed_functions::DisallowedFunctionsRule;
+use mago_linter::plugin::best_practices::rules::enum_like_class::EnumLikeClassRule;
 use mago_linter::plugin::best_practices::rules::excessive_nesting::ExcessiveNesting;
+use mago_linter::plugin::best_practices::rules::json_error_handling::JsonErrorHandlingRule;
 use mago_linter::plugin::best_practices::rules::loop_does_not_iterate::LoopDoesNotIterateRule;
 use mago_linter::plugin::best_practices::rules::no_boolean_flag_parameter::NoBooleanFlagParameterRule;
 use mago_linter::plugin::best_practices::rules::no_boolean_literal_comparison::NoBooleanLiteralComparisonRule;
@@ -12,13 +14,24 @@ use mago_linter::plugin::best_practices::rules::no_goto::NoGotoRule;
 use mago_linter::plugin::best_practices::rules::no_hash_emoji::NoHashEmojiRule;
 use mago_linter::plugin::best_practices::rules::no_multi_assignments::NoMultiAssignmentsRule;
 use mago_linter::plugin::best_practices::rules::no_unused_parameter::NoUnusedParameterRule;
+use mago_linter::plugin::best_practices::rules::prefer_final_class::PreferFinalClassRule;
+use mago_linter::plugin::best_practices::rules::prefer_readonly_promoted_property::PreferReadonlyPromotedPropertyRule;
+use mago_linter::plugin::best_practices::rules::readonly_property_write::ReadonlyPropertyWriteRule;
+use mago_linter::plugin::best_practices::rules::require_exception_message::RequireExceptionMessageRule;
+use mago_linter::plugin::best_practices::rules::require_previous_exception::RequirePreviousExceptionRule;
+use mago_linter::plugin::best_practices::rules::require_throwable::RequireThrowableRule;
+use mago_linter::plugin::best_practices::rules::unclosed_resource::UnclosedResourceRule;
+use mago_linter::plugin::best_practices::rules::unreleased_lock::UnreleasedLockRule;
 use mago_linter::plugin::best_practices::rules::use_while_instead_of_for::UseWhileInsteadOfForRule;
 
+use crate::fix_with_rule;
 use crate::rule_test;
 
 rule_test!(test_combine_consecutive_issets, CombineConsecutiveIssetsRule);
 rule_test!(test_disallowed_functions, DisallowedFunctionsRule);
+rule_test!(test_enum_like_class, EnumLikeClassRule);
 rule_test!(test_excessive_nesting, ExcessiveNesting);
+rule_test!(test_json_error_handling, JsonErrorHandlingRule);
 rule_test!(test_loop_does_not_iterate, LoopDoesNotIterateRule);
 rule_test!(test_no_boolean_flag_parameter, NoBooleanFlagParameterRule);
 rule_test!(test_no_boolean_literal_comparison, NoBooleanLiteralComparisonRule);
@@ -30,4 +43,20 @@ rule_test!(test_no_goto, NoGotoRule);
 rule_test!(test_no_hash_emoji, NoHashEmojiRule);
 rule_test!(test_no_multi_assignments, NoMultiAssignmentsRule);
 rule_test!(test_no_unused_parameter, NoUnusedParameterRule);
+rule_test!(test_readonly_property_write, ReadonlyPropertyWriteRule);
+rule_test!(test_require_exception_message, RequireExceptionMessageRule);
+rule_test!(test_require_previous_exception, RequirePreviousExceptionRule);
+rule_test!(test_require_throwable, RequireThrowableRule);
+rule_test!(test_unclosed_resource, UnclosedResourceRule);
+rule_test!(test_unreleased_lock, UnreleasedLockRule);
 rule_test!(test_use_while_instead_of_for, UseWhileInsteadOfForRule);
+rule_test!(test_prefer_final_class, PreferFinalClassRule);
+rule_test!(test_prefer_readonly_promoted_property, PreferReadonlyPromotedPropertyRule);
+
+#[test]
+fn test_no_debug_symbols_fix_keeps_unbraced_bodies() {
+    let code = "<?php\n\nif ($x) var_dump($y);\nfoo();\n\nwhile ($x) {\n    dump($x);\n    bar();\n}\n";
+    let fixed = fix_with_rule(Box::new(NoDebugSymbolsRule), &[], code);
+
+    assert_eq!(fixed, "<?php\n\nif ($x) ;\nfoo();\n\nwhile ($x) {\n    \n    bar();\n}\n");
+}
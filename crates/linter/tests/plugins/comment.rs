@@ -1,10 +1,15 @@
 use mago_linter::plugin::comment::rules::docblock_syntax::DocblockSyntaxRule;
+use mago_linter::plugin::comment::rules::file_header::FileHeaderRule;
 use mago_linter::plugin::comment::rules::no_empty_comments::NoEmptyCommentsRule;
 use mago_linter::plugin::comment::rules::no_shell_style::NoShellStyleRule;
 use mago_linter::plugin::comment::rules::no_trailing_whitespace::NoTrailingWhitespaceRule;
 use mago_linter::plugin::comment::rules::no_untagged_fixme::NoUntaggedFixmeRule;
 use mago_linter::plugin::comment::rules::no_untagged_todo::NoUntaggedTodoRule;
+use mago_linter::plugin::comment::rules::task_comment_policy::TaskCommentPolicyRule;
 
+use toml::Value;
+
+use crate::fix_with_rule;
 use crate::rule_test;
 
 rule_test!(test_no_untagged_todo, NoUntaggedTodoRule);
@@ -13,3 +18,21 @@ rule_test!(test_no_untagged_fixme, NoUntaggedFixmeRule);
 rule_test!(test_no_shell_style, NoShellStyleRule);
 rule_test!(test_no_trailing_whitespace, NoTrailingWhitespaceRule);
 rule_test!(test_docblock_syntax, DocblockSyntaxRule);
+rule_test!(test_task_comment_policy, TaskCommentPolicyRule);
+rule_test!(test_file_header, FileHeaderRule);
+
+#[test]
+fn test_file_header_fix_keeps_docblock_and_year_range() {
+    let options = [
+        ("template", Value::String("This file is part of Acme.\n\n(c) {year} {company}".to_string())),
+        ("company", Value::String("Acme Inc.".to_string())),
+    ];
+    let code =
+        "<?php\n\n/**\n * This file is part of Acme.\n *\n * (c) 2019-2024 Acme Corporation\n */\n\nnamespace Acme;\n";
+    let fixed = fix_with_rule(Box::new(FileHeaderRule), &options, code);
+
+    assert_eq!(
+        fixed,
+        "<?php\n\n/**\n * This file is part of Acme.\n *\n * (c) 2019-2024 Acme Inc.\n */\n\nnamespace Acme;\n"
+    );
+}
@@ -1,9 +1,14 @@
 use mago_linter::plugin::consistency::rules::array_syntax::ArraySyntaxRule;
+use mago_linter::plugin::consistency::rules::explicit_visibility::ExplicitVisibilityRule;
 use mago_linter::plugin::consistency::rules::lowercase_hint::LowercaseHintRule;
 use mago_linter::plugin::consistency::rules::lowercase_keyword::LowercaseKeywordRule;
 use mago_linter::plugin::consistency::rules::no_function_aliases::NoFunctionAliasesRule;
+use mago_linter::plugin::consistency::rules::no_short_opening_tag::NoShortOpeningTagRule;
 use mago_linter::plugin::consistency::rules::no_tag_pair_terminator::NoTagPairTerminatorRule;
 use mago_linter::plugin::consistency::rules::require_block_statement_body::RequireBlockStatementBodyRule;
+use mago_linter::plugin::consistency::rules::string_style::StringStyleRule;
+use mago_linter::plugin::consistency::rules::union_type_order::UnionTypeOrderRule;
+use mago_linter::plugin::consistency::rules::yoda_conditions::YodaConditionsRule;
 
 use crate::rule_test;
 
@@ -11,5 +16,10 @@ rule_test!(test_array_syntax, ArraySyntaxRule);
 rule_test!(test_lowercase_hint, LowercaseHintRule);
 rule_test!(test_lowercase_keyword, LowercaseKeywordRule);
 rule_test!(test_no_function_aliases, NoFunctionAliasesRule);
+rule_test!(test_no_short_opening_tag, NoShortOpeningTagRule);
 rule_test!(test_no_tag_pair_terminator, NoTagPairTerminatorRule);
 rule_test!(test_require_block_statement_body, RequireBlockStatementBodyRule);
+rule_test!(test_explicit_visibility, ExplicitVisibilityRule);
+rule_test!(test_yoda_conditions, YodaConditionsRule);
+rule_test!(test_union_type_order, UnionTypeOrderRule);
+rule_test!(test_string_style, StringStyleRule);
@@ -0,0 +1,7 @@
+use mago_linter::plugin::include::rules::circular_include::CircularIncludeRule;
+use mago_linter::plugin::include::rules::missing_file::MissingFileRule;
+
+use crate::rule_test;
+
+rule_test!(test_missing_file, MissingFileRule);
+rule_test!(test_circular_include, CircularIncludeRule);
@@ -1,5 +1,9 @@
+use mago_linter::plugin::laravel::rules::best_practices::no_env_outside_config::NoEnvOutsideConfigRule;
+use mago_linter::plugin::laravel::rules::best_practices::no_facade_alias::NoFacadeAliasRule;
 use mago_linter::plugin::laravel::rules::safety::no_request_all::NoRequestAllRule;
 
 use crate::rule_test;
 
 rule_test!(test_no_request_all, NoRequestAllRule);
+rule_test!(test_no_env_outside_config, NoEnvOutsideConfigRule);
+rule_test!(test_no_facade_alias, NoFacadeAliasRule);
@@ -3,6 +3,7 @@ use mago_linter::plugin::maintainability::rules::excessive_parameter_list::Exces
 use mago_linter::plugin::maintainability::rules::halstead::HalsteadRule;
 use mago_linter::plugin::maintainability::rules::kan_defect::KanDefectRule;
 use mago_linter::plugin::maintainability::rules::long_inheritance_chain::LongInheritanceChainRule;
+use mago_linter::plugin::maintainability::rules::spelling::SpellingRule;
 use mago_linter::plugin::maintainability::rules::too_many_enum_cases::TooManyEnumCasesRule;
 use mago_linter::plugin::maintainability::rules::too_many_methods::TooManyMethodsRule;
 use mago_linter::plugin::maintainability::rules::too_many_properties::TooManyPropertiesRule;
@@ -14,6 +15,7 @@ rule_test!(test_excessive_parameter_list, ExcessiveParameterListRule);
 rule_test!(test_halstead, HalsteadRule);
 rule_test!(test_kan_defect, KanDefectRule);
 rule_test!(test_long_inheritance_chain, LongInheritanceChainRule);
+rule_test!(test_spelling, SpellingRule);
 rule_test!(test_too_many_enum_cases, TooManyEnumCasesRule);
 rule_test!(test_too_many_methods, TooManyMethodsRule);
 rule_test!(test_too_many_properties, TooManyPropertiesRule);
@@ -1,4 +1,6 @@
+use mago_linter::plugin::migration::rules::php80::null_safe_operator::NullSafeOperatorRule;
 use mago_linter::plugin::migration::rules::php80::str_contains::StrContainsRule;
+use mago_linter::plugin::migration::rules::php80::str_ends_with::StrEndsWithRule;
 use mago_linter::plugin::migration::rules::php80::str_starts_with::StrStartsWithRule;
 use mago_linter::plugin::migration::rules::php81::explicit_octal_notation::ExplicitOctalNotationRule;
 use mago_linter::plugin::migration::rules::php82::readonly_class_promotion::ReadonlyClassPromotionRule;
@@ -7,5 +9,7 @@ use crate::rule_test;
 
 rule_test!(test_str_starts_with, StrStartsWithRule);
 rule_test!(test_str_contains, StrContainsRule);
+rule_test!(test_str_ends_with, StrEndsWithRule);
+rule_test!(test_null_safe_operator, NullSafeOperatorRule);
 rule_test!(test_explicit_octal_notation, ExplicitOctalNotationRule);
 rule_test!(test_readonly_class_promotion, ReadonlyClassPromotionRule);
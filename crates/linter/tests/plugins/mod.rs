@@ -3,10 +3,12 @@ pub mod best_practices;
 pub mod comment;
 pub mod consistency;
 pub mod deprecation;
+pub mod include;
 pub mod laravel;
 pub mod maintainability;
 pub mod migration;
 pub mod naming;
+pub mod performance;
 pub mod phpunit;
 pub mod redundancy;
 pub mod safety;
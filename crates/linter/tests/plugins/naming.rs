@@ -3,13 +3,17 @@ use mago_linter::plugin::naming::rules::constant::ConstantRule;
 use mago_linter::plugin::naming::rules::r#enum::EnumRule;
 use mago_linter::plugin::naming::rules::function::FunctionRule;
 use mago_linter::plugin::naming::rules::interface::InterfaceRule;
+use mago_linter::plugin::naming::rules::method::MethodRule;
+use mago_linter::plugin::naming::rules::test_method::TestMethodRule;
 use mago_linter::plugin::naming::rules::r#trait::TraitRule;
 
 use crate::rule_test;
 
-rule_test!(test_class, ClassRule);
-rule_test!(test_constant, ConstantRule);
-rule_test!(test_enum, EnumRule);
-rule_test!(test_function, FunctionRule);
-rule_test!(test_interface, InterfaceRule);
-rule_test!(test_trait, TraitRule);
+rule_test!(test_class, ClassRule::default());
+rule_test!(test_constant, ConstantRule::default());
+rule_test!(test_enum, EnumRule::default());
+rule_test!(test_function, FunctionRule::default());
+rule_test!(test_interface, InterfaceRule::default());
+rule_test!(test_method, MethodRule::default());
+rule_test!(test_test_method, TestMethodRule);
+rule_test!(test_trait, TraitRule::default());
@@ -0,0 +1,9 @@
+use mago_linter::plugin::performance::rules::loop_invariant_method_call::LoopInvariantMethodCallRule;
+use mago_linter::plugin::performance::rules::no_count_in_loop_condition::NoCountInLoopConditionRule;
+use mago_linter::plugin::performance::rules::prefer_keyed_lookup::PreferKeyedLookupRule;
+
+use crate::rule_test;
+
+rule_test!(test_loop_invariant_method_call, LoopInvariantMethodCallRule);
+rule_test!(test_no_count_in_loop_condition, NoCountInLoopConditionRule);
+rule_test!(test_prefer_keyed_lookup, PreferKeyedLookupRule);
@@ -1,5 +1,9 @@
 use mago_linter::plugin::phpunit::rules::consistency::assertions_style::AssertionsStyleRule;
+use mago_linter::plugin::phpunit::rules::consistency::test_method_marker::TestMethodMarkerRule;
+use mago_linter::plugin::phpunit::rules::correctness::mock_misuse::MockMisuseRule;
+use mago_linter::plugin::phpunit::rules::correctness::valid_data_provider::ValidDataProviderRule;
 use mago_linter::plugin::phpunit::rules::redundancy::redundant_instanceof::RedundantInstanceOfRule;
+use mago_linter::plugin::phpunit::rules::strictness::missing_assertions::MissingAssertionsRule;
 use mago_linter::plugin::phpunit::rules::strictness::strict_assertions::StrictAssertionsRule;
 
 use crate::rule_test;
@@ -7,3 +11,7 @@ use crate::rule_test;
 rule_test!(test_assertions_style, AssertionsStyleRule);
 rule_test!(test_strict_assertions, StrictAssertionsRule);
 rule_test!(test_redundant_instanceof, RedundantInstanceOfRule);
+rule_test!(test_missing_assertions, MissingAssertionsRule);
+rule_test!(test_test_method_marker, TestMethodMarkerRule);
+rule_test!(test_valid_data_provider, ValidDataProviderRule);
+rule_test!(test_mock_misuse, MockMisuseRule);
@@ -2,8 +2,10 @@ use mago_linter::plugin::safety::rules::no_error_control_operator::NoErrorContro
 use mago_linter::plugin::safety::rules::no_eval::NoEvalRule;
 use mago_linter::plugin::safety::rules::no_ffi::NoFFIRule;
 use mago_linter::plugin::safety::rules::no_global::NoGlobalRule;
+use mago_linter::plugin::safety::rules::no_mutable_static_property::NoMutableStaticPropertyRule;
 use mago_linter::plugin::safety::rules::no_request_variable::NoRequestVariableRule;
 use mago_linter::plugin::safety::rules::no_shell_execute_string::NoShellExecuteStringRule;
+use mago_linter::plugin::safety::rules::no_superglobal_access::NoSuperglobalAccessRule;
 use mago_linter::plugin::safety::rules::no_unsafe_finally::NoUnsafeFinallyRule;
 
 use crate::rule_test;
@@ -12,6 +14,8 @@ rule_test!(test_no_error_control_operator, NoErrorControlOperatorRule);
 rule_test!(test_no_eval, NoEvalRule);
 rule_test!(test_no_ffi, NoFFIRule);
 rule_test!(test_no_global, NoGlobalRule);
+rule_test!(test_no_mutable_static_property, NoMutableStaticPropertyRule);
 rule_test!(test_no_request_variable, NoRequestVariableRule);
 rule_test!(test_no_shell_execute_string, NoShellExecuteStringRule);
+rule_test!(test_no_superglobal_access, NoSuperglobalAccessRule);
 rule_test!(test_no_unsafe_finally, NoUnsafeFinallyRule);
@@ -5,6 +5,7 @@ use mago_linter::plugin::strictness::rules::require_identity_comparison::Require
 use mago_linter::plugin::strictness::rules::require_parameter_type::RequireParameterTypeRule;
 use mago_linter::plugin::strictness::rules::require_property_type::RequirePropertyTypeRule;
 use mago_linter::plugin::strictness::rules::require_return_type::RequireReturnTypeRule;
+use mago_linter::plugin::strictness::rules::require_strict_search::RequireStrictSearchRule;
 use mago_linter::plugin::strictness::rules::require_strict_types::RequireStrictTypesRule;
 
 use crate::rule_test;
@@ -16,4 +17,5 @@ rule_test!(test_require_identity_comparison, RequireIdentityComparisonRule);
 rule_test!(test_require_parameter_type, RequireParameterTypeRule);
 rule_test!(test_require_property_type, RequirePropertyTypeRule);
 rule_test!(test_require_return_type, RequireReturnTypeRule);
+rule_test!(test_require_strict_search, RequireStrictSearchRule);
 rule_test!(test_require_strict_types, RequireStrictTypesRule);
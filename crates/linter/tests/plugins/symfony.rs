@@ -1,5 +1,7 @@
+use mago_linter::plugin::symfony::rules::best_practices::no_service_string_id::NoServiceStringIdRule;
 use mago_linter::plugin::symfony::rules::quality::interface_should_be_used::InterfaceShouldBeUsed;
 
 use crate::rule_test;
 
 rule_test!(test_interface_should_be_used, InterfaceShouldBeUsed);
+rule_test!(test_no_service_string_id, NoServiceStringIdRule);
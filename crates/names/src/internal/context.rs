@@ -149,6 +149,23 @@ impl<'a> NameContext<'a> {
         }
     }
 
+    /// Returns the global name PHP falls back to when the given unqualified name
+    /// cannot be found in the current namespace.
+    ///
+    /// Only unqualified names have a fallback; qualified and fully qualified names are always
+    /// resolved relative to their namespace.
+    pub fn resolve_fallback_name(&self, name_id: StringIdentifier) -> Option<StringIdentifier> {
+        let name = self.interner.lookup(&name_id);
+        if name.contains('\\') {
+            return None;
+        }
+
+        match &self.namespace_name {
+            Some(namespace_name) if !namespace_name.is_empty() => Some(name_id),
+            _ => None,
+        }
+    }
+
     fn resolve_alias(&mut self, kind: NameKind, name: &str) -> Option<StringIdentifier> {
         let context = self
             .name_resolution_contexts
@@ -15,7 +15,23 @@ pub struct NameResolver {
 
 impl NameResolver {
     pub fn new() -> Self {
-        NameResolver { resolved_names: Names { names: HashMap::default() } }
+        NameResolver { resolved_names: Names { names: HashMap::default(), fallbacks: HashMap::default() } }
+    }
+}
+
+impl NameResolver {
+    /// Resolves a function or constant name, recording the global fallback name if applicable.
+    fn resolve_with_fallback(&mut self, kind: NameKind, identifier: &Identifier, context: &mut NameContext<'_>) {
+        let position = identifier.span().start;
+        let (name, imported) = context.resolve_name(kind, identifier.value());
+
+        self.resolved_names.insert_at(position, name, imported);
+
+        if let Some(fallback) = context.resolve_fallback_name(identifier.value()) {
+            if !imported && fallback != name {
+                self.resolved_names.insert_fallback_at(position, fallback);
+            }
+        }
     }
 }
 
@@ -176,9 +192,7 @@ impl<'a> MutWalker<NameContext<'a>> for NameResolver {
 
     fn walk_in_function_call<'ast>(&mut self, function_call: &'ast FunctionCall, context: &mut NameContext<'a>) {
         if let Expression::Identifier(identifier) = function_call.function.as_ref() {
-            let (name, imported) = context.resolve_name(NameKind::Function, identifier.value());
-
-            self.resolved_names.insert_at(identifier.span().start, name, imported);
+            self.resolve_with_fallback(NameKind::Function, identifier, context);
         }
     }
 
@@ -189,9 +203,7 @@ impl<'a> MutWalker<NameContext<'a>> for NameResolver {
         context: &mut NameContext<'a>,
     ) {
         if let Expression::Identifier(identifier) = function_closure_creation.function.as_ref() {
-            let (name, imported) = context.resolve_name(NameKind::Function, identifier.value());
-
-            self.resolved_names.insert_at(identifier.span().start, name, imported);
+            self.resolve_with_fallback(NameKind::Function, identifier, context);
         }
     }
 
@@ -269,9 +281,7 @@ impl<'a> MutWalker<NameContext<'a>> for NameResolver {
         let identifier = &constant_access.name;
 
         if !self.resolved_names.contains(&identifier.span().start) {
-            let (name, imported) = context.resolve_name(NameKind::Constant, identifier.value());
-
-            self.resolved_names.insert_at(identifier.span().start, name, imported);
+            self.resolve_with_fallback(NameKind::Constant, identifier, context);
         }
     }
 
@@ -20,9 +20,14 @@ mod internal;
 ///
 /// This struct stores a mapping of positions (represented as byte offsets)
 /// to resolved names (represented as `StringIdentifier`s).
+///
+/// For unqualified function and constant references inside a namespace, PHP first looks up the
+/// namespaced name, and falls back to the global name if it does not exist at runtime. In this case,
+/// the global name is recorded as a fallback, and can be retrieved using [`Names::get_fallback`].
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Names {
     names: HashMap<usize, (StringIdentifier, bool)>,
+    fallbacks: HashMap<usize, StringIdentifier>,
 }
 
 impl Names {
@@ -101,6 +106,23 @@ impl Names {
         self.names.get(&position.position().offset).map(|(_, imported)| *imported).unwrap_or(false)
     }
 
+    /// Gets the global fallback name at the given position, if any.
+    ///
+    /// A fallback name is only recorded for unqualified function and constant references
+    /// inside a namespace that were not imported, as PHP falls back to the global function or
+    /// constant if the namespaced one does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - A reference to a type that implements `HasPosition`.
+    ///
+    /// # Returns
+    ///
+    /// The `StringIdentifier` of the global fallback name, or `None` if the name has no fallback.
+    pub fn get_fallback(&self, position: &impl HasPosition) -> Option<&StringIdentifier> {
+        self.fallbacks.get(&position.position().offset)
+    }
+
     /// Inserts a resolved name at the given position.
     ///
     /// This method is intended for internal use within the crate.
@@ -113,6 +135,18 @@ impl Names {
         self.names.insert(position.into(), (name, imported));
     }
 
+    /// Inserts a global fallback name at the given position.
+    ///
+    /// This method is intended for internal use within the crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position (as a byte offset) where the name is resolved.
+    /// * `name` - The `StringIdentifier` of the global fallback name.
+    pub(crate) fn insert_fallback_at<P: Into<usize>>(&mut self, position: P, name: StringIdentifier) {
+        self.fallbacks.insert(position.into(), name);
+    }
+
     /// Returns a set of all resolved names.
     ///
     /// The set contains tuples of positions and resolved names.
@@ -0,0 +1,74 @@
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_parser::parse_source;
+use mago_source::Source;
+use mago_span::Position;
+
+fn resolve(code: &'static str) -> (ThreadedInterner, Names) {
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "code.php", code);
+    let (program, error) = parse_source(&interner, &source);
+
+    assert!(error.is_none(), "unexpected parse error: {:?}", error);
+
+    let names = Names::resolve(&interner, &program);
+
+    (interner, names)
+}
+
+fn position_of(code: &str, needle: &str) -> Position {
+    Position::dummy(code.find(needle).expect("needle not found in code"))
+}
+
+#[test]
+fn test_unqualified_function_in_namespace_has_global_fallback() {
+    let code = "<?php namespace App; strlen('foo');";
+    let (interner, names) = resolve(code);
+    let position = position_of(code, "strlen");
+
+    assert_eq!("App\\strlen", interner.lookup(names.get(&position)));
+    assert_eq!(Some("strlen"), names.get_fallback(&position).map(|name| interner.lookup(name)));
+}
+
+#[test]
+fn test_unqualified_constant_in_namespace_has_global_fallback() {
+    let code = "<?php namespace App; echo PHP_EOL;";
+    let (interner, names) = resolve(code);
+    let position = position_of(code, "PHP_EOL");
+
+    assert_eq!("App\\PHP_EOL", interner.lookup(names.get(&position)));
+    assert_eq!(Some("PHP_EOL"), names.get_fallback(&position).map(|name| interner.lookup(name)));
+}
+
+#[test]
+fn test_names_without_fallback() {
+    let code = "<?php namespace App; use function Foo\\bar; bar(); \\baz(); Qux\\quux(); new Foo();";
+    let (interner, names) = resolve(code);
+
+    let imported = position_of(code, "bar()");
+    assert_eq!("Foo\\bar", interner.lookup(names.get(&imported)));
+    assert!(names.is_imported(&imported));
+    assert!(names.get_fallback(&imported).is_none());
+
+    let fully_qualified = position_of(code, "\\baz");
+    assert_eq!("baz", interner.lookup(names.get(&fully_qualified)));
+    assert!(names.get_fallback(&fully_qualified).is_none());
+
+    let qualified = position_of(code, "Qux\\quux");
+    assert_eq!("App\\Qux\\quux", interner.lookup(names.get(&qualified)));
+    assert!(names.get_fallback(&qualified).is_none());
+
+    let class = position_of(code, "Foo()");
+    assert_eq!("App\\Foo", interner.lookup(names.get(&class)));
+    assert!(names.get_fallback(&class).is_none());
+}
+
+#[test]
+fn test_global_namespace_has_no_fallback() {
+    let code = "<?php strlen('foo');";
+    let (interner, names) = resolve(code);
+    let position = position_of(code, "strlen");
+
+    assert_eq!("strlen", interner.lookup(names.get(&position)));
+    assert!(names.get_fallback(&position).is_none());
+}
@@ -10,12 +10,15 @@ use mago_span::Position;
 use mago_span::Span;
 use mago_token::TokenKind;
 
+use crate::MAXIMUM_NESTING_DEPTH;
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ParseError {
     SyntaxError(SyntaxError),
     UnexpectedEndOfFile(Vec<TokenKind>, Position),
     UnexpectedToken(Vec<TokenKind>, TokenKind, Span),
     UnclosedLiteralString(LiteralStringKind, Span),
+    NestingTooDeep(Span),
 }
 
 impl HasSpan for ParseError {
@@ -25,6 +28,7 @@ impl HasSpan for ParseError {
             ParseError::UnexpectedEndOfFile(_, position) => Span::new(*position, *position),
             ParseError::UnexpectedToken(_, _, span) => *span,
             ParseError::UnclosedLiteralString(_, span) => *span,
+            ParseError::NestingTooDeep(span) => *span,
         }
     }
 }
@@ -63,6 +67,9 @@ impl std::fmt::Display for ParseError {
                 LiteralStringKind::SingleQuoted => "Unclosed single-quoted string".to_string(),
                 LiteralStringKind::DoubleQuoted => "Unclosed double-quoted string".to_string(),
             },
+            ParseError::NestingTooDeep(_) => {
+                format!("Code is nested more than {} levels deep", MAXIMUM_NESTING_DEPTH)
+            }
         };
 
         write!(f, "{}", message)
@@ -5,6 +5,7 @@ use mago_token::T;
 use crate::error::ParseError;
 use crate::internal::expression::parse_expression;
 use crate::internal::statement::parse_statement;
+use crate::internal::statement::parse_statement_with_recovery;
 use crate::internal::terminator::parse_terminator;
 use crate::internal::token_stream::TokenStream;
 use crate::internal::utils;
@@ -90,7 +91,7 @@ pub fn parse_if_colon_delimited_body(stream: &mut TokenStream<'_, '_>) -> Result
                     break;
                 }
 
-                statements.push(parse_statement(stream)?);
+                statements.push(parse_statement_with_recovery(stream)?);
             }
 
             Sequence::new(statements)
@@ -134,7 +135,7 @@ pub fn parse_if_colon_delimited_body_else_if_clause(
                     break;
                 }
 
-                statements.push(parse_statement(stream)?);
+                statements.push(parse_statement_with_recovery(stream)?);
             }
 
             Sequence::new(statements)
@@ -164,7 +165,7 @@ pub fn parse_if_colon_delimited_body_else_clause(
                     break;
                 }
 
-                statements.push(parse_statement(stream)?);
+                statements.push(parse_statement_with_recovery(stream)?);
             }
             Sequence::new(statements)
         },
@@ -4,7 +4,7 @@ use mago_token::T;
 
 use crate::error::ParseError;
 use crate::internal::expression::parse_expression;
-use crate::internal::statement::parse_statement;
+use crate::internal::statement::parse_statement_with_recovery;
 use crate::internal::terminator::parse_optional_terminator;
 use crate::internal::terminator::parse_terminator;
 use crate::internal::token_stream::TokenStream;
@@ -99,7 +99,7 @@ pub fn parse_switch_statements(stream: &mut TokenStream<'_, '_>) -> Result<Seque
             break;
         }
 
-        statements.push(parse_statement(stream)?);
+        statements.push(parse_statement_with_recovery(stream)?);
     }
 
     Ok(Sequence::new(statements))
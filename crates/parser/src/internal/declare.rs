@@ -7,6 +7,7 @@ use crate::error::ParseError;
 use crate::internal::expression::parse_expression;
 use crate::internal::identifier::parse_local_identifier;
 use crate::internal::statement::parse_statement;
+use crate::internal::statement::parse_statement_with_recovery;
 use crate::internal::terminator::parse_terminator;
 use crate::internal::token_stream::TokenStream;
 use crate::internal::utils;
@@ -71,7 +72,7 @@ pub fn parse_declare_colon_delimited_body(
                     break;
                 }
 
-                statements.push(parse_statement(stream)?);
+                statements.push(parse_statement_with_recovery(stream)?);
             }
             Sequence::new(statements)
         },
@@ -1,6 +1,7 @@
 use either::Either;
 
 use mago_ast::ast::*;
+use mago_ast::stack::ensure_sufficient_stack;
 use mago_token::Associativity;
 use mago_token::Precedence;
 use mago_token::T;
@@ -38,7 +39,27 @@ pub fn parse_expression_with_precedence(
     stream: &mut TokenStream<'_, '_>,
     precedence: Precedence,
 ) -> Result<Expression, ParseError> {
+    stream.enter_nesting()?;
+    let result = ensure_sufficient_stack(|| parse_nested_expression_with_precedence(stream, precedence));
+    stream.leave_nesting();
+
+    result
+}
+
+/// Parses an expression, and the operations applied to it, e.g. `$a . $b . $c` or `$a->b()->c()`.
+///
+/// Operations are parsed iteratively, but every operation nests the expression it applies to one level
+/// deeper, so they count towards the maximum nesting depth, bounding the depth of the resulting tree,
+/// which is traversed recursively once parsed.
+#[inline(always)]
+fn parse_nested_expression_with_precedence(
+    stream: &mut TokenStream<'_, '_>,
+    precedence: Precedence,
+) -> Result<Expression, ParseError> {
+    let outer_deepest_nesting = stream.reset_deepest_nesting();
     let mut left = parse_lhs_expression(stream)?;
+    // The deepest nesting of the nodes of the expression parsed so far.
+    let mut deepest_nesting = stream.get_deepest_nesting();
 
     while let Some(next) = utils::maybe_peek(stream)? {
         // Stop parsing if the next token is a terminator.
@@ -52,7 +73,11 @@ pub fn parse_expression_with_precedence(
                 break;
             }
 
+            stream.check_nesting(deepest_nesting + 1)?;
+            stream.reset_deepest_nesting();
+
             left = parse_postfix_expression(stream, left, precedence)?;
+            deepest_nesting = stream.get_deepest_nesting().max(deepest_nesting + 1);
         } else if next.kind.is_infix() {
             let infix_precedence = Precedence::infix(&next.kind);
 
@@ -66,7 +91,11 @@ pub fn parse_expression_with_precedence(
                 }
             }
 
+            stream.check_nesting(deepest_nesting + 1)?;
+            stream.reset_deepest_nesting();
+
             left = parse_infix_expression(stream, left)?;
+            deepest_nesting = stream.get_deepest_nesting().max(deepest_nesting + 1);
         } else {
             break;
         }
@@ -78,6 +107,8 @@ pub fn parse_expression_with_precedence(
         }
     }
 
+    stream.record_deepest_nesting(outer_deepest_nesting.max(deepest_nesting));
+
     Ok(left)
 }
 
@@ -334,13 +365,9 @@ fn parse_infix_expression(stream: &mut TokenStream<'_, '_>, lhs: Expression) ->
     Ok(match operator.kind {
         T!["??"] => {
             let qq = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::NullCoalesce)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::NullCoalesce)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::NullCoalesce(qq),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::NullCoalesce(qq), rhs })
         }
         T!["?"] => {
             if matches!(utils::maybe_peek_nth(stream, 1)?.map(|t| t.kind), Some(T![":"])) {
@@ -349,346 +376,290 @@ fn parse_infix_expression(stream: &mut TokenStream<'_, '_>, lhs: Expression) ->
                     question_mark: utils::expect_any(stream)?.span,
                     then: None,
                     colon: utils::expect_any(stream)?.span,
-                    r#else: Box::new(parse_expression(stream)?),
+                    r#else: parse_boxed_expression_with_precedence(stream, Precedence::Lowest)?,
                 })
             } else {
                 Expression::Conditional(Conditional {
                     condition: Box::new(lhs),
                     question_mark: utils::expect_any(stream)?.span,
-                    then: Some(Box::new(parse_expression(stream)?)),
+                    then: Some(parse_boxed_expression_with_precedence(stream, Precedence::Lowest)?),
                     colon: utils::expect_span(stream, T![":"])?,
-                    r#else: Box::new(parse_expression(stream)?),
+                    r#else: parse_boxed_expression_with_precedence(stream, Precedence::Lowest)?,
                 })
             }
         }
         T!["?:"] => {
             let question_colon = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::ElvisOrConditional)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::ElvisOrConditional)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::Elvis(question_colon),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::Elvis(question_colon), rhs })
         }
         T!["+"] => Expression::Binary(Binary {
             lhs: Box::new(lhs),
             operator: BinaryOperator::Addition(utils::expect_any(stream)?.span),
-            rhs: Box::new(parse_expression_with_precedence(stream, Precedence::AddSub)?),
+            rhs: parse_boxed_expression_with_precedence(stream, Precedence::AddSub)?,
         }),
         T!["-"] => Expression::Binary(Binary {
             lhs: Box::new(lhs),
             operator: BinaryOperator::Subtraction(utils::expect_any(stream)?.span),
-            rhs: Box::new(parse_expression_with_precedence(stream, Precedence::AddSub)?),
+            rhs: parse_boxed_expression_with_precedence(stream, Precedence::AddSub)?,
         }),
         T!["*"] => Expression::Binary(Binary {
             lhs: Box::new(lhs),
             operator: BinaryOperator::Multiplication(utils::expect_any(stream)?.span),
-            rhs: Box::new(parse_expression_with_precedence(stream, Precedence::MulDivMod)?),
+            rhs: parse_boxed_expression_with_precedence(stream, Precedence::MulDivMod)?,
         }),
         T!["/"] => Expression::Binary(Binary {
             lhs: Box::new(lhs),
             operator: BinaryOperator::Division(utils::expect_any(stream)?.span),
-            rhs: Box::new(parse_expression_with_precedence(stream, Precedence::MulDivMod)?),
+            rhs: parse_boxed_expression_with_precedence(stream, Precedence::MulDivMod)?,
         }),
         T!["%"] => Expression::Binary(Binary {
             lhs: Box::new(lhs),
             operator: BinaryOperator::Modulo(utils::expect_any(stream)?.span),
-            rhs: Box::new(parse_expression_with_precedence(stream, Precedence::MulDivMod)?),
+            rhs: parse_boxed_expression_with_precedence(stream, Precedence::MulDivMod)?,
         }),
         T!["**"] => Expression::Binary(Binary {
             lhs: Box::new(lhs),
             operator: BinaryOperator::Exponentiation(utils::expect_any(stream)?.span),
-            rhs: Box::new(parse_expression_with_precedence(stream, Precedence::Pow)?),
+            rhs: parse_boxed_expression_with_precedence(stream, Precedence::Pow)?,
         }),
         T!["="] => {
             let operator = AssignmentOperator::Assign(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["+="] => {
             let operator = AssignmentOperator::Addition(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["-="] => {
             let operator = AssignmentOperator::Subtraction(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["*="] => {
             let operator = AssignmentOperator::Multiplication(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["/="] => {
             let operator = AssignmentOperator::Division(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["%="] => {
             let operator = AssignmentOperator::Modulo(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["**="] => {
             let operator = AssignmentOperator::Exponentiation(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["&="] => {
             let operator = AssignmentOperator::BitwiseAnd(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["|="] => {
             let operator = AssignmentOperator::BitwiseOr(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["^="] => {
             let operator = AssignmentOperator::BitwiseXor(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["<<="] => {
             let operator = AssignmentOperator::LeftShift(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T![">>="] => {
             let operator = AssignmentOperator::RightShift(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["??="] => {
             let operator = AssignmentOperator::Coalesce(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T![".="] => {
             let operator = AssignmentOperator::Concat(utils::expect_any(stream)?.span);
-            let rhs = parse_expression_with_precedence(stream, Precedence::Assignment)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Assignment)?;
 
             create_assignment_expression(lhs, operator, rhs)
         }
         T!["&"] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::BitwiseAnd)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::BitwiseAnd)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::BitwiseAnd(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::BitwiseAnd(operator), rhs })
         }
         T!["|"] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::BitwiseOr)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::BitwiseOr)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::BitwiseOr(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::BitwiseOr(operator), rhs })
         }
         T!["^"] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::BitwiseXor)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::BitwiseXor)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::BitwiseXor(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::BitwiseXor(operator), rhs })
         }
         T!["<<"] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::BitShift)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::BitShift)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::LeftShift(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::LeftShift(operator), rhs })
         }
         T![">>"] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::BitShift)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::BitShift)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::RightShift(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::RightShift(operator), rhs })
         }
         T!["=="] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Equality)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Equality)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::Equal(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::Equal(operator), rhs })
         }
         T!["==="] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Equality)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Equality)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::Identical(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::Identical(operator), rhs })
         }
         T!["!="] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Equality)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Equality)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::NotEqual(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::NotEqual(operator), rhs })
         }
         T!["!=="] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Equality)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Equality)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::NotIdentical(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::NotIdentical(operator), rhs })
         }
         T!["<>"] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Equality)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Equality)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::AngledNotEqual(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::AngledNotEqual(operator), rhs })
         }
         T!["<"] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Comparison)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Comparison)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::LessThan(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::LessThan(operator), rhs })
         }
         T![">"] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Comparison)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Comparison)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::GreaterThan(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::GreaterThan(operator), rhs })
         }
         T!["<="] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Comparison)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Comparison)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::LessThanOrEqual(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::LessThanOrEqual(operator), rhs })
         }
         T![">="] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Comparison)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Comparison)?;
 
             Expression::Binary(Binary {
                 lhs: Box::new(lhs),
                 operator: BinaryOperator::GreaterThanOrEqual(operator),
-                rhs: Box::new(rhs),
+                rhs,
             })
         }
         T!["<=>"] => {
             let operator = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Equality)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Equality)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::Spaceship(operator),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::Spaceship(operator), rhs })
         }
         T!["&&"] => {
             let and = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::And)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::And)?;
 
-            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::And(and), rhs: Box::new(rhs) })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::And(and), rhs })
         }
         T!["||"] => {
             let or = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Or)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Or)?;
 
-            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::Or(or), rhs: Box::new(rhs) })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::Or(or), rhs })
         }
         T!["and"] => {
             let and = utils::expect_any_keyword(stream)?;
-            let rhs = parse_expression_with_precedence(stream, Precedence::LowLogicalAnd)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::LowLogicalAnd)?;
 
-            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::LowAnd(and), rhs: Box::new(rhs) })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::LowAnd(and), rhs })
         }
         T!["or"] => {
             let or = utils::expect_any_keyword(stream)?;
-            let rhs = parse_expression_with_precedence(stream, Precedence::LowLogicalOr)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::LowLogicalOr)?;
 
-            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::LowOr(or), rhs: Box::new(rhs) })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::LowOr(or), rhs })
         }
         T!["xor"] => {
             let xor = utils::expect_any_keyword(stream)?;
-            let rhs = parse_expression_with_precedence(stream, Precedence::LowLogicalXor)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::LowLogicalXor)?;
 
-            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::LowXor(xor), rhs: Box::new(rhs) })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::LowXor(xor), rhs })
         }
         T!["."] => {
             let dot = utils::expect_any(stream)?.span;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Concat)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Concat)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::StringConcat(dot),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::StringConcat(dot), rhs })
         }
         T!["instanceof"] => {
             let instanceof = utils::expect_any_keyword(stream)?;
-            let rhs = parse_expression_with_precedence(stream, Precedence::Instanceof)?;
+            let rhs = parse_boxed_expression_with_precedence(stream, Precedence::Instanceof)?;
 
-            Expression::Binary(Binary {
-                lhs: Box::new(lhs),
-                operator: BinaryOperator::Instanceof(instanceof),
-                rhs: Box::new(rhs),
-            })
+            Expression::Binary(Binary { lhs: Box::new(lhs), operator: BinaryOperator::Instanceof(instanceof), rhs })
         }
         _ => unreachable!(),
     })
 }
 
+/// Parses an expression, and boxes it.
+///
+/// The right-hand side of infix operations is parsed using this function, so that the frame of
+/// `parse_infix_expression`, which is on the stack for every level of nested operations, stays small.
+#[inline(never)]
+fn parse_boxed_expression_with_precedence(
+    stream: &mut TokenStream<'_, '_>,
+    precedence: Precedence,
+) -> Result<Box<Expression>, ParseError> {
+    parse_expression_with_precedence(stream, precedence).map(Box::new)
+}
+
 /// Creates an `Expression` representing an assignment operation while ensuring correct associativity.
 ///
 /// In PHP, assignment operations have right-to-left associativity. This function
@@ -705,7 +676,7 @@ fn parse_infix_expression(stream: &mut TokenStream<'_, '_>, lhs: Expression) ->
 ///  * `($x && $y) = $z` is transformed to `$x && ($y = $z)`
 ///  * `($x + $y) = $z` is transformed to `$x + ($y = $z)`
 ///  * `((string) $bar) = $foo` is transformed to `(string) ($bar = $foo)`
-fn create_assignment_expression(lhs: Expression, operator: AssignmentOperator, rhs: Expression) -> Expression {
+fn create_assignment_expression(lhs: Expression, operator: AssignmentOperator, rhs: Box<Expression>) -> Expression {
     match lhs {
         Expression::UnaryPrefix(prefix) => Expression::UnaryPrefix(UnaryPrefix {
             operator: prefix.operator,
@@ -723,16 +694,12 @@ fn create_assignment_expression(lhs: Expression, operator: AssignmentOperator, r
                 Expression::Binary(Binary {
                     lhs: binary_lhs,
                     operator: binary_operator,
-                    rhs: Box::new(Expression::Assignment(Assignment { lhs: binary_rhs, operator, rhs: Box::new(rhs) })),
+                    rhs: Box::new(Expression::Assignment(Assignment { lhs: binary_rhs, operator, rhs })),
                 })
             } else {
-                Expression::Assignment(Assignment {
-                    lhs: Box::new(Expression::Binary(operation)),
-                    operator,
-                    rhs: Box::new(rhs),
-                })
+                Expression::Assignment(Assignment { lhs: Box::new(Expression::Binary(operation)), operator, rhs })
             }
         }
-        _ => Expression::Assignment(Assignment { lhs: Box::new(lhs), operator, rhs: Box::new(rhs) }),
+        _ => Expression::Assignment(Assignment { lhs: Box::new(lhs), operator, rhs }),
     }
 }
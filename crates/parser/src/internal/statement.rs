@@ -1,5 +1,7 @@
 use mago_ast::ast::*;
 use mago_ast::sequence::Sequence;
+use mago_ast::stack::ensure_sufficient_stack;
+use mago_span::Span;
 use mago_token::T;
 
 use crate::error::ParseError;
@@ -41,7 +43,58 @@ use crate::internal::unset::parse_unset;
 use crate::internal::r#use::parse_use;
 use crate::internal::utils;
 
+/// Parses a statement, recovering from a parse error in the tolerant mode.
+///
+/// In the tolerant mode, the error is recorded, and the tokens of the malformed statement are skipped up to
+/// the end of the statement, i.e. a `;` or a `}` closing the braces opened since its start, or up to the `}`,
+/// closing tag, or alternative syntax keyword ending its enclosing block. The skipped region is returned as
+/// a [`Statement::Error`].
+pub fn parse_statement_with_recovery(stream: &mut TokenStream<'_, '_>) -> Result<Statement, ParseError> {
+    if !stream.is_tolerant() {
+        return parse_statement(stream);
+    }
+
+    let start = utils::peek(stream)?.span.start;
+    let depth = stream.get_depth();
+    match parse_statement(stream) {
+        Ok(statement) => return Ok(statement),
+        Err(error) => stream.record_error(error),
+    }
+
+    // Lexer errors cannot be recovered from, so the region stops right before them.
+    while let Some(Ok(token)) = stream.peek() {
+        let has_progressed = stream.get_position().offset > start.offset;
+        if has_progressed
+            && stream.get_depth() <= depth
+            && matches!(
+                token.kind,
+                T!["}" | "?>" | "endif" | "else" | "elseif" | "endforeach" | "endfor" | "endwhile" | "endswitch"]
+                    | T!["enddeclare" | "case" | "default"]
+            )
+        {
+            break;
+        }
+
+        stream.advance();
+
+        if stream.get_depth() <= depth && matches!(token.kind, T![";" | "}"]) {
+            break;
+        }
+    }
+
+    Ok(Statement::Error(Span::new(start, stream.get_position())))
+}
+
 pub fn parse_statement(stream: &mut TokenStream<'_, '_>) -> Result<Statement, ParseError> {
+    stream.enter_nesting()?;
+    let result = ensure_sufficient_stack(|| parse_nested_statement(stream));
+    stream.leave_nesting();
+
+    result
+}
+
+#[inline(always)]
+fn parse_nested_statement(stream: &mut TokenStream<'_, '_>) -> Result<Statement, ParseError> {
     Ok(match utils::peek(stream)?.kind {
         T![InlineText | InlineShebang] => Statement::Inline(parse_inline(stream)?),
         T!["<?php"] | T!["<?="] | T!["<?"] => Statement::OpeningTag(parse_opening_tag(stream)?),
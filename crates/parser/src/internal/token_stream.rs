@@ -8,9 +8,13 @@ use mago_interner::ThreadedInterner;
 use mago_lexer::Lexer;
 use mago_lexer::error::SyntaxError;
 use mago_span::Position;
+use mago_span::Span;
 use mago_token::Token;
 use mago_token::TokenKind;
 
+use crate::MAXIMUM_NESTING_DEPTH;
+use crate::error::ParseError;
+
 #[derive(Debug)]
 pub struct TokenStream<'a, 'i> {
     interner: &'i ThreadedInterner,
@@ -18,19 +22,116 @@ pub struct TokenStream<'a, 'i> {
     buffer: VecDeque<Token>,
     trivia: Vec<Token>,
     position: Position,
+    depth: usize,
+    nesting: usize,
+    deepest_nesting: usize,
+    tolerant: bool,
+    errors: Vec<ParseError>,
 }
 
 impl<'a, 'i> TokenStream<'a, 'i> {
-    pub fn new(interner: &'i ThreadedInterner, lexer: Lexer<'a, 'i>) -> TokenStream<'a, 'i> {
+    pub fn new(interner: &'i ThreadedInterner, lexer: Lexer<'a, 'i>, tolerant: bool) -> TokenStream<'a, 'i> {
         let position = lexer.get_position();
 
-        TokenStream { interner, lexer, buffer: VecDeque::new(), trivia: Vec::new(), position }
+        TokenStream {
+            interner,
+            lexer,
+            buffer: VecDeque::new(),
+            trivia: Vec::new(),
+            position,
+            depth: 0,
+            nesting: 0,
+            deepest_nesting: 0,
+            tolerant,
+            errors: Vec::new(),
+        }
     }
 
     pub fn interner(&self) -> &'i ThreadedInterner {
         self.interner
     }
 
+    /// Whether the stream is parsed in the tolerant mode, recovering from parse errors.
+    #[inline]
+    pub const fn is_tolerant(&self) -> bool {
+        self.tolerant
+    }
+
+    /// Records a parse error the parser recovered from, unless it was already recorded.
+    #[inline]
+    pub fn record_error(&mut self, error: ParseError) {
+        if self.errors.last() != Some(&error) {
+            self.errors.push(error);
+        }
+    }
+
+    /// Consumes the parse errors recorded so far and returns them.
+    #[inline]
+    pub fn take_errors(&mut self) -> Vec<ParseError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Returns the number of braces opened, and not closed yet, by the tokens consumed so far.
+    #[inline]
+    pub const fn get_depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Enters a nested expression, or statement, failing if the maximum nesting depth is exceeded.
+    ///
+    /// Every successful call must be balanced by a call to [`TokenStream::leave_nesting`], whether parsing
+    /// the nested node succeeds or not.
+    #[inline]
+    pub fn enter_nesting(&mut self) -> Result<(), ParseError> {
+        self.check_nesting(self.nesting + 1)?;
+
+        self.nesting += 1;
+        self.deepest_nesting = self.deepest_nesting.max(self.nesting);
+
+        Ok(())
+    }
+
+    /// Fails if the given nesting exceeds the maximum nesting depth.
+    #[inline]
+    pub fn check_nesting(&mut self, nesting: usize) -> Result<(), ParseError> {
+        if nesting > MAXIMUM_NESTING_DEPTH {
+            let span = match self.peek() {
+                Some(Ok(token)) => token.span,
+                _ => Span::new(self.position, self.position),
+            };
+
+            return Err(ParseError::NestingTooDeep(span));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the deepest nesting entered since the last call to [`TokenStream::reset_deepest_nesting`].
+    #[inline]
+    pub const fn get_deepest_nesting(&self) -> usize {
+        self.deepest_nesting
+    }
+
+    /// Resets the deepest nesting to the current nesting, returning the deepest nesting entered before.
+    ///
+    /// This is used to measure how deep the node parsed next is nested, see [`TokenStream::get_deepest_nesting`].
+    #[inline]
+    pub fn reset_deepest_nesting(&mut self) -> usize {
+        std::mem::replace(&mut self.deepest_nesting, self.nesting)
+    }
+
+    /// Records that a node was parsed as deep as the given nesting, e.g. once it was nested into another node.
+    #[inline]
+    pub fn record_deepest_nesting(&mut self, nesting: usize) {
+        self.deepest_nesting = self.deepest_nesting.max(nesting);
+    }
+
+    /// Leaves a nested expression, or statement, entered using [`TokenStream::enter_nesting`].
+    #[inline]
+    pub fn leave_nesting(&mut self) {
+        self.nesting = self.nesting.saturating_sub(1);
+    }
+
     /// Advances the stream to the next token in the input source code and returns it.
     ///
     /// If the stream has already read the entire input source code, this method will return `None`.
@@ -44,6 +145,12 @@ impl<'a, 'i> TokenStream<'a, 'i> {
             Ok(Some(_)) => {
                 if let Some(token) = self.buffer.pop_front() {
                     self.position = token.span.end;
+                    match token.kind {
+                        TokenKind::LeftBrace | TokenKind::DollarLeftBrace => self.depth += 1,
+                        TokenKind::RightBrace => self.depth = self.depth.saturating_sub(1),
+                        _ => {}
+                    }
+
                     Some(Ok(token))
                 } else {
                     None
@@ -2,45 +2,143 @@ use mago_ast::Program;
 use mago_ast::sequence::Sequence;
 use mago_interner::ThreadedInterner;
 use mago_lexer::Lexer;
+use mago_lexer::error::SyntaxError;
 use mago_lexer::input::Input;
 use mago_source::Source;
+use mago_token::Token;
 
 use crate::error::ParseError;
-use crate::internal::statement::parse_statement;
+use crate::internal::statement::parse_statement_with_recovery;
 use crate::internal::token_stream::TokenStream;
+use crate::settings::ParserSettings;
 
 pub mod error;
+pub mod settings;
 
 mod internal;
 
+/// The maximum number of expressions, and statements, that can be nested within each other.
+///
+/// Parsing, and later processing, deeply nested code is recursive, so the parser reports a
+/// [`ParseError::NestingTooDeep`] error past this depth, instead of overflowing the stack on
+/// pathological inputs, such as thousands of nested arrays.
+pub const MAXIMUM_NESTING_DEPTH: usize = 256;
+
 pub fn parse_source(interner: &ThreadedInterner, source: &Source) -> (Program, Option<ParseError>) {
-    let content = interner.lookup(&source.content);
-    let lexer = Lexer::new(interner, Input::new(source.identifier, content.as_bytes()));
+    parse_source_with_settings(interner, source, ParserSettings::default())
+}
 
-    construct(interner, lexer)
+/// Parses the given source, accepting the syntax enabled by the given settings.
+pub fn parse_source_with_settings(
+    interner: &ThreadedInterner,
+    source: &Source,
+    settings: ParserSettings,
+) -> (Program, Option<ParseError>) {
+    let lexer = settings.lexer(interner, Input::new(source.identifier, source.content.as_bytes()));
+    let (program, mut errors) = construct(interner, lexer, false);
+
+    (program, errors.pop())
 }
 
 pub fn parse(interner: &ThreadedInterner, input: Input<'_>) -> (Program, Option<ParseError>) {
     let lexer = Lexer::new(interner, input);
+    let (program, mut errors) = construct(interner, lexer, false);
+
+    (program, errors.pop())
+}
+
+/// Parses the given source in the tolerant mode, producing a best-effort program for code being edited.
+///
+/// Instead of stopping at the first parse error, the parser skips the malformed statement, up to the end of
+/// the statement or of its enclosing block, and represents the skipped region as a [`Statement::Error`],
+/// before carrying on with the next statement. This way, the valid parts of the source can still be analyzed
+/// and formatted.
+///
+/// Every error the parser recovered from is returned, in source order. Errors of the lexer cannot be recovered
+/// from, and stop the parser as in the default mode.
+///
+/// [`Statement::Error`]: mago_ast::Statement::Error
+pub fn parse_source_tolerant(interner: &ThreadedInterner, source: &Source) -> (Program, Vec<ParseError>) {
+    parse_source_tolerant_with_settings(interner, source, ParserSettings::default())
+}
+
+/// Parses the given source in the tolerant mode, accepting the syntax enabled by the given settings.
+///
+/// See [`parse_source_tolerant`] for more details.
+pub fn parse_source_tolerant_with_settings(
+    interner: &ThreadedInterner,
+    source: &Source,
+    settings: ParserSettings,
+) -> (Program, Vec<ParseError>) {
+    let lexer = settings.lexer(interner, Input::new(source.identifier, source.content.as_bytes()));
+
+    construct(interner, lexer, true)
+}
+
+/// Parses the given input in the tolerant mode.
+///
+/// See [`parse_source_tolerant`] for more details.
+pub fn parse_tolerant(interner: &ThreadedInterner, input: Input<'_>) -> (Program, Vec<ParseError>) {
+    let lexer = Lexer::new(interner, input);
+
+    construct(interner, lexer, true)
+}
+
+/// Tokenizes the given source into a lossless stream of tokens.
+///
+/// Unlike the parser, which discards trivia from the main token stream, the returned tokens include
+/// whitespace and comments, each with its own span. Concatenating the values of all tokens yields
+/// the original source content, making this suitable for external tools such as code generators or
+/// custom rewriters that need to work at the token level.
+///
+/// If the lexer encounters a syntax error, tokenization stops, and the tokens produced so far are
+/// returned alongside the error.
+pub fn tokenize_source(interner: &ThreadedInterner, source: &Source) -> (Vec<Token>, Option<SyntaxError>) {
+    let lexer = Lexer::new(interner, Input::new(source.identifier, source.content.as_bytes()));
+
+    collect_tokens(lexer)
+}
+
+/// Tokenizes the given input into a lossless stream of tokens.
+///
+/// See [`tokenize_source`] for more details.
+pub fn tokenize(interner: &ThreadedInterner, input: Input<'_>) -> (Vec<Token>, Option<SyntaxError>) {
+    let lexer = Lexer::new(interner, input);
+
+    collect_tokens(lexer)
+}
+
+fn collect_tokens(mut lexer: Lexer<'_, '_>) -> (Vec<Token>, Option<SyntaxError>) {
+    let mut tokens = Vec::new();
+
+    while let Some(result) = lexer.advance() {
+        match result {
+            Ok(token) => {
+                tokens.push(token);
+            }
+            Err(syntax_error) => {
+                return (tokens, Some(syntax_error));
+            }
+        }
+    }
 
-    construct(interner, lexer)
+    (tokens, None)
 }
 
-fn construct<'i>(interner: &'i ThreadedInterner, lexer: Lexer<'_, 'i>) -> (Program, Option<ParseError>) {
-    let mut stream = TokenStream::new(interner, lexer);
+fn construct<'i>(interner: &'i ThreadedInterner, lexer: Lexer<'_, 'i>, tolerant: bool) -> (Program, Vec<ParseError>) {
+    let mut stream = TokenStream::new(interner, lexer, tolerant);
 
-    let mut error = None;
     let statements = {
         let mut statements = Vec::new();
 
         loop {
             match stream.has_reached_eof() {
-                Ok(false) => match parse_statement(&mut stream) {
+                Ok(false) => match parse_statement_with_recovery(&mut stream) {
                     Ok(statement) => {
                         statements.push(statement);
                     }
                     Err(parse_error) => {
-                        error = Some(parse_error);
+                        stream.record_error(parse_error);
 
                         break;
                     }
@@ -49,7 +147,7 @@ fn construct<'i>(interner: &'i ThreadedInterner, lexer: Lexer<'_, 'i>) -> (Progr
                     break;
                 }
                 Err(syntax_error) => {
-                    error = Some(ParseError::from(syntax_error));
+                    stream.record_error(ParseError::from(syntax_error));
 
                     break;
                 }
@@ -65,6 +163,6 @@ fn construct<'i>(interner: &'i ThreadedInterner, lexer: Lexer<'_, 'i>) -> (Progr
             statements: Sequence::new(statements),
             trivia: stream.get_trivia(),
         },
-        error,
+        stream.take_errors(),
     )
 }
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_interner::ThreadedInterner;
+use mago_lexer::Lexer;
+use mago_lexer::input::Input;
+
+/// Settings controlling which syntax the parser accepts.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ParserSettings {
+    /// Whether the short opening tag, `<?`, opens a PHP block.
+    ///
+    /// When disabled, `<?` is treated as inline text, as PHP does when the `short_open_tag`
+    /// ini setting is off, which allows parsing templates containing `<?xml` declarations.
+    ///
+    /// Default: true
+    pub short_open_tag: bool,
+}
+
+impl ParserSettings {
+    /// Creates the lexer for the given input, configured according to these settings.
+    pub(crate) fn lexer<'a, 'i>(&self, interner: &'i ThreadedInterner, input: Input<'a>) -> Lexer<'a, 'i> {
+        Lexer::new(interner, input).with_short_open_tag(self.short_open_tag)
+    }
+}
+
+impl Default for ParserSettings {
+    fn default() -> Self {
+        Self { short_open_tag: true }
+    }
+}
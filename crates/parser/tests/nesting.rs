@@ -0,0 +1,95 @@
+use mago_ast::Statement;
+use mago_interner::ThreadedInterner;
+use mago_parser::MAXIMUM_NESTING_DEPTH;
+use mago_parser::error::ParseError;
+use mago_parser::parse_source;
+use mago_parser::parse_source_tolerant;
+use mago_source::Source;
+
+#[test]
+fn test_deeply_nested_code_is_rejected() {
+    let depth = MAXIMUM_NESTING_DEPTH * 10;
+    let codes = [
+        format!("<?php\n\n$a = {}{};\n", "[".repeat(depth), "]".repeat(depth)),
+        format!("<?php\n\n$a = {}1{};\n", "(".repeat(depth), ")".repeat(depth)),
+        format!("<?php\n\n$a = {}2;\n", "$b ? 1 : ".repeat(depth)),
+        format!("<?php\n\n$a = {}$b;\n", "!".repeat(depth)),
+        format!("<?php\n\n$a = {}$b;\n", "$b . ".repeat(depth)),
+        format!("<?php\n\n$a = {}$b;\n", "$b && ".repeat(depth)),
+        format!("<?php\n\n$a = $b{};\n", "->c()".repeat(depth)),
+        format!("<?php\n\n$a = $b{};\n", "[0]".repeat(depth)),
+        format!("<?php\n\n{}{}\n", "if ($a) {\n".repeat(depth), "}\n".repeat(depth)),
+    ];
+
+    for code in codes {
+        let error = parse(code.clone(), false).into_iter().last();
+
+        assert!(matches!(error, Some(ParseError::NestingTooDeep(_))), "expected a nesting error, found {:?}", error);
+    }
+}
+
+#[test]
+fn test_operations_nest_previously_parsed_operands() {
+    // Neither the parentheses, nor the concatenations, are nested too deep on their own, but every
+    // concatenation nests the parenthesized operand, parsed first, one level deeper.
+    let depth = MAXIMUM_NESTING_DEPTH / 2 + 10;
+    let code = format!("<?php\n\n$a = $b . {}1{}{};\n", "(".repeat(depth), ")".repeat(depth), " . $b".repeat(depth));
+
+    let error = parse(code.clone(), false).into_iter().last();
+    assert!(matches!(error, Some(ParseError::NestingTooDeep(_))), "expected a nesting error, found {:?}", error);
+
+    let depth = MAXIMUM_NESTING_DEPTH / 2 - 10;
+    let code = format!("<?php\n\n$a = $b . {}1{}{};\n", "(".repeat(depth), ")".repeat(depth), " . $b".repeat(depth));
+
+    let errors = parse(code.clone(), false);
+    assert!(errors.is_empty(), "expected no errors, found {:?}", errors);
+}
+
+#[test]
+fn test_nested_code_within_limit_is_accepted() {
+    let depth = MAXIMUM_NESTING_DEPTH - 6;
+    let codes = [
+        format!("<?php\n\n$a = {}{};\n", "[".repeat(depth), "]".repeat(depth)),
+        format!("<?php\n\n$a = {}1{};\n", "(".repeat(depth), ")".repeat(depth)),
+        format!("<?php\n\n$a = {}2;\n", "$b ? 1 : ".repeat(depth)),
+        format!("<?php\n\n$a = {}$b;\n", "!".repeat(depth)),
+        format!("<?php\n\n$a = {}$b;\n", "$b . ".repeat(depth)),
+        format!("<?php\n\n$a = {}$b;\n", "$b && ".repeat(depth)),
+        format!("<?php\n\n$a = $b{};\n", "->c()".repeat(depth)),
+        format!("<?php\n\n$a = $b{};\n", "[0]".repeat(depth)),
+        format!("<?php\n\n{}{}\n", "if ($a) {\n".repeat(depth / 2), "}\n".repeat(depth / 2)),
+    ];
+
+    for code in codes {
+        let errors = parse(code.clone(), false);
+
+        assert!(errors.is_empty(), "expected no errors, found {:?}", errors);
+    }
+}
+
+#[test]
+fn test_tolerant_parsing_recovers_from_deeply_nested_code() {
+    let depth = MAXIMUM_NESTING_DEPTH * 10;
+    let code = format!("<?php\n\n$a = {}{};\n$b = 2;\n", "[".repeat(depth), "]".repeat(depth));
+
+    let errors = parse(code, true);
+
+    assert_eq!(1, errors.len());
+    assert!(matches!(errors[0], ParseError::NestingTooDeep(_)));
+}
+
+/// Parses the given code on the current thread, which uses the default stack size of test threads, so that
+/// deeply nested code is known to be handled without relying on a large stack.
+fn parse(code: String, tolerant: bool) -> Vec<ParseError> {
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "code.php", &code);
+
+    if tolerant {
+        let (program, errors) = parse_source_tolerant(&interner, &source);
+        assert!(program.statements.iter().any(|statement| matches!(statement, Statement::Error(_))));
+
+        errors
+    } else {
+        parse_source(&interner, &source).1.into_iter().collect()
+    }
+}
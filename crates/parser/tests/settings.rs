@@ -0,0 +1,28 @@
+use mago_ast::Statement;
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source_with_settings;
+use mago_parser::settings::ParserSettings;
+use mago_source::Source;
+
+const TEMPLATE: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<feed><?php echo $title; ?></feed>\n";
+
+#[test]
+fn test_short_open_tag_enabled() {
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "feed.php", TEMPLATE);
+    let (_, error) = parse_source_with_settings(&interner, &source, ParserSettings::default());
+
+    assert!(error.is_some());
+}
+
+#[test]
+fn test_short_open_tag_disabled() {
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "feed.php", TEMPLATE);
+    let settings = ParserSettings { short_open_tag: false };
+    let (program, error) = parse_source_with_settings(&interner, &source, settings);
+
+    assert!(error.is_none(), "unexpected error: {:?}", error);
+    assert!(matches!(program.statements.first(), Some(Statement::Inline(_))));
+    assert!(program.statements.iter().any(|statement| matches!(statement, Statement::Echo(_))));
+}
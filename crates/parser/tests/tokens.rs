@@ -0,0 +1,47 @@
+use pretty_assertions::assert_eq;
+
+use mago_interner::ThreadedInterner;
+use mago_parser::tokenize_source;
+use mago_source::Source;
+use mago_token::TokenKind;
+
+#[test]
+fn test_token_stream_is_lossless() {
+    let code =
+        "<?php\n\n// comment\nfunction foo(int $a) { # hash\n    /** doc */\n    return $a  +  1;\n}\n?>\ninline";
+
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "code.php", code);
+    let (tokens, error) = tokenize_source(&interner, &source);
+
+    assert!(error.is_none());
+
+    let reconstructed = tokens.iter().map(|token| interner.lookup(&token.value)).collect::<String>();
+
+    assert_eq!(code, reconstructed);
+}
+
+#[test]
+fn test_token_stream_spans_are_contiguous() {
+    let code = "<?php   $a = 1; /* block */ $b = 2;";
+
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "code.php", code);
+    let (tokens, error) = tokenize_source(&interner, &source);
+
+    assert!(error.is_none());
+    assert!(tokens.iter().any(|token| token.kind == TokenKind::Whitespace));
+    assert!(tokens.iter().any(|token| token.kind == TokenKind::MultiLineComment));
+
+    let mut offset = 0;
+    for token in &tokens {
+        let span = token.span;
+
+        assert_eq!(offset, span.start.offset);
+        assert_eq!(interner.lookup(&token.value), &code[span.start.offset..span.end.offset]);
+
+        offset = span.end.offset;
+    }
+
+    assert_eq!(code.len(), offset);
+}
@@ -0,0 +1,46 @@
+use pretty_assertions::assert_eq;
+
+use mago_ast::Statement;
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source;
+use mago_parser::parse_source_tolerant;
+use mago_source::Source;
+use mago_span::HasSpan;
+
+#[test]
+fn test_tolerant_parsing_skips_malformed_statements() {
+    let code = "<?php\n\n$a = ;\n$b = 2;\nfunction foo() {\n    $c = ) 1;\n    return 3;\n}\n";
+
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "code.php", code);
+    let (program, errors) = parse_source_tolerant(&interner, &source);
+
+    assert_eq!(2, errors.len());
+
+    let statements = program.statements.iter().collect::<Vec<_>>();
+    assert_eq!(4, statements.len());
+    assert!(matches!(statements[1], Statement::Error(span) if &code[span.to_range()] == "$a = ;"));
+    assert!(matches!(statements[2], Statement::Expression(_)));
+
+    let Statement::Function(function) = statements[3] else {
+        panic!("expected a function, found {:?}", statements[3]);
+    };
+
+    let body = function.body.statements.iter().collect::<Vec<_>>();
+    assert_eq!(2, body.len());
+    assert!(matches!(body[0], Statement::Error(span) if &code[span.to_range()] == "$c = ) 1;"));
+    assert!(matches!(body[1], Statement::Return(_)));
+    assert_eq!(code.trim_end(), &code[program.span().to_range()]);
+}
+
+#[test]
+fn test_default_parsing_stops_at_first_error() {
+    let code = "<?php\n\n$a = ;\n$b = 2;\n";
+
+    let interner = ThreadedInterner::new();
+    let source = Source::standalone(&interner, "code.php", code);
+    let (program, error) = parse_source(&interner, &source);
+
+    assert!(error.is_some());
+    assert!(program.statements.iter().all(|statement| !matches!(statement, Statement::Error(_))));
+}
@@ -0,0 +1,525 @@
+use std::collections::BTreeMap;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+
+use mago_ast::ast::*;
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::module::Module;
+
+/// The kind of construct used to include a file.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InclusionKind {
+    Include,
+    IncludeOnce,
+    Require,
+    RequireOnce,
+}
+
+/// The base directory a literal inclusion path is relative to.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum InclusionBase {
+    /// The path is relative to the source root, e.g. `require 'src/bootstrap.php';`.
+    Root,
+    /// The path is relative to the directory of the including file, e.g. `require __DIR__ . '/bootstrap.php';`.
+    Directory,
+}
+
+/// Represents an `include`/`require` construct whose path could be statically determined.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct Inclusion {
+    pub kind: InclusionKind,
+    pub base: InclusionBase,
+    pub path: String,
+    pub span: Span,
+}
+
+/// Represents an edge in the include graph, from an including file to an included file.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct IncludeEdge {
+    pub kind: InclusionKind,
+    pub target: PathBuf,
+    pub span: Span,
+    pub exists: bool,
+}
+
+/// A file-inclusion graph built from the literal `include`/`require` constructs of a set of modules.
+///
+/// Nodes are file paths, and edges point from the including file to the included file.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IncludeGraph {
+    edges: BTreeMap<PathBuf, Vec<IncludeEdge>>,
+    #[serde(skip)]
+    cycles: OnceLock<Vec<Vec<PathBuf>>>,
+}
+
+impl InclusionKind {
+    #[inline]
+    pub const fn is_require(&self) -> bool {
+        matches!(self, Self::Require | Self::RequireOnce)
+    }
+}
+
+impl Inclusion {
+    /// Resolves the inclusion path to an absolute, normalized path.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The source root, used for paths relative to the root.
+    /// * `file` - The path of the including file, used for paths relative to its directory.
+    pub fn resolve(&self, root: &Path, file: &Path) -> PathBuf {
+        let path = Path::new(&self.path);
+        if path.is_absolute() {
+            return normalize(path);
+        }
+
+        let base = match self.base {
+            InclusionBase::Root => root,
+            InclusionBase::Directory => file.parent().unwrap_or(root),
+        };
+
+        normalize(&base.join(path))
+    }
+}
+
+impl IncludeGraph {
+    /// Builds the include graph for the given modules.
+    ///
+    /// Modules without a path on disk are ignored, as their inclusions cannot be resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The source root, used to resolve paths that are not relative to the including file.
+    /// * `modules` - The modules to build the graph from.
+    pub fn build<'a>(root: &Path, modules: impl IntoIterator<Item = &'a Module>) -> Self {
+        let root = normalize(root);
+        let mut graph = Self::default();
+
+        for module in modules {
+            graph.insert_normalized(&root, module);
+        }
+
+        graph
+    }
+
+    /// Adds the inclusions of the given module to the graph.
+    ///
+    /// This allows building the graph one module at a time, without holding on to all modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The source root, used to resolve paths that are not relative to the including file.
+    /// * `module` - The module to add to the graph.
+    pub fn insert(&mut self, root: &Path, module: &Module) {
+        self.insert_normalized(&normalize(root), module);
+    }
+
+    fn insert_normalized(&mut self, root: &Path, module: &Module) {
+        let Some(file) = &module.source.path else {
+            return;
+        };
+
+        let file = normalize(file);
+        let module_edges = module
+            .inclusions
+            .iter()
+            .map(|inclusion| {
+                let target = inclusion.resolve(root, &file);
+                let exists = target.is_file();
+
+                IncludeEdge { kind: inclusion.kind, target, span: inclusion.span, exists }
+            })
+            .collect();
+
+        self.edges.insert(file, module_edges);
+        self.cycles = OnceLock::new();
+    }
+
+    /// Returns the number of files in the graph.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns `true` if the graph contains no files.
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Returns an iterator over all files in the graph, alongside the edges originating from them.
+    pub fn edges(&self) -> impl Iterator<Item = (&PathBuf, &[IncludeEdge])> {
+        self.edges.iter().map(|(file, edges)| (file, edges.as_slice()))
+    }
+
+    /// Returns the edges originating from the given file, which are empty if the file is not in the graph.
+    pub fn edges_of(&self, file: &Path) -> &[IncludeEdge] {
+        self.edges.get(&normalize(file)).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Returns the inclusion cycle the given file is part of, if any.
+    ///
+    /// The cycles of the graph are found once, on the first call, and reused afterwards.
+    pub fn cycle_of(&self, file: &Path) -> Option<&[PathBuf]> {
+        let file = normalize(file);
+
+        self.cycles.get_or_init(|| self.find_cycles()).iter().find(|cycle| cycle.contains(&file)).map(Vec::as_slice)
+    }
+
+    /// Finds all inclusion cycles in the graph.
+    ///
+    /// Each cycle is returned as a list of files, sorted by path, that (transitively) include each other.
+    /// A file that includes itself is reported as a cycle of one file.
+    pub fn find_cycles(&self) -> Vec<Vec<PathBuf>> {
+        let nodes: Vec<&PathBuf> = self.edges.keys().collect();
+        let index_of = |path: &PathBuf| nodes.binary_search(&path).ok();
+
+        let successors: Vec<Vec<usize>> = nodes
+            .iter()
+            .map(|node| self.edges[*node].iter().filter_map(|edge| index_of(&edge.target)).collect())
+            .collect();
+
+        let mut cycles = vec![];
+        for component in strongly_connected_components(&successors) {
+            let is_cycle = component.len() > 1 || successors[component[0]].contains(&component[0]);
+            if !is_cycle {
+                continue;
+            }
+
+            let mut cycle = component.into_iter().map(|index| nodes[index].clone()).collect::<Vec<_>>();
+            cycle.sort();
+            cycles.push(cycle);
+        }
+
+        cycles.sort();
+        cycles
+    }
+
+    /// Renders the graph in the Graphviz DOT format.
+    ///
+    /// Paths are displayed relative to the given root whenever possible.
+    pub fn to_dot(&self, root: &Path) -> String {
+        let root = normalize(root);
+        let display = |path: &Path| {
+            path.strip_prefix(&root).unwrap_or(path).display().to_string().replace('\\', "\\\\").replace('"', "\\\"")
+        };
+
+        let mut dot = String::from("digraph includes {\n    node [shape=box, fontname=\"monospace\"];\n");
+        for (file, edges) in &self.edges {
+            dot.push_str(&format!("    \"{}\";\n", display(file)));
+
+            for edge in edges {
+                let style = if edge.exists { "solid" } else { "dashed" };
+
+                dot.push_str(&format!(
+                    "    \"{}\" -> \"{}\" [label=\"{}\", style={}];\n",
+                    display(file),
+                    display(&edge.target),
+                    edge.kind,
+                    style
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Collects all inclusions with a statically known path from the given program.
+///
+/// Supported paths are string literals, and `__DIR__` or `dirname(__FILE__)` concatenated with a string literal.
+pub fn collect_inclusions(interner: &ThreadedInterner, program: &Program) -> Vec<Inclusion> {
+    let mut collector = InclusionCollector { interner, inclusions: vec![] };
+
+    collector.walk_program(program, &mut ());
+
+    collector.inclusions
+}
+
+struct InclusionCollector<'a> {
+    interner: &'a ThreadedInterner,
+    inclusions: Vec<Inclusion>,
+}
+
+impl InclusionCollector<'_> {
+    fn collect(&mut self, kind: InclusionKind, value: &Expression, span: Span) {
+        let Some((base, path)) = get_inclusion_path(self.interner, value) else {
+            return;
+        };
+
+        self.inclusions.push(Inclusion { kind, base, path, span });
+    }
+}
+
+impl MutWalker<()> for InclusionCollector<'_> {
+    fn walk_in_include_construct(&mut self, construct: &IncludeConstruct, _: &mut ()) {
+        self.collect(InclusionKind::Include, &construct.value, construct.span());
+    }
+
+    fn walk_in_include_once_construct(&mut self, construct: &IncludeOnceConstruct, _: &mut ()) {
+        self.collect(InclusionKind::IncludeOnce, &construct.value, construct.span());
+    }
+
+    fn walk_in_require_construct(&mut self, construct: &RequireConstruct, _: &mut ()) {
+        self.collect(InclusionKind::Require, &construct.value, construct.span());
+    }
+
+    fn walk_in_require_once_construct(&mut self, construct: &RequireOnceConstruct, _: &mut ()) {
+        self.collect(InclusionKind::RequireOnce, &construct.value, construct.span());
+    }
+}
+
+fn get_inclusion_path(interner: &ThreadedInterner, expression: &Expression) -> Option<(InclusionBase, String)> {
+    match expression {
+        Expression::Parenthesized(parenthesized) => get_inclusion_path(interner, &parenthesized.expression),
+        Expression::Literal(Literal::String(string)) => Some((InclusionBase::Root, get_string_value(interner, string))),
+        Expression::Binary(Binary { lhs, operator: BinaryOperator::StringConcat(_), rhs }) => {
+            if !is_current_directory(interner, lhs) {
+                return None;
+            }
+
+            let Expression::Literal(Literal::String(string)) = rhs.as_ref() else {
+                return None;
+            };
+
+            let path = get_string_value(interner, string);
+
+            Some((InclusionBase::Directory, path.trim_start_matches(['/', '\\']).to_string()))
+        }
+        _ => None,
+    }
+}
+
+fn is_current_directory(interner: &ThreadedInterner, expression: &Expression) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => is_current_directory(interner, &parenthesized.expression),
+        Expression::MagicConstant(MagicConstant::Directory(_)) => true,
+        Expression::Call(Call::Function(FunctionCall { function, argument_list })) => {
+            let Expression::Identifier(identifier) = function.as_ref() else {
+                return false;
+            };
+
+            let name = interner.lookup(&identifier.value());
+            if !name.trim_start_matches('\\').eq_ignore_ascii_case("dirname") || argument_list.arguments.len() != 1 {
+                return false;
+            }
+
+            matches!(
+                argument_list.arguments.first(),
+                Some(Argument::Positional(PositionalArgument {
+                    ellipsis: None,
+                    value: Expression::MagicConstant(MagicConstant::File(_))
+                }))
+            )
+        }
+        _ => false,
+    }
+}
+
+fn get_string_value(interner: &ThreadedInterner, string: &LiteralString) -> String {
+    let value = interner.lookup(&string.value);
+
+    value[1..value.len() - 1].to_string()
+}
+
+/// Normalizes the given path lexically, removing `.` components, and resolving `..` components
+/// without accessing the file system.
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+/// Computes the strongly connected components of a graph using Tarjan's algorithm.
+///
+/// The graph is given as the list of successors of each node, nodes being identified by their index.
+pub fn strongly_connected_components(successors: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    struct State<'a> {
+        successors: &'a [Vec<usize>],
+        index: usize,
+        indices: Vec<Option<usize>>,
+        low_links: Vec<usize>,
+        on_stack: Vec<bool>,
+        stack: Vec<usize>,
+        components: Vec<Vec<usize>>,
+    }
+
+    fn visit(state: &mut State<'_>, node: usize) {
+        state.indices[node] = Some(state.index);
+        state.low_links[node] = state.index;
+        state.index += 1;
+        state.stack.push(node);
+        state.on_stack[node] = true;
+
+        for &successor in &state.successors[node] {
+            match state.indices[successor] {
+                None => {
+                    visit(state, successor);
+                    state.low_links[node] = state.low_links[node].min(state.low_links[successor]);
+                }
+                Some(index) if state.on_stack[successor] => {
+                    state.low_links[node] = state.low_links[node].min(index);
+                }
+                _ => {}
+            }
+        }
+
+        if Some(state.low_links[node]) == state.indices[node] {
+            let mut component = vec![];
+            while let Some(member) = state.stack.pop() {
+                state.on_stack[member] = false;
+                component.push(member);
+
+                if member == node {
+                    break;
+                }
+            }
+
+            state.components.push(component);
+        }
+    }
+
+    let length = successors.len();
+    let mut state = State {
+        successors,
+        index: 0,
+        indices: vec![None; length],
+        low_links: vec![0; length],
+        on_stack: vec![false; length],
+        stack: vec![],
+        components: vec![],
+    };
+
+    for node in 0..length {
+        if state.indices[node].is_none() {
+            visit(&mut state, node);
+        }
+    }
+
+    state.components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use mago_php_version::PHPVersion;
+    use mago_source::Source;
+    use mago_span::Position;
+
+    use crate::module::ModuleBuildOptions;
+
+    fn build_module(interner: &ThreadedInterner, path: &Path, code: &str) -> Module {
+        let mut source = Source::standalone(interner, &path.display().to_string(), code);
+        source.path = Some(path.to_path_buf());
+
+        Module::build(interner, PHPVersion::PHP84, source, ModuleBuildOptions::new(false, false))
+    }
+
+    #[test]
+    fn test_inclusion_paths_are_resolved() {
+        let root = Path::new("/project");
+        let file = Path::new("/project/src/index.php");
+        let inclusion = |base, path: &str| Inclusion {
+            kind: InclusionKind::Require,
+            base,
+            path: path.to_string(),
+            span: Span::new(Position::dummy(0), Position::dummy(0)),
+        };
+
+        assert_eq!(
+            inclusion(InclusionBase::Root, "vendor/autoload.php").resolve(root, file),
+            PathBuf::from("/project/vendor/autoload.php")
+        );
+        assert_eq!(
+            inclusion(InclusionBase::Directory, "../config/./app.php").resolve(root, file),
+            PathBuf::from("/project/config/app.php")
+        );
+        assert_eq!(inclusion(InclusionBase::Root, "/etc/app.php").resolve(root, file), PathBuf::from("/etc/app.php"));
+    }
+
+    #[test]
+    fn test_inclusions_are_collected() {
+        let interner = ThreadedInterner::new();
+        let module = build_module(
+            &interner,
+            Path::new("/project/index.php"),
+            "<?php\n\nrequire 'a.php';\ninclude_once __DIR__ . '/b.php';\nrequire dirname(__FILE__) . '/c.php';\ninclude $path;\n",
+        );
+
+        let inclusions = module
+            .inclusions
+            .iter()
+            .map(|inclusion| (inclusion.kind, inclusion.base, inclusion.path.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            inclusions,
+            vec![
+                (InclusionKind::Require, InclusionBase::Root, "a.php"),
+                (InclusionKind::IncludeOnce, InclusionBase::Directory, "b.php"),
+                (InclusionKind::Require, InclusionBase::Directory, "c.php"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_inclusions_are_detected() {
+        let interner = ThreadedInterner::new();
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let file = directory.join("index.php");
+        let module = build_module(
+            &interner,
+            &file,
+            "<?php\n\nrequire __DIR__ . '/lib.rs';\nrequire __DIR__ . '/missing.php';\n",
+        );
+
+        let graph = IncludeGraph::build(&directory, [&module]);
+        let edges = graph.edges_of(&file);
+
+        assert_eq!(edges.len(), 2);
+        assert!(edges[0].exists);
+        assert_eq!(edges[1].target, directory.join("missing.php"));
+        assert!(!edges[1].exists);
+        assert!(graph.edges_of(&directory.join("other.php")).is_empty());
+    }
+
+    #[test]
+    fn test_inclusion_cycles_are_found() {
+        let interner = ThreadedInterner::new();
+        let modules = [
+            build_module(&interner, Path::new("/project/a.php"), "<?php require __DIR__ . '/b.php';"),
+            build_module(&interner, Path::new("/project/b.php"), "<?php require __DIR__ . '/./a.php';"),
+            build_module(&interner, Path::new("/project/c.php"), "<?php require __DIR__ . '/a.php';"),
+        ];
+
+        let graph = IncludeGraph::build(Path::new("/project"), &modules);
+        let cycle = vec![PathBuf::from("/project/a.php"), PathBuf::from("/project/b.php")];
+
+        assert_eq!(graph.find_cycles(), vec![cycle.clone()]);
+        assert_eq!(graph.cycle_of(Path::new("/project/b.php")), Some(cycle.as_slice()));
+        assert_eq!(graph.cycle_of(Path::new("/project/c.php")), None);
+    }
+}
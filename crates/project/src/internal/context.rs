@@ -1,5 +1,7 @@
+use mago_ast::AttributeList;
 use mago_ast::Program;
-use mago_interner::StringIdentifier;
+use mago_ast::Sequence;
+use mago_docblock::document::Document;
 use mago_interner::ThreadedInterner;
 use mago_names::Names;
 use mago_php_version::PHPVersion;
@@ -48,13 +50,19 @@ impl<'a> Context<'a> {
 
     #[inline]
     pub fn get_code_snippet(&self, span: impl HasSpan) -> &'a str {
-        fn get_code_snippet_of_span<'a>(i: &'a ThreadedInterner, c: &StringIdentifier, s: &Span) -> &'a str {
-            let source = i.lookup(c);
+        let span = span.span();
 
-            &source[s.start.offset..s.end.offset]
-        }
+        &self.source.content[span.start.offset..span.end.offset]
+    }
+
+    /// Retrieves the parsed docblock of a declaration, if any.
+    ///
+    /// Docblocks that fail to parse are ignored.
+    #[inline]
+    pub fn get_docblock(&self, attribute_lists: &Sequence<AttributeList>, declaration: Span) -> Option<Document> {
+        let trivia = mago_docblock::get_declaration_docblock(self.program, attribute_lists, declaration)?;
 
-        get_code_snippet_of_span(self.interner, &self.source.content, &span.span())
+        mago_docblock::parse_trivia(self.interner, trivia).ok()
     }
 
     #[inline]
@@ -34,21 +34,21 @@ pub fn build(
     let mut context = Context::new(interner, &version, program, names, source);
 
     let reflection = match options {
-        ModuleBuildOptions { reflect: true, validate: true } => {
+        ModuleBuildOptions { reflect: true, validate: true, .. } => {
             let mut context = Context::new(interner, &version, program, names, source);
             let mut walker = ModuleBuildingWalker::new();
             walker.walk_program(program, &mut context);
 
             Some(walker.reflection)
         }
-        ModuleBuildOptions { reflect: true, validate: false } => {
+        ModuleBuildOptions { reflect: true, validate: false, .. } => {
             let mut context = Context::new(interner, &version, program, names, source);
             let mut walker = ModuleReflectionWalker::new();
             walker.walk_program(program, &mut context);
 
             Some(walker.reflection)
         }
-        ModuleBuildOptions { reflect: false, validate: true } => {
+        ModuleBuildOptions { reflect: false, validate: true, .. } => {
             ModuleCheckingWalker.walk_program(program, &mut context);
 
             None
@@ -14,6 +14,11 @@ use mago_span::*;
 
 use crate::internal::context::Context;
 use crate::internal::reflector::attribute::reflect_attributes;
+use crate::internal::reflector::docblock::is_immutable;
+use crate::internal::reflector::docblock::is_pure;
+use crate::internal::reflector::docblock::reflect_assertions;
+use crate::internal::reflector::docblock::reflect_templates;
+use crate::internal::reflector::docblock::reflect_type_aliases;
 use crate::internal::reflector::function_like::reflect_function_like_parameter_list;
 use crate::internal::reflector::function_like::reflect_function_like_return_type_hint;
 use crate::internal::reflector::r#type::maybe_reflect_hint;
@@ -29,6 +34,12 @@ pub fn reflect_class<'ast>(class: &'ast Class, context: &'ast mut Context<'_>) -
     reflection.is_readonly = class.modifiers.contains_readonly();
     reflection.is_abstract = class.modifiers.contains_abstract();
     reflection.attribute_reflections = reflect_attributes(&class.attribute_lists, context);
+    reflect_class_like_docblock(
+        &mut reflection,
+        &class.attribute_lists,
+        class.modifiers.first_span().unwrap_or(class.class.span()),
+        context,
+    );
     reflection.inheritance = {
         let mut inheritance_reflection = InheritanceReflection::default();
         if let Some(extends) = &class.extends {
@@ -117,6 +128,7 @@ pub fn reflect_interface<'ast>(interface: &'ast Interface, context: &'ast mut Co
     let mut reflection = ClassLikeReflection::new(name, span);
     reflection.is_abstract = true;
     reflection.attribute_reflections = reflect_attributes(&interface.attribute_lists, context);
+    reflect_class_like_docblock(&mut reflection, &interface.attribute_lists, interface.interface.span(), context);
     reflection.inheritance = {
         let mut inheritance_reflection = InheritanceReflection::default();
         if let Some(extends) = &interface.extends {
@@ -146,6 +158,7 @@ pub fn reflect_trait<'ast>(r#trait: &'ast Trait, context: &'ast mut Context<'_>)
     let mut reflection = ClassLikeReflection::new(name, span);
     reflection.is_abstract = true;
     reflection.attribute_reflections = reflect_attributes(&r#trait.attribute_lists, context);
+    reflect_class_like_docblock(&mut reflection, &r#trait.attribute_lists, r#trait.r#trait.span(), context);
 
     reflect_class_like_members(&mut reflection, &r#trait.members, context);
 
@@ -311,6 +324,20 @@ fn reflect_class_like_enum_case<'ast>(
     }
 }
 
+#[inline]
+fn reflect_class_like_docblock(
+    reflection: &mut ClassLikeReflection,
+    attribute_lists: &Sequence<AttributeList>,
+    declaration: Span,
+    context: &Context<'_>,
+) {
+    let docblock = context.get_docblock(attribute_lists, declaration);
+
+    reflection.templates = reflect_templates(docblock.as_ref(), context);
+    reflection.type_aliases = reflect_type_aliases(docblock.as_ref(), context);
+    reflection.is_immutable = is_immutable(docblock.as_ref());
+}
+
 #[inline]
 fn reflect_class_like_method<'ast>(
     class_like: &mut ClassLikeReflection,
@@ -327,6 +354,8 @@ fn reflect_class_like_method<'ast>(
     };
 
     let visibility_reflection = modifier_to_visibility(method.modifiers.get_first_read_visibility());
+    let docblock =
+        context.get_docblock(&method.attribute_lists, method.modifiers.first_span().unwrap_or(method.function.span()));
 
     (
         name,
@@ -334,9 +363,13 @@ fn reflect_class_like_method<'ast>(
             attribute_reflections: reflect_attributes(&method.attribute_lists, context),
             visibility_reflection,
             name: FunctionLikeName::Method(class_like.name, name),
-            // TODO: parse docblock to get the template list
-            templates: vec![],
-            parameters: reflect_function_like_parameter_list(&method.parameter_list, context, Some(class_like)),
+            templates: reflect_templates(docblock.as_ref(), context),
+            parameters: reflect_function_like_parameter_list(
+                &method.parameter_list,
+                docblock.as_ref(),
+                context,
+                Some(class_like),
+            ),
             return_type_reflection: reflect_function_like_return_type_hint(
                 &method.return_type_hint,
                 context,
@@ -346,8 +379,8 @@ fn reflect_class_like_method<'ast>(
             has_yield,
             has_throws,
             is_anonymous: false,
-            // TODO: parse docblock to determine if pure
-            is_pure: false,
+            is_pure: is_pure(docblock.as_ref()),
+            assertions: reflect_assertions(docblock.as_ref(), context),
             is_static: method.modifiers.contains_static(),
             is_final: class_like.is_final || method.modifiers.contains_final(),
             is_abstract,
@@ -492,9 +525,12 @@ fn reflect_class_like_property<'ast>(
                                 // TODO: parse docblock to get the template list
                                 templates: vec![],
                                 parameters: match hook.parameters.as_ref() {
-                                    Some(parameters) => {
-                                        reflect_function_like_parameter_list(parameters, context, Some(class_like))
-                                    }
+                                    Some(parameters) => reflect_function_like_parameter_list(
+                                        parameters,
+                                        None,
+                                        context,
+                                        Some(class_like),
+                                    ),
                                     None => vec![],
                                 },
                                 return_type_reflection: None,
@@ -505,6 +541,7 @@ fn reflect_class_like_property<'ast>(
                                 is_static: false,
                                 is_final: true,
                                 is_pure: false,
+                                assertions: vec![],
                                 is_abstract: false,
                                 is_overriding: false,
                                 span: hook.span(),
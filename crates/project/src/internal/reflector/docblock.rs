@@ -0,0 +1,133 @@
+use ahash::HashSet;
+
+use mago_docblock::document::Document;
+use mago_docblock::document::Element;
+use mago_docblock::document::Tag;
+use mago_docblock::tag::parse_assertion_tag;
+use mago_docblock::tag::parse_template_tag;
+use mago_docblock::tag::parse_type_alias_import_tag;
+use mago_docblock::tag::parse_type_alias_tag;
+use mago_docblock::tag::split_type;
+use mago_interner::StringIdentifier;
+use mago_reflection::function_like::assertion::AssertionKind;
+use mago_reflection::function_like::assertion::AssertionReflection;
+use mago_reflection::r#type::TypeReflection;
+use mago_reflection::r#type::kind::Template;
+
+use crate::internal::context::Context;
+
+/// Reflects the templates declared in a docblock, e.g. `@template T of Foo`.
+///
+/// Template constraints are not reflected yet, as docblock types are not resolved.
+#[inline]
+pub fn reflect_templates(document: Option<&Document>, context: &Context<'_>) -> Vec<Template> {
+    let mut templates: Vec<Template> = vec![];
+    for tag in get_tags(document) {
+        let Some(template) = parse_template_tag(tag.kind, context.interner.lookup(&tag.description)) else {
+            continue;
+        };
+
+        let name = context.interner.intern(template.name);
+        if templates.iter().any(|existing| existing.get_name() == &name) {
+            continue;
+        }
+
+        templates.push(Template::new(name, vec![]));
+    }
+
+    templates
+}
+
+/// Reflects the assertions declared in a docblock, e.g. `@psalm-assert int $value`.
+#[inline]
+pub fn reflect_assertions(document: Option<&Document>, context: &Context<'_>) -> Vec<AssertionReflection> {
+    get_tags(document)
+        .filter_map(|tag| {
+            let kind = if tag.kind.is_assert() {
+                AssertionKind::Always
+            } else if tag.kind.is_assert_if_true() {
+                AssertionKind::IfTrue
+            } else if tag.kind.is_assert_if_false() {
+                AssertionKind::IfFalse
+            } else {
+                return None;
+            };
+
+            let assertion = parse_assertion_tag(context.interner.lookup(&tag.description))?;
+
+            Some(AssertionReflection {
+                kind,
+                parameter: context.interner.intern(assertion.parameter),
+                r#type: context.interner.intern(assertion.r#type),
+                span: tag.span,
+            })
+        })
+        .collect()
+}
+
+/// Reflects the type of a parameter declared in a docblock, e.g. `@param array{id: int} $user`.
+///
+/// Vendored tags, e.g. `@psalm-param`, take precedence over `@param`. Class names used in the type are not resolved.
+#[inline]
+pub fn reflect_parameter_type(
+    document: Option<&Document>,
+    parameter: &StringIdentifier,
+    context: &Context<'_>,
+) -> Option<TypeReflection> {
+    let parameter = context.interner.lookup(parameter);
+
+    get_tags(document)
+        .filter(|tag| tag.kind.is_param())
+        .filter_map(|tag| {
+            let (r#type, rest) = split_type(context.interner.lookup(&tag.description))?;
+            let name = rest.split_whitespace().next()?.trim_start_matches('&').trim_start_matches("...");
+            if name != parameter {
+                return None;
+            }
+
+            let kind = mago_typing::docblock::parse_docblock_type(context.interner, r#type)?;
+
+            Some((tag.kind.get_vendor().is_some(), TypeReflection { kind, inferred: false, span: tag.span }))
+        })
+        .max_by_key(|(is_vendored, _)| *is_vendored)
+        .map(|(_, type_reflection)| type_reflection)
+}
+
+/// Reflects the type aliases declared or imported in a docblock, e.g. `@psalm-type Foo = int`.
+#[inline]
+pub fn reflect_type_aliases(document: Option<&Document>, context: &Context<'_>) -> HashSet<StringIdentifier> {
+    get_tags(document)
+        .filter_map(|tag| {
+            let description = context.interner.lookup(&tag.description);
+
+            if tag.kind.is_type_alias() {
+                parse_type_alias_tag(description)
+            } else if tag.kind.is_type_alias_import() {
+                parse_type_alias_import_tag(description).map(|import| import.alias.unwrap_or(import.name))
+            } else {
+                None
+            }
+        })
+        .map(|name| context.interner.intern(name))
+        .collect()
+}
+
+/// Determines whether a docblock marks a function or method as pure, e.g. using `@psalm-pure`.
+#[inline]
+pub fn is_pure(document: Option<&Document>) -> bool {
+    get_tags(document).any(|tag| tag.kind.is_pure())
+}
+
+/// Determines whether a docblock marks a class-like entity as immutable, e.g. using `@psalm-immutable`.
+#[inline]
+pub fn is_immutable(document: Option<&Document>) -> bool {
+    get_tags(document).any(|tag| tag.kind.is_immutable())
+}
+
+#[inline]
+fn get_tags(document: Option<&Document>) -> impl Iterator<Item = &Tag> {
+    document.into_iter().flat_map(|document| document.elements.iter()).filter_map(|element| match element {
+        Element::Tag(tag) => Some(tag),
+        _ => None,
+    })
+}
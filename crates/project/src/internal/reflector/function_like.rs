@@ -1,4 +1,5 @@
 use mago_ast::*;
+use mago_docblock::document::Document;
 use mago_reflection::class_like::ClassLikeReflection;
 use mago_reflection::function_like::parameter::*;
 use mago_reflection::function_like::r#return::*;
@@ -9,6 +10,10 @@ use mago_span::*;
 
 use crate::internal::context::Context;
 use crate::internal::reflector::attribute::reflect_attributes;
+use crate::internal::reflector::docblock::is_pure;
+use crate::internal::reflector::docblock::reflect_assertions;
+use crate::internal::reflector::docblock::reflect_parameter_type;
+use crate::internal::reflector::docblock::reflect_templates;
 use crate::internal::reflector::r#type::maybe_reflect_hint;
 use crate::internal::reflector::r#type::reflect_hint;
 
@@ -19,14 +24,14 @@ pub fn reflect_function<'ast>(
     scope: Option<&ClassLikeReflection>,
 ) -> FunctionLikeReflection {
     let name = Name::new(*context.names.get(&function.name), function.name.span);
+    let docblock = context.get_docblock(&function.attribute_lists, function.function.span());
 
     FunctionLikeReflection {
         attribute_reflections: reflect_attributes(&function.attribute_lists, context),
         visibility_reflection: None,
         name: FunctionLikeName::Function(name),
-        // TODO: parse docblock to get the template list
-        templates: vec![],
-        parameters: reflect_function_like_parameter_list(&function.parameter_list, context, scope),
+        templates: reflect_templates(docblock.as_ref(), context),
+        parameters: reflect_function_like_parameter_list(&function.parameter_list, docblock.as_ref(), context, scope),
         return_type_reflection: reflect_function_like_return_type_hint(&function.return_type_hint, context, scope),
         returns_by_reference: function.ampersand.is_some(),
         has_yield: mago_ast_utils::block_has_yield(&function.body),
@@ -34,8 +39,8 @@ pub fn reflect_function<'ast>(
         is_anonymous: false,
         is_static: true,
         is_final: true,
-        // TODO: parse docblock to determine if pure
-        is_pure: false,
+        is_pure: is_pure(docblock.as_ref()),
+        assertions: reflect_assertions(docblock.as_ref(), context),
         is_abstract: false,
         is_overriding: false,
         span: function.span(),
@@ -50,13 +55,17 @@ pub fn reflect_closure<'ast>(
     context: &'ast mut Context<'_>,
     scope: Option<&ClassLikeReflection>,
 ) -> FunctionLikeReflection {
+    let docblock = context.get_docblock(
+        &closure.attribute_lists,
+        closure.r#static.as_ref().map_or(closure.function.span(), |r#static| r#static.span()),
+    );
+
     FunctionLikeReflection {
         attribute_reflections: reflect_attributes(&closure.attribute_lists, context),
         visibility_reflection: None,
         name: FunctionLikeName::Closure(closure.span()),
-        // TODO: parse docblock to get the template list
-        templates: vec![],
-        parameters: reflect_function_like_parameter_list(&closure.parameter_list, context, scope),
+        templates: reflect_templates(docblock.as_ref(), context),
+        parameters: reflect_function_like_parameter_list(&closure.parameter_list, docblock.as_ref(), context, scope),
         return_type_reflection: reflect_function_like_return_type_hint(&closure.return_type_hint, context, scope),
         returns_by_reference: closure.ampersand.is_some(),
         has_yield: mago_ast_utils::block_has_yield(&closure.body),
@@ -64,8 +73,8 @@ pub fn reflect_closure<'ast>(
         is_anonymous: true,
         is_static: closure.r#static.is_some(),
         is_final: true,
-        // TODO: parse docblock to determine if pure
-        is_pure: false,
+        is_pure: is_pure(docblock.as_ref()),
+        assertions: reflect_assertions(docblock.as_ref(), context),
         is_abstract: false,
         is_overriding: false,
         span: closure.span(),
@@ -80,13 +89,22 @@ pub fn reflect_arrow_function<'ast>(
     context: &'ast mut Context<'_>,
     scope: Option<&ClassLikeReflection>,
 ) -> FunctionLikeReflection {
+    let docblock = context.get_docblock(
+        &arrow_function.attribute_lists,
+        arrow_function.r#static.as_ref().map_or(arrow_function.r#fn.span(), |r#static| r#static.span()),
+    );
+
     FunctionLikeReflection {
         attribute_reflections: reflect_attributes(&arrow_function.attribute_lists, context),
         visibility_reflection: None,
         name: FunctionLikeName::ArrowFunction(arrow_function.span()),
-        // TODO: parse docblock to get the template list
-        templates: vec![],
-        parameters: reflect_function_like_parameter_list(&arrow_function.parameter_list, context, scope),
+        templates: reflect_templates(docblock.as_ref(), context),
+        parameters: reflect_function_like_parameter_list(
+            &arrow_function.parameter_list,
+            docblock.as_ref(),
+            context,
+            scope,
+        ),
         return_type_reflection: reflect_function_like_return_type_hint(
             &arrow_function.return_type_hint,
             context,
@@ -98,8 +116,8 @@ pub fn reflect_arrow_function<'ast>(
         is_anonymous: true,
         is_static: arrow_function.r#static.is_some(),
         is_final: true,
-        // TODO: parse docblock to determine if pure
-        is_pure: false,
+        is_pure: is_pure(docblock.as_ref()),
+        assertions: reflect_assertions(docblock.as_ref(), context),
         is_abstract: false,
         is_overriding: false,
         span: arrow_function.span(),
@@ -111,12 +129,13 @@ pub fn reflect_arrow_function<'ast>(
 #[inline]
 pub fn reflect_function_like_parameter_list<'ast>(
     parameter_list: &'ast FunctionLikeParameterList,
+    docblock: Option<&Document>,
     context: &'ast mut Context<'_>,
     scope: Option<&ClassLikeReflection>,
 ) -> Vec<FunctionLikeParameterReflection> {
     let mut parameters = vec![];
     for parameter in parameter_list.parameters.iter() {
-        parameters.push(reflect_function_like_parameter(parameter, context, scope));
+        parameters.push(reflect_function_like_parameter(parameter, docblock, context, scope));
     }
 
     parameters
@@ -125,12 +144,14 @@ pub fn reflect_function_like_parameter_list<'ast>(
 #[inline]
 pub fn reflect_function_like_parameter<'ast>(
     parameter: &'ast FunctionLikeParameter,
+    docblock: Option<&Document>,
     context: &'ast mut Context<'_>,
     scope: Option<&ClassLikeReflection>,
 ) -> FunctionLikeParameterReflection {
     FunctionLikeParameterReflection {
         attribute_reflections: reflect_attributes(&parameter.attribute_lists, context),
         type_reflection: maybe_reflect_hint(&parameter.hint, context, scope),
+        docblock_type_reflection: reflect_parameter_type(docblock, &parameter.variable.name, context),
         name: parameter.variable.name,
         is_variadic: parameter.ellipsis.is_some(),
         is_passed_by_reference: parameter.ampersand.is_some(),
@@ -1,5 +1,6 @@
 pub mod attribute;
 pub mod class_like;
 pub mod constant;
+pub mod docblock;
 pub mod function_like;
 pub mod r#type;
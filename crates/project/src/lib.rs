@@ -8,6 +8,7 @@ use crate::module::Module;
 
 mod internal;
 
+pub mod include;
 pub mod module;
 
 /// A builder for incrementally constructing a [`Project`].
@@ -55,6 +56,22 @@ impl ProjectBuilder {
         self.modules.push(module);
     }
 
+    /// Merges the reflection data of a module into the builder, without keeping the module.
+    ///
+    /// Unlike [`add_module`], the module is dropped right away and is not part of the
+    /// built [`Project`], so that modules can be processed one at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - A [`Module`] whose reflection data is merged into the builder.
+    ///
+    /// [`add_module`]: ProjectBuilder::add_module
+    pub fn merge_module(&mut self, mut module: Module) {
+        if let Some(reflection) = module.reflection.take() {
+            self.reflection.merge(&self.interner, reflection);
+        }
+    }
+
     /// Consumes the builder and constructs a [`Project`].
     ///
     /// This method merges all the reflection data collected from the added modules,
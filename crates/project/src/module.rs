@@ -5,25 +5,30 @@ use mago_ast::Program;
 use mago_interner::ThreadedInterner;
 use mago_names::Names;
 use mago_parser::error::ParseError;
+use mago_parser::settings::ParserSettings;
 use mago_php_version::PHPVersion;
 use mago_reflection::CodebaseReflection;
 use mago_reporting::IssueCollection;
 use mago_source::Source;
 use mago_source::SourceCategory;
 
+use crate::include::Inclusion;
+use crate::include::collect_inclusions;
 use crate::internal;
 
 /// `Module` represents a processed PHP source code module.
 ///
-/// It encapsulates the original source, resolved names, any parsing errors,
-/// optional reflection data (if enabled), and a collection of semantic issues.
+/// It encapsulates the original source, resolved names, statically known file inclusions,
+/// the parse errors the parser recovered from, optional reflection data (if enabled), and a collection of semantic issues.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Module {
     pub source: Source,
     pub names: Names,
-    pub parse_error: Option<ParseError>,
+    pub inclusions: Vec<Inclusion>,
+    pub parse_errors: Vec<ParseError>,
     pub reflection: Option<CodebaseReflection>,
     pub issues: IssueCollection,
+    pub parser_settings: ParserSettings,
 }
 
 /// `ModuleBuildOptions` configures the behavior of the module building process.
@@ -31,18 +36,21 @@ pub struct Module {
 /// The options determine whether the module should perform reflection, validation, or both:
 /// - `reflect`: When true, reflection is performed.
 /// - `validate`: When true, semantic validation is performed.
+///
+/// The `parser_settings` control which syntax the parser accepts, such as short opening tags.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ModuleBuildOptions {
     pub reflect: bool,
     pub validate: bool,
+    pub parser_settings: ParserSettings,
 }
 
 impl Module {
     /// Builds a `Module` from a given PHP source.
     ///
-    /// This convenience function parses the PHP source code to generate an abstract syntax tree (AST),
-    /// resolves names, optionally performs reflection and/or semantic validation based on the provided
-    /// build options, and collects any issues encountered during analysis.
+    /// This convenience function parses the PHP source code in the tolerant mode to generate an abstract
+    /// syntax tree (AST), resolves names, optionally performs reflection and/or semantic validation based
+    /// on the provided build options, and collects any issues encountered during analysis.
     ///
     /// Internally, it delegates to [`build_with_ast`] and discards the AST.
     ///
@@ -72,7 +80,8 @@ impl Module {
     /// Builds a `Module` from a given PHP source and returns the associated AST.
     ///
     /// This function performs the complete module processing workflow:
-    /// - Parses the PHP source to generate an AST (`Program`).
+    /// - Parses the PHP source in the tolerant mode to generate an AST (`Program`), where malformed regions
+    ///   are represented as error statements.
     /// - Resolves symbol names.
     /// - Optionally performs reflection and/or semantic validation based on the provided build options.
     /// - Collects any issues encountered during analysis.
@@ -99,10 +108,13 @@ impl Module {
         source: Source,
         options: ModuleBuildOptions,
     ) -> (Self, Program) {
-        let (program, parse_error) = mago_parser::parse_source(interner, &source);
+        let parser_settings = options.parser_settings;
+        let (program, parse_errors) =
+            mago_parser::parse_source_tolerant_with_settings(interner, &source, parser_settings);
         let names = Names::resolve(interner, &program);
+        let inclusions = collect_inclusions(interner, &program);
         let (reflection, issues) = internal::build(interner, version, &source, &program, &names, options);
-        let module = Self { source, parse_error, names, reflection, issues };
+        let module = Self { source, parse_errors, names, inclusions, reflection, issues, parser_settings };
 
         (module, program)
     }
@@ -120,7 +132,7 @@ impl Module {
     ///
     /// A `Program` representing the abstract syntax tree (AST) of the module.
     pub fn parse(&self, interner: &ThreadedInterner) -> Program {
-        mago_parser::parse_source(interner, &self.source).0
+        mago_parser::parse_source_tolerant_with_settings(interner, &self.source, self.parser_settings).0
     }
 
     /// Retrieves the category of the module's source.
@@ -136,20 +148,27 @@ impl Module {
 impl ModuleBuildOptions {
     /// Creates a new `ModuleBuildOptions` with the specified settings.
     #[inline(always)]
-    pub const fn new(reflect: bool, validate: bool) -> Self {
-        Self { reflect, validate }
+    pub fn new(reflect: bool, validate: bool) -> Self {
+        Self { reflect, validate, parser_settings: ParserSettings::default() }
     }
 
     /// Returns build options configured for reflection only (without validation).
     #[inline(always)]
-    pub const fn reflection() -> Self {
-        Self { reflect: true, validate: false }
+    pub fn reflection() -> Self {
+        Self::new(true, false)
     }
 
     /// Returns build options configured for validation only (without reflection).
     #[inline(always)]
-    pub const fn validation() -> Self {
-        Self { reflect: false, validate: true }
+    pub fn validation() -> Self {
+        Self::new(false, true)
+    }
+
+    /// Returns these build options, parsing the source with the given parser settings.
+    #[inline(always)]
+    pub fn with_parser_settings(mut self, parser_settings: ParserSettings) -> Self {
+        self.parser_settings = parser_settings;
+        self
     }
 }
 
@@ -21,6 +21,7 @@ use crate::function_like::FunctionLikeReflection;
 use crate::identifier::ClassLikeName;
 use crate::identifier::Name;
 use crate::r#type::TypeReflection;
+use crate::r#type::kind::Template;
 
 pub mod constant;
 pub mod enum_case;
@@ -40,6 +41,13 @@ pub struct ClassLikeReflection {
     /// Inheritance information for the class-like entity, including parent classes and implemented interfaces.
     pub inheritance: InheritanceReflection,
 
+    /// The list of templates accepted by the class-like entity, declared using `@template` tags.
+    pub templates: Vec<Template>,
+
+    /// The names of the type aliases available in the class-like entity, either declared using
+    /// `@psalm-type` and `@phpstan-type` tags, or imported using `@psalm-import-type` and `@phpstan-import-type` tags.
+    pub type_aliases: HashSet<StringIdentifier>,
+
     /// Constants defined in the class-like entity.
     pub constants: HashMap<StringIdentifier, ClassLikeConstantReflection>,
 
@@ -67,6 +75,10 @@ pub struct ClassLikeReflection {
     /// Whether the class-like entity is declared as `readonly`.
     pub is_readonly: bool,
 
+    /// Whether the class-like entity is marked as immutable using the `@immutable` docblock tag,
+    /// or its `@psalm-immutable` and `@phpstan-immutable` variants.
+    pub is_immutable: bool,
+
     /// Whether the class-like entity is declared as `abstract`.
     pub is_abstract: bool,
 
@@ -89,6 +101,8 @@ impl ClassLikeReflection {
             attribute_reflections: Vec::new(),
             name,
             inheritance: InheritanceReflection::default(),
+            templates: Vec::new(),
+            type_aliases: HashSet::default(),
             constants: HashMap::default(),
             cases: HashMap::default(),
             properties: MemeberCollection::default(),
@@ -98,6 +112,7 @@ impl ClassLikeReflection {
             backing_type: None,
             is_final: false,
             is_readonly: false,
+            is_immutable: false,
             is_abstract: false,
             is_anonymous: false,
             span,
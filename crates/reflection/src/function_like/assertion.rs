@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_interner::StringIdentifier;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// Represents the condition under which an assertion holds.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum AssertionKind {
+    /// The assertion holds once the function returns, e.g. `@psalm-assert`.
+    Always,
+
+    /// The assertion holds if the function returns `true`, e.g. `@psalm-assert-if-true`.
+    IfTrue,
+
+    /// The assertion holds if the function returns `false`, e.g. `@psalm-assert-if-false`.
+    IfFalse,
+}
+
+/// Represents an assertion made by a function or method about one of its parameters.
+///
+/// Assertions are declared using the `@psalm-assert` and `@phpstan-assert` docblock tags, and their
+/// `-if-true` and `-if-false` variants.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct AssertionReflection {
+    /// The condition under which the assertion holds.
+    pub kind: AssertionKind,
+
+    /// The name of the asserted parameter, including the `$` prefix.
+    pub parameter: StringIdentifier,
+
+    /// The asserted type, as written in the docblock.
+    pub r#type: StringIdentifier,
+
+    /// The span of the docblock tag declaring the assertion.
+    pub span: Span,
+}
+
+impl HasSpan for AssertionReflection {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
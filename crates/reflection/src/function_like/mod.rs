@@ -10,11 +10,13 @@ use mago_span::Span;
 use crate::Reflection;
 use crate::attribute::AttributeReflection;
 use crate::class_like::member::ClassLikeMemberVisibilityReflection;
+use crate::function_like::assertion::AssertionReflection;
 use crate::function_like::parameter::FunctionLikeParameterReflection;
 use crate::function_like::r#return::FunctionLikeReturnTypeReflection;
 use crate::identifier::FunctionLikeName;
 use crate::r#type::kind::Template;
 
+pub mod assertion;
 pub mod parameter;
 pub mod r#return;
 
@@ -72,8 +74,13 @@ pub struct FunctionLikeReflection {
     pub is_abstract: bool,
 
     /// Indicates if this function or method is pure.
+    ///
+    /// Purity is declared using the `@pure` docblock tag, or its `@psalm-pure` and `@phpstan-pure` variants.
     pub is_pure: bool,
 
+    /// The assertions made by this function or method about its parameters.
+    pub assertions: Vec<AssertionReflection>,
+
     /// Flags if this method overrides a method from a parent class.
     ///
     /// Always `false` for functions, arrow functions, and closures. For methods,
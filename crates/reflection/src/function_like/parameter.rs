@@ -20,6 +20,9 @@ pub struct FunctionLikeParameterReflection {
     /// The type of the parameter, if specified.
     pub type_reflection: Option<TypeReflection>,
 
+    /// The type of the parameter declared in the docblock, e.g. using `@param`, if any.
+    pub docblock_type_reflection: Option<TypeReflection>,
+
     /// The name identifier of the parameter.
     pub name: StringIdentifier,
 
@@ -398,6 +398,20 @@ pub enum TypeKind {
 }
 
 impl Template {
+    pub fn new(name: StringIdentifier, constraints: Vec<TypeKind>) -> Self {
+        Self { name, constraints }
+    }
+
+    /// Returns the name of the template parameter.
+    pub fn get_name(&self) -> &StringIdentifier {
+        &self.name
+    }
+
+    /// Returns the type constraints that the template parameter must satisfy.
+    pub fn get_constraints(&self) -> &[TypeKind] {
+        &self.constraints
+    }
+
     pub fn get_key(&self, interner: &ThreadedInterner) -> String {
         let mut key = String::from(interner.lookup(&self.name));
 
@@ -419,6 +433,48 @@ impl ArrayShapePropertyKey {
     }
 }
 
+impl ArrayShape {
+    /// Returns `true` if the shape does not allow properties other than the declared ones.
+    #[inline]
+    pub fn is_sealed(&self) -> bool {
+        self.additional_properties.is_none()
+    }
+
+    /// Returns the properties of the shape, along with their keys, as they would be assigned by PHP.
+    ///
+    /// Properties without a key are assigned the next integer key, and string keys containing a
+    /// decimal integer, e.g. `'1'`, are converted to integer keys.
+    pub fn get_keyed_properties(
+        &self,
+        interner: &ThreadedInterner,
+    ) -> Vec<(ArrayShapePropertyKey, &ArrayShapeProperty)> {
+        let mut next_index = 0;
+        let mut properties = vec![];
+        for property in self.properties.iter() {
+            let key = match &property.key {
+                Some(ArrayShapePropertyKey::String(string)) => {
+                    let value = interner.lookup(string);
+
+                    match value.parse::<isize>() {
+                        Ok(integer) if integer.to_string() == value => ArrayShapePropertyKey::Integer(integer),
+                        _ => ArrayShapePropertyKey::String(*string),
+                    }
+                }
+                Some(ArrayShapePropertyKey::Integer(integer)) => ArrayShapePropertyKey::Integer(*integer),
+                None => ArrayShapePropertyKey::Integer(next_index),
+            };
+
+            if let ArrayShapePropertyKey::Integer(integer) = key {
+                next_index = next_index.max(integer + 1);
+            }
+
+            properties.push((key, property));
+        }
+
+        properties
+    }
+}
+
 impl TypeKind {
     #[inline]
     pub fn is_nullable(&self) -> Trinary {
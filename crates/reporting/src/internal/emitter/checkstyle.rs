@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+
 use termcolor::WriteColor;
 
 use mago_interner::ThreadedInterner;
@@ -19,8 +20,8 @@ pub fn checkstyle_format(
 ) -> Result<Option<Level>, ReportingError> {
     let highest_level = issues.get_highest_level();
 
-    // Group issues by file
-    let mut issues_by_file: HashMap<String, Vec<String>> = HashMap::new();
+    // Group issues by file, sorted by name
+    let mut issues_by_file: BTreeMap<String, Vec<String>> = BTreeMap::new();
 
     for issue in issues.iter() {
         let (filename, line, column) = match issue.annotations.iter().find(|annotation| annotation.is_primary()) {
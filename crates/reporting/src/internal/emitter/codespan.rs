@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 use std::ops::Range;
+use std::sync::Arc;
 
 use codespan_reporting::diagnostic::Diagnostic;
 use codespan_reporting::diagnostic::Label;
@@ -12,6 +13,9 @@ use codespan_reporting::term::Config;
 use codespan_reporting::term::DisplayStyle;
 use termcolor::WriteColor;
 
+use mago_fixer::FixOperation;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
 use mago_interner::ThreadedInterner;
 use mago_source::SourceIdentifier;
 use mago_source::SourceManager;
@@ -24,6 +28,12 @@ use crate::IssueCollection;
 use crate::Level;
 use crate::error::ReportingError;
 
+/// The maximum number of suggested edits described for a single issue.
+const MAXIMUM_DESCRIBED_OPERATIONS: usize = 3;
+
+/// The maximum number of characters of code displayed when describing a suggested edit.
+const MAXIMUM_DESCRIBED_CODE_LENGTH: usize = 60;
+
 pub fn rich_format(
     writer: &mut dyn WriteColor,
     sources: &SourceManager,
@@ -105,7 +115,12 @@ fn codespan_format_with_config(
             suggestions += 1;
         }
 
-        let diagnostic: Diagnostic<SourceIdentifier> = issue.into();
+        let suggestion_notes = describe_suggestions(sources, &issue.suggestions)?;
+        let mut diagnostic: Diagnostic<SourceIdentifier> = issue.into();
+        diagnostic.notes.extend(suggestion_notes);
+
+        // Related spans may point to sources that are not available, such as sources of a previous run.
+        diagnostic.labels.retain(|label| sources.contains(&label.file_id));
 
         term::emit(writer, &config, &files, &diagnostic)?;
     }
@@ -145,12 +160,72 @@ fn codespan_format_with_config(
     Ok(highest_level)
 }
 
+/// Describes the edits of the given suggestions, e.g. "replace `a` with `b`", so they can be reviewed
+/// before applying them with `mago fix`.
+fn describe_suggestions(
+    sources: &SourceManager,
+    suggestions: &[(SourceIdentifier, FixPlan)],
+) -> Result<Vec<String>, ReportingError> {
+    let mut notes = vec![];
+    let operations =
+        suggestions.iter().flat_map(|(source, plan)| plan.get_operations().iter().map(move |o| (source, o)));
+    for (index, (source, operation)) in operations.enumerate() {
+        if index == MAXIMUM_DESCRIBED_OPERATIONS {
+            let remaining = suggestions.iter().map(|(_, plan)| plan.len()).sum::<usize>() - index;
+            notes.push(format!(
+                "Suggestion: ... and {} more edit(s), run `mago fix --dry-run` to preview them.",
+                remaining
+            ));
+
+            break;
+        }
+
+        let content = sources.load(source)?.content;
+        let original = |range: &Range<usize>| content.get(range.clone()).map(shorten).unwrap_or_default();
+
+        let (description, safety) = match operation {
+            FixOperation::Insert { text, safety_classification, .. } => {
+                (format!("insert `{}`", shorten(text)), safety_classification)
+            }
+            FixOperation::Replace { range, text, safety_classification } if text.trim().is_empty() => {
+                (format!("remove `{}`", original(range)), safety_classification)
+            }
+            FixOperation::Replace { range, text, safety_classification } => {
+                (format!("replace `{}` with `{}`", original(range), shorten(text)), safety_classification)
+            }
+            FixOperation::Delete { range, safety_classification } => {
+                (format!("remove `{}`", original(range)), safety_classification)
+            }
+        };
+
+        let safety = match safety {
+            SafetyClassification::Safe => "safe",
+            SafetyClassification::PotentiallyUnsafe => "potentially unsafe",
+            SafetyClassification::Unsafe => "unsafe",
+        };
+
+        notes.push(format!("Suggestion ({}): {}", safety, description));
+    }
+
+    Ok(notes)
+}
+
+/// Collapses whitespace, and truncates the given code so it fits in a single line.
+fn shorten(code: &str) -> String {
+    let code = code.split_whitespace().collect::<Vec<_>>().join(" ");
+    if code.chars().count() <= MAXIMUM_DESCRIBED_CODE_LENGTH {
+        return code;
+    }
+
+    format!("{}...", code.chars().take(MAXIMUM_DESCRIBED_CODE_LENGTH).collect::<String>())
+}
+
 struct SourceManagerFile<'a>(&'a SourceManager, &'a ThreadedInterner);
 
 impl<'a> Files<'a> for SourceManagerFile<'_> {
     type FileId = SourceIdentifier;
     type Name = &'a str;
-    type Source = &'a str;
+    type Source = Arc<str>;
 
     fn name(&'a self, file_id: SourceIdentifier) -> Result<&'a str, Error> {
         self.0.load(&file_id).map(|source| self.1.lookup(&source.identifier.value())).map_err(|e| match e {
@@ -159,8 +234,8 @@ impl<'a> Files<'a> for SourceManagerFile<'_> {
         })
     }
 
-    fn source(&'a self, file_id: SourceIdentifier) -> Result<&'a str, Error> {
-        self.0.load(&file_id).map(|source| self.1.lookup(&source.content)).map_err(|e| match e {
+    fn source(&'a self, file_id: SourceIdentifier) -> Result<Arc<str>, Error> {
+        self.0.load(&file_id).map(|source| source.content).map_err(|e| match e {
             SourceError::UnavailableSource(_) => Error::FileMissing,
             SourceError::IOError(error) => Error::Io(error),
         })
@@ -245,7 +320,7 @@ impl From<Issue> for Diagnostic<SourceIdentifier> {
         }
 
         for note in issue.notes {
-            diagnostic.notes.push(note);
+            diagnostic.notes.push(format!("Note: {}", note));
         }
 
         if let Some(help) = issue.help {
@@ -259,3 +334,70 @@ impl From<Issue> for Diagnostic<SourceIdentifier> {
         diagnostic
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use termcolor::NoColor;
+
+    use mago_source::SourceCategory;
+    use mago_span::Position;
+    use mago_span::Span;
+
+    use super::*;
+
+    #[test]
+    fn test_rich_format_renders_related_spans() {
+        let interner = ThreadedInterner::new();
+        let manager = SourceManager::new(interner.clone());
+        let declaration = manager.insert_content("a.php", "<?php\n\ninterface Foo {}\n", SourceCategory::UserDefined);
+        let usage = manager.insert_content("b.php", "<?php\n\nnew Foo();\n", SourceCategory::UserDefined);
+
+        let span = |source, start, end| Span::new(Position::new(source, start), Position::new(source, end));
+        let issue = Issue::error("Cannot instantiate interface `Foo`.")
+            .with_code("analysis/instantiation")
+            .with_annotation(Annotation::primary(span(usage, 11, 14)).with_message("Used here."))
+            .with_annotation(Annotation::secondary(span(declaration, 17, 20)).with_message("Declared here."))
+            .with_note("Interfaces cannot be instantiated.")
+            .with_help("Instantiate a class implementing `Foo` instead.");
+
+        let mut writer = NoColor::new(Vec::new());
+        rich_format(&mut writer, &manager, &interner, IssueCollection::from(vec![issue])).unwrap();
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+
+        assert!(output.contains("error[analysis/instantiation]: Cannot instantiate interface `Foo`."));
+        assert!(output.contains("b.php:3:5"));
+        assert!(output.contains("a.php:3:11"));
+        assert!(output.contains("Used here."));
+        assert!(output.contains("Declared here."));
+        assert!(output.contains("Note: Interfaces cannot be instantiated."));
+        assert!(output.contains("Help: Instantiate a class implementing `Foo` instead."));
+    }
+
+    #[test]
+    fn test_rich_format_describes_suggestions() {
+        let interner = ThreadedInterner::new();
+        let manager = SourceManager::new(interner.clone());
+        let source = manager.insert_content("a.php", "<?php\n\nvar_dump(sizeof($a));\n", SourceCategory::UserDefined);
+
+        let mut plan = FixPlan::new();
+        plan.replace(16..22, "count", SafetyClassification::Safe);
+        plan.delete(7..16, SafetyClassification::Unsafe);
+
+        let issue = Issue::warning("Function alias `sizeof` used.")
+            .with_annotation(Annotation::primary(Span::new(Position::new(source, 16), Position::new(source, 22))))
+            .with_suggestion(source, plan);
+
+        let mut writer = NoColor::new(Vec::new());
+        rich_format(&mut writer, &manager, &interner, IssueCollection::from(vec![issue])).unwrap();
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+
+        assert!(output.contains("Suggestion (safe): replace `sizeof` with `count`"));
+        assert!(output.contains("Suggestion (unsafe): remove `var_dump(`"));
+    }
+
+    #[test]
+    fn test_shorten() {
+        assert_eq!(shorten("#[\\Override]\n    "), "#[\\Override]");
+        assert_eq!(shorten(&"a".repeat(80)), format!("{}...", "a".repeat(MAXIMUM_DESCRIBED_CODE_LENGTH)));
+    }
+}
@@ -0,0 +1,438 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use termcolor::WriteColor;
+
+use mago_interner::ThreadedInterner;
+use mago_source::HasSource;
+use mago_source::Source;
+use mago_source::SourceIdentifier;
+use mago_source::SourceManager;
+
+use crate::Issue;
+use crate::IssueCollection;
+use crate::Level;
+use crate::error::ReportingError;
+use crate::internal::emitter::utils::xml_encode;
+
+/// The number of lines displayed before and after the highlighted lines of an excerpt.
+const EXCERPT_CONTEXT_LINES: usize = 2;
+
+const PHP_KEYWORDS: [&str; 72] = [
+    "abstract",
+    "and",
+    "array",
+    "as",
+    "break",
+    "callable",
+    "case",
+    "catch",
+    "class",
+    "clone",
+    "const",
+    "continue",
+    "declare",
+    "default",
+    "do",
+    "echo",
+    "else",
+    "elseif",
+    "empty",
+    "enddeclare",
+    "endfor",
+    "endforeach",
+    "endif",
+    "endswitch",
+    "endwhile",
+    "enum",
+    "eval",
+    "exit",
+    "die",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "fn",
+    "for",
+    "foreach",
+    "function",
+    "global",
+    "goto",
+    "if",
+    "implements",
+    "include",
+    "include_once",
+    "instanceof",
+    "insteadof",
+    "interface",
+    "isset",
+    "list",
+    "match",
+    "namespace",
+    "new",
+    "null",
+    "or",
+    "print",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "require",
+    "require_once",
+    "return",
+    "self",
+    "static",
+    "switch",
+    "throw",
+    "trait",
+    "true",
+    "try",
+    "unset",
+    "use",
+    "while",
+    "yield",
+];
+
+const STYLE: &str = r#"
+:root { --error: #d73a49; --warning: #b08800; --help: #22863a; --note: #0366d6; --border: #e1e4e8; }
+* { box-sizing: border-box; }
+body { margin: 0; padding: 2rem; font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; color: #24292e; background: #f6f8fa; }
+h1, h2, h3 { margin: 0 0 1rem; }
+section { background: #fff; border: 1px solid var(--border); border-radius: 6px; padding: 1.5rem; margin-bottom: 1.5rem; }
+.totals { display: flex; flex-wrap: wrap; gap: 1rem; }
+.total { flex: 1; min-width: 8rem; border: 1px solid var(--border); border-radius: 6px; padding: 1rem; text-align: center; }
+.total strong { display: block; font-size: 2rem; }
+table { width: 100%; border-collapse: collapse; }
+th, td { text-align: left; padding: 0.5rem; border-bottom: 1px solid var(--border); }
+th { cursor: pointer; user-select: none; }
+th::after { content: " \2195"; color: #959da5; }
+.level { font-weight: 600; text-transform: uppercase; font-size: 0.75rem; }
+.level-error { color: var(--error); }
+.level-warning { color: var(--warning); }
+.level-help { color: var(--help); }
+.level-note { color: var(--note); }
+details { border-top: 1px solid var(--border); padding: 0.75rem 0; }
+summary { cursor: pointer; font-weight: 600; }
+.issue { margin: 1rem 0 0 1rem; }
+.issue p { margin: 0.25rem 0; }
+.code { font-family: SFMono-Regular, Consolas, Menlo, monospace; font-size: 0.85rem; }
+pre { margin: 0.5rem 0; padding: 0.5rem 0; background: #fafbfc; border: 1px solid var(--border); border-radius: 6px; overflow-x: auto; }
+pre span.line { display: block; padding: 0 0.75rem; white-space: pre; }
+pre span.line.highlighted { background: #fff5b1; }
+pre span.number { display: inline-block; min-width: 3rem; color: #959da5; user-select: none; }
+.tok-keyword { color: #d73a49; }
+.tok-string { color: #032f62; }
+.tok-variable { color: #e36209; }
+.tok-comment { color: #6a737d; font-style: italic; }
+.tok-number { color: #005cc5; }
+"#;
+
+const SCRIPT: &str = r#"
+document.querySelectorAll("table.sortable th").forEach(function (header, index) {
+    header.addEventListener("click", function () {
+        var table = header.closest("table");
+        var body = table.tBodies[0];
+        var ascending = header.dataset.order !== "asc";
+        var rows = Array.prototype.slice.call(body.rows);
+        rows.sort(function (a, b) {
+            var x = a.cells[index].dataset.value || a.cells[index].textContent;
+            var y = b.cells[index].dataset.value || b.cells[index].textContent;
+            var result = isNaN(x) || isNaN(y) ? x.localeCompare(y) : x - y;
+            return ascending ? result : -result;
+        });
+        rows.forEach(function (row) { body.appendChild(row); });
+        table.querySelectorAll("th").forEach(function (other) { delete other.dataset.order; });
+        header.dataset.order = ascending ? "asc" : "desc";
+    });
+});
+"#;
+
+#[derive(Default)]
+struct RuleSummary {
+    level: Option<Level>,
+    count: usize,
+}
+
+pub fn html_format(
+    writer: &mut dyn WriteColor,
+    sources: &SourceManager,
+    interner: &ThreadedInterner,
+    issues: IssueCollection,
+) -> Result<Option<Level>, ReportingError> {
+    let highest_level = issues.get_highest_level();
+
+    let mut loaded_sources: HashMap<SourceIdentifier, Source> = HashMap::new();
+    let mut issues_by_file: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut rules: BTreeMap<String, RuleSummary> = BTreeMap::new();
+
+    for issue in issues.iter() {
+        let summary = rules.entry(issue.code.clone().unwrap_or_else(|| "other".to_string())).or_default();
+        summary.level = summary.level.max(Some(issue.level));
+        summary.count += 1;
+
+        let (filename, location, excerpt) = match issue.annotations.iter().find(|annotation| annotation.is_primary()) {
+            Some(annotation) => {
+                let source_identifier = annotation.span.source();
+                let source = match loaded_sources.entry(source_identifier) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(sources.load(&source_identifier)?),
+                };
+                let line = source.line_number(annotation.span.start.offset);
+                let column = source.column_number(annotation.span.start.offset);
+
+                (
+                    interner.lookup(&source.identifier.0).to_string(),
+                    format!("{}:{}", line + 1, column + 1),
+                    render_excerpt(&source.content, source, line, source.line_number(annotation.span.end.offset)),
+                )
+            }
+            None => ("<unknown>".to_string(), String::new(), String::new()),
+        };
+
+        issues_by_file.entry(filename).or_default().push(render_issue(issue, &location, &excerpt));
+    }
+
+    let count = |level: Level| issues.get_level_count(level);
+
+    writeln!(writer, "<!DOCTYPE html>")?;
+    writeln!(writer, "<html lang=\"en\">")?;
+    writeln!(writer, "<head>")?;
+    writeln!(writer, "<meta charset=\"utf-8\">")?;
+    writeln!(writer, "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">")?;
+    writeln!(writer, "<title>Mago Report</title>")?;
+    writeln!(writer, "<style>{}</style>", STYLE)?;
+    writeln!(writer, "</head>")?;
+    writeln!(writer, "<body>")?;
+    writeln!(writer, "<h1>Mago Report</h1>")?;
+
+    // Totals are also embedded as JSON, so that CI jobs can extract them to track trends over time.
+    writeln!(
+        writer,
+        "<script type=\"application/json\" id=\"totals\">{{\"total\":{},\"files\":{},\"error\":{},\"warning\":{},\"help\":{},\"note\":{}}}</script>",
+        issues.len(),
+        issues_by_file.len(),
+        count(Level::Error),
+        count(Level::Warning),
+        count(Level::Help),
+        count(Level::Note),
+    )?;
+
+    writeln!(writer, "<section>")?;
+    writeln!(writer, "<h2>Totals</h2>")?;
+    writeln!(writer, "<div class=\"totals\">")?;
+    for (label, class, value) in [
+        ("Issues", "", issues.len()),
+        ("Files", "", issues_by_file.len()),
+        ("Errors", "level-error", count(Level::Error)),
+        ("Warnings", "level-warning", count(Level::Warning)),
+        ("Help", "level-help", count(Level::Help)),
+        ("Notes", "level-note", count(Level::Note)),
+    ] {
+        writeln!(writer, "<div class=\"total {}\"><strong>{}</strong>{}</div>", class, value, label)?;
+    }
+    writeln!(writer, "</div>")?;
+    writeln!(writer, "</section>")?;
+
+    if !rules.is_empty() {
+        writeln!(writer, "<section>")?;
+        writeln!(writer, "<h2>Rules</h2>")?;
+        writeln!(writer, "<table class=\"sortable\">")?;
+        writeln!(writer, "<thead><tr><th>Rule</th><th>Level</th><th>Issues</th></tr></thead>")?;
+        writeln!(writer, "<tbody>")?;
+        for (rule, summary) in rules.iter() {
+            let level = summary.level.unwrap_or(Level::Note);
+
+            writeln!(
+                writer,
+                "<tr><td class=\"code\">{}</td><td class=\"level level-{}\" data-value=\"{}\">{}</td><td>{}</td></tr>",
+                xml_encode(rule),
+                level_name(level),
+                level as u8,
+                level_name(level),
+                summary.count
+            )?;
+        }
+        writeln!(writer, "</tbody>")?;
+        writeln!(writer, "</table>")?;
+        writeln!(writer, "</section>")?;
+    }
+
+    if !issues_by_file.is_empty() {
+        writeln!(writer, "<section>")?;
+        writeln!(writer, "<h2>Files</h2>")?;
+        for (filename, file_issues) in issues_by_file.iter() {
+            writeln!(writer, "<details>")?;
+            writeln!(
+                writer,
+                "<summary><span class=\"code\">{}</span> ({} issue(s))</summary>",
+                xml_encode(filename),
+                file_issues.len()
+            )?;
+            for issue in file_issues {
+                writeln!(writer, "{}", issue)?;
+            }
+            writeln!(writer, "</details>")?;
+        }
+        writeln!(writer, "</section>")?;
+    }
+
+    writeln!(writer, "<script>{}</script>", SCRIPT)?;
+    writeln!(writer, "</body>")?;
+    writeln!(writer, "</html>")?;
+
+    Ok(highest_level)
+}
+
+fn level_name(level: Level) -> &'static str {
+    match level {
+        Level::Error => "error",
+        Level::Warning => "warning",
+        Level::Help => "help",
+        Level::Note => "note",
+    }
+}
+
+fn render_issue(issue: &Issue, location: &str, excerpt: &str) -> String {
+    let level = level_name(issue.level);
+    let mut html = format!("<div class=\"issue\"><p><span class=\"level level-{}\">{}</span>", level, level);
+    if let Some(code) = issue.code.as_ref() {
+        html.push_str(&format!(" <span class=\"code\">[{}]</span>", xml_encode(code)));
+    }
+
+    html.push_str(&format!(" {}", xml_encode(&issue.message)));
+    if !location.is_empty() {
+        html.push_str(&format!(" <span class=\"code\">({})</span>", location));
+    }
+
+    html.push_str("</p>");
+    html.push_str(excerpt);
+
+    for note in issue.notes.iter() {
+        html.push_str(&format!("<p>{}</p>", xml_encode(note)));
+    }
+
+    if let Some(help) = issue.help.as_ref() {
+        html.push_str(&format!("<p><strong>Help:</strong> {}</p>", xml_encode(help)));
+    }
+
+    if let Some(link) = issue.link.as_ref() {
+        let link = xml_encode(link);
+
+        html.push_str(&format!("<p><a href=\"{}\">{}</a></p>", link, link));
+    }
+
+    html.push_str("</div>");
+    html
+}
+
+/// Renders the lines between `start_line` and `end_line` (inclusive, 0-based), surrounded by a few
+/// lines of context, as a syntax-highlighted code block.
+fn render_excerpt(content: &str, source: &Source, start_line: usize, end_line: usize) -> String {
+    let first_line = start_line.saturating_sub(EXCERPT_CONTEXT_LINES);
+    let last_line = end_line + EXCERPT_CONTEXT_LINES;
+
+    let mut in_comment = false;
+    let mut html = String::from("<pre class=\"code\">");
+    for line in first_line..=last_line {
+        let Some(start) = source.get_line_start_offset(line) else {
+            break;
+        };
+
+        let end = source.get_line_start_offset(line + 1).unwrap_or(content.len());
+        let text = content[start..end].trim_end_matches(['\r', '\n']);
+        let class = if line >= start_line && line <= end_line { "line highlighted" } else { "line" };
+
+        html.push_str(&format!(
+            "<span class=\"{}\"><span class=\"number\">{}</span>{}</span>",
+            class,
+            line + 1,
+            highlight_line(text, &mut in_comment)
+        ));
+    }
+
+    html.push_str("</pre>");
+    html
+}
+
+/// Highlights a single line of PHP code.
+///
+/// The highlighting is lexical only; `in_comment` carries the state of block comments across lines.
+fn highlight_line(line: &str, in_comment: &mut bool) -> String {
+    let mut html = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while !rest.is_empty() {
+        if *in_comment {
+            let (comment, remaining) = match rest.find("*/") {
+                Some(end) => {
+                    *in_comment = false;
+
+                    rest.split_at(end + 2)
+                }
+                None => (rest, ""),
+            };
+
+            push_token(&mut html, "comment", comment);
+            rest = remaining;
+            continue;
+        }
+
+        if rest.starts_with("/*") {
+            *in_comment = true;
+            push_token(&mut html, "comment", "/*");
+            rest = &rest[2..];
+            continue;
+        }
+
+        if rest.starts_with("//") || (rest.starts_with('#') && !rest.starts_with("#[")) {
+            push_token(&mut html, "comment", rest);
+            break;
+        }
+
+        let mut chars = rest.char_indices();
+        let Some((_, first)) = chars.next() else {
+            break;
+        };
+
+        let length = if first == '\'' || first == '"' {
+            let mut escaped = false;
+            let end = chars.find(|(_, c)| {
+                let is_end = !escaped && *c == first;
+                escaped = !escaped && *c == '\\';
+
+                is_end
+            });
+
+            let length = end.map_or(rest.len(), |(index, c)| index + c.len_utf8());
+            push_token(&mut html, "string", &rest[..length]);
+            length
+        } else if first == '$' || first.is_alphanumeric() || first == '_' {
+            let end = chars.find(|(_, c)| !c.is_alphanumeric() && *c != '_').map_or(rest.len(), |(index, _)| index);
+            let word = &rest[..end];
+
+            if first == '$' && end > 1 {
+                push_token(&mut html, "variable", word);
+            } else if first.is_ascii_digit() {
+                push_token(&mut html, "number", word);
+            } else if PHP_KEYWORDS.contains(&word.to_ascii_lowercase().as_str()) {
+                push_token(&mut html, "keyword", word);
+            } else {
+                html.push_str(&xml_encode(word));
+            }
+
+            end
+        } else {
+            html.push_str(&xml_encode(&rest[..first.len_utf8()]));
+            first.len_utf8()
+        };
+
+        rest = &rest[length..];
+    }
+
+    html
+}
+
+fn push_token(html: &mut String, kind: &str, text: &str) {
+    html.push_str(&format!("<span class=\"tok-{}\">{}</span>", kind, xml_encode(text)));
+}
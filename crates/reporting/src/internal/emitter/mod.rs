@@ -16,6 +16,7 @@ pub mod count;
 pub mod emacs;
 pub mod github;
 pub mod gitlab;
+pub mod html;
 pub mod json;
 
 pub trait Emitter {
@@ -66,6 +67,7 @@ impl Emitter for ReportingFormat {
             ReportingFormat::Count => count::count_format.emit(writer, sources, interner, issues),
             ReportingFormat::Checkstyle => checkstyle::checkstyle_format.emit(writer, sources, interner, issues),
             ReportingFormat::Emacs => emacs::emacs_format.emit(writer, sources, interner, issues),
+            ReportingFormat::Html => html::html_format.emit(writer, sources, interner, issues),
         }
     }
 }
@@ -62,15 +62,15 @@ pub struct ExpandedIssue {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
     pub message: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub notes: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub help: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub link: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub annotations: Vec<ExpandedAnnotation>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub suggestions: Vec<(ExpandedSourceIdentifier, FixPlan)>,
 }
 
@@ -168,3 +168,54 @@ impl Expandable<ExpandedIssueCollection> for IssueCollection {
         Ok(ExpandedIssueCollection { issues: expanded_issues })
     }
 }
+
+impl ExpandedSourceIdentifier {
+    /// Adds the source to the given manager, returning its identifier.
+    ///
+    /// Sources located on disk are added by path, so their content is read when needed. Other sources,
+    /// such as stubs, are expected to be added to the manager beforehand, and are otherwise added with
+    /// blank content of the same size, so that the offsets of the issues remain valid.
+    fn collapse(&self, manager: &SourceManager) -> SourceIdentifier {
+        match &self.path {
+            Some(path) => manager.insert_path(&self.identifier, path.clone(), self.category),
+            None => manager.insert_content(&self.identifier, " ".repeat(self.size), self.category),
+        }
+    }
+}
+
+impl ExpandedIssueCollection {
+    /// Converts the expanded issues back into issues, adding their sources to the given manager.
+    pub fn collapse(self, manager: &SourceManager) -> IssueCollection {
+        let span = |span: ExpandedSpan| {
+            Span::new(
+                Position::new(span.start.source.collapse(manager), span.start.offset),
+                Position::new(span.end.source.collapse(manager), span.end.offset),
+            )
+        };
+
+        IssueCollection::from(self.issues.into_iter().map(|issue| {
+            Issue {
+                level: issue.level,
+                code: issue.code,
+                message: issue.message,
+                notes: issue.notes,
+                help: issue.help,
+                link: issue.link,
+                annotations: issue
+                    .annotations
+                    .into_iter()
+                    .map(|annotation| Annotation {
+                        message: annotation.message,
+                        kind: annotation.kind,
+                        span: span(annotation.span),
+                    })
+                    .collect(),
+                suggestions: issue
+                    .suggestions
+                    .into_iter()
+                    .map(|(source, plan)| (source.collapse(manager), plan))
+                    .collect(),
+            }
+        }))
+    }
+}
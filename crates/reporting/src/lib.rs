@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use std::collections::VecDeque;
 use std::collections::hash_map::Entry;
 use std::iter::Once;
 
@@ -8,9 +8,14 @@ use serde::Serialize;
 use strum::Display;
 
 use mago_fixer::FixPlan;
+use mago_interner::ThreadedInterner;
 use mago_source::SourceIdentifier;
+use mago_source::SourceManager;
 use mago_span::Span;
 
+use crate::error::ReportingError;
+use crate::internal::ExpandedIssueCollection;
+
 mod internal;
 
 pub mod error;
@@ -76,6 +81,17 @@ pub struct IssueCollection {
     issues: Vec<Issue>,
 }
 
+/// The differences between the issues of two runs, as computed by [`IssueCollection::diff`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct IssueDiff {
+    /// The issues only reported by the new run.
+    pub new: IssueCollection,
+    /// The issues only reported by the old run.
+    pub fixed: IssueCollection,
+    /// The issues reported by both runs, as reported by the new run.
+    pub unchanged: IssueCollection,
+}
+
 impl Annotation {
     /// Creates a new annotation with the given kind and span.
     ///
@@ -388,6 +404,16 @@ impl IssueCollection {
         Self { issues: issues.into_iter().collect() }
     }
 
+    /// Reads the issues of a report written in the `json` format, e.g. to merge the reports of several runs.
+    ///
+    /// The sources the issues are located in are added to the given manager. Sources located on disk are
+    /// added by path, so they must still exist for the issues to be reported again.
+    pub fn from_json_report(json: &str, manager: &SourceManager) -> Result<Self, ReportingError> {
+        let report: ExpandedIssueCollection = serde_json::from_str(json)?;
+
+        Ok(report.collapse(manager))
+    }
+
     pub fn push(&mut self, issue: Issue) {
         self.issues.push(issue);
     }
@@ -450,45 +476,104 @@ impl IssueCollection {
         self.issues.into_iter().filter(|issue| !issue.suggestions.is_empty())
     }
 
-    /// Sorts the issues in the collection.
+    /// Sorts the issues in the collection by severity level, then by code.
     ///
-    /// The issues are sorted by severity level in descending order,
-    /// then by code in ascending order, and finally by the primary annotation span.
+    /// The sort is stable, so issues with the same level and code keep their relative order,
+    /// e.g. the order established by [`IssueCollection::sorted_by_location`].
     pub fn sorted(self) -> Self {
         let mut issues = self.issues;
 
-        issues.sort_by(|a, b| match a.level.cmp(&b.level) {
-            Ordering::Greater => Ordering::Greater,
-            Ordering::Less => Ordering::Less,
-            Ordering::Equal => match a.code.as_deref().cmp(&b.code.as_deref()) {
-                Ordering::Less => Ordering::Less,
-                Ordering::Greater => Ordering::Greater,
-                Ordering::Equal => {
-                    let a_span = a
-                        .annotations
-                        .iter()
-                        .find(|annotation| annotation.is_primary())
-                        .map(|annotation| annotation.span);
-
-                    let b_span = b
-                        .annotations
-                        .iter()
-                        .find(|annotation| annotation.is_primary())
-                        .map(|annotation| annotation.span);
-
-                    match (a_span, b_span) {
-                        (Some(a_span), Some(b_span)) => a_span.cmp(&b_span),
-                        (Some(_), None) => Ordering::Less,
-                        (None, Some(_)) => Ordering::Greater,
-                        (None, None) => Ordering::Equal,
-                    }
-                }
-            },
+        issues.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.code.as_deref().cmp(&b.code.as_deref())));
+
+        Self { issues }
+    }
+
+    /// Sorts the issues in the collection by location.
+    ///
+    /// The issues are sorted by the name of the file of their primary annotation, then by the span of the
+    /// annotation, and finally by code, level, and message. Issues without a primary annotation come first.
+    ///
+    /// Unlike the order in which issues are collected, which depends on the order in which parallel tasks
+    /// complete, the resulting order only depends on the issues themselves, so that reports are identical
+    /// across runs.
+    pub fn sorted_by_location(self, interner: &ThreadedInterner) -> Self {
+        let mut issues = self.issues;
+
+        issues.sort_by(|a, b| {
+            let location = |issue: &Issue| {
+                issue.annotations.iter().find(|annotation| annotation.is_primary()).map(|annotation| {
+                    (
+                        interner.lookup(&annotation.span.start.source.0),
+                        annotation.span.start.offset,
+                        annotation.span.end.offset,
+                    )
+                })
+            };
+
+            location(a)
+                .cmp(&location(b))
+                .then_with(|| a.code.as_deref().cmp(&b.code.as_deref()))
+                .then_with(|| a.level.cmp(&b.level))
+                .then_with(|| a.message.cmp(&b.message))
         });
 
         Self { issues }
     }
 
+    /// Computes the issues introduced and fixed between two runs.
+    ///
+    /// Issues are matched by the file of their primary annotation, level, code, and message, but not by
+    /// their exact location, so that an issue is not considered new only because the code above it changed.
+    /// When a file has several identical issues, they are matched in order of location, and only the
+    /// additional ones are considered new or fixed.
+    ///
+    /// The sources of both collections must be identified by the same manager, e.g. by reading both
+    /// reports using [`IssueCollection::from_json_report`] with the same manager.
+    pub fn diff(old: IssueCollection, new: IssueCollection) -> IssueDiff {
+        type Fingerprint = (Option<SourceIdentifier>, Level, Option<String>, String);
+
+        let fingerprint = |issue: &Issue| -> Fingerprint {
+            let source = issue.annotations.iter().find(|annotation| annotation.is_primary());
+
+            (
+                source.map(|annotation| annotation.span.start.source),
+                issue.level,
+                issue.code.clone(),
+                issue.message.clone(),
+            )
+        };
+
+        let offset = |issue: &Issue| {
+            issue
+                .annotations
+                .iter()
+                .find(|annotation| annotation.is_primary())
+                .map(|annotation| (annotation.span.start.offset, annotation.span.end.offset))
+        };
+
+        let mut old = old.issues;
+        old.sort_by_key(offset);
+        let mut new = new.issues;
+        new.sort_by_key(offset);
+
+        let mut remaining: HashMap<Fingerprint, VecDeque<Issue>> = HashMap::default();
+        for issue in old {
+            remaining.entry(fingerprint(&issue)).or_default().push_back(issue);
+        }
+
+        let mut diff = IssueDiff::default();
+        for issue in new {
+            match remaining.get_mut(&fingerprint(&issue)).and_then(|issues| issues.pop_front()) {
+                Some(_) => diff.unchanged.push(issue),
+                None => diff.new.push(issue),
+            }
+        }
+
+        diff.fixed.extend(remaining.into_values().flatten());
+
+        diff
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &Issue> {
         self.issues.iter()
     }
@@ -545,6 +630,13 @@ impl FromIterator<Issue> for IssueCollection {
 
 #[cfg(test)]
 mod tests {
+    use termcolor::NoColor;
+
+    use mago_source::SourceCategory;
+    use mago_span::Position;
+
+    use crate::internal::emitter::json::json_format;
+
     use super::*;
 
     #[test]
@@ -679,4 +771,63 @@ mod tests {
         assert_eq!(collection.get_level_count(Level::Help), 1);
         assert_eq!(collection.get_level_count(Level::Note), 1);
     }
+
+    #[test]
+    pub fn test_json_report_round_trip() {
+        let content = "<?php\n\necho $a;\n";
+        let interner = ThreadedInterner::new();
+        let manager = SourceManager::new(interner.clone());
+        let source = manager.insert_content("a.php", content, SourceCategory::UserDefined);
+        let issue = Issue::warning("Use of `echo`.")
+            .with_code("strictness/no-echo")
+            .with_annotation(Annotation::primary(Span::new(Position::new(source, 7), Position::new(source, 15))));
+
+        let mut writer = NoColor::new(Vec::new());
+        json_format(&mut writer, &manager, &interner, IssueCollection::from(vec![issue])).unwrap();
+        let report = writer.into_inner();
+
+        // Read the report in another process, as when merging the reports of several runs.
+        let other_interner = ThreadedInterner::new();
+        let other_manager = SourceManager::new(other_interner.clone());
+        other_interner.intern("unrelated");
+        other_manager.insert_content("a.php", content, SourceCategory::UserDefined);
+
+        let issues = IssueCollection::from_json_report(std::str::from_utf8(&report).unwrap(), &other_manager).unwrap();
+        assert_eq!(issues.len(), 1);
+
+        let mut writer = NoColor::new(Vec::new());
+        json_format(&mut writer, &other_manager, &other_interner, issues).unwrap();
+        assert_eq!(writer.into_inner(), report);
+    }
+
+    #[test]
+    fn test_issue_diff() {
+        let interner = ThreadedInterner::new();
+        let manager = SourceManager::new(interner.clone());
+        let a = manager.insert_content("a.php", "<?php\n\necho $a;\necho $a;\necho $b;\n", SourceCategory::UserDefined);
+        let b = manager.insert_content("b.php", "<?php\n\necho $b;\n", SourceCategory::UserDefined);
+
+        let issue = |message: &str, source, start| {
+            Issue::warning(message).with_code("strictness/no-echo").with_annotation(Annotation::primary(Span::new(
+                Position::new(source, start),
+                Position::new(source, start + 8),
+            )))
+        };
+
+        let old = IssueCollection::from(vec![issue("echo $a", a, 7), issue("echo $b", a, 25), issue("echo $b", b, 7)]);
+        // Two lines were added above the existing issues, and a second `echo $a` was added.
+        let new = IssueCollection::from(vec![issue("echo $a", a, 9), issue("echo $a", a, 18), issue("echo $b", a, 27)]);
+
+        let diff = IssueCollection::diff(old, new);
+        let messages = |issues: &IssueCollection| {
+            issues.iter().map(|issue| (issue.message.clone(), issue.annotations[0].span.start)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(messages(&diff.new), [("echo $a".to_string(), Position::new(a, 18))]);
+        assert_eq!(messages(&diff.fixed), [("echo $b".to_string(), Position::new(b, 7))]);
+        assert_eq!(
+            messages(&diff.unchanged),
+            [("echo $a".to_string(), Position::new(a, 9)), ("echo $b".to_string(), Position::new(a, 27))]
+        );
+    }
 }
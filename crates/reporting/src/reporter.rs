@@ -13,6 +13,7 @@ use crate::IssueCollection;
 use crate::Level;
 use crate::error::ReportingError;
 use crate::internal::emitter::Emitter;
+use crate::internal::writer::ColorChoice;
 use crate::internal::writer::ReportWriter;
 
 /// Defines the output target for the `ReportWriter`.
@@ -42,6 +43,7 @@ pub enum ReportingFormat {
     Count,
     Checkstyle,
     Emacs,
+    Html,
 }
 
 #[derive(Clone)]
@@ -50,19 +52,44 @@ pub struct Reporter {
     manager: SourceManager,
     target: ReportingTarget,
     writer: ReportWriter,
+    sort_by_level: bool,
 }
 
 impl Reporter {
     pub fn new(interner: ThreadedInterner, manager: SourceManager, target: ReportingTarget) -> Self {
-        Self { interner, manager, target, writer: ReportWriter::new(target) }
+        Self { interner, manager, target, writer: ReportWriter::new(target, ColorChoice::Auto), sort_by_level: false }
     }
 
+    /// Sets whether the reports should be colored, instead of detecting it from the environment.
+    pub fn with_colors(mut self, enabled: bool) -> Self {
+        self.writer = ReportWriter::new(self.target, if enabled { ColorChoice::Always } else { ColorChoice::Never });
+
+        self
+    }
+
+    /// Sets whether the reported issues should be grouped by level and code, instead of only sorted by location.
+    pub fn with_level_sorting(mut self, enabled: bool) -> Self {
+        self.sort_by_level = enabled;
+
+        self
+    }
+
+    /// Reports the given issues in the given format.
+    ///
+    /// The issues are always reported in the same order, regardless of the order in which they were
+    /// collected, so that reports of the same issues are identical across runs.
     pub fn report(
         &self,
         issues: impl IntoIterator<Item = Issue>,
         format: ReportingFormat,
     ) -> Result<Option<Level>, ReportingError> {
-        format.emit(&mut self.writer.lock(), &self.manager, &self.interner, IssueCollection::from(issues))
+        format.emit(&mut self.writer.lock(), &self.manager, &self.interner, self.sort(issues))
+    }
+
+    fn sort(&self, issues: impl IntoIterator<Item = Issue>) -> IssueCollection {
+        let issues = IssueCollection::from(issues).sorted_by_location(&self.interner);
+
+        if self.sort_by_level { issues.sorted() } else { issues }
     }
 }
 
@@ -75,6 +102,7 @@ impl std::fmt::Debug for Reporter {
             .field("interner", &self.interner)
             .field("manager", &self.manager)
             .field("target", &self.target)
+            .field("sort_by_level", &self.sort_by_level)
             .finish_non_exhaustive()
     }
 }
@@ -105,7 +133,93 @@ impl FromStr for ReportingFormat {
             "count" => Ok(Self::Count),
             "checkstyle" => Ok(Self::Checkstyle),
             "emacs" => Ok(Self::Emacs),
+            "html" => Ok(Self::Html),
             _ => Err(ReportingError::InvalidFormat(s.to_string())),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use termcolor::NoColor;
+
+    use mago_source::SourceCategory;
+    use mago_span::Position;
+    use mago_span::Span;
+
+    use crate::Annotation;
+    use crate::internal::emitter::Emitter;
+
+    use super::*;
+
+    #[test]
+    fn test_reports_are_identical_regardless_of_collection_order() {
+        let interner = ThreadedInterner::new();
+        let manager = SourceManager::new(interner.clone());
+        // Insert the sources out of order, so that their identifiers are not sorted by name.
+        let b = manager.insert_content("b.php", "<?php\n\necho $b;\necho $c;\n", SourceCategory::UserDefined);
+        let a = manager.insert_content("a.php", "<?php\n\necho $a;\n", SourceCategory::UserDefined);
+
+        let issue = |level, code: &str, source, start, end| {
+            Issue::new(level, "Use of `echo`.").with_code(code).with_annotation(Annotation::primary(Span::new(
+                Position::new(source, start),
+                Position::new(source, end),
+            )))
+        };
+
+        let issues = vec![
+            issue(Level::Warning, "strictness/no-echo", b, 7, 15),
+            issue(Level::Error, "safety/no-echo", b, 7, 15),
+            issue(Level::Warning, "strictness/no-echo", a, 7, 15),
+            issue(Level::Note, "strictness/no-echo", b, 16, 24),
+            Issue::help("Issue without a location."),
+        ];
+
+        for format in ReportingFormat::VARIANTS.iter().map(|format| format.parse::<ReportingFormat>().unwrap()) {
+            for sort_by_level in [false, true] {
+                let reporter = Reporter::new(interner.clone(), manager.clone(), ReportingTarget::Stdout)
+                    .with_level_sorting(sort_by_level);
+
+                let emit = |issues: Vec<Issue>| {
+                    let mut writer = NoColor::new(Vec::new());
+                    format.emit(&mut writer, &manager, &interner, reporter.sort(issues)).unwrap();
+
+                    writer.into_inner()
+                };
+
+                let expected = emit(issues.clone());
+                let mut shuffled = issues.clone();
+                for _ in 0..shuffled.len() {
+                    shuffled.rotate_left(1);
+                    assert_eq!(emit(shuffled.clone()), expected, "`{}` reports differ", format);
+
+                    shuffled.reverse();
+                    assert_eq!(emit(shuffled.clone()), expected, "`{}` reports differ", format);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_issues_are_sorted_by_file_then_span() {
+        let interner = ThreadedInterner::new();
+        let manager = SourceManager::new(interner.clone());
+        let b = manager.insert_content("b.php", "<?php\n\necho $b;\n", SourceCategory::UserDefined);
+        let a = manager.insert_content("a.php", "<?php\n\necho $a;\n", SourceCategory::UserDefined);
+
+        let span = |source, start, end| Span::new(Position::new(source, start), Position::new(source, end));
+        let issues = vec![
+            Issue::error("b").with_annotation(Annotation::primary(span(b, 7, 15))),
+            Issue::note("a, second").with_annotation(Annotation::primary(span(a, 12, 14))),
+            Issue::warning("a, first").with_annotation(Annotation::primary(span(a, 7, 15))),
+        ];
+
+        let reporter = Reporter::new(interner.clone(), manager.clone(), ReportingTarget::Stdout);
+        let messages: Vec<_> = reporter.sort(issues.clone()).iter().map(|issue| issue.message.clone()).collect();
+        assert_eq!(messages, ["a, first", "a, second", "b"]);
+
+        let reporter = reporter.with_level_sorting(true);
+        let messages: Vec<_> = reporter.sort(issues).iter().map(|issue| issue.message.clone()).collect();
+        assert_eq!(messages, ["a, second", "a, first", "b"]);
+    }
+}
@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+
+use encoding_rs::Encoding;
+use encoding_rs::UTF_8;
+use encoding_rs::UTF_16LE;
+use encoding_rs::WINDOWS_1252;
+use serde::Deserialize;
+use serde::Serialize;
+
+const UTF_8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// The encoding of a source file, detected when it is loaded.
+///
+/// Sources are always analyzed as UTF-8, without a byte order mark (BOM), and are written back
+/// in the encoding they were loaded from, so that files using a BOM or a legacy encoding are
+/// not converted behind the user's back.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum SourceEncoding {
+    /// UTF-8, without a byte order mark.
+    #[default]
+    Utf8,
+
+    /// UTF-8, starting with a byte order mark.
+    Utf8WithBom,
+
+    /// UTF-16 little-endian, starting with a byte order mark.
+    Utf16LeWithBom,
+
+    /// UTF-16 big-endian, starting with a byte order mark.
+    Utf16BeWithBom,
+
+    /// Windows-1252, a superset of ISO-8859-1, assumed for files that are not valid UTF-8.
+    ///
+    /// Every byte is mapped to a character, so that such files are written back unchanged,
+    /// even if they actually use another single-byte encoding.
+    Windows1252,
+}
+
+impl SourceEncoding {
+    /// Detects the encoding of the given bytes, and decodes them to UTF-8, without the byte order mark.
+    pub fn decode(bytes: Vec<u8>) -> (String, SourceEncoding) {
+        if let Some((encoding, length)) = Encoding::for_bom(&bytes) {
+            let (content, had_errors) = encoding.decode_without_bom_handling(&bytes[length..]);
+
+            if !had_errors {
+                let source_encoding = if encoding == UTF_8 {
+                    SourceEncoding::Utf8WithBom
+                } else if encoding == UTF_16LE {
+                    SourceEncoding::Utf16LeWithBom
+                } else {
+                    SourceEncoding::Utf16BeWithBom
+                };
+
+                return (content.into_owned(), source_encoding);
+            }
+        }
+
+        match String::from_utf8(bytes) {
+            Ok(content) => (content, SourceEncoding::Utf8),
+            Err(error) => {
+                let (content, _) = WINDOWS_1252.decode_without_bom_handling(error.as_bytes());
+
+                (content.into_owned(), SourceEncoding::Windows1252)
+            }
+        }
+    }
+
+    /// Encodes the given UTF-8 content, adding the byte order mark if any.
+    ///
+    /// Returns `None` if the content contains characters that cannot be represented in this encoding.
+    pub fn encode<'a>(&self, content: &'a str) -> Option<Cow<'a, [u8]>> {
+        Some(match self {
+            SourceEncoding::Utf8 => Cow::Borrowed(content.as_bytes()),
+            SourceEncoding::Utf8WithBom => Cow::Owned([UTF_8_BOM, content.as_bytes()].concat()),
+            SourceEncoding::Utf16LeWithBom => {
+                Cow::Owned([0xFF, 0xFE].into_iter().chain(content.encode_utf16().flat_map(u16::to_le_bytes)).collect())
+            }
+            SourceEncoding::Utf16BeWithBom => {
+                Cow::Owned([0xFE, 0xFF].into_iter().chain(content.encode_utf16().flat_map(u16::to_be_bytes)).collect())
+            }
+            SourceEncoding::Windows1252 => {
+                let (bytes, _, had_unmappable_characters) = WINDOWS_1252.encode(content);
+                if had_unmappable_characters {
+                    return None;
+                }
+
+                bytes
+            }
+        })
+    }
+
+    /// Returns the name of the encoding.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            SourceEncoding::Utf8 => "UTF-8",
+            SourceEncoding::Utf8WithBom => "UTF-8 with BOM",
+            SourceEncoding::Utf16LeWithBom => "UTF-16LE with BOM",
+            SourceEncoding::Utf16BeWithBom => "UTF-16BE with BOM",
+            SourceEncoding::Windows1252 => "Windows-1252",
+        }
+    }
+}
+
+impl std::fmt::Display for SourceEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf8_is_decoded_as_is() {
+        let (content, encoding) = SourceEncoding::decode("<?php echo 'é';".as_bytes().to_vec());
+
+        assert_eq!(content, "<?php echo 'é';");
+        assert_eq!(encoding, SourceEncoding::Utf8);
+    }
+
+    #[test]
+    fn test_byte_order_marks_are_stripped_and_restored() {
+        let utf8 = b"\xEF\xBB\xBF<?php echo 1;".to_vec();
+        let utf16 = b"\xFF\xFE<\0?\0p\0h\0p\0".to_vec();
+
+        for (bytes, expected_content, expected_encoding) in
+            [(utf8, "<?php echo 1;", SourceEncoding::Utf8WithBom), (utf16, "<?php", SourceEncoding::Utf16LeWithBom)]
+        {
+            let (content, encoding) = SourceEncoding::decode(bytes.clone());
+
+            assert_eq!(content, expected_content);
+            assert_eq!(encoding, expected_encoding);
+            assert_eq!(encoding.encode(&content).as_deref(), Some(bytes.as_slice()));
+        }
+    }
+
+    #[test]
+    fn test_legacy_encoding_is_transcoded_and_restored() {
+        let bytes = b"<?php echo 'caf\xE9 \x80';".to_vec();
+        let (content, encoding) = SourceEncoding::decode(bytes.clone());
+
+        assert_eq!(content, "<?php echo 'café €';");
+        assert_eq!(encoding, SourceEncoding::Windows1252);
+        assert_eq!(encoding.encode(&content).as_deref(), Some(bytes.as_slice()));
+        assert_eq!(encoding.encode("<?php echo '✓';"), None);
+    }
+}
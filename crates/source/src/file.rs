@@ -0,0 +1,90 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use tempfile::NamedTempFile;
+
+/// Writes the given content to the file at the given path, atomically.
+///
+/// The content is written to a temporary file in the same directory, which is flushed to disk, given the
+/// permissions and, on Unix, the ownership of the original file, then renamed over it. A crash while writing
+/// leaves either the original file or the new one, never a truncated file.
+///
+/// Symbolic links are followed, so that the file they point to is replaced, instead of the link itself.
+pub fn write_atomically(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let path = match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_symlink() => std::fs::canonicalize(path)?,
+        _ => path.to_path_buf(),
+    };
+
+    let directory = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let mut temporary = NamedTempFile::new_in(directory)?;
+    temporary.write_all(content)?;
+
+    if let Ok(metadata) = std::fs::metadata(&path) {
+        temporary.as_file().set_permissions(metadata.permissions())?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            // Changing the owner requires privileges, and is not needed when the file is already ours.
+            if let Err(error) =
+                std::os::unix::fs::fchown(temporary.as_file(), Some(metadata.uid()), Some(metadata.gid()))
+            {
+                tracing::trace!("Could not preserve the ownership of '{}': {}", path.display(), error);
+            }
+        }
+    }
+
+    temporary.as_file().sync_all()?;
+    temporary.persist(&path).map_err(|error| error.error)?;
+
+    // Flush the rename itself, which is recorded in the directory; not every platform allows opening one.
+    if let Ok(directory) = File::open(directory) {
+        let _ = directory.sync_all();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomically_replaces_the_content() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("index.php");
+        std::fs::write(&path, "<?php echo 1;").unwrap();
+
+        write_atomically(&path, b"<?php\n\necho 1;\n").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "<?php\n\necho 1;\n");
+        // No temporary file is left behind.
+        assert_eq!(std::fs::read_dir(directory.path()).unwrap().count(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_atomically_preserves_permissions_and_symlinks() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("bin.php");
+        let link = directory.path().join("link.php");
+        std::fs::write(&path, "<?php echo 1;").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o754)).unwrap();
+        std::os::unix::fs::symlink(&path, &link).unwrap();
+
+        write_atomically(&link, b"<?php echo 2;").unwrap();
+
+        assert!(std::fs::symlink_metadata(&link).unwrap().file_type().is_symlink());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "<?php echo 2;");
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o754);
+    }
+}
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
 
 use ahash::HashMap;
 use parking_lot::RwLock;
@@ -9,9 +10,13 @@ use serde::Serialize;
 use mago_interner::StringIdentifier;
 use mago_interner::ThreadedInterner;
 
+use crate::encoding::SourceEncoding;
 use crate::error::SourceError;
+use crate::file::write_atomically;
 
+pub mod encoding;
 pub mod error;
+pub mod file;
 
 /// Represents the category of the source for a PHP construct.
 ///
@@ -47,7 +52,7 @@ pub struct SourceIdentifier(pub StringIdentifier, pub SourceCategory);
 pub struct Source {
     pub identifier: SourceIdentifier,
     pub path: Option<PathBuf>,
-    pub content: StringIdentifier,
+    pub content: Arc<str>,
     pub size: usize,
     pub lines: Vec<usize>,
 }
@@ -63,7 +68,11 @@ struct SourceEntry {
     /// The file path (if any).
     path: Option<PathBuf>,
     /// The content, if already loaded, plus its size and line-start positions.
-    content: Option<(StringIdentifier, usize, Vec<usize>)>,
+    content: Option<(Arc<str>, usize, Vec<usize>)>,
+    /// Whether the content was updated in memory only, and differs from the file.
+    modified: bool,
+    /// The encoding of the file, which the content is transcoded from, and written back in.
+    encoding: SourceEncoding,
 }
 
 /// Internal container for our maps. We keep two maps:
@@ -81,10 +90,12 @@ struct SourceManagerInner {
 /// high-performance `RwLock` (from the parking_lot crate) and uses AHashMap for speed.
 #[derive(Clone, Debug)]
 pub struct SourceManager {
-    /// The interner used for source names and content.
+    /// The interner used for source names.
     interner: ThreadedInterner,
     /// Inner maps protected by a lock.
     inner: Arc<RwLock<SourceManagerInner>>,
+    /// Whether files are written atomically, see [`SourceManager::with_atomic_writes`].
+    atomic_writes: bool,
 }
 
 /// Methods for SourceCategory.
@@ -134,8 +145,7 @@ impl Source {
     ///
     /// # Arguments
     ///
-    /// * `interner` - A reference to a [`ThreadedInterner`] used to intern
-    ///   the `content` and store string identifiers.
+    /// * `interner` - A reference to a [`ThreadedInterner`] used to intern the `name`.
     /// * `name` - A logical identifier for this source, such as `"inline"`
     ///   or `"my_script.php"`.
     /// * `content` - The actual PHP (or other) code string.
@@ -143,12 +153,11 @@ impl Source {
     pub fn standalone(interner: &ThreadedInterner, name: &str, content: &str) -> Self {
         let lines: Vec<_> = line_starts(content).collect();
         let size = content.len();
-        let content_id = interner.intern(content);
 
         Self {
             identifier: SourceIdentifier(interner.intern(name), SourceCategory::UserDefined),
             path: None,
-            content: content_id,
+            content: Arc::from(content),
             size,
             lines,
         }
@@ -208,9 +217,28 @@ impl SourceManager {
                 sources: HashMap::default(),
                 sources_by_name: HashMap::default(),
             })),
+            atomic_writes: true,
         }
     }
 
+    /// Sets whether files are written atomically, which is the default.
+    ///
+    /// Atomic writes go through a temporary file renamed over the original one, so that a crash never leaves
+    /// a truncated file. This replaces the file, which breaks hard links, and may not be supported by some
+    /// network or virtual filesystems; disabling it writes to the file in place instead.
+    pub fn with_atomic_writes(mut self, enabled: bool) -> Self {
+        self.atomic_writes = enabled;
+        self
+    }
+
+    /// Replaces the interner used for source names, e.g. after it was reset to a snapshot.
+    ///
+    /// The interner must hold the names of every source inserted so far.
+    pub fn with_interner(mut self, interner: ThreadedInterner) -> Self {
+        self.interner = interner;
+        self
+    }
+
     /// Inserts a source with the given name and file path.
     #[inline(always)]
     pub fn insert_path(&self, name: impl AsRef<str>, path: PathBuf, category: SourceCategory) -> SourceIdentifier {
@@ -230,7 +258,10 @@ impl SourceManager {
         if inner.sources.contains_key(&source_id) {
             return source_id;
         }
-        inner.sources.insert(source_id, SourceEntry { path: Some(path), content: None });
+        inner.sources.insert(
+            source_id,
+            SourceEntry { path: Some(path), content: None, modified: false, encoding: SourceEncoding::Utf8 },
+        );
         inner.sources_by_name.insert(name_id, source_id);
         source_id
     }
@@ -256,14 +287,21 @@ impl SourceManager {
 
         let lines: Vec<_> = line_starts(content_str).collect();
         let size = content_str.len();
-        let content_id = self.interner.intern(content_str);
         let source_id = SourceIdentifier(name_id, category);
 
         let mut inner = self.inner.write();
         if let Some(&existing) = inner.sources_by_name.get(&name_id) {
             return existing;
         }
-        inner.sources.insert(source_id, SourceEntry { path: None, content: Some((content_id, size, lines)) });
+        inner.sources.insert(
+            source_id,
+            SourceEntry {
+                path: None,
+                content: Some((Arc::from(content_str), size, lines)),
+                modified: false,
+                encoding: SourceEncoding::Utf8,
+            },
+        );
         inner.sources_by_name.insert(name_id, source_id);
         source_id
     }
@@ -299,19 +337,21 @@ impl SourceManager {
     /// Loads the source for the given identifier.
     ///
     /// If the source content is already loaded, it is returned immediately.
-    /// Otherwise the file is read from disk, processed, and cached.
+    /// Otherwise the file is read from disk, transcoded to UTF-8 if needed, processed, and cached.
     #[inline(always)]
     pub fn load(&self, source_id: &SourceIdentifier) -> Result<Source, SourceError> {
         // First, try to read without locking for update.
         {
             let inner = self.inner.read();
             if let Some(entry) = inner.sources.get(source_id) {
-                if let Some((content, size, ref lines)) = entry.content {
+                if let Some((content, size, lines)) = &entry.content {
+                    tracing::trace!(file = self.interner.lookup(&source_id.0), "Loaded source content from the cache.");
+
                     return Ok(Source {
                         identifier: *source_id,
                         path: entry.path.clone(),
-                        content,
-                        size,
+                        content: content.clone(),
+                        size: *size,
                         lines: lines.clone(),
                     });
                 }
@@ -327,19 +367,23 @@ impl SourceManager {
         };
 
         // Perform file I/O outside the lock.
+        let start = Instant::now();
         let bytes = std::fs::read(&path).map_err(SourceError::IOError)?;
-        let content_str = match String::from_utf8(bytes) {
-            Ok(s) => s,
-            Err(err) => {
-                let s = err.into_bytes();
-                let s = String::from_utf8_lossy(&s).into_owned();
-                tracing::warn!("Source '{}' contains invalid UTF-8 sequence; behavior is undefined.", path.display());
-                s
-            }
-        };
+        let (content_str, encoding) = SourceEncoding::decode(bytes);
+        if encoding != SourceEncoding::Utf8 {
+            tracing::debug!("Transcoded source '{}' from {} to UTF-8.", path.display(), encoding);
+        }
+
         let lines: Vec<_> = line_starts(&content_str).collect();
         let size = content_str.len();
-        let content_id = self.interner.intern(&content_str);
+        let content: Arc<str> = Arc::from(content_str);
+
+        tracing::trace!(
+            file = self.interner.lookup(&source_id.0),
+            size,
+            duration_us = start.elapsed().as_micros() as u64,
+            "Loaded source content from disk.",
+        );
 
         // Update the entry under a write lock.
         {
@@ -347,40 +391,114 @@ impl SourceManager {
             if let Some(entry) = inner.sources.get_mut(source_id) {
                 // Check again in case another thread updated it meanwhile.
                 if entry.content.is_none() {
-                    entry.content = Some((content_id, size, lines.clone()));
+                    entry.content = Some((content.clone(), size, lines.clone()));
+                    entry.encoding = encoding;
                 }
-                Ok(Source { identifier: *source_id, path: entry.path.clone(), content: content_id, size, lines })
+                Ok(Source { identifier: *source_id, path: entry.path.clone(), content, size, lines })
             } else {
                 Err(SourceError::UnavailableSource(*source_id))
             }
         }
     }
 
+    /// Drops the cached content of the source with the given identifier.
+    ///
+    /// The content is freed once every loaded [`Source`] sharing it is dropped, and read
+    /// from disk again on the next `load`. Sources without a file are kept, as their
+    /// content could not be loaded again, and so are sources updated in memory only.
+    ///
+    /// Returns `true` if the content was dropped.
+    #[inline(always)]
+    pub fn unload(&self, source_id: &SourceIdentifier) -> bool {
+        let mut inner = self.inner.write();
+
+        match inner.sources.get_mut(source_id) {
+            Some(entry) if entry.path.is_some() && !entry.modified => entry.content.take().is_some(),
+            _ => false,
+        }
+    }
+
+    /// Updates the content of the source with the given identifier in memory, without writing it to disk.
+    ///
+    /// This is useful to process the updated content again, e.g. to preview the result of several
+    /// rounds of fixes without modifying any file.
+    ///
+    /// Returns `true` if the content changed.
+    #[inline(always)]
+    pub fn update(&self, source_id: SourceIdentifier, new_content: impl AsRef<str>) -> Result<bool, SourceError> {
+        let new_content_str = new_content.as_ref();
+
+        let mut inner = self.inner.write();
+        let entry = inner.sources.get_mut(&source_id).ok_or(SourceError::UnavailableSource(source_id))?;
+        if let Some((old_content, _, _)) = &entry.content {
+            if old_content.as_ref() == new_content_str {
+                return Ok(false);
+            }
+        }
+
+        entry.content =
+            Some((Arc::from(new_content_str), new_content_str.len(), line_starts(new_content_str).collect()));
+        entry.modified = entry.path.is_some();
+
+        Ok(true)
+    }
+
+    /// Returns the encoding of the file of the source with the given identifier, as detected when it was loaded.
+    ///
+    /// Sources that were not loaded from a file, or not loaded yet, are reported as UTF-8,
+    /// and `None` is returned for unknown sources.
+    #[inline(always)]
+    pub fn encoding(&self, source_id: &SourceIdentifier) -> Option<SourceEncoding> {
+        let inner = self.inner.read();
+
+        inner.sources.get(source_id).map(|entry| entry.encoding)
+    }
+
     /// Writes updated content for the source with the given identifier.
+    ///
+    /// The file is written in the encoding it was loaded from, failing if the content
+    /// contains characters that cannot be represented in it.
     #[inline(always)]
     pub fn write(&self, source_id: SourceIdentifier, new_content: impl AsRef<str>) -> Result<(), SourceError> {
         let new_content_str = new_content.as_ref();
-        let new_content_id = self.interner.intern(new_content_str);
-        let new_lines: Vec<_> = line_starts(new_content_str).collect();
-        let new_size = new_content_str.len();
 
-        let path_opt = {
-            let mut inner = self.inner.write();
-            let entry = inner.sources.get_mut(&source_id).ok_or(SourceError::UnavailableSource(source_id))?;
-            if let Some((old_content, _, _)) = entry.content {
-                if old_content == new_content_id {
+        let (path_opt, encoding) = {
+            let inner = self.inner.read();
+            let entry = inner.sources.get(&source_id).ok_or(SourceError::UnavailableSource(source_id))?;
+            if let Some((old_content, _, _)) = &entry.content {
+                if old_content.as_ref() == new_content_str {
                     return Ok(());
                 }
             }
-            entry.content = Some((new_content_id, new_size, new_lines));
-            entry.path.clone()
+
+            (entry.path.clone(), entry.encoding)
         };
 
-        // If the source has an associated file, update it on disk.
+        // If the source has an associated file, update it on disk first, so the content
+        // in memory never diverges from the file when encoding or writing fails.
         if let Some(ref path) = path_opt {
-            std::fs::write(path, self.interner.lookup(&new_content_id)).map_err(SourceError::IOError)?;
+            let bytes = encoding.encode(new_content_str).ok_or_else(|| {
+                SourceError::IOError(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("the content cannot be represented in the {} encoding of '{}'", encoding, path.display()),
+                ))
+            })?;
+
+            if self.atomic_writes {
+                write_atomically(path, &bytes).map_err(SourceError::IOError)?;
+            } else {
+                std::fs::write(path, bytes).map_err(SourceError::IOError)?;
+            }
         }
 
+        let new_lines: Vec<_> = line_starts(new_content_str).collect();
+        let new_size = new_content_str.len();
+
+        let mut inner = self.inner.write();
+        let entry = inner.sources.get_mut(&source_id).ok_or(SourceError::UnavailableSource(source_id))?;
+        entry.content = Some((Arc::from(new_content_str), new_size, new_lines));
+        entry.modified = false;
+
         Ok(())
     }
 
@@ -411,3 +529,29 @@ impl<T: HasSource> HasSource for Box<T> {
 fn line_starts(source: &str) -> impl Iterator<Item = usize> + '_ {
     std::iter::once(0).chain(memchr::memchr_iter(b'\n', source.as_bytes()).map(|i| i + 1))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_keeps_the_content_when_it_cannot_be_encoded() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("index.php");
+        std::fs::write(&path, b"<?php echo 'caf\xE9';").unwrap();
+
+        let manager = SourceManager::new(ThreadedInterner::new());
+        let source_id = manager.insert_path("index.php", path.clone(), SourceCategory::UserDefined);
+        assert_eq!(manager.load(&source_id).unwrap().content.as_ref(), "<?php echo 'café';");
+        assert_eq!(manager.encoding(&source_id), Some(SourceEncoding::Windows1252));
+
+        // `✓` cannot be represented in Windows-1252.
+        assert!(manager.write(source_id, "<?php echo '✓';").is_err());
+        assert_eq!(manager.load(&source_id).unwrap().content.as_ref(), "<?php echo 'café';");
+        assert_eq!(std::fs::read(&path).unwrap(), b"<?php echo 'caf\xE9';");
+
+        manager.write(source_id, "<?php echo 'café!';").unwrap();
+        assert_eq!(manager.load(&source_id).unwrap().content.as_ref(), "<?php echo 'café!';");
+        assert_eq!(std::fs::read(&path).unwrap(), b"<?php echo 'caf\xE9!';");
+    }
+}
@@ -0,0 +1,347 @@
+use mago_interner::ThreadedInterner;
+use mago_reflection::r#type::kind::*;
+
+use crate::internal::get_literal_string_value_kind;
+
+/// Parses a type declared in a docblock, e.g. `array{id: int, name?: string}`, or `list<string>`.
+///
+/// Supported types are native types, scalar pseudo-types such as `non-empty-string`, or `array-key`,
+/// literal strings, and integers, `array<K, V>`, `list<T>`, and their `non-empty-` variants, `T[]`,
+/// array shapes, optionally unsealed using `...`, `iterable<K, V>`, and named objects, along with unions,
+/// intersections, and nullable types.
+///
+/// Class names are not resolved against the imports of the source, and are kept as written.
+///
+/// # Arguments
+///
+/// - `interner`: Manages string interning.
+/// - `input`: The type, as written in the docblock.
+///
+/// # Returns
+///
+/// Returns the `TypeKind` of the declared type, or `None` if the type is malformed, or uses an unsupported syntax,
+/// such as callable signatures, conditional types, or `key-of<T>`.
+pub fn parse_docblock_type(interner: &ThreadedInterner, input: &str) -> Option<TypeKind> {
+    let mut parser = Parser { interner, input, position: 0 };
+
+    let kind = parser.parse_union()?;
+    parser.skip_whitespace();
+
+    if parser.position != input.len() {
+        return None;
+    }
+
+    Some(kind)
+}
+
+struct Parser<'i> {
+    interner: &'i ThreadedInterner,
+    input: &'i str,
+    position: usize,
+}
+
+impl<'i> Parser<'i> {
+    fn parse_union(&mut self) -> Option<TypeKind> {
+        let mut kinds = vec![];
+        loop {
+            match self.parse_intersection()? {
+                TypeKind::Union { kinds: union_kinds } => kinds.extend(union_kinds),
+                kind => kinds.push(kind),
+            }
+
+            if !self.eat('|') {
+                break;
+            }
+        }
+
+        Some(if kinds.len() == 1 { kinds.swap_remove(0) } else { union_kind(kinds) })
+    }
+
+    fn parse_intersection(&mut self) -> Option<TypeKind> {
+        let mut kinds = vec![self.parse_postfix()?];
+        while self.eat('&') {
+            kinds.push(self.parse_postfix()?);
+        }
+
+        Some(if kinds.len() == 1 { kinds.swap_remove(0) } else { intersection_kind(kinds) })
+    }
+
+    fn parse_postfix(&mut self) -> Option<TypeKind> {
+        let mut kind = self.parse_atom()?;
+        while self.eat_str("[]") {
+            kind = array_kind(array_key_kind(), kind, None);
+        }
+
+        Some(kind)
+    }
+
+    fn parse_atom(&mut self) -> Option<TypeKind> {
+        self.skip_whitespace();
+
+        match self.peek()? {
+            '?' => {
+                self.position += 1;
+
+                Some(union_kind(vec![null_kind(), self.parse_postfix()?]))
+            }
+            '(' => {
+                self.position += 1;
+                let kind = self.parse_union()?;
+
+                self.eat(')').then_some(kind)
+            }
+            '\'' | '"' => {
+                let value = self.parse_quoted()?;
+
+                Some(get_literal_string_value_kind(self.interner, self.interner.intern(value), false))
+            }
+            '-' | '0'..='9' => Some(value_integer_kind(self.parse_integer()?)),
+            _ => {
+                let name = self.parse_identifier()?;
+
+                self.parse_named(name)
+            }
+        }
+    }
+
+    fn parse_named(&mut self, name: &'i str) -> Option<TypeKind> {
+        let kind = match name.to_ascii_lowercase().as_str() {
+            "array" if self.peek() == Some('{') => self.parse_shape()?,
+            "array" | "non-empty-array" => {
+                let (key, value) = match self.parse_arguments()?.as_mut_slice() {
+                    [] => (array_key_kind(), mixed_kind(false)),
+                    [value] => (array_key_kind(), std::mem::replace(value, mixed_kind(false))),
+                    [key, value] => {
+                        (std::mem::replace(key, mixed_kind(false)), std::mem::replace(value, mixed_kind(false)))
+                    }
+                    _ => return None,
+                };
+
+                if name.eq_ignore_ascii_case("array") {
+                    array_kind(key, value, None)
+                } else {
+                    non_empty_array_kind(key, value, None)
+                }
+            }
+            "list" | "non-empty-list" => {
+                let value = match self.parse_arguments()?.as_mut_slice() {
+                    [] => mixed_kind(false),
+                    [value] => std::mem::replace(value, mixed_kind(false)),
+                    _ => return None,
+                };
+
+                if name.eq_ignore_ascii_case("list") {
+                    list_kind(value, None)
+                } else {
+                    non_empty_list_kind(value, None)
+                }
+            }
+            "iterable" => match self.parse_arguments()?.as_mut_slice() {
+                [] => iterable_kind(mixed_kind(false), mixed_kind(false)),
+                [value] => iterable_kind(mixed_kind(false), std::mem::replace(value, mixed_kind(false))),
+                [key, value] => iterable_kind(
+                    std::mem::replace(key, mixed_kind(false)),
+                    std::mem::replace(value, mixed_kind(false)),
+                ),
+                _ => return None,
+            },
+            "int" | "integer" => integer_kind(),
+            "positive-int" => positive_integer_kind(),
+            "negative-int" => negative_integer_kind(),
+            "non-negative-int" => non_negative_integer_kind(),
+            "non-positive-int" => non_positive_integer_kind(),
+            "float" | "double" => float_kind(),
+            "string" => string_kind(),
+            "non-empty-string" => non_empty_string_kind(),
+            "numeric-string" => TypeKind::Scalar(ScalarTypeKind::NumericString),
+            "literal-string" => TypeKind::Scalar(ScalarTypeKind::LiteralString),
+            "class-string" => TypeKind::Scalar(ScalarTypeKind::ClassString(None)),
+            "bool" | "boolean" => bool_kind(),
+            "true" => true_kind(),
+            "false" => false_kind(),
+            "null" => null_kind(),
+            "array-key" => array_key_kind(),
+            "numeric" => TypeKind::Scalar(ScalarTypeKind::Numeric),
+            "scalar" => TypeKind::Scalar(ScalarTypeKind::Scalar),
+            "mixed" => mixed_kind(true),
+            "void" => void_kind(),
+            "never" | "never-return" | "never-returns" | "no-return" => never_kind(),
+            "object" => any_object_kind(),
+            "resource" => resource_kind(),
+            "callable" if self.peek() != Some('(') => any_callable_kind(),
+            "closure" if self.peek() != Some('(') => any_closure_kind(),
+            // `self`, `static`, and `parent` depend on the scope, and other keywords are not supported.
+            "callable" | "closure" | "self" | "static" | "parent" | "$this" => return None,
+            _ if name.contains('-') || self.input[self.position..].starts_with("::") => return None,
+            _ => {
+                let name = name.strip_prefix('\\').unwrap_or(name);
+                let arguments = self.parse_arguments()?;
+
+                named_object_kind(self.interner.intern(name), arguments)
+            }
+        };
+
+        Some(kind)
+    }
+
+    /// Parses the generic arguments following a type name, e.g. `<int, string>`, if any.
+    fn parse_arguments(&mut self) -> Option<Vec<TypeKind>> {
+        if self.peek() != Some('<') {
+            return Some(vec![]);
+        }
+
+        self.position += 1;
+
+        let mut arguments = vec![self.parse_union()?];
+        while self.eat(',') {
+            arguments.push(self.parse_union()?);
+        }
+
+        self.eat('>').then_some(arguments)
+    }
+
+    fn parse_shape(&mut self) -> Option<TypeKind> {
+        if !self.eat('{') {
+            return None;
+        }
+
+        let mut properties = vec![];
+        let mut additional_properties = None;
+        loop {
+            self.skip_whitespace();
+            if self.eat('}') {
+                break;
+            }
+
+            if self.eat_str("...") {
+                additional_properties = match self.parse_arguments()?.as_mut_slice() {
+                    [] => Some((array_key_kind(), mixed_kind(false))),
+                    [value] => Some((array_key_kind(), std::mem::replace(value, mixed_kind(false)))),
+                    [key, value] => {
+                        Some((std::mem::replace(key, mixed_kind(false)), std::mem::replace(value, mixed_kind(false))))
+                    }
+                    _ => return None,
+                };
+
+                self.eat(',');
+
+                if !self.eat('}') {
+                    return None;
+                }
+                break;
+            }
+
+            properties.push(self.parse_shape_property()?);
+
+            if !self.eat(',') {
+                if !self.eat('}') {
+                    return None;
+                }
+                break;
+            }
+        }
+
+        Some(array_shape_kind(properties, additional_properties))
+    }
+
+    fn parse_shape_property(&mut self) -> Option<ArrayShapeProperty> {
+        let start = self.position;
+
+        let key = match self.peek()? {
+            '\'' | '"' => self.parse_quoted().map(|key| ArrayShapePropertyKey::String(self.interner.intern(key))),
+            '-' | '0'..='9' => self.parse_integer().map(|key| ArrayShapePropertyKey::Integer(key as isize)),
+            _ => self.parse_identifier().map(|key| ArrayShapePropertyKey::String(self.interner.intern(key))),
+        };
+
+        let optional = self.eat('?');
+        if let (Some(key), true) = (key, self.eat(':')) {
+            let kind = self.parse_union()?;
+
+            return Some(ArrayShapeProperty { key: Some(key), kind, optional });
+        }
+
+        // The property has no key, e.g. `array{int, string}`.
+        self.position = start;
+
+        Some(indexed_shape_property(self.parse_union()?, false))
+    }
+
+    fn parse_identifier(&mut self) -> Option<&'i str> {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let length = self.input[start..]
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '\\' | '-' | '$')))
+            .unwrap_or(self.input.len() - start);
+
+        // A `-` may not end a name, e.g. in `array<string, int>`, `->` is not a part of it.
+        let length = self.input[start..start + length].trim_end_matches('-').len();
+        if length == 0 {
+            return None;
+        }
+
+        self.position += length;
+
+        Some(&self.input[start..start + length])
+    }
+
+    fn parse_quoted(&mut self) -> Option<&'i str> {
+        self.skip_whitespace();
+
+        let quote = self.peek()?;
+        let start = self.position + 1;
+        let length = self.input[start..].find(quote)?;
+        self.position = start + length + 1;
+
+        Some(&self.input[start..start + length])
+    }
+
+    fn parse_integer(&mut self) -> Option<i64> {
+        self.skip_whitespace();
+
+        let start = self.position;
+        let negative = self.input[start..].starts_with('-');
+        let digits_start = if negative { start + 1 } else { start };
+        let length =
+            self.input[digits_start..].find(|c: char| !c.is_ascii_digit()).unwrap_or(self.input.len() - digits_start);
+
+        let value = self.input[start..digits_start + length].parse().ok()?;
+        self.position = digits_start + length;
+
+        Some(value)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.position..];
+
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, char: char) -> bool {
+        self.skip_whitespace();
+
+        if self.peek() == Some(char) {
+            self.position += char.len_utf8();
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, string: &str) -> bool {
+        self.skip_whitespace();
+
+        if self.input[self.position..].starts_with(string) {
+            self.position += string.len();
+
+            true
+        } else {
+            false
+        }
+    }
+}
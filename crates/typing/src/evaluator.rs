@@ -0,0 +1,527 @@
+use std::fmt;
+
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_reflection::CodebaseReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reflection::r#type::kind::ValueTypeKind;
+
+/// A scalar value, as evaluated from a constant expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+/// A key of an array, after the casts applied by PHP, e.g. `'1'`, `true`, and `1.5` all result in the key `1`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ArrayKey {
+    Integer(i64),
+    String(String),
+}
+
+/// Evaluates constant expressions, such as `1 << 3`, `'foo' . PHP_EOL`, `Foo::class`, or `self::BAR * 2`,
+/// to their value.
+///
+/// Arithmetic, bitwise, string concatenation, logical, and null coalescing operations on scalar values,
+/// as well as casts, and ternary operations, are folded. Global, and class constants are resolved
+/// using the codebase, if available, when their value is known.
+///
+/// Operations whose result depends on the runtime, such as `static::class`, or that would emit a warning,
+/// or throw, such as a division by zero, are not evaluated.
+pub struct ConstantEvaluator<'i, 'c> {
+    interner: &'i ThreadedInterner,
+    names: &'c Names,
+    codebase: Option<&'c CodebaseReflection>,
+    class_like: Option<&'c ClassLikeReflection>,
+}
+
+impl<'i, 'c> ConstantEvaluator<'i, 'c> {
+    /// Creates a new evaluator.
+    ///
+    /// # Arguments
+    ///
+    /// - `interner`: Manages string interning.
+    /// - `names`: The names of the program.
+    /// - `codebase`: The codebase used to resolve constants, if any.
+    /// - `class_like`: The class-like scope used to resolve `self`, if any.
+    pub fn new(
+        interner: &'i ThreadedInterner,
+        names: &'c Names,
+        codebase: Option<&'c CodebaseReflection>,
+        class_like: Option<&'c ClassLikeReflection>,
+    ) -> Self {
+        Self { interner, names, codebase, class_like }
+    }
+
+    /// Evaluates the given expression.
+    ///
+    /// # Returns
+    ///
+    /// Returns the value of the expression, or `None` if it is not a constant expression, or cannot be evaluated.
+    pub fn evaluate(&self, expression: &Expression) -> Option<ConstantValue> {
+        match expression {
+            Expression::Parenthesized(parenthesized) => self.evaluate(&parenthesized.expression),
+            Expression::Literal(literal) => self.evaluate_literal(literal),
+            Expression::UnaryPrefix(operation) => self.evaluate_unary_prefix(operation),
+            Expression::Binary(operation) => self.evaluate_binary(operation),
+            Expression::Conditional(conditional) => {
+                let condition = self.evaluate(&conditional.condition)?;
+
+                match (condition.to_bool(), &conditional.then) {
+                    (true, Some(then)) => self.evaluate(then),
+                    (true, None) => Some(condition),
+                    (false, _) => self.evaluate(&conditional.r#else),
+                }
+            }
+            Expression::ConstantAccess(access) => self.evaluate_constant(&access.name),
+            // Operands of binary operations, e.g. `PHP_INT_MAX + 1`, are not always parsed as constant accesses.
+            Expression::Identifier(identifier) => self.evaluate_constant(identifier),
+            Expression::Access(Access::ClassConstant(access)) => self.evaluate_class_constant(access),
+            _ => None,
+        }
+    }
+
+    fn evaluate_literal(&self, literal: &Literal) -> Option<ConstantValue> {
+        Some(match literal {
+            Literal::String(string) => ConstantValue::String(get_string_value(string, self.interner)?),
+            Literal::Integer(integer) => match integer.value {
+                Some(value) => match i64::try_from(value) {
+                    Ok(value) => ConstantValue::Integer(value),
+                    // Integer literals exceeding `PHP_INT_MAX` are interpreted as floats.
+                    Err(_) => ConstantValue::Float(value as f64),
+                },
+                None => {
+                    let raw = self.interner.lookup(&integer.raw).replace('_', "");
+                    if !raw.bytes().all(|byte| byte.is_ascii_digit()) {
+                        return None;
+                    }
+
+                    ConstantValue::Float(raw.parse().ok()?)
+                }
+            },
+            Literal::Float(float) => ConstantValue::Float(float.value.into_inner()),
+            Literal::True(_) => ConstantValue::Bool(true),
+            Literal::False(_) => ConstantValue::Bool(false),
+            Literal::Null(_) => ConstantValue::Null,
+        })
+    }
+
+    fn evaluate_unary_prefix(&self, operation: &UnaryPrefix) -> Option<ConstantValue> {
+        let operand = self.evaluate(&operation.operand)?;
+
+        Some(match &operation.operator {
+            UnaryPrefixOperator::Not(_) => ConstantValue::Bool(!operand.to_bool()),
+            UnaryPrefixOperator::Plus(_) => match operand {
+                ConstantValue::Integer(_) | ConstantValue::Float(_) => operand,
+                _ => return None,
+            },
+            UnaryPrefixOperator::Negation(_) => match operand {
+                ConstantValue::Integer(value) => {
+                    value.checked_neg().map_or(ConstantValue::Float(-(value as f64)), ConstantValue::Integer)
+                }
+                ConstantValue::Float(value) => ConstantValue::Float(-value),
+                _ => return None,
+            },
+            UnaryPrefixOperator::BitwiseNot(_) => match operand {
+                ConstantValue::Integer(value) => ConstantValue::Integer(!value),
+                _ => return None,
+            },
+            UnaryPrefixOperator::BoolCast(_, _) | UnaryPrefixOperator::BooleanCast(_, _) => {
+                ConstantValue::Bool(operand.to_bool())
+            }
+            UnaryPrefixOperator::IntCast(_, _) | UnaryPrefixOperator::IntegerCast(_, _) => match operand {
+                ConstantValue::Null => ConstantValue::Integer(0),
+                ConstantValue::Bool(value) => ConstantValue::Integer(value as i64),
+                ConstantValue::Integer(_) => operand,
+                ConstantValue::Float(value) if value.is_finite() && value.abs() < i64::MAX as f64 => {
+                    ConstantValue::Integer(value as i64)
+                }
+                _ => return None,
+            },
+            UnaryPrefixOperator::FloatCast(_, _)
+            | UnaryPrefixOperator::DoubleCast(_, _)
+            | UnaryPrefixOperator::RealCast(_, _) => match operand {
+                ConstantValue::Null => ConstantValue::Float(0.0),
+                ConstantValue::Bool(value) => ConstantValue::Float(value as i64 as f64),
+                ConstantValue::Integer(value) => ConstantValue::Float(value as f64),
+                ConstantValue::Float(_) => operand,
+                ConstantValue::String(_) => return None,
+            },
+            UnaryPrefixOperator::StringCast(_, _) | UnaryPrefixOperator::BinaryCast(_, _) => {
+                ConstantValue::String(operand.to_php_string()?)
+            }
+            _ => return None,
+        })
+    }
+
+    fn evaluate_binary(&self, operation: &Binary) -> Option<ConstantValue> {
+        let lhs = self.evaluate(&operation.lhs)?;
+
+        // The right-hand side of short-circuiting operations is only evaluated when needed.
+        match &operation.operator {
+            BinaryOperator::NullCoalesce(_) => {
+                return if lhs == ConstantValue::Null { self.evaluate(&operation.rhs) } else { Some(lhs) };
+            }
+            BinaryOperator::Elvis(_) => {
+                return if lhs.to_bool() { Some(lhs) } else { self.evaluate(&operation.rhs) };
+            }
+            BinaryOperator::And(_) | BinaryOperator::LowAnd(_) if !lhs.to_bool() => {
+                return Some(ConstantValue::Bool(false));
+            }
+            BinaryOperator::Or(_) | BinaryOperator::LowOr(_) if lhs.to_bool() => {
+                return Some(ConstantValue::Bool(true));
+            }
+            _ => {}
+        }
+
+        let rhs = self.evaluate(&operation.rhs)?;
+
+        Some(match &operation.operator {
+            BinaryOperator::And(_) | BinaryOperator::LowAnd(_) | BinaryOperator::Or(_) | BinaryOperator::LowOr(_) => {
+                ConstantValue::Bool(rhs.to_bool())
+            }
+            BinaryOperator::LowXor(_) => ConstantValue::Bool(lhs.to_bool() != rhs.to_bool()),
+            BinaryOperator::StringConcat(_) => {
+                ConstantValue::String(lhs.to_php_string()? + rhs.to_php_string()?.as_str())
+            }
+            BinaryOperator::Identical(_) => ConstantValue::Bool(lhs.is_identical(&rhs)),
+            BinaryOperator::NotIdentical(_) => ConstantValue::Bool(!lhs.is_identical(&rhs)),
+            BinaryOperator::Equal(_) => ConstantValue::Bool(lhs.is_loosely_equal(&rhs)),
+            BinaryOperator::NotEqual(_) | BinaryOperator::AngledNotEqual(_) => {
+                ConstantValue::Bool(!lhs.is_loosely_equal(&rhs))
+            }
+            BinaryOperator::Addition(_) => arithmetic(lhs, rhs, i64::checked_add, |lhs, rhs| lhs + rhs)?,
+            BinaryOperator::Subtraction(_) => arithmetic(lhs, rhs, i64::checked_sub, |lhs, rhs| lhs - rhs)?,
+            BinaryOperator::Multiplication(_) => arithmetic(lhs, rhs, i64::checked_mul, |lhs, rhs| lhs * rhs)?,
+            BinaryOperator::Division(_) => match (lhs, rhs) {
+                (_, ConstantValue::Integer(0)) => return None,
+                (_, ConstantValue::Float(0.0)) => return None,
+                (ConstantValue::Integer(lhs), ConstantValue::Integer(rhs)) if lhs.checked_rem(rhs) == Some(0) => {
+                    ConstantValue::Integer(lhs / rhs)
+                }
+                (lhs, rhs) => ConstantValue::Float(lhs.to_float()? / rhs.to_float()?),
+            },
+            BinaryOperator::Modulo(_) => match (lhs, rhs) {
+                (ConstantValue::Integer(_), ConstantValue::Integer(0)) => return None,
+                (ConstantValue::Integer(lhs), ConstantValue::Integer(rhs)) => {
+                    ConstantValue::Integer(lhs.checked_rem(rhs).unwrap_or(0))
+                }
+                _ => return None,
+            },
+            BinaryOperator::Exponentiation(_) => match (lhs, rhs) {
+                (ConstantValue::Integer(lhs), ConstantValue::Integer(rhs)) if rhs >= 0 => u32::try_from(rhs)
+                    .ok()
+                    .and_then(|rhs| lhs.checked_pow(rhs))
+                    .map_or(ConstantValue::Float((lhs as f64).powf(rhs as f64)), ConstantValue::Integer),
+                (lhs, rhs) => ConstantValue::Float(lhs.to_float()?.powf(rhs.to_float()?)),
+            },
+            BinaryOperator::BitwiseAnd(_) => ConstantValue::Integer(lhs.as_integer()? & rhs.as_integer()?),
+            BinaryOperator::BitwiseOr(_) => ConstantValue::Integer(lhs.as_integer()? | rhs.as_integer()?),
+            BinaryOperator::BitwiseXor(_) => ConstantValue::Integer(lhs.as_integer()? ^ rhs.as_integer()?),
+            BinaryOperator::LeftShift(_) => match rhs.as_integer()? {
+                shift @ 0..=63 => ConstantValue::Integer(lhs.as_integer()? << shift),
+                64.. => ConstantValue::Integer(0),
+                _ => return None,
+            },
+            BinaryOperator::RightShift(_) => match rhs.as_integer()? {
+                shift @ 0..=63 => ConstantValue::Integer(lhs.as_integer()? >> shift),
+                64.. => ConstantValue::Integer(if lhs.as_integer()? < 0 { -1 } else { 0 }),
+                _ => return None,
+            },
+            _ => return None,
+        })
+    }
+
+    fn evaluate_constant(&self, identifier: &Identifier) -> Option<ConstantValue> {
+        let name = self.interner.lookup(&identifier.value());
+        let short_name = name.strip_prefix('\\').unwrap_or(name);
+
+        match short_name {
+            "PHP_INT_MAX" => return Some(ConstantValue::Integer(i64::MAX)),
+            "PHP_INT_MIN" => return Some(ConstantValue::Integer(i64::MIN)),
+            "PHP_INT_SIZE" => return Some(ConstantValue::Integer(8)),
+            "PHP_FLOAT_EPSILON" => return Some(ConstantValue::Float(f64::EPSILON)),
+            "PHP_FLOAT_MAX" => return Some(ConstantValue::Float(f64::MAX)),
+            "PHP_FLOAT_MIN" => return Some(ConstantValue::Float(f64::MIN_POSITIVE)),
+            _ => {}
+        }
+
+        let codebase = self.codebase?;
+        let constant = codebase.get_constant(self.interner, self.names.get(identifier)).or_else(|| {
+            if self.names.is_imported(identifier) || name.starts_with('\\') {
+                return None;
+            }
+
+            codebase.get_constant(self.interner, self.names.get_fallback(identifier)?)
+        })?;
+
+        self.get_value_of_kind(&constant.type_reflection.kind)
+    }
+
+    fn evaluate_class_constant(&self, access: &ClassConstantAccess) -> Option<ConstantValue> {
+        let ClassLikeConstantSelector::Identifier(constant) = &access.constant else {
+            return None;
+        };
+
+        let is_class = self.interner.lookup(&constant.value).eq_ignore_ascii_case("class");
+
+        let class_like = match access.class.as_ref() {
+            Expression::Identifier(identifier) => {
+                let name = self.names.get(identifier);
+                if is_class {
+                    return Some(ConstantValue::String(self.interner.lookup(name).to_string()));
+                }
+
+                self.codebase?.get_named_class_like(self.interner, name)?
+            }
+            Expression::Self_(_) => {
+                let class_like = self.class_like?;
+                if is_class {
+                    return match &class_like.name {
+                        ClassLikeName::Class(name) | ClassLikeName::Interface(name) | ClassLikeName::Enum(name) => {
+                            Some(ConstantValue::String(self.interner.lookup(&name.value).to_string()))
+                        }
+                        // The name of a trait depends on the class using it.
+                        ClassLikeName::Trait(_) | ClassLikeName::AnonymousClass(_) => None,
+                    };
+                }
+
+                class_like
+            }
+            _ => return None,
+        };
+
+        let constant = class_like.constants.get(&constant.value)?;
+
+        self.get_value_of_kind(&constant.inferred_type_reflection.kind)
+    }
+
+    /// Returns the value of the given type, if it is a scalar value type.
+    fn get_value_of_kind(&self, kind: &TypeKind) -> Option<ConstantValue> {
+        let TypeKind::Value(value) = kind else {
+            return None;
+        };
+
+        Some(match value {
+            ValueTypeKind::String { value, .. } => {
+                let value = self.interner.lookup(value);
+                // The string is not unescaped by the type resolver.
+                if value.contains('\\') {
+                    return None;
+                }
+
+                ConstantValue::String(value.to_string())
+            }
+            ValueTypeKind::Integer { value } => ConstantValue::Integer(*value),
+            ValueTypeKind::Float { value } => ConstantValue::Float(value.into_inner()),
+            ValueTypeKind::Null => ConstantValue::Null,
+            ValueTypeKind::True => ConstantValue::Bool(true),
+            ValueTypeKind::False => ConstantValue::Bool(false),
+            ValueTypeKind::ClassLikeConstant { .. } => return None,
+        })
+    }
+}
+
+impl ConstantValue {
+    /// Converts the value to a boolean, e.g. when used as a condition.
+    pub fn to_bool(&self) -> bool {
+        match self {
+            ConstantValue::Null => false,
+            ConstantValue::Bool(value) => *value,
+            ConstantValue::Integer(value) => *value != 0,
+            ConstantValue::Float(value) => *value != 0.0,
+            ConstantValue::String(value) => !(value.is_empty() || value == "0"),
+        }
+    }
+
+    /// Converts the value to the key of an array, following the casts applied by PHP.
+    ///
+    /// Returns `None` for floats that cannot be represented as an integer.
+    pub fn to_array_key(&self) -> Option<ArrayKey> {
+        Some(match self {
+            ConstantValue::Null => ArrayKey::String(String::new()),
+            ConstantValue::Bool(value) => ArrayKey::Integer(*value as i64),
+            ConstantValue::Integer(value) => ArrayKey::Integer(*value),
+            ConstantValue::Float(value) if value.is_finite() && value.abs() < i64::MAX as f64 => {
+                ArrayKey::Integer(*value as i64)
+            }
+            ConstantValue::Float(_) => return None,
+            ConstantValue::String(value) => match value.parse::<i64>() {
+                Ok(integer) if integer.to_string() == *value => ArrayKey::Integer(integer),
+                _ => ArrayKey::String(value.clone()),
+            },
+        })
+    }
+
+    /// Determines whether the value is identical to the given value, i.e. `===`.
+    pub fn is_identical(&self, other: &ConstantValue) -> bool {
+        self == other
+    }
+
+    /// Determines whether the value is loosely equal to the given value, i.e. `==`, following the PHP 8
+    /// comparison rules.
+    pub fn is_loosely_equal(&self, other: &ConstantValue) -> bool {
+        match (self, other) {
+            (ConstantValue::Null, ConstantValue::Null) => true,
+            (ConstantValue::Bool(_), _) | (_, ConstantValue::Bool(_)) => self.to_bool() == other.to_bool(),
+            (ConstantValue::Null, ConstantValue::String(value))
+            | (ConstantValue::String(value), ConstantValue::Null) => value.is_empty(),
+            (ConstantValue::Null, _) | (_, ConstantValue::Null) => !self.to_bool() && !other.to_bool(),
+            (ConstantValue::Integer(lhs), ConstantValue::Integer(rhs)) => lhs == rhs,
+            (ConstantValue::String(lhs), ConstantValue::String(rhs)) => {
+                match (parse_numeric(lhs), parse_numeric(rhs)) {
+                    (Some(lhs), Some(rhs)) => lhs == rhs,
+                    _ => lhs == rhs,
+                }
+            }
+            (ConstantValue::String(string), number) | (number, ConstantValue::String(string)) => {
+                match parse_numeric(string) {
+                    Some(value) => Some(value) == number.to_float(),
+                    None => number.to_php_string().is_some_and(|number| number == *string),
+                }
+            }
+            (lhs, rhs) => lhs.to_float() == rhs.to_float(),
+        }
+    }
+
+    /// Converts the value to a string, as done by PHP, e.g. when concatenated.
+    ///
+    /// Returns `None` for floats whose string representation is not known.
+    pub fn to_php_string(&self) -> Option<String> {
+        Some(match self {
+            ConstantValue::Null | ConstantValue::Bool(false) => String::new(),
+            ConstantValue::Bool(true) => "1".to_string(),
+            ConstantValue::Integer(value) => value.to_string(),
+            ConstantValue::Float(value) if value.fract() == 0.0 && value.abs() < 1e15 => {
+                format!("{}", *value as i64)
+            }
+            // PHP uses the exponent notation for large, and small values, e.g. `1.0E+25`.
+            ConstantValue::Float(value) if value.is_finite() && value.abs() < 1e15 && value.abs() >= 1e-4 => {
+                value.to_string()
+            }
+            ConstantValue::Float(_) => return None,
+            ConstantValue::String(value) => value.clone(),
+        })
+    }
+
+    fn to_float(&self) -> Option<f64> {
+        match self {
+            ConstantValue::Integer(value) => Some(*value as f64),
+            ConstantValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_integer(&self) -> Option<i64> {
+        match self {
+            ConstantValue::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ConstantValue {
+    /// Formats the value as it would be written in PHP code.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstantValue::Null => write!(f, "null"),
+            ConstantValue::Bool(value) => write!(f, "{}", value),
+            ConstantValue::Integer(value) => write!(f, "{}", value),
+            ConstantValue::Float(value) => write!(f, "{:?}", value),
+            ConstantValue::String(value) => write!(f, "'{}'", value.replace('\\', "\\\\").replace('\'', "\\'")),
+        }
+    }
+}
+
+impl fmt::Display for ArrayKey {
+    /// Formats the key as it would be written in PHP code.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArrayKey::Integer(value) => write!(f, "{}", value),
+            ArrayKey::String(value) => write!(f, "'{}'", value.replace('\\', "\\\\").replace('\'', "\\'")),
+        }
+    }
+}
+
+/// Returns the value of a string literal, if it can be statically determined.
+///
+/// Double-quoted strings containing escape sequences other than the simple ones, e.g. `\n` or `\"`,
+/// are not considered.
+pub fn get_string_value(string: &LiteralString, interner: &ThreadedInterner) -> Option<String> {
+    let raw = interner.lookup(&string.value);
+    let inner = raw.get(1..raw.len().checked_sub(1)?)?;
+
+    let mut value = String::with_capacity(inner.len());
+    let mut characters = inner.chars().peekable();
+    while let Some(character) = characters.next() {
+        match (&string.kind, character) {
+            (LiteralStringKind::SingleQuoted, '\\') => match characters.peek() {
+                Some('\\' | '\'') => value.push(characters.next()?),
+                _ => value.push('\\'),
+            },
+            (LiteralStringKind::DoubleQuoted, '\\') => match characters.peek() {
+                Some('\\' | '$' | '"') => value.push(characters.next()?),
+                Some('n') => value.push(characters.next().map(|_| '\n')?),
+                Some('t') => value.push(characters.next().map(|_| '\t')?),
+                Some('r') => value.push(characters.next().map(|_| '\r')?),
+                Some('v') => value.push(characters.next().map(|_| '\x0B')?),
+                Some('e') => value.push(characters.next().map(|_| '\x1B')?),
+                Some('f') => value.push(characters.next().map(|_| '\x0C')?),
+                Some('x' | 'u' | '0'..='7') => return None,
+                _ => value.push('\\'),
+            },
+            (_, character) => value.push(character),
+        }
+    }
+
+    Some(value)
+}
+
+/// Applies an arithmetic operation to two numbers, falling back to floats when the integer operation overflows,
+/// as done by PHP.
+fn arithmetic(
+    lhs: ConstantValue,
+    rhs: ConstantValue,
+    integer_operation: fn(i64, i64) -> Option<i64>,
+    float_operation: fn(f64, f64) -> f64,
+) -> Option<ConstantValue> {
+    if let (ConstantValue::Integer(lhs), ConstantValue::Integer(rhs)) = (&lhs, &rhs) {
+        if let Some(result) = integer_operation(*lhs, *rhs) {
+            return Some(ConstantValue::Integer(result));
+        }
+    }
+
+    Some(ConstantValue::Float(float_operation(lhs.to_float()?, rhs.to_float()?)))
+}
+
+/// Parses a numeric string, e.g. `' 1.5e3'`, allowing leading, and trailing whitespace, as done by PHP.
+fn parse_numeric(value: &str) -> Option<f64> {
+    let value = value.trim_matches(|c: char| matches!(c, ' ' | '\t' | '\n' | '\r' | '\x0B' | '\x0C'));
+    let unsigned = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(position) => (&unsigned[..position], Some(&unsigned[position + 1..])),
+        None => (unsigned, None),
+    };
+
+    let (integer_part, fraction_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    if integer_part.is_empty() && fraction_part.is_empty() || !is_digits(integer_part) || !is_digits(fraction_part) {
+        return None;
+    }
+
+    if let Some(exponent) = exponent {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        if exponent.is_empty() || !is_digits(exponent) {
+            return None;
+        }
+    }
+
+    value.parse().ok()
+}
@@ -12,6 +12,8 @@ use crate::resolver::TypeResolver;
 mod internal;
 
 pub mod constant;
+pub mod docblock;
+pub mod evaluator;
 pub mod resolver;
 
 /// Infers the type of a given expression by initializing a simple type reflection
@@ -2,6 +2,7 @@
 
 use mago_ast::Program;
 use mago_ast::ast::*;
+use mago_ast::stack::ensure_sufficient_stack;
 
 /// Macro for generating a walker trait and associated functions for traversing an AST.
 ///
@@ -121,7 +122,7 @@ generate_ast_walker! {
     }
 
     Statement as statement => {
-        match &statement {
+        ensure_sufficient_stack(|| match &statement {
             Statement::OpeningTag(opening_tag) => walker.walk_opening_tag(opening_tag, context),
             Statement::ClosingTag(closing_tag) => walker.walk_closing_tag(closing_tag, context),
             Statement::Inline(inline) => walker.walk_inline(inline, context),
@@ -153,10 +154,10 @@ generate_ast_walker! {
             Statement::Static(r#static) => walker.walk_static(r#static, context),
             Statement::HaltCompiler(halt_compiler) => walker.walk_halt_compiler(halt_compiler, context),
             Statement::Unset(unset) => walker.walk_unset(unset, context),
-            Statement::Noop(_) => {
+            Statement::Noop(_) | Statement::Error(_) => {
                 // Do nothing by default
             },
-        }
+        })
     }
 
     OpeningTag as opening_tag => {
@@ -1290,7 +1291,7 @@ generate_ast_walker! {
     }
 
     Expression as expression => {
-        match &expression {
+        ensure_sufficient_stack(|| match &expression {
             Expression::Parenthesized(parenthesized) => walker.walk_parenthesized(parenthesized, context),
             Expression::Binary(expr) => walker.walk_binary(expr, context),
             Expression::UnaryPrefix(operation) => walker.walk_unary_prefix(operation, context),
@@ -1331,7 +1332,7 @@ generate_ast_walker! {
             Expression::Self_(keyword) => walker.walk_self_keyword(keyword, context),
             Expression::Instantiation(instantiation) => walker.walk_instantiation(instantiation, context),
             Expression::MagicConstant(magic_constant) => walker.walk_magic_constant(magic_constant, context),
-        }
+        })
     }
 
     Binary as binary => {
@@ -49,10 +49,11 @@ pub struct AnalysisResults {
 
     /// The abstract syntax tree (AST) resulting from parsing the source code.
     ///
-    /// If [`parse_error`](Self::parse_error) is `Some`, this AST may be incomplete or invalid.
+    /// If [`parse_error`](Self::parse_error) is `Some`, this AST may be incomplete, or contain error
+    /// statements in place of the malformed regions of the source code.
     pub program: Program,
 
-    /// An optional parse error, if one occurred during parsing.
+    /// The first parse error, if any occurred during parsing.
     ///
     /// If this is `Some`, then [`formatted`](Self::formatted) will be `None`,
     /// because the code could not be validly parsed.
@@ -112,7 +113,7 @@ impl AnalysisResults {
         let mut module = Module::build(&interner, lint_settings.php_version, source, ModuleBuildOptions::validation());
         let program = module.parse(&interner);
         let mut formatted = None;
-        if module.parse_error.is_none() {
+        if module.parse_errors.is_empty() {
             // Only format if there are no parse errors
             formatted = Some(mago_formatter::format(&interner, &module.source, &program, format_settings));
         }
@@ -124,7 +125,7 @@ impl AnalysisResults {
         Self {
             strings: interner.all().into_iter().map(|(id, value)| (id, value.to_string())).collect(),
             program,
-            parse_error: module.parse_error.as_ref().map(|e| e.into()),
+            parse_error: module.parse_errors.first().map(|e| e.into()),
             names: module.names.all().into_iter().map(|(offset, (id, imported))| (*offset, (*id, *imported))).collect(),
             formatted,
             semantic_issues: module.issues,
@@ -0,0 +1,54 @@
+<?php
+
+namespace Illuminate\Support\Facades;
+
+abstract class Facade
+{
+    /**
+     * Get the root object behind the facade.
+     *
+     * @return mixed
+     */
+    public static function getFacadeRoot() {}
+
+    /**
+     * Handle dynamic, static calls to the object.
+     *
+     * @param string $method
+     * @param array<int, mixed> $args
+     *
+     * @return mixed
+     */
+    public static function __callStatic($method, $args) {}
+}
+
+class App extends Facade {}
+class Artisan extends Facade {}
+class Auth extends Facade {}
+class Blade extends Facade {}
+class Bus extends Facade {}
+class Cache extends Facade {}
+class Config extends Facade {}
+class Cookie extends Facade {}
+class Crypt extends Facade {}
+class DB extends Facade {}
+class Event extends Facade {}
+class File extends Facade {}
+class Gate extends Facade {}
+class Hash extends Facade {}
+class Http extends Facade {}
+class Lang extends Facade {}
+class Log extends Facade {}
+class Mail extends Facade {}
+class Notification extends Facade {}
+class Queue extends Facade {}
+class Redirect extends Facade {}
+class Request extends Facade {}
+class Response extends Facade {}
+class Route extends Facade {}
+class Schema extends Facade {}
+class Session extends Facade {}
+class Storage extends Facade {}
+class URL extends Facade {}
+class Validator extends Facade {}
+class View extends Facade {}
@@ -0,0 +1,51 @@
+<?php
+
+/**
+ * Gets the value of an environment variable.
+ *
+ * @param string $key
+ * @param mixed $default
+ *
+ * @return mixed
+ */
+function env($key, $default = null) {}
+
+/**
+ * Get / set the specified configuration value.
+ *
+ * @param array<string, mixed>|string|null $key
+ * @param mixed $default
+ *
+ * @return mixed
+ */
+function config($key = null, $default = null) {}
+
+/**
+ * Get the available container instance.
+ *
+ * @param string|null $abstract
+ * @param array<string, mixed> $parameters
+ *
+ * @return mixed
+ */
+function app($abstract = null, array $parameters = []) {}
+
+/**
+ * Generate the URL to a named route.
+ *
+ * @param string $name
+ * @param mixed $parameters
+ * @param bool $absolute
+ */
+function route($name, $parameters = [], $absolute = true): string {}
+
+/**
+ * Get the evaluated view contents for the given view.
+ *
+ * @param string|null $view
+ * @param array<string, mixed> $data
+ * @param array<string, mixed> $mergeData
+ *
+ * @return mixed
+ */
+function view($view = null, $data = [], $mergeData = []) {}
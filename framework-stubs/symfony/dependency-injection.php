@@ -0,0 +1,26 @@
+<?php
+
+namespace Symfony\Component\DependencyInjection;
+
+interface ContainerInterface
+{
+    public const RUNTIME_EXCEPTION_ON_INVALID_REFERENCE = 0;
+    public const EXCEPTION_ON_INVALID_REFERENCE = 1;
+    public const NULL_ON_INVALID_REFERENCE = 2;
+    public const IGNORE_ON_INVALID_REFERENCE = 3;
+    public const IGNORE_ON_UNINITIALIZED_REFERENCE = 4;
+
+    public function set(string $id, ?object $service): void;
+
+    public function get(string $id, int $invalidBehavior = self::EXCEPTION_ON_INVALID_REFERENCE): ?object;
+
+    public function has(string $id): bool;
+
+    public function initialized(string $id): bool;
+
+    public function getParameter(string $name): array|bool|string|int|float|\UnitEnum|null;
+
+    public function hasParameter(string $name): bool;
+
+    public function setParameter(string $name, array|bool|string|int|float|\UnitEnum|null $value): void;
+}
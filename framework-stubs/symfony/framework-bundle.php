@@ -0,0 +1,14 @@
+<?php
+
+namespace Symfony\Bundle\FrameworkBundle\Controller;
+
+use Symfony\Component\DependencyInjection\ContainerInterface;
+
+abstract class AbstractController
+{
+    protected ContainerInterface $container;
+
+    public function setContainer(ContainerInterface $container): ?ContainerInterface {}
+
+    protected function getParameter(string $name): array|bool|string|int|float|\UnitEnum|null {}
+}
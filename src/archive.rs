@@ -0,0 +1,308 @@
+use std::io::Cursor;
+use std::io::Read;
+use std::path::Path;
+
+use ahash::HashSet;
+use flate2::read::DeflateDecoder;
+
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+use mago_source::encoding::SourceEncoding;
+
+/// The extensions of the archives whose files can be included as external sources.
+const ARCHIVE_EXTENSIONS: [&str; 2] = ["phar", "zip"];
+
+/// The token ending the stub of a PHAR archive, followed by its manifest.
+const HALT_COMPILER: &[u8] = b"__HALT_COMPILER();";
+
+/// The flag of a PHAR entry compressed with deflate.
+const PHAR_ENTRY_DEFLATE: u32 = 0x1000;
+
+/// The flag of a PHAR entry compressed with bzip2.
+const PHAR_ENTRY_BZIP2: u32 = 0x2000;
+
+/// Determines whether the given file is an archive whose files can be included as external sources.
+pub fn is_archive(path: &Path) -> bool {
+    path.extension().and_then(|extension| extension.to_str()).is_some_and(|extension| {
+        ARCHIVE_EXTENSIONS.iter().any(|archive_extension| extension.eq_ignore_ascii_case(archive_extension))
+    })
+}
+
+/// Inserts the files of the given `.phar` or `.zip` archive with an accepted extension into the manager,
+/// as external sources named after the archive, e.g. `tools/phpunit.phar/src/TestCase.php`.
+///
+/// PHAR archives in the native and zip formats are supported. As external sources are never reported,
+/// an archive, or an entry, that cannot be read is only skipped.
+///
+/// # Arguments
+///
+/// * `manager` - The source manager to insert the files into.
+/// * `path` - The path of the archive.
+/// * `name` - The name of the archive, i.e. its path relative to the root.
+/// * `extensions` - The extensions of the files to insert.
+pub fn insert_archive(manager: &SourceManager, path: &Path, name: &str, extensions: &HashSet<&str>) {
+    let entries = match std::fs::read(path) {
+        Ok(content) if content.starts_with(b"PK\x03\x04") => read_zip(&content),
+        Ok(content) => read_phar(&content),
+        Err(error) => Err(error.to_string()),
+    };
+
+    let entries = match entries {
+        Ok(entries) => entries,
+        Err(message) => {
+            tracing::warn!("Skipping archive `{}`, it cannot be read: {}.", name, message);
+
+            return;
+        }
+    };
+
+    let mut inserted = 0;
+    for (entry, content) in entries {
+        let is_accepted = Path::new(&entry)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| extensions.contains(extension));
+
+        if !is_accepted {
+            continue;
+        }
+
+        let (content, _) = SourceEncoding::decode(content);
+        manager.insert_content(
+            format!("{}/{}", name, entry.trim_start_matches('/')),
+            content,
+            SourceCategory::External,
+        );
+        inserted += 1;
+    }
+
+    tracing::debug!("Included {} files of archive `{}`.", inserted, name);
+}
+
+/// Reads the files of a `.zip` archive, or of a PHAR archive in the zip format.
+fn read_zip(content: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(content)).map_err(|error| error.to_string())?;
+
+    let mut entries = Vec::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).map_err(|error| error.to_string())?;
+        if !file.is_file() {
+            continue;
+        }
+
+        // The size is read from the archive, so it is not trusted beyond the size of the archive itself.
+        let mut content = Vec::with_capacity((file.size() as usize).min(content.len()));
+        if let Err(error) = file.read_to_end(&mut content) {
+            tracing::debug!("Skipping entry `{}` of the archive: {}.", file.name(), error);
+
+            continue;
+        }
+
+        entries.push((file.name().to_string(), content));
+    }
+
+    Ok(entries)
+}
+
+/// Reads the files of a PHAR archive in the native format.
+///
+/// The archive starts with a PHP stub, ending with `__HALT_COMPILER();`, followed by the manifest listing the files,
+/// and by the content of the files, in the order of the manifest.
+fn read_phar(content: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let halt = content
+        .windows(HALT_COMPILER.len())
+        .position(|window| window == HALT_COMPILER)
+        .ok_or_else(|| "the `__HALT_COMPILER();` token of the stub was not found".to_string())?;
+
+    let mut offset = halt + HALT_COMPILER.len();
+    for terminator in [b" ?>".as_slice(), b"?>"] {
+        if content[offset..].starts_with(terminator) {
+            offset += terminator.len();
+
+            break;
+        }
+    }
+
+    for newline in [b"\r\n".as_slice(), b"\n"] {
+        if content[offset..].starts_with(newline) {
+            offset += newline.len();
+
+            break;
+        }
+    }
+
+    let mut reader = PharReader { content, offset };
+    let manifest_length = reader.read_u32()? as usize;
+    let mut data_offset = reader.offset.saturating_add(manifest_length);
+
+    let count = reader.read_u32()?;
+    reader.skip(2 + 4)?; // The API version, and the global flags.
+    let alias_length = reader.read_u32()? as usize;
+    reader.skip(alias_length)?;
+    let metadata_length = reader.read_u32()? as usize;
+    reader.skip(metadata_length)?;
+
+    let mut entries = Vec::new();
+    for _ in 0..count {
+        let name_length = reader.read_u32()? as usize;
+        let name = String::from_utf8_lossy(reader.read(name_length)?).into_owned();
+        let size = reader.read_u32()? as usize;
+        reader.skip(4)?; // The timestamp.
+        let compressed_size = reader.read_u32()? as usize;
+        reader.skip(4)?; // The CRC32 checksum.
+        let flags = reader.read_u32()?;
+        let metadata_length = reader.read_u32()? as usize;
+        reader.skip(metadata_length)?;
+
+        let data_end = data_offset.saturating_add(compressed_size);
+        let data = content
+            .get(data_offset..data_end)
+            .ok_or_else(|| format!("the content of entry `{}` is truncated", name))?;
+        data_offset = data_end;
+
+        if flags & PHAR_ENTRY_BZIP2 != 0 {
+            tracing::debug!("Skipping entry `{}` of the archive, bzip2 compression is not supported.", name);
+
+            continue;
+        }
+
+        if flags & PHAR_ENTRY_DEFLATE != 0 {
+            // The size is read from the manifest, so it is not trusted beyond the size of the archive itself.
+            let mut decompressed = Vec::with_capacity(size.min(content.len()));
+            if let Err(error) = DeflateDecoder::new(data).read_to_end(&mut decompressed) {
+                tracing::debug!("Skipping entry `{}` of the archive: {}.", name, error);
+
+                continue;
+            }
+
+            entries.push((name, decompressed));
+        } else {
+            entries.push((name, data.to_vec()));
+        }
+    }
+
+    Ok(entries)
+}
+
+/// A cursor over the little-endian manifest of a PHAR archive.
+struct PharReader<'a> {
+    content: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> PharReader<'a> {
+    fn read(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self.offset.saturating_add(length);
+        let bytes =
+            self.content.get(self.offset..end).ok_or_else(|| "the manifest of the archive is truncated".to_string())?;
+        self.offset = end;
+
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, String> {
+        let bytes = self.read(4)?;
+
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn skip(&mut self, length: usize) -> Result<(), String> {
+        self.read(length).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::Compression;
+    use flate2::write::DeflateEncoder;
+
+    use super::*;
+
+    /// Builds a PHAR archive in the native format, with the given entries, and their flags.
+    fn build_phar(entries: &[(&str, &[u8], u32, u32)]) -> Vec<u8> {
+        let mut manifest = vec![];
+        manifest.extend((entries.len() as u32).to_le_bytes());
+        manifest.extend(0x1100u16.to_le_bytes());
+        manifest.extend(0u32.to_le_bytes());
+        manifest.extend(0u32.to_le_bytes()); // The alias.
+        manifest.extend(0u32.to_le_bytes()); // The metadata.
+
+        let mut data: Vec<u8> = vec![];
+        for (name, content, size, flags) in entries {
+            manifest.extend((name.len() as u32).to_le_bytes());
+            manifest.extend(name.as_bytes());
+            manifest.extend(size.to_le_bytes());
+            manifest.extend(0u32.to_le_bytes());
+            manifest.extend((content.len() as u32).to_le_bytes());
+            manifest.extend(0u32.to_le_bytes());
+            manifest.extend(flags.to_le_bytes());
+            manifest.extend(0u32.to_le_bytes());
+
+            data.extend_from_slice(content);
+        }
+
+        let mut phar = b"<?php __HALT_COMPILER(); ?>\r\n".to_vec();
+        phar.extend((manifest.len() as u32).to_le_bytes());
+        phar.extend(manifest);
+        phar.extend(data);
+        phar
+    }
+
+    fn deflate(content: &[u8]) -> Vec<u8> {
+        let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+        encoder.write_all(content).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_read_phar() {
+        let compressed = deflate(b"<?php echo 2;");
+        let phar = build_phar(&[
+            ("src/a.php", b"<?php echo 1;", 13, 0),
+            ("src/b.php", &compressed, 13, PHAR_ENTRY_DEFLATE),
+            ("src/c.php", b"BZh", 3, PHAR_ENTRY_BZIP2),
+        ]);
+
+        let entries = read_phar(&phar).unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                ("src/a.php".to_string(), b"<?php echo 1;".to_vec()),
+                ("src/b.php".to_string(), b"<?php echo 2;".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_phar_does_not_trust_the_entry_size() {
+        let compressed = deflate(b"<?php echo 1;");
+        let phar = build_phar(&[("a.php", &compressed, u32::MAX, PHAR_ENTRY_DEFLATE)]);
+
+        let entries = read_phar(&phar).unwrap();
+
+        assert_eq!(entries, vec![("a.php".to_string(), b"<?php echo 1;".to_vec())]);
+    }
+
+    #[test]
+    fn test_read_truncated_phar() {
+        let phar = build_phar(&[("src/a.php", b"<?php echo 1;", 13, 0)]);
+
+        let error = read_phar(&phar[..phar.len() - 1]).unwrap_err();
+        assert_eq!(error, "the content of entry `src/a.php` is truncated");
+
+        for length in [phar.len() - 20, 40, 31] {
+            let error = read_phar(&phar[..length]).unwrap_err();
+            assert_eq!(error, "the manifest of the archive is truncated");
+        }
+    }
+
+    #[test]
+    fn test_read_phar_without_stub() {
+        let error = read_phar(b"<?php echo 1;").unwrap_err();
+
+        assert_eq!(error, "the `__HALT_COMPILER();` token of the stub was not found");
+    }
+}
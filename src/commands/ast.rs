@@ -2,13 +2,16 @@ use std::process::ExitCode;
 
 use clap::Parser;
 use serde_json::json;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantNames;
 use termtree::Tree;
 
 use mago_ast::Node;
 use mago_ast::node::NodeKind;
 use mago_interner::ThreadedInterner;
 use mago_names::Names;
-use mago_parser::parse_source;
+use mago_parser::parse_source_with_settings;
 use mago_reporting::Issue;
 use mago_reporting::reporter::Reporter;
 use mago_reporting::reporter::ReportingFormat;
@@ -16,8 +19,10 @@ use mago_reporting::reporter::ReportingTarget;
 use mago_source::SourceCategory;
 use mago_source::SourceManager;
 
+use crate::config::Configuration;
 use crate::enum_variants;
 use crate::error::Error;
+use crate::utils::color;
 
 /// Represents the `ast` command, which parses a PHP file and prints its abstract syntax tree (AST).
 #[derive(Parser, Debug)]
@@ -28,19 +33,36 @@ use crate::error::Error;
 The `ast` command parses a PHP file and outputs its abstract syntax tree (AST).
 
 This command helps you understand the structure of your PHP code and debug parsing issues.
+
+Use `--format=json` to dump the full AST, including spans, for use by rule authors and external tooling,
+or `--format=dot` to emit a Graphviz graph that can be rendered with `dot -Tsvg`.
 "#
 )]
 pub struct AstCommand {
     /// Path to the PHP file to be parsed.
-    #[arg(long, short = 'f', help = "Specify the PHP file to parse", required = true)]
-    pub file: String,
+    #[arg(help = "Specify the PHP file to parse", required_unless_present = "deprecated_file")]
+    pub file: Option<String>,
+
+    /// Path to the PHP file to be parsed, kept for backward compatibility with the positional argument.
+    #[arg(long = "file", short = 'f', hide = true, value_name = "FILE", conflicts_with = "file")]
+    pub deprecated_file: Option<String>,
 
     /// Include resolved names in the output.
     #[arg(long, help = "Include resolved names in the output to show symbol resolution")]
     pub include_names: bool,
 
-    /// Output the AST in JSON format for integration with other tools.
-    #[arg(long, help = "Output the AST in JSON format")]
+    /// The format in which the AST should be printed.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Choose the output format for the AST",
+        ignore_case = true,
+        value_parser = enum_variants!(AstFormat)
+    )]
+    pub format: AstFormat,
+
+    /// Output the AST in JSON format, kept for backward compatibility with `--format=json`.
+    #[arg(long, hide = true)]
     pub json: bool,
 
     /// Specify where the results should be reported.
@@ -64,11 +86,25 @@ pub struct AstCommand {
     pub reporting_format: ReportingFormat,
 }
 
+/// The output format of the `ast` command.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Display, EnumString, VariantNames)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum AstFormat {
+    /// Print the AST as a human-readable tree.
+    #[default]
+    Tree,
+    /// Print the AST as JSON.
+    Json,
+    /// Print the AST as a Graphviz DOT graph.
+    Dot,
+}
+
 /// Executes the AST command with the provided options.
 ///
 /// # Arguments
 ///
 /// * `command` - The `AstCommand` structure containing user-specified options.
+/// * `configuration` - The configuration, providing the settings to parse the file with.
 ///
 /// # Returns
 ///
@@ -77,18 +113,31 @@ pub struct AstCommand {
 /// # Errors
 ///
 /// An error is returned if the file does not exist or is not readable.
-pub async fn execute(command: AstCommand) -> Result<ExitCode, Error> {
-    let file_path = std::path::Path::new(&command.file).to_path_buf();
+pub async fn execute(command: AstCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let file = match (command.file, command.deprecated_file) {
+        (Some(file), _) => file,
+        (None, Some(file)) => {
+            tracing::warn!(
+                "The `--file` option is deprecated, pass the file as an argument instead: `mago ast {}`.",
+                file
+            );
+
+            file
+        }
+        (None, None) => unreachable!("the file is required by the command line parser"),
+    };
+
+    let file_path = std::path::Path::new(&file).to_path_buf();
 
     // Verify if the file exists and is readable.
     if !file_path.exists() {
-        tracing::error!("File '{}' does not exist.", command.file);
+        tracing::error!("File '{}' does not exist.", file);
 
         return Ok(ExitCode::FAILURE);
     }
 
     if !file_path.is_file() {
-        tracing::error!("The path '{}' is not a file.", command.file);
+        tracing::error!("The path '{}' is not a file.", file);
 
         return Ok(ExitCode::FAILURE);
     }
@@ -98,44 +147,82 @@ pub async fn execute(command: AstCommand) -> Result<ExitCode, Error> {
     let source_manager = SourceManager::new(interner.clone());
 
     // Load the source file.
-    let source_id = source_manager.insert_path(command.file.clone(), file_path, SourceCategory::UserDefined);
+    let source_id = source_manager.insert_path(file, file_path, SourceCategory::UserDefined);
     let source = source_manager.load(&source_id)?;
 
     // Parse the source file into an AST.
-    let (ast, error) = parse_source(&interner, &source);
+    let (ast, error) = parse_source_with_settings(&interner, &source, configuration.parser_settings());
 
     let has_error = error.is_some();
-    if command.json {
-        // Prepare and display JSON output.
-        let result = json!({
-            "interner": interner.all().into_iter().collect::<Vec<_>>(),
-            "program": ast,
-            "error": error.map(|e| Into::<Issue>::into(&e)),
-        });
+    let format = if command.json { AstFormat::Json } else { command.format };
 
-        println!("{}", serde_json::to_string_pretty(&result)?);
-    } else {
-        // Display the AST as a tree.
-        let tree = node_to_tree(Node::Program(&ast));
+    match format {
+        AstFormat::Json => {
+            let names = if command.include_names {
+                let names = Names::resolve(&interner, &ast);
+                let mut names = names
+                    .all()
+                    .into_iter()
+                    .map(|(position, (value, is_imported))| {
+                        json!({
+                            "offset": position,
+                            "name": interner.lookup(value),
+                            "imported": is_imported,
+                        })
+                    })
+                    .collect::<Vec<_>>();
 
-        println!("{tree}");
+                names.sort_by_key(|name| name["offset"].as_u64());
 
-        if command.include_names {
-            let names = Names::resolve(&interner, &ast);
+                Some(names)
+            } else {
+                None
+            };
 
-            for (position, (value, is_imported)) in names.all() {
-                let name = interner.lookup(value);
+            // Prepare and display JSON output.
+            let result = json!({
+                "interner": interner.all().into_iter().collect::<Vec<_>>(),
+                "program": ast,
+                "names": names,
+                "error": error.map(|e| Into::<Issue>::into(&e)),
+            });
 
-                println!("{}: {}{}", position, name, if *is_imported { " (imported)" } else { "" });
-            }
+            println!("{}", serde_json::to_string_pretty(&result)?);
         }
+        AstFormat::Dot => {
+            let mut dot = String::from("digraph AST {\n    node [shape=box, fontname=\"monospace\"];\n");
+            let mut counter = 0;
+
+            node_to_dot(Node::Program(&ast), &mut dot, &mut counter);
+
+            dot.push_str("}\n");
+
+            print!("{dot}");
+        }
+        AstFormat::Tree => {
+            // Display the AST as a tree.
+            let tree = node_to_tree(Node::Program(&ast));
+
+            println!("{tree}");
 
-        // Report errors if any exist.
-        if let Some(error) = &error {
-            let issue = Into::<Issue>::into(error);
+            if command.include_names {
+                let names = Names::resolve(&interner, &ast);
 
-            Reporter::new(interner.clone(), source_manager, command.reporting_target)
-                .report([issue], command.reporting_format)?;
+                for (position, (value, is_imported)) in names.all() {
+                    let name = interner.lookup(value);
+
+                    println!("{}: {}{}", position, name, if *is_imported { " (imported)" } else { "" });
+                }
+            }
+
+            // Report errors if any exist.
+            if let Some(error) = &error {
+                let issue = Into::<Issue>::into(error);
+
+                Reporter::new(interner.clone(), source_manager, command.reporting_target)
+                    .with_colors(color::is_enabled(command.reporting_target))
+                    .report([issue], command.reporting_format)?;
+            }
         }
     }
 
@@ -159,3 +246,29 @@ fn node_to_tree(node: Node<'_>) -> Tree<NodeKind> {
 
     tree
 }
+
+/// Writes an AST node and its children into a Graphviz DOT graph.
+///
+/// # Arguments
+///
+/// * `node` - The AST node to be written.
+/// * `dot` - The buffer holding the DOT graph.
+/// * `counter` - The counter used to assign unique identifiers to graph nodes.
+///
+/// # Returns
+///
+/// The identifier of the graph node representing the given AST node.
+fn node_to_dot(node: Node<'_>, dot: &mut String, counter: &mut usize) -> usize {
+    let id = *counter;
+    *counter += 1;
+
+    dot.push_str(&format!("    n{} [label=\"{}\"];\n", id, node.kind()));
+
+    for child in node.children() {
+        let child_id = node_to_dot(child, dot, counter);
+
+        dot.push_str(&format!("    n{} -> n{};\n", id, child_id));
+    }
+
+    id
+}
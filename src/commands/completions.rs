@@ -0,0 +1,53 @@
+use std::io::Write;
+use std::process::ExitCode;
+
+use clap::CommandFactory;
+use clap::Parser;
+use clap_complete::Shell;
+
+use crate::commands::MagoCli;
+use crate::consts::BIN;
+use crate::error::Error;
+
+/// Represents the `completions` command, which prints a shell completion script.
+#[derive(Parser, Debug)]
+#[command(
+    name = "completions",
+    about = "Generate a shell completion script",
+    long_about = r#"
+The `completions` command prints a completion script for the given shell to stdout.
+
+The script is generated from the installed version of Mago, so it always matches its commands and flags.
+
+For example, to enable completions in bash, add the following to your `~/.bashrc`:
+
+    eval "$(mago completions bash)"
+"#
+)]
+pub struct CompletionsCommand {
+    /// The shell to generate the completion script for.
+    #[arg(help = "The shell to generate the completion script for", ignore_case = true)]
+    pub shell: Shell,
+}
+
+/// Executes the `completions` command.
+///
+/// # Arguments
+///
+/// * `command` - The `CompletionsCommand` structure containing user-specified options.
+///
+/// # Returns
+///
+/// An `ExitCode` indicating the success of the command.
+///
+/// # Errors
+///
+/// An error is returned if the script could not be written to stdout.
+pub fn execute(command: CompletionsCommand) -> Result<ExitCode, Error> {
+    let mut script = Vec::new();
+    clap_complete::generate(command.shell, &mut MagoCli::command(), BIN, &mut script);
+
+    std::io::stdout().write_all(&script).map_err(Error::WritingOutput)?;
+
+    Ok(ExitCode::SUCCESS)
+}
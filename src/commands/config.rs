@@ -0,0 +1,597 @@
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+use clap::Subcommand;
+use config::Config;
+use config::ConfigError;
+use config::Value as ConfigValue;
+use config::ValueKind;
+use serde_json::Value as JsonValue;
+use toml_edit::ImDocument;
+use toml_edit::Item;
+use toml_edit::TableLike;
+
+use mago_interner::ThreadedInterner;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::reporter::Reporter;
+use mago_reporting::reporter::ReportingFormat;
+use mago_reporting::reporter::ReportingTarget;
+use mago_source::SourceCategory;
+use mago_source::SourceIdentifier;
+use mago_source::SourceManager;
+use mago_span::Position;
+use mago_span::Span;
+
+use crate::config::Configuration;
+use crate::config::overrides::ConfigurationOverride;
+use crate::consts::*;
+use crate::enum_variants;
+use crate::error::Error;
+use crate::source;
+use crate::utils::color;
+
+/// The minimum similarity for a known key to be suggested in place of an unknown one.
+const SUGGESTION_THRESHOLD: f64 = 0.8;
+
+/// The maximum length of the lines whose origin comments are aligned by `config show`.
+const MAXIMUM_ALIGNMENT: usize = 60;
+
+/// Represents the `config` command, which helps writing the configuration file.
+#[derive(Parser, Debug)]
+#[command(
+    name = "config",
+    about = "Validate the configuration file, or export its schema",
+    long_about = r#"
+The `config` command helps writing the `mago.toml` configuration file.
+
+Use `mago config validate` to check the configuration file for syntax errors, unknown options,
+and invalid values, each reported at its location in the file.
+
+Use `mago config schema` to print the JSON Schema of the configuration file, which editors can use
+to provide autocompletion and documentation while editing it, e.g. through the `taplo` language server.
+
+Use `mago config show` to print the configuration as resolved from the defaults, the configuration file,
+and the environment, along with where each value came from. With `--for <FILE>`, it also explains whether
+the given file is analyzed and formatted.
+"#
+)]
+pub struct ConfigCommand {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigAction {
+    /// Validate the configuration file.
+    #[command(about = "Check the configuration file for unknown options and invalid values")]
+    Validate {
+        /// The configuration file to validate, defaults to `mago.toml` in the current directory.
+        #[arg(help = "The configuration file to validate, defaults to `mago.toml`")]
+        file: Option<PathBuf>,
+
+        /// Specify where the results should be reported.
+        #[arg(
+            long,
+            default_value_t,
+            help = "Specify where the results should be reported",
+            ignore_case = true,
+            value_parser = enum_variants!(ReportingTarget)
+        )]
+        reporting_target: ReportingTarget,
+
+        /// Choose the format for reporting issues.
+        #[arg(
+            long,
+            default_value_t,
+            help = "Choose the format for reporting issues",
+            ignore_case = true,
+            value_parser = enum_variants!(ReportingFormat)
+        )]
+        reporting_format: ReportingFormat,
+    },
+    /// Print the JSON Schema of the configuration file.
+    #[command(about = "Print the JSON Schema of the configuration file")]
+    Schema,
+    /// Print the resolved configuration, and where each value came from.
+    #[command(about = "Print the resolved configuration, and where each value came from")]
+    Show {
+        /// A file to explain how the configuration applies to.
+        #[arg(
+            long = "for",
+            value_name = "FILE",
+            help = "Also explain how the configuration applies to the given file"
+        )]
+        file: Option<PathBuf>,
+    },
+}
+
+/// Executes the `config` command.
+///
+/// # Arguments
+///
+/// * `command` - The `ConfigCommand` structure containing user-specified options.
+/// * `overrides` - The configuration overrides given on the command line.
+///
+/// # Returns
+///
+/// An `ExitCode` indicating whether the configuration file is valid.
+///
+/// # Errors
+///
+/// An error is returned if the configuration file could not be read, or the results could not be reported.
+pub fn execute(command: ConfigCommand, overrides: &[ConfigurationOverride]) -> Result<ExitCode, Error> {
+    match command.action {
+        ConfigAction::Validate { file, reporting_target, reporting_format } => {
+            let path = file.unwrap_or_else(|| CURRENT_DIR.join(CONFIGURATION_FILE));
+
+            validate(path, reporting_target, reporting_format)
+        }
+        ConfigAction::Schema => {
+            writeln!(std::io::stdout(), "{:#}", Configuration::schema()).map_err(Error::WritingOutput)?;
+
+            Ok(ExitCode::SUCCESS)
+        }
+        ConfigAction::Show { file } => show(file, overrides),
+    }
+}
+
+fn validate(
+    path: PathBuf,
+    reporting_target: ReportingTarget,
+    reporting_format: ReportingFormat,
+) -> Result<ExitCode, Error> {
+    let content = std::fs::read_to_string(&path).map_err(|error| Error::ReadingConfiguration(path.clone(), error))?;
+
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+    let source = manager.insert_content(path.to_string_lossy(), &content, SourceCategory::UserDefined);
+
+    let issues = get_issues(source, &content);
+    if issues.is_empty() {
+        tracing::info!("The configuration file `{}` is valid.", path.display());
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    Reporter::new(interner, manager, reporting_target)
+        .with_colors(color::is_enabled(reporting_target))
+        .report(issues, reporting_format)?;
+
+    Ok(ExitCode::FAILURE)
+}
+
+fn show(file: Option<PathBuf>, overrides: &[ConfigurationOverride]) -> Result<ExitCode, Error> {
+    let config = Configuration::build(overrides)?;
+    let sources = config.cache.clone();
+
+    // The linter and formatter settings are resolved without the framework profile as well, to tell which
+    // values the profile changed. Neither section is normalized, so deserializing them is enough.
+    let mut baseline = config.clone().try_deserialize::<Configuration>()?;
+    baseline.framework = None;
+
+    let configuration = Configuration::from_config(config)?;
+
+    let mut output = vec![];
+    if let Some(file) = file {
+        for line in explain_file(&configuration, &file)? {
+            output.push(format!("# {}", line));
+        }
+    }
+
+    let mut baseline_entries = vec![];
+    collect_entries(&resolve(&baseline)?, "", &mut baseline_entries);
+
+    let mut entries = vec![];
+    collect_entries(&resolve(&configuration)?, "", &mut entries);
+
+    let mut sections: Vec<(String, Vec<(String, String)>)> = vec![(String::new(), vec![])];
+    for (key, value) in entries {
+        let baseline = baseline_entries.iter().find(|(baseline, _)| *baseline == key).map(|(_, value)| value.as_str());
+        let origin = describe_origin(&configuration, &sources, &key, &value, baseline);
+        let (section, name) = key.split_once('.').unwrap_or(("", &key));
+
+        let line = format!("{} = {}", name, value);
+        match sections.iter_mut().find(|(existing, _)| *existing == section) {
+            Some((_, lines)) => lines.push((line, origin)),
+            None => sections.push((section.to_string(), vec![(line, origin)])),
+        }
+    }
+
+    for (section, lines) in sections {
+        if lines.is_empty() {
+            continue;
+        }
+
+        if !output.is_empty() {
+            output.push(String::new());
+        }
+
+        if !section.is_empty() {
+            output.push(format!("[{}]", section));
+        }
+
+        let width = lines.iter().map(|(line, _)| line.len()).filter(|width| *width <= MAXIMUM_ALIGNMENT).max();
+        for (line, origin) in lines {
+            output.push(format!("{:width$} # {}", line, origin, width = width.unwrap_or(0)));
+        }
+    }
+
+    writeln!(std::io::stdout(), "{}", output.join("\n")).map_err(Error::WritingOutput)?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Describes whether the given file is analyzed and formatted as per the given configuration.
+fn explain_file(configuration: &Configuration, file: &Path) -> Result<Vec<String>, Error> {
+    let path = CURRENT_DIR.join(file);
+    let path = path.canonicalize().map_err(|error| Error::CanonicalizingPath(path, error))?;
+    let name = path.strip_prefix(&configuration.source.root).unwrap_or(&path).display().to_string();
+
+    let mut lines = vec![match source::get_category(&configuration.source, &path) {
+        Some(SourceCategory::UserDefined) => format!("`{}` is analyzed as a project source file.", name),
+        Some(_) => format!("`{}` is analyzed as an external source file, e.g. a dependency, and not linted.", name),
+        None => format!(
+            "`{}` is not analyzed, as it is excluded, outside of the source paths, or has an unaccepted extension.",
+            name
+        ),
+    }];
+
+    let mut formatting = configuration.source.clone();
+    formatting.includes.clear();
+    formatting.excludes.extend(configuration.format.excludes.iter().cloned());
+
+    lines.push(match source::get_category(&formatting, &path) {
+        Some(_) if configuration.source.includes.iter().any(|include| path.starts_with(include)) => {
+            format!("`{}` is not formatted, as it is an external source file.", name)
+        }
+        Some(_) => format!("`{}` is formatted using the `[format]` settings below.", name),
+        None => format!("`{}` is not formatted.", name),
+    });
+
+    lines.push("The configuration below applies to every file of the project.".to_string());
+
+    Ok(lines)
+}
+
+/// Converts the given configuration to a configuration value, including the formatter settings it resolves to.
+fn resolve(configuration: &Configuration) -> Result<ConfigValue, Error> {
+    let mut resolved = Config::try_from(configuration)?.cache;
+
+    // Formatter settings that are not configured are not serialized, so the settings they resolve to are shown instead.
+    let settings = Config::try_from(&configuration.format.get_settings())?.cache;
+    if let (ValueKind::Table(resolved), ValueKind::Table(settings)) = (&mut resolved.kind, settings.kind) {
+        if let Some(ValueKind::Table(format)) = resolved.get_mut("format").map(|format| &mut format.kind) {
+            for (key, value) in settings {
+                format.entry(key).or_insert(value);
+            }
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Flattens the given table into `(key, value)` entries, where values are formatted as TOML.
+fn collect_entries(value: &ConfigValue, prefix: &str, entries: &mut Vec<(String, String)>) {
+    let ValueKind::Table(table) = &value.kind else {
+        return;
+    };
+
+    let mut keys: Vec<&String> = table.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        let value = &table[key];
+        let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+
+        if let ValueKind::Table(_) = value.kind {
+            collect_entries(value, &path, entries);
+        } else if let Some(value) = to_toml(value) {
+            entries.push((path, value.to_string().trim().to_string()));
+        }
+    }
+}
+
+/// Describes where the resolved value of the given key came from.
+///
+/// The `baseline` is the value the key resolves to without the framework profile, if any.
+fn describe_origin(
+    configuration: &Configuration,
+    sources: &ConfigValue,
+    key: &str,
+    resolved: &str,
+    baseline: Option<&str>,
+) -> String {
+    let mut source = Some(sources);
+    for segment in key.split('.') {
+        source = source.and_then(|source| match &source.kind {
+            ValueKind::Table(table) => table.get(segment),
+            _ => None,
+        });
+    }
+
+    let origin = match source.and_then(ConfigValue::origin) {
+        Some(origin) => {
+            let path = Path::new(origin);
+
+            path.strip_prefix(&*CURRENT_DIR).unwrap_or(path).display().to_string()
+        }
+        None => "default".to_string(),
+    };
+
+    if let Some(framework) = configuration.framework {
+        if (key.starts_with("linter.") || key.starts_with("format.")) && baseline != Some(resolved) {
+            return format!("`{}` framework profile", framework);
+        }
+    }
+
+    // Values set in the environment or on the command line are strings, converted when the configuration is deserialized.
+    let normalized = source.is_some_and(|source| match (&source.kind, to_toml(source)) {
+        (ValueKind::String(raw), _) if raw == resolved => false,
+        (_, Some(source)) => source.to_string().trim() != resolved,
+        (_, None) => false,
+    });
+
+    if normalized { format!("{}, normalized", origin) } else { origin }
+}
+
+/// Converts the given configuration value to a TOML value, if it can be represented in TOML.
+fn to_toml(value: &ConfigValue) -> Option<toml_edit::Value> {
+    Some(match &value.kind {
+        ValueKind::Nil => return None,
+        ValueKind::Boolean(value) => toml_edit::Value::from(*value),
+        ValueKind::I64(value) => toml_edit::Value::from(*value),
+        ValueKind::I128(value) => toml_edit::Value::from(i64::try_from(*value).ok()?),
+        ValueKind::U64(value) => toml_edit::Value::from(i64::try_from(*value).ok()?),
+        ValueKind::U128(value) => toml_edit::Value::from(i64::try_from(*value).ok()?),
+        ValueKind::Float(value) => toml_edit::Value::from(*value),
+        ValueKind::String(value) => toml_edit::Value::from(value.as_str()),
+        ValueKind::Array(values) => toml_edit::Value::Array(values.iter().filter_map(to_toml).collect()),
+        ValueKind::Table(table) => {
+            let mut keys: Vec<&String> = table.keys().collect();
+            keys.sort();
+
+            toml_edit::Value::InlineTable(
+                keys.into_iter().filter_map(|key| Some((key.as_str(), to_toml(&table[key])?))).collect(),
+            )
+        }
+    })
+}
+
+/// Returns the issues found in the given configuration file content.
+///
+/// Unknown keys are all reported at once, using the schema of the configuration. Once there are none,
+/// the configuration is loaded, and the first invalid value found, if any, is reported.
+fn get_issues(source: SourceIdentifier, content: &str) -> Vec<Issue> {
+    let document = match ImDocument::parse(content) {
+        Ok(document) => document,
+        Err(error) => {
+            let issue = Issue::error(format!("Invalid TOML: {}", error.message().trim()));
+
+            return vec![match error.span() {
+                Some(range) => issue.with_annotation(Annotation::primary(get_span(source, range))),
+                None => issue,
+            }];
+        }
+    };
+
+    let schema = Configuration::schema();
+    let mut issues = vec![];
+    check_table(source, &schema, &schema, document.as_table(), "", &mut issues);
+
+    if !issues.is_empty() {
+        return issues;
+    }
+
+    match Configuration::from_toml(content) {
+        Ok(_) => vec![],
+        Err(Error::BuildingConfiguration(error)) => {
+            let (key, message) = describe_error(&error);
+            let issue = Issue::error(format!("Invalid configuration: {}", message));
+
+            let range = key.and_then(|key| find_key_range(document.as_table(), key));
+            vec![match (range, key) {
+                (Some(range), _) => issue.with_annotation(
+                    Annotation::primary(get_span(source, range))
+                        .with_message(format!("Invalid value for `{}`", format_key(key.unwrap_or_default()))),
+                ),
+                (None, Some(key)) => issue.with_note(format!("The invalid value is located at `{}`.", format_key(key))),
+                (None, None) => issue,
+            }]
+        }
+        Err(error) => vec![Issue::error(format!("Invalid configuration: {}", error))],
+    }
+}
+
+/// Reports the keys of the given table that are not described by the given schema, recursively.
+fn check_table(
+    source: SourceIdentifier,
+    root: &JsonValue,
+    schema: &JsonValue,
+    table: &dyn TableLike,
+    path: &str,
+    issues: &mut Vec<Issue>,
+) {
+    let schema = resolve_schema(root, schema);
+    let properties = schema.get("properties").and_then(JsonValue::as_object);
+    let additional = schema.get("additionalProperties");
+
+    for (name, item) in table.iter() {
+        let key_path = if path.is_empty() { name.to_string() } else { format!("{}.{}", path, name) };
+
+        let property = match properties.and_then(|properties| properties.get(name)) {
+            Some(property) => property,
+            None => match additional {
+                Some(JsonValue::Bool(false)) => {
+                    let mut issue = Issue::error(format!("Unknown configuration key `{}`.", key_path));
+
+                    let span = table.get_key_value(name).and_then(|(key, _)| key.span());
+                    if let Some(range) = span {
+                        issue = issue.with_annotation(
+                            Annotation::primary(get_span(source, range)).with_message("This key is not recognized"),
+                        );
+                    }
+
+                    let suggestion = properties.and_then(|properties| {
+                        properties
+                            .keys()
+                            .map(|candidate| (strsim::jaro_winkler(name, candidate), candidate))
+                            .filter(|(similarity, _)| *similarity >= SUGGESTION_THRESHOLD)
+                            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+                    });
+
+                    issues.push(match suggestion {
+                        Some((_, candidate)) => issue.with_help(format!("Did you mean `{}`?", candidate)),
+                        None => {
+                            issue.with_help("Remove this key, or check the documentation for the available options.")
+                        }
+                    });
+
+                    continue;
+                }
+                Some(schema @ JsonValue::Object(_)) => schema,
+                _ => continue,
+            },
+        };
+
+        check_item(source, root, property, item, &key_path, issues);
+    }
+}
+
+fn check_item(
+    source: SourceIdentifier,
+    root: &JsonValue,
+    schema: &JsonValue,
+    item: &Item,
+    path: &str,
+    issues: &mut Vec<Issue>,
+) {
+    if let Some(table) = item.as_table_like() {
+        check_table(source, root, schema, table, path, issues);
+
+        return;
+    }
+
+    let schema = resolve_schema(root, schema);
+    let Some(items) = schema.get("items") else {
+        return;
+    };
+
+    let elements: Vec<Item> = match item {
+        Item::ArrayOfTables(array) => array.iter().cloned().map(Item::Table).collect(),
+        Item::Value(toml_edit::Value::Array(array)) => array.iter().cloned().map(Item::Value).collect(),
+        _ => return,
+    };
+
+    for (index, element) in elements.iter().enumerate() {
+        check_item(source, root, items, element, &format!("{}[{}]", path, index), issues);
+    }
+}
+
+/// Resolves references and optional wrappers of the given schema, e.g. `{ "anyOf": [{ "$ref": ... }, { "type": "null" }] }`.
+fn resolve_schema<'a>(root: &'a JsonValue, schema: &'a JsonValue) -> &'a JsonValue {
+    if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+        let resolved = reference
+            .strip_prefix("#/definitions/")
+            .and_then(|name| root.get("definitions").and_then(|definitions| definitions.get(name)));
+
+        return match resolved {
+            Some(resolved) => resolve_schema(root, resolved),
+            None => schema,
+        };
+    }
+
+    for combinator in ["allOf", "anyOf", "oneOf"] {
+        let Some(variants) = schema.get(combinator).and_then(JsonValue::as_array) else {
+            continue;
+        };
+
+        let variant = variants.iter().find(|variant| variant.get("type").and_then(JsonValue::as_str) != Some("null"));
+        if let Some(variant) = variant {
+            return resolve_schema(root, variant);
+        }
+    }
+
+    schema
+}
+
+/// Returns the key of the value that caused the given error, if known, along with a description of the error.
+fn describe_error(error: &ConfigError) -> (Option<&str>, String) {
+    match error {
+        ConfigError::Type { unexpected, expected, key, .. } => {
+            (key.as_deref(), format!("invalid type: {}, expected {}", unexpected, expected))
+        }
+        ConfigError::At { error, key, .. } => (key.as_deref(), describe_error(error).1),
+        error => (None, error.to_string()),
+    }
+}
+
+/// Splits the given key path, e.g. `linter.rules[0]level`, into its names and indices.
+fn get_key_segments(key: &str) -> Vec<(&str, Option<usize>)> {
+    key.split(['.', '[', ']'])
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| match segment.parse::<usize>() {
+            Ok(index) => ("", Some(index)),
+            Err(_) => (segment, None),
+        })
+        .collect()
+}
+
+/// Formats the given key path for display, e.g. `linter.rules[0]level` as `linter.rules[0].level`.
+fn format_key(key: &str) -> String {
+    let mut formatted = String::new();
+    for (name, index) in get_key_segments(key) {
+        match index {
+            Some(index) => formatted.push_str(&format!("[{}]", index)),
+            None if formatted.is_empty() => formatted.push_str(name),
+            None => formatted.push_str(&format!(".{}", name)),
+        }
+    }
+
+    formatted
+}
+
+/// Finds the range of the value at the given key path, e.g. `linter.rules[0]level`, in the given table.
+fn find_key_range(table: &dyn TableLike, key: &str) -> Option<std::ops::Range<usize>> {
+    let segments = get_key_segments(key);
+
+    let mut current: Option<Item> = None;
+    let mut range = None;
+    for (name, index) in segments {
+        let next = match (index, &current) {
+            (None, None) => table.get_key_value(name).map(|(key, item)| (key.span(), item.clone())),
+            (None, Some(item)) => {
+                item.as_table_like()?.get_key_value(name).map(|(key, item)| (key.span(), item.clone()))
+            }
+            (Some(index), Some(Item::ArrayOfTables(array))) => {
+                array.get(index).map(|table| (table.span(), Item::Table(table.clone())))
+            }
+            (Some(index), Some(Item::Value(toml_edit::Value::Array(array)))) => {
+                array.get(index).map(|value| (value.span(), Item::Value(value.clone())))
+            }
+            _ => None,
+        };
+
+        let (key_range, item) = next?;
+        range = get_item_range(&item).or(key_range);
+        current = Some(item);
+    }
+
+    range
+}
+
+fn get_item_range(item: &Item) -> Option<std::ops::Range<usize>> {
+    match item {
+        Item::Value(value) => value.span(),
+        Item::Table(table) => table.span(),
+        Item::ArrayOfTables(array) => array.span(),
+        Item::None => None,
+    }
+}
+
+fn get_span(source: SourceIdentifier, range: std::ops::Range<usize>) -> Span {
+    Span::new(Position::new(source, range.start), Position::new(source, range.end))
+}
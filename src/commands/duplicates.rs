@@ -0,0 +1,179 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use mago_duplicates::CloneGroup;
+use mago_duplicates::DuplicationDetector;
+use mago_duplicates::DuplicationSettings;
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source_with_settings;
+use mago_parser::settings::ParserSettings;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_reporting::reporter::Reporter;
+use mago_reporting::reporter::ReportingFormat;
+use mago_reporting::reporter::ReportingTarget;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+use crate::config::Configuration;
+use crate::enum_variants;
+use crate::error::Error;
+use crate::source;
+use crate::utils::color;
+use crate::utils::progress::ProgressBarTheme;
+use crate::utils::progress::create_progress_bar;
+use crate::utils::progress::remove_progress_bar;
+
+/// Represents the `duplicates` command, which detects copy-pasted code in the project.
+#[derive(Parser, Debug)]
+#[command(
+    name = "duplicates",
+    about = "Detect duplicated (copy-pasted) code in the project",
+    long_about = r#"
+The `duplicates` command detects copy-pasted code by comparing the normalized syntax trees
+of statements, blocks, and class members across the project.
+
+Fragments that are structurally identical are reported together as a clone group. By default,
+fragments must be identical to be reported; use `--ignore-identifiers` and `--ignore-literals`
+to also detect fragments that only differ in the names or literal values they use.
+
+Defaults for these options can be set in the `[duplicates]` section of `mago.toml`.
+"#
+)]
+pub struct DuplicatesCommand {
+    /// The minimum number of tokens a fragment must contain to be reported.
+    #[arg(long, help = "The minimum number of tokens a fragment must contain to be reported")]
+    pub minimum_tokens: Option<usize>,
+
+    /// Whether to ignore identifiers when comparing fragments.
+    #[arg(long, help = "Ignore identifiers (names and variables) when comparing fragments")]
+    pub ignore_identifiers: bool,
+
+    /// Whether to ignore literals when comparing fragments.
+    #[arg(long, help = "Ignore literals (strings and numbers) when comparing fragments")]
+    pub ignore_literals: bool,
+
+    /// Specify where the results should be reported (e.g. CLI, JSON, file).
+    #[arg(
+        long,
+        default_value_t,
+        help = "Specify where the results should be reported",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingTarget)
+    )]
+    pub reporting_target: ReportingTarget,
+
+    /// Choose the format (human-friendly, JSON, etc.) for reporting issues.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Choose the format for reporting issues",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingFormat)
+    )]
+    pub reporting_format: ReportingFormat,
+}
+
+/// Executes the duplicates command with the provided options.
+///
+/// # Arguments
+///
+/// * `command` - The `DuplicatesCommand` structure containing user-specified options.
+/// * `configuration` - The configuration containing the source and duplication settings.
+///
+/// # Returns
+///
+/// An `ExitCode` indicating whether duplicated code was found.
+pub async fn execute(command: DuplicatesCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+    let source_manager = source::load(&interner, &configuration.source, false, false).await?;
+
+    let mut settings = configuration.duplicates.get_settings();
+    if let Some(minimum_tokens) = command.minimum_tokens {
+        settings.minimum_tokens = minimum_tokens;
+    }
+
+    settings.ignore_identifiers |= command.ignore_identifiers;
+    settings.ignore_literals |= command.ignore_literals;
+
+    let groups = detect_duplicates(&interner, &source_manager, settings, configuration.parser_settings()).await?;
+    let found_duplicates = !groups.is_empty();
+
+    let mut issues = IssueCollection::new();
+    for group in groups {
+        issues.push(clone_group_to_issue(&source_manager, group)?);
+    }
+
+    Reporter::new(interner, source_manager, command.reporting_target)
+        .with_colors(color::is_enabled(command.reporting_target))
+        .report(issues, command.reporting_format)?;
+
+    Ok(if found_duplicates { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+/// Detects duplicated code across all user-defined sources.
+async fn detect_duplicates(
+    interner: &ThreadedInterner,
+    manager: &SourceManager,
+    settings: DuplicationSettings,
+    parser_settings: ParserSettings,
+) -> Result<Vec<CloneGroup>, Error> {
+    let sources: Vec<_> = manager.source_ids_for_category(SourceCategory::UserDefined);
+    let length = sources.len();
+
+    let progress_bar = create_progress_bar(length, "🔎  Scanning", ProgressBarTheme::Magenta);
+    let mut handles = Vec::with_capacity(length);
+    for source_id in sources {
+        handles.push(tokio::spawn({
+            let interner = interner.clone();
+            let manager = manager.clone();
+            let progress_bar = progress_bar.clone();
+
+            async move {
+                let source = manager.load(&source_id)?;
+                let (program, _) = parse_source_with_settings(&interner, &source, parser_settings);
+
+                let mut detector = DuplicationDetector::new(settings);
+                detector.add(&interner, &source, &program);
+                progress_bar.inc(1);
+
+                Result::<_, Error>::Ok(detector)
+            }
+        }));
+    }
+
+    let mut detector = DuplicationDetector::new(settings);
+    for handle in handles {
+        detector.merge(handle.await??);
+    }
+
+    remove_progress_bar(progress_bar);
+
+    Ok(detector.finish())
+}
+
+/// Converts a clone group into an issue, annotating every duplicated fragment.
+fn clone_group_to_issue(manager: &SourceManager, group: CloneGroup) -> Result<Issue, Error> {
+    let mut annotations = Vec::with_capacity(group.fragments.len());
+    for (index, fragment) in group.fragments.iter().enumerate() {
+        let source = manager.load(&fragment.start.source)?;
+        let message = format!(
+            "Duplicated fragment spanning lines {} to {}.",
+            source.line_number(fragment.start.offset) + 1,
+            source.line_number(fragment.end.offset) + 1
+        );
+
+        annotations.push(if index == 0 {
+            Annotation::primary(*fragment).with_message(message)
+        } else {
+            Annotation::secondary(*fragment).with_message(message)
+        });
+    }
+
+    Ok(Issue::warning(format!("Found {} duplicated fragments of {} tokens.", group.fragments.len(), group.tokens))
+        .with_code("duplicates")
+        .with_annotations(annotations)
+        .with_help("Consider extracting the duplicated code into a shared function, method, or trait."))
+}
@@ -22,6 +22,7 @@ use crate::config::Configuration;
 use crate::enum_variants;
 use crate::error::Error;
 use crate::source;
+use crate::utils::color;
 use crate::utils::progress::ProgressBarTheme;
 use crate::utils::progress::create_progress_bar;
 use crate::utils::progress::remove_progress_bar;
@@ -119,10 +120,12 @@ pub async fn execute(command: FindCommand, configuration: Configuration) -> Resu
         find_references(&interner, &configuration, &source_manager, query, command.include_external).await?;
 
     // Convert references to issues, then report
-    Reporter::new(interner.clone(), source_manager, command.reporting_target).report(
-        references.into_iter().map(|reference| reference_to_issue(&interner, reference)).collect::<Vec<_>>(),
-        command.reporting_format,
-    )?;
+    Reporter::new(interner.clone(), source_manager, command.reporting_target)
+        .with_colors(color::is_enabled(command.reporting_target))
+        .report(
+            references.into_iter().map(|reference| reference_to_issue(&interner, reference)).collect::<Vec<_>>(),
+            command.reporting_format,
+        )?;
 
     Ok(ExitCode::SUCCESS)
 }
@@ -149,6 +152,7 @@ pub async fn find_references(
     include_externals: bool,
 ) -> Result<Vec<Reference>, Error> {
     let php_version = configuration.php_version;
+    let parser_settings = configuration.parser_settings();
 
     // Choose which sources to analyze
     let sources: Vec<_> = if include_externals {
@@ -172,8 +176,12 @@ pub async fn find_references(
             // 1) Load the source code
             let source = manager.load(&source_id)?;
             // 2) Build module
-            let (module, program) =
-                Module::build_with_ast(&interner, php_version, source, ModuleBuildOptions::new(false, false));
+            let (module, program) = Module::build_with_ast(
+                &interner,
+                php_version,
+                source,
+                ModuleBuildOptions::new(false, false).with_parser_settings(parser_settings),
+            );
             // 3) Use the reference finder
             let references = ReferenceFinder::new(&interner).find(&module, &program, query);
 
@@ -1,14 +1,21 @@
 use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::Arc;
 
+use ahash::HashMap;
 use clap::Parser;
+use colored::Colorize;
 
 use mago_fixer::FixPlan;
 use mago_fixer::SafetyClassification;
 use mago_interner::ThreadedInterner;
+use mago_reflection::CodebaseReflection;
 use mago_reporting::IssueCollection;
+use mago_source::Source;
 use mago_source::SourceIdentifier;
 
+use crate::commands::lint::SourceSelection;
+use crate::commands::lint::create_linter;
 use crate::commands::lint::lint_check;
 use crate::config::Configuration;
 use crate::error::Error;
@@ -17,6 +24,8 @@ use crate::utils;
 use crate::utils::progress::ProgressBarTheme;
 use crate::utils::progress::create_progress_bar;
 use crate::utils::progress::remove_progress_bar;
+use crate::utils::undo::DEFAULT_UNDO_DIRECTORY;
+use crate::utils::undo::UndoJournal;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -50,6 +59,54 @@ pub struct FixCommand {
     /// Run the command without writing any changes to disk.
     #[arg(long, short = 'd', help = "Preview the fixes without applying them, showing what changes would be made")]
     pub dry_run: bool,
+
+    /// The maximum number of times to lint and fix the sources, when fixes conflict with each other.
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 5,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        help = "The maximum number of times to lint and fix the sources again, to apply the fixes that conflict with each other"
+    )]
+    pub passes: usize,
+
+    /// Write the fixed files in place, instead of atomically through a temporary file.
+    #[arg(
+        long,
+        help = "Write files in place instead of atomically replacing them, for filesystems that do not support it",
+        conflicts_with = "dry_run"
+    )]
+    pub no_atomic: bool,
+
+    /// Record the original content of the modified files, to restore them with `mago undo`.
+    #[arg(
+        long,
+        value_name = "DIR",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = DEFAULT_UNDO_DIRECTORY,
+        conflicts_with = "dry_run",
+        help = "Record the original content of the modified files in the given directory, `.mago/undo` by default, to restore them with `mago undo`"
+    )]
+    pub backup_dir: Option<PathBuf>,
+
+    /// Only apply the fixes of the given rules.
+    #[arg(
+        long,
+        value_name = "RULE",
+        value_delimiter = ',',
+        help = "Only apply the fixes of the given rules, e.g. `--only redundancy/redundant-parentheses`; a plugin name selects all of its rules"
+    )]
+    pub only: Vec<String>,
+
+    /// Do not apply the fixes of the given rules.
+    #[arg(
+        long,
+        value_name = "RULE",
+        value_delimiter = ',',
+        help = "Do not apply the fixes of the given rules; a plugin name excludes all of its rules"
+    )]
+    pub except: Vec<String>,
 }
 
 impl FixCommand {
@@ -79,52 +136,154 @@ pub async fn execute(command: FixCommand, mut configuration: Configuration) -> R
         configuration.linter.plugins = command.plugins;
     }
 
+    // Ensure the selected rules exist, as a typo would otherwise silently apply no fixes, or every fix.
+    let linter = create_linter(&interner, &configuration, CodebaseReflection::new());
+    let slugs: Vec<_> = linter.get_configured_rules().iter().map(|rule| rule.slug.clone()).collect();
+    for name in command.only.iter().chain(&command.except) {
+        if !slugs.iter().any(|slug| matches_rule(std::slice::from_ref(name), slug)) {
+            tracing::error!("Cannot find rule or plugin '{}' in the linter configuration.", name.bold().bright_red());
+            tracing::error!("Please check the spelling and ensure the rule is enabled in the configuration.");
+
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
     // Load sources
     let source_manager = if !command.path.is_empty() {
         source::from_paths(&interner, &configuration.source, command.path, true).await?
     } else {
         source::load(&interner, &configuration.source, true, true).await?
+    }
+    .with_atomic_writes(!command.no_atomic);
+
+    if let Some(framework) = configuration.framework {
+        source::insert_framework_stubs(&source_manager, framework);
+    }
+
+    let journal = match &command.backup_dir {
+        Some(directory) => Some(Arc::new(UndoJournal::create(&configuration.source.root.join(directory))?)),
+        None => None,
     };
 
-    let issues = lint_check(&interner, &source_manager, &configuration).await?;
-    let (plans, skipped_unsafe, skipped_potentially_unsafe) = filter_fix_plans(&interner, issues, classification);
-
-    let total = plans.len();
-    let progress_bar = create_progress_bar(total, "✨  Fixing", ProgressBarTheme::Cyan);
-    let mut handles = Vec::with_capacity(total);
-    for (source, plan) in plans.into_iter() {
-        handles.push(tokio::spawn({
-            let source_manager = source_manager.clone();
-            let interner = interner.clone();
-            let progress_bar = progress_bar.clone();
-
-            async move {
-                let source = source_manager.load(&source)?;
-                let source_content = interner.lookup(&source.content);
-                let result = utils::apply_changes(
-                    &interner,
-                    &source_manager,
-                    &source,
-                    plan.execute(source_content).get_fixed(),
-                    command.dry_run,
-                );
-
-                progress_bar.inc(1);
-
-                result
+    let end_of_line = configuration.format.get_settings().end_of_line;
+    let mut originals: HashMap<SourceIdentifier, Source> = HashMap::default();
+    let mut skipped = None;
+    let mut conflicts = vec![];
+    let result: Result<(), Error> = async {
+        for pass in 1..=command.passes {
+            let (issues, _) = lint_check(
+                &interner,
+                &source_manager,
+                &configuration,
+                &SourceSelection::default(),
+                None,
+                &Arc::default(),
+            )
+            .await?;
+            let issues = IssueCollection::from(
+                issues
+                    .into_iter()
+                    .filter(|issue| is_rule_selected(&command.only, &command.except, issue.code.as_deref())),
+            );
+
+            let plans = collect_fix_plans(&interner, issues, classification);
+            // The same fixes are skipped in every pass, so they are only reported once.
+            skipped.get_or_insert((plans.skipped_unsafe, plans.skipped_potentially_unsafe));
+            conflicts = plans.conflicts;
+
+            if plans.plans.is_empty() {
+                break;
+            }
+
+            let total = plans.plans.len();
+            let progress_bar = create_progress_bar(total, "✨  Fixing", ProgressBarTheme::Cyan);
+            let mut handles = Vec::with_capacity(total);
+            for (source, plan) in plans.plans.into_iter() {
+                handles.push(tokio::spawn({
+                    let source_manager = source_manager.clone();
+                    let interner = interner.clone();
+                    let progress_bar = progress_bar.clone();
+                    let journal = journal.clone();
+
+                    async move {
+                        let source = source_manager.load(&source)?;
+                        // Fixes follow the line endings of the file, unless the formatter settings force them.
+                        let line_ending = end_of_line.resolve(&source.content).as_str();
+                        let fixed = plan.with_line_ending(line_ending).execute(&source.content).get_fixed();
+                        let fixed = preserve_trailing_newline(&source.content, fixed, line_ending);
+                        // With `--dry-run`, the fixes are kept in memory, so that the next pass lints the fixed code.
+                        let changed = if command.dry_run {
+                            source_manager.update(source.identifier, fixed)?
+                        } else {
+                            utils::apply_changes(&interner, &source_manager, &source, fixed, false, journal.as_deref())?
+                        };
+
+                        progress_bar.inc(1);
+
+                        Result::<_, Error>::Ok(changed.then_some(source))
+                    }
+                }));
             }
-        }));
+
+            let mut changed = 0;
+            for handle in handles {
+                if let Some(source) = handle.await?? {
+                    changed += 1;
+                    originals.entry(source.identifier).or_insert(source);
+                }
+            }
+
+            remove_progress_bar(progress_bar);
+
+            if conflicts.is_empty() || changed == 0 {
+                break;
+            }
+
+            tracing::debug!(
+                "Deferred {} conflicting fixes in pass {}, linting the fixed sources again.",
+                conflicts.iter().map(|(_, count)| count).sum::<usize>(),
+                pass
+            );
+        }
+
+        Ok(())
+    }
+    .await;
+
+    // The files fixed before a failure are recorded as well, so that they can be restored.
+    if let Some(journal) = journal {
+        journal.finish("fix")?;
     }
 
+    result?;
+
+    let mut originals: Vec<_> = originals.into_values().collect();
+    originals.sort_by(|a, b| interner.lookup(&a.identifier.0).cmp(interner.lookup(&b.identifier.0)));
+
     let mut changed = 0;
-    for handle in handles {
-        if handle.await?? {
+    for original in originals {
+        let fixed = source_manager.load(&original.identifier)?;
+        let is_changed = if command.dry_run {
+            utils::apply_changes(&interner, &source_manager, &original, fixed.content.to_string(), true, None)?
+        } else {
+            fixed.content != original.content
+        };
+
+        if is_changed {
             changed += 1;
         }
     }
 
-    remove_progress_bar(progress_bar);
+    conflicts.sort_by(|(a, _), (b, _)| interner.lookup(&a.0).cmp(interner.lookup(&b.0)));
+    for (source, count) in conflicts {
+        tracing::warn!(
+            "Could not apply {} fixes to `{}` because they conflict with other fixes. Run the command again, or increase `--passes`, to apply them.",
+            count,
+            interner.lookup(&source.0)
+        );
+    }
 
+    let (skipped_unsafe, skipped_potentially_unsafe) = skipped.unwrap_or_default();
     if skipped_unsafe > 0 {
         tracing::warn!(
             "Skipped {} fixes because they were marked as unsafe. To apply those fixes, use the `--unsafe` flag.",
@@ -156,16 +315,61 @@ pub async fn execute(command: FixCommand, mut configuration: Configuration) -> R
     })
 }
 
-fn filter_fix_plans(
+/// Restores the line break ending the original content, if the fixes removed it, e.g. by replacing
+/// or deleting code at the end of the file.
+fn preserve_trailing_newline(original: &str, mut fixed: String, line_ending: &str) -> String {
+    if original.ends_with(['\n', '\r']) && !fixed.is_empty() && !fixed.ends_with(['\n', '\r']) {
+        fixed.push_str(line_ending);
+    }
+
+    fixed
+}
+
+/// Determines whether the fixes of the rule with the given slug should be applied.
+///
+/// Issues without a code are not reported by a rule, so they are only selected when no rule is selected explicitly.
+fn is_rule_selected(only: &[String], except: &[String], slug: Option<&str>) -> bool {
+    match slug {
+        Some(slug) => (only.is_empty() || matches_rule(only, slug)) && !matches_rule(except, slug),
+        None => only.is_empty(),
+    }
+}
+
+/// Determines whether the rule with the given slug is one of the given rules, or belongs to one of the given plugins.
+fn matches_rule(names: &[String], slug: &str) -> bool {
+    names.iter().any(|name| {
+        slug.eq_ignore_ascii_case(name)
+            || slug.split_once('/').is_some_and(|(plugin, _)| plugin.eq_ignore_ascii_case(name))
+    })
+}
+
+/// The fixes to apply to each source.
+struct FixPlans {
+    /// The fixes of each source, merged without conflicts.
+    plans: Vec<(SourceIdentifier, FixPlan)>,
+    /// The number of fixes of each source left out as they conflict with the merged fixes.
+    conflicts: Vec<(SourceIdentifier, usize)>,
+    /// The number of fix operations skipped as they are unsafe.
+    skipped_unsafe: usize,
+    /// The number of fix operations skipped as they are potentially unsafe.
+    skipped_potentially_unsafe: usize,
+}
+
+/// Collects the fixes of the given issues that satisfy the given safety classification.
+///
+/// The fixes of each issue are merged in order of location, and a fix conflicting with the ones already
+/// merged for the same source, e.g. an overlapping edit proposed by another rule, is left out, so that
+/// it can be applied to the fixed source in another pass.
+fn collect_fix_plans(
     interner: &ThreadedInterner,
     issues: IssueCollection,
     classification: SafetyClassification,
-) -> (Vec<(SourceIdentifier, FixPlan)>, usize, usize) {
+) -> FixPlans {
     let mut skipped_unsafe = 0;
     let mut skipped_potentially_unsafe = 0;
 
-    let mut results = vec![];
-    for (source, plan) in issues.to_fix_plans() {
+    let mut plans: HashMap<SourceIdentifier, (FixPlan, usize)> = HashMap::default();
+    for (source, plan) in issues.sorted_by_location(interner).into_iter().flat_map(|issue| issue.suggestions) {
         if plan.is_empty() {
             continue;
         }
@@ -205,10 +409,22 @@ fn filter_fix_plans(
             }
         }
 
-        if !operations.is_empty() {
-            results.push((source, FixPlan::from_operations(operations)));
+        if operations.is_empty() {
+            continue;
+        }
+
+        let (merged, conflicts) = plans.entry(source).or_default();
+        if merged.try_merge(FixPlan::from_operations(operations)).is_err() {
+            *conflicts += 1;
         }
     }
 
-    (results, skipped_unsafe, skipped_potentially_unsafe)
+    let conflicts = plans
+        .iter()
+        .filter(|(_, (_, conflicts))| *conflicts > 0)
+        .map(|(source, (_, conflicts))| (*source, *conflicts))
+        .collect();
+    let plans = plans.into_iter().map(|(source, (plan, _))| (source, plan)).collect();
+
+    FixPlans { plans, conflicts, skipped_unsafe, skipped_potentially_unsafe }
 }
@@ -1,23 +1,41 @@
+use std::path::Path;
 use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
 use clap::Parser;
+use colored::Colorize;
+use serde_json::Value as JsonValue;
 
+use mago_ast::Program;
 use mago_formatter::format;
 use mago_formatter::settings::FormatSettings;
 use mago_interner::ThreadedInterner;
-use mago_parser::parse_source;
+use mago_parser::parse_source_with_settings;
+use mago_parser::settings::ParserSettings;
+use mago_source::Source;
 use mago_source::SourceCategory;
 use mago_source::SourceIdentifier;
 use mago_source::SourceManager;
 
 use crate::config::Configuration;
+use crate::config::formatter::FormatterConfiguration;
+use crate::config::generated::GeneratedConfiguration;
+use crate::consts::VERSION;
 use crate::error::Error;
 use crate::source;
 use crate::utils;
+use crate::utils::crash::CrashReporter;
+use crate::utils::crash::catch_panic;
+use crate::utils::git;
 use crate::utils::progress::ProgressBarTheme;
 use crate::utils::progress::create_progress_bar;
 use crate::utils::progress::remove_progress_bar;
+use crate::utils::shard::Shard;
+use crate::utils::undo::DEFAULT_UNDO_DIRECTORY;
+use crate::utils::undo::UndoJournal;
 
 /// Represents the `format` command, which is responsible for formatting source files
 /// according to specified rules in the configuration file.
@@ -40,6 +58,64 @@ pub struct FormatCommand {
     /// Perform a dry run to check if files are already formatted.
     #[arg(long, short = 'd', help = "Check if the source files are already formatted without making changes")]
     pub dry_run: bool,
+
+    /// Only format the sources of the given shard, to split the work across parallel jobs.
+    #[arg(
+        long,
+        value_name = "INDEX/COUNT",
+        help = "Only format the sources of the given shard, e.g. `2/5`, to split the work across parallel CI jobs"
+    )]
+    pub shard: Option<Shard>,
+
+    /// Write the formatted files in place, instead of atomically through a temporary file.
+    #[arg(
+        long,
+        help = "Write files in place instead of atomically replacing them, for filesystems that do not support it",
+        conflicts_with = "dry_run"
+    )]
+    pub no_atomic: bool,
+
+    /// Record the original content of the modified files, to restore them with `mago undo`.
+    #[arg(
+        long,
+        value_name = "DIR",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = DEFAULT_UNDO_DIRECTORY,
+        conflicts_with = "dry_run",
+        help = "Record the original content of the modified files in the given directory, `.mago/undo` by default, to restore them with `mago undo`"
+    )]
+    pub backup_dir: Option<PathBuf>,
+
+    /// Exit with a failure code when a source file cannot be read or parsed, instead of only skipping it.
+    #[arg(
+        long,
+        help = "Exit with a failure code when a source file cannot be read or parsed, instead of only skipping it"
+    )]
+    pub error_on_parse_failure: bool,
+
+    /// Print statistics about the impact of formatting, and the settings causing the most changes.
+    #[arg(
+        long,
+        help = "Print the number of changed files and lines, and estimate which settings cause the most changes"
+    )]
+    pub stats: bool,
+
+    /// Prepare `.git-blame-ignore-revs` for the commit of the formatted files, to keep `git blame` useful.
+    #[arg(
+        long,
+        conflicts_with = "dry_run",
+        help = "Prepare `.git-blame-ignore-revs` for the commit of the formatted files, so that `git blame` ignores the reformat"
+    )]
+    pub write_ignore_revs: bool,
+
+    /// Configure the git repository to use `.git-blame-ignore-revs` when running `git blame`.
+    #[arg(
+        long,
+        requires = "write_ignore_revs",
+        help = "Run `git config blame.ignoreRevsFile .git-blame-ignore-revs`, along with `--write-ignore-revs`"
+    )]
+    pub configure_git_blame: bool,
 }
 
 /// Executes the format command with the provided configuration and options.
@@ -63,19 +139,64 @@ pub async fn execute(command: FormatCommand, mut configuration: Configuration) -
         source::from_paths(&interner, &configuration.source, command.path, false).await?
     } else {
         source::load(&interner, &configuration.source, false, false).await?
-    };
+    }
+    .with_atomic_writes(!command.no_atomic);
 
     // Extract formatting settings from the configuration.
     let settings = configuration.format.get_settings();
 
+    let journal = match &command.backup_dir {
+        Some(directory) => Some(Arc::new(UndoJournal::create(&configuration.source.root.join(directory))?)),
+        None => None,
+    };
+
+    let crash_reporter = Arc::new(CrashReporter::new(&interner, &configuration, "format"));
+    let statistics = if command.stats { Some(Arc::new(FormatStatistics::new(settings))) } else { None };
+
     // Format all sources and get the count of changed files.
-    let changed = format_all(interner, source_manager, settings, command.dry_run).await?;
+    let result = format_all(
+        interner,
+        source_manager,
+        settings,
+        configuration.parser_settings(),
+        Arc::new(configuration.generated.clone()),
+        command.dry_run,
+        configuration.low_memory,
+        command.shard,
+        journal.clone(),
+        crash_reporter.clone(),
+        statistics.clone(),
+    )
+    .await;
+
+    // The files formatted before a failure are recorded as well, so that they can be restored.
+    if let Some(journal) = journal {
+        journal.finish("format")?;
+    }
+
+    let (changed, skipped, generated) = result?;
+    if generated > 0 {
+        tracing::info!("Skipped {} generated source files.", generated);
+    }
+
+    let crashes = crash_reporter.finish();
+
+    let mut failed = crashes > 0;
+    if skipped > crashes {
+        tracing::warn!("Skipped {} source files that could not be read or parsed.", skipped - crashes);
+
+        failed |= command.error_on_parse_failure;
+    }
+
+    if let Some(statistics) = statistics {
+        statistics.print(skipped);
+    }
 
     // Provide feedback and return appropriate exit code.
     if changed == 0 {
         tracing::info!("All source files are already formatted.");
 
-        return Ok(ExitCode::SUCCESS);
+        return Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS });
     }
 
     Ok(if command.dry_run {
@@ -85,10 +206,71 @@ pub async fn execute(command: FormatCommand, mut configuration: Configuration) -
     } else {
         tracing::info!("Formatted {} source files successfully.", changed);
 
-        ExitCode::SUCCESS
+        if command.write_ignore_revs {
+            write_ignore_revs(&configuration.source.root, command.configure_git_blame)?;
+        }
+
+        if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
     })
 }
 
+/// Prepares the `.git-blame-ignore-revs` file of the repository for the commit containing the formatted files.
+///
+/// The commit does not exist yet, so a comment marking the reformat is appended to the file, and the commands
+/// recording the hash of the commit once it is made are printed.
+///
+/// # Arguments
+///
+/// * `root` - The root directory of the workspace, inside the git repository.
+/// * `configure_git_blame` - Whether to configure the repository to use the file when running `git blame`.
+fn write_ignore_revs(root: &Path, configure_git_blame: bool) -> Result<(), Error> {
+    let repository = git::get_repository_root(root)?;
+    let path = repository.join(git::IGNORE_REVS_FILE);
+
+    let mut content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            "# Revisions ignored by `git blame`, such as mass reformats.\n".to_string()
+        }
+        Err(error) => return Err(Error::WritingIgnoreRevs(path, error)),
+    };
+
+    // Formatting again before committing must not mark the same reformat twice.
+    let marker = format!("# Reformat with mago {}, the hash of its commit follows.", VERSION);
+    if !content.trim_end().ends_with(&marker) {
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+
+        content.push('\n');
+        content.push_str(&marker);
+        content.push('\n');
+
+        std::fs::write(&path, content).map_err(|error| Error::WritingIgnoreRevs(path.clone(), error))?;
+    }
+
+    if configure_git_blame {
+        git::run(&repository, &["config", "blame.ignoreRevsFile", git::IGNORE_REVS_FILE])?;
+
+        tracing::info!("Configured `git blame` to ignore the revisions listed in `{}`.", git::IGNORE_REVS_FILE);
+    }
+
+    println!();
+    println!("{}", "Commit the formatted files, then record the commit to ignore it in `git blame`:".bold());
+    println!();
+    println!("  git commit --all --message \"Reformat with mago\"");
+    println!("  git rev-parse HEAD >> {}", git::IGNORE_REVS_FILE);
+    println!("  git add {} && git commit --message \"Ignore the reformat in git blame\"", git::IGNORE_REVS_FILE);
+    if !configure_git_blame {
+        println!();
+        println!("{}", "GitHub and GitLab use the file automatically, while local clones must be configured:".bold());
+        println!();
+        println!("  git config blame.ignoreRevsFile {}", git::IGNORE_REVS_FILE);
+    }
+
+    Ok(())
+}
+
 /// Formats all source files using the provided settings.
 ///
 /// # Arguments
@@ -96,20 +278,39 @@ pub async fn execute(command: FormatCommand, mut configuration: Configuration) -
 /// * `interner` - The interner to manage source identifiers.
 /// * `source_manager` - The manager responsible for handling source files.
 /// * `settings` - Formatting settings to apply.
+/// * `parser_settings` - The settings to parse the sources with.
+/// * `generated` - The configuration detecting the generated sources, which are skipped.
 /// * `check` - A flag to determine whether to check or apply formatting.
+/// * `low_memory` - A flag to determine whether to release the content of each source once it is formatted.
+/// * `shard` - The shard of the sources to format, if any.
+/// * `journal` - The journal to record the original content of the formatted sources in, if any.
+/// * `crash_reporter` - The reporter catching the crashes on individual sources, which are then skipped.
+/// * `statistics` - The statistics to record the impact of formatting in, if any.
 ///
 /// # Returns
 ///
-/// A result containing the number of changed files or a source error.
+/// A result containing the number of changed files, of skipped files, and of generated files, or an error.
 #[inline]
+#[allow(clippy::too_many_arguments)]
 async fn format_all(
     interner: ThreadedInterner,
     source_manager: SourceManager,
     settings: FormatSettings,
+    parser_settings: ParserSettings,
+    generated: Arc<GeneratedConfiguration>,
     dry_run: bool,
-) -> Result<usize, Error> {
-    // Collect all user-defined sources.
-    let sources: Vec<_> = source_manager.source_ids_for_category(SourceCategory::UserDefined);
+    low_memory: bool,
+    shard: Option<Shard>,
+    journal: Option<Arc<UndoJournal>>,
+    crash_reporter: Arc<CrashReporter>,
+    statistics: Option<Arc<FormatStatistics>>,
+) -> Result<(usize, usize, usize), Error> {
+    // Collect all user-defined sources of the shard.
+    let sources: Vec<_> = source_manager
+        .source_ids_for_category(SourceCategory::UserDefined)
+        .into_iter()
+        .filter(|source| shard.is_none_or(|shard| shard.contains(interner.lookup(&source.0))))
+        .collect();
 
     let length = sources.len();
     let progress_bar = create_progress_bar(length, "✨ Formatting", ProgressBarTheme::Green);
@@ -121,9 +322,27 @@ async fn format_all(
             let interner = interner.clone();
             let manager = source_manager.clone();
             let progress_bar = progress_bar.clone();
+            let journal = journal.clone();
+            let crash_reporter = crash_reporter.clone();
+            let statistics = statistics.clone();
+            let generated = generated.clone();
 
             async move {
-                let result = format_source(&interner, &manager, &source, settings, dry_run);
+                let result = format_source(
+                    &interner,
+                    &manager,
+                    &source,
+                    settings,
+                    parser_settings,
+                    &generated,
+                    dry_run,
+                    journal.as_deref(),
+                    &crash_reporter,
+                    statistics.as_deref(),
+                );
+                if low_memory {
+                    manager.unload(&source);
+                }
 
                 progress_bar.inc(1);
 
@@ -133,17 +352,22 @@ async fn format_all(
     }
 
     let mut changed = 0;
+    let mut skipped = 0;
+    let mut generated = 0;
 
     // Process each formatting task and update progress bar.
     for handle in handles {
-        if handle.await?? {
-            changed += 1;
+        match handle.await?? {
+            FormatOutcome::Changed => changed += 1,
+            FormatOutcome::Unchanged => {}
+            FormatOutcome::Skipped => skipped += 1,
+            FormatOutcome::Generated => generated += 1,
         }
     }
 
     remove_progress_bar(progress_bar);
 
-    Ok(changed)
+    Ok((changed, skipped, generated))
 }
 
 /// Formats a single source file.
@@ -152,42 +376,306 @@ async fn format_all(
 ///
 /// * `interner` - Reference to the interner for identifier management.
 /// * `manager` - Reference to the source manager.
-/// * `source` - Identifier of the source file to format.
+/// * `source_id` - Identifier of the source file to format.
 /// * `settings` - Formatting settings to apply.
+/// * `parser_settings` - The settings to parse the source with.
+/// * `generated` - The configuration detecting whether the source is generated, in which case it is not formatted.
 /// * `check` - A flag to determine whether to check or apply formatting.
+/// * `journal` - The journal to record the original content of the source in, if any.
+/// * `crash_reporter` - The reporter catching a crash while parsing or formatting the source.
+/// * `statistics` - The statistics to record the impact of formatting the source in, if any.
 ///
 /// # Returns
 ///
-/// A result indicating whether the file was changed or an error occurred.
+/// A result indicating whether the file was changed, or skipped.
 #[inline]
+#[allow(clippy::too_many_arguments)]
 fn format_source(
     interner: &ThreadedInterner,
     manager: &SourceManager,
-    source: &SourceIdentifier,
+    source_id: &SourceIdentifier,
     settings: FormatSettings,
+    parser_settings: ParserSettings,
+    generated: &GeneratedConfiguration,
     dry_run: bool,
-) -> Result<bool, Error> {
-    // Load the source file.
-    let source = manager.load(source)?;
+    journal: Option<&UndoJournal>,
+    crash_reporter: &CrashReporter,
+    statistics: Option<&FormatStatistics>,
+) -> Result<FormatOutcome, Error> {
+    // Load the source file, skipping it if it cannot be read.
+    let source = match manager.load(source_id) {
+        Ok(source) => source,
+        Err(error) => {
+            tracing::error!("Skipping formatting for source '{}': {}.", interner.lookup(&source_id.0), error);
+
+            return Ok(FormatOutcome::Skipped);
+        }
+    };
 
-    // Parse the source file to generate an AST.
-    let (program, error) = parse_source(interner, &source);
+    let source_name = interner.lookup(&source.identifier.0);
+    if generated.is_generated(source_name, &source.content) {
+        tracing::debug!("Skipping formatting for generated source '{}'.", source_name);
 
-    // Handle parsing errors and perform formatting.
-    let changed = match error {
-        Some(error) => {
-            let source_name = interner.lookup(&source.identifier.0);
+        return Ok(FormatOutcome::Generated);
+    }
 
+    // Parse the source file to generate an AST, and format it, skipping the source if either crashes.
+    let result = crash_reporter.run(&source, |source| {
+        let (program, error) = parse_source_with_settings(interner, source, parser_settings);
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok((format(interner, source, &program, settings), program)),
+        }
+    });
+
+    // Handle parsing errors and perform formatting.
+    let outcome = match result {
+        Some(Err(error)) => {
             tracing::error!("Skipping formatting for source '{}': {}.", source_name, error);
 
-            false
+            FormatOutcome::Skipped
+        }
+        Some(Ok((formatted, program))) => {
+            if let Some(statistics) = statistics {
+                statistics.record(interner, &source, &program, &formatted);
+            }
+
+            if utils::apply_changes(interner, manager, &source, formatted, dry_run, journal)? {
+                FormatOutcome::Changed
+            } else {
+                FormatOutcome::Unchanged
+            }
+        }
+        None => FormatOutcome::Skipped,
+    };
+
+    Ok(outcome)
+}
+
+/// The outcome of formatting a single source.
+enum FormatOutcome {
+    /// The source was formatted, and changed.
+    Changed,
+
+    /// The source was already formatted.
+    Unchanged,
+
+    /// The source was skipped, as it could not be read, parsed, or formatted.
+    Skipped,
+
+    /// The source was skipped, as it is generated.
+    Generated,
+}
+
+/// The number of setting variations printed by `--stats`, those avoiding the most changed lines first.
+const SETTING_VARIATIONS_SHOWN: usize = 10;
+
+/// The alternative values tried for the numeric settings, other settings being varied through their possible values.
+const NUMERIC_SETTING_VALUES: [(&str, [u64; 3]); 2] = [("print_width", [80, 100, 120]), ("tab_width", [2, 4, 8])];
+
+/// The formatter settings with a single setting changed to another value.
+struct SettingVariation {
+    name: String,
+    value: String,
+    settings: FormatSettings,
+}
+
+/// Statistics about the impact of formatting, collected by `--stats`.
+///
+/// The settings causing the most changes are estimated by formatting every changed source again with each
+/// setting changed to each of its other values, and counting the changed lines each variation avoids.
+struct FormatStatistics {
+    variations: Vec<SettingVariation>,
+    formatted: AtomicUsize,
+    changed: AtomicUsize,
+    removed_lines: AtomicUsize,
+    added_lines: AtomicUsize,
+    /// The number of changed lines avoided by each variation, in the order of the variations.
+    avoided_lines: Vec<AtomicUsize>,
+}
+
+impl FormatStatistics {
+    fn new(settings: FormatSettings) -> Self {
+        let variations = get_setting_variations(settings);
+        let avoided_lines = variations.iter().map(|_| AtomicUsize::new(0)).collect();
+
+        Self {
+            variations,
+            formatted: AtomicUsize::new(0),
+            changed: AtomicUsize::new(0),
+            removed_lines: AtomicUsize::new(0),
+            added_lines: AtomicUsize::new(0),
+            avoided_lines,
         }
-        None => {
-            let formatted = format(interner, &source, &program, settings);
+    }
 
-            utils::apply_changes(interner, manager, &source, formatted, dry_run)?
+    fn record(&self, interner: &ThreadedInterner, source: &Source, program: &Program, formatted: &str) {
+        self.formatted.fetch_add(1, Ordering::Relaxed);
+        if source.content.as_ref() == formatted {
+            return;
         }
+
+        let (removed, added) = count_changed_lines(&source.content, formatted);
+        self.changed.fetch_add(1, Ordering::Relaxed);
+        self.removed_lines.fetch_add(removed, Ordering::Relaxed);
+        self.added_lines.fetch_add(added, Ordering::Relaxed);
+
+        for (variation, avoided) in self.variations.iter().zip(&self.avoided_lines) {
+            // A crash of the formatter with another setting is not worth reporting, the variation is only skipped.
+            let Ok(varied) = catch_panic(|| format(interner, source, program, variation.settings)) else {
+                continue;
+            };
+
+            let (varied_removed, varied_added) = count_changed_lines(&source.content, &varied);
+            avoided.fetch_add((removed + added).saturating_sub(varied_removed + varied_added), Ordering::Relaxed);
+        }
+    }
+
+    fn print(&self, skipped: usize) {
+        let formatted = self.formatted.load(Ordering::Relaxed);
+        let changed = self.changed.load(Ordering::Relaxed);
+        let removed = self.removed_lines.load(Ordering::Relaxed);
+        let added = self.added_lines.load(Ordering::Relaxed);
+
+        println!();
+        println!(
+            "{} {} changed, {} unchanged, {} skipped files",
+            "Format statistics:".bold().underline(),
+            changed.to_string().bold(),
+            (formatted - changed).to_string().bold(),
+            skipped.to_string().bold().yellow()
+        );
+        println!();
+        println!("  Lines touched: {} removed, {} added", removed.to_string().red(), added.to_string().green());
+
+        let mut shown = self
+            .variations
+            .iter()
+            .zip(&self.avoided_lines)
+            .map(|(variation, avoided)| (variation, avoided.load(Ordering::Relaxed)))
+            .filter(|(_, avoided)| *avoided > 0)
+            .collect::<Vec<_>>();
+
+        shown.sort_by(|(a, a_avoided), (b, b_avoided)| b_avoided.cmp(a_avoided).then_with(|| a.name.cmp(&b.name)));
+        shown.truncate(SETTING_VARIATIONS_SHOWN);
+
+        if shown.is_empty() {
+            return;
+        }
+
+        let width = shown
+            .iter()
+            .map(|(variation, _)| variation.name.len() + variation.value.len() + 3)
+            .chain(["Setting".len()])
+            .max()
+            .unwrap_or_default();
+
+        println!();
+        println!(
+            "  The settings changes avoiding the most changed lines, as an estimate of the settings causing them:"
+        );
+        println!();
+        println!("  {:width$}  {:>13}  {:>6}", "Setting".bold(), "Avoided lines".bold(), "Share".bold(), width = width);
+        for (variation, avoided) in shown {
+            let share = avoided as f64 / (removed + added) as f64 * 100.0;
+
+            println!(
+                "  {:width$}  {:>13}  {:>5.1}%",
+                format!("{} = {}", variation.name, variation.value),
+                avoided,
+                share,
+                width = width
+            );
+        }
+    }
+}
+
+/// Returns the number of removed and added lines between the given contents.
+fn count_changed_lines(original: &str, formatted: &str) -> (usize, usize) {
+    let patch = diffy::create_patch(original, formatted);
+
+    patch.hunks().iter().flat_map(|hunk| hunk.lines()).fold((0, 0), |(removed, added), line| match line {
+        diffy::Line::Delete(_) => (removed + 1, added),
+        diffy::Line::Insert(_) => (removed, added + 1),
+        diffy::Line::Context(_) => (removed, added),
+    })
+}
+
+/// Returns the variations of the given settings, changing each setting to each of its other values.
+///
+/// Boolean settings are toggled, settings taking one of several values are changed to each of the values
+/// allowed by the configuration schema, and numeric settings to common values.
+fn get_setting_variations(settings: FormatSettings) -> Vec<SettingVariation> {
+    let Ok(JsonValue::Object(current)) = serde_json::to_value(settings) else {
+        return vec![];
     };
 
-    Ok(changed)
+    let schema = serde_json::to_value(schemars::schema_for!(FormatterConfiguration)).unwrap_or_default();
+
+    let mut variations = vec![];
+    for (name, value) in &current {
+        // Line endings change every line, without being a matter of style.
+        if name == "end_of_line" {
+            continue;
+        }
+
+        let candidates = match value {
+            JsonValue::Bool(value) => vec![JsonValue::Bool(!value)],
+            JsonValue::Null => vec![JsonValue::Bool(true), JsonValue::Bool(false)],
+            JsonValue::Number(_) => NUMERIC_SETTING_VALUES
+                .iter()
+                .find(|(setting, _)| setting == name)
+                .map(|(_, values)| values.iter().map(|value| JsonValue::from(*value)).collect())
+                .unwrap_or_default(),
+            JsonValue::String(_) => {
+                let mut values = vec![];
+                if let Some(property) = schema.get("properties").and_then(|properties| properties.get(name)) {
+                    collect_enum_values(&schema, property, &mut values);
+                }
+
+                values
+            }
+            _ => vec![],
+        };
+
+        for candidate in candidates {
+            if &candidate == value {
+                continue;
+            }
+
+            let mut varied = current.clone();
+            varied.insert(name.clone(), candidate.clone());
+
+            // Values that do not apply to the setting, e.g. a boolean for an optional enum, are skipped.
+            if let Ok(settings) = serde_json::from_value(JsonValue::Object(varied)) {
+                variations.push(SettingVariation { name: name.clone(), value: candidate.to_string(), settings });
+            }
+        }
+    }
+
+    variations
+}
+
+/// Collects the values enumerated by the given schema, following its references and combinators.
+fn collect_enum_values(root: &JsonValue, schema: &JsonValue, values: &mut Vec<JsonValue>) {
+    if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str) {
+        let resolved = reference
+            .strip_prefix("#/definitions/")
+            .and_then(|name| root.get("definitions").and_then(|definitions| definitions.get(name)));
+
+        if let Some(resolved) = resolved {
+            collect_enum_values(root, resolved, values);
+        }
+    }
+
+    if let Some(enumerated) = schema.get("enum").and_then(JsonValue::as_array) {
+        values.extend(enumerated.iter().filter(|value| value.is_string()).cloned());
+    }
+
+    for combinator in ["allOf", "anyOf", "oneOf"] {
+        for variant in schema.get(combinator).and_then(JsonValue::as_array).into_iter().flatten() {
+            collect_enum_values(root, variant, values);
+        }
+    }
 }
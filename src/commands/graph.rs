@@ -0,0 +1,456 @@
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+
+use clap::Parser;
+use serde_json::json;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantNames;
+
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_php_version::PHPVersion;
+use mago_project::include::IncludeGraph;
+use mago_project::include::strongly_connected_components;
+use mago_project::module::Module;
+use mago_project::module::ModuleBuildOptions;
+use mago_reflection::CodebaseReflection;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::r#type::kind::ObjectTypeKind;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+use crate::config::Configuration;
+use crate::enum_variants;
+use crate::error::Error;
+use crate::source;
+use crate::utils::progress::ProgressBarTheme;
+use crate::utils::progress::create_progress_bar;
+use crate::utils::progress::remove_progress_bar;
+
+/// Represents the `graph` command, which exports dependency graphs of the project.
+#[derive(Parser, Debug)]
+#[command(
+    name = "graph",
+    about = "Export dependency graphs of the project source code",
+    long_about = r#"
+The `graph` command analyzes the project source code and exports a dependency graph.
+
+Available graphs:
+
+  includes : the file-inclusion graph built from literal `include`/`require` paths.
+  classes  : the class dependency graph built from `extends`, `implements`, and trait `use` clauses,
+             and the classes referenced in the declared types of properties, parameters, and return values.
+
+Dependency cycles are reported in every format.
+
+Use `--format=dot` to emit a Graphviz graph that can be rendered with `dot -Tsvg`.
+"#
+)]
+pub struct GraphCommand {
+    /// The graph to export.
+    #[arg(help = "The graph to export", ignore_case = true, value_parser = enum_variants!(GraphKind))]
+    pub kind: GraphKind,
+
+    /// The format in which the graph should be printed.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Choose the output format for the graph",
+        ignore_case = true,
+        value_parser = enum_variants!(GraphFormat)
+    )]
+    pub format: GraphFormat,
+}
+
+/// The kind of graph exported by the `graph` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumString, VariantNames)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum GraphKind {
+    /// The file-inclusion graph.
+    Includes,
+    /// The class dependency graph.
+    Classes,
+}
+
+/// A dependency of a class-like on another class-like.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct ClassDependency {
+    kind: &'static str,
+    target: String,
+}
+
+/// The output format of the `graph` command.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Display, EnumString, VariantNames)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum GraphFormat {
+    /// Print the graph as a human-readable list of edges.
+    #[default]
+    Text,
+    /// Print the graph as JSON.
+    Json,
+    /// Print the graph as a Graphviz DOT graph.
+    Dot,
+}
+
+/// Executes the graph command with the provided options.
+///
+/// # Arguments
+///
+/// * `command` - The `GraphCommand` structure containing user-specified options.
+/// * `configuration` - The configuration containing the source settings.
+///
+/// # Returns
+///
+/// An `ExitCode` indicating the success or failure of the command.
+pub async fn execute(command: GraphCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+    let source_manager = source::load(&interner, &configuration.source, false, false).await?;
+    let root = &configuration.source.root;
+
+    match command.kind {
+        GraphKind::Includes => {
+            let modules = build_modules(
+                &interner,
+                &source_manager,
+                configuration.php_version,
+                ModuleBuildOptions::new(false, false).with_parser_settings(configuration.parser_settings()),
+            )
+            .await?;
+            let graph = IncludeGraph::build(root, &modules);
+
+            match command.format {
+                GraphFormat::Dot => {
+                    print!("{}", graph.to_dot(root));
+                }
+                GraphFormat::Json => {
+                    let files = graph
+                        .edges()
+                        .map(|(file, edges)| {
+                            json!({
+                                "file": file,
+                                "includes": edges.iter().map(|edge| json!({
+                                    "kind": edge.kind.to_string(),
+                                    "target": edge.target,
+                                    "exists": edge.exists,
+                                })).collect::<Vec<_>>(),
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&json!({ "files": files, "cycles": graph.find_cycles() }))?
+                    );
+                }
+                GraphFormat::Text => {
+                    for (file, edges) in graph.edges() {
+                        println!("{}", file.strip_prefix(root).unwrap_or(file).display());
+
+                        for edge in edges {
+                            println!(
+                                "  -> {} {}{}",
+                                edge.kind,
+                                edge.target.strip_prefix(root).unwrap_or(&edge.target).display(),
+                                if edge.exists { "" } else { " (missing)" }
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        GraphKind::Classes => {
+            let modules = build_modules(
+                &interner,
+                &source_manager,
+                configuration.php_version,
+                ModuleBuildOptions::reflection().with_parser_settings(configuration.parser_settings()),
+            )
+            .await?;
+
+            // Reflections are merged without being populated, so that only direct dependencies are recorded.
+            let mut codebase = CodebaseReflection::new();
+            for module in modules {
+                if let Some(reflection) = module.reflection {
+                    codebase.merge(&interner, reflection);
+                }
+            }
+
+            let graph = get_class_dependencies(&interner, &codebase);
+            let cycles = find_class_cycles(&graph);
+
+            match command.format {
+                GraphFormat::Dot => {
+                    let cyclic = cycles.iter().flatten().collect::<Vec<_>>();
+
+                    let mut dot = String::from("digraph classes {\n    node [shape=box, fontname=\"monospace\"];\n");
+                    for (name, (kind, dependencies)) in &graph {
+                        dot.push_str(&format!(
+                            "    \"{}\" [label=\"{}\\n<<{}>>\"{}];\n",
+                            escape_dot(name),
+                            escape_dot(name),
+                            kind,
+                            if cyclic.contains(&name) { ", color=red" } else { "" }
+                        ));
+
+                        for dependency in dependencies {
+                            let style = if dependency.kind == "references" { "dashed" } else { "solid" };
+
+                            dot.push_str(&format!(
+                                "    \"{}\" -> \"{}\" [label=\"{}\", style={}];\n",
+                                escape_dot(name),
+                                escape_dot(&dependency.target),
+                                dependency.kind,
+                                style
+                            ));
+                        }
+                    }
+
+                    dot.push_str("}\n");
+
+                    print!("{dot}");
+                }
+                GraphFormat::Json => {
+                    let classes = graph
+                        .iter()
+                        .map(|(name, (kind, dependencies))| {
+                            json!({
+                                "name": name,
+                                "kind": kind,
+                                "dependencies": dependencies.iter().map(|dependency| json!({
+                                    "kind": dependency.kind,
+                                    "target": dependency.target,
+                                })).collect::<Vec<_>>(),
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    println!("{}", serde_json::to_string_pretty(&json!({ "classes": classes, "cycles": cycles }))?);
+                }
+                GraphFormat::Text => {
+                    for (name, (kind, dependencies)) in &graph {
+                        println!("{} {}", kind, name);
+
+                        for dependency in dependencies {
+                            println!("  -> {} {}", dependency.kind, dependency.target);
+                        }
+                    }
+
+                    for cycle in &cycles {
+                        println!("cycle: {}", cycle.join(" <-> "));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Collects the direct dependencies of every named class-like in the given codebase, sorted by name.
+///
+/// The returned map associates each class-like name with its kind, and its dependencies. Besides the
+/// `extends`, `implements`, and trait `use` clauses, classes referenced in the declared types of
+/// properties, parameters, and return values are recorded as `references` dependencies.
+fn get_class_dependencies(
+    interner: &ThreadedInterner,
+    codebase: &CodebaseReflection,
+) -> BTreeMap<String, (&'static str, Vec<ClassDependency>)> {
+    let mut graph = BTreeMap::new();
+
+    for (name, class_like) in &codebase.class_like_reflections {
+        let (kind, name) = match name {
+            ClassLikeName::Class(name) => ("class", name),
+            ClassLikeName::Interface(name) => ("interface", name),
+            ClassLikeName::Enum(name) => ("enum", name),
+            ClassLikeName::Trait(name) => ("trait", name),
+            ClassLikeName::AnonymousClass(_) => continue,
+        };
+
+        let inheritance = &class_like.inheritance;
+        let dependency = |kind: &'static str, target: &StringIdentifier| {
+            // Prefer the declared name of the dependency, as some names are stored lowercased.
+            let target =
+                match codebase.get_named_class_like(interner, target).and_then(|class_like| class_like.name.inner()) {
+                    Some(declared) => interner.lookup(&declared.value),
+                    None => interner.lookup(target),
+                };
+
+            ClassDependency { kind, target: target.to_string() }
+        };
+
+        let mut dependencies = vec![];
+        dependencies
+            .extend(inheritance.direct_extended_class.iter().map(|target| dependency("extends", &target.value)));
+        dependencies
+            .extend(inheritance.direct_extended_interfaces.iter().map(|target| dependency("extends", &target.value)));
+        dependencies.extend(
+            inheritance.direct_implemented_interfaces.iter().map(|target| dependency("implements", &target.value)),
+        );
+        dependencies.extend(class_like.used_traits.iter().map(|target| dependency("uses", &target.value)));
+
+        let mut types = vec![];
+        types.extend(class_like.properties.members.values().filter_map(|property| property.type_reflection.as_ref()));
+        for method in class_like.methods.members.values() {
+            types.extend(method.parameters.iter().filter_map(|parameter| parameter.type_reflection.as_ref()));
+            types.extend(method.return_type_reflection.as_ref().map(|r#return| &r#return.type_reflection));
+        }
+
+        let mut references = vec![];
+        for type_reflection in types {
+            collect_referenced_classes(&type_reflection.kind, &mut references);
+        }
+
+        let class_name = interner.lookup(&name.value);
+        for reference in references {
+            let reference = dependency("references", &reference);
+
+            // Skip self-references, and classes that are already depended upon through a clause.
+            if reference.target.eq_ignore_ascii_case(class_name)
+                || dependencies.iter().any(|dependency| dependency.target.eq_ignore_ascii_case(&reference.target))
+            {
+                continue;
+            }
+
+            dependencies.push(reference);
+        }
+
+        dependencies.sort();
+        dependencies.dedup();
+
+        graph.insert(class_name.to_string(), (kind, dependencies));
+    }
+
+    graph
+}
+
+/// Collects the names of the classes referenced in the given type, including its type parameters.
+fn collect_referenced_classes(kind: &TypeKind, names: &mut Vec<StringIdentifier>) {
+    match kind {
+        TypeKind::Union { kinds } | TypeKind::Intersection { kinds } => {
+            for kind in kinds {
+                collect_referenced_classes(kind, names);
+            }
+        }
+        TypeKind::Object(ObjectTypeKind::NamedObject { name, type_parameters }) => {
+            names.push(*name);
+
+            for kind in type_parameters {
+                collect_referenced_classes(kind, names);
+            }
+        }
+        TypeKind::Object(ObjectTypeKind::EnumCase { enum_name, .. }) => {
+            names.push(*enum_name);
+        }
+        TypeKind::Iterable { key, value } => {
+            collect_referenced_classes(key, names);
+            collect_referenced_classes(value, names);
+        }
+        TypeKind::GenericParameter { of, .. } => {
+            collect_referenced_classes(of, names);
+        }
+        _ => {}
+    }
+}
+
+/// Finds all dependency cycles in the given class graph.
+///
+/// Each cycle is returned as a list of class-like names, sorted by name, that (transitively) depend on each other.
+fn find_class_cycles(graph: &BTreeMap<String, (&'static str, Vec<ClassDependency>)>) -> Vec<Vec<String>> {
+    let nodes: Vec<&String> = graph.keys().collect();
+    let index_of = |name: &str| nodes.iter().position(|node| node.eq_ignore_ascii_case(name));
+
+    let successors: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|node| graph[*node].1.iter().filter_map(|dependency| index_of(&dependency.target)).collect())
+        .collect();
+
+    let mut cycles = vec![];
+    for component in strongly_connected_components(&successors) {
+        let is_cycle = component.len() > 1 || successors[component[0]].contains(&component[0]);
+        if !is_cycle {
+            continue;
+        }
+
+        let mut cycle = component.into_iter().map(|index| nodes[index].clone()).collect::<Vec<_>>();
+        cycle.sort();
+        cycles.push(cycle);
+    }
+
+    cycles.sort();
+    cycles
+}
+
+/// Escapes the given string for use in a quoted Graphviz DOT identifier or label.
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Builds a module for each user-defined source using the given build options.
+async fn build_modules(
+    interner: &ThreadedInterner,
+    manager: &SourceManager,
+    php_version: PHPVersion,
+    options: ModuleBuildOptions,
+) -> Result<Vec<Module>, Error> {
+    let sources: Vec<_> = manager.source_ids_for_category(SourceCategory::UserDefined);
+    let length = sources.len();
+
+    let progress_bar = create_progress_bar(length, "🔎  Scanning", ProgressBarTheme::Magenta);
+    let mut handles = Vec::with_capacity(length);
+    for source_id in sources {
+        handles.push(tokio::spawn({
+            let interner = interner.clone();
+            let manager = manager.clone();
+            let progress_bar = progress_bar.clone();
+
+            async move {
+                let source = manager.load(&source_id)?;
+                let module = Module::build(&interner, php_version, source, options);
+                progress_bar.inc(1);
+
+                Result::<_, Error>::Ok(module)
+            }
+        }));
+    }
+
+    let mut modules = Vec::with_capacity(length);
+    for handle in handles {
+        modules.push(handle.await??);
+    }
+
+    remove_progress_bar(progress_bar);
+
+    Ok(modules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependency(kind: &'static str, target: &str) -> ClassDependency {
+        ClassDependency { kind, target: target.to_string() }
+    }
+
+    #[test]
+    fn test_find_class_cycles() {
+        let graph = BTreeMap::from([
+            ("App\\Node".to_string(), ("class", vec![dependency("references", "App\\Tree")])),
+            ("App\\Tree".to_string(), ("class", vec![dependency("references", "app\\node")])),
+            ("App\\Leaf".to_string(), ("class", vec![dependency("extends", "App\\Node")])),
+            ("App\\Loop".to_string(), ("class", vec![dependency("references", "App\\Loop")])),
+        ]);
+
+        assert_eq!(
+            find_class_cycles(&graph),
+            vec![vec!["App\\Loop".to_string()], vec!["App\\Node".to_string(), "App\\Tree".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_escape_dot() {
+        assert_eq!(escape_dot("App\\Node"), "App\\\\Node");
+        assert_eq!(escape_dot("say \"hi\""), "say \\\"hi\\\"");
+    }
+}
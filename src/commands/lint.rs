@@ -1,16 +1,31 @@
+use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Instant;
 
 use clap::Parser;
 use colored::Colorize;
+use serde::Serialize;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantNames;
 
 use mago_interner::ThreadedInterner;
 use mago_linter::Linter;
+use mago_linter::definition::RuleCategory;
+use mago_linter::plugin::Plugin;
+use mago_linter::profile::RuleProfile;
+use mago_linter::profile::RuleProfiler;
+use mago_linter::settings::NewRulesPolicy;
 use mago_linter::settings::RuleSettings;
 use mago_linter::settings::Settings;
+use mago_parser::settings::ParserSettings;
 use mago_php_version::PHPVersion;
 use mago_project::Project;
 use mago_project::ProjectBuilder;
+use mago_project::include::IncludeGraph;
 use mago_project::module::Module;
 use mago_project::module::ModuleBuildOptions;
 use mago_reflection::CodebaseReflection;
@@ -21,6 +36,7 @@ use mago_reporting::reporter::Reporter;
 use mago_reporting::reporter::ReportingFormat;
 use mago_reporting::reporter::ReportingTarget;
 use mago_source::SourceCategory;
+use mago_source::SourceIdentifier;
 use mago_source::SourceManager;
 use mago_source::error::SourceError;
 
@@ -30,10 +46,17 @@ use crate::enum_variants;
 use crate::error::Error;
 use crate::reflection::reflect_non_user_sources;
 use crate::source;
+use crate::utils::allocator;
+use crate::utils::codeowners::CodeOwners;
+use crate::utils::color;
+use crate::utils::git;
+use crate::utils::git::Changes;
 use crate::utils::indent_multiline;
+use crate::utils::limit::IssueLimit;
 use crate::utils::progress::ProgressBarTheme;
 use crate::utils::progress::create_progress_bar;
 use crate::utils::progress::remove_progress_bar;
+use crate::utils::shard::Shard;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -102,7 +125,7 @@ pub struct LintCommand {
 
     #[arg(
         long,
-        help = "List all the enabled rules alongside their descriptions",
+        help = "List all the known rules, with their category, default level, and whether they are enabled and fixable",
         conflicts_with = "explain",
         conflicts_with = "sort",
         conflicts_with = "fixable_only",
@@ -113,14 +136,88 @@ pub struct LintCommand {
     )]
     pub list_rules: bool,
 
+    /// The format in which the rules are listed by `--list-rules`.
     #[arg(
         long,
-        help = "Sort the reported issues by level, code, and location",
+        default_value_t,
+        help = "Choose the format in which `--list-rules` lists the rules",
+        ignore_case = true,
+        requires = "list_rules",
+        value_parser = enum_variants!(RuleListFormat)
+    )]
+    pub list_format: RuleListFormat,
+
+    #[arg(
+        long,
+        help = "List the rules introduced after the adopted `linter.rules_version`, pending adoption",
+        conflicts_with = "explain",
+        conflicts_with = "list_rules",
+        conflicts_with = "sort",
+        conflicts_with = "fixable_only",
+        conflicts_with = "semantics_only",
+        conflicts_with = "compilation"
+    )]
+    pub list_new_rules: bool,
+
+    #[arg(
+        long,
+        help = "Group the reported issues by level and code, instead of only sorting them by location",
         conflicts_with = "explain",
         conflicts_with = "list_rules"
     )]
     pub sort: bool,
 
+    /// Print statistics about the reported and suppressed issues, per rule and per file.
+    #[arg(
+        long,
+        help = "Print statistics about the reported and suppressed issues, per rule and per file",
+        conflicts_with = "explain",
+        conflicts_with = "list_rules"
+    )]
+    pub stats: bool,
+
+    /// Include the issues suppressed by ignore directives in the output.
+    #[arg(
+        long,
+        help = "Include the issues suppressed by ignore directives in the output",
+        conflicts_with = "explain",
+        conflicts_with = "list_rules",
+        conflicts_with = "semantics_only",
+        conflicts_with = "compilation"
+    )]
+    pub show_suppressed: bool,
+
+    /// Measure the wall time and allocations of every rule, and print the most expensive ones.
+    #[arg(
+        long,
+        help = "Measure the wall time and allocations of every rule, and print the most expensive ones",
+        conflicts_with = "explain",
+        conflicts_with = "list_rules",
+        conflicts_with = "semantics_only",
+        conflicts_with = "compilation"
+    )]
+    pub profile_rules: bool,
+
+    /// Stop linting further files once an error is found.
+    #[arg(
+        long,
+        help = "Stop linting further files once an error is found, for a quick feedback loop",
+        conflicts_with = "explain",
+        conflicts_with = "list_rules"
+    )]
+    pub fail_fast: bool,
+
+    /// Stop linting further files once the given number of issues is found, and only report that many.
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Stop linting further files once the given number of issues is found, and only report that many",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        conflicts_with = "explain",
+        conflicts_with = "list_rules"
+    )]
+    pub max_diagnostics: Option<usize>,
+
     #[arg(
         short,
         long,
@@ -139,6 +236,56 @@ pub struct LintCommand {
     )]
     pub plugins: Vec<String>,
 
+    /// Only enable the rules of the given categories, overriding the configuration.
+    #[arg(
+        long = "category",
+        value_name = "CATEGORY",
+        help = "Only enable the rules of the given categories, e.g. `security`, overriding the configuration",
+        value_parser = enum_variants!(RuleCategory),
+        conflicts_with = "compilation",
+        conflicts_with = "semantics_only"
+    )]
+    pub categories: Vec<RuleCategory>,
+
+    /// Only lint the sources of the given shard, to split the work across parallel jobs.
+    #[arg(
+        long,
+        value_name = "INDEX/COUNT",
+        help = "Only lint the sources of the given shard, e.g. `2/5`, to split the work across parallel CI jobs",
+        conflicts_with = "explain",
+        conflicts_with = "list_rules"
+    )]
+    pub shard: Option<Shard>,
+
+    /// Only lint the sources changed since the given revision, or in the given revision range.
+    #[arg(
+        long,
+        value_name = "REV",
+        help = "Only lint the sources changed since the given revision, including uncommitted changes, or in the given range, e.g. `main..HEAD`",
+        conflicts_with = "explain",
+        conflicts_with = "list_rules"
+    )]
+    pub since: Option<String>,
+
+    /// Only report the issues located on the lines changed since the revision given to `--since`.
+    #[arg(
+        long,
+        help = "Only report the issues located on the lines changed since the revision given to `--since`",
+        requires = "since",
+        conflicts_with = "fail_fast",
+        conflicts_with = "max_diagnostics"
+    )]
+    pub changed_lines_only: bool,
+
+    /// Report the issues located in external and built-in sources, which are otherwise only analyzed.
+    #[arg(
+        long,
+        help = "Report the issues located in external and built-in sources, for debugging, instead of only analyzing them",
+        conflicts_with = "explain",
+        conflicts_with = "list_rules"
+    )]
+    pub report_externals: bool,
+
     /// Specify where the results should be reported.
     #[arg(
         long,
@@ -160,6 +307,17 @@ pub struct LintCommand {
     pub reporting_format: ReportingFormat,
 }
 
+/// The format in which `--list-rules` lists the rules.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Display, EnumString, VariantNames)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum RuleListFormat {
+    /// Print the rules as a human-readable table.
+    #[default]
+    Table,
+    /// Print the rules as JSON, for tooling.
+    Json,
+}
+
 pub async fn execute(command: LintCommand, mut configuration: Configuration) -> Result<ExitCode, Error> {
     let interner = ThreadedInterner::new();
 
@@ -171,12 +329,20 @@ pub async fn execute(command: LintCommand, mut configuration: Configuration) ->
         configuration.linter.plugins = command.plugins;
     }
 
+    if !command.categories.is_empty() {
+        configuration.linter.categories = command.categories;
+    }
+
     if let Some(rule) = &command.explain {
         return explain_rule(&interner, rule, &configuration);
     }
 
     if command.list_rules {
-        return list_rules(&interner, &configuration);
+        return list_rules(&interner, &configuration, command.list_format);
+    }
+
+    if command.list_new_rules {
+        return list_new_rules(&interner, &configuration);
     }
 
     // Load sources
@@ -186,31 +352,117 @@ pub async fn execute(command: LintCommand, mut configuration: Configuration) ->
         source::load(&interner, &configuration.source, !command.semantics_only, !command.semantics_only).await?
     };
 
-    let mut issues = if command.semantics_only {
-        semantics_check(&interner, &source_manager, configuration.php_version).await?
+    if let Some(framework) = configuration.framework.filter(|_| !command.semantics_only) {
+        source::insert_framework_stubs(&source_manager, framework);
+    }
+
+    let changes = match &command.since {
+        Some(revision) => {
+            let changes = git::get_changes(&configuration.source.root, revision)?;
+            if changes.is_empty() {
+                tracing::info!("No files changed since `{}`.", revision);
+            } else {
+                tracing::debug!("Found {} files changed since `{}`.", changes.len(), revision);
+            }
+
+            Some(Arc::new(changes))
+        }
+        None => None,
+    };
+
+    let selection =
+        SourceSelection { shard: command.shard, changes: changes.clone(), report_externals: command.report_externals };
+    let profiler = command.profile_rules.then(|| Arc::new(RuleProfiler::new(allocator::allocated)));
+    let limit = Arc::new(IssueLimit::new(command.fail_fast, command.max_diagnostics));
+    let (issues, suppressed) = if command.semantics_only {
+        let issues = semantics_check(
+            &interner,
+            &source_manager,
+            configuration.php_version,
+            configuration.parser_settings(),
+            &selection,
+            &limit,
+        )
+        .await?;
+
+        (issues, IssueCollection::new())
     } else if command.compilation {
-        compilation_check(&interner, &source_manager, configuration.php_version).await?
+        let issues = compilation_check(
+            &interner,
+            &source_manager,
+            configuration.php_version,
+            configuration.parser_settings(),
+            &selection,
+        )
+        .await?;
+
+        (issues, IssueCollection::new())
     } else {
-        lint_check(&interner, &source_manager, &configuration).await?
+        lint_check(&interner, &source_manager, &configuration, &selection, profiler.as_ref(), &limit).await?
     };
 
+    let (issues, suppressed) = match changes.filter(|_| command.changed_lines_only) {
+        Some(changes) => (
+            filter_changed_lines(&interner, &source_manager, &changes, issues),
+            filter_changed_lines(&interner, &source_manager, &changes, suppressed),
+        ),
+        None => (issues, suppressed),
+    };
+
+    let issues = apply_profiles(&interner, &configuration, issues);
+
+    // Whether the run fails is determined by every issue found, including the ones left out by the limit.
     let issues_contain_errors = issues.has_minimum_level(Level::Error);
+    let mut issues = limit.apply(&interner, issues);
+    let statistics = if command.stats { Some(IssueStatistics::collect(&interner, &issues, &suppressed)) } else { None };
 
-    let reporter = Reporter::new(interner, source_manager, command.reporting_target);
+    if command.show_suppressed {
+        issues.extend(suppressed.into_iter().map(|issue| {
+            let level = issue.level;
 
-    if command.sort {
-        issues = issues.sorted();
+            Issue { level: Level::Note, ..issue }.with_note(format!(
+                "This issue is suppressed by an ignore directive, it would otherwise be reported as {}.",
+                get_level_name(level)
+            ))
+        }));
     }
 
+    let reporter = Reporter::new(interner, source_manager, command.reporting_target)
+        .with_colors(color::is_enabled(command.reporting_target))
+        .with_level_sorting(command.sort);
+
     if command.fixable_only {
         reporter.report(issues.only_fixable(), command.reporting_format)?;
     } else {
         reporter.report(issues, command.reporting_format)?;
     }
 
+    if let Some(statistics) = statistics {
+        statistics.print();
+    }
+
+    if let Some(profiler) = profiler {
+        print_rule_profiles(&profiler.profiles());
+    }
+
     Ok(if issues_contain_errors { ExitCode::FAILURE } else { ExitCode::SUCCESS })
 }
 
+/// Creates the linter, attaching the given profiler to it, if any.
+fn create_profiled_linter(
+    interner: &ThreadedInterner,
+    configuration: &Configuration,
+    codebase: CodebaseReflection,
+    profiler: Option<&Arc<RuleProfiler>>,
+) -> Linter {
+    let linter = create_linter(interner, configuration, codebase);
+
+    match profiler {
+        Some(profiler) => linter.with_profiler(profiler.clone()),
+        None => linter,
+    }
+}
+
 pub(super) fn create_linter(
     interner: &ThreadedInterner,
     configuration: &Configuration,
@@ -222,17 +474,20 @@ pub(super) fn create_linter(
         settings = settings.with_default_plugins(default_plugins);
     }
 
-    settings = settings.with_plugins(configuration.linter.plugins.clone());
+    settings = settings
+        .with_plugins(configuration.linter.plugins.clone())
+        .with_categories(configuration.linter.categories.clone())
+        .with_excluded_categories(configuration.linter.excluded_categories.clone())
+        .with_rules_version(Some(configuration.linter.rules_version().to_owned()));
+
+    if let Some(new_rules) = configuration.linter.new_rules {
+        settings = settings.with_new_rules(new_rules);
+    }
 
     for rule in &configuration.linter.rules {
-        let rule_settings = match rule.level {
-            Some(linter_level) => match linter_level {
-                LinterLevel::Off => RuleSettings::disabled(),
-                LinterLevel::Help => RuleSettings::from_level(Some(Level::Help)),
-                LinterLevel::Note => RuleSettings::from_level(Some(Level::Note)),
-                LinterLevel::Warning => RuleSettings::from_level(Some(Level::Warning)),
-                LinterLevel::Error => RuleSettings::from_level(Some(Level::Error)),
-            },
+        let rule_settings = match rule.level.map(LinterLevel::to_level) {
+            Some(None) => RuleSettings::disabled(),
+            Some(level) => RuleSettings::from_level(level),
             None => RuleSettings::enabled(),
         };
 
@@ -382,53 +637,358 @@ pub(super) fn explain_rule(
     Ok(ExitCode::SUCCESS)
 }
 
-pub(super) fn list_rules(interner: &ThreadedInterner, configuration: &Configuration) -> Result<ExitCode, Error> {
+pub(super) fn list_rules(
+    interner: &ThreadedInterner,
+    configuration: &Configuration,
+    format: RuleListFormat,
+) -> Result<ExitCode, Error> {
     let linter = create_linter(interner, configuration, CodebaseReflection::new());
+
+    let mut entries = Vec::new();
+    mago_linter::foreach_plugin!(|plugin| {
+        collect_rule_entries(&mut entries, &linter, plugin);
+    });
+
+    if let RuleListFormat::Json = format {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let format_level = |level: Option<Level>| match level {
+        Some(level) => level.to_string(),
+        None => "Off".to_string(),
+    };
+
+    let color_level = |level: Option<Level>, text: String| match level {
+        Some(Level::Error) => text.red(),
+        Some(Level::Warning) => text.yellow(),
+        Some(Level::Help) => text.green(),
+        Some(Level::Note) => text.blue(),
+        None => text.bright_black(),
+    };
+
+    let headers = ["Rule", "Category", "Default", "Level", "Fixable"];
+    let rows = entries
+        .iter()
+        .map(|entry| {
+            [
+                entry.slug.clone(),
+                entry.category.to_string(),
+                format_level(entry.default_level),
+                format_level(entry.level),
+                if entry.fixable { "Yes" } else { "No" }.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = headers.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let header = headers
+        .iter()
+        .zip(widths)
+        .map(|(header, width)| format!("{:<width$}", header, width = width))
+        .collect::<Vec<_>>()
+        .join("  ");
+
+    println!("{}", header.bold().underline());
+    for (entry, row) in entries.iter().zip(rows) {
+        let [slug, category, default_level, level, fixable] = row;
+        let slug = format!("{:<width$}", slug, width = widths[0]);
+
+        println!(
+            "{}  {:<category_width$}  {}  {}  {:<fixable_width$}",
+            if entry.level.is_some() { slug.normal() } else { slug.bright_black() },
+            category,
+            color_level(entry.default_level, format!("{:<width$}", default_level, width = widths[2])),
+            color_level(entry.level, format!("{:<width$}", level, width = widths[3])).bold(),
+            fixable,
+            category_width = widths[1],
+            fixable_width = widths[4],
+        );
+    }
+
+    let enabled = entries.iter().filter(|entry| entry.level.is_some()).count();
+    let fixable = entries.iter().filter(|entry| entry.level.is_some() && entry.fixable).count();
+
+    println!();
+    println!(
+        "{} rule(s), {} enabled under the current configuration, {} of which are fixable.",
+        entries.len(),
+        enabled,
+        fixable
+    );
+    println!("{}", "For more information about a rule, run `mago lint --explain <RULE>`.".bright_black());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Collects the rules of the given plugin, along with their level under the configuration of the given linter.
+fn collect_rule_entries(entries: &mut Vec<RuleEntry>, linter: &Linter, plugin: impl Plugin) {
+    let plugin_definition = plugin.get_definition();
+    for rule in plugin.get_rules() {
+        let definition = rule.get_definition();
+        let slug = format!("{}/{}", plugin_definition.get_slug(), definition.get_slug());
+
+        entries.push(RuleEntry {
+            level: linter.get_rule_level(&slug),
+            slug,
+            category: definition.category.unwrap_or(plugin_definition.category),
+            default_level: definition.level,
+            fixable: definition.fixable,
+            introduced_in: definition.introduced_in,
+            deprecated: definition.deprecated,
+            description: definition.description,
+        });
+    }
+}
+
+/// A rule, as listed by `--list-rules`.
+#[derive(Debug, Serialize)]
+struct RuleEntry {
+    /// The slug of the rule, e.g. `analysis/instantiation`.
+    slug: String,
+    /// The effective category of the rule.
+    category: RuleCategory,
+    /// The default level of the rule, `None` if it is disabled by default.
+    default_level: Option<Level>,
+    /// The level of the rule under the current configuration, `None` if it is disabled.
+    level: Option<Level>,
+    /// Whether the rule proposes fixes for the issues it reports.
+    fixable: bool,
+    /// The version of Mago the rule was introduced in, if known.
+    introduced_in: Option<&'static str>,
+    /// Whether the rule is deprecated.
+    deprecated: bool,
+    /// The description of the rule.
+    description: &'static str,
+}
+
+/// Lists the rules introduced after the adopted `linter.rules_version`, which are handled according
+/// to the `linter.new_rules` policy until they are adopted.
+pub(super) fn list_new_rules(interner: &ThreadedInterner, configuration: &Configuration) -> Result<ExitCode, Error> {
+    let rules_version = configuration.linter.rules_version().to_owned();
+    let policy = configuration.linter.new_rules.unwrap_or_default();
+
+    // Enable the new rules, so that they are listed with their default level.
+    let mut configuration = configuration.clone();
+    configuration.linter.new_rules = Some(NewRulesPolicy::On);
+
+    let linter = create_linter(interner, &configuration, CodebaseReflection::new());
+    let settings = linter.get_settings();
     let configured_rules = linter.get_configured_rules();
-    if configured_rules.is_empty() {
-        println!("{}", "No rules are currently configured or enabled.".bright_red());
+    let new_rules = configured_rules
+        .iter()
+        .filter(|configured_rule| {
+            settings.get_rule_settings(&configured_rule.slug).is_none()
+                && settings.is_new_rule(configured_rule.rule.get_definition().introduced_in)
+        })
+        .collect::<Vec<_>>();
+
+    if new_rules.is_empty() {
+        println!("{}", format!("No rules were introduced after version {}.", rules_version).bright_green());
 
         return Ok(ExitCode::SUCCESS);
     }
 
-    println!("Listing {} rule(s):", configured_rules.len());
-    for (i, configured_rule) in configured_rules.iter().enumerate() {
-        let current_level = match linter.get_rule_level(&configured_rule.slug) {
-            Some(level) => match level {
-                Level::Error => "Error".red(),
-                Level::Warning => "Warning".yellow(),
-                Level::Help => "Help".green(),
-                Level::Note => "Note".blue(),
-            },
-            None => {
-                continue;
-            }
-        };
+    let status = match policy {
+        NewRulesPolicy::Warn => "Enabled, reporting issues as warnings at most".yellow().bold(),
+        NewRulesPolicy::Off => "Disabled".red().bold(),
+        NewRulesPolicy::On => "Enabled".green().bold(),
+    };
+
+    println!("Listing {} rule(s) introduced after version {}, pending adoption:", new_rules.len(), rules_version);
+    for (i, configured_rule) in new_rules.iter().enumerate() {
+        let definition = configured_rule.rule.get_definition();
 
         let title = format!("{:2}. {}", i + 1, configured_rule.slug.underline());
-        let description = indent_multiline(configured_rule.rule.get_definition().description, "      ", false);
-        let explanation = format!("mago lint --explain {}", configured_rule.slug);
-        let footer = format!("for more information, run {}", explanation.underline());
+        let description = indent_multiline(definition.description, "      ", false);
 
         println!();
         println!("{}", title.bold().bright_purple());
         println!();
-        println!("    - Level: {}", current_level.bold());
+        println!("    - Introduced in: {}", definition.introduced_in.unwrap_or_default().bold());
+        println!("    - Category: {}", configured_rule.category.to_string().bold());
+        println!("    - Default level: {}", configured_rule.level.to_string().bold());
+        println!("    - Status: {}", status);
         println!("    - {}", description);
-        println!();
-        println!("    {}", footer.bright_black());
     }
 
+    let footer = "To adopt these rules, set `linter.rules_version` to the latest version they were introduced in, or configure them in `linter.rules`.";
+
+    println!();
+    println!("{}", footer.bright_black());
+
     Ok(ExitCode::SUCCESS)
 }
 
+/// Statistics about the reported and suppressed issues, used to track the burn-down of suppressed issues.
+struct IssueStatistics {
+    /// The number of reported and suppressed issues, keyed by rule.
+    rules: BTreeMap<String, (usize, usize)>,
+    /// The number of reported and suppressed issues, keyed by file.
+    files: BTreeMap<String, (usize, usize)>,
+}
+
+impl IssueStatistics {
+    fn collect(interner: &ThreadedInterner, issues: &IssueCollection, suppressed: &IssueCollection) -> Self {
+        let mut statistics = Self { rules: BTreeMap::new(), files: BTreeMap::new() };
+
+        for (issue, is_suppressed) in
+            issues.iter().map(|issue| (issue, false)).chain(suppressed.iter().map(|issue| (issue, true)))
+        {
+            let rule = issue.code.clone().unwrap_or_else(|| "(semantics)".to_string());
+            let file = issue
+                .annotations
+                .iter()
+                .find(|annotation| annotation.is_primary())
+                .map(|annotation| interner.lookup(&annotation.span.start.source.0).to_string())
+                .unwrap_or_else(|| "(unknown)".to_string());
+
+            for counts in [statistics.rules.entry(rule).or_default(), statistics.files.entry(file).or_default()] {
+                if is_suppressed {
+                    counts.1 += 1;
+                } else {
+                    counts.0 += 1;
+                }
+            }
+        }
+
+        statistics
+    }
+
+    fn print(&self) {
+        let (reported, suppressed) = self
+            .rules
+            .values()
+            .fold((0, 0), |(reported, suppressed), counts| (reported + counts.0, suppressed + counts.1));
+
+        println!();
+        println!(
+            "{} {} reported, {} suppressed",
+            "Issue statistics:".bold().underline(),
+            reported.to_string().bold(),
+            suppressed.to_string().bold().yellow()
+        );
+
+        Self::print_table("Rule", &self.rules);
+        Self::print_table("File", &self.files);
+    }
+
+    fn print_table(title: &str, counts: &BTreeMap<String, (usize, usize)>) {
+        if counts.is_empty() {
+            return;
+        }
+
+        let width = counts.keys().map(|key| key.len()).chain([title.len()]).max().unwrap_or_default();
+
+        println!();
+        println!("  {:width$}  {:>8}  {:>10}", title.bold(), "Reported".bold(), "Suppressed".bold(), width = width);
+        for (key, (reported, suppressed)) in counts {
+            let suppressed = if *suppressed > 0 { suppressed.to_string().yellow() } else { "0".dimmed() };
+
+            println!("  {:width$}  {:>8}  {:>10}", key, reported, suppressed, width = width);
+        }
+    }
+}
+
+/// The number of rules printed by `--profile-rules`, the most expensive first.
+const PROFILED_RULES_SHOWN: usize = 20;
+
+fn print_rule_profiles(profiles: &[RuleProfile]) {
+    let total = profiles.iter().map(|profile| profile.duration).sum::<std::time::Duration>();
+    let shown = &profiles[..profiles.len().min(PROFILED_RULES_SHOWN)];
+
+    println!();
+    println!(
+        "{} {} rules ran for {:.2?} in total, the {} most expensive are shown",
+        "Rule profile:".bold().underline(),
+        profiles.len().to_string().bold(),
+        total,
+        shown.len()
+    );
+
+    if shown.is_empty() {
+        return;
+    }
+
+    let width = shown.iter().map(|profile| profile.slug.len()).chain(["Rule".len()]).max().unwrap_or_default();
+
+    println!();
+    println!(
+        "  {:width$}  {:>10}  {:>6}  {:>10}  {:>8}",
+        "Rule".bold(),
+        "Time".bold(),
+        "Share".bold(),
+        "Allocated".bold(),
+        "Issues".bold(),
+        width = width
+    );
+    for profile in shown {
+        let share = if total.is_zero() { 0.0 } else { profile.duration.as_secs_f64() / total.as_secs_f64() * 100.0 };
+
+        println!(
+            "  {:width$}  {:>10}  {:>5.1}%  {:>10}  {:>8}",
+            profile.slug,
+            format!("{:.2?}", profile.duration),
+            share,
+            format_bytes(profile.allocated),
+            profile.issues,
+            width = width
+        );
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+fn get_level_name(level: Level) -> &'static str {
+    match level {
+        Level::Error => "an error",
+        Level::Warning => "a warning",
+        Level::Help => "a help",
+        Level::Note => "a note",
+    }
+}
+
+/// Lints the user-defined sources, returning the reported issues, and the issues suppressed by ignore directives.
+///
+/// With a selection, every user-defined source is still scanned to build the codebase reflection, but only the
+/// selected sources are linted, and only the issues located in them are returned.
 #[inline]
 pub(super) async fn lint_check(
     interner: &ThreadedInterner,
     manager: &SourceManager,
     configuration: &Configuration,
-) -> Result<IssueCollection, Error> {
+    selection: &SourceSelection,
+    profiler: Option<&Arc<RuleProfiler>>,
+    limit: &Arc<IssueLimit>,
+) -> Result<(IssueCollection, IssueCollection), Error> {
+    if configuration.low_memory {
+        return lint_check_low_memory(interner, manager, configuration, selection, profiler, limit).await;
+    }
+
     let php_version = configuration.php_version;
+    let parser_settings = configuration.parser_settings();
     let sources: Vec<_> = manager.source_ids_for_category(SourceCategory::UserDefined);
     let length = sources.len();
 
@@ -437,6 +997,7 @@ pub(super) async fn lint_check(
         reflect_non_user_sources(interner, php_version, manager).await?,
     );
 
+    let scan_start = Instant::now();
     let scan_progress = create_progress_bar(length, "🔎  Scanning", ProgressBarTheme::Yellow);
     let mut handles = Vec::with_capacity(length);
     for source_id in sources {
@@ -444,59 +1005,286 @@ pub(super) async fn lint_check(
             let interner = interner.clone();
             let manager = manager.clone();
             let scan_progress = scan_progress.clone();
+            let selection = selection.clone();
 
             async move {
                 // Step 1: load the source
-                let source = manager.load(&source_id)?;
-                // Step 2: build module
-                let module = Module::build(&interner, php_version, source, ModuleBuildOptions::default());
-
+                let start = Instant::now();
+                let source = manager.load(&source_id);
                 scan_progress.inc(1);
 
-                Result::<_, Error>::Ok(module)
+                let source = source.map_err(|error| report_read_failure(&interner, &selection, &source_id, error))?;
+                let load_duration = start.elapsed();
+                // Step 2: build module
+                let module = Module::build(
+                    &interner,
+                    php_version,
+                    source,
+                    ModuleBuildOptions::default().with_parser_settings(parser_settings),
+                );
+
+                tracing::trace!(
+                    file = interner.lookup(&source_id.0),
+                    load_us = load_duration.as_micros() as u64,
+                    build_us = (start.elapsed() - load_duration).as_micros() as u64,
+                    "Scanned source.",
+                );
+
+                Ok(module)
             }
         }));
     }
 
+    let mut results = Vec::with_capacity(length + 1);
     for handle in handles {
-        builder.add_module(handle.await??);
+        match handle.await? {
+            Ok(module) => builder.add_module(module),
+            Err(issues) => results.push(issues),
+        }
     }
 
     remove_progress_bar(scan_progress);
 
-    let Project { modules, mut reflection } = builder.build(true);
+    tracing::debug!("Scanned {} sources in {:?}.", length, scan_start.elapsed());
+
+    let reflection_start = Instant::now();
+    let Project { mut modules, mut reflection } = builder.build(true);
+
+    tracing::debug!("Built the codebase reflection in {:?}.", reflection_start.elapsed());
+
+    results.push(selection.filter_issues(interner, reflection.take_issues()));
+    let include_graph = IncludeGraph::build(&configuration.source.root, &modules);
+    modules.retain(|module| selection.contains(interner, &module.source.identifier));
+
+    // Generated sources are only checked for semantic issues, as they are not meant to be edited.
+    let (generated, modules): (Vec<_>, Vec<_>) = modules.into_iter().partition(|module| {
+        configuration.generated.is_generated(interner.lookup(&module.source.identifier.0), &module.source.content)
+    });
+
+    report_generated_sources(interner, generated.iter().map(|module| &module.source.identifier));
+    for module in generated {
+        results.push(module.issues);
+        results.push(module.parse_errors.iter().map(Into::<Issue>::into).collect());
+    }
+
+    limit.record(results.iter().flat_map(IssueCollection::iter));
+
     let length = modules.len();
-    let mut results = Vec::with_capacity(length + 1);
-    results.push(reflection.take_issues());
-    let linter = create_linter(interner, configuration, reflection);
+    let linter =
+        create_profiled_linter(interner, configuration, reflection, profiler).with_include_graph(include_graph);
+    let lint_start = Instant::now();
     let lint_progress = create_progress_bar(length, "🧹  Linting", ProgressBarTheme::Red);
     let mut handles = Vec::with_capacity(length);
     for module in modules {
         handles.push(tokio::spawn({
+            let interner = interner.clone();
             let linter = linter.clone();
             let lint_progress = lint_progress.clone();
+            let limit = limit.clone();
 
             async move {
-                let mut issues = linter.lint(&module);
-                issues.extend(module.issues);
-                if let Some(error) = &module.parse_error {
-                    issues.push(Into::<Issue>::into(error));
+                if limit.is_reached() {
+                    lint_progress.inc(1);
+
+                    return Result::<_, SourceError>::Ok((IssueCollection::new(), IssueCollection::new()));
                 }
 
+                let start = Instant::now();
+                let (mut issues, suppressed) = linter.lint_with_suppressed(&module);
+
+                tracing::trace!(
+                    file = interner.lookup(&module.source.identifier.0),
+                    lint_us = start.elapsed().as_micros() as u64,
+                    "Linted source.",
+                );
+
+                issues.extend(module.issues);
+                issues.extend(module.parse_errors.iter().map(Into::<Issue>::into));
+
+                limit.record(issues.iter());
                 lint_progress.inc(1);
 
-                Result::<_, SourceError>::Ok(issues)
+                Result::<_, SourceError>::Ok((issues, suppressed))
             }
         }));
     }
 
+    let mut suppressed = IssueCollection::new();
     for handle in handles {
-        results.push(handle.await??);
+        let (issues, suppressed_issues) = handle.await??;
+
+        results.push(issues);
+        suppressed.extend(suppressed_issues);
+    }
+
+    remove_progress_bar(lint_progress);
+
+    tracing::debug!("Linted {} modules in {:?}.", length, lint_start.elapsed());
+
+    Ok((IssueCollection::from(results.into_iter().flatten()), suppressed))
+}
+
+/// Lints the user-defined sources like [`lint_check`], without holding all of them in memory at once.
+///
+/// Sources are processed in batches of one source per thread, twice: first to build the codebase reflection,
+/// then to lint them. The content of each source is released once its batch is done, and the modules are
+/// dropped instead of being kept for the linting phase, so only a batch of sources is in memory at a time.
+async fn lint_check_low_memory(
+    interner: &ThreadedInterner,
+    manager: &SourceManager,
+    configuration: &Configuration,
+    selection: &SourceSelection,
+    profiler: Option<&Arc<RuleProfiler>>,
+    limit: &Arc<IssueLimit>,
+) -> Result<(IssueCollection, IssueCollection), Error> {
+    let php_version = configuration.php_version;
+    let parser_settings = configuration.parser_settings();
+    let root = &configuration.source.root;
+    let sources: Vec<_> = manager.source_ids_for_category(SourceCategory::UserDefined);
+    let length = sources.len();
+    let batch_size = configuration.threads.max(1);
+
+    let mut builder = ProjectBuilder::from_reflection(
+        interner.clone(),
+        reflect_non_user_sources(interner, php_version, manager).await?,
+    );
+
+    let mut results = Vec::with_capacity(length + 1);
+    let mut include_graph = IncludeGraph::default();
+    let mut generated = HashSet::new();
+
+    let scan_start = Instant::now();
+    let scan_progress = create_progress_bar(length, "🔎  Scanning", ProgressBarTheme::Yellow);
+    for batch in sources.chunks(batch_size) {
+        let mut handles = Vec::with_capacity(batch.len());
+        for &source_id in batch {
+            handles.push(tokio::spawn({
+                let interner = interner.clone();
+                let manager = manager.clone();
+                let scan_progress = scan_progress.clone();
+                let selection = selection.clone();
+
+                async move {
+                    let source = manager.load(&source_id);
+                    manager.unload(&source_id);
+                    scan_progress.inc(1);
+
+                    let source =
+                        source.map_err(|error| report_read_failure(&interner, &selection, &source_id, error))?;
+
+                    Ok(Module::build(
+                        &interner,
+                        php_version,
+                        source,
+                        ModuleBuildOptions::default().with_parser_settings(parser_settings),
+                    ))
+                }
+            }));
+        }
+
+        for handle in handles {
+            let mut module = match handle.await? {
+                Ok(module) => module,
+                Err(issues) => {
+                    results.push(issues);
+
+                    continue;
+                }
+            };
+
+            module.issues.extend(module.parse_errors.iter().map(Into::<Issue>::into));
+
+            if selection.contains(interner, &module.source.identifier) {
+                results.push(std::mem::take(&mut module.issues));
+
+                let name = interner.lookup(&module.source.identifier.0);
+                if configuration.generated.is_generated(name, &module.source.content) {
+                    generated.insert(module.source.identifier);
+                }
+            }
+
+            include_graph.insert(root, &module);
+            builder.merge_module(module);
+        }
+    }
+
+    remove_progress_bar(scan_progress);
+
+    tracing::debug!("Scanned {} sources in {:?}.", length, scan_start.elapsed());
+
+    let reflection_start = Instant::now();
+    let Project { mut reflection, .. } = builder.build(true);
+
+    tracing::debug!("Built the codebase reflection in {:?}.", reflection_start.elapsed());
+
+    results.push(selection.filter_issues(interner, reflection.take_issues()));
+    limit.record(results.iter().flat_map(IssueCollection::iter));
+
+    report_generated_sources(interner, generated.iter());
+    let sources: Vec<_> = sources
+        .into_iter()
+        .filter(|source_id| selection.contains(interner, source_id) && !generated.contains(source_id))
+        .collect();
+    let length = sources.len();
+    let linter =
+        create_profiled_linter(interner, configuration, reflection, profiler).with_include_graph(include_graph);
+    let lint_start = Instant::now();
+    let lint_progress = create_progress_bar(length, "🧹  Linting", ProgressBarTheme::Red);
+    let mut suppressed = IssueCollection::new();
+    for batch in sources.chunks(batch_size) {
+        if limit.is_reached() {
+            break;
+        }
+
+        let mut handles = Vec::with_capacity(batch.len());
+        for &source_id in batch {
+            handles.push(tokio::spawn({
+                let interner = interner.clone();
+                let manager = manager.clone();
+                let linter = linter.clone();
+                let lint_progress = lint_progress.clone();
+                let limit = limit.clone();
+
+                async move {
+                    let source = manager.load(&source_id);
+                    manager.unload(&source_id);
+
+                    // Sources that cannot be read were already reported while scanning.
+                    let Ok(source) = source else {
+                        lint_progress.inc(1);
+
+                        return (IssueCollection::new(), IssueCollection::new());
+                    };
+
+                    // The reflection and the semantic issues were collected while scanning.
+                    let module = Module::build(
+                        &interner,
+                        php_version,
+                        source,
+                        ModuleBuildOptions::new(false, false).with_parser_settings(parser_settings),
+                    );
+                    let result = linter.lint_with_suppressed(&module);
+                    limit.record(result.0.iter());
+                    lint_progress.inc(1);
+
+                    result
+                }
+            }));
+        }
+
+        for handle in handles {
+            let (issues, suppressed_issues) = handle.await?;
+
+            results.push(issues);
+            suppressed.extend(suppressed_issues);
+        }
     }
 
     remove_progress_bar(lint_progress);
 
-    Ok(IssueCollection::from(results.into_iter().flatten()))
+    tracing::debug!("Linted {} sources in {:?}.", length, lint_start.elapsed());
+
+    Ok((IssueCollection::from(results.into_iter().flatten()), suppressed))
 }
 
 #[inline]
@@ -504,9 +1292,16 @@ pub(super) async fn semantics_check(
     interner: &ThreadedInterner,
     manager: &SourceManager,
     php_version: PHPVersion,
+    parser_settings: ParserSettings,
+    selection: &SourceSelection,
+    limit: &Arc<IssueLimit>,
 ) -> Result<IssueCollection, Error> {
-    // Collect all user-defined sources.
-    let sources: Vec<_> = manager.source_ids_for_category(SourceCategory::UserDefined);
+    // Collect all selected user-defined sources.
+    let sources: Vec<_> = manager
+        .source_ids_for_category(SourceCategory::UserDefined)
+        .into_iter()
+        .filter(|source_id| selection.contains(interner, source_id))
+        .collect();
     let length = sources.len();
 
     let progress_bar = create_progress_bar(length, "🔎  Scanning", ProgressBarTheme::Magenta);
@@ -517,13 +1312,36 @@ pub(super) async fn semantics_check(
             let interner = interner.clone();
             let manager = manager.clone();
             let progress_bar = progress_bar.clone();
+            let limit = limit.clone();
 
             async move {
-                let source = manager.load(&source_id)?;
-                let module = Module::build(&interner, php_version, source, ModuleBuildOptions::validation());
+                if limit.is_reached() {
+                    progress_bar.inc(1);
+
+                    return Result::<_, Error>::Ok(IssueCollection::new());
+                }
+
+                let source = match manager.load(&source_id) {
+                    Ok(source) => source,
+                    Err(error) => {
+                        progress_bar.inc(1);
+
+                        return Ok(report_read_failure(&interner, &SourceSelection::default(), &source_id, error));
+                    }
+                };
+
+                let mut module = Module::build(
+                    &interner,
+                    php_version,
+                    source,
+                    ModuleBuildOptions::validation().with_parser_settings(parser_settings),
+                );
+                module.issues.extend(module.parse_errors.iter().map(Into::<Issue>::into));
+
+                limit.record(module.issues.iter());
                 progress_bar.inc(1);
 
-                Result::<_, Error>::Ok(module)
+                Result::<_, Error>::Ok(module.issues)
             }
         }));
     }
@@ -531,18 +1349,12 @@ pub(super) async fn semantics_check(
     let mut results = Vec::with_capacity(length);
 
     for handle in handles {
-        let module = handle.await??;
-
-        if let Some(error) = &module.parse_error {
-            results.push(Into::<Issue>::into(error));
-        }
-
-        results.extend(module.issues);
+        results.extend(handle.await??);
     }
 
     remove_progress_bar(progress_bar);
 
-    Ok(IssueCollection::from(results.into_iter()))
+    Ok(IssueCollection::from(results))
 }
 
 #[inline]
@@ -550,6 +1362,8 @@ pub(super) async fn compilation_check(
     interner: &ThreadedInterner,
     manager: &SourceManager,
     php_version: PHPVersion,
+    parser_settings: ParserSettings,
+    selection: &SourceSelection,
 ) -> Result<IssueCollection, Error> {
     let sources: Vec<_> = manager.source_ids_for_category(SourceCategory::UserDefined);
     let length = sources.len();
@@ -559,6 +1373,7 @@ pub(super) async fn compilation_check(
         reflect_non_user_sources(interner, php_version, manager).await?,
     );
 
+    let scan_start = Instant::now();
     let scan_progress = create_progress_bar(length, "🔎  Scanning", ProgressBarTheme::Yellow);
     let mut handles = Vec::with_capacity(length);
     for source_id in sources {
@@ -569,24 +1384,45 @@ pub(super) async fn compilation_check(
 
             async move {
                 // Step 1: load the source
-                let source = manager.load(&source_id)?;
-                // Step 2: build module
-                let module = Module::build(&interner, php_version, source, ModuleBuildOptions::default());
-
+                let start = Instant::now();
+                let source = manager.load(&source_id);
                 scan_progress.inc(1);
 
-                Result::<_, Error>::Ok(module)
+                let source = source
+                    .map_err(|error| report_read_failure(&interner, &SourceSelection::default(), &source_id, error))?;
+                let load_duration = start.elapsed();
+                // Step 2: build module
+                let module = Module::build(
+                    &interner,
+                    php_version,
+                    source,
+                    ModuleBuildOptions::default().with_parser_settings(parser_settings),
+                );
+
+                tracing::trace!(
+                    file = interner.lookup(&source_id.0),
+                    load_us = load_duration.as_micros() as u64,
+                    build_us = (start.elapsed() - load_duration).as_micros() as u64,
+                    "Scanned source.",
+                );
+
+                Result::<_, IssueCollection>::Ok(module)
             }
         }));
     }
 
     let mut results = Vec::with_capacity(length);
     for handle in handles {
-        let mut module = handle.await??;
+        let mut module = match handle.await? {
+            Ok(module) => module,
+            Err(issues) => {
+                results.extend(issues);
 
-        if let Some(error) = &module.parse_error {
-            results.push(Into::<Issue>::into(error));
-        }
+                continue;
+            }
+        };
+
+        results.extend(module.parse_errors.iter().map(Into::<Issue>::into));
 
         results.extend(std::mem::take(&mut module.issues));
 
@@ -598,5 +1434,165 @@ pub(super) async fn compilation_check(
 
     remove_progress_bar(scan_progress);
 
-    Ok(IssueCollection::from(results.into_iter()))
+    tracing::debug!("Scanned and reflected {} sources in {:?}.", length, scan_start.elapsed());
+
+    Ok(selection.filter_issues(interner, IssueCollection::from(results)))
+}
+
+/// Reports the generated sources, which are not linted.
+fn report_generated_sources<'a>(interner: &ThreadedInterner, sources: impl Iterator<Item = &'a SourceIdentifier>) {
+    let mut count = 0;
+    for source_id in sources {
+        tracing::debug!("Skipping linting for generated source '{}'.", interner.lookup(&source_id.0));
+
+        count += 1;
+    }
+
+    if count > 0 {
+        tracing::info!("Skipped linting {} generated source files.", count);
+    }
+}
+
+/// Keeps the issues located on the changed lines, and the issues without a location.
+fn filter_changed_lines(
+    interner: &ThreadedInterner,
+    manager: &SourceManager,
+    changes: &Changes,
+    issues: IssueCollection,
+) -> IssueCollection {
+    issues
+        .into_iter()
+        .filter(|issue| {
+            let Some(annotation) = issue.annotations.iter().find(|annotation| annotation.is_primary()) else {
+                return true;
+            };
+
+            let Ok(source) = manager.load(&annotation.span.start.source) else {
+                return true;
+            };
+
+            let lines =
+                source.line_number(annotation.span.start.offset)..source.line_number(annotation.span.end.offset) + 1;
+
+            changes.contains_lines(interner.lookup(&source.identifier.0), lines)
+        })
+        .collect()
+}
+
+/// Applies the strictness profiles of the configuration to the given issues, scaling the level of each issue
+/// according to the first profile applying to the source it is located in.
+fn apply_profiles(
+    interner: &ThreadedInterner,
+    configuration: &Configuration,
+    issues: IssueCollection,
+) -> IssueCollection {
+    let profiles = &configuration.linter.profiles;
+    if profiles.is_empty() {
+        return issues;
+    }
+
+    let code_owners = if profiles.iter().any(|profile| !profile.owners.is_empty()) {
+        CodeOwners::load(&configuration.source.root).unwrap_or_else(|| {
+            tracing::warn!("Linter profiles apply to code owners, but no `CODEOWNERS` file was found.");
+
+            CodeOwners::default()
+        })
+    } else {
+        CodeOwners::default()
+    };
+
+    issues
+        .into_iter()
+        .filter_map(|issue| {
+            let Some(annotation) = issue.annotations.iter().find(|annotation| annotation.is_primary()) else {
+                return Some(issue);
+            };
+
+            let name = interner.lookup(&annotation.span.start.source.0);
+            let owners = code_owners.get_owners(name);
+            let Some(profile) = profiles.iter().find(|profile| profile.applies_to(name, owners)) else {
+                return Some(issue);
+            };
+
+            let level = match profile.escalate_to.and_then(LinterLevel::to_level) {
+                Some(escalated) => escalated.max(issue.level),
+                None => issue.level,
+            };
+
+            match profile.minimum_level.map(LinterLevel::to_level) {
+                Some(None) => None,
+                Some(Some(minimum)) if level < minimum => None,
+                _ => Some(Issue { level, ..issue }),
+            }
+        })
+        .collect()
+}
+
+/// Reports a source that could not be read as an issue, so that the other sources are still checked.
+///
+/// The issue is only reported if the source is selected.
+fn report_read_failure(
+    interner: &ThreadedInterner,
+    selection: &SourceSelection,
+    source_id: &SourceIdentifier,
+    error: SourceError,
+) -> IssueCollection {
+    if !selection.contains(interner, source_id) {
+        return IssueCollection::new();
+    }
+
+    let name = interner.lookup(&source_id.0);
+
+    IssueCollection::from(vec![
+        Issue::error(format!("Failed to read source `{}`: {}.", name, error))
+            .with_help("Make sure the file exists and is readable, or exclude it from the sources."),
+    ])
+}
+
+/// The user-defined sources whose issues are reported, e.g. the sources of a shard.
+///
+/// Every user-defined source is still scanned to build the codebase reflection, but only the selected sources
+/// are linted, and only the issues located in them are reported. External and built-in sources are analyzed
+/// for the reflection, but are never selected, unless their issues are explicitly requested.
+#[derive(Debug, Clone, Default)]
+pub(super) struct SourceSelection {
+    /// The shard of the sources to select, if any.
+    shard: Option<Shard>,
+
+    /// The changes since a revision, to only select the changed sources, if any.
+    changes: Option<Arc<Changes>>,
+
+    /// Whether the external and built-in sources are selected as well.
+    report_externals: bool,
+}
+
+impl SourceSelection {
+    /// Determines whether the given source is selected.
+    fn contains(&self, interner: &ThreadedInterner, source_id: &SourceIdentifier) -> bool {
+        if !self.report_externals && !source_id.category().is_user_defined() {
+            return false;
+        }
+
+        let name = interner.lookup(&source_id.0);
+
+        self.shard.is_none_or(|shard| shard.contains(name))
+            && self.changes.as_ref().is_none_or(|changes| changes.contains_file(name))
+    }
+
+    /// Keeps the issues located in the selected sources.
+    ///
+    /// Issues without a location are kept by the first shard only, so that they are reported once.
+    fn filter_issues(&self, interner: &ThreadedInterner, issues: IssueCollection) -> IssueCollection {
+        if self.report_externals && self.shard.is_none() && self.changes.is_none() {
+            return issues;
+        }
+
+        issues
+            .into_iter()
+            .filter(|issue| match issue.annotations.iter().find(|annotation| annotation.is_primary()) {
+                Some(annotation) => self.contains(interner, &annotation.span.start.source),
+                None => self.shard.is_none_or(|shard| shard.is_first()),
+            })
+            .collect()
+    }
 }
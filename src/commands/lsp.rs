@@ -0,0 +1,108 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use mago_interner::ThreadedInterner;
+use mago_language_server::Workspace;
+use mago_project::Project;
+use mago_project::ProjectBuilder;
+use mago_project::module::Module;
+use mago_project::module::ModuleBuildOptions;
+use mago_source::SourceCategory;
+
+use crate::commands::lint::create_linter;
+use crate::config::Configuration;
+use crate::error::Error;
+use crate::reflection::reflect_non_user_sources;
+use crate::source;
+
+/// Represents the `lsp` command, which starts the language server.
+#[derive(Parser, Debug)]
+#[command(
+    name = "lsp",
+    about = "Start the language server, communicating over stdin and stdout",
+    long_about = r#"
+The `lsp` command starts a Language Server Protocol server for the project.
+
+The project is analyzed once on startup; open documents are then linted as they change, and
+lint fixes are offered to the editor as code actions, alongside `Fix all safe issues` and
+`Organize imports` source actions.
+
+Classes, functions, and constants can be navigated with go-to-definition and find-references,
+and hovering them shows their signature along with the summary of their docblock. The outline
+of open documents, and fuzzy workspace symbol search are available as well.
+
+Code is formatted as it is typed: typing `;` or `}` formats the statement it ends, and typing
+a newline formats the previous line, leaving the rest of the document untouched. Semantic
+tokens, computed from the resolved names of each document, provide accurate highlighting.
+
+Completion of variables, class members, and imported symbols can be enabled by setting
+`completion = true` in the `[lsp]` section of the configuration. The class of member access
+receivers is inferred from type hints, docblocks, and preceding assignments.
+
+The server communicates over stdin and stdout, and is meant to be started by an editor.
+"#
+)]
+pub struct LspCommand {}
+
+/// Executes the `lsp` command with the provided configuration.
+///
+/// # Arguments
+///
+/// * `command` - The `LspCommand` structure containing user-specified options.
+/// * `configuration` - The application configuration loaded from file or defaults.
+///
+/// # Returns
+///
+/// A `Result` containing the exit code once the client has shut down the server, or an error.
+pub async fn execute(_command: LspCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+    let php_version = configuration.php_version;
+
+    let manager = source::load(&interner, &configuration.source, true, true).await?;
+    if let Some(framework) = configuration.framework {
+        source::insert_framework_stubs(&manager, framework);
+    }
+
+    let mut builder = ProjectBuilder::from_reflection(
+        interner.clone(),
+        reflect_non_user_sources(&interner, php_version, &manager).await?,
+    );
+
+    let sources = manager.source_ids_for_category(SourceCategory::UserDefined);
+    let mut handles = Vec::with_capacity(sources.len());
+    for source_id in sources {
+        handles.push(tokio::spawn({
+            let interner = interner.clone();
+            let manager = manager.clone();
+
+            async move {
+                let source = manager.load(&source_id)?;
+
+                Result::<_, Error>::Ok(Module::build(&interner, php_version, source, ModuleBuildOptions::default()))
+            }
+        }));
+    }
+
+    for handle in handles {
+        builder.add_module(handle.await??);
+    }
+
+    let Project { reflection, .. } = builder.build(true);
+    let linter = create_linter(&interner, &configuration, reflection.clone());
+
+    mago_language_server::serve(Workspace {
+        snapshot: interner.snapshot(),
+        interner,
+        php_version,
+        linter,
+        format_settings: configuration.format.get_settings(),
+        completion: configuration.lsp.is_completion_enabled(),
+        manager,
+        codebase: reflection,
+        root: configuration.source.root,
+    })
+    .await;
+
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::CommandFactory;
+use clap::Parser;
+use clap_mangen::Man;
+
+use crate::commands::MagoCli;
+use crate::error::Error;
+
+/// Represents the `man` command, which generates man pages from the command line interface.
+#[derive(Parser, Debug)]
+#[command(
+    name = "man",
+    about = "Generate man pages",
+    long_about = r#"
+The `man` command generates man pages for Mago from the definition of its command line interface.
+
+The pages are generated from the installed version of Mago, so they always match its commands and flags.
+
+By default, the page of the `mago` command is printed to stdout, e.g. `mago man | man -l -`.
+Use `--output` to write the pages of `mago` and of each of its commands to a directory instead.
+"#
+)]
+pub struct ManCommand {
+    /// The directory to write the man pages to.
+    #[arg(long, short, help = "Write the pages of every command to the given directory", value_name = "DIRECTORY")]
+    pub output: Option<PathBuf>,
+}
+
+/// Executes the `man` command.
+///
+/// # Arguments
+///
+/// * `command` - The `ManCommand` structure containing user-specified options.
+///
+/// # Returns
+///
+/// An `ExitCode` indicating the success of the command.
+///
+/// # Errors
+///
+/// An error is returned if the pages could not be written.
+pub fn execute(command: ManCommand) -> Result<ExitCode, Error> {
+    let cli = MagoCli::command();
+
+    match command.output {
+        Some(directory) => {
+            std::fs::create_dir_all(&directory).map_err(Error::WritingOutput)?;
+            clap_mangen::generate_to(cli, &directory).map_err(Error::WritingOutput)?;
+
+            tracing::info!("Man pages written to `{}`.", directory.display());
+        }
+        None => {
+            Man::new(cli).render(&mut std::io::stdout()).map_err(Error::WritingOutput)?;
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
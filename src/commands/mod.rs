@@ -1,21 +1,46 @@
+use std::path::PathBuf;
+
+use clap::ArgAction;
 use clap::Parser;
+use clap::Subcommand;
 use clap::builder::Styles;
 use clap::builder::styling::AnsiColor;
 use clap::builder::styling::Effects;
 
 use crate::commands::ast::AstCommand;
+use crate::commands::completions::CompletionsCommand;
+use crate::commands::config::ConfigCommand;
+use crate::commands::duplicates::DuplicatesCommand;
 use crate::commands::find::FindCommand;
 use crate::commands::fix::FixCommand;
 use crate::commands::format::FormatCommand;
+use crate::commands::graph::GraphCommand;
 use crate::commands::lint::LintCommand;
+use crate::commands::lsp::LspCommand;
+use crate::commands::man::ManCommand;
+use crate::commands::report::ReportCommand;
+use crate::commands::self_test::SelfTestCommand;
 use crate::commands::self_update::SelfUpdateCommand;
+use crate::commands::undo::UndoCommand;
+use crate::config::overrides::ConfigurationOverride;
+use crate::enum_variants;
+use crate::utils::color::ColorPolicy;
 
 pub mod ast;
+pub mod completions;
+pub mod config;
+pub mod duplicates;
 pub mod find;
 pub mod fix;
 pub mod format;
+pub mod graph;
 pub mod lint;
+pub mod lsp;
+pub mod man;
+pub mod report;
+pub mod self_test;
 pub mod self_update;
+pub mod undo;
 
 pub const CLAP_STYLING: Styles = Styles::styled()
     .header(AnsiColor::Green.on_default().effects(Effects::BOLD))
@@ -49,6 +74,59 @@ Here’s what makes Mago special:
 
 🛠  Start transforming your PHP workflow today! Explore the commands below to unleash the full power of Mago 💻
 "#)]
+pub struct MagoCli {
+    /// Override a configuration setting, taking precedence over the configuration file and the environment.
+    #[arg(
+        long = "config",
+        global = true,
+        value_name = "KEY=VALUE",
+        help = "Override a configuration setting, e.g. `--config format.print_width=100`; can be repeated"
+    )]
+    pub overrides: Vec<ConfigurationOverride>,
+
+    /// Increase the verbosity of the logs, once for debug messages, and twice for trace messages.
+    #[arg(
+        long,
+        short = 'v',
+        global = true,
+        action = ArgAction::Count,
+        help = "Increase the verbosity of the logs: `-v` for debug messages, `-vv` for trace messages"
+    )]
+    pub verbose: u8,
+
+    /// Write every log message, including per-file timings, to the given file as JSON lines.
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        help = "Write detailed logs, including per-file and per-rule timings, to the given file as JSON lines"
+    )]
+    pub log_file: Option<PathBuf>,
+
+    /// Whether to color the output.
+    #[arg(
+        long,
+        global = true,
+        default_value_t,
+        help = "Whether to color the output; `auto` honors the `NO_COLOR` and `CLICOLOR` environment variables",
+        ignore_case = true,
+        value_parser = enum_variants!(ColorPolicy)
+    )]
+    pub color: ColorPolicy,
+
+    /// Release the content of each file once it is processed, trading speed for a lower memory usage.
+    #[arg(
+        long,
+        global = true,
+        help = "Reduce memory usage on very large codebases by loading and releasing files one batch at a time"
+    )]
+    pub low_memory: bool,
+
+    #[command(subcommand)]
+    pub command: MagoCommand,
+}
+
+#[derive(Subcommand, Debug)]
 pub enum MagoCommand {
     #[command(name = "ast")]
     Ast(AstCommand),
@@ -60,6 +138,24 @@ pub enum MagoCommand {
     Format(FormatCommand),
     #[command(name = "find")]
     Find(FindCommand),
+    #[command(name = "graph")]
+    Graph(GraphCommand),
+    #[command(name = "duplicates")]
+    Duplicates(DuplicatesCommand),
+    #[command(name = "lsp")]
+    Lsp(LspCommand),
+    #[command(name = "self-test")]
+    SelfTest(SelfTestCommand),
     #[command(name = "self-update")]
     SelfUpdate(SelfUpdateCommand),
+    #[command(name = "completions")]
+    Completions(CompletionsCommand),
+    #[command(name = "man")]
+    Man(ManCommand),
+    #[command(name = "config")]
+    Config(ConfigCommand),
+    #[command(name = "report")]
+    Report(ReportCommand),
+    #[command(name = "undo")]
+    Undo(UndoCommand),
 }
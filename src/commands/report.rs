@@ -0,0 +1,218 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use ahash::HashSet;
+use clap::Parser;
+use clap::Subcommand;
+
+use mago_interner::ThreadedInterner;
+use mago_reporting::IssueCollection;
+use mago_reporting::Level;
+use mago_reporting::reporter::Reporter;
+use mago_reporting::reporter::ReportingFormat;
+use mago_reporting::reporter::ReportingTarget;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+use crate::consts::FRAMEWORK_STUBS;
+use crate::consts::PHP_STUBS;
+use crate::enum_variants;
+use crate::error::Error;
+use crate::utils::color;
+
+/// Represents the `report` command, which processes reports written by other commands.
+#[derive(Parser, Debug)]
+#[command(
+    name = "report",
+    about = "Process the reports written by other commands, e.g. merge or compare the reports of several runs",
+    long_about = r#"
+The `report` command processes the reports written by other commands in the `json` format.
+
+Use `mago report merge` to combine the reports of runs split across parallel CI jobs with `--shard`
+into a single report, in any of the supported formats, e.g.:
+
+    mago lint --shard 1/2 --reporting-format json > lint-1.json
+    mago lint --shard 2/2 --reporting-format json > lint-2.json
+    mago report merge lint-1.json lint-2.json --reporting-format checkstyle
+
+Use `mago report diff` to compare the reports of two runs, e.g. of the target branch and a pull request, and only
+report the issues introduced by the newer one, failing when there are any:
+
+    mago lint --reporting-format json > old.json
+    mago lint --reporting-format json > new.json
+    mago report diff old.json new.json
+
+The files the issues are located in must be available at the same paths as when the reports were written.
+"#
+)]
+pub struct ReportCommand {
+    #[command(subcommand)]
+    pub action: ReportAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ReportAction {
+    /// Merge reports written in the `json` format into a single report.
+    #[command(about = "Merge reports written in the `json` format into a single report")]
+    Merge {
+        /// The reports to merge.
+        #[arg(required = true, help = "The reports to merge, written in the `json` format")]
+        files: Vec<PathBuf>,
+
+        /// Specify where the results should be reported.
+        #[arg(
+            long,
+            default_value_t,
+            help = "Specify where the results should be reported",
+            ignore_case = true,
+            value_parser = enum_variants!(ReportingTarget)
+        )]
+        reporting_target: ReportingTarget,
+
+        /// Choose the format for reporting issues.
+        #[arg(
+            long,
+            default_value_t,
+            help = "Choose the format for reporting issues",
+            ignore_case = true,
+            value_parser = enum_variants!(ReportingFormat)
+        )]
+        reporting_format: ReportingFormat,
+    },
+    /// Compare the reports of two runs, reporting the issues introduced by the newer one.
+    #[command(about = "Compare the reports of two runs, reporting the issues introduced by the newer one")]
+    Diff {
+        /// The report of the older run.
+        #[arg(help = "The report of the older run, written in the `json` format")]
+        old: PathBuf,
+
+        /// The report of the newer run.
+        #[arg(help = "The report of the newer run, written in the `json` format")]
+        new: PathBuf,
+
+        /// Specify where the results should be reported.
+        #[arg(
+            long,
+            default_value_t,
+            help = "Specify where the results should be reported",
+            ignore_case = true,
+            value_parser = enum_variants!(ReportingTarget)
+        )]
+        reporting_target: ReportingTarget,
+
+        /// Choose the format for reporting issues.
+        #[arg(
+            long,
+            default_value_t,
+            help = "Choose the format for reporting issues",
+            ignore_case = true,
+            value_parser = enum_variants!(ReportingFormat)
+        )]
+        reporting_format: ReportingFormat,
+    },
+}
+
+/// Executes the `report` command.
+///
+/// # Arguments
+///
+/// * `command` - The `ReportCommand` structure containing user-specified options.
+///
+/// # Returns
+///
+/// An `ExitCode` indicating whether the merged reports contain errors, as `lint` would, or whether the newer
+/// of the compared reports introduces any issue.
+///
+/// # Errors
+///
+/// An error is returned if a report could not be read or parsed, or the merged report could not be written.
+pub fn execute(command: ReportCommand) -> Result<ExitCode, Error> {
+    match command.action {
+        ReportAction::Merge { files, reporting_target, reporting_format } => {
+            merge(files, reporting_target, reporting_format)
+        }
+        ReportAction::Diff { old, new, reporting_target, reporting_format } => {
+            diff(old, new, reporting_target, reporting_format)
+        }
+    }
+}
+
+fn merge(
+    files: Vec<PathBuf>,
+    reporting_target: ReportingTarget,
+    reporting_format: ReportingFormat,
+) -> Result<ExitCode, Error> {
+    let (interner, manager) = create_manager();
+
+    // Issues without a location, e.g. about the configuration, are reported by every shard of a run.
+    let mut seen = HashSet::default();
+    let mut issues = IssueCollection::new();
+    for file in files {
+        let report = read_report(file, &manager)?;
+
+        issues.extend(report.into_iter().filter(|issue| seen.insert(issue.clone())));
+    }
+
+    tracing::debug!("Merged {} issues.", issues.len());
+
+    let issues_contain_errors = issues.has_minimum_level(Level::Error);
+
+    Reporter::new(interner, manager, reporting_target)
+        .with_colors(color::is_enabled(reporting_target))
+        .report(issues, reporting_format)?;
+
+    Ok(if issues_contain_errors { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn diff(
+    old: PathBuf,
+    new: PathBuf,
+    reporting_target: ReportingTarget,
+    reporting_format: ReportingFormat,
+) -> Result<ExitCode, Error> {
+    let (interner, manager) = create_manager();
+
+    let old = read_report(old, &manager)?;
+    let new = read_report(new, &manager)?;
+    let diff = IssueCollection::diff(old, new);
+
+    tracing::info!(
+        "Found {} new, {} fixed, and {} unchanged issues.",
+        diff.new.len(),
+        diff.fixed.len(),
+        diff.unchanged.len()
+    );
+
+    let introduces_issues = !diff.new.is_empty();
+
+    // Only the new issues are reported, as the fixed ones may point to code that has since changed.
+    Reporter::new(interner, manager, reporting_target)
+        .with_colors(color::is_enabled(reporting_target))
+        .report(diff.new, reporting_format)?;
+
+    Ok(if introduces_issues { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+/// Creates the source manager the reports are read into, containing the stubs.
+fn create_manager() -> (ThreadedInterner, SourceManager) {
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    // Issues may point to the stubs, which are not located on disk, so they are added beforehand.
+    for (stub, content) in PHP_STUBS {
+        manager.insert_content(stub, content, SourceCategory::BuiltIn);
+    }
+
+    for (_, stub, content) in FRAMEWORK_STUBS {
+        manager.insert_content(stub, content, SourceCategory::BuiltIn);
+    }
+
+    (interner, manager)
+}
+
+/// Reads the issues of a report written in the `json` format, adding their sources to the given manager.
+fn read_report(file: PathBuf, manager: &SourceManager) -> Result<IssueCollection, Error> {
+    let content = std::fs::read_to_string(&file).map_err(|error| Error::ReadingReport(file.clone(), error))?;
+
+    IssueCollection::from_json_report(&content, manager).map_err(|error| Error::ParsingReport(file, error))
+}
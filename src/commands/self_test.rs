@@ -0,0 +1,274 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use mago_ast::Program;
+use mago_formatter::format;
+use mago_formatter::settings::FormatSettings;
+use mago_interner::ThreadedInterner;
+use mago_linter::Linter;
+use mago_parser::parse_source;
+use mago_php_version::PHPVersion;
+use mago_project::ProjectBuilder;
+use mago_project::module::Module;
+use mago_project::module::ModuleBuildOptions;
+use mago_reflection::CodebaseReflection;
+use mago_source::Source;
+use mago_source::SourceCategory;
+
+use crate::commands::lint::create_linter;
+use crate::config::Configuration;
+use crate::consts::SELF_TEST_CORPUS;
+use crate::error::Error;
+use crate::source;
+use crate::utils::crash::catch_panic;
+use crate::utils::crash::minimize;
+use crate::utils::progress::ProgressBarTheme;
+use crate::utils::progress::create_progress_bar;
+use crate::utils::progress::remove_progress_bar;
+
+/// Represents the `self-test` command, which formats and lints a corpus of snippets to catch regressions.
+#[derive(Parser, Debug)]
+#[command(
+    name = "self-test",
+    about = "Format and lint a bundled corpus of PHP snippets, checking that Mago does not crash on them",
+    long_about = r#"
+The `self-test` command formats and lints a bundled corpus of representative PHP snippets, and optionally the PHP
+files in the given files or directories, using the formatter and linter settings of the configuration.
+
+Each snippet is checked for:
+
+- crashes of the formatter, or of the linter,
+- formatted code that no longer parses,
+- formatting that is not idempotent, i.e. formatting the formatted code changes it again.
+
+Snippets that do not parse are skipped. For every failure, the snippet is minimized to the smallest set of statements
+and lines that still reproduces it, which is printed to help reporting the issue.
+"#
+)]
+pub struct SelfTestCommand {
+    /// Test the PHP files in the given files or directories as well as the bundled corpus.
+    #[arg(help = "Test the PHP files in the given files or directories as well as the bundled corpus")]
+    pub path: Vec<PathBuf>,
+
+    /// Do not test the bundled corpus.
+    #[arg(long, help = "Do not test the bundled corpus, only the given files or directories", requires = "path")]
+    pub no_corpus: bool,
+
+    /// Print the failing snippets as they are, instead of minimizing them.
+    #[arg(long, help = "Print the failing snippets as they are, instead of minimizing them first")]
+    pub no_minimize: bool,
+}
+
+/// A failure found while testing a snippet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Failure {
+    /// The formatter panicked, with the given message.
+    FormatterCrash(String),
+    /// The formatted code does not parse, with the given error.
+    InvalidOutput(String),
+    /// Formatting the formatted code changes it again.
+    NotIdempotent,
+    /// The linter panicked, with the given message.
+    LinterCrash(String),
+}
+
+impl Failure {
+    /// Determines whether the given failure is of the same kind as this one, regardless of its message.
+    fn is_same_kind(&self, other: &Failure) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FormatterCrash(message) => write!(f, "the formatter crashed: {}", message),
+            Self::InvalidOutput(error) => write!(f, "the formatted code does not parse: {}", error),
+            Self::NotIdempotent => write!(f, "formatting is not idempotent"),
+            Self::LinterCrash(message) => write!(f, "the linter crashed: {}", message),
+        }
+    }
+}
+
+/// The outcome of testing a snippet.
+enum Outcome {
+    Passed,
+    Skipped,
+    Failed(Failure, String),
+}
+
+/// Executes the `self-test` command.
+///
+/// # Arguments
+///
+/// * `command` - The `SelfTestCommand` structure containing user-specified options.
+/// * `configuration` - The application configuration, whose formatter and linter settings are tested.
+///
+/// # Returns
+///
+/// Exit code: `0` if every snippet passed, `1` if any snippet failed.
+pub async fn execute(command: SelfTestCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+
+    let mut snippets = vec![];
+    if !command.no_corpus {
+        snippets.extend(SELF_TEST_CORPUS.iter().map(|(name, content)| (name.to_string(), content.to_string())));
+    }
+
+    if !command.path.is_empty() {
+        let manager = source::from_paths(&interner, &configuration.source, command.path, false).await?;
+        for source_id in manager.source_ids_for_category(SourceCategory::UserDefined) {
+            let source = manager.load(&source_id)?;
+
+            snippets.push((interner.lookup(&source_id.0).to_string(), source.content.to_string()));
+        }
+    }
+
+    let settings = configuration.format.get_settings();
+    let linter = create_linter(&interner, &configuration, CodebaseReflection::new());
+    let tester = SnippetTester { interner, php_version: configuration.php_version, settings, linter };
+
+    let length = snippets.len();
+    let progress_bar = create_progress_bar(length, "🧪  Testing", ProgressBarTheme::Magenta);
+    let mut handles = Vec::with_capacity(length);
+    for (name, content) in snippets {
+        handles.push(tokio::spawn({
+            let tester = tester.clone();
+            let progress_bar = progress_bar.clone();
+            let minimize = !command.no_minimize;
+
+            async move {
+                let outcome = tester.test(&name, &content, minimize);
+
+                progress_bar.inc(1);
+
+                (name, outcome)
+            }
+        }));
+    }
+
+    let mut outcomes = Vec::with_capacity(length);
+    for handle in handles {
+        outcomes.push(handle.await);
+    }
+
+    remove_progress_bar(progress_bar);
+
+    let (mut passed, mut skipped, mut failed) = (0, 0, 0);
+    for outcome in outcomes {
+        match outcome? {
+            (_, Outcome::Passed) => passed += 1,
+            (name, Outcome::Skipped) => {
+                tracing::warn!("Skipping snippet '{}' as it does not parse.", name);
+
+                skipped += 1;
+            }
+            (name, Outcome::Failed(failure, reproduction)) => {
+                tracing::error!("Snippet '{}' failed: {}.", name, failure);
+
+                println!("--- {} ---", name);
+                println!("{}", reproduction.trim_end());
+                println!();
+
+                failed += 1;
+            }
+        }
+    }
+
+    if failed == 0 {
+        tracing::info!("Tested {} snippets: {} passed, {} skipped.", length, passed, skipped);
+
+        Ok(ExitCode::SUCCESS)
+    } else {
+        tracing::error!("Tested {} snippets: {} passed, {} failed, {} skipped.", length, passed, failed, skipped);
+
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+/// Tests snippets using the formatter and linter settings of the configuration.
+#[derive(Clone)]
+struct SnippetTester {
+    interner: ThreadedInterner,
+    php_version: PHPVersion,
+    settings: FormatSettings,
+    linter: Linter,
+}
+
+impl SnippetTester {
+    /// Tests the given snippet, returning its failure along with a snippet reproducing it, if any.
+    fn test(&self, name: &str, content: &str, minimize: bool) -> Outcome {
+        let Some(result) = self.check(name, content) else {
+            return Outcome::Skipped;
+        };
+
+        match result {
+            Ok(()) => Outcome::Passed,
+            Err(failure) if minimize => {
+                let reproduction = self.minimize(name, content, &failure);
+
+                Outcome::Failed(failure, reproduction)
+            }
+            Err(failure) => Outcome::Failed(failure, content.to_string()),
+        }
+    }
+
+    /// Checks the given snippet, returning `None` if it does not parse.
+    fn check(&self, name: &str, content: &str) -> Option<Result<(), Failure>> {
+        let source = Source::standalone(&self.interner, name, content);
+        let (program, error) = parse_source(&self.interner, &source);
+        if error.is_some() {
+            return None;
+        }
+
+        Some(self.check_format(name, &source, &program).and_then(|()| self.check_lint(source)))
+    }
+
+    /// Checks that formatting the given source does not crash, and is idempotent.
+    fn check_format(&self, name: &str, source: &Source, program: &Program) -> Result<(), Failure> {
+        let formatted =
+            catch_panic(|| format(&self.interner, source, program, self.settings)).map_err(Failure::FormatterCrash)?;
+
+        let formatted_source = Source::standalone(&self.interner, name, &formatted);
+        let (formatted_program, error) = parse_source(&self.interner, &formatted_source);
+        if let Some(error) = error {
+            return Err(Failure::InvalidOutput(error.to_string()));
+        }
+
+        let reformatted = catch_panic(|| format(&self.interner, &formatted_source, &formatted_program, self.settings))
+            .map_err(Failure::FormatterCrash)?;
+
+        if reformatted != formatted {
+            return Err(Failure::NotIdempotent);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that building and linting the given source does not crash.
+    fn check_lint(&self, source: Source) -> Result<(), Failure> {
+        catch_panic(|| {
+            let module = Module::build(&self.interner, self.php_version, source, ModuleBuildOptions::default());
+
+            let mut builder = ProjectBuilder::new(self.interner.clone());
+            builder.add_module(module);
+
+            let project = builder.build(false);
+            for module in &project.modules {
+                self.linter.lint(module);
+            }
+        })
+        .map_err(Failure::LinterCrash)
+    }
+
+    /// Minimizes the given snippet to the smallest part of it that still fails with the same kind of failure.
+    fn minimize(&self, name: &str, content: &str, failure: &Failure) -> String {
+        minimize(
+            &self.interner,
+            content,
+            |candidate| matches!(self.check(name, candidate), Some(Err(other)) if failure.is_same_kind(&other)),
+        )
+    }
+}
@@ -3,6 +3,7 @@ use std::env::consts::OS;
 use std::fs;
 use std::io::BufRead;
 use std::io::Write;
+use std::path::Path;
 use std::process::ExitCode;
 
 use clap::Parser;
@@ -17,6 +18,8 @@ use self_update::update::ReleaseUpdate;
 use self_update::update::UpdateStatus;
 use self_update::version::bump_is_compatible;
 use self_update::version::bump_is_greater;
+use sha2::Digest;
+use sha2::Sha256;
 use tempfile::TempDir;
 use tracing::debug;
 use tracing::info;
@@ -33,6 +36,13 @@ use crate::error::Error;
 The `self-update` command helps keep Mago up-to-date by checking for and applying the latest updates.
 
 This command ensures you are always using the most recent version of Mago with the latest features and fixes.
+
+The release archive for the current target is downloaded from GitHub, verified against the SHA-256
+checksum published alongside it, and the current executable is then replaced atomically. Releases
+without a published checksum are refused, unless `--no-verify` is given.
+
+Requests honor the `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY`, and `NO_PROXY` environment variables,
+and a proxy can also be given explicitly using `--proxy`.
 "#
 )]
 pub struct SelfUpdateCommand {
@@ -47,9 +57,35 @@ pub struct SelfUpdateCommand {
     /// Update to a specific version by providing the version tag.
     #[arg(long, help = "Update to a specific version", value_name = "VERSION_TAG")]
     pub tag: Option<String>,
+
+    /// Allow updating to a release that has no published checksum, which is otherwise refused.
+    #[arg(
+        long,
+        help = "Allow updating to a release without a published checksum; published checksums are still verified"
+    )]
+    pub no_verify: bool,
+
+    /// The proxy to send requests through, e.g. `http://proxy.example.com:8080`.
+    #[arg(long, help = "Send requests through the given proxy", value_name = "URL")]
+    pub proxy: Option<String>,
 }
 
+/// Executes the `self-update` command.
+///
+/// This function must be called before any other thread is spawned, as it may set the proxy
+/// environment variables of the process.
 pub fn execute(command: SelfUpdateCommand) -> Result<ExitCode, Error> {
+    if let Some(proxy) = &command.proxy {
+        debug!("PROXY: {}", proxy);
+
+        // SAFETY: The command is executed before the runtime is created, while the process is
+        // still single-threaded, so no other thread can be reading the environment.
+        unsafe {
+            std::env::set_var("HTTPS_PROXY", proxy);
+            std::env::set_var("HTTP_PROXY", proxy);
+        }
+    }
+
     let mut status_builder = Update::configure();
     status_builder
         .repo_owner(REPO_OWNER)
@@ -107,7 +143,7 @@ pub fn execute(command: SelfUpdateCommand) -> Result<ExitCode, Error> {
         });
     }
 
-    let status = perform_update(release_update)?;
+    let status = perform_update(release_update, !command.no_verify)?;
 
     match status {
         UpdateStatus::UpToDate => {
@@ -121,7 +157,9 @@ pub fn execute(command: SelfUpdateCommand) -> Result<ExitCode, Error> {
     Ok(ExitCode::SUCCESS)
 }
 
-fn perform_update(release_update: Box<dyn ReleaseUpdate>) -> Result<UpdateStatus, Error> {
+/// Downloads and installs the release targeted by the given update, refusing releases without a published
+/// checksum when `require_checksum` is `true`.
+fn perform_update(release_update: Box<dyn ReleaseUpdate>, require_checksum: bool) -> Result<UpdateStatus, Error> {
     info!("Starting the update process for Mago. Current version: `{}`. Target platform: `{}`.", VERSION, TARGET);
 
     let release = match release_update.target_version() {
@@ -153,6 +191,13 @@ fn perform_update(release_update: Box<dyn ReleaseUpdate>) -> Result<UpdateStatus
     };
 
     let target_asset = get_target_asset_from_release(&release)?;
+    let checksum_asset = get_checksum_asset_from_release(&release, target_asset);
+    if checksum_asset.is_none() && require_checksum {
+        return Err(Error::SelfUpdate(SelfUpdateError::Update(format!(
+            "No checksum was published for `{}`, so the download cannot be verified. Use `--no-verify` to update anyway.",
+            target_asset.name
+        ))));
+    }
 
     debug!("Mago release status:");
     debug!("- New release asset name: {:?}", target_asset.name);
@@ -175,6 +220,23 @@ fn perform_update(release_update: Box<dyn ReleaseUpdate>) -> Result<UpdateStatus
 
     debug!("Downloaded archive to: {:?}", tmp_archive_path);
 
+    match checksum_asset {
+        Some(checksum_asset) => {
+            info!("Verifying checksum...");
+            let mut checksum = Vec::new();
+            let mut download = Download::from_url(&checksum_asset.download_url);
+            let mut headers = release_update.api_headers(&release_update.auth_token())?;
+            headers.insert("Accept", "application/octet-stream".parse().unwrap());
+            download.set_headers(headers);
+            download.download_to(&mut checksum)?;
+
+            verify_checksum(&tmp_archive_path, &String::from_utf8_lossy(&checksum))?;
+        }
+        None => {
+            warn!("No checksum was published for `{}`, skipping verification.", target_asset.name);
+        }
+    }
+
     let binary_path = release_update
         .bin_path_in_archive()
         .replace("{{ version }}", &release.version)
@@ -223,3 +285,35 @@ fn get_target_asset_from_release(release: &Release) -> Result<&ReleaseAsset, Err
             Error::SelfUpdate(SelfUpdateError::Release("No asset found for the current platform.".to_string()))
         })
 }
+
+/// Returns the asset containing the SHA-256 checksum of the given asset, named after it with a `.sha256` suffix.
+fn get_checksum_asset_from_release<'a>(release: &'a Release, asset: &ReleaseAsset) -> Option<&'a ReleaseAsset> {
+    let name = format!("{}.sha256", asset.name);
+
+    release.assets.iter().find(|candidate| candidate.name == name)
+}
+
+/// Verifies that the SHA-256 checksum of the given file matches the given checksum file.
+///
+/// The checksum file is expected to be in the format produced by `sha256sum`, i.e. the
+/// hexadecimal digest followed by the file name.
+fn verify_checksum(path: &Path, checksum_file: &str) -> Result<(), Error> {
+    let Some(expected) = checksum_file.split_whitespace().next() else {
+        return Err(Error::SelfUpdate(SelfUpdateError::Update("The published checksum is empty.".to_string())));
+    };
+
+    let content = fs::read(path).map_err(SelfUpdateError::from)?;
+    let actual = format!("{:x}", Sha256::digest(&content));
+
+    debug!("Expected checksum: {}", expected);
+    debug!("Actual checksum: {}", actual);
+
+    if !actual.eq_ignore_ascii_case(expected) {
+        return Err(Error::SelfUpdate(SelfUpdateError::Update(format!(
+            "Checksum mismatch for the downloaded archive: expected `{}`, got `{}`.",
+            expected, actual
+        ))));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use mago_source::file::write_atomically;
+
+use crate::config::Configuration;
+use crate::error::Error;
+use crate::utils::undo::DEFAULT_UNDO_DIRECTORY;
+use crate::utils::undo::find_latest_journal;
+use crate::utils::undo::find_modified_files;
+
+/// Represents the `undo` command, which restores the files modified by the last run of `fix` or `format`.
+#[derive(Parser, Debug)]
+#[command(
+    name = "undo",
+    about = "Restore the files modified by the last run of `fix` or `format` with `--backup-dir`",
+    long_about = r#"
+The `undo` command restores the original content of the files modified by the last run of `fix` or `format`
+with `--backup-dir`, which records them in a journal beforehand, e.g.:
+
+    mago fix --unsafe --backup-dir
+    mago undo
+
+Each run is recorded separately, so running `mago undo` again restores the run before it.
+
+Files modified since the run are not restored, unless `--force` is given, as their changes would be lost.
+"#
+)]
+pub struct UndoCommand {
+    /// The directory the journals were recorded in.
+    #[arg(
+        long,
+        value_name = "DIR",
+        default_value = DEFAULT_UNDO_DIRECTORY,
+        help = "The directory the journals were recorded in, as given to `--backup-dir`"
+    )]
+    pub backup_dir: PathBuf,
+
+    /// List the files that would be restored, without restoring them.
+    #[arg(long, short = 'd', help = "List the files that would be restored, without restoring them")]
+    pub dry_run: bool,
+
+    /// Restore the files even if they were modified since the run.
+    #[arg(long, help = "Restore the files even if they were modified since the run, discarding those changes")]
+    pub force: bool,
+}
+
+/// Executes the `undo` command.
+///
+/// # Arguments
+///
+/// * `command` - The `UndoCommand` structure containing user-specified options.
+/// * `configuration` - The application configuration, whose workspace the journals are relative to.
+///
+/// # Returns
+///
+/// Exit code: `0` if the files were restored, `1` if there is nothing to restore, or some files were
+/// modified since the run.
+pub fn execute(command: UndoCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let directory = configuration.source.root.join(&command.backup_dir);
+
+    let Some((run, manifest)) = find_latest_journal(&directory)? else {
+        tracing::error!("Cannot find any run to undo in '{}'.", directory.display());
+
+        return Ok(ExitCode::FAILURE);
+    };
+
+    if !command.force {
+        let modified = find_modified_files(&manifest);
+        if !modified.is_empty() {
+            for path in &modified {
+                tracing::error!("File '{}' was modified since the last run of `{}`.", path.display(), manifest.command);
+            }
+
+            tracing::error!("Use `--force` to restore these files anyway, discarding their changes.");
+
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    for file in &manifest.files {
+        if command.dry_run {
+            println!("{}", file.path.display());
+
+            continue;
+        }
+
+        let backup = run.join(&file.backup);
+        let content = std::fs::read(&backup).map_err(|error| Error::ReadingJournal(backup, error))?;
+        // Restored atomically, so that an interrupted undo never leaves a truncated file behind.
+        write_atomically(&file.path, &content).map_err(|error| Error::RestoringFile(file.path.clone(), error))?;
+
+        tracing::debug!("Restored '{}'.", file.path.display());
+    }
+
+    if command.dry_run {
+        tracing::info!("Found {} files to restore from the last run of `{}`.", manifest.files.len(), manifest.command);
+    } else {
+        // The journal is removed once restored, so that the next undo restores the run before it.
+        std::fs::remove_dir_all(&run).map_err(|error| Error::WritingJournal(run, error))?;
+
+        tracing::info!("Restored {} files modified by the last run of `{}`.", manifest.files.len(), manifest.command);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,51 @@
+use config::ConfigBuilder;
+use config::builder::BuilderState;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_duplicates::DuplicationSettings;
+
+use crate::config::ConfigurationEntry;
+use crate::error::Error;
+
+/// Configuration options for duplicated code detection.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DuplicatesConfiguration {
+    /// The minimum number of tokens a code fragment must contain to be reported as a clone.
+    ///
+    /// Defaults to `50`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_tokens: Option<usize>,
+
+    /// Whether to ignore identifiers (names and variables) when comparing code fragments.
+    ///
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_identifiers: Option<bool>,
+
+    /// Whether to ignore literals (strings and numbers) when comparing code fragments.
+    ///
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_literals: Option<bool>,
+}
+
+impl DuplicatesConfiguration {
+    pub fn get_settings(&self) -> DuplicationSettings {
+        let default = DuplicationSettings::default();
+
+        DuplicationSettings {
+            minimum_tokens: self.minimum_tokens.unwrap_or(default.minimum_tokens),
+            ignore_identifiers: self.ignore_identifiers.unwrap_or(default.ignore_identifiers),
+            ignore_literals: self.ignore_literals.unwrap_or(default.ignore_literals),
+        }
+    }
+}
+
+impl ConfigurationEntry for DuplicatesConfiguration {
+    fn configure<St: BuilderState>(self, builder: ConfigBuilder<St>) -> Result<ConfigBuilder<St>, Error> {
+        Ok(builder)
+    }
+}
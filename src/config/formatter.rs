@@ -2,6 +2,7 @@ use config::ConfigBuilder;
 use config::Value;
 use config::ValueKind;
 use config::builder::BuilderState;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -11,7 +12,7 @@ use crate::config::ConfigurationEntry;
 use crate::error::Error;
 
 /// Configuration options for formatting source code.
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FormatterConfiguration {
     /// A list of patterns to exclude from formatting.
@@ -51,6 +52,14 @@ pub struct FormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub keyword_case: Option<CasingStyle>,
 
+    /// Casing of the `true`, `false`, and `null` constants, defaults to the keyword casing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constant_case: Option<CasingStyle>,
+
+    /// Casing of type keywords in type hints, defaults to the keyword casing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_keyword_case: Option<CasingStyle>,
+
     /// Blank line after the opening PHP tag.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blank_line_after_open_tag: Option<bool>,
@@ -113,16 +122,80 @@ pub struct FormatterConfiguration {
     pub break_promoted_properties_list: Option<bool>,
 
     /// Whether to add a space before and after the concatenation operator.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "space_around_concatenation")]
     pub space_concatenation: Option<bool>,
 
+    /// Whether to add a space before and after the concatenation assignment operator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub space_concatenation_assignment: Option<bool>,
+
     /// How to format broken method/property chains.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub method_chain_breaking_style: Option<MethodChainBreakingStyle>,
 
+    /// How to indent the lines of a broken method chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_indent: Option<ChainIndent>,
+
     /// Whether to add a line before a binary operator or after if it is broken.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub line_before_binary_operator: Option<bool>,
+
+    /// Whether to keep closures with a single statement on one line, when they fit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_simple_closures: Option<bool>,
+
+    /// Whether to vertically align the `=` of consecutive simple assignments and constant definitions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_assignments: Option<bool>,
+
+    /// Whether to print casts in their canonical form, e.g. `(int)` instead of `(integer)`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical_casts: Option<bool>,
+
+    /// Whether to add a space between a cast and its operand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub space_after_cast: Option<bool>,
+
+    /// Whether to rewrite `else if` to `elseif`, or vice versa.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub else_if_style: Option<ElseIfStyle>,
+
+    /// The number of elements above which arrays are always broken into multiple lines.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub array_multiline_threshold: Option<usize>,
+
+    /// Whether to keep a single attribute list on the same line as its parameter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_parameter_attributes: Option<bool>,
+
+    /// Whether to keep a single attribute list on the same line as its property.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_property_attributes: Option<bool>,
+
+    /// How to group attributes into attribute lists.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_grouping: Option<AttributeGrouping>,
+
+    /// Whether to wrap ternaries nested in the middle operand of another ternary in parentheses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parenthesize_nested_ternaries: Option<bool>,
+
+    /// The number of arms above which a `match` expression is always broken into multiple lines.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_multiline_threshold: Option<usize>,
+
+    /// Whether to align the `=>` of consecutive `match` arms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_match_arrows: Option<bool>,
+
+    /// Whether to place each condition of a `match` arm with multiple conditions on its own line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub break_match_arm_conditions: Option<bool>,
+
+    /// Whether to add a trailing comma after the last arm of a multi-line `match` expression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_trailing_comma: Option<bool>,
 }
 
 impl FormatterConfiguration {
@@ -140,6 +213,8 @@ impl FormatterConfiguration {
                 .space_around_declare_equals
                 .unwrap_or(default.space_around_declare_equals),
             keyword_case: self.keyword_case.unwrap_or(default.keyword_case),
+            constant_case: self.constant_case.or(default.constant_case),
+            type_keyword_case: self.type_keyword_case.or(default.type_keyword_case),
             blank_line_after_open_tag: self.blank_line_after_open_tag.unwrap_or(default.blank_line_after_open_tag),
             control_space_parens: self.control_space_parens.unwrap_or(default.control_space_parens),
             closure_brace_style: self.closure_brace_style.unwrap_or(default.closure_brace_style),
@@ -164,12 +239,34 @@ impl FormatterConfiguration {
                 .break_promoted_properties_list
                 .unwrap_or(default.break_promoted_properties_list),
             space_concatenation: self.space_concatenation.unwrap_or(default.space_concatenation),
+            space_concatenation_assignment: self
+                .space_concatenation_assignment
+                .unwrap_or(default.space_concatenation_assignment),
             method_chain_breaking_style: self
                 .method_chain_breaking_style
                 .unwrap_or(default.method_chain_breaking_style),
+            chain_indent: self.chain_indent.unwrap_or(default.chain_indent),
             line_before_binary_operator: self
                 .line_before_binary_operator
                 .unwrap_or(default.line_before_binary_operator),
+            inline_simple_closures: self.inline_simple_closures.unwrap_or(default.inline_simple_closures),
+            align_assignments: self.align_assignments.unwrap_or(default.align_assignments),
+            canonical_casts: self.canonical_casts.unwrap_or(default.canonical_casts),
+            space_after_cast: self.space_after_cast.unwrap_or(default.space_after_cast),
+            else_if_style: self.else_if_style.unwrap_or(default.else_if_style),
+            array_multiline_threshold: self.array_multiline_threshold.or(default.array_multiline_threshold),
+            inline_parameter_attributes: self
+                .inline_parameter_attributes
+                .unwrap_or(default.inline_parameter_attributes),
+            inline_property_attributes: self.inline_property_attributes.unwrap_or(default.inline_property_attributes),
+            attribute_grouping: self.attribute_grouping.unwrap_or(default.attribute_grouping),
+            parenthesize_nested_ternaries: self
+                .parenthesize_nested_ternaries
+                .unwrap_or(default.parenthesize_nested_ternaries),
+            match_multiline_threshold: self.match_multiline_threshold.unwrap_or(default.match_multiline_threshold),
+            align_match_arrows: self.align_match_arrows.unwrap_or(default.align_match_arrows),
+            break_match_arm_conditions: self.break_match_arm_conditions.unwrap_or(default.break_match_arm_conditions),
+            match_trailing_comma: self.match_trailing_comma.or(default.match_trailing_comma),
         }
     }
 }
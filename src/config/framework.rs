@@ -0,0 +1,71 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+
+use mago_formatter::settings::NullTypeHint;
+
+use crate::config::formatter::FormatterConfiguration;
+use crate::config::linter::LinterConfiguration;
+use crate::consts::FRAMEWORK_STUBS;
+
+/// A framework profile, enabling framework-specific stubs, linter rules, and formatter defaults.
+///
+/// Profiles are opt-in, and selected using `framework = "laravel"` in the configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Framework {
+    #[serde(alias = "Laravel")]
+    Laravel,
+    #[serde(alias = "Symfony")]
+    Symfony,
+}
+
+impl Framework {
+    /// Returns the name of the linter plugin providing the rules of this framework.
+    pub const fn plugin(&self) -> &'static str {
+        match self {
+            Framework::Laravel => "laravel",
+            Framework::Symfony => "symfony",
+        }
+    }
+
+    /// Returns the names and contents of the stubs bundled for this framework.
+    pub fn stubs(&self) -> impl Iterator<Item = (&'static str, &'static str)> {
+        let framework = self.to_string();
+
+        FRAMEWORK_STUBS
+            .iter()
+            .filter(move |(stub_framework, _, _)| *stub_framework == framework)
+            .map(|(_, name, content)| (*name, *content))
+    }
+
+    /// Enables the linter plugin of this framework, unless it is already enabled.
+    pub fn configure_linter(&self, linter: &mut LinterConfiguration) {
+        let plugin = self.plugin();
+
+        if !linter.plugins.iter().any(|enabled| enabled.eq_ignore_ascii_case(plugin)) {
+            linter.plugins.push(plugin.to_string());
+        }
+    }
+
+    /// Applies the formatter defaults of this framework's coding style.
+    ///
+    /// Only options that are not explicitly set in the configuration are changed.
+    pub fn configure_formatter(&self, format: &mut FormatterConfiguration) {
+        // Both Laravel (Pint) and Symfony separate the `fn` keyword of arrow functions from their parameters.
+        format.space_before_arrow_function_params.get_or_insert(true);
+        format.null_type_hint.get_or_insert(NullTypeHint::Question);
+
+        match self {
+            Framework::Laravel => {
+                // Laravel does not add spaces around the concatenation operator, e.g. `'foo'.$bar`.
+                format.space_concatenation.get_or_insert(false);
+            }
+            Framework::Symfony => {
+                format.space_concatenation.get_or_insert(true);
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use config::ConfigBuilder;
+use config::builder::BuilderState;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::config::ConfigurationEntry;
+use crate::error::Error;
+
+/// The markers identifying generated sources, unless configured otherwise.
+const DEFAULT_MARKERS: [&str; 3] = ["@generated", "Generated by", "Code generated"];
+
+/// The number of leading lines of a source searched for the markers.
+const HEADER_LINES: usize = 10;
+
+/// Configuration options for the detection of generated sources, which are neither formatted nor linted.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GeneratedConfiguration {
+    /// Markers identifying a generated source when found in its first lines, case-insensitively.
+    ///
+    /// Defaults to `["@generated", "Generated by", "Code generated"]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markers: Option<Vec<String>>,
+
+    /// Glob patterns matching the paths of generated sources, relative to the root, e.g. `src/Proto/**`.
+    ///
+    /// Defaults to `[]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paths: Option<Vec<String>>,
+}
+
+impl GeneratedConfiguration {
+    /// Determines whether the source with the given name, i.e. its path relative to the root, and content is generated.
+    pub fn is_generated(&self, name: &str, content: &str) -> bool {
+        let name = name.replace('\\', "/");
+        if self.paths.iter().flatten().any(|pattern| glob_match::glob_match(pattern, &name)) {
+            return true;
+        }
+
+        let header = content.lines().take(HEADER_LINES).collect::<Vec<_>>().join("\n").to_lowercase();
+
+        match &self.markers {
+            Some(markers) => markers.iter().any(|marker| header.contains(&marker.to_lowercase())),
+            None => DEFAULT_MARKERS.iter().any(|marker| header.contains(&marker.to_lowercase())),
+        }
+    }
+}
+
+impl ConfigurationEntry for GeneratedConfiguration {
+    fn configure<St: BuilderState>(self, builder: ConfigBuilder<St>) -> Result<ConfigBuilder<St>, Error> {
+        Ok(builder)
+    }
+}
@@ -1,14 +1,26 @@
 use ahash::HashMap;
 use config::ConfigBuilder;
 use config::builder::BuilderState;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use toml::value::Value;
 
+use mago_linter::definition::RuleCategory;
+use mago_linter::settings::NewRulesPolicy;
+use mago_reporting::Level;
+
 use crate::config::ConfigurationEntry;
 use crate::error::Error;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+/// The version of Mago whose rules are considered adopted when `linter.rules_version` is not set,
+/// i.e. the release preceding the introduction of the `rules_version` option.
+///
+/// This ensures that upgrading Mago does not suddenly enable the rules introduced since, at their
+/// default level, in projects that never set `rules_version`.
+pub const DEFAULT_RULES_VERSION: &str = "0.11.1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum LinterLevel {
     #[serde(alias = "off")]
     Off,
@@ -22,22 +34,83 @@ pub enum LinterLevel {
     Error,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+impl LinterLevel {
+    /// Returns the level of issues corresponding to this level, `None` if it is `Off`.
+    pub fn to_level(self) -> Option<Level> {
+        match self {
+            LinterLevel::Off => None,
+            LinterLevel::Help => Some(Level::Help),
+            LinterLevel::Note => Some(Level::Note),
+            LinterLevel::Warning => Some(Level::Warning),
+            LinterLevel::Error => Some(Level::Error),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LinterConfiguration {
     pub default_plugins: Option<bool>,
     pub plugins: Vec<String>,
+    #[schemars(with = "Vec<String>")]
+    pub categories: Vec<RuleCategory>,
+    #[schemars(with = "Vec<String>")]
+    pub excluded_categories: Vec<RuleCategory>,
+    pub rules_version: Option<String>,
+    #[schemars(with = "Option<String>")]
+    pub new_rules: Option<NewRulesPolicy>,
     pub rules: Vec<LinterRuleConfiguration>,
+    pub profiles: Vec<LinterProfileConfiguration>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, JsonSchema)]
 pub struct LinterRuleConfiguration {
     pub name: String,
     pub level: Option<LinterLevel>,
     #[serde(flatten, skip_serializing_if = "HashMap::is_empty")]
+    #[schemars(with = "HashMap<String, serde_json::Value>")]
     pub options: HashMap<String, Value>,
 }
 
+impl LinterConfiguration {
+    /// Returns the version of Mago whose rules have been adopted, defaulting to [`DEFAULT_RULES_VERSION`]
+    /// when `rules_version` is not set.
+    pub fn rules_version(&self) -> &str {
+        self.rules_version.as_deref().unwrap_or(DEFAULT_RULES_VERSION)
+    }
+}
+
+/// A strictness profile, scaling the levels of the issues reported in the sources it applies to,
+/// e.g. to report everything as an error in new code, and only errors in legacy code.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LinterProfileConfiguration {
+    /// Glob patterns matching the paths of the sources the profile applies to, relative to the root, e.g. `src/Legacy/**`.
+    #[serde(default)]
+    pub paths: Vec<String>,
+
+    /// Owners, as declared in the `CODEOWNERS` file, of the sources the profile applies to, e.g. `@acme/payments`.
+    #[serde(default)]
+    pub owners: Vec<String>,
+
+    /// The minimum level of the issues reported in the sources, the issues of lower levels being left out.
+    pub minimum_level: Option<LinterLevel>,
+
+    /// The level the issues of lower levels are escalated to.
+    pub escalate_to: Option<LinterLevel>,
+}
+
+impl LinterProfileConfiguration {
+    /// Determines whether the profile applies to the source with the given name, i.e. its path relative
+    /// to the root, owned by the given owners.
+    pub fn applies_to(&self, name: &str, owners: &[String]) -> bool {
+        let name = name.replace('\\', "/");
+
+        self.paths.iter().any(|pattern| glob_match::glob_match(pattern, &name))
+            || self.owners.iter().any(|owner| owners.iter().any(|other| other.eq_ignore_ascii_case(owner)))
+    }
+}
+
 impl ConfigurationEntry for LinterConfiguration {
     fn configure<St: BuilderState>(self, builder: ConfigBuilder<St>) -> Result<ConfigBuilder<St>, Error> {
         use ::config::Value;
@@ -46,8 +119,44 @@ impl ConfigurationEntry for LinterConfiguration {
         let builder = builder
             .set_default("linter.default_plugins", Value::new(None, ValueKind::Nil))?
             .set_default("linter.plugins", Value::new(None, ValueKind::Array(vec![])))?
-            .set_default("linter.rules", Value::new(None, ValueKind::Array(vec![])))?;
+            .set_default("linter.categories", Value::new(None, ValueKind::Array(vec![])))?
+            .set_default("linter.excluded_categories", Value::new(None, ValueKind::Array(vec![])))?
+            .set_default("linter.rules_version", Value::new(None, ValueKind::Nil))?
+            .set_default("linter.new_rules", Value::new(None, ValueKind::Nil))?
+            .set_default("linter.rules", Value::new(None, ValueKind::Array(vec![])))?
+            .set_default("linter.profiles", Value::new(None, ValueKind::Array(vec![])))?;
 
         Ok(builder)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use mago_linter::settings::Settings;
+    use mago_php_version::PHPVersion;
+
+    use super::*;
+
+    #[test]
+    fn test_rules_version_defaults_to_previous_release_when_unset() {
+        let configuration = LinterConfiguration::default();
+        assert_eq!(configuration.rules_version, None);
+        assert_eq!(configuration.rules_version(), DEFAULT_RULES_VERSION);
+
+        let settings =
+            Settings::new(PHPVersion::PHP84).with_rules_version(Some(configuration.rules_version().to_owned()));
+        assert!(settings.is_new_rule(Some("0.12.0")));
+        assert!(!settings.is_new_rule(Some("0.11.0")));
+        assert!(!settings.is_new_rule(None));
+    }
+
+    #[test]
+    fn test_rules_version_is_kept_when_set() {
+        let configuration = LinterConfiguration { rules_version: Some("0.12.0".to_owned()), ..Default::default() };
+        assert_eq!(configuration.rules_version(), "0.12.0");
+
+        let settings =
+            Settings::new(PHPVersion::PHP84).with_rules_version(Some(configuration.rules_version().to_owned()));
+        assert!(!settings.is_new_rule(Some("0.12.0")));
+    }
+}
@@ -0,0 +1,34 @@
+use config::ConfigBuilder;
+use config::builder::BuilderState;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::config::ConfigurationEntry;
+use crate::error::Error;
+
+/// Configuration options for the language server.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LspConfiguration {
+    /// Whether to offer completions for variables, class members, and imported symbols.
+    ///
+    /// Completion infers the type of member access receivers, which makes it significantly more
+    /// expensive, and less precise, than the other features of the language server.
+    ///
+    /// Defaults to `false`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion: Option<bool>,
+}
+
+impl LspConfiguration {
+    pub fn is_completion_enabled(&self) -> bool {
+        self.completion.unwrap_or(false)
+    }
+}
+
+impl ConfigurationEntry for LspConfiguration {
+    fn configure<St: BuilderState>(self, builder: ConfigBuilder<St>) -> Result<ConfigBuilder<St>, Error> {
+        Ok(builder)
+    }
+}
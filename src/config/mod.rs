@@ -2,28 +2,40 @@ use std::path::PathBuf;
 
 use config::Config;
 use config::ConfigBuilder;
-use config::Environment;
 use config::File;
 use config::FileFormat;
 use config::Value;
 use config::ValueKind;
 use config::builder::BuilderState;
+use schemars::JsonSchema;
 use serde::Deserialize;
+use serde::Serialize;
 
+use mago_parser::settings::ParserSettings;
 use mago_php_version::PHPVersion;
 
+use crate::config::duplicates::DuplicatesConfiguration;
 use crate::config::formatter::FormatterConfiguration;
+use crate::config::framework::Framework;
+use crate::config::generated::GeneratedConfiguration;
 use crate::config::linter::LinterConfiguration;
+use crate::config::lsp::LspConfiguration;
+use crate::config::overrides::ConfigurationOverride;
 use crate::config::source::SourceConfiguration;
 use crate::consts::*;
 use crate::error::Error;
 
+pub mod duplicates;
 pub mod formatter;
+pub mod framework;
+pub mod generated;
 pub mod linter;
+pub mod lsp;
+pub mod overrides;
 pub mod source;
 
 /// Configuration options for mago.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Configuration {
     /// The number of threads to use.
@@ -33,11 +45,32 @@ pub struct Configuration {
     pub stack_size: usize,
 
     /// The version of PHP to use.
+    #[schemars(with = "String")]
     pub php_version: PHPVersion,
 
     /// Whether to allow unsupported PHP versions.
     pub allow_unsupported_php_version: bool,
 
+    /// Whether the short opening tag, `<?`, opens a PHP block.
+    ///
+    /// This mirrors PHP's `short_open_tag` ini setting. When disabled, `<?` is treated as inline text,
+    /// as in templates starting with an `<?xml` declaration.
+    pub short_open_tag: bool,
+
+    /// Whether to reduce memory usage on very large codebases.
+    ///
+    /// Files are loaded and released one batch at a time instead of being kept in memory
+    /// for the whole run, at the cost of reading and parsing them again when needed.
+    #[serde(default)]
+    pub low_memory: bool,
+
+    /// The framework profile to use, if any.
+    ///
+    /// Enabling a profile loads the framework stubs, enables the framework linter plugin,
+    /// and applies the formatter defaults of the framework's coding style.
+    #[serde(default)]
+    pub framework: Option<Framework>,
+
     /// Configuration options for source discovery.
     pub source: SourceConfiguration,
 
@@ -49,32 +82,103 @@ pub struct Configuration {
     #[serde(default)]
     pub format: FormatterConfiguration,
 
+    /// Configuration options for duplicated code detection.
+    #[serde(default)]
+    pub duplicates: DuplicatesConfiguration,
+
+    /// Configuration options for the detection of generated sources.
+    #[serde(default)]
+    pub generated: GeneratedConfiguration,
+
+    /// Configuration options for the language server.
+    #[serde(default)]
+    pub lsp: LspConfiguration,
+
     /// The log filter.
     ///
     /// This is not a configuration option, but it is included here to allow specifying the log filter
     /// in the environment using `MAGO_LOG`.
     ///
     /// If this field is to be removed, serde will complain about an unknown field in the configuration
-    /// when `MAGO_LOG` is set due to the `deny_unknown_fields` attribute and the use of `Environment` source.
+    /// when `MAGO_LOG` is set due to the `deny_unknown_fields` attribute and the environment overrides.
+    #[serde(skip_serializing)]
+    #[schemars(skip)]
     log: Value,
 }
 
 impl Configuration {
-    pub fn load() -> Result<Configuration, Error> {
-        let builder = Config::builder()
-            .add_source(File::with_name(CONFIGURATION_FILE).required(false).format(FileFormat::Toml))
-            .add_source(Environment::with_prefix(ENVIRONMENT_PREFIX));
+    /// Loads the configuration, applying the given command line overrides last.
+    ///
+    /// Settings are merged with the following precedence, from lowest to highest:
+    ///
+    /// 1. The defaults.
+    /// 2. The configuration file.
+    /// 3. The `MAGO_*` environment variables.
+    /// 4. The `--config KEY=VALUE` command line flags, in the order they are given.
+    pub fn load(overrides: &[ConfigurationOverride]) -> Result<Configuration, Error> {
+        Configuration::from_config(Configuration::build(overrides)?)
+    }
 
-        let mut configuration = Configuration::from_root(CURRENT_DIR.to_path_buf())
-            .configure(builder)?
-            .build()?
-            .try_deserialize::<Configuration>()?;
+    /// Merges the configuration sources as `load` does, without deserializing them.
+    ///
+    /// Each value of the resulting `Config` remembers its origin, which `load` discards.
+    pub fn build(overrides: &[ConfigurationOverride]) -> Result<Config, Error> {
+        let mut builder =
+            Config::builder().add_source(File::with_name(CONFIGURATION_FILE).required(false).format(FileFormat::Toml));
+
+        for ConfigurationOverride { key, value } in ConfigurationOverride::from_environment().iter().chain(overrides) {
+            builder = builder.set_override(key, value.clone())?;
+        }
+
+        Ok(Configuration::from_root(CURRENT_DIR.to_path_buf()).configure(builder)?.build()?)
+    }
+
+    /// Loads the configuration from the given TOML content, ignoring the environment.
+    ///
+    /// This is used to validate a configuration file on its own, as `load` would also pick up
+    /// the `MAGO_*` environment variables and the command line overrides.
+    pub fn from_toml(content: &str) -> Result<Configuration, Error> {
+        let builder = Config::builder().add_source(File::from_str(content, FileFormat::Toml));
+
+        Configuration::from_config(Configuration::from_root(CURRENT_DIR.to_path_buf()).configure(builder)?.build()?)
+    }
+
+    /// Deserializes and normalizes the configuration from the given merged sources.
+    pub fn from_config(config: Config) -> Result<Configuration, Error> {
+        let mut configuration = config.try_deserialize::<Configuration>()?;
 
         configuration.normalize()?;
 
         Ok(configuration)
     }
 
+    /// Returns the settings to parse the sources with.
+    pub fn parser_settings(&self) -> ParserSettings {
+        ParserSettings { short_open_tag: self.short_open_tag }
+    }
+
+    /// Returns the JSON Schema of the configuration file.
+    ///
+    /// Every option of the configuration file is optional, as missing options fall back to their
+    /// defaults, so no property of the schema is marked as required.
+    pub fn schema() -> serde_json::Value {
+        fn remove_required(value: &mut serde_json::Value) {
+            match value {
+                serde_json::Value::Object(object) => {
+                    object.remove("required");
+                    object.values_mut().for_each(remove_required);
+                }
+                serde_json::Value::Array(array) => array.iter_mut().for_each(remove_required),
+                _ => {}
+            }
+        }
+
+        let mut schema = serde_json::to_value(schemars::schema_for!(Configuration)).unwrap_or_default();
+        remove_required(&mut schema);
+
+        schema
+    }
+
     /// Creates a new `Configuration` with the given root directory.
     ///
     /// # Arguments
@@ -90,9 +194,15 @@ impl Configuration {
             stack_size: DEFAULT_STACK_SIZE,
             php_version: DEFAULT_PHP_VERSION,
             allow_unsupported_php_version: false,
+            short_open_tag: true,
+            low_memory: false,
+            framework: None,
             source: SourceConfiguration::from_root(root),
             linter: LinterConfiguration::default(),
             format: FormatterConfiguration::default(),
+            duplicates: DuplicatesConfiguration::default(),
+            generated: GeneratedConfiguration::default(),
+            lsp: LspConfiguration::default(),
             log: Value::new(None, ValueKind::Nil),
         }
     }
@@ -114,11 +224,16 @@ impl ConfigurationEntry for Configuration {
             .set_default("stack_size", Value::new(None, ValueKind::U64(self.stack_size as u64)))?
             .set_default("php_version", Value::new(None, ValueKind::String(self.php_version.to_string())))?
             .set_default("allow_unsupported_php_version", self.allow_unsupported_php_version)?
+            .set_default("short_open_tag", self.short_open_tag)?
+            .set_default("low_memory", self.low_memory)?
             .set_default("log", self.log)?;
 
         builder = self.source.configure(builder)?;
         builder = self.linter.configure(builder)?;
         builder = self.format.configure(builder)?;
+        builder = self.duplicates.configure(builder)?;
+        builder = self.generated.configure(builder)?;
+        builder = self.lsp.configure(builder)?;
 
         Ok(builder)
     }
@@ -168,6 +283,13 @@ impl ConfigurationEntry for Configuration {
         self.source.normalize()?;
         self.linter.normalize()?;
 
+        if let Some(framework) = self.framework {
+            tracing::debug!("Configuration specifies the `{}` framework profile.", framework);
+
+            framework.configure_linter(&mut self.linter);
+            framework.configure_formatter(&mut self.format);
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use config::Map;
+use config::Value;
+use config::ValueKind;
+
+use crate::consts::ENVIRONMENT_PREFIX;
+
+/// The separator between the sections of a key in environment variable names, e.g. `MAGO_FORMAT__PRINT_WIDTH`.
+const ENVIRONMENT_SEPARATOR: &str = "__";
+
+/// The settings holding a list of strings, which can be given as a comma-separated list in the environment.
+const LIST_KEYS: [&str; 10] = [
+    "source.paths",
+    "source.includes",
+    "source.excludes",
+    "source.extensions",
+    "format.excludes",
+    "linter.plugins",
+    "linter.categories",
+    "linter.excluded_categories",
+    "generated.markers",
+    "generated.paths",
+];
+
+/// A configuration value set from outside of the configuration file, overriding it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigurationOverride {
+    /// The dotted key of the setting, e.g. `format.print_width`.
+    pub key: String,
+
+    /// The value of the setting, remembering where it was set.
+    pub value: Value,
+}
+
+impl ConfigurationOverride {
+    /// Creates the override set by a dedicated command line flag, e.g. `--low-memory`.
+    pub fn from_flag(flag: &str, key: &str, value: ValueKind) -> ConfigurationOverride {
+        let origin = format!("`{}`", flag);
+
+        ConfigurationOverride { key: key.to_string(), value: Value::new(Some(&origin), value) }
+    }
+
+    /// Collects the overrides set by the `MAGO_*` environment variables.
+    ///
+    /// Sections are separated by a double underscore, e.g. `MAGO_FORMAT__PRINT_WIDTH=100` sets `format.print_width`,
+    /// and list settings accept comma-separated values, e.g. `MAGO_SOURCE__PATHS=src,tests`.
+    pub fn from_environment() -> Vec<ConfigurationOverride> {
+        let prefix = format!("{}_", ENVIRONMENT_PREFIX);
+
+        let mut overrides: Vec<ConfigurationOverride> = std::env::vars()
+            .filter_map(|(name, raw)| {
+                let key = name.strip_prefix(&prefix)?.to_lowercase().replace(ENVIRONMENT_SEPARATOR, ".");
+                if key.is_empty() || raw.is_empty() {
+                    return None;
+                }
+
+                let origin = format!("environment variable `{}`", name);
+                let value = if LIST_KEYS.contains(&key.as_str()) && !raw.trim_start().starts_with('[') {
+                    let items = raw
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(|item| Value::new(Some(&origin), ValueKind::String(item.to_string())))
+                        .collect();
+
+                    Value::new(Some(&origin), ValueKind::Array(items))
+                } else {
+                    parse_value(&raw, &origin)
+                };
+
+                Some(ConfigurationOverride { key, value })
+            })
+            .collect();
+
+        // Keep the order deterministic, as the environment is not ordered.
+        overrides.sort_by(|a, b| a.key.cmp(&b.key));
+
+        overrides
+    }
+}
+
+impl FromStr for ConfigurationOverride {
+    type Err = String;
+
+    /// Parses an override given on the command line, e.g. `format.print_width=100`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((key, raw)) = s.split_once('=') else {
+            return Err(format!("expected `KEY=VALUE`, e.g. `format.print_width=100`, found `{}`", s));
+        };
+
+        let key = key.trim();
+        if key.is_empty() || key.split('.').any(str::is_empty) {
+            return Err(format!("invalid key `{}`, expected a dotted key, e.g. `format.print_width`", key));
+        }
+
+        let origin = format!("`--config {}`", s);
+
+        Ok(ConfigurationOverride { key: key.to_string(), value: parse_value(raw.trim(), &origin) })
+    }
+}
+
+/// Parses the given raw value of an override.
+///
+/// Arrays, inline tables, and quoted strings are parsed as TOML, e.g. `["src", "tests"]`. Any other value is kept
+/// as a string, and converted to the type of the setting when the configuration is deserialized, so that values
+/// such as `8.0` are not mistaken for numbers.
+fn parse_value(raw: &str, origin: &String) -> Value {
+    if raw.starts_with(['[', '{', '"', '\'']) {
+        if let Ok(value) = raw.parse::<toml_edit::Value>() {
+            return to_config_value(&value, origin);
+        }
+    }
+
+    Value::new(Some(origin), ValueKind::String(raw.to_string()))
+}
+
+fn to_config_value(value: &toml_edit::Value, origin: &String) -> Value {
+    let kind = match value {
+        toml_edit::Value::String(value) => ValueKind::String(value.value().clone()),
+        toml_edit::Value::Integer(value) => ValueKind::I64(*value.value()),
+        toml_edit::Value::Float(value) => ValueKind::Float(*value.value()),
+        toml_edit::Value::Boolean(value) => ValueKind::Boolean(*value.value()),
+        toml_edit::Value::Datetime(value) => ValueKind::String(value.value().to_string()),
+        toml_edit::Value::Array(array) => {
+            ValueKind::Array(array.iter().map(|value| to_config_value(value, origin)).collect())
+        }
+        toml_edit::Value::InlineTable(table) => ValueKind::Table(
+            table
+                .iter()
+                .map(|(key, value)| (key.to_string(), to_config_value(value, origin)))
+                .collect::<Map<String, Value>>(),
+        ),
+    };
+
+    Value::new(Some(origin), kind)
+}
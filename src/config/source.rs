@@ -4,6 +4,7 @@ use config::ConfigBuilder;
 use config::Value;
 use config::ValueKind;
 use config::builder::BuilderState;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -12,7 +13,7 @@ use crate::config::ConfigurationEntry;
 use crate::error::Error;
 
 /// Configuration options for source discovery.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SourceConfiguration {
     /// The root directory from which to start scanning.
@@ -41,6 +42,33 @@ pub struct SourceConfiguration {
     ///
     /// Defaults to `[".php"]`.
     pub extensions: Vec<String>,
+
+    /// Remote sources, such as stub packages, downloaded into the cache and included in the scan.
+    ///
+    /// Defaults to `[]`.
+    #[serde(default)]
+    pub remote: Vec<RemoteSource>,
+}
+
+/// A remote source, downloaded and pinned in the lock file, then included in the scan as a non-user defined source.
+///
+/// Exactly one of `url` and `package` must be set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteSource {
+    /// The URL of a PHP file, or of a `.zip` archive containing PHP files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// The name of a Composer package, e.g. `acme/stubs`, downloaded from Packagist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+
+    /// The version of the Composer package.
+    ///
+    /// Defaults to the latest stable version when the package is first locked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
 }
 
 impl SourceConfiguration {
@@ -54,7 +82,7 @@ impl SourceConfiguration {
     ///
     /// A new `SourceConfiguration` with the given root directory.
     pub fn from_root(root: PathBuf) -> Self {
-        Self { root, paths: vec![], includes: vec![], excludes: vec![], extensions: vec![] }
+        Self { root, paths: vec![], includes: vec![], excludes: vec![], extensions: vec![], remote: vec![] }
     }
 }
 
@@ -65,6 +93,7 @@ impl ConfigurationEntry for SourceConfiguration {
             .set_default("source.paths", Value::new(None, ValueKind::Array(vec![])))?
             .set_default("source.includes", Value::new(None, ValueKind::Array(vec![])))?
             .set_default("source.excludes", Value::new(None, ValueKind::Array(vec![])))?
+            .set_default("source.remote", Value::new(None, ValueKind::Array(vec![])))?
             .set_default(
                 "source.extensions",
                 Value::new(None, ValueKind::Array(vec![Value::new(None, ValueKind::String("php".to_string()))])),
@@ -36,6 +36,9 @@ pub const ENVIRONMENT_PREFIX: &str = "MAGO";
 /// The name of the configuration file for mago.
 pub const CONFIGURATION_FILE: &str = "mago.toml";
 
+/// The name of the lock file, pinning the remote sources of mago.
+pub const LOCK_FILE: &str = "mago.lock";
+
 /// The minimum stack size for each thread.
 pub const MINIMUM_STACK_SIZE: usize = 8 * 1024 * 1024;
 
@@ -70,3 +73,5 @@ pub static CURRENT_DIR: LazyLock<PathBuf> = LazyLock::new(|| {
 });
 
 include!(concat!(env!("OUT_DIR"), "/stubs_map.rs"));
+include!(concat!(env!("OUT_DIR"), "/framework_stubs_map.rs"));
+include!(concat!(env!("OUT_DIR"), "/self_test_corpus.rs"));
@@ -12,9 +12,24 @@ pub enum Error {
     DeserializingToml(toml::de::Error),
     SerializingToml(toml::ser::Error),
     CanonicalizingPath(std::path::PathBuf, std::io::Error),
+    ReadingConfiguration(std::path::PathBuf, std::io::Error),
+    CreatingLogFile(std::path::PathBuf, std::io::Error),
+    ReadingReport(std::path::PathBuf, std::io::Error),
+    ParsingReport(std::path::PathBuf, ReportingError),
+    WritingJournal(std::path::PathBuf, std::io::Error),
+    ReadingJournal(std::path::PathBuf, std::io::Error),
+    RestoringFile(std::path::PathBuf, std::io::Error),
+    WritingCrashReport(std::path::PathBuf, std::io::Error),
+    WritingIgnoreRevs(std::path::PathBuf, std::io::Error),
+    RunningGit(String, std::io::Error),
+    FetchingRemoteSource(String, String),
+    ReadingLockFile(std::path::PathBuf, std::io::Error),
+    WritingLockFile(std::path::PathBuf, std::io::Error),
+    WritingCache(std::path::PathBuf, std::io::Error),
     Join(tokio::task::JoinError),
     Json(serde_json::Error),
     SelfUpdate(self_update::errors::Error),
+    WritingOutput(std::io::Error),
     PHPVersionIsTooOld(PHPVersion, PHPVersion),
     PHPVersionIsTooNew(PHPVersion, PHPVersion),
 }
@@ -30,9 +45,55 @@ impl std::fmt::Display for Error {
             Self::DeserializingToml(error) => write!(f, "Failed to deserialize TOML: {}", error),
             Self::SerializingToml(error) => write!(f, "Failed to serialize TOML: {}", error),
             Self::CanonicalizingPath(path, error) => write!(f, "Failed to canonicalize path `{:?}`: {}", path, error),
+            Self::CreatingLogFile(path, error) => {
+                write!(f, "Failed to create the log file `{}`: {}", path.display(), error)
+            }
+            Self::ReadingConfiguration(path, error) => {
+                write!(f, "Failed to read the configuration file `{}`: {}", path.display(), error)
+            }
+            Self::ReadingReport(path, error) => {
+                write!(f, "Failed to read the report `{}`: {}", path.display(), error)
+            }
+            Self::ParsingReport(path, error) => {
+                write!(
+                    f,
+                    "Failed to parse the report `{}`, expected a report in the `json` format: {}",
+                    path.display(),
+                    error
+                )
+            }
+            Self::WritingJournal(path, error) => {
+                write!(f, "Failed to write the undo journal `{}`: {}", path.display(), error)
+            }
+            Self::ReadingJournal(path, error) => {
+                write!(f, "Failed to read the undo journal `{}`: {}", path.display(), error)
+            }
+            Self::RestoringFile(path, error) => {
+                write!(f, "Failed to restore the file `{}`: {}", path.display(), error)
+            }
+            Self::WritingCrashReport(path, error) => {
+                write!(f, "Failed to write the crash report `{}`: {}", path.display(), error)
+            }
+            Self::WritingIgnoreRevs(path, error) => {
+                write!(f, "Failed to write the ignored revisions file `{}`: {}", path.display(), error)
+            }
+            Self::RunningGit(command, error) => write!(f, "Failed to run `{}`: {}", command, error),
+            Self::FetchingRemoteSource(source, message) => {
+                write!(f, "Failed to fetch the remote source `{}`: {}", source, message)
+            }
+            Self::ReadingLockFile(path, error) => {
+                write!(f, "Failed to read the lock file `{}`: {}", path.display(), error)
+            }
+            Self::WritingLockFile(path, error) => {
+                write!(f, "Failed to write the lock file `{}`: {}", path.display(), error)
+            }
+            Self::WritingCache(path, error) => {
+                write!(f, "Failed to write the cache `{}`: {}", path.display(), error)
+            }
             Self::Join(error) => write!(f, "Failed to join tasks: {}", error),
             Self::Json(error) => write!(f, "Failed to parse JSON: {}", error),
             Self::SelfUpdate(error) => write!(f, "Failed to self update: {}", error),
+            Self::WritingOutput(error) => write!(f, "Failed to write the output: {}", error),
             Self::PHPVersionIsTooOld(minimum, actual) => {
                 write!(f, "PHP version {} is not supported, minimum supported version is {}", actual, minimum)
             }
@@ -54,9 +115,23 @@ impl std::error::Error for Error {
             Self::DeserializingToml(error) => Some(error),
             Self::SerializingToml(error) => Some(error),
             Self::CanonicalizingPath(_, error) => Some(error),
+            Self::ReadingConfiguration(_, error) => Some(error),
+            Self::CreatingLogFile(_, error) => Some(error),
+            Self::ReadingReport(_, error) => Some(error),
+            Self::ParsingReport(_, error) => Some(error),
+            Self::WritingJournal(_, error) => Some(error),
+            Self::ReadingJournal(_, error) => Some(error),
+            Self::RestoringFile(_, error) => Some(error),
+            Self::WritingCrashReport(_, error) => Some(error),
+            Self::WritingIgnoreRevs(_, error) => Some(error),
+            Self::RunningGit(_, error) => Some(error),
+            Self::ReadingLockFile(_, error) => Some(error),
+            Self::WritingLockFile(_, error) => Some(error),
+            Self::WritingCache(_, error) => Some(error),
             Self::Join(error) => Some(error),
             Self::Json(error) => Some(error),
             Self::SelfUpdate(error) => Some(error),
+            Self::WritingOutput(error) => Some(error),
             _ => None,
         }
     }
@@ -1,29 +1,66 @@
+use std::fs::File;
 use std::process::ExitCode;
 
+use ::config::ValueKind;
 use clap::Parser;
 use tokio::runtime::Builder;
 use tracing::level_filters::LevelFilter;
 
+use mago_reporting::reporter::ReportingTarget;
+
+use crate::commands::MagoCli;
 use crate::commands::MagoCommand;
 use crate::config::Configuration;
+use crate::config::overrides::ConfigurationOverride;
 use crate::consts::MAXIMUM_PHP_VERSION;
 use crate::consts::MINIMUM_PHP_VERSION;
 use crate::error::Error;
+use crate::utils::allocator::CountingAllocator;
+use crate::utils::color;
 use crate::utils::logger::initialize_logger;
 
+mod archive;
 mod commands;
 mod config;
 mod consts;
 mod error;
 mod macros;
 mod reflection;
+mod remote;
 mod source;
 mod utils;
 
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
 pub fn main() -> ExitCode {
-    initialize_logger(if cfg!(debug_assertions) { LevelFilter::DEBUG } else { LevelFilter::INFO }, "MAGO_LOG");
+    let cli = MagoCli::parse();
+
+    let level = match cli.verbose {
+        0 if cfg!(debug_assertions) => LevelFilter::DEBUG,
+        0 => LevelFilter::INFO,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+
+    let (log_file, log_file_error) = match &cli.log_file {
+        Some(path) => match File::create(path) {
+            Ok(file) => (Some(file), None),
+            Err(error) => (None, Some(Error::CreatingLogFile(path.clone(), error))),
+        },
+        None => (None, None),
+    };
+
+    color::initialize(cli.color);
+    initialize_logger(level, "MAGO_LOG", color::is_enabled(ReportingTarget::Stderr), log_file);
 
-    run().unwrap_or_else(|error| {
+    if let Some(error) = log_file_error {
+        tracing::error!("{}", error);
+
+        return ExitCode::FAILURE;
+    }
+
+    run(cli).unwrap_or_else(|error| {
         tracing::error!("{}", error);
 
         ExitCode::FAILURE
@@ -31,9 +68,26 @@ pub fn main() -> ExitCode {
 }
 
 #[inline(always)]
-pub fn run() -> Result<ExitCode, Error> {
+pub fn run(cli: MagoCli) -> Result<ExitCode, Error> {
+    let MagoCli { mut overrides, low_memory, command, .. } = cli;
+
+    if low_memory {
+        overrides.push(ConfigurationOverride::from_flag("--low-memory", "low_memory", ValueKind::Boolean(true)));
+    }
+
+    // The `completions` and `man` commands only describe the command line interface, the `config` command
+    // checks the configuration file itself, and the `report` command only processes existing reports, so they
+    // are executed before the configuration is loaded.
+    let command = match command {
+        MagoCommand::Completions(cmd) => return commands::completions::execute(cmd),
+        MagoCommand::Man(cmd) => return commands::man::execute(cmd),
+        MagoCommand::Config(cmd) => return commands::config::execute(cmd, &overrides),
+        MagoCommand::Report(cmd) => return commands::report::execute(cmd),
+        command => command,
+    };
+
     // Load the configuration.
-    let configuration = Configuration::load()?;
+    let configuration = Configuration::load(&overrides)?;
 
     if !configuration.allow_unsupported_php_version {
         if configuration.php_version < MINIMUM_PHP_VERSION {
@@ -45,6 +99,12 @@ pub fn run() -> Result<ExitCode, Error> {
         }
     }
 
+    // The `self-update` command does not need the runtime, and is executed before it is created,
+    // so that it runs while the process is still single-threaded.
+    if let MagoCommand::SelfUpdate(cmd) = command {
+        return commands::self_update::execute(cmd);
+    }
+
     // Create the runtime.
     let runtime = if configuration.threads <= 1 {
         Builder::new_current_thread().enable_all().build().map_err(Error::BuildingRuntime)?
@@ -57,12 +117,23 @@ pub fn run() -> Result<ExitCode, Error> {
             .map_err(Error::BuildingRuntime)?
     };
 
-    match MagoCommand::parse() {
+    match command {
         MagoCommand::Lint(cmd) => runtime.block_on(commands::lint::execute(cmd, configuration)),
         MagoCommand::Fix(cmd) => runtime.block_on(commands::fix::execute(cmd, configuration)),
         MagoCommand::Format(cmd) => runtime.block_on(commands::format::execute(cmd, configuration)),
-        MagoCommand::Ast(cmd) => runtime.block_on(commands::ast::execute(cmd)),
+        MagoCommand::Ast(cmd) => runtime.block_on(commands::ast::execute(cmd, configuration)),
         MagoCommand::Find(find) => runtime.block_on(commands::find::execute(find, configuration)),
-        MagoCommand::SelfUpdate(cmd) => commands::self_update::execute(cmd),
+        MagoCommand::Graph(cmd) => runtime.block_on(commands::graph::execute(cmd, configuration)),
+        MagoCommand::Duplicates(cmd) => runtime.block_on(commands::duplicates::execute(cmd, configuration)),
+        MagoCommand::Lsp(cmd) => runtime.block_on(commands::lsp::execute(cmd, configuration)),
+        MagoCommand::SelfTest(cmd) => runtime.block_on(commands::self_test::execute(cmd, configuration)),
+        MagoCommand::Undo(cmd) => commands::undo::execute(cmd, configuration),
+        MagoCommand::SelfUpdate(_)
+        | MagoCommand::Completions(_)
+        | MagoCommand::Man(_)
+        | MagoCommand::Config(_)
+        | MagoCommand::Report(_) => {
+            unreachable!("the command is executed before the runtime is created")
+        }
     }
 }
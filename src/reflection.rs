@@ -24,13 +24,12 @@ use crate::error::Error;
 /// # Returns
 ///
 /// A `Result` containing a combined [`CodebaseReflection`] that represents the aggregated
-/// reflection of all non-user sources, or an [`Error`] if any source fails to load, parse, or reflect.
+/// reflection of all non-user sources, or an [`Error`] if any of the tasks fails.
 ///
 /// # Errors
 ///
-/// This function returns an [`Error`] if:
-/// - Any source cannot be loaded from the `SourceManager`.
-/// - Parsing or reflecting on any source fails.
+/// This function returns an [`Error`] if any of the concurrent tasks fails. Sources that cannot be
+/// loaded are skipped, as issues are never reported for non-user sources.
 ///
 /// # Concurrency
 ///
@@ -52,7 +51,15 @@ pub async fn reflect_non_user_sources(
             let manager = manager.clone();
             let interner = interner.clone();
             async move {
-                let source = manager.load(&source_id)?;
+                // External sources are never reported, so one that cannot be read is only left out.
+                let source = match manager.load(&source_id) {
+                    Ok(source) => source,
+                    Err(error) => {
+                        tracing::debug!("Skipping external source `{}`: {}.", interner.lookup(&source_id.0), error);
+
+                        return Ok::<CodebaseReflection, Error>(CodebaseReflection::new());
+                    }
+                };
 
                 Ok::<CodebaseReflection, Error>(
                     Module::build(&interner, php_version, source, ModuleBuildOptions::reflection())
@@ -0,0 +1,384 @@
+use std::io::Cursor;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value as JsonValue;
+use sha2::Digest;
+use sha2::Sha256;
+
+use mago_source::file::write_atomically;
+
+use crate::config::source::RemoteSource;
+use crate::consts::LOCK_FILE;
+use crate::consts::VERSION;
+use crate::error::Error;
+
+/// The directory, relative to the workspace, the remote sources are downloaded into.
+pub const REMOTE_DIRECTORY: &str = ".mago/remote";
+
+/// The URL of the Packagist metadata of a Composer package, given its name.
+const PACKAGIST_URL: &str = "https://repo.packagist.org/p2";
+
+/// The lock file, pinning the remote sources to the exact content they were first downloaded with.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Lock {
+    #[serde(default)]
+    remote: Vec<LockedSource>,
+}
+
+/// A remote source pinned in the lock file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LockedSource {
+    /// The remote source as configured, e.g. `acme/stubs@1.0.0`, or its URL.
+    source: String,
+
+    /// The URL the source was downloaded from.
+    url: String,
+
+    /// The SHA-256 hash of the downloaded content.
+    sha256: String,
+}
+
+/// Downloads the given remote sources into the cache of the workspace, unless they are already cached,
+/// and returns the directories containing them.
+///
+/// Sources are downloaded as pinned in the lock file, the download failing if its content changed. Sources that
+/// are not locked yet are resolved to their latest version, and added to the lock file, which should be committed,
+/// so that every member of the team analyzes the same sources.
+///
+/// # Arguments
+///
+/// * `root` - The root directory of the workspace, containing the lock file.
+/// * `sources` - The remote sources to download.
+pub async fn fetch(root: &Path, sources: &[RemoteSource]) -> Result<Vec<PathBuf>, Error> {
+    let lock_path = root.join(LOCK_FILE);
+    let lock = read_lock(&lock_path)?;
+    let cache = root.join(REMOTE_DIRECTORY);
+    let client = reqwest::Client::builder()
+        .user_agent(format!("mago/{}", VERSION))
+        .build()
+        .map_err(|error| Error::FetchingRemoteSource("client".to_string(), error.to_string()))?;
+
+    let mut updated = Lock::default();
+    let mut directories = Vec::with_capacity(sources.len());
+    for source in sources {
+        let key = get_key(source)?;
+
+        let locked = match lock.remote.iter().find(|locked| locked.source == key) {
+            Some(locked) => {
+                let directory = cache.join(&locked.sha256);
+                if !directory.exists() {
+                    let content = download(&client, &key, &locked.url).await?;
+                    let sha256 = hash(&content);
+                    if sha256 != locked.sha256 {
+                        return Err(Error::FetchingRemoteSource(
+                            key,
+                            format!(
+                                "the content of `{}` changed since it was locked, expected SHA-256 `{}`, found `{}`",
+                                locked.url, locked.sha256, sha256
+                            ),
+                        ));
+                    }
+
+                    extract(&key, &locked.url, &content, &directory)?;
+                }
+
+                locked.clone()
+            }
+            None => {
+                let url = match (&source.url, &source.package) {
+                    (Some(url), _) => url.clone(),
+                    (None, Some(package)) => resolve_package(&client, &key, package, source.version.as_deref()).await?,
+                    (None, None) => unreachable!("the source is validated by `get_key`"),
+                };
+
+                let content = download(&client, &key, &url).await?;
+                let sha256 = hash(&content);
+                let directory = cache.join(&sha256);
+                if !directory.exists() {
+                    extract(&key, &url, &content, &directory)?;
+                }
+
+                tracing::info!("Locked remote source `{}` to `{}`.", key, url);
+
+                LockedSource { source: key, url, sha256 }
+            }
+        };
+
+        directories.push(cache.join(&locked.sha256));
+        updated.remote.push(locked);
+    }
+
+    if updated != lock {
+        write_lock(&lock_path, &updated)?;
+    }
+
+    Ok(directories)
+}
+
+/// Returns the key identifying the given remote source in the lock file.
+fn get_key(source: &RemoteSource) -> Result<String, Error> {
+    match (&source.url, &source.package, &source.version) {
+        (Some(url), None, None) => Ok(url.clone()),
+        (None, Some(package), None) => Ok(package.clone()),
+        (None, Some(package), Some(version)) => Ok(format!("{}@{}", package, version)),
+        _ => Err(Error::FetchingRemoteSource(
+            format!("{:?}", source),
+            "exactly one of `url` and `package` must be set, and `version` is only allowed with `package`".to_string(),
+        )),
+    }
+}
+
+/// Resolves the URL of the archive of the given version of a Composer package, the latest stable one by default.
+async fn resolve_package(
+    client: &reqwest::Client,
+    key: &str,
+    package: &str,
+    version: Option<&str>,
+) -> Result<String, Error> {
+    let metadata = download(client, key, &format!("{}/{}.json", PACKAGIST_URL, package)).await?;
+    let metadata: JsonValue = serde_json::from_slice(&metadata)?;
+
+    let Some(versions) = metadata.get("packages").and_then(|packages| packages.get(package)).and_then(|v| v.as_array())
+    else {
+        return Err(Error::FetchingRemoteSource(key.to_string(), "the package was not found on Packagist".to_string()));
+    };
+
+    if let Some(url) = find_package_url(versions, version) {
+        return Ok(url);
+    }
+
+    Err(Error::FetchingRemoteSource(
+        key.to_string(),
+        match version {
+            Some(version) => format!("version `{}` of the package was not found on Packagist", version),
+            None => "no stable version of the package was found on Packagist".to_string(),
+        },
+    ))
+}
+
+/// Finds the URL of the archive of the given version, the latest stable one by default, in the versions
+/// listed in the Packagist metadata of a package, from the latest to the oldest.
+///
+/// Packagist only lists the fields of each version that differ from the previous one, fields that are
+/// removed being set to `__unset`, so the versions are expanded while they are searched.
+fn find_package_url(versions: &[JsonValue], version: Option<&str>) -> Option<String> {
+    let mut current = Map::new();
+    for entry in versions.iter().filter_map(JsonValue::as_object) {
+        for (field, value) in entry {
+            if value.as_str() == Some("__unset") {
+                current.remove(field);
+            } else {
+                current.insert(field.clone(), value.clone());
+            }
+        }
+
+        let Some(name) = current.get("version").and_then(JsonValue::as_str) else {
+            continue;
+        };
+
+        let matches = match version {
+            Some(version) => name.trim_start_matches('v') == version.trim_start_matches('v'),
+            None => !name.contains('-') && !name.starts_with("dev"),
+        };
+
+        let url = current.get("dist").and_then(|dist| dist.get("url")).and_then(JsonValue::as_str);
+        if let Some(url) = url.filter(|_| matches) {
+            return Some(url.to_string());
+        }
+    }
+
+    None
+}
+
+/// Downloads the content at the given URL.
+async fn download(client: &reqwest::Client, key: &str, url: &str) -> Result<Vec<u8>, Error> {
+    tracing::debug!("Downloading `{}` for remote source `{}`.", url, key);
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|error| Error::FetchingRemoteSource(key.to_string(), error.to_string()))?;
+
+    let content =
+        response.bytes().await.map_err(|error| Error::FetchingRemoteSource(key.to_string(), error.to_string()))?;
+
+    Ok(content.to_vec())
+}
+
+/// Extracts the downloaded content into the given directory, either a `.zip` archive, or a single file.
+///
+/// The content is extracted into a temporary directory first, so that an interrupted extraction is never used.
+fn extract(key: &str, url: &str, content: &[u8], directory: &Path) -> Result<(), Error> {
+    let partial = directory.with_extension("partial");
+    if partial.exists() {
+        std::fs::remove_dir_all(&partial).map_err(|error| Error::WritingCache(partial.clone(), error))?;
+    }
+
+    std::fs::create_dir_all(&partial).map_err(|error| Error::WritingCache(partial.clone(), error))?;
+
+    if content.starts_with(b"PK\x03\x04") {
+        let invalid = |error: zip::result::ZipError| Error::FetchingRemoteSource(key.to_string(), error.to_string());
+        let mut archive = zip::ZipArchive::new(Cursor::new(content)).map_err(invalid)?;
+
+        for index in 0..archive.len() {
+            let mut file = archive.by_index(index).map_err(invalid)?;
+            // Entries escaping the directory, e.g. `../file.php`, are skipped.
+            let Some(name) = file.enclosed_name().filter(|_| file.is_file()) else {
+                continue;
+            };
+
+            let path = partial.join(name);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(|error| Error::WritingCache(parent.to_path_buf(), error))?;
+            }
+
+            let mut output = std::fs::File::create(&path).map_err(|error| Error::WritingCache(path.clone(), error))?;
+            std::io::copy(&mut file, &mut output).map_err(|error| Error::WritingCache(path.clone(), error))?;
+        }
+    } else {
+        let name = url.rsplit('/').next().and_then(|name| name.split(['?', '#']).next()).unwrap_or_default();
+        let name = if name.is_empty() { "source.php" } else { name };
+
+        let path = partial.join(name);
+        std::fs::write(&path, content).map_err(|error| Error::WritingCache(path, error))?;
+    }
+
+    std::fs::rename(&partial, directory).map_err(|error| Error::WritingCache(directory.to_path_buf(), error))
+}
+
+/// Returns the hexadecimal SHA-256 hash of the given content.
+fn hash(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+/// Reads the lock file, which is empty if it does not exist yet.
+fn read_lock(path: &Path) -> Result<Lock, Error> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(toml::from_str(&content)?),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Lock::default()),
+        Err(error) => Err(Error::ReadingLockFile(path.to_path_buf(), error)),
+    }
+}
+
+/// Writes the lock file, atomically, so that an interrupted write never leaves a truncated lock file.
+fn write_lock(path: &Path, lock: &Lock) -> Result<(), Error> {
+    let content = format!(
+        "# This file pins the remote sources of Mago to the exact content they were downloaded with.\n\
+         # It should be committed. Remove an entry to update the source to its latest version.\n\n{}",
+        toml::to_string(lock)?
+    );
+
+    write_atomically(path, content.as_bytes()).map_err(|error| Error::WritingLockFile(path.to_path_buf(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use serde_json::json;
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    use super::*;
+
+    fn source(url: Option<&str>, package: Option<&str>, version: Option<&str>) -> RemoteSource {
+        RemoteSource {
+            url: url.map(str::to_string),
+            package: package.map(str::to_string),
+            version: version.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_get_key() {
+        let url = "https://example.com/stubs.php";
+
+        assert_eq!(get_key(&source(Some(url), None, None)).unwrap(), url);
+        assert_eq!(get_key(&source(None, Some("acme/stubs"), None)).unwrap(), "acme/stubs");
+        assert_eq!(get_key(&source(None, Some("acme/stubs"), Some("1.0.0"))).unwrap(), "acme/stubs@1.0.0");
+
+        assert!(get_key(&source(None, None, None)).is_err());
+        assert!(get_key(&source(Some(url), Some("acme/stubs"), None)).is_err());
+        assert!(get_key(&source(Some(url), None, Some("1.0.0"))).is_err());
+    }
+
+    #[test]
+    fn test_find_package_url_expands_minified_versions() {
+        let versions = json!([
+            { "version": "2.0.0-beta1", "dist": { "url": "https://example.com/2.0.0-beta1.zip" } },
+            { "version": "1.1.0", "dist": { "url": "https://example.com/1.1.0.zip" } },
+            { "version": "1.0.0", "dist": "__unset" },
+            { "version": "0.9.0", "dist": { "url": "https://example.com/0.9.0.zip" } },
+        ]);
+        let versions = versions.as_array().unwrap();
+
+        assert_eq!(find_package_url(versions, None).as_deref(), Some("https://example.com/1.1.0.zip"));
+        assert_eq!(find_package_url(versions, Some("v1.1.0")).as_deref(), Some("https://example.com/1.1.0.zip"));
+        assert_eq!(
+            find_package_url(versions, Some("2.0.0-beta1")).as_deref(),
+            Some("https://example.com/2.0.0-beta1.zip")
+        );
+        assert_eq!(find_package_url(versions, Some("0.9.0")).as_deref(), Some("https://example.com/0.9.0.zip"));
+
+        // The distribution of this version was unset, and is not inherited from the previous one.
+        assert_eq!(find_package_url(versions, Some("1.0.0")), None);
+        assert_eq!(find_package_url(versions, Some("3.0.0")), None);
+    }
+
+    #[test]
+    fn test_extract_zip_archive() {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in [("src/Foo.php", "<?php class Foo {}"), ("../escape.php", "<?php")] {
+            writer.start_file(name, SimpleFileOptions::default()).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+
+        let content = writer.finish().unwrap().into_inner();
+        let temporary = tempfile::tempdir().unwrap();
+        let directory = temporary.path().join("cache").join(hash(&content));
+
+        extract("acme/stubs", "https://example.com/stubs.zip", &content, &directory).unwrap();
+
+        assert_eq!(std::fs::read_to_string(directory.join("src/Foo.php")).unwrap(), "<?php class Foo {}");
+        assert!(!temporary.path().join("cache/escape.php").exists());
+        assert!(!directory.with_extension("partial").exists());
+    }
+
+    #[test]
+    fn test_extract_single_file() {
+        let temporary = tempfile::tempdir().unwrap();
+        let directory = temporary.path().join("stubs");
+
+        extract("stubs", "https://example.com/path/stubs.php?token=1", b"<?php", &directory).unwrap();
+
+        assert_eq!(std::fs::read(directory.join("stubs.php")).unwrap(), b"<?php");
+    }
+
+    #[test]
+    fn test_write_and_read_lock() {
+        let temporary = tempfile::tempdir().unwrap();
+        let path = temporary.path().join(LOCK_FILE);
+
+        assert_eq!(read_lock(&path).unwrap(), Lock::default());
+
+        let lock = Lock {
+            remote: vec![LockedSource {
+                source: "acme/stubs@1.0.0".to_string(),
+                url: "https://example.com/1.0.0.zip".to_string(),
+                sha256: hash(b"content"),
+            }],
+        };
+
+        write_lock(&path, &lock).unwrap();
+
+        assert_eq!(read_lock(&path).unwrap(), lock);
+    }
+}
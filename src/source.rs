@@ -10,12 +10,18 @@ use mago_interner::ThreadedInterner;
 use mago_source::SourceCategory;
 use mago_source::SourceManager;
 
+use crate::archive;
+use crate::config::framework::Framework;
 use crate::config::source::SourceConfiguration;
 use crate::consts::PHP_STUBS;
 use crate::error::Error;
+use crate::remote;
+use crate::remote::REMOTE_DIRECTORY;
 
 /// Load the source manager from the given files or directories,
-/// ignoring the `paths`, `includes`, and `excludes` configuration.
+/// ignoring the `paths` and `excludes` configuration.
+///
+/// Files inside of the `includes` are loaded as external sources.
 ///
 /// # Arguments
 ///
@@ -34,7 +40,7 @@ pub async fn from_paths(
     paths: Vec<PathBuf>,
     include_stubs: bool,
 ) -> Result<SourceManager, Error> {
-    let SourceConfiguration { root, extensions, .. } = configuration;
+    let SourceConfiguration { root, includes, extensions, .. } = configuration;
 
     let manager = SourceManager::new(interner.clone());
 
@@ -42,7 +48,16 @@ pub async fn from_paths(
     let extensions: HashSet<&str> = extensions.iter().map(|ext| ext.as_str()).collect();
 
     for path in paths {
-        add_path_to_manager(&manager, path, root, &[], &excludes_set, &extensions, true).await?;
+        // Paths of external sources are only analyzed, so that they are never reported or formatted.
+        let external = path.canonicalize().is_ok_and(|path| includes.iter().any(|include| path.starts_with(include)));
+        if external {
+            tracing::warn!(
+                "`{}` is part of the external sources, it is analyzed, but never reported or formatted.",
+                path.display()
+            );
+        }
+
+        add_path_to_manager(&manager, path, root, &[], &excludes_set, &extensions, !external).await?;
     }
 
     if include_stubs {
@@ -61,7 +76,7 @@ pub async fn from_paths(
 ///
 /// * `interner` - The interner to use for string interning.
 /// * `configuration` - The configuration to use for loading the sources.
-/// * `include_externals` - Whether to include external sources in the source manager.
+/// * `include_externals` - Whether to include external sources in the source manager, downloading the remote sources.
 /// * `include_stubs` - Whether to include stubs in the source manager.
 ///
 /// # Returns
@@ -74,7 +89,11 @@ pub async fn load(
     include_externals: bool,
     include_stubs: bool,
 ) -> Result<SourceManager, Error> {
-    let SourceConfiguration { root, paths, includes, excludes, extensions } = configuration;
+    let SourceConfiguration { root, paths, includes, excludes, extensions, remote } = configuration;
+
+    // The downloaded remote sources are never user-defined, even if they are inside of the scanned paths.
+    let mut includes = includes.clone();
+    includes.push(root.join(REMOTE_DIRECTORY));
 
     let mut starting_paths = Vec::new();
 
@@ -87,9 +106,15 @@ pub async fn load(
     }
 
     if include_externals {
-        for include in includes {
+        for include in configuration.includes.iter() {
             starting_paths.push((include.clone(), false));
         }
+
+        if !remote.is_empty() {
+            for directory in remote::fetch(root, remote).await? {
+                starting_paths.push((directory, false));
+            }
+        }
     }
 
     let excludes_set = create_excludes_set(excludes, root);
@@ -97,7 +122,7 @@ pub async fn load(
 
     let manager = SourceManager::new(interner.clone());
     for (path, user_defined) in starting_paths.into_iter() {
-        add_path_to_manager(&manager, path, root, includes, &excludes_set, &extensions, user_defined).await?;
+        add_path_to_manager(&manager, path, root, &includes, &excludes_set, &extensions, user_defined).await?;
     }
 
     if include_stubs {
@@ -109,6 +134,47 @@ pub async fn load(
     Ok(manager)
 }
 
+/// Insert the stubs of the given framework into the source manager.
+///
+/// # Arguments
+///
+/// * `manager` - The source manager to insert the stubs into.
+/// * `framework` - The framework whose stubs should be inserted.
+pub fn insert_framework_stubs(manager: &SourceManager, framework: Framework) {
+    for (stub, content) in framework.stubs() {
+        manager.insert_content(stub, content, SourceCategory::BuiltIn);
+    }
+}
+
+/// Get the category the given file would be loaded with when scanning the sources
+/// as per the given configuration.
+///
+/// # Arguments
+///
+/// * `configuration` - The configuration to use for scanning the sources.
+/// * `path` - The absolute path of the file.
+///
+/// # Returns
+///
+/// The category of the file, or `None` if the file would not be loaded.
+pub fn get_category(configuration: &SourceConfiguration, path: &Path) -> Option<SourceCategory> {
+    let SourceConfiguration { root, paths, includes, excludes, extensions, .. } = configuration;
+
+    let extensions: HashSet<&str> = extensions.iter().map(|ext| ext.as_str()).collect();
+    if !is_accepted_file(path, &extensions) || is_excluded(path, &create_excludes_set(excludes, root)) {
+        return None;
+    }
+
+    if includes.iter().any(|include| path.starts_with(include)) {
+        return Some(SourceCategory::External);
+    }
+
+    let user_defined =
+        if paths.is_empty() { path.starts_with(root) } else { paths.iter().any(|source| path.starts_with(source)) };
+
+    user_defined.then_some(SourceCategory::UserDefined)
+}
+
 #[inline(always)]
 async fn add_path_to_manager(
     manager: &SourceManager,
@@ -124,6 +190,15 @@ async fn add_path_to_manager(
     }
 
     if !path.is_dir() {
+        // Archives are only included, as they cannot be formatted or fixed.
+        if !user_defined && archive::is_archive(&path) {
+            if !is_excluded(&path, excludes_set) {
+                archive::insert_archive(manager, &path, &get_source_name(&path, root), extensions);
+            }
+
+            return Ok(());
+        }
+
         add_file_to_manager(manager, path, root, includes, excludes_set, extensions, user_defined);
 
         return Ok(());
@@ -170,14 +245,19 @@ fn add_file_to_manager(
         return;
     }
 
-    let name = match path.strip_prefix(root) {
-        Ok(rel_path) => rel_path.display().to_string(),
-        Err(_) => path.display().to_string(),
-    };
+    let name = get_source_name(&path, root);
 
     manager.insert_path(name, path, if user_defined { SourceCategory::UserDefined } else { SourceCategory::External });
 }
 
+/// Returns the name of the source at the given path, i.e. its path relative to the root, if it is inside of it.
+fn get_source_name(path: &Path, root: &Path) -> String {
+    match path.strip_prefix(root) {
+        Ok(rel_path) => rel_path.display().to_string(),
+        Err(_) => path.display().to_string(),
+    }
+}
+
 fn create_excludes_set(excludes: &[String], root: &Path) -> HashSet<Exclusion> {
     excludes
         .iter()
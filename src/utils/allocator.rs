@@ -0,0 +1,49 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::Cell;
+
+thread_local! {
+    static ALLOCATED: Cell<u64> = const { Cell::new(0) };
+}
+
+/// The global allocator, which keeps track of the number of bytes allocated by each thread.
+///
+/// Allocations are delegated to the system allocator; only a thread-local counter is updated, so that the
+/// allocations of a rule can be measured when profiling rules, without synchronizing the threads.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size.saturating_sub(layout.size()));
+
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+/// Returns the number of bytes allocated so far by the current thread.
+pub fn allocated() -> u64 {
+    ALLOCATED.try_with(Cell::get).unwrap_or(0)
+}
+
+#[inline(always)]
+fn count(size: usize) {
+    // The counter is unavailable while the thread is being torn down, which is not worth measuring.
+    let _ = ALLOCATED.try_with(|allocated| allocated.set(allocated.get() + size as u64));
+}
@@ -0,0 +1,66 @@
+use std::path::Path;
+
+/// The locations of the `CODEOWNERS` file, relative to the workspace, in the order GitHub looks them up.
+const CODEOWNERS_FILES: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// The owners of the files of the workspace, as declared in its `CODEOWNERS` file.
+#[derive(Debug, Default)]
+pub struct CodeOwners {
+    /// The patterns of the file, each converted to globs, along with their owners, in the order of the file.
+    rules: Vec<(Vec<String>, Vec<String>)>,
+}
+
+impl CodeOwners {
+    /// Loads the `CODEOWNERS` file of the given workspace, returning `None` if there is none.
+    pub fn load(root: &Path) -> Option<Self> {
+        let (path, content) = CODEOWNERS_FILES.iter().find_map(|file| {
+            let path = root.join(file);
+
+            std::fs::read_to_string(&path).ok().map(|content| (path, content))
+        })?;
+
+        tracing::debug!("Loaded code owners from `{}`.", path.display());
+
+        let rules = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let pattern = parts.next()?;
+                let owners = parts.take_while(|part| !part.starts_with('#')).map(str::to_string).collect();
+
+                Some((to_globs(pattern), owners))
+            })
+            .collect();
+
+        Some(Self { rules })
+    }
+
+    /// Returns the owners of the file with the given name, i.e. its path relative to the workspace.
+    ///
+    /// As in GitHub, the last matching pattern takes precedence.
+    pub fn get_owners(&self, name: &str) -> &[String] {
+        let name = name.replace('\\', "/");
+
+        self.rules
+            .iter()
+            .rev()
+            .find(|(globs, _)| globs.iter().any(|glob| glob_match::glob_match(glob, &name)))
+            .map(|(_, owners)| owners.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Converts a `CODEOWNERS` pattern, which follows the `.gitignore` syntax, to globs.
+///
+/// Patterns containing a slash, other than a trailing one, are relative to the workspace, while the others match
+/// at any depth. Patterns matching a directory also match everything it contains, unless they end with `/*`.
+fn to_globs(pattern: &str) -> Vec<String> {
+    let is_anchored = pattern.trim_end_matches('/').contains('/');
+    let pattern = pattern.trim_start_matches('/').trim_end_matches('/');
+
+    let glob = if is_anchored { pattern.to_string() } else { format!("**/{}", pattern) };
+
+    if pattern.ends_with("/*") { vec![glob] } else { vec![format!("{}/**", glob), glob] }
+}
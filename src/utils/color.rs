@@ -0,0 +1,89 @@
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use strum::Display;
+use strum::VariantNames;
+
+use mago_reporting::reporter::ReportingTarget;
+
+/// The color policy of the process, set once the command line is parsed.
+static POLICY: OnceLock<ColorPolicy> = OnceLock::new();
+
+/// Whether output should be colored.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Display, VariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum ColorPolicy {
+    /// Color output written to a terminal, unless disabled by the `NO_COLOR` or `CLICOLOR` environment variables.
+    #[default]
+    Auto,
+    /// Always color output.
+    Always,
+    /// Never color output.
+    Never,
+}
+
+impl FromStr for ColorPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(format!("invalid color policy `{}`", s)),
+        }
+    }
+}
+
+/// Sets the color policy of the process, and applies it to the libraries that color their output on their own.
+///
+/// # Arguments
+///
+/// * `policy` - The color policy given on the command line.
+pub fn initialize(policy: ColorPolicy) {
+    let _ = POLICY.set(policy);
+
+    colored::control::set_override(is_enabled(ReportingTarget::Stdout));
+    console::set_colors_enabled(is_enabled(ReportingTarget::Stdout));
+    console::set_colors_enabled_stderr(is_enabled(ReportingTarget::Stderr));
+}
+
+/// Determines whether output written to the given stream should be colored.
+///
+/// With the `auto` policy, output is colored when the stream is a terminal. Setting `NO_COLOR` to any
+/// non-empty value disables colors, and otherwise, `CLICOLOR_FORCE` enables them and `CLICOLOR=0` disables them.
+///
+/// # Arguments
+///
+/// * `target` - The stream the output is written to.
+///
+/// # Returns
+///
+/// `true` if the output should contain color escape codes.
+pub fn is_enabled(target: ReportingTarget) -> bool {
+    match POLICY.get().copied().unwrap_or_default() {
+        ColorPolicy::Always => true,
+        ColorPolicy::Never => false,
+        ColorPolicy::Auto => {
+            let variable = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty());
+
+            if variable("NO_COLOR").is_some() {
+                return false;
+            }
+
+            if variable("CLICOLOR_FORCE").is_some_and(|value| value != "0") {
+                return true;
+            }
+
+            if variable("CLICOLOR").is_some_and(|value| value == "0") {
+                return false;
+            }
+
+            match target {
+                ReportingTarget::Stdout => std::io::stdout().is_terminal(),
+                ReportingTarget::Stderr => std::io::stderr().is_terminal(),
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::panic::AssertUnwindSafe;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Once;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source;
+use mago_source::Source;
+use mago_span::HasSpan;
+
+use crate::config::Configuration;
+use crate::consts::ISSUE_URL;
+use crate::consts::TARGET;
+use crate::consts::VERSION;
+use crate::error::Error;
+
+/// The directory the crash reports are written to, relative to the workspace.
+pub const DEFAULT_CRASH_DIRECTORY: &str = ".mago/crash";
+
+static PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    /// Whether a panic of the current thread is being caught by [`catch_panic`].
+    static IS_CATCHING: Cell<bool> = const { Cell::new(false) };
+
+    /// The description of the last panic caught on the current thread, including its location.
+    static CAUGHT_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs the given operation, catching any panic it raises.
+///
+/// Caught panics are not printed by the panic hook, but returned along with their location instead.
+pub fn catch_panic<T>(operation: impl FnOnce() -> T) -> Result<T, String> {
+    PANIC_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            if IS_CATCHING.get() {
+                CAUGHT_PANIC.set(Some(info.to_string()));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+
+    let was_catching = IS_CATCHING.replace(true);
+    let result = std::panic::catch_unwind(AssertUnwindSafe(operation));
+    IS_CATCHING.set(was_catching);
+
+    result.map_err(|_| CAUGHT_PANIC.take().unwrap_or_else(|| "panicked at an unknown location".to_string()))
+}
+
+/// Reduces the given code to the smallest part of it that still fails the given check.
+///
+/// Top-level statements are bisected first, then the remaining lines, removing chunks of them while
+/// the code still fails, halving the size of the chunks until single statements or lines are removed.
+pub fn minimize(interner: &ThreadedInterner, content: &str, fails: impl Fn(&str) -> bool) -> String {
+    let starts = catch_panic(|| {
+        let source = Source::standalone(interner, "minimize", content);
+        let (program, _) = parse_source(interner, &source);
+
+        program.statements.iter().map(|statement| statement.span().start.offset).collect::<Vec<_>>()
+    })
+    .unwrap_or_default();
+
+    let mut statements = vec![];
+    let mut previous = 0;
+    for start in starts.into_iter().chain([content.len()]) {
+        if start > previous && start <= content.len() {
+            statements.push(&content[previous..start]);
+            previous = start;
+        }
+    }
+
+    let content = reduce(statements, &fails).concat();
+    let lines = content.split_inclusive('\n').collect::<Vec<_>>();
+
+    reduce(lines, &fails).concat()
+}
+
+/// Removes chunks of the given parts while their concatenation still fails the given check.
+fn reduce<'a>(mut parts: Vec<&'a str>, fails: &impl Fn(&str) -> bool) -> Vec<&'a str> {
+    let mut chunk = parts.len() / 2;
+    while chunk > 0 {
+        let mut index = 0;
+        while index < parts.len() {
+            let end = (index + chunk).min(parts.len());
+            let candidate = parts[..index].iter().chain(&parts[end..]).copied().collect::<Vec<_>>();
+
+            if fails(&candidate.concat()) {
+                parts = candidate;
+            } else {
+                index = end;
+            }
+        }
+
+        chunk /= 2;
+    }
+
+    parts
+}
+
+/// Catches the crashes of a command on individual sources, so that the rest of the run continues,
+/// and writes a report for each of them, with a minimized snippet reproducing the crash.
+#[derive(Debug)]
+pub struct CrashReporter {
+    interner: ThreadedInterner,
+    directory: PathBuf,
+    command: &'static str,
+    configuration: String,
+    crashes: AtomicUsize,
+}
+
+impl CrashReporter {
+    /// Creates a new reporter for the given command, writing its reports to the crash directory of the workspace.
+    pub fn new(interner: &ThreadedInterner, configuration: &Configuration, command: &'static str) -> Self {
+        Self {
+            interner: interner.clone(),
+            directory: configuration.source.root.join(DEFAULT_CRASH_DIRECTORY),
+            command,
+            configuration: toml::to_string_pretty(configuration).unwrap_or_default(),
+            crashes: AtomicUsize::new(0),
+        }
+    }
+
+    /// Runs the given operation on the given source, returning `None` if it crashed.
+    ///
+    /// When the operation crashes, it is run again on parts of the source to minimize the snippet
+    /// reproducing the crash, which is written to a crash report.
+    pub fn run<T>(&self, source: &Source, operation: impl Fn(&Source) -> T) -> Option<T> {
+        let message = match catch_panic(|| operation(source)) {
+            Ok(result) => return Some(result),
+            Err(message) => message,
+        };
+
+        self.crashes.fetch_add(1, Ordering::Relaxed);
+
+        let name = self.interner.lookup(&source.identifier.0);
+        let snippet = minimize(&self.interner, &source.content, |candidate| {
+            let candidate = Source::standalone(&self.interner, name, candidate);
+
+            catch_panic(|| operation(&candidate)).is_err()
+        });
+
+        match self.write(name, &message, &snippet) {
+            Ok(path) => {
+                tracing::error!("Mago crashed while processing '{}', see the crash report '{}'.", name, path.display())
+            }
+            Err(error) => {
+                tracing::error!("Mago crashed while processing '{}': {}", name, message);
+                tracing::error!("{}", error);
+            }
+        }
+
+        None
+    }
+
+    /// Logs a summary of the crashes, if any, prompting to report them, and returns their number.
+    pub fn finish(&self) -> usize {
+        let crashes = self.crashes.load(Ordering::Relaxed);
+        if crashes > 0 {
+            tracing::error!(
+                "Mago crashed while processing {} source files, which were skipped, the crash reports were written to '{}'.",
+                crashes,
+                self.directory.display()
+            );
+            tracing::error!("Please open an issue at {} including the crash reports.", ISSUE_URL);
+        }
+
+        crashes
+    }
+
+    /// Writes a crash report, returning its path.
+    fn write(&self, name: &str, message: &str, snippet: &str) -> Result<PathBuf, Error> {
+        std::fs::create_dir_all(&self.directory)
+            .map_err(|error| Error::WritingCrashReport(self.directory.clone(), error))?;
+
+        let report = format!(
+            "# Mago crash report\n\n\
+            - Version: {}\n\
+            - Target: {}\n\
+            - Command: `mago {}`\n\
+            - File: `{}`\n\n\
+            ## Panic\n\n```\n{}\n```\n\n\
+            ## Reproduction\n\n```php\n{}\n```\n\n\
+            ## Configuration\n\n```toml\n{}```\n",
+            VERSION,
+            TARGET,
+            self.command,
+            name,
+            message.trim_end(),
+            snippet.trim_end(),
+            self.configuration,
+        );
+
+        let (path, mut file) = create_report_file(&self.directory)?;
+        file.write_all(report.as_bytes()).map_err(|error| Error::WritingCrashReport(path.clone(), error))?;
+
+        Ok(path)
+    }
+}
+
+/// Creates a new report file in the given directory, named after the current time.
+fn create_report_file(directory: &Path) -> Result<(PathBuf, std::fs::File), Error> {
+    let mut timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_millis()).unwrap_or(0);
+    loop {
+        let path = directory.join(format!("{}.md", timestamp));
+
+        // Crashes of concurrent tasks must not share a report, so the next free timestamp is taken.
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => timestamp += 1,
+            Err(error) => return Err(Error::WritingCrashReport(path, error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_profile_unwinds_on_panic() {
+        // Tests always unwind, so the strategy of the release profile is checked against the manifest,
+        // as `catch_panic` cannot catch anything if release builds abort on panic.
+        let manifest = std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml")).unwrap();
+        let manifest = manifest.parse::<toml::Table>().unwrap();
+        let strategy = manifest["profile"]["release"].get("panic").and_then(|strategy| strategy.as_str());
+
+        assert!(matches!(strategy, None | Some("unwind")), "the release profile must unwind, found {:?}", strategy);
+    }
+
+    #[test]
+    fn test_crash_reporter_continues_after_a_panicking_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let interner = ThreadedInterner::new();
+        let configuration = Configuration::from_root(directory.path().to_path_buf());
+        let reporter = CrashReporter::new(&interner, &configuration, "format");
+
+        let operation = |source: &Source| {
+            if source.content.contains("crash") {
+                panic!("crashed on purpose");
+            }
+
+            source.content.len()
+        };
+
+        let panicking = Source::standalone(&interner, "panicking.php", "<?php\n\n$a = 1;\ncrash();\n$b = 2;\n");
+        let valid = Source::standalone(&interner, "valid.php", "<?php\n\n$a = 1;\n");
+
+        assert_eq!(None, reporter.run(&panicking, operation));
+        assert_eq!(Some(15), reporter.run(&valid, operation));
+        assert_eq!(1, reporter.finish());
+
+        let reports = std::fs::read_dir(directory.path().join(DEFAULT_CRASH_DIRECTORY)).unwrap().collect::<Vec<_>>();
+        assert_eq!(1, reports.len());
+
+        let report = std::fs::read_to_string(reports[0].as_ref().unwrap().path()).unwrap();
+        assert!(report.contains("crashed on purpose"));
+        assert!(report.contains("```php\ncrash();\n```"));
+    }
+}
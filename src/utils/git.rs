@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::error::Error;
+
+/// The name of the file listing the revisions `git blame` should ignore, as recognized by GitHub and GitLab.
+pub const IGNORE_REVS_FILE: &str = ".git-blame-ignore-revs";
+
+/// Runs `git` with the given arguments in the given directory, returning its standard output.
+///
+/// Fails if `git` cannot be run, or exits with a failure code, in which case the error contains its standard error.
+pub fn run(directory: &Path, arguments: &[&str]) -> Result<String, Error> {
+    let command = format!("git {}", arguments.join(" "));
+
+    let output = Command::new("git")
+        .args(arguments)
+        .current_dir(directory)
+        .output()
+        .map_err(|error| Error::RunningGit(command.clone(), error))?;
+
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+
+        return Err(Error::RunningGit(command, std::io::Error::other(message)));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Returns the root directory of the git repository containing the given directory.
+pub fn get_repository_root(directory: &Path) -> Result<PathBuf, Error> {
+    let root = run(directory, &["rev-parse", "--show-toplevel"])?;
+
+    Ok(PathBuf::from(root.trim_end()))
+}
+
+/// The files changed since a revision, along with the lines changed in each of them.
+#[derive(Debug, Default)]
+pub struct Changes {
+    /// The changed lines of each file, as 0-based ranges, keyed by the path of the file relative to the workspace.
+    files: HashMap<String, Vec<Range<usize>>>,
+}
+
+impl Changes {
+    /// Determines whether the file with the given name, i.e. its path relative to the workspace, was changed.
+    pub fn contains_file(&self, name: &str) -> bool {
+        self.files.contains_key(&name.replace('\\', "/"))
+    }
+
+    /// Determines whether any line in the given 0-based range of lines of the given file was changed.
+    pub fn contains_lines(&self, name: &str, lines: Range<usize>) -> bool {
+        let Some(changed) = self.files.get(&name.replace('\\', "/")) else {
+            return false;
+        };
+
+        changed.iter().any(|changed| changed.start < lines.end && lines.start < changed.end)
+    }
+
+    /// Returns the number of changed files.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Determines whether no file was changed.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+/// Returns the files changed in the given revision range, and the lines changed in each of them.
+///
+/// A range, e.g. `main..feature`, compares two revisions, while a single revision is compared to the working
+/// tree, in which case the untracked files are considered entirely changed as well. Deleted files are left out,
+/// and the paths are relative to the given directory, files outside of it being left out as well.
+pub fn get_changes(directory: &Path, revision: &str) -> Result<Changes, Error> {
+    let diff = run(
+        directory,
+        &[
+            "-c",
+            "core.quotePath=false",
+            "diff",
+            "--unified=0",
+            "--no-color",
+            "--no-ext-diff",
+            "--no-renames",
+            "--diff-filter=d",
+            "--relative",
+            "--end-of-options",
+            revision,
+            "--",
+        ],
+    )?;
+
+    let mut changes = parse_diff(&diff);
+
+    if !revision.contains("..") {
+        let untracked = run(directory, &["ls-files", "-z", "--others", "--exclude-standard"])?;
+
+        for path in untracked.split('\0').filter(|path| !path.is_empty()) {
+            changes.files.insert(path.to_string(), std::iter::once(0..usize::MAX).collect());
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Parses the changed files, and the lines changed in each of them, from the output of `git diff --unified=0`.
+///
+/// The `+++` line naming the new file is only recognized in the header of each file, i.e. between its
+/// `diff --git` line and its first hunk, so that added lines starting with `++` are not mistaken for it.
+fn parse_diff(diff: &str) -> Changes {
+    let mut changes = Changes::default();
+    let mut current: Option<String> = None;
+    let mut in_header = false;
+    for line in diff.lines() {
+        if line.starts_with("diff --git ") {
+            current = None;
+            in_header = true;
+        } else if let Some(header) = line.strip_prefix("@@ ") {
+            in_header = false;
+
+            let (Some(path), Some(range)) = (&current, parse_hunk_header(header)) else {
+                continue;
+            };
+
+            if !range.is_empty() {
+                changes.files.entry(path.clone()).or_default().push(range);
+            }
+        } else if in_header {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                current = unquote(path).strip_prefix("b/").map(str::to_string);
+                if let Some(path) = &current {
+                    changes.files.entry(path.clone()).or_default();
+                }
+            }
+        }
+    }
+
+    changes
+}
+
+/// Parses the 0-based range of lines added by a hunk, from its header, e.g. `-10,2 +12,3 @@`.
+fn parse_hunk_header(header: &str) -> Option<Range<usize>> {
+    let added = header.split_whitespace().find_map(|part| part.strip_prefix('+'))?;
+    let (start, count) = match added.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>().ok()?, count.parse::<usize>().ok()?),
+        None => (added.parse::<usize>().ok()?, 1),
+    };
+
+    Some(start.saturating_sub(1)..start.saturating_sub(1) + count)
+}
+
+/// Decodes a path git quoted because it contains unusual characters, e.g. `"caf\303\251.php"`.
+///
+/// Quoted paths use C-style escapes, including octal escapes for the bytes of non-ASCII characters.
+/// Paths that are not quoted are returned as is.
+fn unquote(path: &str) -> String {
+    let Some(quoted) = path.strip_prefix('"').and_then(|path| path.strip_suffix('"')) else {
+        return path.to_string();
+    };
+
+    let mut bytes = Vec::with_capacity(quoted.len());
+    let mut input = quoted.bytes().peekable();
+    while let Some(byte) = input.next() {
+        if byte != b'\\' {
+            bytes.push(byte);
+
+            continue;
+        }
+
+        match input.next() {
+            Some(b'a') => bytes.push(0x07),
+            Some(b'b') => bytes.push(0x08),
+            Some(b'f') => bytes.push(0x0C),
+            Some(b'n') => bytes.push(b'\n'),
+            Some(b'r') => bytes.push(b'\r'),
+            Some(b't') => bytes.push(b'\t'),
+            Some(b'v') => bytes.push(0x0B),
+            Some(digit @ b'0'..=b'7') => {
+                let mut value = u32::from(digit - b'0');
+                for _ in 0..2 {
+                    match input.peek() {
+                        Some(digit @ b'0'..=b'7') => {
+                            value = value * 8 + u32::from(digit - b'0');
+                            input.next();
+                        }
+                        _ => break,
+                    }
+                }
+
+                bytes.push(value as u8);
+            }
+            Some(other) => bytes.push(other),
+            None => bytes.push(b'\\'),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unquote() {
+        assert_eq!(unquote("src/a.php"), "src/a.php");
+        assert_eq!(unquote("\"src/with space\\\"quote\\\".php\""), "src/with space\"quote\".php");
+        assert_eq!(unquote("\"src/caf\\303\\251.php\""), "src/café.php");
+        assert_eq!(unquote("\"src/tab\\there.php\""), "src/tab\there.php");
+    }
+
+    #[test]
+    fn test_parse_diff() {
+        let diff = [
+            "diff --git a/src/a.php b/src/a.php",
+            "index 1111111..2222222 100644",
+            "--- a/src/a.php",
+            "+++ b/src/a.php",
+            "@@ -1,0 +2,2 @@",
+            "+++ $i;",
+            "+++ b/src/b.php",
+            "diff --git \"a/src/caf\\303\\251.php\" \"b/src/caf\\303\\251.php\"",
+            "--- \"a/src/caf\\303\\251.php\"",
+            "+++ \"b/src/caf\\303\\251.php\"",
+            "@@ -5 +5 @@",
+            "-old",
+            "+new",
+        ]
+        .join("\n");
+
+        let changes = parse_diff(&diff);
+
+        assert_eq!(changes.len(), 2);
+        assert!(changes.contains_lines("src/a.php", 1..2));
+        assert!(changes.contains_lines("src/a.php", 2..3));
+        assert!(!changes.contains_lines("src/a.php", 3..10));
+        assert!(!changes.contains_file("src/b.php"));
+        assert!(changes.contains_lines("src/café.php", 4..5));
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use mago_interner::ThreadedInterner;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_reporting::Level;
+
+/// A limit on the issues to find, after which the remaining sources are no longer processed.
+///
+/// The limit is shared by the tasks processing sources in parallel: each task records the issues it found,
+/// and the tasks starting after the limit was reached skip their source. Tasks already running when the limit
+/// is reached still complete, so slightly more issues than the limit may be found.
+#[derive(Debug, Default)]
+pub struct IssueLimit {
+    /// Whether to stop after the first error.
+    fail_fast: bool,
+
+    /// The maximum number of issues to report.
+    maximum: Option<usize>,
+
+    /// The number of issues found so far.
+    found: AtomicUsize,
+
+    /// Whether the limit was reached.
+    reached: AtomicBool,
+}
+
+impl IssueLimit {
+    /// Creates a new limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `fail_fast` - Whether to stop after the first issue of the `error` level.
+    /// * `maximum` - The maximum number of issues to report, if any.
+    pub fn new(fail_fast: bool, maximum: Option<usize>) -> Self {
+        Self { fail_fast, maximum, ..Self::default() }
+    }
+
+    /// Determines whether the limit was reached, i.e. whether the remaining sources should be skipped.
+    pub fn is_reached(&self) -> bool {
+        self.reached.load(Ordering::Relaxed)
+    }
+
+    /// Records the issues found in a source, or in the whole project, e.g. by the codebase reflection.
+    pub fn record<'a>(&self, issues: impl IntoIterator<Item = &'a Issue>) {
+        let mut count = 0;
+        let mut contains_errors = false;
+        for issue in issues {
+            count += 1;
+            contains_errors |= issue.level >= Level::Error;
+        }
+
+        let found = self.found.fetch_add(count, Ordering::Relaxed) + count;
+        if (self.fail_fast && contains_errors) || self.maximum.is_some_and(|maximum| found >= maximum) {
+            self.reached.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Applies the limit to the issues to report, keeping at most the maximum number of issues, in order of location.
+    ///
+    /// With `fail_fast`, every issue found is kept, as the issues found alongside the first error may
+    /// help fixing it.
+    pub fn apply(&self, interner: &ThreadedInterner, issues: IssueCollection) -> IssueCollection {
+        if self.is_reached() {
+            tracing::warn!("Stopped processing sources after reaching the issue limit, some sources were skipped.");
+        }
+
+        match self.maximum {
+            Some(maximum) if issues.len() > maximum => {
+                IssueCollection::from(issues.sorted_by_location(interner).into_iter().take(maximum))
+            }
+            _ => issues,
+        }
+    }
+}
@@ -1,10 +1,17 @@
+use std::fs::File;
 use std::io::Result;
 use std::io::Stderr;
 use std::io::Write;
+use std::sync::Mutex;
 
+use tracing::Level;
 use tracing_subscriber::EnvFilter;
+use tracing_subscriber::Layer;
 use tracing_subscriber::filter::Directive;
+use tracing_subscriber::filter::filter_fn;
 use tracing_subscriber::fmt;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 
 use crate::utils::progress::GLOBAL_PROGRESS_MANAGER;
 
@@ -14,16 +21,32 @@ use crate::utils::progress::GLOBAL_PROGRESS_MANAGER;
 ///
 /// * `directive` - A logging directive that controls the log level and filtering rules.
 /// * `env_var` - The environment variable used to override log filtering rules.
-pub fn initialize_logger(directive: impl Into<Directive>, env_var: impl Into<String>) {
-    fmt()
-        .with_env_filter(
-            EnvFilter::builder().with_default_directive(directive.into()).with_env_var(env_var.into()).from_env_lossy(),
-        )
+/// * `ansi` - Whether to color the log messages.
+/// * `log_file` - A file to write every event of mago, down to the `trace` level, to as JSON lines,
+///   regardless of the other filtering rules.
+pub fn initialize_logger(
+    directive: impl Into<Directive>,
+    env_var: impl Into<String>,
+    ansi: bool,
+    log_file: Option<File>,
+) {
+    let stderr_layer = fmt::layer()
         .with_writer(LoggerWriter::stderr)
         .with_target(cfg!(debug_assertions))
+        .with_ansi(ansi)
         .without_time()
         .compact()
-        .init();
+        .with_filter(
+            EnvFilter::builder().with_default_directive(directive.into()).with_env_var(env_var.into()).from_env_lossy(),
+        );
+
+    let file_layer = log_file.map(|file| {
+        fmt::layer().json().with_writer(Mutex::new(file)).with_thread_names(true).with_filter(filter_fn(|metadata| {
+            metadata.target().starts_with("mago") || *metadata.level() <= Level::WARN
+        }))
+    });
+
+    tracing_subscriber::registry().with(stderr_layer).with(file_layer).init();
 }
 
 /// A writer that allows feedback output to be redirected to the specified writer,
@@ -1,20 +1,31 @@
 use diffy::PatchFormatter;
 
 use mago_interner::ThreadedInterner;
+use mago_reporting::reporter::ReportingTarget;
 use mago_source::Source;
 use mago_source::SourceManager;
 
 use crate::error::Error;
+use crate::utils::undo::UndoJournal;
 
+pub mod allocator;
+pub mod codeowners;
+pub mod color;
+pub mod crash;
+pub mod git;
+pub mod limit;
 pub mod logger;
 pub mod progress;
+pub mod shard;
+pub mod undo;
 
 /// Applies changes to the source file.
 ///
 /// If `dry_run` is `true`, it compares the original and modified content,
 /// displays a diff with context around changes, and does not write to disk.
 ///
-/// If `dry_run` is `false`, it writes the formatted content to the source manager.
+/// If `dry_run` is `false`, it writes the formatted content to the source manager, recording
+/// the original content in the given journal first, if any.
 ///
 /// # Arguments
 ///
@@ -23,6 +34,7 @@ pub mod progress;
 /// * `source` - Reference to the `Source` being processed.
 /// * `changed_code` - The formatted content as a `String`.
 /// * `dry_run` - Boolean flag indicating whether to perform a dry run.
+/// * `journal` - The journal to record the original content in, to be able to undo the change.
 ///
 /// # Returns
 ///
@@ -33,8 +45,9 @@ pub fn apply_changes(
     source: &Source,
     changed_code: String,
     dry_run: bool,
+    journal: Option<&UndoJournal>,
 ) -> Result<bool, Error> {
-    let original_content = interner.lookup(&source.content);
+    let original_content = source.content.as_ref();
     if original_content == changed_code {
         return Ok(false);
     }
@@ -44,12 +57,17 @@ pub fn apply_changes(
         let patch = diffy::create_patch(original_content, changed_code.as_str());
 
         progress::GLOBAL_PROGRESS_MANAGER.suspend(|| {
-            let formatter = PatchFormatter::new().with_color();
+            let formatter = PatchFormatter::new();
+            let formatter = if color::is_enabled(ReportingTarget::Stdout) { formatter.with_color() } else { formatter };
 
             println!("diff of '{}':", source_name);
             println!("{}", formatter.fmt_patch(&patch));
         });
     } else {
+        if let Some(journal) = journal {
+            journal.record(source)?;
+        }
+
         source_manager.write(source.identifier, changed_code)?;
     }
 
@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+/// A part of the user-defined sources, processed on its own, e.g. by one of several parallel CI jobs.
+///
+/// Sources are assigned to a shard by the hash of their name, i.e. their path relative to the root, so that
+/// every job agrees on the partition regardless of where the project is checked out, and the order in which
+/// the sources are discovered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    /// The 1-based index of the shard.
+    index: u64,
+
+    /// The number of shards.
+    count: u64,
+}
+
+impl Shard {
+    /// Determines whether the source with the given name belongs to this shard.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the source, i.e. its path relative to the root.
+    pub fn contains(&self, name: &str) -> bool {
+        let digest = Sha256::digest(name.replace('\\', "/").as_bytes());
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&digest[..8]);
+
+        u64::from_be_bytes(bytes) % self.count == self.index - 1
+    }
+
+    /// Determines whether this is the first shard, which reports the issues not located in any source.
+    pub const fn is_first(&self) -> bool {
+        self.index == 1
+    }
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    /// Parses a shard given on the command line, e.g. `2/5` for the second of five shards.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid shard `{}`, expected `INDEX/COUNT`, e.g. `2/5`", s);
+
+        let (index, count) = s.split_once('/').ok_or_else(invalid)?;
+        let index = index.trim().parse::<u64>().map_err(|_| invalid())?;
+        let count = count.trim().parse::<u64>().map_err(|_| invalid())?;
+
+        if count == 0 || index == 0 || index > count {
+            return Err(format!("invalid shard `{}`, the index must be between 1 and the number of shards", s));
+        }
+
+        Ok(Shard { index, count })
+    }
+}
+
+impl std::fmt::Display for Shard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
@@ -0,0 +1,181 @@
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use mago_source::Source;
+
+use crate::error::Error;
+
+/// The directory the journals are written to by default, relative to the workspace.
+pub const DEFAULT_UNDO_DIRECTORY: &str = ".mago/undo";
+
+/// The name of the file listing the files recorded in a journal.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// A journal of the original content of the files modified by a single run of `fix` or `format`,
+/// which `mago undo` restores.
+///
+/// Every run is recorded in its own directory, named after the time it started, containing a copy of
+/// every modified file and a manifest mapping each copy to the file it was taken from.
+#[derive(Debug)]
+pub struct UndoJournal {
+    directory: PathBuf,
+    files: Mutex<Vec<JournalFile>>,
+}
+
+/// The manifest of a journal.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JournalManifest {
+    /// The command that modified the files, e.g. `"fix"`.
+    pub command: String,
+
+    /// The files modified by the command.
+    pub files: Vec<JournalFile>,
+}
+
+/// A file recorded in a journal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalFile {
+    /// The path of the modified file.
+    pub path: PathBuf,
+
+    /// The name of the copy of its original content, in the directory of the journal.
+    pub backup: String,
+
+    /// The SHA-256 digest of the content written by the command, to detect the files modified since.
+    pub digest: Option<String>,
+}
+
+impl UndoJournal {
+    /// Creates a new journal in the given directory, in which every run is recorded in its own directory.
+    pub fn create(directory: &Path) -> Result<Self, Error> {
+        std::fs::create_dir_all(directory).map_err(|error| Error::WritingJournal(directory.to_path_buf(), error))?;
+
+        let mut timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_millis()).unwrap_or(0);
+        loop {
+            let run = directory.join(timestamp.to_string());
+
+            // Concurrent runs must not share a directory, so the next free timestamp is taken.
+            match std::fs::create_dir(&run) {
+                Ok(()) => return Ok(Self { directory: run, files: Mutex::new(vec![]) }),
+                Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => timestamp += 1,
+                Err(error) => return Err(Error::WritingJournal(run, error)),
+            }
+        }
+    }
+
+    /// Records the original content of the given source, before it is written for the first time.
+    ///
+    /// Sources written several times, e.g. by every pass of `fix`, are only recorded once, and sources
+    /// without a path are not recorded, as they are not written to disk.
+    pub fn record(&self, source: &Source) -> Result<(), Error> {
+        let Some(path) = &source.path else {
+            return Ok(());
+        };
+
+        let mut files = self.files.lock().expect("Unable to record the source: poisoned lock");
+        if files.iter().any(|file| &file.path == path) {
+            return Ok(());
+        }
+
+        let backup = format!("{}.bak", files.len());
+        let backup_path = self.directory.join(&backup);
+        // The file is copied as it is, rather than its content, which may have been transcoded to UTF-8 when loaded.
+        std::fs::copy(path, &backup_path).map_err(|error| Error::WritingJournal(backup_path, error))?;
+
+        files.push(JournalFile { path: path.clone(), backup, digest: None });
+
+        Ok(())
+    }
+
+    /// Completes the journal once every file is written, writing its manifest.
+    ///
+    /// A journal recording no file is removed, so that `mago undo` restores the last run which modified files.
+    ///
+    /// # Returns
+    ///
+    /// The number of files recorded.
+    pub fn finish(&self, command: &str) -> Result<usize, Error> {
+        let mut files = std::mem::take(&mut *self.files.lock().expect("Unable to complete the journal: poisoned lock"));
+        if files.is_empty() {
+            std::fs::remove_dir_all(&self.directory)
+                .map_err(|error| Error::WritingJournal(self.directory.clone(), error))?;
+
+            return Ok(0);
+        }
+
+        for file in &mut files {
+            file.digest = std::fs::read(&file.path).ok().map(|content| digest(&content));
+        }
+
+        let count = files.len();
+        let manifest = JournalManifest { command: command.to_string(), files };
+        let path = self.directory.join(MANIFEST_FILE);
+        let content = serde_json::to_vec_pretty(&manifest)?;
+        std::fs::write(&path, content).map_err(|error| Error::WritingJournal(path, error))?;
+
+        tracing::info!(
+            "Recorded the original content of {} files in '{}', run `mago undo` to restore them.",
+            count,
+            self.directory.display()
+        );
+
+        Ok(count)
+    }
+}
+
+/// Returns the directory and the manifest of the latest completed journal in the given directory, if any.
+pub fn find_latest_journal(directory: &Path) -> Result<Option<(PathBuf, JournalManifest)>, Error> {
+    let entries = match std::fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(Error::ReadingJournal(directory.to_path_buf(), error)),
+    };
+
+    let mut runs = vec![];
+    for entry in entries {
+        let entry = entry.map_err(|error| Error::ReadingJournal(directory.to_path_buf(), error))?;
+        let Some(timestamp) = entry.file_name().to_str().and_then(|name| name.parse::<u128>().ok()) else {
+            continue;
+        };
+
+        // Journals of runs which were interrupted, or are still running, have no manifest yet.
+        if entry.path().join(MANIFEST_FILE).is_file() {
+            runs.push((timestamp, entry.path()));
+        }
+    }
+
+    let Some((_, run)) = runs.into_iter().max_by_key(|(timestamp, _)| *timestamp) else {
+        return Ok(None);
+    };
+
+    let path = run.join(MANIFEST_FILE);
+    let content = std::fs::read(&path).map_err(|error| Error::ReadingJournal(path, error))?;
+    let manifest = serde_json::from_slice(&content)?;
+
+    Ok(Some((run, manifest)))
+}
+
+/// Returns the files of the given manifest which were modified since the journal was recorded.
+pub fn find_modified_files(manifest: &JournalManifest) -> Vec<&Path> {
+    manifest
+        .files
+        .iter()
+        .filter(|file| {
+            file.digest.as_deref() != std::fs::read(&file.path).ok().map(|content| digest(&content)).as_deref()
+        })
+        .map(|file| file.path.as_path())
+        .collect()
+}
+
+/// Returns the hexadecimal SHA-256 digest of the given content.
+fn digest(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}